@@ -12,7 +12,8 @@ pub mod traits;
 pub use tool_composition::{
     CompositionError, CompositionErrorStrategy, CompositionMetrics, CompositionResult,
     CompositionStep, ConditionType, DataFlow, DataTransform, ExecutionCondition, OutputTransform,
-    RetryConfig, StepErrorStrategy, StepMetrics, StepResult, ToolComposition, ToolProvider,
+    RetryConfig, StepAlternative, StepErrorStrategy, StepMetrics, StepResult, ToolComposition,
+    ToolProvider,
 };
 
 pub use traits::{
@@ -80,6 +80,23 @@ pub struct CompositionStep {
     pub max_execution_time: Option<Duration>,
     /// Retry configuration
     pub retry_config: Option<RetryConfig>,
+    /// Tools tried in order when the primary tool fails
+    pub alternatives: Vec<StepAlternative>,
+}
+
+/// A fallback tool for a composition step
+///
+/// Input mappings override the step's mappings of the same name, and output
+/// field mappings rename the alternative's output fields so downstream steps
+/// can keep referring to the primary tool's field names.
+#[derive(Debug, Clone)]
+pub struct StepAlternative {
+    /// Name of the tool to execute
+    pub tool_name: String,
+    /// Input parameter mappings that replace or extend the step's mappings
+    pub input_mappings: HashMap<String, DataFlow>,
+    /// Output field renames, from the alternative's field to the step's field
+    pub output_mappings: HashMap<String, String>,
 }
 
 /// Describes how data flows between steps
@@ -224,6 +241,8 @@ pub struct CompositionResult {
 pub struct StepResult {
     /// Whether the step succeeded
     pub success: bool,
+    /// Tool that produced the result (the primary or one of its alternatives)
+    pub tool_name: String,
     /// Output from the step
     pub output: JsonValue,
     /// Error if step failed
@@ -368,17 +387,15 @@ impl ToolComposition {
                 loop {
                     steps_executed += 1;
 
-                    // Prepare step input
-                    let step_input = self.prepare_step_input(step, &execution_context)?;
-
-                    // Execute the step
+                    // Execute the step, falling back to its alternatives
                     match self
-                        .execute_step(tool_provider, step, step_input, context.clone())
+                        .execute_step(tool_provider, step, &execution_context, context.clone())
                         .await
                     {
-                        Ok(output) => {
+                        Ok((tool_name, output)) => {
                             let result = Self::handle_step_success(
                                 &step.id,
+                                tool_name,
                                 output.clone(),
                                 step_start_time.elapsed(),
                                 retry_attempts,
@@ -504,10 +521,31 @@ impl ToolComposition {
         &self,
         step: &CompositionStep,
         context: &CompositionExecutionContext,
+    ) -> Result<JsonValue> {
+        self.prepare_input(&step.input_mappings, context)
+    }
+
+    /// Prepare input for an alternative, applying its mapping overrides
+    fn prepare_alternative_input(
+        &self,
+        step: &CompositionStep,
+        alternative: &StepAlternative,
+        context: &CompositionExecutionContext,
+    ) -> Result<JsonValue> {
+        let mut mappings = step.input_mappings.clone();
+        mappings.extend(alternative.input_mappings.clone());
+        self.prepare_input(&mappings, context)
+    }
+
+    /// Resolve a set of input mappings into a parameter object
+    fn prepare_input(
+        &self,
+        mappings: &HashMap<String, DataFlow>,
+        context: &CompositionExecutionContext,
     ) -> Result<JsonValue> {
         let mut input_params = Map::new();
 
-        for (param_name, data_flow) in &step.input_mappings {
+        for (param_name, data_flow) in mappings {
             let value = self.resolve_data_flow(data_flow, context)?;
             input_params.insert(param_name.clone(), value);
         }
@@ -515,6 +553,27 @@ impl ToolComposition {
         Ok(JsonValue::Object(input_params))
     }
 
+    /// Rename an alternative's output fields to the step's field names
+    fn map_alternative_output(alternative: &StepAlternative, output: JsonValue) -> JsonValue {
+        match output {
+            JsonValue::Object(obj) if !alternative.output_mappings.is_empty() => {
+                let mapped = obj
+                    .into_iter()
+                    .map(|(field, value)| {
+                        let field = alternative
+                            .output_mappings
+                            .get(&field)
+                            .cloned()
+                            .unwrap_or(field);
+                        (field, value)
+                    })
+                    .collect();
+                JsonValue::Object(mapped)
+            }
+            output => output,
+        }
+    }
+
     /// Resolve a data flow to a concrete value
     #[allow(clippy::only_used_in_recursion)]
     fn resolve_data_flow(
@@ -596,28 +655,59 @@ impl ToolComposition {
         }
     }
 
-    /// Execute a single step
+    /// Execute a single step, trying its alternatives in order if the primary tool fails
+    ///
+    /// Returns the name of the tool that succeeded with its (mapped) output, or
+    /// the last error if every candidate failed.
     #[allow(clippy::future_not_send)]
-    #[instrument(skip(self, tool_provider))]
+    #[instrument(skip(self, tool_provider, execution_context))]
     async fn execute_step<T>(
         &self,
         tool_provider: &T,
         step: &CompositionStep,
-        input: JsonValue,
+        execution_context: &CompositionExecutionContext,
         context: ExecutionContext,
-    ) -> Result<JsonValue>
+    ) -> Result<(String, JsonValue)>
     where
         T: ToolProvider,
     {
-        tool_provider
-            .execute_tool(&step.tool_name, input, context)
+        let input = self.prepare_step_input(step, execution_context)?;
+        let mut last_error = match tool_provider
+            .execute_tool(&step.tool_name, input, context.clone())
             .await
+        {
+            Ok(output) => return Ok((step.tool_name.clone(), output)),
+            Err(e) => e,
+        };
+
+        for alternative in &step.alternatives {
+            warn!(
+                "Step '{}' tool '{}' failed ({}), trying alternative '{}'",
+                step.id, step.tool_name, last_error, alternative.tool_name
+            );
+            let input = self.prepare_alternative_input(step, alternative, execution_context)?;
+            match tool_provider
+                .execute_tool(&alternative.tool_name, input, context.clone())
+                .await
+            {
+                Ok(output) => {
+                    return Ok((
+                        alternative.tool_name.clone(),
+                        Self::map_alternative_output(alternative, output),
+                    ))
+                }
+                Err(e) => last_error = e,
+            }
+        }
+
+        Err(last_error)
     }
 
     /// Helper to handle successful step execution
     #[allow(clippy::missing_const_for_fn)] // Cannot be const due to struct creation
     fn handle_step_success(
         _step_id: &str,
+        tool_name: String,
         output: JsonValue,
         execution_time: Duration,
         retry_attempts: u32,
@@ -630,6 +720,7 @@ impl ToolComposition {
 
         StepResult {
             success: true,
+            tool_name,
             output,
             error: None,
             metrics: step_metrics,
@@ -662,6 +753,7 @@ impl ToolComposition {
 
         StepResult {
             success: false,
+            tool_name: step.tool_name.clone(),
             output: JsonValue::Null,
             error: Some(error_msg),
             metrics: step_metrics,
@@ -714,6 +806,7 @@ impl CompositionStep {
             conditions: Vec::new(),
             max_execution_time: None,
             retry_config: None,
+            alternatives: Vec::new(),
         }
     }
 
@@ -755,6 +848,47 @@ impl CompositionStep {
         self.retry_config = Some(config);
         self
     }
+
+    /// Add an alternative tool, tried after the primary and earlier alternatives fail
+    #[must_use]
+    pub fn with_alternative(mut self, alternative: StepAlternative) -> Self {
+        self.alternatives.push(alternative);
+        self
+    }
+}
+
+impl StepAlternative {
+    /// Create an alternative that reuses the step's input mappings
+    pub fn new(tool_name: impl Into<String> + std::fmt::Debug) -> Self {
+        Self {
+            tool_name: tool_name.into(),
+            input_mappings: HashMap::new(),
+            output_mappings: HashMap::new(),
+        }
+    }
+
+    /// Override or add an input mapping for this alternative
+    #[must_use]
+    pub fn with_input_mapping(
+        mut self,
+        param_name: impl Into<String> + std::fmt::Debug,
+        data_flow: DataFlow,
+    ) -> Self {
+        self.input_mappings.insert(param_name.into(), data_flow);
+        self
+    }
+
+    /// Rename an output field of this alternative to the step's field name
+    #[must_use]
+    pub fn with_output_mapping(
+        mut self,
+        source_field: impl Into<String> + std::fmt::Debug,
+        target_field: impl Into<String> + std::fmt::Debug,
+    ) -> Self {
+        self.output_mappings
+            .insert(source_field.into(), target_field.into());
+        self
+    }
 }
 
 /// Execution context for a composition
@@ -843,6 +977,16 @@ mod tests {
                     || json!({"result": "NO_TEXT"}),
                     |text| json!({"result": text.to_uppercase()}),
                 )),
+                "flaky" => Err(LLMSpellError::Network {
+                    message: "connection reset".to_string(),
+                    source: None,
+                }),
+                "reverse" => Ok(json!({
+                    "reversed": input
+                        .get("content")
+                        .and_then(|v| v.as_str())
+                        .map(|text| text.chars().rev().collect::<String>())
+                })),
                 _ => Err(LLMSpellError::Component {
                     message: format!("Tool not found: {tool_name}"),
                     source: None,
@@ -909,6 +1053,50 @@ mod tests {
         assert!(step2_result.success);
     }
     #[tokio::test]
+    async fn test_step_falls_back_to_alternative_tool() {
+        let provider = MockToolProvider::new();
+        let mut composition = ToolComposition::new("fallback-test");
+
+        composition.add_step(
+            CompositionStep::new("step1", "flaky")
+                .with_input_mapping("text", DataFlow::Parameter("input_text".to_string()))
+                .with_alternative(
+                    StepAlternative::new("reverse")
+                        .with_input_mapping(
+                            "content",
+                            DataFlow::Parameter("input_text".to_string()),
+                        )
+                        .with_output_mapping("reversed", "text"),
+                ),
+        );
+
+        composition.add_step(
+            CompositionStep::new("step2", "transform").with_input_mapping(
+                "text",
+                DataFlow::StepOutput("step1".to_string(), "text".to_string()),
+            ),
+        );
+
+        let params = json!({"input_text": "hello"});
+        let result = composition
+            .execute(&provider, params, ExecutionContext::new())
+            .await
+            .unwrap();
+
+        assert!(result.success);
+        assert!(result.errors.is_empty());
+
+        let step1_result = result.step_results.get("step1").unwrap();
+        assert!(step1_result.success);
+        assert_eq!(step1_result.tool_name, "reverse");
+        assert_eq!(step1_result.output, json!({"text": "olleh"}));
+
+        let step2_result = result.step_results.get("step2").unwrap();
+        assert_eq!(step2_result.tool_name, "transform");
+        assert_eq!(step2_result.output, json!({"result": "OLLEH"}));
+        assert_eq!(result.output, json!({"result": "OLLEH"}));
+    }
+    #[tokio::test]
     async fn test_data_flow_transforms() {
         let composition = ToolComposition::new("test");
         let context = CompositionExecutionContext::new(
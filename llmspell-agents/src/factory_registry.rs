@@ -53,6 +53,27 @@ impl FactoryRegistry {
         Ok(())
     }
 
+    /// Replace a registered factory, returning the previous one
+    ///
+    /// The swap is atomic: agents already created by the old factory keep
+    /// running, and subsequent creations use the new factory.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no factory is registered under `name`
+    #[instrument(skip(factory, self))]
+    pub async fn replace(
+        &self,
+        name: &str,
+        factory: Arc<dyn AgentFactory>,
+    ) -> Result<Arc<dyn AgentFactory>> {
+        let mut factories = self.factories.write().await;
+        let Some(slot) = factories.get_mut(name) else {
+            anyhow::bail!("Factory '{name}' is not registered");
+        };
+        Ok(std::mem::replace(slot, factory))
+    }
+
     /// Get a factory by name
     #[instrument(skip_all)]
     pub async fn get_factory(&self, name: &str) -> Option<Arc<dyn AgentFactory>> {
@@ -293,6 +314,47 @@ mod tests {
             .await;
         assert!(result.is_err());
     }
+    #[tokio::test]
+    async fn test_replace_factory() {
+        let registry = FactoryRegistry::new();
+        let provider_manager = Arc::new(ProviderManager::new());
+        let versioned = |version: &'static str| {
+            let base = Arc::new(DefaultAgentFactory::new(provider_manager.clone()));
+            Arc::new(
+                CustomAgentFactory::new(base).with_customizer(move |config| {
+                    config.description = format!("built by {version}");
+                }),
+            )
+        };
+        let config = AgentConfig {
+            name: "worker".to_string(),
+            description: "worker".to_string(),
+            agent_type: "basic".to_string(),
+            model: None,
+            allowed_tools: vec![],
+            custom_config: serde_json::Map::new(),
+            resource_limits: ResourceLimits::default(),
+        };
+
+        // Replacing an unknown factory fails
+        assert!(registry.replace("custom", versioned("v1")).await.is_err());
+
+        registry
+            .register_factory("custom".to_string(), versioned("v1"))
+            .await
+            .unwrap();
+        let first = registry
+            .create_agent_with("custom", config.clone())
+            .await
+            .unwrap();
+
+        registry.replace("custom", versioned("v2")).await.unwrap();
+        let second = registry.create_agent_with("custom", config).await.unwrap();
+
+        assert_eq!(first.metadata().description, "built by v1");
+        assert_eq!(second.metadata().description, "built by v2");
+        assert_eq!(registry.list_factories().await, vec!["custom".to_string()]);
+    }
     #[test]
     fn test_global_registry() {
         let registry = global_registry();
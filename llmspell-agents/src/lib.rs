@@ -64,7 +64,9 @@ pub use lifecycle::{
 pub use tool_context::{
     ContextInheritanceRule, ToolContextManager, ToolExecutionContext, ToolExecutionRecord,
 };
-pub use tool_discovery::{RecommendationContext, ToolDiscovery, ToolSearchCriteria};
+pub use tool_discovery::{
+    RecommendationContext, ToolDiscovery, ToolOutcomeStats, ToolRecommendation, ToolSearchCriteria,
+};
 pub use tool_errors::{
     ErrorContext, ErrorRecoveryStrategy, RecoveryAction, ToolErrorHandler, ToolIntegrationError,
 };
@@ -3,16 +3,26 @@
 
 use llmspell_core::{
     traits::{
+        storage::StorageBackend,
         tool::{SecurityLevel, ToolCategory},
         tool_capable::{ToolInfo, ToolQuery},
     },
-    Result,
+    LLMSpellError, Result,
 };
+use llmspell_storage::StorageSerialize;
 use llmspell_tools::registry::{CapabilityMatcher, ToolRegistry};
+use serde::{Deserialize, Serialize};
 use serde_json::Value as JsonValue;
 use std::collections::HashMap;
 use std::sync::Arc;
-use tracing::instrument;
+use tokio::sync::Mutex;
+use tracing::{debug, instrument};
+
+/// Key prefix for per-tool outcome statistics
+const TOOL_STATS_PREFIX: &str = "tool_discovery:stats:";
+
+/// Share of the recommendation score given to relevance; the rest is success rate
+const RELEVANCE_WEIGHT: f64 = 0.5;
 
 /// High-level tool discovery that provides convenient APIs
 /// for finding and filtering tools based on various criteria.
@@ -44,13 +54,44 @@ use tracing::instrument;
 /// ```
 pub struct ToolDiscovery {
     registry: Arc<ToolRegistry>,
+    success_tracker: Option<ToolSuccessTracker>,
 }
 
 impl ToolDiscovery {
     /// Create a new tool discovery instance
     #[must_use]
     pub const fn new(registry: Arc<ToolRegistry>) -> Self {
-        Self { registry }
+        Self {
+            registry,
+            success_tracker: None,
+        }
+    }
+
+    /// Track tool outcomes in `storage` and weight recommendations by success rate
+    #[must_use]
+    pub fn with_success_tracking(mut self, storage: Arc<dyn StorageBackend>) -> Self {
+        self.success_tracker = Some(ToolSuccessTracker::new(storage));
+        self
+    }
+
+    /// Record whether a tool succeeded in the given context
+    ///
+    /// Does nothing unless success tracking is enabled.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the statistics cannot be read or written
+    #[instrument(skip(self, context))]
+    pub async fn record_outcome(
+        &self,
+        tool_name: &str,
+        context: &RecommendationContext,
+        success: bool,
+    ) -> Result<()> {
+        match &self.success_tracker {
+            Some(tracker) => tracker.record(tool_name, context, success).await,
+            None => Ok(()),
+        }
     }
 
     /// Find tools by category
@@ -183,7 +224,7 @@ impl ToolDiscovery {
         self.discover_tools(&query).await
     }
 
-    /// Get recommended tools based on context
+    /// Get recommended tools based on context, best first
     ///
     /// # Errors
     ///
@@ -195,6 +236,67 @@ impl ToolDiscovery {
         &self,
         context: &RecommendationContext,
     ) -> Result<Vec<ToolInfo>> {
+        Ok(self
+            .recommend(context)
+            .await?
+            .into_iter()
+            .map(|recommendation| recommendation.tool)
+            .collect())
+    }
+
+    /// Recommend tools for a context, ranked by relevance and historical success rate
+    ///
+    /// Relevance favours tools in the task's primary category and tools from the
+    /// context's usage history. Without success tracking, or for tools with no
+    /// recorded outcomes in similar contexts, the success rate is 0.5.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if tool discovery or reading outcome statistics fails
+    #[instrument(skip(self))]
+    pub async fn recommend(
+        &self,
+        context: &RecommendationContext,
+    ) -> Result<Vec<ToolRecommendation>> {
+        let criteria = Self::recommendation_criteria(context);
+        let primary_category = criteria.categories.first().cloned();
+
+        let mut recommendations = Vec::new();
+        for tool in self.find_by_criteria(&criteria).await? {
+            let mut relevance = 0.5;
+            if primary_category
+                .as_ref()
+                .is_some_and(|category| tool.category.eq_ignore_ascii_case(category))
+            {
+                relevance += 0.25;
+            }
+            if context.usage_history.contains(&tool.name) {
+                relevance += 0.25;
+            }
+
+            let success_rate = match &self.success_tracker {
+                Some(tracker) => tracker.stats(&tool.name, context).await?.success_rate(),
+                None => ToolOutcomeStats::default().success_rate(),
+            };
+
+            recommendations.push(ToolRecommendation {
+                score: RELEVANCE_WEIGHT.mul_add(relevance, (1.0 - RELEVANCE_WEIGHT) * success_rate),
+                relevance,
+                success_rate,
+                tool,
+            });
+        }
+
+        recommendations.sort_by(|a, b| {
+            b.score
+                .total_cmp(&a.score)
+                .then_with(|| a.tool.name.cmp(&b.tool.name))
+        });
+        Ok(recommendations)
+    }
+
+    /// Build search criteria from a recommendation context
+    fn recommendation_criteria(context: &RecommendationContext) -> ToolSearchCriteria {
         let mut criteria = ToolSearchCriteria::new();
 
         // Add task-based recommendations
@@ -235,7 +337,7 @@ impl ToolDiscovery {
                 .insert("performance_optimized".to_string(), JsonValue::Bool(true));
         }
 
-        self.find_by_criteria(&criteria).await
+        criteria
     }
 
     /// Internal method to discover tools using `ToolQuery`
@@ -493,6 +595,151 @@ impl Default for RecommendationContext {
     }
 }
 
+impl RecommendationContext {
+    /// Features under which tool outcomes are recorded and looked up
+    ///
+    /// Contexts sharing a feature, such as the task type, share that part of
+    /// their outcome history.
+    #[must_use]
+    pub fn features(&self) -> Vec<String> {
+        let mut features = Vec::new();
+        if let Some(task_type) = &self.task_type {
+            features.push(format!("task={task_type}"));
+        }
+        if let Some(level) = &self.max_security_level {
+            features.push(format!("security={level}"));
+        }
+        if self.performance_critical {
+            features.push("performance_critical".to_string());
+        }
+        if features.is_empty() {
+            features.push("general".to_string());
+        }
+        features
+    }
+}
+
+/// A recommended tool with its ranking components
+#[derive(Debug, Clone)]
+pub struct ToolRecommendation {
+    /// The recommended tool
+    pub tool: ToolInfo,
+    /// How well the tool matches the context (0.5 to 1.0)
+    pub relevance: f64,
+    /// Historical success rate in similar contexts (0.0 to 1.0)
+    pub success_rate: f64,
+    /// Combined ranking score
+    pub score: f64,
+}
+
+/// Success and failure counts of a tool for one context feature
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ToolOutcomeStats {
+    /// Number of successful invocations
+    pub successes: u64,
+    /// Number of failed invocations
+    pub failures: u64,
+}
+
+impl ToolOutcomeStats {
+    /// Success rate with add-one smoothing, so tools without history score 0.5
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)] // Counts stay far below 2^52
+    pub fn success_rate(&self) -> f64 {
+        (self.successes as f64 + 1.0) / ((self.successes + self.failures) as f64 + 2.0)
+    }
+
+    const fn merge(&mut self, other: Self) {
+        self.successes += other.successes;
+        self.failures += other.failures;
+    }
+}
+
+/// Persists per-tool outcome statistics keyed by context feature
+#[derive(Debug)]
+struct ToolSuccessTracker {
+    storage: Arc<dyn StorageBackend>,
+    /// Serializes read-modify-write updates of the counters
+    write_lock: Mutex<()>,
+}
+
+impl ToolSuccessTracker {
+    fn new(storage: Arc<dyn StorageBackend>) -> Self {
+        Self {
+            storage,
+            write_lock: Mutex::new(()),
+        }
+    }
+
+    fn stats_key(feature: &str, tool_name: &str) -> String {
+        format!("{TOOL_STATS_PREFIX}{feature}:{tool_name}")
+    }
+
+    async fn load(&self, key: &str) -> Result<ToolOutcomeStats> {
+        let data = self
+            .storage
+            .get(key)
+            .await
+            .map_err(|e| LLMSpellError::Storage {
+                message: format!("Failed to read tool statistics: {e}"),
+                operation: Some("read".to_string()),
+                source: None,
+            })?;
+        Ok(data
+            .and_then(|bytes| ToolOutcomeStats::from_storage_bytes(&bytes).ok())
+            .unwrap_or_default())
+    }
+
+    async fn record(
+        &self,
+        tool_name: &str,
+        context: &RecommendationContext,
+        success: bool,
+    ) -> Result<()> {
+        let _guard = self.write_lock.lock().await;
+        for feature in context.features() {
+            let key = Self::stats_key(&feature, tool_name);
+            let mut stats = self.load(&key).await?;
+            if success {
+                stats.successes += 1;
+            } else {
+                stats.failures += 1;
+            }
+
+            let bytes = stats
+                .to_storage_bytes()
+                .map_err(|e| LLMSpellError::Storage {
+                    message: format!("Failed to serialize tool statistics: {e}"),
+                    operation: Some("serialize".to_string()),
+                    source: None,
+                })?;
+            self.storage
+                .set(&key, bytes)
+                .await
+                .map_err(|e| LLMSpellError::Storage {
+                    message: format!("Failed to write tool statistics: {e}"),
+                    operation: Some("write".to_string()),
+                    source: None,
+                })?;
+        }
+        debug!(tool_name, success, "Recorded tool outcome");
+        Ok(())
+    }
+
+    /// Outcome statistics of a tool summed over the context's features
+    async fn stats(
+        &self,
+        tool_name: &str,
+        context: &RecommendationContext,
+    ) -> Result<ToolOutcomeStats> {
+        let mut total = ToolOutcomeStats::default();
+        for feature in context.features() {
+            total.merge(self.load(&Self::stats_key(&feature, tool_name)).await?);
+        }
+        Ok(total)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -553,4 +800,62 @@ mod tests {
         // Should return empty for new registry, but test that it doesn't error
         assert!(tools.is_empty() || !tools.is_empty()); // Just test it doesn't panic
     }
+    #[tokio::test]
+    async fn test_recommendations_favor_historically_successful_tools() {
+        use llmspell_storage::backends::MemoryBackend;
+        use llmspell_tools::util::uuid_generator::{UuidGeneratorConfig, UuidGeneratorTool};
+        use llmspell_tools::CalculatorTool;
+
+        let registry = Arc::new(ToolRegistry::new());
+        registry
+            .register("calculator".to_string(), CalculatorTool::new())
+            .await
+            .unwrap();
+        registry
+            .register(
+                "uuid_generator".to_string(),
+                UuidGeneratorTool::new(UuidGeneratorConfig::default()),
+            )
+            .await
+            .unwrap();
+        let storage: Arc<dyn StorageBackend> = Arc::new(MemoryBackend::new());
+        let discovery = ToolDiscovery::new(registry.clone()).with_success_tracking(storage.clone());
+
+        // Without history, equally relevant tools tie and rank by name
+        let context = RecommendationContext::new().with_task_type("identifiers");
+        let ranked = discovery.recommend(&context).await.unwrap();
+        assert_eq!(ranked[0].tool.name, "calculator");
+        assert!((ranked[0].success_rate - 0.5).abs() < f64::EPSILON);
+
+        let recorded = RecommendationContext::new()
+            .with_task_type("identifiers")
+            .with_max_security_level("safe");
+        for _ in 0..3 {
+            discovery
+                .record_outcome("uuid_generator", &recorded, true)
+                .await
+                .unwrap();
+            discovery
+                .record_outcome("calculator", &recorded, false)
+                .await
+                .unwrap();
+        }
+
+        // A similar context sharing the task type ranks the successful tool first,
+        // including for a new discovery instance reading the persisted stats
+        let similar = RecommendationContext::new()
+            .with_task_type("identifiers")
+            .performance_critical();
+        let reloaded = ToolDiscovery::new(registry).with_success_tracking(storage);
+        for discovery in [&discovery, &reloaded] {
+            let ranked = discovery.recommend(&similar).await.unwrap();
+            let names: Vec<&str> = ranked.iter().map(|r| r.tool.name.as_str()).collect();
+            assert_eq!(names, vec!["uuid_generator", "calculator"]);
+            assert!(ranked[0].success_rate > ranked[1].success_rate);
+        }
+        assert_eq!(
+            discovery.get_recommended_tools(&similar).await.unwrap()[0].name,
+            "uuid_generator"
+        );
+    }
 }
@@ -81,6 +81,8 @@ pub struct ToolManagerConfig {
     pub max_parallel_executions: usize,
     /// Whether to validate tool parameters before invocation
     pub validate_parameters: bool,
+    /// Glob patterns of tools that may be invoked (`None` allows every tool)
+    pub allowed_tools: Option<Vec<String>>,
 }
 
 impl Default for ToolManagerConfig {
@@ -91,6 +93,7 @@ impl Default for ToolManagerConfig {
             enable_availability_cache: true,
             max_parallel_executions: 4,
             validate_parameters: true,
+            allowed_tools: None,
         }
     }
 }
@@ -160,6 +163,10 @@ impl ToolManager {
 
         let mut tools = Vec::new();
         for registry_info in tool_info_list {
+            if !self.is_tool_allowed(&registry_info.name) {
+                continue;
+            }
+
             // Apply text search filter if specified
             if let Some(search_text) = &query.text_search {
                 let text_lower = search_text.to_lowercase();
@@ -195,6 +202,7 @@ impl ToolManager {
     /// # Errors
     ///
     /// Returns an error if:
+    /// - Tool is not on the allowlist
     /// - Tool is not found or not available
     /// - Tool execution times out
     /// - Tool execution fails
@@ -213,6 +221,14 @@ impl ToolManager {
         context: ExecutionContext,
     ) -> Result<AgentOutput> {
         info!("Invoking tool '{}'", tool_name);
+        if !self.is_tool_allowed(tool_name) {
+            warn!("Tool '{}' denied by allowlist", tool_name);
+            return Err(LLMSpellError::Security {
+                message: format!("Tool '{tool_name}' is not in the allowed tools for this agent"),
+                violation_type: Some("tool_not_allowed".to_string()),
+            });
+        }
+
         // Check if tool is available
         if !self.tool_available(tool_name).await {
             return Err(LLMSpellError::Component {
@@ -273,10 +289,21 @@ impl ToolManager {
     /// Returns an error if tool listing fails
     #[instrument(skip(self))]
     pub async fn list_available_tools(&self) -> Result<Vec<String>> {
-        let all_tools = self.registry.list_tools().await;
+        let mut all_tools = self.registry.list_tools().await;
+        all_tools.retain(|name| self.is_tool_allowed(name));
         Ok(all_tools)
     }
 
+    /// Check if a tool is permitted by the `allowed_tools` patterns
+    #[must_use]
+    pub fn is_tool_allowed(&self, tool_name: &str) -> bool {
+        self.config.allowed_tools.as_ref().is_none_or(|patterns| {
+            patterns
+                .iter()
+                .any(|pattern| llmspell_utils::glob_match(pattern, tool_name))
+        })
+    }
+
     /// Check if a specific tool is available
     #[instrument(skip(self))]
     pub async fn tool_available(&self, tool_name: &str) -> bool {
@@ -584,6 +611,7 @@ mod tests {
             enable_availability_cache: false,
             max_parallel_executions: 2,
             validate_parameters: false,
+            allowed_tools: None,
         };
 
         let manager = ToolManager::with_config(registry, config);
@@ -663,6 +691,59 @@ mod tests {
         assert_eq!(result["other"], JsonValue::String("value".to_string()));
     }
     #[tokio::test]
+    async fn test_allowed_tools_restricts_invocation() {
+        use llmspell_tools::api::http_request::HttpRequestConfig;
+        use llmspell_tools::{CalculatorTool, HttpRequestTool};
+
+        let registry = Arc::new(ToolRegistry::new());
+        registry
+            .register("calculator".to_string(), CalculatorTool::new())
+            .await
+            .unwrap();
+        registry
+            .register(
+                "http_request".to_string(),
+                HttpRequestTool::new(HttpRequestConfig::default()).unwrap(),
+            )
+            .await
+            .unwrap();
+        let config = ToolManagerConfig {
+            allowed_tools: Some(vec!["calc*".to_string()]),
+            ..ToolManagerConfig::default()
+        };
+        let manager = ToolManager::with_config(registry, config);
+
+        let output = manager
+            .invoke_tool(
+                "calculator",
+                json!({"input": "2 + 3"}),
+                ExecutionContext::new(),
+            )
+            .await
+            .unwrap();
+        assert!(output.text.contains('5'));
+
+        let denied = manager
+            .invoke_tool(
+                "http_request",
+                json!({"input": "https://example.com"}),
+                ExecutionContext::new(),
+            )
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            denied,
+            LLMSpellError::Security { ref violation_type, .. }
+                if violation_type.as_deref() == Some("tool_not_allowed")
+        ));
+        assert!(denied.to_string().contains("http_request"));
+
+        assert_eq!(
+            manager.list_available_tools().await.unwrap(),
+            vec!["calculator".to_string()]
+        );
+    }
+    #[tokio::test]
     async fn test_cache_clearing() {
         let registry = Arc::new(ToolRegistry::new());
         let manager = ToolManager::new(registry);
@@ -5,6 +5,7 @@
 
 use crate::agents::{AgentDiscovery, AgentInfo};
 use crate::ComponentRegistry;
+use futures::StreamExt;
 use llmspell_agents::lifecycle::{AgentState, AgentStateMachine};
 use llmspell_agents::monitoring::metrics::MetricAccess;
 use llmspell_agents::monitoring::{
@@ -17,7 +18,7 @@ use llmspell_core::execution_context::{
 };
 #[cfg(test)]
 use llmspell_core::types::ComponentId;
-use llmspell_core::types::{AgentInput, AgentOutput};
+use llmspell_core::types::{AgentChunk, AgentInput, AgentOutput, ChunkContent, ControlMessage};
 use llmspell_core::{Agent, ExecutionContext, LLMSpellError, Result, Tool};
 use llmspell_kernel::state::{StateManager, StateScope};
 use serde::{Deserialize, Serialize};
@@ -27,6 +28,92 @@ use std::time::Duration;
 use tokio::sync::mpsc;
 use tracing::{debug, info, warn};
 
+/// Finish reason of a stream that completed normally
+const FINISH_STOP: &str = "stop";
+
+/// Finish reason of a stream that ended with an error
+const FINISH_ERROR: &str = "error";
+
+/// Finish reason of a stream cancelled by the agent
+const FINISH_CANCELLED: &str = "cancelled";
+
+/// Token usage reported on streamed chunks
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub struct StreamUsage {
+    /// Tokens produced so far; the aggregate on the final chunk
+    pub total_tokens: usize,
+}
+
+/// A streamed agent chunk as surfaced to scripts
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StreamChunk {
+    /// Text content of the chunk
+    pub content: String,
+    /// Position of the chunk in the stream, starting at 0
+    pub index: usize,
+    /// Why the stream ended; set only on the final chunk
+    pub finish_reason: Option<String>,
+    /// Token usage so far, when the agent reports token counts
+    pub usage: Option<StreamUsage>,
+    /// Whole output of a non-streaming agent, surfaced with its metadata and media
+    pub output: Option<AgentOutput>,
+}
+
+/// Turns agent chunks into script-facing chunks, tracking index and token usage
+#[derive(Debug, Default)]
+struct StreamChunkBuilder {
+    index: usize,
+    total_tokens: Option<usize>,
+    finished: bool,
+}
+
+impl StreamChunkBuilder {
+    fn usage(&self) -> Option<StreamUsage> {
+        self.total_tokens
+            .map(|total_tokens| StreamUsage { total_tokens })
+    }
+
+    fn chunk(&mut self, content: String, finish_reason: Option<&str>) -> StreamChunk {
+        let chunk = StreamChunk {
+            content,
+            index: self.index,
+            finish_reason: finish_reason.map(str::to_string),
+            usage: self.usage(),
+            output: None,
+        };
+        self.index += 1;
+        self.finished |= finish_reason.is_some();
+        chunk
+    }
+
+    /// Convert an agent chunk, or `None` if it carries nothing for scripts
+    fn accept(&mut self, chunk: AgentChunk) -> Option<StreamChunk> {
+        if let Some(tokens) = chunk.metadata.token_count {
+            self.total_tokens = Some(self.total_tokens.unwrap_or(0) + tokens);
+        }
+        let finish_reason = chunk.metadata.is_final.then_some(FINISH_STOP);
+
+        match chunk.content {
+            ChunkContent::Text(text) => Some(self.chunk(text, finish_reason)),
+            ChunkContent::Control(ControlMessage::StreamEnd { total_tokens, .. }) => {
+                if total_tokens.is_some() {
+                    self.total_tokens = total_tokens;
+                }
+                Some(self.finish(FINISH_STOP, String::new()))
+            }
+            ChunkContent::Control(ControlMessage::StreamCancelled { reason }) => {
+                Some(self.finish(FINISH_CANCELLED, reason))
+            }
+            _ => finish_reason.map(|reason| self.chunk(String::new(), Some(reason))),
+        }
+    }
+
+    /// Final chunk carrying the aggregate usage
+    fn finish(&mut self, reason: &str, content: String) -> StreamChunk {
+        self.chunk(content, Some(reason))
+    }
+}
+
 /// Routing strategy for composite agents
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Default)]
 #[serde(rename_all = "lowercase")]
@@ -215,6 +302,8 @@ pub struct AgentBridge {
     registry: Arc<ComponentRegistry>,
     /// Active agent instances
     active_agents: Arc<tokio::sync::RwLock<HashMap<String, Arc<dyn Agent>>>>,
+    /// Tool allowlists (non-empty `AgentConfig.allowed_tools` globs) of agents created from a config
+    tool_allowlists: Arc<tokio::sync::RwLock<HashMap<String, Vec<String>>>>,
     /// Agent state machines
     state_machines: Arc<tokio::sync::RwLock<HashMap<String, Arc<AgentStateMachine>>>>,
     /// Monitoring components
@@ -230,7 +319,7 @@ pub struct AgentBridge {
     /// Active contexts by ID
     contexts: Arc<tokio::sync::RwLock<HashMap<String, Arc<ExecutionContext>>>>,
     /// Active streaming channels
-    streaming_channels: Arc<tokio::sync::RwLock<HashMap<String, mpsc::Sender<AgentOutput>>>>,
+    streaming_channels: Arc<tokio::sync::RwLock<HashMap<String, mpsc::Sender<StreamChunk>>>>,
     /// State manager for agent state persistence
     state_manager: Option<Arc<StateManager>>,
 }
@@ -246,6 +335,7 @@ impl AgentBridge {
             discovery: Arc::new(AgentDiscovery::new(provider_manager)),
             registry,
             active_agents: Arc::new(tokio::sync::RwLock::new(HashMap::new())),
+            tool_allowlists: Arc::new(tokio::sync::RwLock::new(HashMap::new())),
             state_machines: Arc::new(tokio::sync::RwLock::new(HashMap::new())),
             metrics_registry: Arc::new(MetricRegistry::new()),
             performance_monitor: Arc::new(PerformanceMonitor::new(
@@ -270,6 +360,7 @@ impl AgentBridge {
             discovery: Arc::new(AgentDiscovery::with_factory(factory)),
             registry,
             active_agents: Arc::new(tokio::sync::RwLock::new(HashMap::new())),
+            tool_allowlists: Arc::new(tokio::sync::RwLock::new(HashMap::new())),
             state_machines: Arc::new(tokio::sync::RwLock::new(HashMap::new())),
             metrics_registry: Arc::new(MetricRegistry::new()),
             performance_monitor: Arc::new(PerformanceMonitor::new(
@@ -329,6 +420,7 @@ impl AgentBridge {
         }
 
         // Create the agent with typed config
+        let allowed_tools = config.allowed_tools.clone();
         let agent = self.discovery.create_agent(config).await?;
 
         // Create state machine for the agent
@@ -343,6 +435,11 @@ impl AgentBridge {
             let mut machines = self.state_machines.write().await;
             machines.insert(instance_name.clone(), state_machine);
         }
+        // An empty `allowed_tools` (the script builders' default) leaves tools unrestricted
+        if !allowed_tools.is_empty() {
+            let mut allowlists = self.tool_allowlists.write().await;
+            allowlists.insert(instance_name.clone(), allowed_tools);
+        }
 
         // Also register in component registry for script access
         debug!(
@@ -358,6 +455,35 @@ impl AgentBridge {
         Ok(())
     }
 
+    /// Register an agent implemented by the host as a named instance
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the agent instance already exists
+    pub async fn register_agent(&self, instance_name: &str, agent: Arc<dyn Agent>) -> Result<()> {
+        {
+            let mut agents = self.active_agents.write().await;
+            if agents.contains_key(instance_name) {
+                return Err(LLMSpellError::Validation {
+                    field: Some("instance_name".to_string()),
+                    message: format!("Agent instance '{instance_name}' already exists"),
+                });
+            }
+            // Only track the agent once the registry has accepted it
+            self.registry
+                .register_agent(instance_name.to_string(), agent.clone())?;
+            agents.insert(instance_name.to_string(), agent);
+        }
+        {
+            let mut machines = self.state_machines.write().await;
+            machines.insert(
+                instance_name.to_string(),
+                Arc::new(AgentStateMachine::default(instance_name.to_string())),
+            );
+        }
+        Ok(())
+    }
+
     /// Create agent from template
     ///
     /// # Errors
@@ -457,6 +583,10 @@ impl AgentBridge {
             let mut machines = self.state_machines.write().await;
             machines.remove(instance_name);
         }
+        {
+            let mut allowlists = self.tool_allowlists.write().await;
+            allowlists.remove(instance_name);
+        }
 
         if removed.is_none() {
             return Err(LLMSpellError::Component {
@@ -507,6 +637,7 @@ impl AgentBridge {
     pub async fn clear_all(&self) {
         let mut agents = self.active_agents.write().await;
         agents.clear();
+        self.tool_allowlists.write().await.clear();
         // Note: This doesn't clear the component registry
     }
 
@@ -524,11 +655,26 @@ impl AgentBridge {
         self.registry.get_tool(tool_name)
     }
 
+    /// Check if an agent may invoke a tool
+    ///
+    /// Agents created from an `AgentConfig` with a non-empty `allowed_tools` may only use
+    /// tools matching one of its glob patterns (`"*"` allows every tool). Agents with an
+    /// empty list, created from a template, or registered by the host may use any tool.
+    pub async fn is_tool_allowed_for_agent(&self, agent_instance: &str, tool_name: &str) -> bool {
+        let allowlists = self.tool_allowlists.read().await;
+        allowlists.get(agent_instance).is_none_or(|patterns| {
+            patterns
+                .iter()
+                .any(|pattern| llmspell_utils::glob_match(pattern, tool_name))
+        })
+    }
+
     /// Invoke a tool on behalf of an agent
     ///
     /// # Errors
     ///
-    /// Returns an error if the agent instance or tool is not found, or if tool execution fails
+    /// Returns an error if the agent instance or tool is not found, the tool is not in
+    /// the agent's `allowed_tools`, or tool execution fails
     pub async fn invoke_tool_for_agent(
         &self,
         agent_instance: &str,
@@ -545,6 +691,22 @@ impl AgentBridge {
                     source: None,
                 })?;
 
+        if !self
+            .is_tool_allowed_for_agent(agent_instance, tool_name)
+            .await
+        {
+            warn!(
+                "Tool '{}' denied by allowlist of agent '{}'",
+                tool_name, agent_instance
+            );
+            return Err(LLMSpellError::Security {
+                message: format!(
+                    "Tool '{tool_name}' is not in the allowed tools for agent '{agent_instance}'"
+                ),
+                violation_type: Some("tool_not_allowed".to_string()),
+            });
+        }
+
         // Get the tool
         let tool = self
             .registry
@@ -1305,6 +1467,9 @@ impl AgentBridge {
 
     /// Execute agent with streaming
     ///
+    /// Agents that support streaming are streamed chunk by chunk; others produce a single
+    /// chunk. The last chunk always has a `finish_reason` and carries the aggregate usage.
+    ///
     /// # Errors
     ///
     /// Returns an error if the agent instance is not found
@@ -1313,9 +1478,9 @@ impl AgentBridge {
         instance_name: &str,
         input: AgentInput,
         context: Option<ExecutionContext>,
-    ) -> Result<mpsc::Receiver<AgentOutput>> {
+    ) -> Result<mpsc::Receiver<StreamChunk>> {
         // Create streaming channel
-        let (tx, rx) = mpsc::channel::<AgentOutput>(100);
+        let (tx, rx) = mpsc::channel::<StreamChunk>(100);
         let channel_id = uuid::Uuid::new_v4().to_string();
 
         // Store channel
@@ -1337,18 +1502,48 @@ impl AgentBridge {
         let context = context.unwrap_or_default();
         let channels = self.streaming_channels.clone();
         tokio::spawn(async move {
-            // Execute agent
-            match agent.execute(input, context).await {
-                Ok(output) => {
-                    // Send output chunks (for now, send as single chunk)
-                    // In a real implementation, we'd support streaming from the agent
-                    let _ = tx.send(output).await;
-                }
-                Err(e) => {
-                    // Send error as output
-                    let error_output = AgentOutput::text(format!("Error: {e}"));
-                    let _ = tx.send(error_output).await;
+            let mut builder = StreamChunkBuilder::default();
+
+            if agent.supports_streaming() {
+                match agent.stream_execute(input, context).await {
+                    Ok(mut stream) => {
+                        while let Some(item) = stream.next().await {
+                            let chunk = match item {
+                                Ok(chunk) => builder.accept(chunk),
+                                Err(e) => Some(builder.finish(FINISH_ERROR, format!("Error: {e}"))),
+                            };
+                            if let Some(chunk) = chunk {
+                                if tx.send(chunk).await.is_err() {
+                                    break;
+                                }
+                            }
+                            if builder.finished {
+                                break;
+                            }
+                        }
+                        if !builder.finished {
+                            let _ = tx.send(builder.finish(FINISH_STOP, String::new())).await;
+                        }
+                    }
+                    Err(e) => {
+                        let _ = tx
+                            .send(builder.finish(FINISH_ERROR, format!("Error: {e}")))
+                            .await;
+                    }
                 }
+            } else {
+                // Non-streaming agents produce their whole output as one final chunk
+                let chunk = match agent.execute(input, context).await {
+                    Ok(output) => {
+                        builder.total_tokens =
+                            output.metadata.token_count.map(|count| count as usize);
+                        let mut chunk = builder.finish(FINISH_STOP, output.text.clone());
+                        chunk.output = Some(output);
+                        chunk
+                    }
+                    Err(e) => builder.finish(FINISH_ERROR, format!("Error: {e}")),
+                };
+                let _ = tx.send(chunk).await;
             }
 
             // Clean up channel
@@ -2001,6 +2196,81 @@ mod tests {
         let agent_after = bridge.get_agent("test-instance").await;
         assert!(agent_after.is_none());
     }
+    #[tokio::test]
+    async fn test_register_agent_rejected_by_registry_is_not_tracked() {
+        let registry = Arc::new(ComponentRegistry::new());
+        let provider_manager = Arc::new(llmspell_providers::ProviderManager::new());
+        let bridge = AgentBridge::new(registry.clone(), provider_manager);
+
+        bridge
+            .create_agent(create_test_agent_config("source"))
+            .await
+            .unwrap();
+        let agent = bridge.get_agent("source").await.unwrap();
+
+        // The name is taken in the component registry but not by the bridge
+        registry
+            .register_agent("taken".to_string(), agent.clone())
+            .unwrap();
+        assert!(bridge.register_agent("taken", agent).await.is_err());
+
+        assert!(bridge.get_agent("taken").await.is_none());
+        assert!(!bridge.list_instances().await.contains(&"taken".to_string()));
+    }
+    #[tokio::test]
+    async fn test_invoke_tool_respects_agent_allowed_tools() {
+        use llmspell_tools::api::http_request::HttpRequestConfig;
+        use llmspell_tools::{CalculatorTool, HttpRequestTool};
+
+        let registry = Arc::new(ComponentRegistry::new());
+        registry
+            .register_tool("calculator".to_string(), Arc::new(CalculatorTool::new()))
+            .unwrap();
+        registry
+            .register_tool(
+                "http_request".to_string(),
+                Arc::new(HttpRequestTool::new(HttpRequestConfig::default()).unwrap()),
+            )
+            .unwrap();
+        let provider_manager = Arc::new(llmspell_providers::ProviderManager::new());
+        let bridge = AgentBridge::new(registry, provider_manager);
+
+        let mut config = create_test_agent_config("calc-only");
+        config.allowed_tools = vec!["calculator".to_string()];
+        bridge.create_agent(config).await.unwrap();
+
+        let output = bridge
+            .invoke_tool_for_agent(
+                "calc-only",
+                "calculator",
+                AgentInput::text("calculate")
+                    .with_parameter("parameters", serde_json::json!({"input": "2 + 3"})),
+                None,
+            )
+            .await
+            .unwrap();
+        assert!(output.text.contains('5'));
+
+        let denied = bridge
+            .invoke_tool_for_agent(
+                "calc-only",
+                "http_request",
+                AgentInput::text("fetch").with_parameter(
+                    "parameters",
+                    serde_json::json!({"input": "https://example.com"}),
+                ),
+                None,
+            )
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            denied,
+            LLMSpellError::Security { ref violation_type, .. }
+                if violation_type.as_deref() == Some("tool_not_allowed")
+        ));
+        assert!(denied.to_string().contains("http_request"));
+    }
+
     #[tokio::test]
     async fn test_agent_execution() {
         let registry = Arc::new(ComponentRegistry::new());
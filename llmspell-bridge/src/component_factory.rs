@@ -28,6 +28,7 @@ impl ComponentFactory {
                 emit_state_events: config.events.emit_state_events,
                 emit_debug_events: config.events.emit_debug_events,
                 max_events_per_second: config.events.max_events_per_second,
+                sampling: config.events.sampling_policies(),
             };
             Arc::new(ComponentRegistry::with_event_bus(event_bus, event_config))
         } else {
@@ -244,20 +244,33 @@ impl ConfigBridge {
         self.immutable.boot_locked_security.contains(setting_name)
     }
 
-    /// Redact sensitive information from a value
+    /// Check if a configuration key holds sensitive data
+    fn is_secret_key(key: &str) -> bool {
+        key.contains("api_key") || key.contains("secret") || key.contains("password")
+    }
+
+    /// Redact sensitive information from a value, including nested sections
     fn redact_secrets(&self, mut value: serde_json::Value) -> serde_json::Value {
         if !self.permissions.access_secrets {
-            if let serde_json::Value::Object(ref mut map) = value {
-                // Redact API keys and other secrets
+            Self::redact_nested(&mut value);
+        }
+        value
+    }
+
+    fn redact_nested(value: &mut serde_json::Value) {
+        match value {
+            serde_json::Value::Object(map) => {
                 for (key, val) in map.iter_mut() {
-                    if key.contains("api_key") || key.contains("secret") || key.contains("password")
-                    {
+                    if Self::is_secret_key(key) {
                         *val = serde_json::Value::String("<REDACTED>".to_string());
+                    } else {
+                        Self::redact_nested(val);
                     }
                 }
             }
+            serde_json::Value::Array(items) => items.iter_mut().for_each(Self::redact_nested),
+            _ => {}
         }
-        value
     }
 
     /// Create a snapshot of current configuration
@@ -420,6 +433,63 @@ impl ConfigBridge {
         Ok(redacted)
     }
 
+    /// Get a single value from the effective configuration by dot-separated path
+    ///
+    /// Returns `None` for unknown paths and, without `access_secrets`, for paths
+    /// through secret fields such as `providers.openai.api_key`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if user lacks read permission
+    pub fn get_path(&self, path: &str) -> Result<Option<serde_json::Value>, LLMSpellError> {
+        if !self.permissions.read {
+            self.audit(
+                ConfigChangeType::Read,
+                path.to_string(),
+                None,
+                None,
+                false,
+                Some("No read permission".to_string()),
+            );
+            return Err(LLMSpellError::Configuration {
+                message: "No read permission for configuration".to_string(),
+                source: None,
+            });
+        }
+
+        let config = self.get_effective_config()?;
+        let json = serde_json::to_value(&config).map_err(|e| LLMSpellError::Configuration {
+            message: format!("Failed to serialize config: {e}"),
+            source: None,
+        })?;
+
+        let secret = !self.permissions.access_secrets && path.split('.').any(Self::is_secret_key);
+        let value = if secret {
+            None
+        } else {
+            path.split('.')
+                .try_fold(&json, |value, segment| match value {
+                    serde_json::Value::Object(map) => map.get(segment),
+                    serde_json::Value::Array(items) => {
+                        segment.parse::<usize>().ok().and_then(|i| items.get(i))
+                    }
+                    _ => None,
+                })
+                .map(|value| self.redact_secrets(value.clone()))
+        };
+
+        self.audit(
+            ConfigChangeType::Read,
+            path.to_string(),
+            None,
+            value.clone(),
+            !secret,
+            secret.then(|| "Secret configuration value".to_string()),
+        );
+
+        Ok(value)
+    }
+
     /// Get the default engine
     ///
     /// # Errors
@@ -884,10 +954,12 @@ impl ConfigBridge {
             }
         };
 
-        value.map_err(|e| LLMSpellError::Configuration {
-            message: format!("Failed to serialize section {section}: {e}"),
-            source: None,
-        })
+        value
+            .map(|value| self.redact_secrets(value))
+            .map_err(|e| LLMSpellError::Configuration {
+                message: format!("Failed to serialize section {section}: {e}"),
+                source: None,
+            })
     }
 }
 
@@ -1000,4 +1072,36 @@ mod tests {
         let providers_json = bridge.section_to_json("providers").unwrap();
         assert!(providers_json.is_object());
     }
+
+    #[test]
+    fn test_config_get_path() {
+        let mut config = LLMSpellConfig::default();
+        config.rag.vector_storage.dimensions = 768;
+        config.providers.providers.insert(
+            "openai".to_string(),
+            ProviderConfig {
+                provider_type: "openai".to_string(),
+                api_key: Some("sk-secret".to_string()),
+                ..ProviderConfig::default()
+            },
+        );
+        let bridge = ConfigBridge::new(config, ConfigPermissions::read_only());
+
+        assert_eq!(
+            bridge.get_path("default_engine").unwrap(),
+            Some(serde_json::json!("lua"))
+        );
+        assert_eq!(
+            bridge.get_path("rag.vector_storage.dimensions").unwrap(),
+            Some(serde_json::json!(768))
+        );
+        assert_eq!(bridge.get_path("rag.no_such_field").unwrap(), None);
+
+        // Secrets are hidden by path and redacted inside returned sections
+        assert_eq!(bridge.get_path("providers.openai.api_key").unwrap(), None);
+        let provider = bridge.get_path("providers.openai").unwrap().unwrap();
+        assert_eq!(provider["provider_type"], "openai");
+        assert_eq!(provider["api_key"], "<REDACTED>");
+        assert!(!bridge.get().unwrap().to_string().contains("sk-secret"));
+    }
 }
@@ -8,6 +8,7 @@ use llmspell_core::{
 use serde_json::Value;
 use std::collections::HashMap;
 use std::sync::Arc;
+use tokio_util::sync::CancellationToken;
 
 /// Dependencies for API injection into script engines
 ///
@@ -91,6 +92,25 @@ pub trait ScriptEngineBridge: Send + Sync {
     /// Execute a script and return the output
     async fn execute_script(&self, script: &str) -> Result<ScriptOutput, LLMSpellError>;
 
+    /// Execute a script, aborting it when `token` is cancelled
+    ///
+    /// Returns `LLMSpellError::Cancelled` if the token fires before the script completes.
+    /// The default implementation only interrupts engines whose execution yields to the
+    /// async runtime; engines running scripts synchronously should override it.
+    async fn execute_script_cancellable(
+        &self,
+        script: &str,
+        token: CancellationToken,
+    ) -> Result<ScriptOutput, LLMSpellError> {
+        tokio::select! {
+            biased;
+            () = token.cancelled() => Err(LLMSpellError::Cancelled {
+                message: "script execution cancelled".to_string(),
+            }),
+            result = self.execute_script(script) => result,
+        }
+    }
+
     /// Execute a script with streaming output support
     async fn execute_script_streaming(&self, script: &str) -> Result<ScriptStream, LLMSpellError>;
 
@@ -2,7 +2,9 @@
 // ABOUTME: Bridges the core EventEmitter trait with the actual EventBus implementation
 
 use async_trait::async_trait;
-use llmspell_core::traits::event::{EventConfig, EventData, EventEmitter};
+use llmspell_core::traits::event::{
+    EventConfig, EventData, EventEmitter, EventSampler, SamplingDecision,
+};
 use llmspell_core::{LLMSpellError, Result};
 use llmspell_events::{EventBus, Language, UniversalEvent};
 use serde_json::Value;
@@ -17,6 +19,8 @@ pub struct EventBusAdapter {
     config: EventConfig,
     /// Language context for events
     language: Language,
+    /// Per-event-type sampling state
+    sampler: Arc<EventSampler>,
 }
 
 impl EventBusAdapter {
@@ -27,16 +31,18 @@ impl EventBusAdapter {
             event_bus,
             config: EventConfig::default(),
             language: Language::Rust,
+            sampler: Arc::new(EventSampler::new()),
         }
     }
 
     /// Create with custom configuration
     #[must_use]
-    pub const fn with_config(event_bus: Arc<EventBus>, config: EventConfig) -> Self {
+    pub fn with_config(event_bus: Arc<EventBus>, config: EventConfig) -> Self {
         Self {
             event_bus,
             config,
             language: Language::Rust,
+            sampler: Arc::new(EventSampler::new()),
         }
     }
 
@@ -47,6 +53,13 @@ impl EventBusAdapter {
         self
     }
 
+    /// Share sampling state with other adapters using the same config
+    #[must_use]
+    pub fn with_sampler(mut self, sampler: Arc<EventSampler>) -> Self {
+        self.sampler = sampler;
+        self
+    }
+
     /// Convert `EventData` to `UniversalEvent`
     fn to_universal_event(&self, event: EventData) -> UniversalEvent {
         let mut universal = UniversalEvent::new(&event.event_type, event.data, self.language);
@@ -117,7 +130,21 @@ impl EventEmitter for EventBusAdapter {
             return Ok(());
         }
 
-        let event = UniversalEvent::new(event_type, data, self.language);
+        let decision = self.sampler.sample(&self.config, event_type);
+        if !decision.should_emit() {
+            return Ok(());
+        }
+
+        let mut event = UniversalEvent::new(event_type, data, self.language);
+        if let SamplingDecision::Sampled(sampling) = decision {
+            for (key, value) in sampling.metadata() {
+                let value_str = match value {
+                    Value::String(s) => s,
+                    other => other.to_string(),
+                };
+                event.metadata.tags.push(format!("{key}:{value_str}"));
+            }
+        }
         self.event_bus
             .publish(event)
             .await
@@ -135,6 +162,12 @@ impl EventEmitter for EventBusAdapter {
             return Ok(());
         }
 
+        let event = match self.sampler.sample(&self.config, &event.event_type) {
+            SamplingDecision::Emit => event,
+            SamplingDecision::Sampled(sampling) => event.with_sampling(sampling),
+            SamplingDecision::Drop => return Ok(()),
+        };
+
         let universal_event = self.to_universal_event(event);
         self.event_bus
             .publish(universal_event)
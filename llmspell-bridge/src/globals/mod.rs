@@ -326,15 +326,13 @@ async fn register_template_global(
             state_manager,
             session_manager,
         };
-        Arc::new(
-            crate::template_bridge::TemplateBridge::with_state_and_session(
-                template_registry,
-                context.registry.clone(),
-                core_providers,
-                provider_config,
-                infra,
-                managers,
-            ),
+        crate::template_bridge::TemplateBridge::with_state_and_session(
+            template_registry,
+            context.registry.clone(),
+            core_providers,
+            provider_config,
+            infra,
+            managers,
         )
     } else if let Some(state_manager) =
         context.get_bridge::<llmspell_kernel::state::StateManager>("state_manager")
@@ -346,24 +344,36 @@ async fn register_template_global(
             workflow_factory: infra.workflow_factory.clone(),
             rag: infra.rag.clone(),
         };
-        Arc::new(crate::template_bridge::TemplateBridge::with_state_manager(
+        crate::template_bridge::TemplateBridge::with_state_manager(
             template_registry,
             context.registry.clone(),
             core_providers,
             provider_config,
             infra_clone,
             state_manager,
-        ))
+        )
     } else {
-        Arc::new(crate::template_bridge::TemplateBridge::new(
+        crate::template_bridge::TemplateBridge::new(
             template_registry,
             context.registry.clone(),
             core_providers,
             provider_config,
             infra,
-        ))
+        )
     };
 
+    // Templates may only spawn subprocesses when the security config allows it,
+    // and only resume from artifact collections saved in the templates directory
+    let runtime_config = context.get_bridge::<llmspell_config::LLMSpellConfig>("runtime_config");
+    let allow_process_spawn = runtime_config
+        .as_ref()
+        .is_some_and(|config| config.runtime.security.allow_process_spawn);
+    let mut template_bridge = template_bridge.with_process_spawn(allow_process_spawn);
+    if let Some(config) = &runtime_config {
+        template_bridge = template_bridge.with_resume_dir(config.templates_dir());
+    }
+    let template_bridge = Arc::new(template_bridge);
+
     // Add template_bridge to context so workflow registration can access it
     context.set_bridge("template_bridge", template_bridge.clone());
 
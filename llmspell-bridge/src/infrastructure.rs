@@ -52,7 +52,7 @@ use llmspell_config::LLMSpellConfig;
 use llmspell_core::error::LLMSpellError;
 use llmspell_storage::backends::sqlite::{SqliteBackend, SqliteConfig, SqliteVectorStorage};
 use std::sync::Arc;
-use tracing::{debug, info};
+use tracing::{debug, info, warn};
 
 /// Central infrastructure container with all `ScriptRuntime` dependencies
 ///
@@ -89,6 +89,9 @@ pub struct Infrastructure {
 
     /// Component registry (lightweight script access layer)
     pub component_registry: Arc<ComponentRegistry>,
+
+    /// OTLP event exporter (optional, running if `config.events.export.otlp_endpoint` is set)
+    pub otlp_export: Option<llmspell_events::OtlpExportHandle>,
 }
 
 impl Infrastructure {
@@ -148,7 +151,7 @@ impl Infrastructure {
             Arc::new(llmspell_workflows::factory::DefaultWorkflowFactory::new());
 
         // 9. Create component registry (with EventBus if enabled)
-        let component_registry = create_component_registry(config)?;
+        let (component_registry, otlp_export) = create_component_registry(config)?;
 
         info!("Infrastructure created successfully");
 
@@ -163,6 +166,7 @@ impl Infrastructure {
             agent_registry,
             workflow_factory,
             component_registry,
+            otlp_export,
         })
     }
 }
@@ -363,20 +367,31 @@ async fn create_memory_manager(
     Ok(Arc::new(manager))
 }
 
-/// Create component registry with optional `EventBus`
+/// Create component registry with optional `EventBus` and OTLP exporter
 ///
 /// # Errors
 ///
 /// Returns an error if component registry initialization fails
 fn create_component_registry(
     config: &LLMSpellConfig,
-) -> Result<Arc<ComponentRegistry>, LLMSpellError> {
+) -> Result<
+    (
+        Arc<ComponentRegistry>,
+        Option<llmspell_events::OtlpExportHandle>,
+    ),
+    LLMSpellError,
+> {
     debug!("Creating component registry");
 
+    let mut otlp_export = None;
     let registry = if config.events.enabled {
         // Create EventBus
         let event_bus = Arc::new(llmspell_events::EventBus::new());
 
+        if let Some(endpoint) = &config.events.export.otlp_endpoint {
+            otlp_export = spawn_otlp_exporter(&event_bus, endpoint, config);
+        }
+
         // Convert config to EventConfig
         let event_config = llmspell_core::traits::event::EventConfig {
             enabled: config.events.enabled,
@@ -386,6 +401,7 @@ fn create_component_registry(
             emit_state_events: config.events.emit_state_events,
             emit_debug_events: config.events.emit_debug_events,
             max_events_per_second: config.events.max_events_per_second,
+            sampling: config.events.sampling_policies(),
         };
 
         ComponentRegistry::with_event_bus_and_templates(event_bus, event_config).map_err(|e| {
@@ -401,5 +417,35 @@ fn create_component_registry(
         })?
     };
 
-    Ok(Arc::new(registry))
+    Ok((Arc::new(registry), otlp_export))
+}
+
+/// Export filtered events to the configured OTLP collector
+///
+/// The exporter runs in the background until the returned handle is shut down,
+/// which flushes any pending events.
+fn spawn_otlp_exporter(
+    event_bus: &llmspell_events::EventBus,
+    endpoint: &str,
+    config: &LLMSpellConfig,
+) -> Option<llmspell_events::OtlpExportHandle> {
+    if tokio::runtime::Handle::try_current().is_err() {
+        warn!("No async runtime available, OTLP event export to {endpoint} disabled");
+        return None;
+    }
+
+    let filtering = &config.events.filtering;
+    let export_config = llmspell_events::OtlpExportConfig::new(endpoint)
+        .with_batch_size(config.events.buffer_size)
+        .with_type_filter(
+            filtering.include_types.clone(),
+            filtering.exclude_types.clone(),
+        )
+        .with_component_filter(
+            filtering.include_components.clone(),
+            filtering.exclude_components.clone(),
+        );
+    let handle = llmspell_events::OtlpExporter::new(export_config).spawn(event_bus);
+    info!("Exporting events to OTLP collector at {endpoint}");
+    Some(handle)
 }
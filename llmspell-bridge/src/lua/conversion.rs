@@ -1,6 +1,7 @@
 //! ABOUTME: Consolidated Lua-specific type conversions
 //! ABOUTME: All Lua <-> Rust type conversions in one place
 
+use crate::agent_bridge::StreamChunk;
 use crate::conversion::{FromScriptValue, ScriptValue};
 use llmspell_core::types::{
     AgentInput, AgentOutput, ColorSpace, ImageFormat, ImageMetadata, MediaContent, MediaType,
@@ -335,6 +336,32 @@ pub fn agent_output_to_lua_table<'a>(
     Ok(table)
 }
 
+/// Convert a streamed `StreamChunk` to a Lua table
+///
+/// The table has the `AgentOutput` fields (`text`, `metadata`, ...) plus `content`,
+/// `index`, and, when present, `finish_reason` and `usage = { total_tokens = ... }`.
+///
+/// # Errors
+///
+/// Returns an error if table creation or setting values fails
+pub fn stream_chunk_to_lua_table<'a>(lua: &'a Lua, chunk: &StreamChunk) -> mlua::Result<Table<'a>> {
+    let table = match &chunk.output {
+        Some(output) => agent_output_to_lua_table(lua, output)?,
+        None => agent_output_to_lua_table(lua, &AgentOutput::text(chunk.content.clone()))?,
+    };
+    table.set("content", chunk.content.as_str())?;
+    table.set("index", chunk.index)?;
+    if let Some(reason) = &chunk.finish_reason {
+        table.set("finish_reason", reason.as_str())?;
+    }
+    if let Some(usage) = chunk.usage {
+        let usage_table = lua.create_table()?;
+        usage_table.set("total_tokens", usage.total_tokens)?;
+        table.set("usage", usage_table)?;
+    }
+    Ok(table)
+}
+
 /// Process output text, handling JSON structured responses
 fn process_output_text(lua: &Lua, table: &Table, text: &str) -> mlua::Result<()> {
     if let Ok(json_value) = serde_json::from_str::<serde_json::Value>(text) {
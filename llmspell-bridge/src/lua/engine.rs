@@ -18,8 +18,21 @@ use llmspell_core::traits::debug_context::DebugContext;
 use serde_json::Value;
 use std::sync::Arc;
 use std::time::{Duration, Instant};
+use tokio_util::sync::CancellationToken;
 use tracing::{debug, info, instrument, trace, warn};
 
+/// Number of Lua VM instructions between cancellation checks
+#[cfg(feature = "lua")]
+const CANCEL_CHECK_INSTRUCTIONS: u32 = 1000;
+
+/// Registry key under which `debug.getlocal` is kept for the debugger
+#[cfg(feature = "lua")]
+const GETLOCAL_REGISTRY_KEY: &str = "llmspell.debug.getlocal";
+
+/// Maximum number of locals captured per frame
+#[cfg(feature = "lua")]
+const MAX_CAPTURED_LOCALS: i64 = 100;
+
 #[cfg(feature = "lua")]
 use {
     crate::globals::{create_standard_registry, GlobalContext, GlobalInjector},
@@ -184,14 +197,163 @@ impl LuaEngine {
     }
 }
 
-/// Install debug hooks for the current script execution
+/// Keep `debug.getlocal` in the registry for the debugger, loading it on first use
+///
+/// The debug library table is created directly and never registered in globals
+/// or `package.loaded`, so scripts cannot reach it.
+#[cfg(feature = "lua")]
+fn load_getlocal(lua: &mlua::Lua) -> mlua::Result<()> {
+    use mlua::{Function, Table};
+
+    if lua
+        .named_registry_value::<Option<Function>>(GETLOCAL_REGISTRY_KEY)?
+        .is_some()
+    {
+        return Ok(());
+    }
+
+    // SAFETY: `luaopen_debug` only builds and returns the library table; the
+    // table is dropped after `getlocal` is taken from it
+    #[allow(unsafe_code)]
+    let open_debug = unsafe { lua.create_c_function(mlua::ffi::luaopen_debug)? };
+    let debug_lib: Table = open_debug.call(())?;
+    let getlocal: Function = debug_lib.get("getlocal")?;
+    lua.set_named_registry_value(GETLOCAL_REGISTRY_KEY, getlocal)
+}
+
+/// Capture the locals of the function that triggered the current hook
+#[cfg(feature = "lua")]
+fn capture_hook_locals(lua: &mlua::Lua) -> Vec<llmspell_core::traits::debug_context::Variable> {
+    lua.named_registry_value::<mlua::Function>(GETLOCAL_REGISTRY_KEY)
+        .map(|getlocal| capture_frame_locals(&getlocal, 0))
+        .unwrap_or_default()
+}
+
+/// Capture the locals of the frame at `level` of the hooked call stack
+///
+/// Hooks run without a frame of their own, so level 0 is the hooked function
+/// and level 1 of `debug.getlocal` called from the hook refers to it. Later
+/// locals shadow earlier ones with the same name, as in Lua scoping.
+#[cfg(feature = "lua")]
+fn capture_frame_locals(
+    getlocal: &mlua::Function,
+    level: usize,
+) -> Vec<llmspell_core::traits::debug_context::Variable> {
+    use crate::lua::stacktrace::value_to_debug_string;
+    use llmspell_core::traits::debug_context::Variable;
+
+    let level = level + 1;
+    let mut locals: Vec<Variable> = Vec::new();
+    for index in 1..=MAX_CAPTURED_LOCALS {
+        let Ok((Some(name), value)) =
+            getlocal.call::<_, (Option<String>, mlua::Value)>((level, index))
+        else {
+            break;
+        };
+        // Skip internal slots such as "(for state)"
+        if name.starts_with('(') {
+            continue;
+        }
+        locals.retain(|local| local.name != name);
+        locals.push(Variable {
+            name,
+            value: value_to_debug_string(&value),
+            var_type: value.type_name().to_string(),
+            has_children: matches!(value, mlua::Value::Table(_)),
+        });
+    }
+    locals
+}
+
+/// Capture the hooked call stack, innermost frame first, with each frame's locals
+///
+/// C functions have no current line and are skipped.
+#[cfg(feature = "lua")]
+fn capture_call_stack(
+    lua: &mlua::Lua,
+) -> Vec<(
+    llmspell_core::traits::debug_context::StackFrame,
+    Vec<llmspell_core::traits::debug_context::Variable>,
+)> {
+    use llmspell_core::traits::debug_context::StackFrame;
+
+    let getlocal = lua
+        .named_registry_value::<mlua::Function>(GETLOCAL_REGISTRY_KEY)
+        .ok();
+    let mut frames = Vec::new();
+    let mut level = 0;
+    while let Some(frame) = lua.inspect_stack(level) {
+        let line = u32::try_from(frame.curr_line()).unwrap_or(0);
+        let name = frame
+            .names()
+            .name
+            .map_or_else(|| "main chunk".to_string(), |name| name.to_string());
+        let file = frame
+            .source()
+            .source
+            .map_or_else(|| "unknown".to_string(), |source| source.to_string());
+        if line > 0 {
+            let locals = getlocal
+                .as_ref()
+                .map(|getlocal| capture_frame_locals(getlocal, level))
+                .unwrap_or_default();
+            let stack_frame = StackFrame {
+                id: frames.len(),
+                name,
+                file,
+                line,
+                column: None,
+            };
+            frames.push((stack_frame, locals));
+        }
+        level += 1;
+    }
+    frames
+}
+
+/// Install debug and cancellation hooks for the current script execution
+///
+/// Lua allows a single hook, so line-level debugging and periodic cancellation
+/// checks share one callback.
 #[cfg(feature = "lua")]
-fn install_debug_hooks_internal(lua: &mlua::Lua, debug_context: &Arc<dyn DebugContext>) {
+fn install_execution_hooks(
+    lua: &mlua::Lua,
+    debug_context: Option<Arc<dyn DebugContext>>,
+    cancel: Option<CancellationToken>,
+) {
     use mlua::DebugEvent;
 
-    // Set up a debug hook that gets called on each line
-    let debug_ctx = Arc::clone(debug_context);
-    lua.set_hook(mlua::HookTriggers::EVERY_LINE, move |_lua, debug| {
+    let mut triggers = if debug_context.is_some() {
+        mlua::HookTriggers::EVERY_LINE
+    } else {
+        mlua::HookTriggers::new()
+    };
+    if cancel.is_some() {
+        // Call events fire before `pcall` protects its callee, so a cancelled
+        // script cannot keep running by catching the error in a loop
+        triggers = triggers
+            .every_nth_instruction(CANCEL_CHECK_INSTRUCTIONS)
+            .on_calls();
+    }
+
+    if debug_context.is_some() {
+        if let Err(e) = load_getlocal(lua) {
+            warn!(
+                "Failed to load debug.getlocal, frame locals unavailable: {}",
+                e
+            );
+        }
+    }
+
+    lua.set_hook(triggers, move |lua, debug| {
+        if cancel.as_ref().is_some_and(CancellationToken::is_cancelled) {
+            return Err(mlua::Error::RuntimeError(
+                "script execution cancelled".to_string(),
+            ));
+        }
+        let Some(debug_ctx) = debug_context.as_ref() else {
+            return Ok(());
+        };
         if debug.event() == DebugEvent::Line {
             // Get current location info
             let source = debug.source();
@@ -203,8 +365,15 @@ fn install_debug_hooks_internal(lua: &mlua::Lua, debug_context: &Arc<dyn DebugCo
             // Report current location to debug context
             debug_ctx.report_location(&file, line);
 
+            // Conditional breakpoints are evaluated against the frame's locals
+            if debug_ctx.wants_locals(&file, line) {
+                debug_ctx.set_frame_locals(&file, line, capture_hook_locals(lua));
+            }
+
             // Check if we should pause at this line (breakpoint or stepping)
             if debug_ctx.should_pause_sync(&file, line) {
+                // Every frame is inspectable while paused
+                debug_ctx.set_call_stack(capture_call_stack(lua));
                 // Handle async pause in sync context
                 // We need to use a different approach since block_on doesn't work in async tests
                 // Use futures::executor::block_on which works in any context
@@ -218,12 +387,16 @@ fn install_debug_hooks_internal(lua: &mlua::Lua, debug_context: &Arc<dyn DebugCo
         Ok(())
     });
 
-    debug!("Lua debug hooks installed for current execution");
+    debug!("Lua execution hooks installed for current execution");
 }
 
-#[async_trait]
-impl ScriptEngineBridge for LuaEngine {
-    async fn execute_script(&self, script: &str) -> Result<ScriptOutput, LLMSpellError> {
+impl LuaEngine {
+    /// Execute a script, checking `cancel` periodically when provided
+    fn execute_script_internal(
+        &self,
+        script: &str,
+        cancel: Option<&CancellationToken>,
+    ) -> Result<ScriptOutput, LLMSpellError> {
         #[cfg(feature = "lua")]
         {
             let start_time = Instant::now();
@@ -236,22 +409,19 @@ impl ScriptEngineBridge for LuaEngine {
             }
 
             // Check if we should install debug hooks for this execution
-            let should_install_hooks = {
-                let debug_context = self.debug_context.read();
-                debug_context
-                    .as_ref()
-                    .is_some_and(|ctx| ctx.is_debug_enabled())
-            };
+            let debug_ctx = self
+                .debug_context
+                .read()
+                .clone()
+                .filter(|ctx| ctx.is_debug_enabled());
+            let should_install_hooks = debug_ctx.is_some() || cancel.is_some();
 
             let result = {
                 let lua = self.lua.lock();
 
-                // Install debug hooks if debugging is enabled
+                // Install hooks if debugging is enabled or execution is cancellable
                 if should_install_hooks {
-                    let debug_ctx = self.debug_context.read().clone();
-                    if let Some(ctx) = debug_ctx {
-                        install_debug_hooks_internal(&lua, &ctx);
-                    }
+                    install_execution_hooks(&lua, debug_ctx, cancel.cloned());
                 }
 
                 // Inject ARGS global if script arguments were provided
@@ -275,6 +445,13 @@ impl ScriptEngineBridge for LuaEngine {
                 // This is especially important when running many scripts in sequence
                 let _ = lua.gc_collect();
 
+                // A script may catch the cancellation error and finish normally
+                if cancel.is_some_and(CancellationToken::is_cancelled) {
+                    return Err(LLMSpellError::Cancelled {
+                        message: "script execution cancelled".to_string(),
+                    });
+                }
+
                 match lua_result {
                     Ok(value) => {
                         // Convert Lua value to JSON
@@ -314,12 +491,33 @@ impl ScriptEngineBridge for LuaEngine {
 
         #[cfg(not(feature = "lua"))]
         {
+            let _ = (script, cancel);
             Err(LLMSpellError::Component {
                 message: "Lua feature not enabled".to_string(),
                 source: None,
             })
         }
     }
+}
+
+#[async_trait]
+impl ScriptEngineBridge for LuaEngine {
+    async fn execute_script(&self, script: &str) -> Result<ScriptOutput, LLMSpellError> {
+        self.execute_script_internal(script, None)
+    }
+
+    async fn execute_script_cancellable(
+        &self,
+        script: &str,
+        token: CancellationToken,
+    ) -> Result<ScriptOutput, LLMSpellError> {
+        if token.is_cancelled() {
+            return Err(LLMSpellError::Cancelled {
+                message: "script execution cancelled".to_string(),
+            });
+        }
+        self.execute_script_internal(script, Some(&token))
+    }
 
     async fn execute_script_streaming(&self, script: &str) -> Result<ScriptStream, LLMSpellError> {
         #[cfg(feature = "lua")]
@@ -955,6 +1153,23 @@ mod tests {
         assert_eq!(result.output, serde_json::json!(100));
     }
 
+    #[tokio::test]
+    async fn test_debug_library_hidden_from_scripts() {
+        let config = LuaConfig::default();
+        let engine = LuaEngine::new(&config).unwrap();
+        let script = "return debug == nil and package.loaded.debug == nil";
+
+        let result = engine.execute_script(script).await.unwrap();
+        assert_eq!(result.output, serde_json::json!(true));
+
+        // Attaching a debugger loads `debug.getlocal` without exposing the library
+        let debug_ctx = Arc::new(TestDebugContext::new());
+        debug_ctx.enable_debug_mode();
+        engine.set_debug_context(Some(debug_ctx));
+        let result = engine.execute_script(script).await.unwrap();
+        assert_eq!(result.output, serde_json::json!(true));
+    }
+
     #[tokio::test]
     async fn test_no_debug_overhead_when_disabled() {
         let config = LuaConfig::default();
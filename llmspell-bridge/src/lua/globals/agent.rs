@@ -7,6 +7,7 @@ use crate::agent_bridge::AgentBridge;
 use crate::globals::GlobalContext;
 use crate::lua::conversion::{
     agent_output_to_lua_table, json_to_lua_value, lua_table_to_agent_input, lua_value_to_json,
+    stream_chunk_to_lua_table,
 };
 use crate::lua::sync_utils::block_on_async;
 use llmspell_agents::{AgentConfig, ModelConfig, ResourceLimits};
@@ -567,16 +568,19 @@ impl UserData for LuaAgentInstance {
 
                         // Process stream
                         let mut chunk_count = 0;
-                        while let Some(output) = rx.recv().await {
-                            let output_table = agent_output_to_lua_table(lua, &output)?;
-                            callback.call::<_, ()>(output_table)?;
+                        let mut finish_reason = None;
+                        while let Some(chunk) = rx.recv().await {
+                            let chunk_table = stream_chunk_to_lua_table(lua, &chunk)?;
+                            callback.call::<_, ()>(chunk_table)?;
                             chunk_count += 1;
+                            finish_reason = chunk.finish_reason;
                         }
 
                         // Return a table with streaming results
                         let result_table = lua.create_table()?;
-                        result_table.set("success", true)?;
+                        result_table.set("success", finish_reason.as_deref() == Some("stop"))?;
                         result_table.set("chunks_received", chunk_count)?;
+                        result_table.set("finish_reason", finish_reason)?;
                         Ok(result_table)
                     })
                 })
@@ -17,14 +17,21 @@ pub fn inject_config_global(lua: &Lua, bridge: &Arc<ConfigBridge>) -> LuaResult<
     let bridge = Arc::clone(bridge);
     let config_table = lua.create_table()?;
 
-    // Config.get() - Get full configuration as table
+    // Config.get([path]) - Get full configuration, or one value by dot path (read-only)
     let bridge_clone = bridge.clone();
     config_table.set(
         "get",
-        lua.create_function(move |lua, ()| {
-            let json = bridge_clone.get().map_err(mlua::Error::external)?;
+        lua.create_function(move |lua, path: Option<String>| {
+            let Some(path) = path else {
+                let json = bridge_clone.get().map_err(mlua::Error::external)?;
+                return lua.to_value(&json);
+            };
+
+            let value = bridge_clone
+                .get_path(&path)
+                .map_err(mlua::Error::external)?;
 
-            lua.to_value(&json)
+            value.map_or_else(|| Ok(Value::Nil), |json| lua.to_value(&json))
         })?,
     )?;
 
@@ -288,6 +295,9 @@ pub const fn config_lua_examples() -> &'static str {
 local config = Config.get()
 print("Default engine:", config.default_engine)
 
+-- Read a single value by path (nil if unknown or secret)
+local dims = Config.get("rag.vector_storage.dimensions")
+
 -- Get specific provider
 local openai = Config.getProvider("openai")
 if openai then
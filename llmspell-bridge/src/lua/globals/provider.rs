@@ -2,6 +2,7 @@
 //! ABOUTME: Provides Lua bindings for LLM provider information and capabilities
 
 use crate::globals::GlobalContext;
+use crate::lua::sync_utils::block_on_async;
 use crate::ProviderManager;
 use llmspell_core::error::LLMSpellError;
 use mlua::{Lua, Result as LuaResult, Table, Value};
@@ -182,6 +183,48 @@ pub fn inject_provider_global(
             source: None,
         })?;
 
+    // Provider.models(name) - List models offered by a provider
+    let providers_models = providers.clone();
+    let models_fn = lua
+        .create_function(move |lua, name: String| -> LuaResult<Table> {
+            let models =
+                block_on_async("provider_models", providers_models.list_models(&name), None)?;
+
+            let result = lua.create_table()?;
+            for (idx, model) in models.into_iter().enumerate() {
+                let model_table = lua.create_table()?;
+                model_table.set("id", model.id)?;
+                model_table.set("backend", model.backend)?;
+                #[allow(clippy::cast_precision_loss)] // Acceptable for model file sizes
+                model_table.set("size_bytes", model.size_bytes.map(|b| b as f64))?;
+                model_table.set("parameter_count", model.parameter_count)?;
+                model_table.set("quantization", model.quantization)?;
+
+                if let Some(caps) = model.capabilities {
+                    let caps_table = lua.create_table()?;
+                    caps_table.set("supports_streaming", caps.supports_streaming)?;
+                    caps_table.set("supports_multimodal", caps.supports_multimodal)?;
+                    caps_table.set("max_context_tokens", caps.max_context_tokens)?;
+                    model_table.set("capabilities", caps_table)?;
+                }
+
+                result.set(idx + 1, model_table)?;
+            }
+
+            Ok(result)
+        })
+        .map_err(|e| LLMSpellError::Component {
+            message: format!("Failed to create Provider.models function: {e}"),
+            source: None,
+        })?;
+
+    provider_table
+        .set("models", models_fn)
+        .map_err(|e| LLMSpellError::Component {
+            message: format!("Failed to set Provider.models: {e}"),
+            source: None,
+        })?;
+
     // Set the Provider table as a global
     lua.globals()
         .set("Provider", provider_table)
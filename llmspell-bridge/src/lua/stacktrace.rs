@@ -273,7 +273,7 @@ fn capture_upvalues(lua: &Lua, level: i32) -> LuaResult<HashMap<String, String>>
     Ok(upvalues)
 }
 
-fn value_to_debug_string(value: &Value) -> String {
+pub(crate) fn value_to_debug_string(value: &Value) -> String {
     match value {
         Value::Nil => "nil".to_string(),
         Value::Boolean(b) => b.to_string(),
@@ -8,6 +8,7 @@ use llmspell_providers::{
     ProviderInstance, ProviderManager as CoreProviderManager,
 };
 use std::sync::Arc;
+use tracing::warn;
 
 /// Manages LLM providers for script access
 pub struct ProviderManager {
@@ -308,6 +309,105 @@ impl ProviderManager {
         })
     }
 
+    /// List the models offered by a provider
+    ///
+    /// `name` may be a configured provider name or a provider instance name. Local
+    /// providers are queried for their installed models; remote providers report the
+    /// configured `models` option or their advertised models. Providers that cannot
+    /// enumerate models report their configured default model.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the provider is neither configured nor initialized
+    pub async fn list_models(&self, name: &str) -> Result<Vec<ModelDescriptor>, LLMSpellError> {
+        let config = self.config.providers.get(name);
+        let instance = self.resolve_instance(name, config).await;
+        if instance.is_none() && config.is_none() {
+            return Err(LLMSpellError::Validation {
+                field: Some("provider".to_string()),
+                message: format!("Provider '{name}' not found"),
+            });
+        }
+        let capabilities = instance.as_ref().map(|p| p.capabilities().clone());
+
+        if let Some(local) = instance.as_ref().and_then(|p| p.as_local()) {
+            match local.list_local_models().await {
+                Ok(models) => {
+                    let mut descriptors = Vec::with_capacity(models.len());
+                    for model in models {
+                        let info = local.model_info(&model.id).await.ok();
+                        descriptors.push(ModelDescriptor {
+                            id: model.id,
+                            backend: Some(model.backend),
+                            size_bytes: Some(model.size_bytes),
+                            parameter_count: info.and_then(|i| i.parameter_count),
+                            quantization: model.quantization,
+                            capabilities: capabilities.clone(),
+                        });
+                    }
+                    return Ok(descriptors);
+                }
+                Err(e) => warn!("Failed to list models for provider '{name}': {e}"),
+            }
+        }
+
+        let mut ids: Vec<String> = config
+            .and_then(|c| c.options.get("models"))
+            .and_then(serde_json::Value::as_array)
+            .map(|models| {
+                models
+                    .iter()
+                    .filter_map(|m| m.as_str().map(str::to_string))
+                    .collect()
+            })
+            .unwrap_or_default();
+        if ids.is_empty() {
+            if let Some(caps) = &capabilities {
+                ids.clone_from(&caps.available_models);
+            }
+        }
+        if ids.is_empty() {
+            ids.extend(
+                config
+                    .and_then(|c| c.default_model.clone())
+                    .or_else(|| instance.as_ref().map(|p| p.model().to_string())),
+            );
+        }
+
+        Ok(ids
+            .into_iter()
+            .map(|id| ModelDescriptor {
+                id,
+                capabilities: capabilities.clone(),
+                ..ModelDescriptor::default()
+            })
+            .collect())
+    }
+
+    /// Find the runtime instance for a configured or instance provider name
+    async fn resolve_instance(
+        &self,
+        name: &str,
+        config: Option<&ProviderConfig>,
+    ) -> Option<Arc<Box<dyn ProviderInstance>>> {
+        if let Ok(provider) = self.core_manager.get_provider(Some(name)).await {
+            return Some(provider);
+        }
+        if let Some(instance_name) = config
+            .and_then(|c| Self::create_provider_config(name, c).ok())
+            .map(|c| c.instance_name())
+        {
+            if let Ok(provider) = self.core_manager.get_provider(Some(&instance_name)).await {
+                return Some(provider);
+            }
+        }
+        self.core_manager
+            .get_provider_for_backend(name)
+            .await
+            .ok()
+            .flatten()
+    }
+
     /// Check if a provider supports a specific capability
     pub async fn check_provider_capability(&self, provider_name: &str, capability: &str) -> bool {
         self.core_manager
@@ -400,6 +500,23 @@ pub struct ProviderInfo {
     pub capabilities: Option<ProviderCapabilities>,
 }
 
+/// A model offered by a provider
+#[derive(Debug, Clone, Default)]
+pub struct ModelDescriptor {
+    /// Model identifier
+    pub id: String,
+    /// Backend serving the model, for local providers
+    pub backend: Option<String>,
+    /// Model size in bytes, for local providers
+    pub size_bytes: Option<u64>,
+    /// Parameter count (e.g. "7B"), if known
+    pub parameter_count: Option<String>,
+    /// Quantization format, if known
+    pub quantization: Option<String>,
+    /// Capabilities of the provider serving the model
+    pub capabilities: Option<ProviderCapabilities>,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -3,7 +3,7 @@
 
 use crate::event_bus_adapter::EventBusAdapter;
 use async_trait::async_trait;
-use llmspell_core::traits::event::EventConfig;
+use llmspell_core::traits::event::{EventConfig, EventSampler};
 use llmspell_core::{
     Agent, BaseAgent, ComponentLookup, ExecutionContext, LLMSpellError, Tool, Workflow,
 };
@@ -20,6 +20,8 @@ pub struct ComponentRegistry {
     template_registry: Option<Arc<TemplateRegistry>>,
     event_bus: Option<Arc<EventBus>>,
     event_config: EventConfig,
+    /// Sampling state shared by every context's event adapter
+    event_sampler: Arc<EventSampler>,
 }
 
 impl ComponentRegistry {
@@ -33,6 +35,7 @@ impl ComponentRegistry {
             template_registry: None,
             event_bus: None,
             event_config: EventConfig::default(),
+            event_sampler: Arc::new(EventSampler::new()),
         }
     }
 
@@ -46,6 +49,7 @@ impl ComponentRegistry {
             template_registry: None,
             event_bus: Some(event_bus),
             event_config: config,
+            event_sampler: Arc::new(EventSampler::new()),
         }
     }
 
@@ -68,6 +72,7 @@ impl ComponentRegistry {
             template_registry: Some(Arc::new(template_registry)),
             event_bus: None,
             event_config: EventConfig::default(),
+            event_sampler: Arc::new(EventSampler::new()),
         })
     }
 
@@ -93,6 +98,7 @@ impl ComponentRegistry {
             template_registry: Some(Arc::new(template_registry)),
             event_bus: Some(event_bus),
             event_config: config,
+            event_sampler: Arc::new(EventSampler::new()),
         })
     }
 
@@ -117,7 +123,8 @@ impl ComponentRegistry {
         if let Some(ref event_bus) = self.event_bus {
             if self.event_config.enabled {
                 let adapter =
-                    EventBusAdapter::with_config(event_bus.clone(), self.event_config.clone());
+                    EventBusAdapter::with_config(event_bus.clone(), self.event_config.clone())
+                        .with_sampler(self.event_sampler.clone());
                 ctx.events = Some(Arc::new(adapter));
             }
         }
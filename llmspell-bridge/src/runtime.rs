@@ -10,7 +10,7 @@ use crate::{
 #[cfg(any(feature = "lua", feature = "javascript"))]
 use crate::engine::EngineFactory;
 #[cfg(any(feature = "lua", feature = "javascript"))]
-use crate::tools::register_all_tools;
+use crate::tools::register_all_tools_with_security;
 
 #[cfg(feature = "lua")]
 use crate::engine::LuaConfig;
@@ -28,8 +28,9 @@ use llmspell_core::traits::script_executor::{
 use std::collections::HashMap;
 use std::fmt::Write;
 use std::sync::{Arc, RwLock};
-use std::time::Instant;
-use tracing::{debug, info, instrument};
+use std::time::{Duration, Instant};
+use tokio_util::sync::CancellationToken;
+use tracing::{debug, info, instrument, warn};
 
 /// Central script runtime that uses `ScriptEngineBridge` abstraction
 ///
@@ -283,12 +284,15 @@ pub struct ScriptRuntime {
     /// Optional to support configurations without memory.
     memory_manager: Option<Arc<dyn llmspell_memory::MemoryManager>>,
 
+    /// OTLP event exporter, flushed and stopped by `shutdown()`
+    otlp_export: tokio::sync::Mutex<Option<llmspell_events::OtlpExportHandle>>,
+
     /// Execution context
     execution_context: Arc<RwLock<crate::engine::ExecutionContext>>,
     /// Debug context for debugging support (uses interior mutability)
     debug_context: Arc<RwLock<Option<Arc<dyn DebugContext>>>>,
     /// Runtime configuration
-    _config: LLMSpellConfig,
+    config: LLMSpellConfig,
 }
 
 impl ScriptRuntime {
@@ -513,6 +517,7 @@ impl ScriptRuntime {
             agent_registry,
             workflow_factory,
             component_registry,
+            otlp_export,
         } = infrastructure;
 
         // Convert memory_manager to trait object (Phase 13b.16.2)
@@ -520,12 +525,17 @@ impl ScriptRuntime {
             memory_manager.map(|m| m as Arc<dyn llmspell_memory::MemoryManager>);
 
         // Register all Phase 2 tools with BOTH registries using dual-registration
-        register_all_tools(&component_registry, &tool_registry, &config.tools)
-            .await
-            .map_err(|e| LLMSpellError::Component {
-                message: format!("Failed to register tools: {e}"),
-                source: None,
-            })?;
+        register_all_tools_with_security(
+            &component_registry,
+            &tool_registry,
+            &config.tools,
+            &config.runtime.security,
+        )
+        .await
+        .map_err(|e| LLMSpellError::Component {
+            message: format!("Failed to register tools: {e}"),
+            source: None,
+        })?;
 
         // Register default agent factory with `AgentRegistry`
         debug!("Registering default agent factory");
@@ -612,17 +622,26 @@ impl ScriptRuntime {
             session_manager,
             rag,
             memory_manager,
+            otlp_export: tokio::sync::Mutex::new(otlp_export),
             execution_context,
             debug_context: Arc::new(RwLock::new(None)),
-            _config: config,
+            config,
         })
     }
 
     /// Execute a script and return the output
     ///
+    /// When the security context sets `max_execution_time_ms`, the script is cancelled
+    /// once that limit elapses.
+    ///
     /// # Errors
     ///
-    /// Returns an error if script execution fails
+    /// Returns an error if script execution fails, or `LLMSpellError::Timeout` if it
+    /// exceeds the maximum execution time
+    ///
+    /// # Panics
+    ///
+    /// Panics if the execution context lock is poisoned
     #[instrument(level = "info", skip(self, script), fields(
         engine_name = self.engine.get_engine_name(),
         script_size = script.len(),
@@ -630,7 +649,68 @@ impl ScriptRuntime {
     ))]
     pub async fn execute_script(&self, script: &str) -> Result<ScriptOutput, LLMSpellError> {
         info!("Executing script with {} bytes", script.len());
-        self.engine.execute_script(script).await
+        self.execute_with_time_limit(script).await
+    }
+
+    /// Execute a script on the engine, cancelling it after `max_execution_time_ms`
+    ///
+    /// Shared by the inherent `execute_script` and the `ScriptExecutor` impl used by
+    /// the kernel and REPL, so every entry point enforces the limit.
+    async fn execute_with_time_limit(&self, script: &str) -> Result<ScriptOutput, LLMSpellError> {
+        let max_execution_time_ms = self
+            .execution_context
+            .read()
+            .unwrap()
+            .security
+            .max_execution_time_ms;
+        let Some(limit_ms) = max_execution_time_ms else {
+            return self.engine.execute_script(script).await;
+        };
+
+        let token = CancellationToken::new();
+        let timer = {
+            let token = token.clone();
+            tokio::spawn(async move {
+                tokio::time::sleep(Duration::from_millis(limit_ms)).await;
+                token.cancel();
+            })
+        };
+        let result = self.engine.execute_script_cancellable(script, token).await;
+        timer.abort();
+
+        match result {
+            Err(LLMSpellError::Cancelled { .. }) => {
+                warn!("Script exceeded max execution time of {limit_ms}ms");
+                Err(LLMSpellError::Timeout {
+                    message: format!("Script exceeded max execution time of {limit_ms}ms"),
+                    duration_ms: Some(limit_ms),
+                })
+            }
+            other => other,
+        }
+    }
+
+    /// Execute a script, aborting it when `token` is cancelled
+    ///
+    /// Lets the host stop a runaway script without killing the process. The Lua engine
+    /// checks the token periodically while the script runs.
+    ///
+    /// # Errors
+    ///
+    /// Returns `LLMSpellError::Cancelled` if the token fires before the script completes,
+    /// or an error if script execution fails
+    #[instrument(level = "info", skip(self, script, token), fields(
+        engine_name = self.engine.get_engine_name(),
+        script_size = script.len(),
+        execution_id = %uuid::Uuid::new_v4()
+    ))]
+    pub async fn execute_script_cancellable(
+        &self,
+        script: &str,
+        token: CancellationToken,
+    ) -> Result<ScriptOutput, LLMSpellError> {
+        info!("Executing cancellable script with {} bytes", script.len());
+        self.engine.execute_script_cancellable(script, token).await
     }
 
     /// Get completion candidates for the given context
@@ -733,6 +813,25 @@ impl ScriptRuntime {
         &self.registry
     }
 
+    /// Register a host-implemented tool after the runtime was created
+    ///
+    /// The tool is added to the script-facing `ComponentRegistry`, so it shows up in
+    /// `Tool.list()` and can be called from scripts immediately.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a tool with the same name is already registered
+    pub fn register_tool(
+        &self,
+        name: impl Into<String>,
+        tool: Arc<dyn llmspell_core::Tool>,
+    ) -> Result<(), LLMSpellError> {
+        let name = name.into();
+        self.registry.register_tool(name.clone(), tool)?;
+        info!("Registered runtime tool '{name}'");
+        Ok(())
+    }
+
     /// Get the provider manager
     #[must_use]
     pub const fn provider_manager(&self) -> &Arc<ProviderManager> {
@@ -831,8 +930,8 @@ impl ScriptExecutor for ScriptRuntime {
     async fn execute_script(&self, script: &str) -> Result<ScriptExecutionOutput, LLMSpellError> {
         let start = Instant::now();
 
-        // Execute using the underlying engine
-        let engine_output = self.engine.execute_script(script).await?;
+        // Execute using the underlying engine, enforcing the max execution time
+        let engine_output = self.execute_with_time_limit(script).await?;
 
         // Convert ScriptOutput to ScriptExecutionOutput
         let output = ScriptExecutionOutput {
@@ -854,6 +953,13 @@ impl ScriptExecutor for ScriptRuntime {
         self.engine.set_output_callback(callback);
     }
 
+    /// Flush pending events to the OTLP collector and stop the exporter
+    async fn shutdown(&self) {
+        if let Some(handle) = self.otlp_export.lock().await.take() {
+            handle.shutdown().await;
+        }
+    }
+
     async fn execute_script_with_args(
         &self,
         script: &str,
@@ -881,8 +987,8 @@ impl ScriptExecutor for ScriptRuntime {
             preamble
         };
 
-        // Execute using the underlying engine
-        let engine_output = self.engine.execute_script(&script_with_args).await?;
+        // Execute using the underlying engine, enforcing the max execution time
+        let engine_output = self.execute_with_time_limit(&script_with_args).await?;
 
         // Convert ScriptOutput to ScriptExecutionOutput
         let output = ScriptExecutionOutput {
@@ -1115,8 +1221,10 @@ impl ScriptExecutor for ScriptRuntime {
         // Get template from registry
         let template = self.get_template_from_registry(template_id)?;
 
-        // Convert and validate params
-        let template_params = Self::convert_and_validate_params(&template, &params)?;
+        // Convert and validate params, resolving what a resumed run continues from
+        let templates_dir = self.config.templates_dir();
+        let (template_params, resume) =
+            Self::convert_and_validate_params(&template, &params, &templates_dir)?;
 
         // Build execution context with infrastructure registries (Phase 12.7.1.3 + 12.8.2.5)
         // Wire in the 5 required components for template execution
@@ -1153,7 +1261,16 @@ impl ScriptExecutor for ScriptRuntime {
             .with_agent_registry(self.agent_registry.clone())
             .with_workflow_factory(self.workflow_factory.clone())
             .with_providers(core_provider_manager)
-            .with_provider_config(provider_config);
+            .with_provider_config(provider_config)
+            .with_state_manager(self.state_manager.clone())
+            .with_template_registry(self.get_template_registry()?)
+            .with_process_spawn(
+                self.execution_context
+                    .read()
+                    .unwrap()
+                    .security
+                    .allow_process_spawn,
+            );
 
         // Add session manager if wired from kernel (Phase 12.8.2.5)
         if let Some(sm) = session_manager {
@@ -1186,14 +1303,18 @@ impl ScriptExecutor for ScriptRuntime {
             });
         }
 
-        // Execute template
-        let output = template
-            .execute(template_params, context)
-            .await
-            .map_err(|e| LLMSpellError::Component {
-                message: format!("Template execution failed: {e}"),
-                source: None,
-            })?;
+        // Execute template, checkpointing phases and resuming if requested
+        let output = llmspell_templates::checkpoint::execute_resumable(
+            template.as_ref(),
+            template_params,
+            context,
+            resume,
+        )
+        .await
+        .map_err(|e| LLMSpellError::Component {
+            message: format!("Template execution failed: {e}"),
+            source: None,
+        })?;
 
         // Convert output to JSON response
         Ok(json!({
@@ -1235,8 +1356,8 @@ impl ScriptExecutor for ScriptRuntime {
             source: None,
         })?;
 
-        // Search templates
-        let mut results = template_registry.search(query);
+        // Search templates, most relevant first
+        let mut results = template_registry.search(query, template_registry.count());
 
         // Filter by category if specified
         if let Some(cat_str) = category {
@@ -1255,19 +1376,20 @@ impl ScriptExecutor for ScriptRuntime {
                     });
                 }
             };
-            results.retain(|m| m.category == category);
+            results.retain(|(m, _)| m.category == category);
         }
 
         // Convert to JSON
         let results_json: Vec<serde_json::Value> = results
             .iter()
-            .map(|meta| {
+            .map(|(meta, score)| {
                 json!({
                     "id": meta.id,
                     "name": meta.name,
                     "description": meta.description,
                     "category": format!("{:?}", meta.category),
                     "tags": meta.tags,
+                    "score": score,
                 })
             })
             .collect();
@@ -1719,11 +1841,10 @@ impl ScriptExecutor for ScriptRuntime {
 
 /// Helper methods for template execution
 impl ScriptRuntime {
-    /// Helper to get template from registry with type erasure
-    fn get_template_from_registry(
+    /// Helper to get the template registry through type erasure
+    fn get_template_registry(
         &self,
-        template_id: &str,
-    ) -> Result<std::sync::Arc<dyn llmspell_templates::core::Template>, LLMSpellError> {
+    ) -> Result<std::sync::Arc<llmspell_templates::registry::TemplateRegistry>, LLMSpellError> {
         let registry_any =
             self.template_registry_any()
                 .ok_or_else(|| LLMSpellError::Component {
@@ -1731,15 +1852,19 @@ impl ScriptRuntime {
                     source: None,
                 })?;
 
-        let template_registry = std::sync::Arc::downcast::<
-            llmspell_templates::registry::TemplateRegistry,
-        >(registry_any)
-        .map_err(|_| LLMSpellError::Component {
-            message: "Failed to access template registry".to_string(),
-            source: None,
-        })?;
+        std::sync::Arc::downcast::<llmspell_templates::registry::TemplateRegistry>(registry_any)
+            .map_err(|_| LLMSpellError::Component {
+                message: "Failed to access template registry".to_string(),
+                source: None,
+            })
+    }
 
-        template_registry
+    /// Helper to get template from registry with type erasure
+    fn get_template_from_registry(
+        &self,
+        template_id: &str,
+    ) -> Result<std::sync::Arc<dyn llmspell_templates::core::Template>, LLMSpellError> {
+        self.get_template_registry()?
             .get(template_id)
             .map_err(|e| LLMSpellError::Component {
                 message: format!("Template not found: {e}"),
@@ -1748,10 +1873,20 @@ impl ScriptRuntime {
     }
 
     /// Helper to convert and validate JSON params to `TemplateParams`
+    ///
+    /// Also extracts the `resume` param, resolving what a resumed run continues from.
+    /// Saved artifact collections are resolved against `templates_dir`.
     fn convert_and_validate_params(
         template: &std::sync::Arc<dyn llmspell_templates::core::Template>,
         params: &serde_json::Value,
-    ) -> Result<llmspell_templates::core::TemplateParams, LLMSpellError> {
+        templates_dir: &std::path::Path,
+    ) -> Result<
+        (
+            llmspell_templates::core::TemplateParams,
+            Option<llmspell_templates::checkpoint::ResumeFrom>,
+        ),
+        LLMSpellError,
+    > {
         let params_obj = params
             .as_object()
             .ok_or_else(|| LLMSpellError::Validation {
@@ -1764,6 +1899,15 @@ impl ScriptRuntime {
             template_params.insert(key.clone(), value.clone());
         }
 
+        let resume = llmspell_templates::checkpoint::take_resume_param(
+            &mut template_params,
+            Some(templates_dir),
+        )
+        .map_err(|e| LLMSpellError::Validation {
+            field: Some(llmspell_templates::checkpoint::RESUME_PARAM.to_string()),
+            message: format!("Invalid resume parameter: {e}"),
+        })?;
+
         template
             .validate(&template_params)
             .map_err(|e| LLMSpellError::Validation {
@@ -1771,7 +1915,7 @@ impl ScriptRuntime {
                 message: format!("Parameter validation failed: {e}"),
             })?;
 
-        Ok(template_params)
+        Ok((template_params, resume))
     }
 
     /// Helper to convert `TemplateResult` to JSON
@@ -64,6 +64,10 @@ pub struct TemplateBridge {
     session_manager: Option<Arc<llmspell_kernel::sessions::manager::SessionManager>>,
     /// Optional RAG infrastructure from `ScriptRuntime` (Task 13b.15.6)
     rag: Option<Arc<llmspell_rag::multi_tenant_integration::MultiTenantRAG>>,
+    /// Whether templates may spawn subprocesses (`runtime.security.allow_process_spawn`)
+    allow_process_spawn: bool,
+    /// Directory saved artifact collections are resumed from
+    resume_dir: Option<std::path::PathBuf>,
 }
 
 impl TemplateBridge {
@@ -95,6 +99,8 @@ impl TemplateBridge {
             state_manager: None,
             session_manager: None,
             rag: infra.rag,
+            allow_process_spawn: false,
+            resume_dir: None,
         }
     }
 
@@ -128,6 +134,8 @@ impl TemplateBridge {
             state_manager: Some(state_manager),
             session_manager: None,
             rag: infra.rag,
+            allow_process_spawn: false,
+            resume_dir: None,
         }
     }
 
@@ -161,9 +169,25 @@ impl TemplateBridge {
             state_manager: Some(managers.state_manager),
             session_manager: Some(managers.session_manager),
             rag: infra.rag,
+            allow_process_spawn: false,
+            resume_dir: None,
         }
     }
 
+    /// Allow or deny templates spawning subprocesses (denied by default)
+    #[must_use]
+    pub const fn with_process_spawn(mut self, allowed: bool) -> Self {
+        self.allow_process_spawn = allowed;
+        self
+    }
+
+    /// Set the directory `resume` paths are resolved against (none by default)
+    #[must_use]
+    pub fn with_resume_dir(mut self, dir: std::path::PathBuf) -> Self {
+        self.resume_dir = Some(dir);
+        self
+    }
+
     /// List templates by optional category
     ///
     /// # Arguments
@@ -243,7 +267,7 @@ impl TemplateBridge {
     pub async fn execute_template(
         &self,
         name: &str,
-        params: TemplateParams,
+        mut params: TemplateParams,
     ) -> Result<TemplateOutput, LLMSpellError> {
         // Get template
         let template = self
@@ -254,6 +278,16 @@ impl TemplateBridge {
                 source: None,
             })?;
 
+        // Resolve what a resumed run continues from
+        let resume = llmspell_templates::checkpoint::take_resume_param(
+            &mut params,
+            self.resume_dir.as_deref(),
+        )
+        .map_err(|e| LLMSpellError::Validation {
+            field: Some(llmspell_templates::checkpoint::RESUME_PARAM.to_string()),
+            message: format!("Invalid resume parameter: {e}"),
+        })?;
+
         // Validate parameters against schema
         template
             .validate(&params)
@@ -269,7 +303,9 @@ impl TemplateBridge {
             .with_agent_registry(self.agent_registry.clone())
             .with_workflow_factory(self.workflow_factory.clone())
             .with_providers(self.providers.clone())
-            .with_provider_config(self.provider_config.clone());
+            .with_provider_config(self.provider_config.clone())
+            .with_template_registry(self.template_registry.clone())
+            .with_process_spawn(self.allow_process_spawn);
 
         // Add optional components
         if let Some(state_mgr) = &self.state_manager {
@@ -292,14 +328,18 @@ impl TemplateBridge {
                 source: None,
             })?;
 
-        // Execute template
-        template
-            .execute(params, exec_context)
-            .await
-            .map_err(|e| LLMSpellError::Component {
-                message: format!("Template execution failed: {e}"),
-                source: None,
-            })
+        // Execute template, checkpointing phases and resuming if requested
+        llmspell_templates::checkpoint::execute_resumable(
+            template.as_ref(),
+            params,
+            exec_context,
+            resume,
+        )
+        .await
+        .map_err(|e| LLMSpellError::Component {
+            message: format!("Template execution failed: {e}"),
+            source: None,
+        })
     }
 
     /// Search templates by query and optional category
@@ -311,21 +351,23 @@ impl TemplateBridge {
     ///
     /// # Returns
     ///
-    /// Vector of matching template metadata
+    /// Vector of matching template metadata, most relevant first
     #[must_use]
     pub fn search_templates(
         &self,
         query: &str,
         category: Option<TemplateCategory>,
     ) -> Vec<TemplateMetadata> {
-        let mut results = self.template_registry.search(query);
+        let mut results = self
+            .template_registry
+            .search(query, self.template_registry.count());
 
         // Filter by category if provided
         if let Some(cat) = category {
-            results.retain(|metadata| metadata.category == cat);
+            results.retain(|(metadata, _)| metadata.category == cat);
         }
 
-        results
+        results.into_iter().map(|(metadata, _)| metadata).collect()
     }
 
     /// Get template parameter schema
@@ -424,6 +466,7 @@ impl llmspell_core::traits::template_executor::TemplateExecutor for TemplateBrid
 #[cfg(test)]
 mod tests {
     use super::*;
+    use llmspell_templates::testing::{NotesTemplate, StepTemplate};
 
     // Test helper to create infrastructure registries
     fn create_test_infrastructure() -> (
@@ -622,4 +665,47 @@ mod tests {
             );
         }
     }
+
+    #[tokio::test]
+    async fn test_template_step_resolves_from_bridge_registry() {
+        // Templates registered on the bridge's registry only, not the global one
+        let template_registry = Arc::new(TemplateRegistry::new());
+        template_registry
+            .register(Arc::new(NotesTemplate::new("notes")))
+            .unwrap();
+        template_registry
+            .register(Arc::new(StepTemplate::new(
+                "report",
+                llmspell_templates::TemplateStep::new("notes").map_param("topic", "topic"),
+            )))
+            .unwrap();
+        let (tool_registry, agent_registry, workflow_factory) = create_test_infrastructure();
+
+        let bridge = TemplateBridge::new(
+            template_registry,
+            Arc::new(crate::registry::ComponentRegistry::new()),
+            Arc::new(llmspell_providers::ProviderManager::new()),
+            Arc::new(llmspell_config::providers::ProviderManagerConfig::default()),
+            InfraConfig {
+                tool_registry,
+                agent_registry,
+                workflow_factory,
+                rag: None,
+            },
+        );
+
+        let output = bridge
+            .execute_template(
+                "report",
+                TemplateParams::from(serde_json::json!({"topic": "rust"})),
+            )
+            .await
+            .expect("report should run its notes step");
+        let notes = output
+            .artifacts
+            .iter()
+            .find(|a| a.filename == "notes.md")
+            .expect("notes artifact propagated");
+        assert_eq!(notes.content, "# rust");
+    }
 }
@@ -4,11 +4,12 @@
 use crate::discovery::BridgeDiscovery;
 use crate::ComponentRegistry;
 use llmspell_config::tools::ToolsConfig;
+use llmspell_config::SecurityConfig;
 use llmspell_core::traits::tool::{
     ResourceLimits, SecurityLevel, SecurityRequirements, ToolCategory, ToolSchema,
 };
 use llmspell_core::Tool;
-use llmspell_security::sandbox::{file_sandbox::FileSandbox, SandboxContext};
+use llmspell_security::sandbox::{file_sandbox::FileSandbox, EnvSandbox, SandboxContext};
 // Import tools conditionally based on features
 use llmspell_tools::{
     ApiTesterTool, AudioProcessorTool, Base64EncoderTool, CalculatorTool, CitationFormatterTool,
@@ -72,6 +73,30 @@ pub async fn register_all_tools(
     component_registry: &Arc<ComponentRegistry>,
     tool_registry: &Arc<llmspell_tools::ToolRegistry>,
     tools_config: &ToolsConfig,
+) -> Result<(), Box<dyn std::error::Error>> {
+    register_all_tools_with_security(
+        component_registry,
+        tool_registry,
+        tools_config,
+        &SecurityConfig::default(),
+    )
+    .await
+}
+
+/// Initialize and register all tools, applying runtime security settings
+///
+/// Like [`register_all_tools`], but tools that read the process environment
+/// are restricted to `security_config.allowed_env_vars` (minus
+/// `denied_env_vars`) when an allowlist is configured.
+///
+/// # Errors
+///
+/// Returns an error if tool registration fails in either registry
+pub async fn register_all_tools_with_security(
+    component_registry: &Arc<ComponentRegistry>,
+    tool_registry: &Arc<llmspell_tools::ToolRegistry>,
+    tools_config: &ToolsConfig,
+    security_config: &SecurityConfig,
 ) -> Result<(), Box<dyn std::error::Error>> {
     // Create a shared file sandbox for file system tools using configured allowed paths
     let mut security_requirements = SecurityRequirements::default();
@@ -85,6 +110,14 @@ pub async fn register_all_tools(
     );
     let file_sandbox = Arc::new(FileSandbox::new(sandbox_context)?);
 
+    // Environment sandbox from the security allowlist, if one is configured
+    let env_sandbox = (!security_config.allowed_env_vars.is_empty()).then(|| {
+        Arc::new(
+            EnvSandbox::new(security_config.allowed_env_vars.clone())
+                .with_denylist(security_config.denied_env_vars.clone()),
+        )
+    });
+
     // Register different tool categories with their specific configurations
     // Phase 12.7.1.2: Pass both registries for dual-registration
     register_utility_tools(component_registry, tool_registry).await?;
@@ -101,7 +134,13 @@ pub async fn register_all_tools(
         &tools_config.file_operations,
     )
     .await?;
-    register_system_tools(component_registry, tool_registry, &file_sandbox).await?;
+    register_system_tools(
+        component_registry,
+        tool_registry,
+        &file_sandbox,
+        env_sandbox.as_ref(),
+    )
+    .await?;
     register_media_tools(component_registry, tool_registry, &file_sandbox).await?;
     register_search_tools(component_registry, tool_registry, &tools_config.web_search).await?;
     register_web_tools(component_registry, tool_registry).await?;
@@ -410,19 +449,23 @@ async fn register_system_tools(
     component_registry: &Arc<ComponentRegistry>,
     tool_registry: &Arc<llmspell_tools::ToolRegistry>,
     file_sandbox: &Arc<FileSandbox>,
+    env_sandbox: Option<&Arc<EnvSandbox>>,
 ) -> Result<(), Box<dyn std::error::Error>> {
+    let environment_reader = || {
+        let tool = EnvironmentReaderTool::new(EnvironmentReaderConfig::default());
+        match env_sandbox {
+            Some(env_sandbox) => tool.with_env_sandbox(env_sandbox.clone()),
+            None => tool,
+        }
+    };
+
     // Environment reader - manual dual-registration (create separate instances)
     component_registry.register_tool(
         "environment-reader".to_string(),
-        Arc::new(EnvironmentReaderTool::new(
-            EnvironmentReaderConfig::default(),
-        )),
+        Arc::new(environment_reader()),
     )?;
     tool_registry
-        .register(
-            "environment-reader".to_string(),
-            EnvironmentReaderTool::new(EnvironmentReaderConfig::default()),
-        )
+        .register("environment-reader".to_string(), environment_reader())
         .await?;
 
     // Process executor - manual dual-registration (create separate instances)
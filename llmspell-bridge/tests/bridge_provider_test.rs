@@ -259,3 +259,156 @@ async fn test_script_provider_error_handling() {
         "Creating agent with non-existent provider should fail"
     );
 }
+
+mod mock_local {
+    use async_trait::async_trait;
+    use llmspell_core::error::LLMSpellError;
+    use llmspell_core::types::{AgentInput, AgentOutput};
+    use llmspell_providers::local::{
+        HealthStatus, LocalModel, LocalProviderInstance, ModelInfo, ModelSpec, PullProgress,
+    };
+    use llmspell_providers::{ProviderCapabilities, ProviderInstance};
+
+    const MODELS: [&str; 2] = ["tiny-a", "tiny-b"];
+
+    /// Local provider with two installed models
+    pub struct MockLocalProvider {
+        capabilities: ProviderCapabilities,
+    }
+
+    impl MockLocalProvider {
+        pub fn new() -> Self {
+            Self {
+                capabilities: ProviderCapabilities {
+                    supports_streaming: true,
+                    ..ProviderCapabilities::default()
+                },
+            }
+        }
+    }
+
+    #[async_trait]
+    impl ProviderInstance for MockLocalProvider {
+        fn capabilities(&self) -> &ProviderCapabilities {
+            &self.capabilities
+        }
+
+        async fn complete(&self, _input: &AgentInput) -> Result<AgentOutput, LLMSpellError> {
+            Ok(AgentOutput::text("ok"))
+        }
+
+        async fn validate(&self) -> Result<(), LLMSpellError> {
+            Ok(())
+        }
+
+        fn name(&self) -> &str {
+            "mock"
+        }
+
+        fn model(&self) -> &str {
+            MODELS[0]
+        }
+
+        fn as_local(&self) -> Option<&dyn LocalProviderInstance> {
+            Some(self)
+        }
+    }
+
+    #[async_trait]
+    impl LocalProviderInstance for MockLocalProvider {
+        async fn health_check(&self) -> anyhow::Result<HealthStatus> {
+            Ok(HealthStatus::Healthy {
+                available_models: MODELS.len(),
+                version: None,
+            })
+        }
+
+        async fn list_local_models(&self) -> anyhow::Result<Vec<LocalModel>> {
+            Ok(MODELS
+                .iter()
+                .map(|id| LocalModel {
+                    id: (*id).to_string(),
+                    backend: "mock".to_string(),
+                    size_bytes: 1024,
+                    quantization: None,
+                    modified_at: None,
+                })
+                .collect())
+        }
+
+        async fn pull_model(&self, _model_spec: &ModelSpec) -> anyhow::Result<PullProgress> {
+            anyhow::bail!("pull not supported")
+        }
+
+        async fn model_info(&self, model_id: &str) -> anyhow::Result<ModelInfo> {
+            Ok(ModelInfo {
+                id: model_id.to_string(),
+                backend: "mock".to_string(),
+                size_bytes: 1024,
+                parameter_count: Some("1B".to_string()),
+                quantization: None,
+                format: "GGUF".to_string(),
+                loaded: false,
+            })
+        }
+
+        async fn unload_model(&self, _model_id: &str) -> anyhow::Result<()> {
+            Ok(())
+        }
+    }
+}
+
+/// Test listing the models of a local provider from script
+#[tokio::test(flavor = "multi_thread")]
+async fn test_script_provider_models_local() {
+    use llmspell_providers::{ProviderConfig as InstanceConfig, ProviderManager as CoreManager};
+
+    let core_manager = CoreManager::new();
+    core_manager
+        .register_provider("mock", |_config| {
+            Ok(Box::new(mock_local::MockLocalProvider::new())
+                as Box<dyn llmspell_providers::ProviderInstance>)
+        })
+        .await;
+    core_manager
+        .init_provider(InstanceConfig::new_with_type("mock", "mock", "tiny-a"))
+        .await
+        .unwrap();
+
+    let mut provider_config = ProviderManagerConfig::default();
+    provider_config.providers.insert(
+        "local-mock".to_string(),
+        ProviderConfig {
+            name: "local-mock".to_string(),
+            provider_type: "mock".to_string(),
+            default_model: Some("tiny-a".to_string()),
+            ..ProviderConfig::default()
+        },
+    );
+    let providers = Arc::new(ProviderManager::from_core_manager(
+        core_manager,
+        provider_config,
+    ));
+
+    let lua_config = LuaConfig::default();
+    let mut engine = EngineFactory::create_lua_engine(&lua_config).unwrap();
+    let (tool_registry, agent_registry, workflow_factory) = create_test_infrastructure();
+    let api_deps = ApiDependencies::new(
+        Arc::new(ComponentRegistry::new()),
+        providers,
+        tool_registry,
+        agent_registry,
+        workflow_factory,
+    );
+    engine.inject_apis(&api_deps).unwrap();
+
+    let script = r#"
+        local models = Provider.models("local-mock")
+        assert(#models == 2, "expected 2 models, got " .. #models)
+        assert(models[1].capabilities.supports_streaming)
+        assert(models[1].parameter_count == "1B")
+        return models[1].id .. "," .. models[2].id
+    "#;
+    let output = engine.execute_script(script).await.unwrap();
+    assert_eq!(output.output.as_str(), Some("tiny-a,tiny-b"));
+}
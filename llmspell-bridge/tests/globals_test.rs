@@ -124,6 +124,35 @@ mod lua_globals {
         Ok(())
     }
 
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_agent_invoke_tool_without_allowlist_lua() -> Result<()> {
+        let (lua, context) = setup_lua_with_globals().await?;
+        context.registry.register_tool(
+            "calculator".to_string(),
+            Arc::new(llmspell_tools::CalculatorTool::new()),
+        )?;
+
+        lua.load(
+            r#"
+            -- No allowed_tools given, so the agent may invoke any registered tool
+            Agent.register({name = "plain-agent", agent_type = "basic"})
+            local agent = Agent.get("plain-agent")
+            assert(agent ~= nil, "registered agent should be found")
+
+            local result = agent:invokeTool("calculator", {input = "2 + 3"})
+            assert(result.text ~= nil, "tool should return output")
+            assert(string.find(result.text, "5"), "unexpected result: " .. result.text)
+        "#,
+        )
+        .exec()
+        .map_err(|e| llmspell_core::LLMSpellError::Component {
+            message: format!("Agent invokeTool Lua test failed: {e}"),
+            source: None,
+        })?;
+
+        Ok(())
+    }
+
     #[tokio::test(flavor = "multi_thread")]
     async fn test_tool_global_lua() -> Result<()> {
         // Define test tool struct and implementation before any statements
@@ -680,4 +709,158 @@ mod lua_globals {
 
         Ok(())
     }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_agent_invoke_stream_chunks_lua() -> Result<()> {
+        use llmspell_bridge::agent_bridge::AgentBridge;
+        use llmspell_core::traits::agent::{Agent, AgentConfig, ConversationMessage};
+        use llmspell_core::types::{
+            AgentChunk, AgentInput, AgentOutput, AgentStream, ChunkContent, ChunkMetadata,
+        };
+
+        /// Agent streaming three text chunks of two tokens each
+        struct StreamingAgent {
+            metadata: ComponentMetadata,
+            config: AgentConfig,
+        }
+
+        #[async_trait]
+        impl BaseAgent for StreamingAgent {
+            fn metadata(&self) -> &ComponentMetadata {
+                &self.metadata
+            }
+
+            async fn execute_impl(
+                &self,
+                _input: AgentInput,
+                _context: ExecutionContext,
+            ) -> Result<AgentOutput> {
+                Ok(AgentOutput::text("one two three"))
+            }
+
+            async fn validate_input(&self, _input: &AgentInput) -> Result<()> {
+                Ok(())
+            }
+
+            async fn handle_error(
+                &self,
+                error: llmspell_core::LLMSpellError,
+            ) -> Result<AgentOutput> {
+                Err(error)
+            }
+
+            async fn stream_execute(
+                &self,
+                _input: AgentInput,
+                _context: ExecutionContext,
+            ) -> Result<AgentStream> {
+                let chunks = ["one ", "two ", "three"]
+                    .into_iter()
+                    .enumerate()
+                    .map(|(i, text)| {
+                        Ok(AgentChunk {
+                            stream_id: "test-stream".to_string(),
+                            chunk_index: i,
+                            content: ChunkContent::Text(text.to_string()),
+                            metadata: ChunkMetadata {
+                                is_final: i == 2,
+                                token_count: Some(2),
+                                ..Default::default()
+                            },
+                            timestamp: chrono::Utc::now(),
+                        })
+                    });
+                Ok(Box::pin(futures::stream::iter(chunks.collect::<Vec<_>>())))
+            }
+
+            fn supports_streaming(&self) -> bool {
+                true
+            }
+        }
+
+        #[async_trait]
+        impl Agent for StreamingAgent {
+            fn config(&self) -> &AgentConfig {
+                &self.config
+            }
+
+            async fn get_conversation(&self) -> Result<Vec<ConversationMessage>> {
+                Ok(vec![])
+            }
+
+            async fn add_message(&self, _message: ConversationMessage) -> Result<()> {
+                Ok(())
+            }
+
+            async fn clear_conversation(&self) -> Result<()> {
+                Ok(())
+            }
+        }
+
+        let (lua, context) = setup_lua_with_globals().await?;
+        let bridge = context
+            .get_bridge::<AgentBridge>("agent")
+            .expect("agent bridge should be injected");
+        bridge
+            .register_agent(
+                "streamer",
+                Arc::new(StreamingAgent {
+                    metadata: ComponentMetadata::new(
+                        "streamer".to_string(),
+                        "Streaming test agent".to_string(),
+                    ),
+                    config: AgentConfig::default(),
+                }),
+            )
+            .await?;
+        assert!(bridge
+            .register_agent(
+                "streamer",
+                Arc::new(StreamingAgent {
+                    metadata: ComponentMetadata::new("streamer".to_string(), String::new()),
+                    config: AgentConfig::default(),
+                }),
+            )
+            .await
+            .is_err());
+
+        lua.load(
+            r#"
+            local agent = Agent.get("streamer")
+            assert(agent ~= nil, "registered agent should be found")
+
+            local chunks = {}
+            local result = agent:invokeStream({text = "count"}, function(chunk)
+                table.insert(chunks, chunk)
+            end)
+
+            assert(#chunks == 3, "expected 3 chunks, got " .. #chunks)
+            assert(result.chunks_received == 3, "chunks_received mismatch")
+            assert(result.success == true, "stream should succeed")
+            assert(result.finish_reason == "stop", "result finish_reason mismatch")
+
+            for i, chunk in ipairs(chunks) do
+                assert(chunk.index == i - 1, "chunk index mismatch")
+                assert(chunk.usage.total_tokens == 2 * i, "running usage mismatch")
+            end
+            assert(chunks[1].content == "one ", "first chunk content mismatch")
+            assert(chunks[1].text == "one ", "chunk should keep the AgentOutput text field")
+            assert(type(chunks[1].metadata) == "table", "chunk should keep AgentOutput metadata")
+            assert(chunks[1].finish_reason == nil, "intermediate chunk should not finish")
+
+            local last = chunks[#chunks]
+            assert(last.content == "three", "last chunk content mismatch")
+            assert(last.finish_reason ~= nil, "last chunk should have a finish_reason")
+            assert(last.finish_reason == "stop", "last chunk finish_reason mismatch")
+            assert(last.usage.total_tokens == 6, "final usage should be aggregated")
+        "#,
+        )
+        .exec()
+        .map_err(|e| llmspell_core::LLMSpellError::Component {
+            message: format!("Agent streaming Lua test failed: {e}"),
+            source: None,
+        })?;
+
+        Ok(())
+    }
 }
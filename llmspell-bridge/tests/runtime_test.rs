@@ -167,3 +167,221 @@ async fn test_available_engines() {
     assert!(lua.features.streaming);
     assert!(lua.features.multimodal);
 }
+
+#[cfg(feature = "lua")]
+mod runtime_tool {
+    use async_trait::async_trait;
+    use llmspell_core::traits::tool::{SecurityLevel, ToolCategory, ToolSchema};
+    use llmspell_core::types::{AgentInput, AgentOutput};
+    use llmspell_core::{BaseAgent, ComponentMetadata, ExecutionContext, Result, Tool};
+
+    /// Host tool that always answers "pong"
+    pub struct PingTool {
+        metadata: ComponentMetadata,
+    }
+
+    impl PingTool {
+        pub fn new() -> Self {
+            Self {
+                metadata: ComponentMetadata::new(
+                    "ping_tool".to_string(),
+                    "Answers pong".to_string(),
+                ),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl BaseAgent for PingTool {
+        fn metadata(&self) -> &ComponentMetadata {
+            &self.metadata
+        }
+
+        async fn execute_impl(
+            &self,
+            _input: AgentInput,
+            _context: ExecutionContext,
+        ) -> Result<AgentOutput> {
+            Ok(AgentOutput::text("pong"))
+        }
+
+        async fn validate_input(&self, _input: &AgentInput) -> Result<()> {
+            Ok(())
+        }
+
+        async fn handle_error(&self, error: llmspell_core::LLMSpellError) -> Result<AgentOutput> {
+            Err(error)
+        }
+    }
+
+    impl Tool for PingTool {
+        fn category(&self) -> ToolCategory {
+            ToolCategory::Utility
+        }
+
+        fn security_level(&self) -> SecurityLevel {
+            SecurityLevel::Safe
+        }
+
+        fn schema(&self) -> ToolSchema {
+            ToolSchema::new("ping_tool".to_string(), "Answers pong".to_string())
+        }
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+#[cfg(feature = "lua")]
+async fn test_runtime_registered_tool_callable_from_lua() {
+    use std::sync::Arc;
+
+    let config = LLMSpellConfig::default();
+    let runtime = Box::pin(ScriptRuntime::new(config)).await.unwrap();
+
+    runtime
+        .register_tool("ping_tool", Arc::new(runtime_tool::PingTool::new()))
+        .unwrap();
+    assert!(runtime
+        .register_tool("ping_tool", Arc::new(runtime_tool::PingTool::new()))
+        .is_err());
+
+    let result = runtime
+        .execute_script(
+            r#"
+            local listed = false
+            for _, tool in ipairs(Tool.list()) do
+                if tool.name == "ping_tool" then
+                    listed = true
+                end
+            end
+            assert(listed, "ping_tool should be listed")
+            return Tool.execute("ping_tool", {}).text
+            "#,
+        )
+        .await
+        .unwrap();
+    assert_eq!(result.output.as_str(), Some("pong"));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+#[cfg(feature = "lua")]
+async fn test_runtime_cancellable_script_stops_infinite_loop() {
+    use llmspell_core::error::LLMSpellError;
+    use std::time::{Duration, Instant};
+    use tokio_util::sync::CancellationToken;
+
+    let config = LLMSpellConfig::default();
+    let runtime = Box::pin(ScriptRuntime::new(config)).await.unwrap();
+
+    let token = CancellationToken::new();
+    let canceller = token.clone();
+    tokio::spawn(async move {
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        canceller.cancel();
+    });
+
+    let start = Instant::now();
+    let result = runtime
+        .execute_script_cancellable("while true do end", token)
+        .await;
+    assert!(
+        matches!(result, Err(LLMSpellError::Cancelled { .. })),
+        "expected cancelled error, got {result:?}"
+    );
+    assert!(start.elapsed() < Duration::from_secs(2));
+
+    // The engine remains usable after cancellation
+    let output = runtime.execute_script("return 1 + 1").await.unwrap();
+    assert_eq!(output.output.as_i64(), Some(2));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+#[cfg(feature = "lua")]
+async fn test_runtime_max_execution_time_times_out() {
+    use llmspell_core::error::LLMSpellError;
+
+    let mut config = LLMSpellConfig::default();
+    config.runtime.security.max_execution_time_ms = Some(100);
+    let runtime = Box::pin(ScriptRuntime::new(config)).await.unwrap();
+
+    let result = runtime.execute_script("while true do end").await;
+    assert!(
+        matches!(
+            result,
+            Err(LLMSpellError::Timeout {
+                duration_ms: Some(100),
+                ..
+            })
+        ),
+        "expected timeout error, got {result:?}"
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+#[cfg(feature = "lua")]
+async fn test_runtime_max_execution_time_survives_pcall_loop() {
+    use llmspell_core::error::LLMSpellError;
+    use llmspell_core::traits::script_executor::ScriptExecutor;
+    use std::time::{Duration, Instant};
+
+    let mut config = LLMSpellConfig::default();
+    config.runtime.security.max_execution_time_ms = Some(100);
+    let runtime = Box::pin(ScriptRuntime::new(config)).await.unwrap();
+
+    // Scripts cannot swallow the cancellation error with pcall
+    let script = "while true do pcall(function() while true do end end) end";
+    let start = Instant::now();
+    let result = runtime.execute_script(script).await;
+    assert!(
+        matches!(result, Err(LLMSpellError::Timeout { .. })),
+        "expected timeout error, got {result:?}"
+    );
+
+    // A script that catches the cancellation and returns normally still times out
+    let script = "pcall(function() while true do end end) return 1";
+    let result = runtime.execute_script(script).await;
+    assert!(
+        matches!(result, Err(LLMSpellError::Timeout { .. })),
+        "expected timeout error, got {result:?}"
+    );
+
+    // The kernel path (ScriptExecutor) enforces the same limit
+    let result = ScriptExecutor::execute_script(&runtime, "while true do end").await;
+    assert!(
+        matches!(result, Err(LLMSpellError::Timeout { .. })),
+        "expected timeout error, got {result:?}"
+    );
+    assert!(start.elapsed() < Duration::from_secs(5));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+#[cfg(feature = "lua")]
+async fn test_runtime_config_get_path_is_read_only_and_redacted() {
+    use llmspell_config::providers::ProviderConfig;
+
+    let mut config = LLMSpellConfig::default();
+    config.rag.vector_storage.dimensions = 768;
+    config.providers.providers.insert(
+        "openai".to_string(),
+        ProviderConfig {
+            provider_type: "openai".to_string(),
+            api_key: Some("sk-secret".to_string()),
+            ..ProviderConfig::default()
+        },
+    );
+    let runtime = Box::pin(ScriptRuntime::new(config)).await.unwrap();
+
+    let result = runtime
+        .execute_script(
+            r#"
+            assert(Config.get("default_engine") == "lua")
+            assert(Config.get("rag.vector_storage.dimensions") == 768)
+            assert(Config.get("rag.no_such_field") == nil)
+            assert(Config.get("providers.openai.api_key") == nil)
+            assert(Config.get("providers.openai").api_key ~= "sk-secret")
+            return Config.get("providers.openai").provider_type
+            "#,
+        )
+        .await
+        .unwrap();
+    assert_eq!(result.output.as_str(), Some("openai"));
+}
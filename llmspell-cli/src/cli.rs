@@ -1366,13 +1366,17 @@ EXAMPLES:
     #[command(long_about = "Execute a template with specified parameters.
 
 Parameters can be provided as --param key=value flags. Values are parsed as JSON first,
-falling back to strings. Complex values should use JSON syntax.
+falling back to strings. Complex values should use JSON syntax. When run from a
+terminal, missing required parameters are prompted for interactively. Use --resume to skip
+the phases a failed run with the same parameters already completed, or --resume <PATH> to
+resume from an artifact collection saved in the templates directory (~/.llmspell/templates).
 
 EXAMPLES:
     llmspell template exec research-assistant --param topic=\"Rust async runtime design\"
     llmspell template exec research-assistant --param topic=\"AI safety\" --param max_sources=20
     llmspell template exec data-analysis --param data_file=\"data.csv\" --param chart_type=\"bar\"
-    llmspell template exec research-assistant --param topic=\"Quantum\" --output-dir /tmp/results")]
+    llmspell template exec research-assistant --param topic=\"Quantum\" --output-dir /tmp/results
+    llmspell template exec research-assistant --param topic=\"Quantum\" --resume")]
     Exec {
         /// Template ID to execute
         name: String,
@@ -1384,6 +1388,11 @@ EXAMPLES:
         /// Output directory for artifacts
         #[arg(long, short = 'o')]
         output_dir: Option<std::path::PathBuf>,
+
+        /// Resume a failed run from its checkpoint, or from an artifact collection saved in
+        /// the templates directory
+        #[arg(long, value_name = "PATH", num_args = 0..=1)]
+        resume: Option<Option<std::path::PathBuf>>,
     },
 
     /// Search templates by keywords
@@ -143,6 +143,7 @@ impl KeysCommand {
             expires_at: expires_in.map(|days| Utc::now() + chrono::Duration::days(days as i64)),
             is_active: true,
             usage_count: 0,
+            rotated_to: None,
         };
 
         manager
@@ -4,13 +4,17 @@
 //! All template logic is executed in the kernel which has ComponentRegistry access.
 
 use anyhow::{anyhow, Result};
-use serde_json::json;
+use serde_json::{json, Map, Value};
+use std::collections::HashMap;
+use std::io::IsTerminal;
 use tracing::{info, instrument, trace};
 
 use crate::cli::{OutputFormat, TemplateCommands};
 use crate::execution_context::ExecutionContext;
 use crate::output::OutputFormatter;
 use llmspell_config::LLMSpellConfig;
+use llmspell_templates::ConfigSchema;
+use llmspell_utils::terminal;
 
 /// Handle template management commands by sending requests to kernel
 #[instrument(skip(runtime_config), fields(command_type))]
@@ -223,6 +227,7 @@ async fn handle_template_embedded(
             name,
             params,
             output_dir,
+            resume,
         } => {
             info!("Executing template: {} via kernel", name);
 
@@ -233,6 +238,20 @@ async fn handle_template_embedded(
                 let json_value = serde_json::from_str(&value).unwrap_or_else(|_| json!(value));
                 params_obj.insert(key, json_value);
             }
+            match resume {
+                Some(Some(path)) => {
+                    params_obj.insert("resume".to_string(), json!(path.display().to_string()));
+                }
+                Some(None) => {
+                    params_obj.insert("resume".to_string(), json!(true));
+                }
+                None => {}
+            }
+
+            // Interactively collect missing required params when attached to a terminal
+            if std::io::stdin().is_terminal() && !matches!(output_format, OutputFormat::Json) {
+                prompt_missing_params(&mut handle, &name, &mut params_obj).await?;
+            }
 
             // Create template_request message for exec command
             let request_content = json!({
@@ -528,6 +547,90 @@ async fn handle_template_embedded(
     }
 }
 
+/// Prompt for required template parameters missing from `params`
+///
+/// Uses the prompt metadata in the template's parameter schema: the label
+/// and help text, a selection list when choices are declared, and masked
+/// input for secrets. Input is re-requested until it parses and passes the
+/// parameter's constraints; the kernel still validates the full parameter set.
+async fn prompt_missing_params(
+    handle: &mut llmspell_kernel::api::KernelHandle,
+    name: &str,
+    params: &mut Map<String, Value>,
+) -> Result<()> {
+    let response = handle
+        .send_template_request(json!({
+            "command": "schema",
+            "name": name,
+        }))
+        .await?;
+    if let Some(error) = response.get("error") {
+        return Err(anyhow!("Template schema error: {}", error));
+    }
+
+    let schema: ConfigSchema = response
+        .get("schema")
+        .cloned()
+        .map(serde_json::from_value)
+        .transpose()
+        .map_err(|e| anyhow!("Invalid template schema: {}", e))?
+        .ok_or_else(|| anyhow!("Invalid response format"))?;
+    let provided: HashMap<String, Value> = params
+        .iter()
+        .map(|(key, value)| (key.clone(), value.clone()))
+        .collect();
+
+    for param in schema.missing_required(&provided) {
+        let prompt = param.prompt.as_ref();
+        let label = param.prompt_label();
+        let help = prompt
+            .and_then(|p| p.help.as_deref())
+            .unwrap_or(&param.description);
+        if !help.is_empty() {
+            println!("{help}");
+        }
+
+        let value = match prompt.filter(|p| !p.choices.is_empty()) {
+            Some(prompt) => {
+                let options: Vec<String> = prompt
+                    .choices
+                    .iter()
+                    .map(|choice| {
+                        choice
+                            .as_str()
+                            .map_or_else(|| choice.to_string(), String::from)
+                    })
+                    .collect();
+                let options: Vec<&str> = options.iter().map(String::as_str).collect();
+                let index = terminal::select(label, &options, 0)?;
+                prompt.choices[index].clone()
+            }
+            None => {
+                let secret = prompt.is_some_and(|p| p.secret);
+                loop {
+                    let input = if secret {
+                        terminal::password(label)?
+                    } else {
+                        terminal::input(label)?
+                    };
+                    if input.trim().is_empty() {
+                        eprintln!("Invalid input: {} is required", param.name);
+                        continue;
+                    }
+                    match param.parse_input(&input) {
+                        Ok(value) => break value,
+                        Err(e) => eprintln!("Invalid input: {e}"),
+                    }
+                }
+            }
+        };
+
+        params.insert(param.name.clone(), value);
+    }
+
+    Ok(())
+}
+
 /// Handle template commands in connected mode (remote kernel)
 async fn handle_template_remote(
     command: TemplateCommands,
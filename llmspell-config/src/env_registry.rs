@@ -1375,6 +1375,17 @@ fn register_event_vars(registry: &EnvRegistry) -> Result<(), String> {
             .build(),
     )?;
 
+    registry.register_var(
+        EnvVarDefBuilder::new("LLMSPELL_EVENTS_SAMPLING")
+            .description(
+                "Per-event-type sampling (comma-separated pattern=rate or pattern=every:N)",
+            )
+            .category(EnvCategory::Runtime)
+            .config_path("events.sampling")
+            .validator(|v| crate::EventSamplingConfig::parse_list(v).map(|_| ()))
+            .build(),
+    )?;
+
     // Filtering configuration
     registry.register_var(
         EnvVarDefBuilder::new("LLMSPELL_EVENTS_FILTERING_INCLUDE_TYPES")
@@ -1447,6 +1458,21 @@ fn register_event_vars(registry: &EnvRegistry) -> Result<(), String> {
             .build(),
     )?;
 
+    registry.register_var(
+        EnvVarDefBuilder::new("LLMSPELL_EVENTS_EXPORT_OTLP_ENDPOINT")
+            .description("Export events to an OTLP/HTTP collector (URL)")
+            .category(EnvCategory::Runtime)
+            .config_path("events.export.otlp_endpoint")
+            .validator(|v| {
+                if v.starts_with("http://") || v.starts_with("https://") {
+                    Ok(())
+                } else {
+                    Err("OTLP endpoint must start with http:// or https://".to_string())
+                }
+            })
+            .build(),
+    )?;
+
     registry.register_var(
         EnvVarDefBuilder::new("LLMSPELL_EVENTS_EXPORT_PRETTY_JSON")
             .description("Pretty-print JSON output")
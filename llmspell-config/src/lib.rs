@@ -285,6 +285,15 @@ impl LLMSpellConfig {
         })
     }
 
+    /// Helper to get the directory templates resume saved artifact collections from
+    pub fn templates_dir(&self) -> PathBuf {
+        self.storage
+            .base_dir
+            .clone()
+            .unwrap_or_else(|| PathBuf::from(".llmspell"))
+            .join("templates")
+    }
+
     /// Merge values from JSON config (from registry) - exposed for testing
     pub fn merge_from_json(&mut self, json: &serde_json::Value) -> Result<(), ConfigError> {
         self.merge_from_json_impl(json)
@@ -652,6 +661,22 @@ impl LLMSpellConfig {
                 self.events.max_events_per_second = Some(max_events as u32);
             }
 
+            if let Some(sampling_value) = events.get("sampling") {
+                let sampling = if let Some(sampling_str) = sampling_value.as_str() {
+                    // From environment variable - comma-separated pattern=policy entries
+                    EventSamplingConfig::parse_list(sampling_str)
+                } else {
+                    // From JSON - map of pattern to policy
+                    serde_json::from_value(sampling_value.clone()).map_err(|e| e.to_string())
+                }
+                .map_err(|message| ConfigError::Validation {
+                    field: Some("events.sampling".to_string()),
+                    message,
+                })?;
+                debug!("Overriding events.sampling from env: {:?}", sampling);
+                self.events.sampling.extend(sampling);
+            }
+
             // Merge filtering configuration
             if let Some(filtering) = events.get("filtering").and_then(|v| v.as_object()) {
                 if let Some(include_types_value) = filtering.get("include_types") {
@@ -740,6 +765,14 @@ impl LLMSpellConfig {
                     self.events.export.webhook = Some(webhook.to_string());
                 }
 
+                if let Some(endpoint) = export.get("otlp_endpoint").and_then(|v| v.as_str()) {
+                    debug!(
+                        "Overriding events.export.otlp_endpoint from env: {}",
+                        endpoint
+                    );
+                    self.events.export.otlp_endpoint = Some(endpoint.to_string());
+                }
+
                 if let Some(pretty_json) = export.get("pretty_json").and_then(|v| v.as_bool()) {
                     debug!(
                         "Overriding events.export.pretty_json from env: {}",
@@ -1606,6 +1639,14 @@ pub struct SecurityConfig {
     pub max_memory_bytes: Option<usize>,
     /// Maximum execution time in milliseconds
     pub max_execution_time_ms: Option<u64>,
+    /// Environment variables scripts may read (supports * wildcards).
+    /// Empty leaves environment access to each tool's own policy.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub allowed_env_vars: Vec<String>,
+    /// Environment variables scripts may never read, even if matched by
+    /// `allowed_env_vars`
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub denied_env_vars: Vec<String>,
 }
 
 impl Default for SecurityConfig {
@@ -1616,6 +1657,8 @@ impl Default for SecurityConfig {
             allow_process_spawn: false,
             max_memory_bytes: Some(50_000_000),   // 50MB
             max_execution_time_ms: Some(300_000), // 5 minutes
+            allowed_env_vars: Vec::new(),
+            denied_env_vars: Vec::new(),
         }
     }
 }
@@ -1764,6 +1807,10 @@ pub struct EventsConfig {
     pub max_events_per_second: Option<u32>,
     /// Event filtering configuration
     pub filtering: EventFilterConfig,
+    /// Sampling policies keyed by event type pattern (glob)
+    ///
+    /// Critical events (error, failed, failure, critical) are never sampled.
+    pub sampling: std::collections::HashMap<String, EventSamplingConfig>,
     /// Event export configuration
     pub export: EventExportConfig,
 }
@@ -1778,11 +1825,24 @@ impl Default for EventsConfig {
             emit_debug_events: false,
             max_events_per_second: None,
             filtering: EventFilterConfig::default(),
+            sampling: std::collections::HashMap::new(),
             export: EventExportConfig::default(),
         }
     }
 }
 
+impl EventsConfig {
+    /// Sampling policies in the form `EventConfig::sampling` expects
+    pub fn sampling_policies(
+        &self,
+    ) -> std::collections::HashMap<String, llmspell_core::traits::event::EventSampling> {
+        self.sampling
+            .iter()
+            .map(|(pattern, sampling)| (pattern.clone(), (*sampling).into()))
+            .collect()
+    }
+}
+
 /// Event filtering configuration
 #[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(default)]
@@ -1808,6 +1868,59 @@ impl Default for EventFilterConfig {
     }
 }
 
+/// Sampling policy for one event type pattern
+///
+/// In TOML: `"tool.progress" = { rate = 0.1 }` or `"stream.*" = { every_nth = 10 }`.
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum EventSamplingConfig {
+    /// Emit each event with the given probability (0.0 - 1.0)
+    Rate(f64),
+    /// Emit the first of every `n` events
+    EveryNth(usize),
+}
+
+impl EventSamplingConfig {
+    /// Parse a comma-separated list of `pattern=rate` or `pattern=every:N` entries
+    ///
+    /// # Errors
+    ///
+    /// Returns a message describing the first malformed entry.
+    pub fn parse_list(value: &str) -> Result<std::collections::HashMap<String, Self>, String> {
+        value
+            .split(',')
+            .map(str::trim)
+            .filter(|entry| !entry.is_empty())
+            .map(|entry| {
+                let (pattern, policy) = entry
+                    .split_once('=')
+                    .ok_or_else(|| format!("Sampling entry '{}' must be pattern=policy", entry))?;
+                let policy = policy.trim();
+                let sampling = if let Some(n) = policy.strip_prefix("every:") {
+                    n.parse()
+                        .map(Self::EveryNth)
+                        .map_err(|e| format!("Invalid every-nth count in '{}': {}", entry, e))?
+                } else {
+                    policy
+                        .parse()
+                        .map(Self::Rate)
+                        .map_err(|e| format!("Invalid sample rate in '{}': {}", entry, e))?
+                };
+                Ok((pattern.trim().to_string(), sampling))
+            })
+            .collect()
+    }
+}
+
+impl From<EventSamplingConfig> for llmspell_core::traits::event::EventSampling {
+    fn from(config: EventSamplingConfig) -> Self {
+        match config {
+            EventSamplingConfig::Rate(rate) => Self::Rate(rate),
+            EventSamplingConfig::EveryNth(n) => Self::EveryNth(n),
+        }
+    }
+}
+
 /// Event export configuration
 #[derive(Debug, Clone, Deserialize, Serialize, Default, JsonSchema)]
 #[serde(default)]
@@ -1818,6 +1931,8 @@ pub struct EventExportConfig {
     pub file: Option<String>,
     /// Export events to webhook
     pub webhook: Option<String>,
+    /// Export events as OTLP log records to a collector (OTLP/HTTP base URL)
+    pub otlp_endpoint: Option<String>,
     /// Pretty-print JSON output
     pub pretty_json: bool,
 }
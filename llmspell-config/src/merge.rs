@@ -144,6 +144,12 @@ fn merge_security(base: &mut crate::SecurityConfig, source: crate::SecurityConfi
     if source.max_execution_time_ms.is_some() {
         base.max_execution_time_ms = source.max_execution_time_ms;
     }
+    if !source.allowed_env_vars.is_empty() {
+        base.allowed_env_vars = source.allowed_env_vars;
+    }
+    if !source.denied_env_vars.is_empty() {
+        base.denied_env_vars = source.denied_env_vars;
+    }
 }
 
 /// Merge state persistence configurations
@@ -343,6 +349,9 @@ fn merge_events(base: &mut EventsConfig, source: EventsConfig) {
     // Merge filtering
     merge_event_filter(&mut base.filtering, source.filtering);
 
+    // Sampling policies override per pattern
+    base.sampling.extend(source.sampling);
+
     // Merge export
     merge_event_export(&mut base.export, source.export);
 }
@@ -378,6 +387,9 @@ fn merge_event_export(base: &mut crate::EventExportConfig, source: crate::EventE
     if source.webhook.is_some() {
         base.webhook = source.webhook;
     }
+    if source.otlp_endpoint.is_some() {
+        base.otlp_endpoint = source.otlp_endpoint;
+    }
     if source.pretty_json != default_export.pretty_json {
         base.pretty_json = source.pretty_json;
     }
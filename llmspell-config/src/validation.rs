@@ -517,6 +517,23 @@ fn validate_events_config(config: &LLMSpellConfig) -> Result<(), ConfigError> {
         }
     }
 
+    // Validate sampling policies
+    for (pattern, sampling) in &events.sampling {
+        let valid = match *sampling {
+            crate::EventSamplingConfig::Rate(rate) => (0.0..=1.0).contains(&rate),
+            crate::EventSamplingConfig::EveryNth(n) => n > 0,
+        };
+        if !valid {
+            return Err(ConfigError::Validation {
+                field: Some("events.sampling".to_string()),
+                message: format!(
+                    "Sampling for '{}' must be a rate between 0.0 and 1.0 or every_nth above 0",
+                    pattern
+                ),
+            });
+        }
+    }
+
     // Debug log if all event types are disabled
     if !events.emit_timing_events && !events.emit_state_events && !events.emit_debug_events {
         debug!("All event types are disabled - event system will emit no events");
@@ -526,7 +543,12 @@ fn validate_events_config(config: &LLMSpellConfig) -> Result<(), ConfigError> {
     let export = &events.export;
 
     // Debug log if no export method is configured (informational, not a problem)
-    if events.enabled && !export.stdout && export.file.is_none() && export.webhook.is_none() {
+    if events.enabled
+        && !export.stdout
+        && export.file.is_none()
+        && export.webhook.is_none()
+        && export.otlp_endpoint.is_none()
+    {
         debug!("Events are enabled but no export method is configured - events will be generated but not output");
     }
 
@@ -579,6 +601,17 @@ fn validate_events_config(config: &LLMSpellConfig) -> Result<(), ConfigError> {
         }
     }
 
+    // Validate OTLP collector endpoint if specified
+    if let Some(endpoint) = &export.otlp_endpoint {
+        if !endpoint.starts_with("http://") && !endpoint.starts_with("https://") {
+            return Err(ConfigError::Validation {
+                field: Some("events.export.otlp_endpoint".to_string()),
+                message: "Event export OTLP endpoint must start with http:// or https://"
+                    .to_string(),
+            });
+        }
+    }
+
     Ok(())
 }
 
@@ -1087,6 +1120,59 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_validate_events_config_otlp_endpoint() {
+        let config_with = |endpoint: &str| LLMSpellConfig {
+            events: crate::EventsConfig {
+                export: crate::EventExportConfig {
+                    otlp_endpoint: Some(endpoint.to_string()),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        assert!(validate_events_config(&config_with("http://localhost:4318")).is_ok());
+
+        let result = validate_events_config(&config_with("localhost:4317"));
+        if let Err(ConfigError::Validation { field, .. }) = result {
+            assert_eq!(field, Some("events.export.otlp_endpoint".to_string()));
+        } else {
+            panic!("Expected validation error for OTLP endpoint without scheme");
+        }
+    }
+
+    #[test]
+    fn test_validate_events_config_sampling() {
+        let config_with = |sampling: crate::EventSamplingConfig| LLMSpellConfig {
+            events: crate::EventsConfig {
+                sampling: [("tool.progress".to_string(), sampling)].into(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        assert!(
+            validate_events_config(&config_with(crate::EventSamplingConfig::Rate(0.1))).is_ok()
+        );
+        assert!(
+            validate_events_config(&config_with(crate::EventSamplingConfig::EveryNth(10))).is_ok()
+        );
+
+        for invalid in [
+            crate::EventSamplingConfig::Rate(1.5),
+            crate::EventSamplingConfig::EveryNth(0),
+        ] {
+            let result = validate_events_config(&config_with(invalid));
+            if let Err(ConfigError::Validation { field, .. }) = result {
+                assert_eq!(field, Some("events.sampling".to_string()));
+            } else {
+                panic!("Expected validation error for sampling {:?}", invalid);
+            }
+        }
+    }
+
     #[test]
     fn test_validate_events_config_success() {
         let config = LLMSpellConfig {
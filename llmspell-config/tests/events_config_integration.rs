@@ -17,9 +17,11 @@ fn cleanup_event_env_vars() {
         "LLMSPELL_EVENTS_FILTERING_EXCLUDE_TYPES",
         "LLMSPELL_EVENTS_FILTERING_INCLUDE_COMPONENTS",
         "LLMSPELL_EVENTS_FILTERING_EXCLUDE_COMPONENTS",
+        "LLMSPELL_EVENTS_SAMPLING",
         "LLMSPELL_EVENTS_EXPORT_STDOUT",
         "LLMSPELL_EVENTS_EXPORT_FILE",
         "LLMSPELL_EVENTS_EXPORT_WEBHOOK",
+        "LLMSPELL_EVENTS_EXPORT_OTLP_ENDPOINT",
         "LLMSPELL_EVENTS_EXPORT_PRETTY_JSON",
     ];
 
@@ -50,6 +52,7 @@ fn test_events_config_defaults() {
     assert!(!events.export.stdout);
     assert_eq!(events.export.file, None);
     assert_eq!(events.export.webhook, None);
+    assert_eq!(events.export.otlp_endpoint, None);
     assert!(!events.export.pretty_json);
 }
 
@@ -170,6 +173,48 @@ fn test_events_config_env_overrides() {
     );
 }
 
+#[test]
+fn test_events_config_sampling_env_override() {
+    use llmspell_config::{env_registry::register_standard_vars, EnvRegistry, EventSamplingConfig};
+    use std::collections::HashMap;
+
+    let toml_content = r#"
+[events.sampling]
+"tool.progress" = { rate = 0.5 }
+"#;
+    let mut config: LLMSpellConfig = toml::from_str(toml_content).expect("Failed to parse TOML");
+
+    let registry = EnvRegistry::new();
+    register_standard_vars(&registry).expect("Failed to register standard vars");
+
+    let mut overrides = HashMap::new();
+    overrides.insert(
+        "LLMSPELL_EVENTS_SAMPLING".to_string(),
+        "tool.progress=0.1, stream.*=every:10".to_string(),
+    );
+    registry
+        .with_overrides(overrides)
+        .expect("Failed to set overrides");
+
+    let env_config = registry
+        .build_config()
+        .expect("Failed to build config from registry");
+    config
+        .merge_from_json(&env_config)
+        .expect("Failed to merge env config");
+
+    let sampling = &config.events.sampling;
+    assert_eq!(
+        sampling.get("tool.progress"),
+        Some(&EventSamplingConfig::Rate(0.1))
+    );
+    assert_eq!(
+        sampling.get("stream.*"),
+        Some(&EventSamplingConfig::EveryNth(10))
+    );
+    assert_eq!(config.events.sampling_policies().len(), 2);
+}
+
 #[test]
 fn test_events_config_toml_with_env_override() {
     use llmspell_config::{env_registry::register_standard_vars, EnvRegistry};
@@ -7,7 +7,7 @@
 //!
 //! 1. **Filter by Confidence**: Remove low-confidence chunks (< `min_confidence`)
 //! 2. **Sort by Temporal Order**: Recent chunks first (timestamp descending)
-//! 3. **Token Budget Enforcement**: Truncate to fit `max_tokens` limit
+//! 3. **Token Budget Enforcement**: Trim to fit `max_tokens` using a [`TruncationStrategy`]
 //! 4. **Metadata Preservation**: Keep timestamps, sources, confidence scores
 //!
 //! # Example
@@ -19,7 +19,9 @@
 //! let context = assembler.assemble(ranked_chunks, &query_understanding);
 //! ```
 
-use crate::types::{AssembledContext, QueryUnderstanding, RankedChunk};
+use crate::types::{
+    AssembledContext, QueryUnderstanding, RankedChunk, TruncationReport, TruncationStrategy,
+};
 use chrono::{DateTime, Utc};
 use tracing::{debug, trace};
 
@@ -31,8 +33,13 @@ pub struct ContextAssembler {
     max_tokens: usize,
     /// Minimum confidence threshold (0.0-1.0)
     min_confidence: f32,
+    /// How to trim chunks that exceed `max_tokens`
+    truncation_strategy: TruncationStrategy,
 }
 
+/// Marker inserted where `HeadTail` truncation elides the middle of a chunk
+const ELISION_MARKER: &str = " … ";
+
 impl ContextAssembler {
     /// Create a new context assembler with default settings
     ///
@@ -44,6 +51,7 @@ impl ContextAssembler {
         Self {
             max_tokens: 8000,
             min_confidence: 0.3,
+            truncation_strategy: TruncationStrategy::DropLowestScore,
         }
     }
 
@@ -58,9 +66,17 @@ impl ContextAssembler {
         Self {
             max_tokens,
             min_confidence,
+            truncation_strategy: TruncationStrategy::DropLowestScore,
         }
     }
 
+    /// Set how chunks exceeding the token budget are trimmed
+    #[must_use]
+    pub const fn with_truncation_strategy(mut self, strategy: TruncationStrategy) -> Self {
+        self.truncation_strategy = strategy;
+        self
+    }
+
     /// Assemble chunks into coherent context for LLM consumption
     ///
     /// # Arguments
@@ -73,7 +89,7 @@ impl ContextAssembler {
     /// 1. Filter chunks below `min_confidence` threshold
     /// 2. Sort by timestamp (recent first) for temporal coherence
     /// 3. Estimate token count (rough: 4 chars = 1 token)
-    /// 4. Truncate to fit token budget
+    /// 4. Trim to fit token budget with the configured `TruncationStrategy`
     /// 5. Calculate aggregate confidence score
     ///
     /// # Returns
@@ -122,7 +138,7 @@ impl ContextAssembler {
     /// Build final assembled context from filtered chunks
     fn build_context(&self, chunks: Vec<RankedChunk>) -> AssembledContext {
         // Step 3: Enforce token budget
-        let (selected_chunks, token_count) = self.enforce_token_budget(chunks);
+        let (selected_chunks, token_count, truncation) = self.enforce_token_budget(chunks);
         debug!(
             "After token budget: {} chunks ({} tokens, max: {})",
             selected_chunks.len(),
@@ -148,6 +164,7 @@ impl ContextAssembler {
             temporal_span,
             token_count,
             formatted,
+            truncation,
         }
     }
 
@@ -160,6 +177,7 @@ impl ContextAssembler {
             temporal_span: (now, now),
             token_count: 0,
             formatted: String::new(),
+            truncation: None,
         }
     }
 
@@ -215,31 +233,167 @@ impl ContextAssembler {
             .join("\n\n---\n\n")
     }
 
-    /// Enforce token budget by truncating chunks to fit `max_tokens`
+    /// Enforce token budget by trimming chunks to fit `max_tokens`
     ///
-    /// Uses rough tokenization estimate: 4 characters ≈ 1 token
-    fn enforce_token_budget(&self, chunks: Vec<RankedChunk>) -> (Vec<RankedChunk>, usize) {
-        let mut selected = Vec::new();
-        let mut token_count = 0;
+    /// Chunks keep their temporal order; returns the report of what was trimmed,
+    /// if anything. Uses rough tokenization estimate: 4 characters ≈ 1 token
+    fn enforce_token_budget(
+        &self,
+        chunks: Vec<RankedChunk>,
+    ) -> (Vec<RankedChunk>, usize, Option<TruncationReport>) {
+        let total_tokens: usize = chunks
+            .iter()
+            .map(|c| Self::estimate_tokens(&c.chunk.content))
+            .sum();
+        if total_tokens <= self.max_tokens {
+            return (chunks, total_tokens, None);
+        }
 
-        for chunk in chunks {
-            let chunk_tokens = Self::estimate_tokens(&chunk.chunk.content);
+        let mut report = TruncationReport {
+            strategy: self.truncation_strategy,
+            ..TruncationReport::default()
+        };
+        let selected = match self.truncation_strategy {
+            TruncationStrategy::DropLowestScore => self.drop_lowest_score(chunks, &mut report),
+            TruncationStrategy::ProportionalShrink | TruncationStrategy::HeadTail => {
+                self.shrink_proportionally(chunks, total_tokens, &mut report)
+            }
+        };
 
+        let token_count = selected
+            .iter()
+            .map(|c| Self::estimate_tokens(&c.chunk.content))
+            .sum();
+        report.tokens_removed = total_tokens - token_count;
+        debug!(
+            "Truncated context with {:?}: {} chunks dropped, {} shortened, {} tokens removed",
+            report.strategy,
+            report.dropped_chunk_ids.len(),
+            report.truncated_chunk_ids.len(),
+            report.tokens_removed
+        );
+
+        (selected, token_count, Some(report))
+    }
+
+    /// Keep chunks in descending score order while they fit, dropping the rest
+    fn drop_lowest_score(
+        &self,
+        chunks: Vec<RankedChunk>,
+        report: &mut TruncationReport,
+    ) -> Vec<RankedChunk> {
+        let mut by_score: Vec<usize> = (0..chunks.len()).collect();
+        by_score.sort_by(|&a, &b| chunks[b].score.total_cmp(&chunks[a].score));
+
+        let mut keep = vec![false; chunks.len()];
+        let mut token_count = 0;
+        for index in by_score {
+            let chunk_tokens = Self::estimate_tokens(&chunks[index].chunk.content);
             if token_count + chunk_tokens <= self.max_tokens {
                 token_count += chunk_tokens;
-                selected.push(chunk);
-            } else {
-                trace!(
-                    "Skipping chunk (would exceed budget): {} tokens + {} > {}",
-                    token_count,
-                    chunk_tokens,
-                    self.max_tokens
-                );
-                break;
+                keep[index] = true;
             }
         }
 
-        (selected, token_count)
+        chunks
+            .into_iter()
+            .zip(keep)
+            .filter_map(|(chunk, keep)| {
+                if !keep {
+                    trace!(
+                        "Dropping chunk {} (score {:.2})",
+                        chunk.chunk.id,
+                        chunk.score
+                    );
+                    report.dropped_chunk_ids.push(chunk.chunk.id.clone());
+                }
+                keep.then_some(chunk)
+            })
+            .collect()
+    }
+
+    /// Keep the top-scored chunk intact and shrink the others into the remaining budget
+    ///
+    /// Each other chunk gets a share of the remaining budget proportional to its
+    /// size; chunks whose share rounds to zero tokens are dropped.
+    fn shrink_proportionally(
+        &self,
+        chunks: Vec<RankedChunk>,
+        total_tokens: usize,
+        report: &mut TruncationReport,
+    ) -> Vec<RankedChunk> {
+        let Some(top) =
+            (0..chunks.len()).max_by(|&a, &b| chunks[a].score.total_cmp(&chunks[b].score))
+        else {
+            return chunks;
+        };
+        let top_tokens = Self::estimate_tokens(&chunks[top].chunk.content);
+        let remaining = self.max_tokens.saturating_sub(top_tokens);
+        let others_tokens = total_tokens - top_tokens;
+
+        chunks
+            .into_iter()
+            .enumerate()
+            .filter_map(|(index, mut chunk)| {
+                let chunk_tokens = Self::estimate_tokens(&chunk.chunk.content);
+                let allotted = if index == top {
+                    chunk_tokens.min(self.max_tokens)
+                } else {
+                    chunk_tokens * remaining / others_tokens.max(1)
+                };
+
+                if allotted == 0 {
+                    report.dropped_chunk_ids.push(chunk.chunk.id.clone());
+                    return None;
+                }
+                if allotted < chunk_tokens {
+                    chunk.chunk.content =
+                        if self.truncation_strategy == TruncationStrategy::HeadTail {
+                            Self::truncate_head_tail(&chunk.chunk.content, allotted)
+                        } else {
+                            Self::truncate_tail(&chunk.chunk.content, allotted)
+                        };
+                    report.truncated_chunk_ids.push(chunk.chunk.id.clone());
+                }
+                Some(chunk)
+            })
+            .collect()
+    }
+
+    /// Keep the start of `text` within `max_tokens`
+    fn truncate_tail(text: &str, max_tokens: usize) -> String {
+        let end = Self::floor_char_boundary(text, max_tokens * 4);
+        text[..end].to_string()
+    }
+
+    /// Keep the start and end of `text` within `max_tokens`, eliding the middle
+    fn truncate_head_tail(text: &str, max_tokens: usize) -> String {
+        let max_bytes = max_tokens * 4;
+        if max_bytes <= ELISION_MARKER.len() || text.len() <= max_bytes {
+            return Self::truncate_tail(text, max_tokens);
+        }
+
+        let keep = max_bytes - ELISION_MARKER.len();
+        let head_end = Self::floor_char_boundary(text, keep / 2);
+        let mut tail_start = text.len() - (keep - head_end);
+        while !text.is_char_boundary(tail_start) {
+            tail_start += 1;
+        }
+
+        format!(
+            "{}{ELISION_MARKER}{}",
+            &text[..head_end],
+            &text[tail_start..]
+        )
+    }
+
+    /// Largest char boundary in `text` at or below `index`
+    fn floor_char_boundary(text: &str, index: usize) -> usize {
+        let mut index = index.min(text.len());
+        while !text.is_char_boundary(index) {
+            index -= 1;
+        }
+        index
     }
 
     /// Estimate token count for text
@@ -409,6 +563,65 @@ mod tests {
         assert!(duration.num_days() >= 6);
     }
 
+    #[test]
+    fn test_truncation_strategies_keep_top_chunk() {
+        let now = Utc::now();
+        let chunk = |id: &str, content: String, score: f32| RankedChunk {
+            chunk: Chunk {
+                id: id.to_string(),
+                content,
+                source: "memory".to_string(),
+                timestamp: now,
+                metadata: None,
+            },
+            score,
+            ranker: "bm25".to_string(),
+        };
+        let top_content = "Rust guarantees memory safety without a garbage collector".to_string();
+        let chunks = vec![
+            chunk("low", "Unrelated notes about lunch plans. ".repeat(10), 0.4),
+            chunk("top", top_content.clone(), 0.95),
+            chunk(
+                "mid",
+                "Borrow checker background and history. ".repeat(8),
+                0.7,
+            ),
+        ];
+        let query = QueryUnderstanding {
+            intent: QueryIntent::Unknown,
+            entities: vec![],
+            keywords: vec![],
+        };
+
+        for strategy in [
+            TruncationStrategy::DropLowestScore,
+            TruncationStrategy::ProportionalShrink,
+            TruncationStrategy::HeadTail,
+        ] {
+            let assembler =
+                ContextAssembler::with_config(60, 0.0).with_truncation_strategy(strategy);
+            let context = assembler.assemble(chunks.clone(), &query);
+
+            assert!(context.token_count <= 60, "{strategy:?} exceeded budget");
+            let top = context
+                .chunks
+                .iter()
+                .find(|c| c.chunk.id == "top")
+                .expect("top chunk kept");
+            assert_eq!(top.chunk.content, top_content);
+
+            let report = context.truncation.expect("truncation recorded");
+            assert_eq!(report.strategy, strategy);
+            assert!(report.tokens_removed > 0);
+            assert!(!report.dropped_chunk_ids.contains(&"top".to_string()));
+            assert!(!report.truncated_chunk_ids.contains(&"top".to_string()));
+        }
+
+        // Nothing is recorded when everything fits
+        let context = ContextAssembler::with_config(8000, 0.0).assemble(chunks, &query);
+        assert!(context.truncation.is_none());
+    }
+
     #[test]
     fn test_estimate_tokens() {
         // 4 chars ≈ 1 token (rough estimate)
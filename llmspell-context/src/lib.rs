@@ -27,11 +27,17 @@
 //! use llmspell_context::prelude::*;
 //!
 //! let pipeline = ContextPipeline::builder()
-//!     .with_reranker(DeBERTaReranker::new().await?)
+//!     .with_retriever(RetrievalStrategy::Episodic, episodic_retriever)
+//!     .with_retriever(RetrievalStrategy::BM25, keyword_retriever)
+//!     .with_reranker(Arc::new(DeBERTaReranker::new().await?))
 //!     .with_assembler(ContextAssembler::default())
 //!     .build()?;
 //!
-//! let context = pipeline.process_query("What is Rust?", &memory, &graph).await?;
+//! // Auto-selected strategy
+//! let context = pipeline.process_query("What is Rust?", None).await?;
+//!
+//! // Explicit strategy, bypassing selection
+//! let context = pipeline.process_query("tokio runtime", Some(RetrievalStrategy::BM25)).await?;
 //! ```
 
 #![warn(missing_docs)]
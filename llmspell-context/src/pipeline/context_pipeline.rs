@@ -0,0 +1,257 @@
+//! Context pipeline composing query understanding, retrieval, reranking and assembly
+//!
+//! Retrievers are registered per [`RetrievalStrategy`]. By default the strategy is
+//! chosen by [`StrategySelector`] from the query understanding; callers can pass an
+//! explicit strategy to override it for a single query.
+
+use crate::assembly::ContextAssembler;
+use crate::error::{ContextError, Result};
+use crate::query::RegexQueryAnalyzer;
+use crate::reranking::BM25Reranker;
+use crate::retrieval::StrategySelector;
+use crate::traits::{QueryAnalyzer, Reranker, Retriever};
+use crate::types::{AssembledContext, RetrievalStrategy};
+use std::collections::HashMap;
+use std::sync::Arc;
+use tracing::{debug, info};
+
+/// Default number of chunks retrieved and kept after reranking
+const DEFAULT_TOP_K: usize = 10;
+
+/// End-to-end context pipeline
+///
+/// Query → Understanding → Strategy → Retrieval → Reranking → Assembly
+pub struct ContextPipeline {
+    analyzer: Arc<dyn QueryAnalyzer>,
+    selector: StrategySelector,
+    retrievers: HashMap<RetrievalStrategy, Arc<dyn Retriever>>,
+    reranker: Arc<dyn Reranker>,
+    assembler: ContextAssembler,
+    top_k: usize,
+}
+
+impl ContextPipeline {
+    /// Create a pipeline builder
+    #[must_use]
+    pub fn builder() -> ContextPipelineBuilder {
+        ContextPipelineBuilder::new()
+    }
+
+    /// Assemble context for a query
+    ///
+    /// With `strategy` set, that retriever is used and strategy selection is
+    /// skipped; query understanding, reranking and assembly still run. With
+    /// `None`, the strategy is selected from the query understanding.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ContextError::RetrievalError` if no retriever is registered for
+    /// the strategy, or any error from the pipeline stages
+    pub async fn process_query(
+        &self,
+        query: &str,
+        strategy: Option<RetrievalStrategy>,
+    ) -> Result<AssembledContext> {
+        let understanding = self.analyzer.understand(query).await?;
+
+        let strategy = if let Some(strategy) = strategy {
+            debug!("Using explicit {:?} strategy, skipping selection", strategy);
+            strategy
+        } else {
+            self.selector.select(&understanding)
+        };
+
+        let retriever = self.retrievers.get(&strategy).ok_or_else(|| {
+            ContextError::RetrievalError(format!(
+                "No retriever registered for {strategy:?} strategy"
+            ))
+        })?;
+
+        let chunks = retriever.retrieve(query, self.top_k).await?;
+        info!(
+            "Retrieved {} chunks with {:?} strategy",
+            chunks.len(),
+            strategy
+        );
+
+        let ranked = self.reranker.rerank(chunks, query, self.top_k).await?;
+
+        Ok(self.assembler.assemble(ranked, &understanding))
+    }
+}
+
+/// Builder for [`ContextPipeline`]
+///
+/// Defaults to [`RegexQueryAnalyzer`], [`StrategySelector::new`], [`BM25Reranker`]
+/// and [`ContextAssembler::new`]. At least one retriever is required.
+pub struct ContextPipelineBuilder {
+    analyzer: Option<Arc<dyn QueryAnalyzer>>,
+    selector: StrategySelector,
+    retrievers: HashMap<RetrievalStrategy, Arc<dyn Retriever>>,
+    reranker: Option<Arc<dyn Reranker>>,
+    assembler: ContextAssembler,
+    top_k: usize,
+}
+
+impl ContextPipelineBuilder {
+    /// Create a builder with default components and no retrievers
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            analyzer: None,
+            selector: StrategySelector::new(),
+            retrievers: HashMap::new(),
+            reranker: None,
+            assembler: ContextAssembler::new(),
+            top_k: DEFAULT_TOP_K,
+        }
+    }
+
+    /// Set the query analyzer
+    #[must_use]
+    pub fn with_analyzer(mut self, analyzer: Arc<dyn QueryAnalyzer>) -> Self {
+        self.analyzer = Some(analyzer);
+        self
+    }
+
+    /// Set the strategy selector used when no strategy is given
+    #[must_use]
+    pub const fn with_selector(mut self, selector: StrategySelector) -> Self {
+        self.selector = selector;
+        self
+    }
+
+    /// Register the retriever for a strategy, replacing any existing one
+    #[must_use]
+    pub fn with_retriever(
+        mut self,
+        strategy: RetrievalStrategy,
+        retriever: Arc<dyn Retriever>,
+    ) -> Self {
+        self.retrievers.insert(strategy, retriever);
+        self
+    }
+
+    /// Set the reranker
+    #[must_use]
+    pub fn with_reranker(mut self, reranker: Arc<dyn Reranker>) -> Self {
+        self.reranker = Some(reranker);
+        self
+    }
+
+    /// Set the assembler
+    #[must_use]
+    pub const fn with_assembler(mut self, assembler: ContextAssembler) -> Self {
+        self.assembler = assembler;
+        self
+    }
+
+    /// Set how many chunks are retrieved and kept after reranking
+    #[must_use]
+    pub const fn with_top_k(mut self, top_k: usize) -> Self {
+        self.top_k = top_k;
+        self
+    }
+
+    /// Build the pipeline
+    ///
+    /// # Errors
+    ///
+    /// Returns `ContextError::ConfigError` if no retriever is registered
+    pub fn build(self) -> Result<ContextPipeline> {
+        if self.retrievers.is_empty() {
+            return Err(ContextError::ConfigError(
+                "Context pipeline requires at least one retriever".to_string(),
+            ));
+        }
+
+        Ok(ContextPipeline {
+            analyzer: self
+                .analyzer
+                .unwrap_or_else(|| Arc::new(RegexQueryAnalyzer::new())),
+            selector: self.selector,
+            retrievers: self.retrievers,
+            reranker: self
+                .reranker
+                .unwrap_or_else(|| Arc::new(BM25Reranker::new())),
+            assembler: self.assembler,
+            top_k: self.top_k,
+        })
+    }
+}
+
+impl Default for ContextPipelineBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Chunk;
+    use async_trait::async_trait;
+    use chrono::Utc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// Retriever returning one fixed chunk tagged with its source
+    struct StubRetriever {
+        source: &'static str,
+        calls: AtomicUsize,
+    }
+
+    impl StubRetriever {
+        fn new(source: &'static str) -> Arc<Self> {
+            Arc::new(Self {
+                source,
+                calls: AtomicUsize::new(0),
+            })
+        }
+    }
+
+    #[async_trait]
+    impl Retriever for StubRetriever {
+        async fn retrieve(&self, _query: &str, _top_k: usize) -> Result<Vec<Chunk>> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            Ok(vec![Chunk {
+                id: format!("{}-1", self.source),
+                content: "Use HashMap in Rust to map keys to values".to_string(),
+                source: self.source.to_string(),
+                timestamp: Utc::now(),
+                metadata: None,
+            }])
+        }
+    }
+
+    #[tokio::test]
+    async fn test_explicit_strategy_overrides_selection() {
+        let episodic = StubRetriever::new("episodic");
+        let bm25 = StubRetriever::new("bm25");
+        let pipeline = ContextPipeline::builder()
+            .with_retriever(RetrievalStrategy::Episodic, episodic.clone())
+            .with_retriever(RetrievalStrategy::BM25, bm25.clone())
+            .build()
+            .unwrap();
+
+        // HowTo queries are routed to episodic (vector) retrieval
+        let query = "How do I use HashMap in Rust?";
+        let auto = pipeline.process_query(query, None).await.unwrap();
+        assert_eq!(episodic.calls.load(Ordering::SeqCst), 1);
+        assert_eq!(bm25.calls.load(Ordering::SeqCst), 0);
+        assert_eq!(auto.chunks[0].chunk.source, "episodic");
+
+        let forced = pipeline
+            .process_query(query, Some(RetrievalStrategy::BM25))
+            .await
+            .unwrap();
+        assert_eq!(episodic.calls.load(Ordering::SeqCst), 1);
+        assert_eq!(bm25.calls.load(Ordering::SeqCst), 1);
+        assert_eq!(forced.chunks.len(), 1);
+        assert_eq!(forced.chunks[0].chunk.source, "bm25");
+
+        let missing = pipeline
+            .process_query(query, Some(RetrievalStrategy::Semantic))
+            .await;
+        assert!(matches!(missing, Err(ContextError::RetrievalError(_))));
+    }
+}
@@ -2,4 +2,6 @@
 //!
 //! Orchestrates the full context engineering pipeline from query to assembled context.
 
-// Module will be implemented after Tasks 13.4.1-13.4.5
+pub mod context_pipeline;
+
+pub use context_pipeline::{ContextPipeline, ContextPipelineBuilder};
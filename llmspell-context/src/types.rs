@@ -32,7 +32,7 @@ pub struct QueryUnderstanding {
 }
 
 /// Retrieval strategy selection
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum RetrievalStrategy {
     /// Recent interactions (vector similarity)
     Episodic,
@@ -83,6 +83,36 @@ pub struct AssembledContext {
     pub token_count: usize,
     /// Formatted context string
     pub formatted: String,
+    /// What was trimmed to fit the token budget (None if nothing was)
+    #[serde(default)]
+    pub truncation: Option<TruncationReport>,
+}
+
+/// How the assembler trims chunks that exceed the token budget
+///
+/// Every strategy keeps the highest-scored chunk intact when it fits the budget.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TruncationStrategy {
+    /// Drop whole chunks, lowest score first
+    #[default]
+    DropLowestScore,
+    /// Shrink the other chunks in proportion to their size, cutting their tails
+    ProportionalShrink,
+    /// Shrink like `ProportionalShrink` but keep each chunk's head and tail, eliding the middle
+    HeadTail,
+}
+
+/// Record of chunks trimmed to fit the token budget
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TruncationReport {
+    /// Strategy that was applied
+    pub strategy: TruncationStrategy,
+    /// IDs of chunks dropped entirely
+    pub dropped_chunk_ids: Vec<String>,
+    /// IDs of chunks kept with shortened content
+    pub truncated_chunk_ids: Vec<String>,
+    /// Estimated tokens removed
+    pub tokens_removed: usize,
 }
 
 /// BM25 parameters
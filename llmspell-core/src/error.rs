@@ -1,6 +1,7 @@
 //! ABOUTME: Error types and handling for rs-llmspell
 //! ABOUTME: Provides `LLMSpellError` enum and `Result` type alias
 
+use std::time::Duration;
 use thiserror::Error;
 use tracing::error;
 
@@ -195,6 +196,9 @@ pub enum LLMSpellError {
         duration_ms: Option<u64>,
     },
 
+    #[error("Execution cancelled: {message}")]
+    Cancelled { message: String },
+
     #[error("Network error: {message}")]
     Network {
         message: String,
@@ -232,9 +236,10 @@ impl LLMSpellError {
             Self::Provider { .. } | Self::Network { .. } | Self::RateLimit { .. } => {
                 ErrorCategory::Network
             }
-            Self::Resource { .. } | Self::Timeout { .. } | Self::ResourceLimit { .. } => {
-                ErrorCategory::Resource
-            }
+            Self::Resource { .. }
+            | Self::Timeout { .. }
+            | Self::Cancelled { .. }
+            | Self::ResourceLimit { .. } => ErrorCategory::Resource,
             Self::Security { .. } => ErrorCategory::Security,
             Self::Validation { .. } | Self::Component { .. } => ErrorCategory::Logic,
             Self::Tool { .. } | Self::Script { .. } | Self::Workflow { .. } => {
@@ -248,7 +253,9 @@ impl LLMSpellError {
     #[must_use]
     pub fn severity(&self) -> ErrorSeverity {
         match self {
-            Self::Validation { .. } | Self::Timeout { .. } => ErrorSeverity::Warning,
+            Self::Validation { .. } | Self::Timeout { .. } | Self::Cancelled { .. } => {
+                ErrorSeverity::Warning
+            }
             Self::Configuration { .. } => ErrorSeverity::Error,
             Self::Security { .. } => ErrorSeverity::Critical,
             Self::Internal { .. } => ErrorSeverity::Fatal,
@@ -276,6 +283,7 @@ impl LLMSpellError {
             | Self::Validation { .. }
             | Self::Internal { .. }
             | Self::ResourceLimit { .. } // Resource limits are not retryable
+            | Self::Cancelled { .. }
             | Self::Component { .. }
             | Self::Tool { .. }
             | Self::Script { .. }
@@ -298,13 +306,18 @@ impl LLMSpellError {
             Self::Provider { .. } => Some(2000), // 2 seconds
             Self::Resource { .. } => Some(500),  // 500ms
             Self::Storage { .. } => Some(100),   // 100ms
-            Self::Network { .. } | Self::RateLimit { .. } => Some(1000), // 1 second
+            Self::Network { .. } => Some(1000),  // 1 second
+            // Honor the server's hint (in seconds) when one was given
+            Self::RateLimit { retry_after, .. } => {
+                Some(retry_after.map_or(1000, |secs| secs.saturating_mul(1000)))
+            }
             // These errors are not retryable so shouldn't reach here
             Self::Security { .. }
             | Self::Configuration { .. }
             | Self::Validation { .. }
             | Self::Internal { .. }
             | Self::ResourceLimit { .. }
+            | Self::Cancelled { .. }
             | Self::Component { .. }
             | Self::Tool { .. }
             | Self::Script { .. }
@@ -312,6 +325,14 @@ impl LLMSpellError {
         }
     }
 
+    /// Get the suggested delay before retrying, if the error is retryable
+    ///
+    /// Rate-limit errors carrying a `retry_after` hint report that delay.
+    #[must_use]
+    pub fn retry_after(&self) -> Option<Duration> {
+        self.retry_delay_ms().map(Duration::from_millis)
+    }
+
     /// Chain with another error as the source
     pub fn with_source<E>(mut self, source: E) -> Self
     where
@@ -545,6 +566,79 @@ mod tests {
         assert_eq!(security_err.retry_delay_ms(), None);
     }
     #[test]
+    fn test_retry_classification_per_variant() {
+        let rate_limited = LLMSpellError::RateLimit {
+            message: "Too many requests".to_string(),
+            retry_after: Some(30),
+        };
+        assert!(rate_limited.is_retryable());
+        assert_eq!(rate_limited.retry_after(), Some(Duration::from_secs(30)));
+
+        let rate_limited_no_hint = LLMSpellError::RateLimit {
+            message: "Too many requests".to_string(),
+            retry_after: None,
+        };
+        assert!(rate_limited_no_hint.is_retryable());
+        assert_eq!(
+            rate_limited_no_hint.retry_after(),
+            Some(Duration::from_secs(1))
+        );
+
+        let network_err = LLMSpellError::Network {
+            message: "Connection reset".to_string(),
+            source: None,
+        };
+        assert!(network_err.is_retryable());
+        assert_eq!(network_err.retry_after(), Some(Duration::from_secs(1)));
+
+        let timeout_err = LLMSpellError::Timeout {
+            message: "Timed out".to_string(),
+            duration_ms: Some(250),
+        };
+        assert!(timeout_err.is_retryable());
+        assert_eq!(timeout_err.retry_after(), Some(Duration::from_millis(500)));
+
+        let provider_err = LLMSpellError::Provider {
+            message: "Upstream unavailable".to_string(),
+            provider: Some("openai".to_string()),
+            source: None,
+        };
+        assert!(provider_err.is_retryable());
+        assert_eq!(provider_err.retry_after(), Some(Duration::from_secs(2)));
+
+        let non_retryable = [
+            LLMSpellError::Validation {
+                message: "Invalid".to_string(),
+                field: Some("url".to_string()),
+            },
+            LLMSpellError::Security {
+                message: "Forbidden".to_string(),
+                violation_type: None,
+            },
+            LLMSpellError::Configuration {
+                message: "Missing key".to_string(),
+                source: None,
+            },
+            LLMSpellError::Tool {
+                message: "Bad input".to_string(),
+                tool_name: None,
+                source: None,
+            },
+            LLMSpellError::Cancelled {
+                message: "Stopped".to_string(),
+            },
+            LLMSpellError::ResourceLimit {
+                resource: "memory".to_string(),
+                limit: 10,
+                used: 20,
+            },
+        ];
+        for err in &non_retryable {
+            assert!(!err.is_retryable(), "{err} should not be retryable");
+            assert_eq!(err.retry_after(), None);
+        }
+    }
+    #[test]
     fn test_storage_error_retryability() {
         let read_err = LLMSpellError::Storage {
             message: "Read failed".to_string(),
@@ -9,6 +9,7 @@ use serde_json::Value;
 use std::collections::HashMap;
 use std::fmt;
 use std::sync::{Arc, RwLock};
+use std::time::{Duration, Instant};
 use tracing::{debug, info, trace};
 
 /// Context inheritance policy
@@ -179,6 +180,10 @@ pub struct ExecutionContext {
 
     /// Security context (placeholder for Phase 3.2 integration)
     pub security_context: Option<SecurityContext>,
+
+    /// Absolute deadline for this execution, shared with child contexts
+    #[serde(skip)]
+    pub deadline: Option<Instant>,
 }
 
 /// Security context placeholder
@@ -208,6 +213,7 @@ impl ExecutionContext {
             events: None,
             metadata: EventMetadata::default(),
             security_context: None,
+            deadline: None,
         }
     }
 
@@ -243,6 +249,7 @@ impl ExecutionContext {
             events: self.events.clone(),               // Events are shared across hierarchy
             metadata: self.metadata.clone(),
             security_context: self.security_context.clone(),
+            deadline: self.deadline,
         };
 
         // Apply inheritance policy
@@ -370,6 +377,18 @@ impl ExecutionContext {
         self
     }
 
+    /// Set the absolute deadline (builder pattern)
+    pub fn with_deadline(mut self, deadline: Instant) -> Self {
+        self.deadline = Some(deadline);
+        self
+    }
+
+    /// Time left before the deadline, if one is set
+    pub fn remaining_time(&self) -> Option<Duration> {
+        self.deadline
+            .map(|deadline| deadline.saturating_duration_since(Instant::now()))
+    }
+
     /// Check if context has a specific capability
     pub fn has_capability(&self, capability: &str) -> bool {
         self.get("capabilities")
@@ -491,6 +510,12 @@ impl ExecutionContextBuilder {
         self
     }
 
+    /// Set absolute deadline
+    pub fn deadline(mut self, deadline: Instant) -> Self {
+        self.context.deadline = Some(deadline);
+        self
+    }
+
     /// Build the context
     pub fn build(self) -> ExecutionContext {
         self.context
@@ -530,6 +555,16 @@ mod tests {
         // HierarchicalContext should handle inheritance
     }
     #[test]
+    fn test_child_shares_deadline() {
+        let deadline = Instant::now() + Duration::from_secs(60);
+        let root = ExecutionContext::new().with_deadline(deadline);
+
+        let child = root.create_child(ContextScope::Global, InheritancePolicy::Isolate);
+        assert_eq!(child.deadline, Some(deadline));
+        assert!(child.remaining_time().unwrap() <= Duration::from_secs(60));
+        assert!(ExecutionContext::new().remaining_time().is_none());
+    }
+    #[test]
     fn test_inheritance_policies() {
         let parent = ExecutionContext::new()
             .with_data("parent_key".to_string(), json!("parent_value"))
@@ -151,7 +151,7 @@ pub use traits::{
     context_assembler::ContextAssembler,
     debug_context::{DebugContext, MockDebugContext, NoOpDebugContext},
     embedding::EmbeddingProvider,
-    event::{EventConfig, EventData, EventEmitter},
+    event::{EventConfig, EventData, EventEmitter, EventSampler, EventSampling, SamplingDecision},
     state::StateAccess,
     tenant_scoped::TenantScoped,
     tool::Tool,
@@ -1,14 +1,41 @@
 //! ABOUTME: Structured logging infrastructure for rs-llmspell
 //! ABOUTME: Provides tracing setup with JSON formatting and runtime configuration
 
-use tracing::Level;
+use std::collections::HashSet;
+use std::sync::Arc;
+use tracing::field::{Field, Visit};
+use tracing::{Event, Level, Subscriber};
 use tracing_subscriber::{
-    fmt::{self, format::FmtSpan},
+    field::RecordFields,
+    fmt::{
+        self,
+        format::{FmtSpan, Writer},
+        FmtContext, FormatEvent, FormatFields, MakeWriter,
+    },
     layer::SubscriberExt,
+    registry::LookupSpan,
     util::SubscriberInitExt,
     EnvFilter, Layer,
 };
 
+/// Replacement text for redacted field values
+pub const REDACTED: &str = "[REDACTED]";
+
+/// Field names redacted by default
+pub const DEFAULT_REDACTED_FIELDS: &[&str] = &[
+    "api_key",
+    "apikey",
+    "password",
+    "passwd",
+    "secret",
+    "client_secret",
+    "token",
+    "access_token",
+    "refresh_token",
+    "auth_token",
+    "authorization",
+];
+
 /// Logging configuration for the `LLMSpell` system.
 ///
 /// Controls various aspects of log output including format, level,
@@ -38,6 +65,7 @@ use tracing_subscriber::{
 ///     with_thread_ids: false,
 ///     with_file_lines: true,
 ///     with_span_events: false,
+///     redacted_fields: vec!["api_key".to_string(), "session_cookie".to_string()],
 /// };
 /// ```
 #[derive(Debug, Clone)]
@@ -56,6 +84,15 @@ pub struct LoggingConfig {
     pub with_file_lines: bool,
     /// Whether to include span events
     pub with_span_events: bool,
+    /// Field names whose values are masked in all log output (case-insensitive)
+    pub redacted_fields: Vec<String>,
+}
+
+fn default_redacted_fields() -> Vec<String> {
+    DEFAULT_REDACTED_FIELDS
+        .iter()
+        .map(ToString::to_string)
+        .collect()
 }
 
 impl Default for LoggingConfig {
@@ -68,6 +105,7 @@ impl Default for LoggingConfig {
             with_thread_ids: false,
             with_file_lines: true,
             with_span_events: false,
+            redacted_fields: default_redacted_fields(),
         }
     }
 }
@@ -84,6 +122,7 @@ impl LoggingConfig {
             with_thread_ids: false,
             with_file_lines: true,
             with_span_events: true,
+            redacted_fields: default_redacted_fields(),
         }
     }
 
@@ -121,44 +160,226 @@ pub fn init_logging(config: LoggingConfig) -> Result<(), Box<dyn std::error::Err
     let env_filter = EnvFilter::try_from_default_env()
         .unwrap_or_else(|_| EnvFilter::new(config.default_level.to_string()));
 
-    // Configure format layer
-    let fmt_layer = if config.json_format {
-        fmt::layer()
+    // Build the subscriber
+    tracing_subscriber::registry()
+        .with(env_filter)
+        .with(fmt_layer(&config, std::io::stdout))
+        .try_init()?;
+
+    Ok(())
+}
+
+/// Build the formatting layer for a configuration, writing to `make_writer`
+///
+/// Values of fields named in `redacted_fields` are replaced with
+/// [`REDACTED`] in both event and span fields, in JSON and text output.
+/// Text output uses the single-line formatter, since the pretty formatter
+/// writes event fields without going through the field formatter.
+#[must_use]
+pub fn fmt_layer<S, W>(config: &LoggingConfig, make_writer: W) -> Box<dyn Layer<S> + Send + Sync>
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+    W: for<'w> MakeWriter<'w> + Send + Sync + 'static,
+{
+    let redactor = FieldRedactor::new(&config.redacted_fields);
+    let span_events = if config.with_span_events {
+        FmtSpan::FULL
+    } else {
+        FmtSpan::NONE
+    };
+
+    if config.json_format {
+        let format = fmt::format()
             .json()
             .with_timer(fmt::time::UtcTime::rfc_3339())
             .with_thread_names(config.with_thread_names)
             .with_thread_ids(config.with_thread_ids)
             .with_file(config.with_file_lines)
-            .with_line_number(config.with_file_lines)
-            .with_span_events(if config.with_span_events {
-                FmtSpan::FULL
-            } else {
-                FmtSpan::NONE
-            })
+            .with_line_number(config.with_file_lines);
+        fmt::layer()
+            .json()
+            .with_span_events(span_events)
+            .event_format(RedactingJsonFormat::new(format, redactor))
+            .with_writer(make_writer)
             .boxed()
     } else {
         fmt::layer()
-            .pretty()
             .with_timer(fmt::time::UtcTime::rfc_3339())
             .with_thread_names(config.with_thread_names)
             .with_thread_ids(config.with_thread_ids)
             .with_file(config.with_file_lines)
             .with_line_number(config.with_file_lines)
-            .with_span_events(if config.with_span_events {
-                FmtSpan::FULL
-            } else {
-                FmtSpan::NONE
-            })
+            .with_span_events(span_events)
+            .fmt_fields(RedactingFields::new(redactor))
+            .with_writer(make_writer)
             .boxed()
-    };
+    }
+}
 
-    // Build the subscriber
-    tracing_subscriber::registry()
-        .with(env_filter)
-        .with(fmt_layer)
-        .try_init()?;
+/// Matches log field names against a denylist of sensitive names
+#[derive(Debug, Clone, Default)]
+pub struct FieldRedactor {
+    fields: Arc<HashSet<String>>,
+}
 
-    Ok(())
+impl FieldRedactor {
+    /// Create a redactor for the given field names (matched case-insensitively)
+    #[must_use]
+    pub fn new<I, N>(fields: I) -> Self
+    where
+        I: IntoIterator<Item = N>,
+        N: AsRef<str>,
+    {
+        Self {
+            fields: Arc::new(
+                fields
+                    .into_iter()
+                    .map(|name| name.as_ref().to_ascii_lowercase())
+                    .collect(),
+            ),
+        }
+    }
+
+    /// Check if a field's value must be masked
+    #[must_use]
+    pub fn is_sensitive(&self, name: &str) -> bool {
+        self.fields.contains(&name.to_ascii_lowercase())
+    }
+
+    /// Mask sensitive keys anywhere in a JSON value
+    pub fn redact_json(&self, value: &mut serde_json::Value) {
+        match value {
+            serde_json::Value::Object(map) => {
+                for (key, value) in map.iter_mut() {
+                    if self.is_sensitive(key) {
+                        *value = serde_json::Value::String(REDACTED.to_string());
+                    } else {
+                        self.redact_json(value);
+                    }
+                }
+            }
+            serde_json::Value::Array(items) => {
+                for item in items {
+                    self.redact_json(item);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Field formatter writing `name=value` pairs with sensitive values masked
+#[derive(Debug, Clone, Default)]
+pub struct RedactingFields {
+    redactor: FieldRedactor,
+}
+
+impl RedactingFields {
+    /// Create a field formatter using the given redactor
+    #[must_use]
+    pub const fn new(redactor: FieldRedactor) -> Self {
+        Self { redactor }
+    }
+}
+
+impl<'writer> FormatFields<'writer> for RedactingFields {
+    fn format_fields<R: RecordFields>(
+        &self,
+        writer: Writer<'writer>,
+        fields: R,
+    ) -> std::fmt::Result {
+        let mut visitor = RedactingVisitor {
+            writer,
+            redactor: &self.redactor,
+            is_empty: true,
+            result: Ok(()),
+        };
+        fields.record(&mut visitor);
+        visitor.result
+    }
+}
+
+struct RedactingVisitor<'a, 'writer> {
+    writer: Writer<'writer>,
+    redactor: &'a FieldRedactor,
+    is_empty: bool,
+    result: std::fmt::Result,
+}
+
+impl RedactingVisitor<'_, '_> {
+    fn write_field(&mut self, field: &Field, value: std::fmt::Arguments<'_>) {
+        if self.result.is_err() {
+            return;
+        }
+        let separator = if self.is_empty { "" } else { " " };
+        self.is_empty = false;
+        self.result = if self.redactor.is_sensitive(field.name()) {
+            write!(self.writer, "{separator}{}={REDACTED}", field.name())
+        } else if field.name() == "message" {
+            write!(self.writer, "{separator}{value}")
+        } else {
+            write!(self.writer, "{separator}{}={value}", field.name())
+        };
+    }
+}
+
+impl Visit for RedactingVisitor<'_, '_> {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == "message" {
+            self.write_field(field, format_args!("{value}"));
+        } else {
+            self.write_field(field, format_args!("{value:?}"));
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        self.write_field(field, format_args!("{value:?}"));
+    }
+}
+
+/// Event formatter wrapping a JSON formatter and masking sensitive keys
+///
+/// The JSON formatter serializes event fields directly rather than through
+/// the field formatter, so redaction is applied to each formatted line.
+#[derive(Debug, Clone)]
+pub struct RedactingJsonFormat<E> {
+    inner: E,
+    redactor: FieldRedactor,
+}
+
+impl<E> RedactingJsonFormat<E> {
+    /// Wrap a JSON event formatter
+    #[must_use]
+    pub const fn new(inner: E, redactor: FieldRedactor) -> Self {
+        Self { inner, redactor }
+    }
+}
+
+impl<S, N, E> FormatEvent<S, N> for RedactingJsonFormat<E>
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+    N: for<'a> FormatFields<'a> + 'static,
+    E: FormatEvent<S, N>,
+{
+    fn format_event(
+        &self,
+        ctx: &FmtContext<'_, S, N>,
+        mut writer: Writer<'_>,
+        event: &Event<'_>,
+    ) -> std::fmt::Result {
+        let mut line = String::new();
+        self.inner
+            .format_event(ctx, Writer::new(&mut line), event)?;
+
+        match serde_json::from_str::<serde_json::Value>(&line) {
+            Ok(mut value) => {
+                self.redactor.redact_json(&mut value);
+                writeln!(writer, "{value}")
+            }
+            // Not a single JSON document; never emit it unredacted
+            Err(_) => writeln!(writer, "{{\"message\":\"{REDACTED}\"}}"),
+        }
+    }
 }
 
 /// Initialize logging from environment variables
@@ -264,6 +485,42 @@ mod tests {
         assert!(config.with_file_lines);
     }
     #[test]
+    fn test_sensitive_fields_redacted() {
+        #[derive(Clone, Default)]
+        struct Captured(Arc<std::sync::Mutex<Vec<u8>>>);
+
+        impl std::io::Write for Captured {
+            fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+                self.0.lock().unwrap().extend_from_slice(buf);
+                Ok(buf.len())
+            }
+
+            fn flush(&mut self) -> std::io::Result<()> {
+                Ok(())
+            }
+        }
+
+        for config in [LoggingConfig::production(), LoggingConfig::development()] {
+            let captured = Captured::default();
+            let writer = captured.clone();
+            let subscriber =
+                tracing_subscriber::registry().with(fmt_layer(&config, move || writer.clone()));
+
+            tracing::subscriber::with_default(subscriber, || {
+                let span = tracing::info_span!("request", token = "span-secret");
+                let _guard = span.enter();
+                tracing::info!(api_key = "sk-live-123", user = "alice", "calling provider");
+            });
+
+            let output = String::from_utf8(captured.0.lock().unwrap().clone()).unwrap();
+            assert!(output.contains(REDACTED), "{output}");
+            assert!(!output.contains("sk-live-123"), "{output}");
+            assert!(!output.contains("span-secret"), "{output}");
+            assert!(output.contains("alice"), "{output}");
+            assert!(output.contains("calling provider"), "{output}");
+        }
+    }
+    #[test]
     fn test_logging_initialization() {
         // We can't actually initialize logging in tests (it's global state)
         // but we can verify the config builds correctly
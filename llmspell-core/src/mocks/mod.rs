@@ -7,9 +7,11 @@ pub mod debug;
 pub mod memory;
 pub mod observability;
 pub mod service;
+pub mod streaming;
 
 // Re-export commonly used mocks
 pub use debug::MockMultiLanguageDebug;
 pub use memory::MockMemoryIntegration;
 pub use observability::MockObservabilityFramework;
 pub use service::MockServiceInfrastructure;
+pub use streaming::MockStreamingAgent;
@@ -0,0 +1,252 @@
+//! Mock streaming agent for testing stream consumers
+
+use crate::error::LLMSpellError;
+use crate::execution_context::ExecutionContext;
+use crate::traits::base_agent::BaseAgent;
+use crate::types::{AgentChunk, AgentInput, AgentOutput, AgentStream, ChunkContent, ChunkMetadata};
+use crate::ComponentMetadata;
+use async_trait::async_trait;
+use std::time::Duration;
+
+/// One scripted step of a mock stream
+#[derive(Debug, Clone)]
+enum ScriptedStep {
+    Chunk {
+        content: ChunkContent,
+        delay: Option<Duration>,
+    },
+    Error(String),
+}
+
+/// Mock agent that streams a scripted sequence of chunks
+///
+/// Steps are emitted in the order they were added. An injected error is
+/// yielded as an `Err` item at its position; the stream continues with any
+/// steps scripted after it, so consumers decide whether to stop.
+#[derive(Debug, Clone)]
+pub struct MockStreamingAgent {
+    metadata: ComponentMetadata,
+    steps: Vec<ScriptedStep>,
+}
+
+impl MockStreamingAgent {
+    /// Create a mock streaming agent with an empty script
+    #[must_use]
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            metadata: ComponentMetadata::new(
+                name.into(),
+                "Mock streaming agent for testing".to_string(),
+            ),
+            steps: Vec::new(),
+        }
+    }
+
+    /// Append a chunk emitted immediately
+    #[must_use]
+    pub fn with_chunk(mut self, content: ChunkContent) -> Self {
+        self.steps.push(ScriptedStep::Chunk {
+            content,
+            delay: None,
+        });
+        self
+    }
+
+    /// Append a chunk emitted after `delay`
+    #[must_use]
+    pub fn with_delayed_chunk(mut self, content: ChunkContent, delay: Duration) -> Self {
+        self.steps.push(ScriptedStep::Chunk {
+            content,
+            delay: Some(delay),
+        });
+        self
+    }
+
+    /// Append a text chunk for each string
+    #[must_use]
+    pub fn with_text_chunks<I, S>(mut self, texts: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        for text in texts {
+            self = self.with_chunk(ChunkContent::Text(text.into()));
+        }
+        self
+    }
+
+    /// Append an error yielded at this point in the stream
+    #[must_use]
+    pub fn with_error(mut self, message: impl Into<String>) -> Self {
+        self.steps.push(ScriptedStep::Error(message.into()));
+        self
+    }
+
+    fn scripted_error(message: &str) -> LLMSpellError {
+        LLMSpellError::Component {
+            message: message.to_string(),
+            source: None,
+        }
+    }
+}
+
+#[async_trait]
+impl BaseAgent for MockStreamingAgent {
+    fn metadata(&self) -> &ComponentMetadata {
+        &self.metadata
+    }
+
+    async fn execute_impl(
+        &self,
+        _input: AgentInput,
+        _context: ExecutionContext,
+    ) -> Result<AgentOutput, LLMSpellError> {
+        // Non-streaming execution returns the concatenated text chunks
+        let mut text = String::new();
+        for step in &self.steps {
+            match step {
+                ScriptedStep::Chunk {
+                    content: ChunkContent::Text(chunk),
+                    ..
+                } => text.push_str(chunk),
+                ScriptedStep::Chunk { .. } => {}
+                ScriptedStep::Error(message) => return Err(Self::scripted_error(message)),
+            }
+        }
+        Ok(AgentOutput::text(text))
+    }
+
+    async fn validate_input(&self, _input: &AgentInput) -> Result<(), LLMSpellError> {
+        Ok(())
+    }
+
+    async fn handle_error(&self, error: LLMSpellError) -> Result<AgentOutput, LLMSpellError> {
+        Err(error)
+    }
+
+    async fn stream_execute(
+        &self,
+        _input: AgentInput,
+        _context: ExecutionContext,
+    ) -> Result<AgentStream, LLMSpellError> {
+        let stream_id = format!("mock-stream-{}", self.metadata.name);
+        let last_chunk = self
+            .steps
+            .iter()
+            .rposition(|step| matches!(step, ScriptedStep::Chunk { .. }));
+        let steps = self.steps.clone().into_iter().enumerate();
+
+        let stream = futures::stream::unfold((steps, 0usize), move |(mut steps, chunk_index)| {
+            let stream_id = stream_id.clone();
+            async move {
+                let (position, step) = steps.next()?;
+                match step {
+                    ScriptedStep::Chunk { content, delay } => {
+                        if let Some(delay) = delay {
+                            tokio::time::sleep(delay).await;
+                        }
+                        let chunk = AgentChunk {
+                            stream_id,
+                            chunk_index,
+                            content,
+                            metadata: ChunkMetadata {
+                                is_final: Some(position) == last_chunk,
+                                ..ChunkMetadata::default()
+                            },
+                            timestamp: chrono::Utc::now(),
+                        };
+                        Some((Ok(chunk), (steps, chunk_index + 1)))
+                    }
+                    ScriptedStep::Error(message) => {
+                        Some((Err(Self::scripted_error(&message)), (steps, chunk_index)))
+                    }
+                }
+            }
+        });
+
+        Ok(Box::pin(stream))
+    }
+
+    fn supports_streaming(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::StreamExt;
+
+    /// Consumer that collects text until the stream ends or fails
+    async fn consume(mut stream: AgentStream) -> (Vec<String>, Option<LLMSpellError>) {
+        let mut texts = Vec::new();
+        while let Some(item) = stream.next().await {
+            match item {
+                Ok(AgentChunk {
+                    content: ChunkContent::Text(text),
+                    ..
+                }) => texts.push(text),
+                Ok(_) => {}
+                Err(e) => return (texts, Some(e)),
+            }
+        }
+        (texts, None)
+    }
+
+    #[tokio::test]
+    async fn test_streams_scripted_chunks_in_order() {
+        let agent = MockStreamingAgent::new("streamer")
+            .with_text_chunks(["Hello", ", "])
+            .with_delayed_chunk(
+                ChunkContent::Text("world".to_string()),
+                Duration::from_millis(10),
+            );
+        assert!(agent.supports_streaming());
+
+        let stream = agent
+            .stream_execute(AgentInput::text("hi"), ExecutionContext::new())
+            .await
+            .unwrap();
+        let (texts, error) = consume(stream).await;
+        assert_eq!(texts, vec!["Hello", ", ", "world"]);
+        assert!(error.is_none());
+
+        let output = agent
+            .execute(AgentInput::text("hi"), ExecutionContext::new())
+            .await
+            .unwrap();
+        assert_eq!(output.text, "Hello, world");
+    }
+
+    #[tokio::test]
+    async fn test_injected_error_surfaces_mid_stream() {
+        let agent = MockStreamingAgent::new("flaky")
+            .with_text_chunks(["one", "two"])
+            .with_error("connection reset")
+            .with_text_chunks(["three"]);
+
+        let stream = agent
+            .stream_execute(AgentInput::text("hi"), ExecutionContext::new())
+            .await
+            .unwrap();
+        let (texts, error) = consume(stream).await;
+        assert_eq!(texts, vec!["one", "two"]);
+        assert!(error.unwrap().to_string().contains("connection reset"));
+
+        // Chunk indices and the final marker skip over the error
+        let items: Vec<_> = agent
+            .stream_execute(AgentInput::text("hi"), ExecutionContext::new())
+            .await
+            .unwrap()
+            .collect()
+            .await;
+        assert_eq!(items.len(), 4);
+        let chunks: Vec<_> = items.into_iter().filter_map(Result::ok).collect();
+        assert_eq!(
+            chunks.iter().map(|c| c.chunk_index).collect::<Vec<_>>(),
+            vec![0, 1, 2]
+        );
+        assert!(chunks[2].metadata.is_final);
+        assert!(!chunks[1].metadata.is_final);
+    }
+}
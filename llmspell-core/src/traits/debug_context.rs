@@ -94,6 +94,30 @@ pub trait DebugContext: Send + Sync {
 
     /// Set step mode
     fn set_step_mode(&self, stepping: bool);
+
+    /// Check if the frame's local variables are needed at this location
+    ///
+    /// When true, the engine captures the locals and passes them to
+    /// [`Self::set_frame_locals`] before calling [`Self::should_pause_sync`],
+    /// so conditional breakpoints can be evaluated.
+    fn wants_locals(&self, _file: &str, _line: u32) -> bool {
+        false
+    }
+
+    /// Receive the local variables of the frame executing at the given location
+    ///
+    /// Engines call this before checking a location that wants locals.
+    fn set_frame_locals(&self, _file: &str, _line: u32, _locals: Vec<Variable>) {}
+
+    /// Receive the call stack at a pause, innermost frame first, with each frame's locals
+    ///
+    /// Engines call this before every pause. The default forwards the
+    /// innermost frame to [`Self::set_frame_locals`].
+    fn set_call_stack(&self, frames: Vec<(StackFrame, Vec<Variable>)>) {
+        if let Some((frame, locals)) = frames.into_iter().next() {
+            self.set_frame_locals(&frame.file, frame.line, locals);
+        }
+    }
 }
 
 /// Mock implementation for testing
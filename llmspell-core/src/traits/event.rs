@@ -3,6 +3,7 @@
 
 use crate::{ComponentId, Result};
 use async_trait::async_trait;
+use parking_lot::Mutex;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use std::collections::HashMap;
@@ -182,6 +183,111 @@ impl EventData {
         self.metadata.insert(key.into(), value);
         self
     }
+
+    /// Builder-style method to record the sampling applied to this event
+    pub fn with_sampling(mut self, sampling: EventSampling) -> Self {
+        self.metadata.extend(sampling.metadata());
+        self
+    }
+}
+
+/// Sampling policy for high-frequency event types
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EventSampling {
+    /// Emit each event with the given probability (0.0 - 1.0)
+    Rate(f64),
+    /// Emit the first of every `n` events
+    EveryNth(usize),
+}
+
+impl EventSampling {
+    /// Fraction of events that pass this sampling policy
+    #[allow(clippy::cast_precision_loss)]
+    pub fn effective_rate(&self) -> f64 {
+        match *self {
+            Self::Rate(rate) => rate.clamp(0.0, 1.0),
+            Self::EveryNth(n) => 1.0 / n.max(1) as f64,
+        }
+    }
+
+    /// Metadata attached to sampled events so consumers can scale counts
+    pub fn metadata(&self) -> HashMap<String, Value> {
+        let strategy = match self {
+            Self::Rate(_) => "rate",
+            Self::EveryNth(_) => "every_nth",
+        };
+        HashMap::from([
+            (
+                "sample_rate".to_string(),
+                serde_json::json!(self.effective_rate()),
+            ),
+            (
+                "sampling_strategy".to_string(),
+                Value::String(strategy.to_string()),
+            ),
+        ])
+    }
+}
+
+/// Outcome of applying sampling to a single event
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SamplingDecision {
+    /// No sampling applies; emit the event as-is
+    Emit,
+    /// The event passed sampling and should carry the sampling metadata
+    Sampled(EventSampling),
+    /// The event was sampled out
+    Drop,
+}
+
+impl SamplingDecision {
+    /// Whether the event should be emitted
+    pub const fn should_emit(&self) -> bool {
+        !matches!(self, Self::Drop)
+    }
+}
+
+/// Stateful sampler applying `EventConfig::sampling` to a stream of events
+///
+/// Keeps one counter per event type so `EveryNth` sampling is deterministic
+/// for each type independently.
+#[derive(Debug, Default)]
+pub struct EventSampler {
+    counters: Mutex<HashMap<String, u64>>,
+}
+
+impl EventSampler {
+    /// Create a sampler with no recorded events
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Decide whether an event of `event_type` passes the configured sampling
+    ///
+    /// Critical events (see [`EventConfig::is_critical_event`]) always pass.
+    pub fn sample(&self, config: &EventConfig, event_type: &str) -> SamplingDecision {
+        let Some(sampling) = config.sampling_for(event_type) else {
+            return SamplingDecision::Emit;
+        };
+
+        let passed = match sampling {
+            EventSampling::Rate(_) => rand::random::<f64>() < sampling.effective_rate(),
+            EventSampling::EveryNth(n) => {
+                let mut counters = self.counters.lock();
+                let seen = counters.entry(event_type.to_string()).or_insert(0);
+                let passed = *seen % (n.max(1) as u64) == 0;
+                *seen += 1;
+                passed
+            }
+        };
+
+        if passed {
+            SamplingDecision::Sampled(sampling)
+        } else {
+            SamplingDecision::Drop
+        }
+    }
 }
 
 /// Event configuration for filtering and processing
@@ -214,6 +320,10 @@ pub struct EventConfig {
     /// Maximum events per second (rate limiting)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub max_events_per_second: Option<u32>,
+
+    /// Sampling policies keyed by event type pattern (supports * wildcard)
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub sampling: HashMap<String, EventSampling>,
 }
 
 fn default_true() -> bool {
@@ -230,6 +340,7 @@ impl Default for EventConfig {
             emit_state_events: false,
             emit_debug_events: false,
             max_events_per_second: None,
+            sampling: HashMap::new(),
         }
     }
 }
@@ -262,6 +373,40 @@ impl EventConfig {
         true
     }
 
+    /// Builder-style method to sample event types matching `pattern`
+    pub fn with_sampling(mut self, pattern: impl Into<String>, sampling: EventSampling) -> Self {
+        self.sampling.insert(pattern.into(), sampling);
+        self
+    }
+
+    /// Check if an event type is critical and must never be sampled
+    ///
+    /// Any dot-separated segment of `error`, `failed`, `failure` or
+    /// `critical` marks the event as critical.
+    pub fn is_critical_event(event_type: &str) -> bool {
+        event_type
+            .split('.')
+            .any(|segment| matches!(segment, "error" | "failed" | "failure" | "critical"))
+    }
+
+    /// Sampling policy applying to an event type, if any
+    ///
+    /// An exact match wins over wildcard patterns; among wildcard patterns
+    /// the longest one wins.
+    pub fn sampling_for(&self, event_type: &str) -> Option<EventSampling> {
+        if Self::is_critical_event(event_type) {
+            return None;
+        }
+        if let Some(sampling) = self.sampling.get(event_type) {
+            return Some(*sampling);
+        }
+        self.sampling
+            .iter()
+            .filter(|(pattern, _)| Self::matches_pattern(pattern, event_type))
+            .max_by_key(|(pattern, _)| pattern.len())
+            .map(|(_, sampling)| *sampling)
+    }
+
     /// Simple glob pattern matching (supports * wildcard)
     fn matches_pattern(pattern: &str, text: &str) -> bool {
         if pattern == "*" {
@@ -300,6 +445,40 @@ mod tests {
         assert!(!config.should_emit("tool.debug")); // Excluded
     }
 
+    #[test]
+    fn test_every_nth_sampling_spares_errors() {
+        let config = EventConfig::default().with_sampling("*", EventSampling::EveryNth(10));
+        let sampler = EventSampler::new();
+
+        let mut ticks = Vec::new();
+        let mut errors = 0;
+        for i in 0..1000 {
+            let decision = sampler.sample(&config, "metrics.tick");
+            if decision.should_emit() {
+                ticks.push(
+                    EventData::new("metrics.tick").with_sampling(match decision {
+                        SamplingDecision::Sampled(sampling) => sampling,
+                        other => panic!("expected sampled decision, got {other:?}"),
+                    }),
+                );
+            }
+            if i % 100 == 0 && sampler.sample(&config, "agent.error") == SamplingDecision::Emit {
+                errors += 1;
+            }
+        }
+
+        assert_eq!(ticks.len(), 100);
+        assert_eq!(errors, 10);
+        assert_eq!(
+            ticks[0].metadata.get("sample_rate"),
+            Some(&serde_json::json!(0.1))
+        );
+        assert_eq!(
+            ticks[0].metadata.get("sampling_strategy"),
+            Some(&serde_json::json!("every_nth"))
+        );
+    }
+
     #[test]
     fn test_event_data_builder() {
         let event = EventData::new("test.event")
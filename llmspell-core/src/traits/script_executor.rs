@@ -81,6 +81,11 @@ pub trait ScriptExecutor: Send + Sync + 'static {
         true
     }
 
+    /// Flush and release background resources before the kernel stops
+    ///
+    /// Default implementation does nothing.
+    async fn shutdown(&self) {}
+
     /// Set debug context for debugging support
     ///
     /// Default implementation does nothing for backward compatibility.
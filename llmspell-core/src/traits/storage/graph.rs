@@ -128,6 +128,44 @@ pub trait KnowledgeGraph: Send + Sync {
         changes: HashMap<String, serde_json::Value>,
     ) -> Result<()>;
 
+    /// Add a batch of entities, merging those that match an existing entity
+    ///
+    /// Entities are matched on `(name, entity_type)`. A match gets the incoming
+    /// properties merged in as a new version (see [`update_entity`](Self::update_entity))
+    /// instead of a second node. Returned IDs map positionally to the input.
+    ///
+    /// The default implementation only deduplicates within the batch and is not
+    /// atomic; backends override it to match stored entities in one transaction.
+    ///
+    /// # Errors
+    /// Returns an error if any insert or update fails
+    ///
+    /// # Examples
+    /// ```ignore
+    /// let ids = graph.upsert_entities(extracted_entities).await?;
+    /// ```
+    async fn upsert_entities(&self, entities: Vec<Entity>) -> Result<Vec<String>> {
+        let mut seen: HashMap<(String, String), String> = HashMap::new();
+        let mut ids = Vec::with_capacity(entities.len());
+        for entity in entities {
+            let key = (entity.name.clone(), entity.entity_type.clone());
+            let id = if let Some(id) = seen.get(&key) {
+                let changes = match entity.properties {
+                    serde_json::Value::Object(map) => map.into_iter().collect(),
+                    _ => HashMap::new(),
+                };
+                self.update_entity(id, changes).await?;
+                id.clone()
+            } else {
+                let id = self.add_entity(entity).await?;
+                seen.insert(key, id.clone());
+                id
+            };
+            ids.push(id);
+        }
+        Ok(ids)
+    }
+
     /// Get the current version of an entity
     ///
     /// Returns the entity with the most recent `ingestion_time`, representing
@@ -215,6 +253,35 @@ pub trait KnowledgeGraph: Send + Sync {
     /// ```
     async fn get_related(&self, entity_id: &str, relationship_type: &str) -> Result<Vec<Entity>>;
 
+    /// Get entities related to a given entity as the graph stood at a past time
+    ///
+    /// Like [`get_related`](Self::get_related), but only follows relationships that
+    /// were valid at `at`. Backends whose [`traverse`](Self::traverse) also honours
+    /// transaction time (e.g. SQLite) exclude corrections recorded after `at`.
+    ///
+    /// The default implementation runs a one-hop [`traverse`](Self::traverse) at `at`.
+    ///
+    /// # Examples
+    /// ```ignore
+    /// // Where did Alice work as of January 1?
+    /// let employers = graph.get_related_at("alice", "works_at", jan_1).await?;
+    /// ```
+    async fn get_related_at(
+        &self,
+        entity_id: &str,
+        relationship_type: &str,
+        at: DateTime<Utc>,
+    ) -> Result<Vec<Entity>> {
+        let reachable = self
+            .traverse(entity_id, Some(relationship_type), 1, Some(at))
+            .await?;
+        Ok(reachable
+            .into_iter()
+            .filter(|(_, depth, _)| *depth == 1)
+            .map(|(entity, _, _)| entity)
+            .collect())
+    }
+
     /// Get all relationships for an entity
     ///
     /// Returns both outgoing (from this entity) and incoming (to this entity) relationships.
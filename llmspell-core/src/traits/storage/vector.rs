@@ -166,6 +166,26 @@ pub trait VectorStorage: Send + Sync {
     /// batch deletion strategies for better performance.
     async fn delete_scope(&self, scope: &StateScope) -> Result<usize>;
 
+    /// Delete all vectors in a scope whose metadata matches a filter.
+    ///
+    /// Used to retract every chunk of a source document without tracking chunk
+    /// IDs externally. Vectors outside `scope` are never touched.
+    ///
+    /// # Arguments
+    ///
+    /// * `scope` - The scope to delete from
+    /// * `filter` - Metadata key/value pairs that must all be equal; an empty
+    ///   filter matches every vector in the scope
+    ///
+    /// # Returns
+    ///
+    /// The number of vectors that were deleted.
+    async fn delete_by_filter(
+        &self,
+        scope: &StateScope,
+        filter: HashMap<String, Value>,
+    ) -> Result<usize>;
+
     /// Get overall storage statistics and performance metrics.
     ///
     /// Provides insights into storage usage, performance characteristics,
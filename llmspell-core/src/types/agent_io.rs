@@ -1,7 +1,8 @@
 //! ABOUTME: Agent input/output types with multimodal support
 //! ABOUTME: Provides `AgentInput`, `AgentOutput`, and related types for agent communication
 
-use super::{ComponentId, MediaContent, MediaType};
+use super::{Attachment, ComponentId, MediaContent, MediaType};
+use crate::error::{LLMSpellError, Result};
 use crate::execution_context::ExecutionContext;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
@@ -62,6 +63,9 @@ pub struct AgentInput {
     pub parameters: HashMap<String, Value>,
     /// Preferred output modalities
     pub output_modalities: Vec<MediaType>,
+    /// Attachments for multimodal providers
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub attachments: Vec<Attachment>,
 }
 
 impl AgentInput {
@@ -73,6 +77,7 @@ impl AgentInput {
             context: None,
             parameters: HashMap::new(),
             output_modalities: vec![MediaType::Text],
+            attachments: vec![],
         }
     }
 
@@ -110,6 +115,25 @@ impl AgentInput {
         self
     }
 
+    /// Add an attachment for multimodal providers
+    #[must_use]
+    pub fn with_attachment(mut self, attachment: Attachment) -> Self {
+        self.attachments.push(attachment);
+        self
+    }
+
+    /// Attachments carried by this input
+    #[must_use]
+    pub fn attachments(&self) -> &[Attachment] {
+        &self.attachments
+    }
+
+    /// Check if input has attachments
+    #[must_use]
+    pub fn has_attachments(&self) -> bool {
+        !self.attachments.is_empty()
+    }
+
     /// Create a builder for more complex inputs
     #[must_use]
     pub fn builder() -> AgentInputBuilder {
@@ -130,6 +154,42 @@ impl AgentInput {
             .filter(|m| m.media_type() == media_type)
             .collect()
     }
+
+    /// Get a raw parameter value
+    #[must_use]
+    pub fn get_json(&self, key: &str) -> Option<&Value> {
+        self.parameters.get(key)
+    }
+
+    /// Get a string parameter
+    #[must_use]
+    pub fn get_str(&self, key: &str) -> Option<&str> {
+        self.parameters.get(key).and_then(Value::as_str)
+    }
+
+    /// Get an integer parameter
+    #[must_use]
+    pub fn get_i64(&self, key: &str) -> Option<i64> {
+        self.parameters.get(key).and_then(Value::as_i64)
+    }
+
+    /// Get an unsigned integer parameter
+    #[must_use]
+    pub fn get_u64(&self, key: &str) -> Option<u64> {
+        self.parameters.get(key).and_then(Value::as_u64)
+    }
+
+    /// Get a floating-point parameter (integers are widened)
+    #[must_use]
+    pub fn get_f64(&self, key: &str) -> Option<f64> {
+        self.parameters.get(key).and_then(Value::as_f64)
+    }
+
+    /// Get a boolean parameter
+    #[must_use]
+    pub fn get_bool(&self, key: &str) -> Option<bool> {
+        self.parameters.get(key).and_then(Value::as_bool)
+    }
 }
 
 impl fmt::Display for AgentInput {
@@ -158,6 +218,7 @@ pub struct AgentInputBuilder {
     context: Option<ExecutionContext>,
     parameters: HashMap<String, Value>,
     output_modalities: Vec<MediaType>,
+    attachments: Vec<Attachment>,
 }
 
 impl AgentInputBuilder {
@@ -170,6 +231,7 @@ impl AgentInputBuilder {
             context: None,
             parameters: HashMap::new(),
             output_modalities: vec![MediaType::Text],
+            attachments: vec![],
         }
     }
 
@@ -199,6 +261,34 @@ impl AgentInputBuilder {
         self
     }
 
+    /// Add a string parameter
+    pub fn param_str(self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.param_json(key, Value::String(value.into()))
+    }
+
+    /// Add an integer parameter
+    pub fn param_i64(self, key: impl Into<String>, value: i64) -> Self {
+        self.param_json(key, Value::from(value))
+    }
+
+    /// Add a boolean parameter
+    pub fn param_bool(self, key: impl Into<String>, value: bool) -> Self {
+        self.param_json(key, Value::Bool(value))
+    }
+
+    /// Add a structured JSON parameter
+    pub fn param_json(mut self, key: impl Into<String>, value: Value) -> Self {
+        self.parameters.insert(key.into(), value);
+        self
+    }
+
+    /// Replace the media content
+    #[must_use]
+    pub fn media(mut self, media: Vec<MediaContent>) -> Self {
+        self.media = media;
+        self
+    }
+
     /// Set output modalities
     #[must_use]
     pub fn output_modalities(mut self, modalities: Vec<MediaType>) -> Self {
@@ -206,6 +296,13 @@ impl AgentInputBuilder {
         self
     }
 
+    /// Add an attachment
+    #[must_use]
+    pub fn attachment(mut self, attachment: Attachment) -> Self {
+        self.attachments.push(attachment);
+        self
+    }
+
     /// Build the `AgentInput`
     #[must_use]
     pub fn build(self) -> AgentInput {
@@ -215,8 +312,31 @@ impl AgentInputBuilder {
             context: self.context,
             parameters: self.parameters,
             output_modalities: self.output_modalities,
+            attachments: self.attachments,
         }
     }
+
+    /// Build the `AgentInput`, validating it first
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error if a parameter key is empty or no output
+    /// modality is requested
+    pub fn try_build(self) -> Result<AgentInput> {
+        if self.parameters.keys().any(|key| key.trim().is_empty()) {
+            return Err(LLMSpellError::Validation {
+                message: "Parameter names must not be empty".to_string(),
+                field: Some("parameters".to_string()),
+            });
+        }
+        if self.output_modalities.is_empty() {
+            return Err(LLMSpellError::Validation {
+                message: "At least one output modality is required".to_string(),
+                field: Some("output_modalities".to_string()),
+            });
+        }
+        Ok(self.build())
+    }
 }
 
 impl Default for AgentInputBuilder {
@@ -435,6 +555,71 @@ mod tests {
         assert_eq!(input.output_modalities.len(), 2);
     }
     #[test]
+    fn test_agent_input_builder_typed_params() {
+        let input = AgentInput::builder()
+            .text("Fetch the page")
+            .param_str("url", "https://example.com")
+            .param_i64("retries", -1)
+            .param_bool("follow_redirects", true)
+            .param_json("headers", serde_json::json!({"accept": "text/html"}))
+            .media(vec![MediaContent::Text("inline".to_string())])
+            .try_build()
+            .unwrap();
+
+        assert_eq!(input.get_str("url"), Some("https://example.com"));
+        assert_eq!(input.get_i64("retries"), Some(-1));
+        assert_eq!(input.get_u64("retries"), None);
+        assert!(input
+            .get_f64("retries")
+            .is_some_and(|v| (v + 1.0).abs() < f64::EPSILON));
+        assert_eq!(input.get_bool("follow_redirects"), Some(true));
+        assert_eq!(
+            input.get_json("headers"),
+            Some(&serde_json::json!({"accept": "text/html"}))
+        );
+        assert_eq!(input.media.len(), 1);
+
+        // Typed getters do not coerce mismatched types
+        assert_eq!(input.get_str("retries"), None);
+        assert_eq!(input.get_bool("url"), None);
+        assert_eq!(input.get_str("missing"), None);
+
+        let invalid = AgentInput::builder().param_str("", "value").try_build();
+        assert!(matches!(
+            invalid,
+            Err(LLMSpellError::Validation { field: Some(ref f), .. }) if f == "parameters"
+        ));
+        let invalid = AgentInput::builder().output_modalities(vec![]).try_build();
+        assert!(invalid.is_err());
+    }
+    #[test]
+    fn test_agent_input_attachment_round_trip() {
+        use crate::types::{Attachment, AttachmentData};
+
+        let input = AgentInput::text("Describe this image")
+            .with_attachment(Attachment::inline(
+                MediaType::Image,
+                vec![0x89, 0x50, 0x4E, 0x47],
+                Some("image/png".to_string()),
+            ))
+            .with_attachment(Attachment::artifact(MediaType::Image, "artifact-42"));
+        assert!(input.has_attachments());
+
+        let json = serde_json::to_string(&input).unwrap();
+        let restored: AgentInput = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.attachments(), input.attachments());
+        assert!(matches!(
+            &restored.attachments()[0].data,
+            AttachmentData::Inline { mime_type: Some(mime), .. } if mime == "image/png"
+        ));
+
+        // Inputs serialized before attachments existed still deserialize
+        let mut legacy = serde_json::to_value(AgentInput::text("plain")).unwrap();
+        legacy.as_object_mut().unwrap().remove("attachments");
+        let legacy: AgentInput = serde_json::from_value(legacy).unwrap();
+        assert!(!legacy.has_attachments());
+    }
+    #[test]
     fn test_agent_input_with_media() {
         let media = MediaContent::Text("Additional context".to_string());
         let input = AgentInput::text("Main prompt").with_media(media);
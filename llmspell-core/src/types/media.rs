@@ -80,6 +80,67 @@ pub enum MediaContent {
     },
 }
 
+/// Media attached to an agent input, sent alongside the text prompt
+///
+/// Unlike [`MediaContent`], the payload may be a reference that the
+/// provider resolves, so large files need not be loaded into memory.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Attachment {
+    /// Kind of media carried by the attachment
+    pub kind: MediaType,
+    /// Attachment payload
+    pub data: AttachmentData,
+}
+
+/// Payload of an [`Attachment`]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "source", rename_all = "snake_case")]
+pub enum AttachmentData {
+    /// Raw bytes embedded in the input
+    Inline {
+        /// Raw media bytes
+        bytes: Vec<u8>,
+        /// MIME type if known
+        mime_type: Option<String>,
+    },
+    /// Media reachable at a URI (http(s), data or file URI)
+    Uri(String),
+    /// Media stored as a session artifact
+    Artifact {
+        /// Artifact identifier
+        artifact_id: String,
+    },
+}
+
+impl Attachment {
+    /// Create an attachment with inline bytes
+    #[must_use]
+    pub fn inline(kind: MediaType, bytes: Vec<u8>, mime_type: Option<String>) -> Self {
+        Self {
+            kind,
+            data: AttachmentData::Inline { bytes, mime_type },
+        }
+    }
+
+    /// Create an attachment referencing a URI
+    pub fn uri(kind: MediaType, uri: impl Into<String>) -> Self {
+        Self {
+            kind,
+            data: AttachmentData::Uri(uri.into()),
+        }
+    }
+
+    /// Create an attachment referencing a stored artifact
+    pub fn artifact(kind: MediaType, artifact_id: impl Into<String>) -> Self {
+        Self {
+            kind,
+            data: AttachmentData::Artifact {
+                artifact_id: artifact_id.into(),
+            },
+        }
+    }
+}
+
 /// Supported image formats
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
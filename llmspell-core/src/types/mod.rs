@@ -5,14 +5,16 @@ pub mod agent_io;
 mod media;
 pub mod storage;
 mod streaming;
+mod version_range;
 
 pub use agent_io::{
     AgentInput, AgentInputBuilder, AgentOutput, AgentOutputBuilder, OutputMetadata, ToolCall,
     ToolOutput,
 };
 pub use media::{
-    AudioFormat, AudioMetadata, ColorSpace, ImageFormat, ImageMetadata, MediaContent, MediaType,
-    VideoFormat, VideoMetadata, MAX_AUDIO_SIZE, MAX_BINARY_SIZE, MAX_IMAGE_SIZE, MAX_VIDEO_SIZE,
+    Attachment, AttachmentData, AudioFormat, AudioMetadata, ColorSpace, ImageFormat, ImageMetadata,
+    MediaContent, MediaType, VideoFormat, VideoMetadata, MAX_AUDIO_SIZE, MAX_BINARY_SIZE,
+    MAX_IMAGE_SIZE, MAX_VIDEO_SIZE,
 };
 pub use streaming::{
     AgentChunk, AgentStream, ChunkContent, ChunkMetadata, ControlMessage, ReasoningStep,
@@ -127,6 +129,10 @@ impl Default for ComponentId {
 /// // Check if newer
 /// assert!(v2.is_newer_than(&v1));
 ///
+/// // Check against a semver range
+/// assert!(v2.satisfies(">=1.0, <2.0"));
+/// assert!(!v2.satisfies("^2"));
+///
 /// // Display version
 /// assert_eq!(v1.to_string(), "1.0.0");
 /// ```
@@ -158,6 +164,17 @@ impl Version {
     pub fn is_newer_than(&self, other: &Version) -> bool {
         self > other
     }
+
+    /// Check if this version satisfies a semver range.
+    ///
+    /// Supports caret (`^1.2`), tilde (`~1.2.3`), wildcard (`1.*`) and
+    /// comparator (`>=1.2, <2.0`) ranges. Comparators separated by commas or
+    /// spaces must all match; `||` separates alternatives. A bare version is
+    /// treated as a caret range, as in Cargo. Malformed ranges match nothing.
+    #[must_use]
+    pub fn satisfies(&self, range: &str) -> bool {
+        version_range::matches(self, range)
+    }
 }
 
 impl fmt::Display for Version {
@@ -483,6 +500,55 @@ mod tests {
         assert!(!v2_0_0.is_compatible_with(&v1_0_0));
     }
     #[test]
+    fn test_version_satisfies_caret_and_tilde() {
+        let version = Version::new(1, 2, 5);
+
+        assert!(version.satisfies("^1.2"));
+        assert!(version.satisfies("^1.0.0"));
+        assert!(version.satisfies("1.1"));
+        assert!(!version.satisfies("^1.3"));
+        assert!(!version.satisfies("^2.0.0"));
+        assert!(version.satisfies("~1.2.3"));
+        assert!(!version.satisfies("~1.2.6"));
+        assert!(!version.satisfies("~1.1"));
+        assert!(version.satisfies("~1"));
+
+        // Below 1.0 the minor (or patch) version is the breaking component
+        assert!(Version::new(0, 2, 9).satisfies("^0.2.3"));
+        assert!(!Version::new(0, 3, 0).satisfies("^0.2.3"));
+        assert!(!Version::new(0, 0, 4).satisfies("^0.0.3"));
+    }
+    #[test]
+    fn test_version_satisfies_comparators() {
+        let version = Version::new(1, 4, 0);
+
+        assert!(version.satisfies(">=1.2, <2.0"));
+        assert!(version.satisfies(">= 1.2 < 2"));
+        assert!(!version.satisfies(">1.4.0"));
+        assert!(version.satisfies("<=1.4"));
+        assert!(version.satisfies("=1.4.0"));
+        assert!(!version.satisfies("=1.4.1"));
+        assert!(version.satisfies("1.*"));
+        assert!(version.satisfies("*"));
+        assert!(version.satisfies("^2 || ~1.4"));
+
+        // Malformed ranges never match
+        assert!(!version.satisfies(""));
+        assert!(!version.satisfies(">="));
+        assert!(!version.satisfies("1.x.3"));
+        assert!(!version.satisfies("latest"));
+    }
+    #[test]
+    fn test_version_major_incompatibility() {
+        let required = Version::new(1, 2, 0);
+        let upgraded = Version::new(2, 0, 0);
+
+        assert!(!upgraded.is_compatible_with(&required));
+        assert!(!upgraded.satisfies("^1.2"));
+        assert!(!upgraded.satisfies(">=1.2, <2.0"));
+        assert!(upgraded.satisfies(">=1.2"));
+    }
+    #[test]
     fn test_version_display() {
         let version = Version::new(1, 2, 3);
         assert_eq!(format!("{}", version), "1.2.3");
@@ -49,7 +49,7 @@ use uuid::Uuid;
 /// )
 /// .with_event_time(Utc::now());
 /// ```
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Entity {
     /// Unique identifier for the entity
     pub id: String,
@@ -68,8 +68,38 @@ pub struct Entity {
 
     /// When we ingested this knowledge (always present)
     pub ingestion_time: DateTime<Utc>,
+
+    /// Optional embedding for entity similarity search
+    ///
+    /// Stored per entity rather than per version, so historical reads return
+    /// the current embedding.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub embedding: Option<Vec<f32>>,
 }
 
+/// Embeddings compare by bit pattern, which keeps equality reflexive (NaN
+/// equals itself) so `Entity` can stay `Eq`
+impl PartialEq for Entity {
+    fn eq(&self, other: &Self) -> bool {
+        let embeddings_eq = match (&self.embedding, &other.embedding) {
+            (Some(a), Some(b)) => {
+                a.len() == b.len() && a.iter().zip(b).all(|(x, y)| x.to_bits() == y.to_bits())
+            }
+            (None, None) => true,
+            _ => false,
+        };
+        self.id == other.id
+            && self.name == other.name
+            && self.entity_type == other.entity_type
+            && self.properties == other.properties
+            && self.event_time == other.event_time
+            && self.ingestion_time == other.ingestion_time
+            && embeddings_eq
+    }
+}
+
+impl Eq for Entity {}
+
 impl Entity {
     /// Create a new entity with auto-generated ID and current ingestion time
     ///
@@ -99,6 +129,7 @@ impl Entity {
             properties,
             event_time: None,
             ingestion_time: Utc::now(),
+            embedding: None,
         }
     }
 
@@ -123,6 +154,17 @@ impl Entity {
         self.id = id;
         self
     }
+
+    /// Attach an embedding for similarity search
+    ///
+    /// # Arguments
+    ///
+    /// * `embedding` - Entity embedding vector
+    #[must_use]
+    pub fn with_embedding(mut self, embedding: Vec<f32>) -> Self {
+        self.embedding = Some(embedding);
+        self
+    }
 }
 
 /// A relationship between two entities with bi-temporal tracking
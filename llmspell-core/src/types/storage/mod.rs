@@ -37,7 +37,7 @@ pub use session::{SessionData, SessionStatus};
 // Re-export vector types
 pub use vector::{
     DistanceMetric, HNSWConfig, NamespaceStats, ScopedStats, StorageStats, VectorEntry,
-    VectorQuery, VectorResult,
+    VectorPruneFilter, VectorQuery, VectorResult,
 };
 
 // Re-export workflow types
@@ -4,6 +4,7 @@
 //! - `VectorEntry`: Multi-tenant vector with bi-temporal support
 //! - `VectorQuery`: Query parameters with temporal filters
 //! - `VectorResult`: Search result with similarity score
+//! - `VectorPruneFilter`: Age, count and access rules for pruning a scope
 //! - `StorageStats`: Overall storage metrics
 //! - `ScopedStats`: Per-tenant statistics
 //! - `DistanceMetric`: Similarity calculation methods
@@ -274,6 +275,32 @@ pub struct VectorResult {
     pub distance: f32,
 }
 
+/// Rules selecting vectors to delete when pruning a scope
+///
+/// Each rule is optional and applied independently; a vector is removed if any
+/// rule selects it. Vectors are ranked by `event_time` (falling back to
+/// `created_at`), newest first. Access counts are the number of reads recorded
+/// by the storage backend.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VectorPruneFilter {
+    /// Remove vectors whose event time is before this
+    pub older_than: Option<SystemTime>,
+
+    /// Keep only the newest N vectors
+    pub keep_newest: Option<usize>,
+
+    /// Remove vectors read fewer times than this
+    pub min_access_count: Option<u64>,
+}
+
+impl VectorPruneFilter {
+    /// Check if no rule is set, in which case nothing is pruned
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.older_than.is_none() && self.keep_newest.is_none() && self.min_access_count.is_none()
+    }
+}
+
 /// Storage statistics
 ///
 /// Overall metrics for the vector storage system including counts,
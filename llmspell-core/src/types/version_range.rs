@@ -0,0 +1,182 @@
+//! ABOUTME: Semver range parsing for `Version::satisfies`
+//! ABOUTME: Supports caret, tilde, wildcard and comparator ranges joined by `,` or `||`
+
+use super::Version;
+
+/// Comparison operator of a single resolved bound
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Op {
+    Eq,
+    Gt,
+    Ge,
+    Lt,
+    Le,
+}
+
+/// A version with optional minor and patch components (`1`, `1.2`, `1.2.*`)
+#[derive(Debug, Clone, Copy)]
+struct Partial {
+    major: u32,
+    minor: Option<u32>,
+    patch: Option<u32>,
+}
+
+impl Partial {
+    fn parse(input: &str) -> Option<Self> {
+        let input = input.strip_prefix('v').unwrap_or(input);
+        // Pre-release and build metadata are not tracked by `Version`
+        let input = input.split(['-', '+']).next()?;
+        let mut parts = input.split('.');
+
+        let major = parse_part(parts.next()?)??;
+        let minor = match parts.next() {
+            Some(part) => parse_part(part)?,
+            None => None,
+        };
+        let patch = match parts.next() {
+            Some(part) => parse_part(part)?,
+            None => None,
+        };
+        // Nothing but a wildcard may follow a wildcard minor
+        if minor.is_none() && patch.is_some() {
+            return None;
+        }
+        if parts.next().is_some() {
+            return None;
+        }
+
+        Some(Self {
+            major,
+            minor,
+            patch,
+        })
+    }
+
+    /// Lowest version matching this partial
+    fn floor(self) -> Version {
+        Version::new(self.major, self.minor.unwrap_or(0), self.patch.unwrap_or(0))
+    }
+
+    /// First version past every version matching this partial
+    fn ceiling(self) -> Version {
+        match (self.minor, self.patch) {
+            (Some(minor), Some(patch)) => Version::new(self.major, minor, patch.saturating_add(1)),
+            (Some(minor), None) => Version::new(self.major, minor.saturating_add(1), 0),
+            (None, _) => Version::new(self.major.saturating_add(1), 0, 0),
+        }
+    }
+
+    const fn is_full(self) -> bool {
+        self.minor.is_some() && self.patch.is_some()
+    }
+}
+
+/// Parse one dotted component; `Some(None)` is a wildcard
+fn parse_part(part: &str) -> Option<Option<u32>> {
+    match part {
+        "*" | "x" | "X" => Some(None),
+        _ => part.parse().ok().map(Some),
+    }
+}
+
+/// Resolve one comparator (`^1.2`, `>=1.0.0`, `1.*`, ...) into bounds
+fn resolve(comparator: &str) -> Option<Vec<(Op, Version)>> {
+    let (op, rest) = ["^", "~", ">=", "<=", ">", "<", "="]
+        .iter()
+        .find_map(|op| comparator.strip_prefix(op).map(|rest| (*op, rest)))
+        .unwrap_or(("", comparator));
+    let rest = rest.trim();
+
+    if rest == "*" || rest == "x" || rest == "X" {
+        return match op {
+            "" | "=" | ">=" | "^" | "~" => Some(Vec::new()),
+            _ => None,
+        };
+    }
+
+    let partial = Partial::parse(rest)?;
+    let floor = partial.floor();
+    let wildcard = rest.contains(['*', 'x', 'X']);
+    let bounds = match op {
+        // A bare version is a caret requirement, as in Cargo, unless it has wildcards
+        "" if !wildcard => caret(partial),
+        "" | "=" if !partial.is_full() => vec![(Op::Ge, floor), (Op::Lt, partial.ceiling())],
+        "=" => vec![(Op::Eq, floor)],
+        "^" => caret(partial),
+        "~" => {
+            let tilde = Partial {
+                patch: None,
+                ..partial
+            };
+            vec![(Op::Ge, floor), (Op::Lt, tilde.ceiling())]
+        }
+        ">=" => vec![(Op::Ge, floor)],
+        "<" => vec![(Op::Lt, floor)],
+        ">" if partial.is_full() => vec![(Op::Gt, floor)],
+        ">" => vec![(Op::Ge, partial.ceiling())],
+        "<=" if partial.is_full() => vec![(Op::Le, floor)],
+        "<=" => vec![(Op::Lt, partial.ceiling())],
+        _ => return None,
+    };
+    Some(bounds)
+}
+
+/// Caret bounds: changes left of the first non-zero component are breaking
+fn caret(partial: Partial) -> Vec<(Op, Version)> {
+    let floor = partial.floor();
+    let significant = match (partial.major, partial.minor, partial.patch) {
+        (0, Some(0), Some(_)) => partial,
+        (0, Some(_), _) => Partial {
+            patch: None,
+            ..partial
+        },
+        _ => Partial {
+            minor: None,
+            patch: None,
+            ..partial
+        },
+    };
+    vec![(Op::Ge, floor), (Op::Lt, significant.ceiling())]
+}
+
+/// Split a comparator set on commas and whitespace, rejoining detached operators
+fn comparators(set: &str) -> Vec<String> {
+    let mut result: Vec<String> = Vec::new();
+    let mut pending_op: Option<&str> = None;
+    for token in set.split([',', ' ', '\t']).filter(|t| !t.is_empty()) {
+        if token.chars().all(|c| "<>=^~".contains(c)) {
+            pending_op = Some(token);
+            continue;
+        }
+        match pending_op.take() {
+            Some(op) => result.push(format!("{op}{token}")),
+            None => result.push(token.to_string()),
+        }
+    }
+    if let Some(op) = pending_op {
+        // A trailing operator has no version and can never resolve
+        result.push(op.to_string());
+    }
+    result
+}
+
+/// Check `version` against a semver range; malformed ranges match nothing
+pub(super) fn matches(version: &Version, range: &str) -> bool {
+    range.split("||").any(|set| {
+        let comparators = comparators(set);
+        if comparators.is_empty() {
+            return false;
+        }
+        comparators.iter().all(|comparator| {
+            resolve(comparator).is_some_and(|bounds| {
+                bounds.iter().all(|(op, bound)| match op {
+                    Op::Eq => version == bound,
+                    Op::Gt => version > bound,
+                    Op::Ge => version >= bound,
+                    Op::Lt => version < bound,
+                    Op::Le => version <= bound,
+                })
+            })
+        })
+    })
+}
@@ -1,6 +1,7 @@
 // ABOUTME: EventBus implementation with async pub/sub and pattern matching
 // ABOUTME: Provides high-performance event routing with flow control integration
 
+use crate::dead_letter::{DeadLetterQueue, DeadLetterReason, DeadLetterTarget};
 use crate::flow_controller::{FlowController, FlowControllerConfig};
 use crate::handler::AsyncEventHandler;
 use crate::pattern::{EventPattern, PatternMatcher};
@@ -10,7 +11,7 @@ use dashmap::DashMap;
 use llmspell_core::traits::storage::StorageBackend;
 use std::sync::Arc;
 use tokio::sync::{broadcast, mpsc};
-use tracing::{debug, error, info};
+use tracing::{debug, error, info, warn};
 use uuid::Uuid;
 
 /// Event bus for publishing and subscribing to events
@@ -26,6 +27,10 @@ pub struct EventBus {
     pattern_matcher: PatternMatcher,
     /// Optional event persistence
     persistence_manager: Option<Arc<tokio::sync::Mutex<Box<dyn EventPersistenceManagerTrait>>>>,
+    /// Optional sink for events that fail delivery
+    dead_letter: Option<Arc<DeadLetterQueue>>,
+    /// Held while draining the flow controller buffer to subscribers
+    delivery_lock: Arc<tokio::sync::Mutex<()>>,
 }
 
 /// Trait for type-erased persistence manager
@@ -49,11 +54,36 @@ impl<B: StorageBackend + 'static> EventPersistenceManagerTrait for EventPersiste
 /// Individual subscription
 #[derive(Debug)]
 struct Subscription {
-    #[allow(dead_code)] // Used for future unsubscribe functionality
     id: Uuid,
     #[allow(dead_code)] // Used for debugging and future pattern optimization
     pattern: EventPattern,
-    sender: mpsc::UnboundedSender<UniversalEvent>,
+    sender: SubscriptionSender,
+}
+
+/// Delivery channel for a subscription
+#[derive(Debug)]
+enum SubscriptionSender {
+    /// Never drops events; grows without bound for slow consumers
+    Unbounded(mpsc::UnboundedSender<UniversalEvent>),
+    /// Drops events once the subscriber falls `capacity` events behind
+    Bounded(mpsc::Sender<UniversalEvent>),
+}
+
+impl SubscriptionSender {
+    /// Try to deliver an event, returning the failure reason if it was not delivered
+    fn deliver(&self, event: UniversalEvent) -> Result<(), (UniversalEvent, DeadLetterReason)> {
+        match self {
+            Self::Unbounded(tx) => tx
+                .send(event)
+                .map_err(|e| (e.0, DeadLetterReason::SubscriberClosed)),
+            Self::Bounded(tx) => tx.try_send(event).map_err(|e| match e {
+                mpsc::error::TrySendError::Full(event) => (event, DeadLetterReason::SubscriberFull),
+                mpsc::error::TrySendError::Closed(event) => {
+                    (event, DeadLetterReason::SubscriberClosed)
+                }
+            }),
+        }
+    }
 }
 
 impl EventBus {
@@ -72,6 +102,8 @@ impl EventBus {
             broadcast_tx,
             pattern_matcher: PatternMatcher::new(),
             persistence_manager: None,
+            dead_letter: None,
+            delivery_lock: Arc::new(tokio::sync::Mutex::new(())),
         }
     }
 
@@ -92,13 +124,19 @@ impl EventBus {
             persistence_manager: Some(Arc::new(tokio::sync::Mutex::new(
                 Box::new(persistence_manager) as Box<dyn EventPersistenceManagerTrait>,
             ))),
+            dead_letter: None,
+            delivery_lock: Arc::new(tokio::sync::Mutex::new(())),
         }
     }
 
     /// Publish an event to the bus
+    ///
+    /// Accepted events are buffered by the flow controller and delivered in
+    /// publish order; the call returns once the buffer has been drained.
     pub async fn publish(&self, event: UniversalEvent) -> Result<(), PublishError> {
         // Check rate limiting
         if !self.flow_controller.can_process(&event).await {
+            self.dead_letter(event, DeadLetterReason::RateLimited, None);
             return Err(PublishError::RateLimited);
         }
 
@@ -109,16 +147,48 @@ impl EventBus {
                 // Continue with publish
             }
             crate::overflow::OverflowResult::Dropped { reason } => {
+                self.dead_letter(
+                    event,
+                    DeadLetterReason::Overflow {
+                        reason: reason.clone(),
+                    },
+                    None,
+                );
                 return Err(PublishError::Dropped { reason });
             }
             crate::overflow::OverflowResult::Rejected { reason } => {
+                self.dead_letter(
+                    event,
+                    DeadLetterReason::Rejected {
+                        reason: reason.clone(),
+                    },
+                    None,
+                );
                 return Err(PublishError::Rejected { reason });
             }
             crate::overflow::OverflowResult::Blocked => {
+                self.dead_letter(event, DeadLetterReason::Blocked, None);
                 return Err(PublishError::Blocked);
             }
         }
 
+        self.deliver_buffered().await;
+        Ok(())
+    }
+
+    /// Deliver buffered events until the flow controller buffer is empty
+    ///
+    /// One publisher drains at a time, so events published meanwhile wait in
+    /// the buffer, where backpressure and load shedding apply to them.
+    async fn deliver_buffered(&self) {
+        let _delivering = self.delivery_lock.lock().await;
+        while let Some(event) = self.flow_controller.pop_event() {
+            self.deliver(event).await;
+        }
+    }
+
+    /// Deliver an event to broadcast receivers, persistence and subscriptions
+    async fn deliver(&self, event: UniversalEvent) {
         // Send to broadcast channel
         if self.broadcast_tx.send(event.clone()).is_err() {
             debug!("No broadcast receivers for event: {}", event.event_type);
@@ -135,8 +205,6 @@ impl EventBus {
 
         // Route to pattern-matched subscriptions
         self.route_event(event).await;
-
-        Ok(())
     }
 
     /// Subscribe to events matching a pattern
@@ -144,13 +212,36 @@ impl EventBus {
         &self,
         pattern: &str,
     ) -> Result<mpsc::UnboundedReceiver<UniversalEvent>, SubscribeError> {
-        let event_pattern = EventPattern::new(pattern)?;
         let (tx, rx) = mpsc::unbounded_channel();
+        self.add_subscription(pattern, SubscriptionSender::Unbounded(tx))?;
+        Ok(rx)
+    }
+
+    /// Subscribe with a bounded channel that drops events once the subscriber
+    /// falls `capacity` events behind; drops are sent to the dead-letter queue
+    pub async fn subscribe_bounded(
+        &self,
+        pattern: &str,
+        capacity: usize,
+    ) -> Result<mpsc::Receiver<UniversalEvent>, SubscribeError> {
+        let (tx, rx) = mpsc::channel(capacity.max(1));
+        self.add_subscription(pattern, SubscriptionSender::Bounded(tx))?;
+        Ok(rx)
+    }
+
+    /// Register a subscription for a pattern
+    fn add_subscription(
+        &self,
+        pattern: &str,
+        sender: SubscriptionSender,
+    ) -> Result<Uuid, SubscribeError> {
+        let event_pattern = EventPattern::new(pattern)?;
+        let id = Uuid::new_v4();
 
         let subscription = Subscription {
-            id: Uuid::new_v4(),
-            pattern: event_pattern.clone(),
-            sender: tx,
+            id,
+            pattern: event_pattern,
+            sender,
         };
 
         // Add to subscriptions
@@ -160,7 +251,7 @@ impl EventBus {
             .push(subscription);
 
         info!("New subscription created for pattern: {}", pattern);
-        Ok(rx)
+        Ok(id)
     }
 
     /// Subscribe with a custom event handler
@@ -208,10 +299,22 @@ impl EventBus {
 
             if self.pattern_matcher.matches(&event.event_type, pattern) {
                 for subscription in subscriptions {
-                    if subscription.sender.send(event.clone()).is_err() {
-                        debug!("Subscription receiver dropped for pattern: {}", pattern);
-                    } else {
-                        matched_count += 1;
+                    match subscription.sender.deliver(event.clone()) {
+                        Ok(()) => matched_count += 1,
+                        Err((event, reason)) => {
+                            debug!(
+                                "Delivery to subscription {} ({}) failed: {:?}",
+                                subscription.id, pattern, reason
+                            );
+                            self.dead_letter(
+                                event,
+                                reason,
+                                Some(DeadLetterTarget {
+                                    subscription_id: subscription.id,
+                                    pattern: pattern.clone(),
+                                }),
+                            );
+                        }
                     }
                 }
             }
@@ -227,6 +330,27 @@ impl EventBus {
         }
     }
 
+    /// Send an undelivered event to the dead-letter queue, if configured
+    fn dead_letter(
+        &self,
+        event: UniversalEvent,
+        reason: DeadLetterReason,
+        target: Option<DeadLetterTarget>,
+    ) {
+        if let Some(dlq) = &self.dead_letter {
+            warn!(
+                "Dead-lettering event {} ({}): {:?}",
+                event.id, event.event_type, reason
+            );
+            dlq.push(event, reason, target);
+        }
+    }
+
+    /// Get the dead-letter queue, if configured
+    pub fn dead_letter_queue(&self) -> Option<Arc<DeadLetterQueue>> {
+        self.dead_letter.clone()
+    }
+
     /// Get current buffer size
     pub fn buffer_size(&self) -> usize {
         self.flow_controller.buffer_size()
@@ -299,6 +423,7 @@ pub struct EventBusBuilder {
     flow_config: FlowControllerConfig,
     broadcast_capacity: usize,
     persistence_config: Option<(Box<dyn EventPersistenceManagerTrait>, PersistenceConfig)>,
+    dead_letter: Option<Arc<DeadLetterQueue>>,
 }
 
 impl EventBusBuilder {
@@ -308,6 +433,7 @@ impl EventBusBuilder {
             flow_config: FlowControllerConfig::default(),
             broadcast_capacity: 10000,
             persistence_config: None,
+            dead_letter: None,
         }
     }
 
@@ -334,6 +460,12 @@ impl EventBusBuilder {
         self
     }
 
+    /// Send events that fail delivery to a dead-letter queue
+    pub fn with_dead_letter(mut self, sink: Arc<DeadLetterQueue>) -> Self {
+        self.dead_letter = Some(sink);
+        self
+    }
+
     /// Build the event bus
     pub fn build(self) -> EventBus {
         if let Some((manager, _)) = self.persistence_config {
@@ -344,9 +476,13 @@ impl EventBusBuilder {
                 broadcast_tx,
                 pattern_matcher: PatternMatcher::new(),
                 persistence_manager: Some(Arc::new(tokio::sync::Mutex::new(manager))),
+                dead_letter: self.dead_letter,
+                delivery_lock: Arc::new(tokio::sync::Mutex::new(())),
             }
         } else {
-            EventBus::with_config(self.flow_config)
+            let mut bus = EventBus::with_config(self.flow_config);
+            bus.dead_letter = self.dead_letter;
+            bus
         }
     }
 }
@@ -471,4 +607,91 @@ mod tests {
 
         assert_eq!(bus.subscription_count(), 0);
     }
+    #[tokio::test]
+    async fn test_dead_letter_for_slow_subscriber() {
+        let dlq = Arc::new(DeadLetterQueue::new(10));
+        let bus = EventBusBuilder::new().with_dead_letter(dlq.clone()).build();
+
+        // Slow subscriber that never reads; capacity of 2 before drops begin
+        let _slow = bus.subscribe_bounded("slow.*", 2).await.unwrap();
+        let mut fast = bus.subscribe("slow.*").await.unwrap();
+
+        for i in 0..5 {
+            bus.publish(create_test_event(&format!("slow.event{i}")))
+                .await
+                .unwrap();
+        }
+
+        // Unbounded subscriber receives everything
+        for _ in 0..5 {
+            assert!(fast.recv().await.is_some());
+        }
+
+        let dead = dlq.entries();
+        assert_eq!(dead.len(), 3);
+        for (i, letter) in dead.iter().enumerate() {
+            assert_eq!(letter.event.event_type, format!("slow.event{}", i + 2));
+            assert_eq!(letter.reason, DeadLetterReason::SubscriberFull);
+            assert_eq!(letter.target.as_ref().unwrap().pattern, "slow.*");
+        }
+    }
+    #[tokio::test]
+    async fn test_priority_shedding_under_flood() {
+        use crate::overflow::{EventPriority, EventPriorityMap, OverflowConfig, OverflowStrategy};
+        use futures::FutureExt;
+
+        let priorities = EventPriorityMap::new()
+            .with_rule("error.*", EventPriority::High)
+            .with_rule("debug.*", EventPriority::Low);
+        let bus = EventBus::with_config(FlowControllerConfig {
+            rate_limit: None,
+            overflow_config: OverflowConfig::new(OverflowStrategy::DropNewest, 10)
+                .with_priorities(priorities),
+            ..Default::default()
+        });
+        let mut receiver = bus.subscribe_all();
+
+        // Stall delivery so published events back up in the buffer
+        let delivering = bus.delivery_lock.lock().await;
+
+        // Three times the buffer capacity, two debug events for every error.
+        // Queued events wait for delivery; shed events fail immediately.
+        let mut shed = 0;
+        for i in 0..30 {
+            let event_type = if i % 3 == 0 {
+                "error.failed"
+            } else {
+                "debug.trace"
+            };
+            match bus.publish(create_test_event(event_type)).now_or_never() {
+                None => {}
+                Some(Err(PublishError::Dropped { .. })) => {
+                    assert_eq!(event_type, "debug.trace", "error event {i} was shed");
+                    shed += 1;
+                }
+                Some(other) => panic!("event {i} was not queued: {other:?}"),
+            }
+        }
+        // Debug events are only admitted below the high water mark (8), and
+        // errors arriving at a full buffer evict the ones that were admitted
+        assert_eq!(shed, 15);
+        assert_eq!(bus.buffer_size(), 10);
+        assert_eq!(bus.get_stats().events_shed, 20);
+
+        // Once delivery resumes the buffer drains and only errors are delivered
+        drop(delivering);
+        bus.deliver_buffered().await;
+        assert_eq!(bus.buffer_size(), 0);
+        let mut delivered = std::collections::HashMap::new();
+        while let Ok(event) = receiver.try_recv() {
+            *delivered.entry(event.event_type).or_insert(0) += 1;
+        }
+        assert_eq!(delivered.get("error.failed"), Some(&10));
+        assert_eq!(delivered.get("debug.trace"), None);
+
+        // A drained bus delivers low-priority events again
+        bus.publish(create_test_event("debug.trace")).await.unwrap();
+        assert_eq!(receiver.try_recv().unwrap().event_type, "debug.trace");
+        assert_eq!(bus.get_stats().events_shed, 20);
+    }
 }
@@ -7,7 +7,7 @@ pub mod timeline;
 use crate::universal_event::UniversalEvent;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
-use std::collections::{HashMap, VecDeque};
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::sync::{Arc, RwLock};
 use std::time::Duration;
 use uuid::Uuid;
@@ -189,6 +189,8 @@ pub struct EventCorrelationTracker {
     correlations: Arc<RwLock<HashMap<Uuid, VecDeque<UniversalEvent>>>>,
     /// Links between events
     links: Arc<RwLock<HashMap<Uuid, Vec<EventLink>>>>,
+    /// `CausedBy` links keyed by the caused event, for ancestry lookups
+    causes: Arc<RwLock<HashMap<Uuid, Vec<EventLink>>>>,
     /// Correlation contexts
     contexts: Arc<RwLock<HashMap<Uuid, CorrelationContext>>>,
     /// Statistics
@@ -210,6 +212,7 @@ impl EventCorrelationTracker {
             config,
             correlations: Arc::new(RwLock::new(HashMap::new())),
             links: Arc::new(RwLock::new(HashMap::new())),
+            causes: Arc::new(RwLock::new(HashMap::new())),
             contexts: Arc::new(RwLock::new(HashMap::new())),
             stats: Arc::new(RwLock::new(CorrelationStats::default())),
             event_index: Arc::new(RwLock::new(HashMap::new())),
@@ -279,6 +282,15 @@ impl EventCorrelationTracker {
 
     /// Add a manual link between events
     pub fn add_link(&self, link: EventLink) {
+        if link.relationship == EventRelationship::CausedBy {
+            self.causes
+                .write()
+                .unwrap()
+                .entry(link.to_event_id)
+                .or_default()
+                .push(link.clone());
+        }
+
         let mut links = self.links.write().unwrap();
         links
             .entry(link.from_event_id)
@@ -319,6 +331,38 @@ impl EventCorrelationTracker {
             .unwrap_or_default()
     }
 
+    /// Get the causal ancestry of an event, root cause first and the event itself last
+    ///
+    /// Follows `CausedBy` links (strongest first when an event has several causes).
+    /// Stops on cycles; ancestors that are no longer tracked are omitted.
+    pub fn causal_chain(&self, event_id: &Uuid) -> Vec<UniversalEvent> {
+        let mut chain_ids = vec![*event_id];
+        let mut visited = HashSet::from([*event_id]);
+
+        {
+            let causes = self.causes.read().unwrap();
+            let mut current = *event_id;
+            while let Some(cause) = causes.get(&current).and_then(|links| {
+                links
+                    .iter()
+                    .max_by(|a, b| a.strength.total_cmp(&b.strength))
+                    .map(|link| link.from_event_id)
+            }) {
+                if !visited.insert(cause) {
+                    break;
+                }
+                chain_ids.push(cause);
+                current = cause;
+            }
+        }
+
+        chain_ids
+            .into_iter()
+            .rev()
+            .filter_map(|id| self.find_event(&id))
+            .collect()
+    }
+
     /// Get all correlations
     pub fn get_all_correlations(&self) -> HashMap<Uuid, Vec<UniversalEvent>> {
         self.correlations
@@ -357,11 +401,24 @@ impl EventCorrelationTracker {
     pub fn clear(&self) {
         self.correlations.write().unwrap().clear();
         self.links.write().unwrap().clear();
+        self.causes.write().unwrap().clear();
         self.contexts.write().unwrap().clear();
         self.event_index.write().unwrap().clear();
         *self.stats.write().unwrap() = CorrelationStats::default();
     }
 
+    /// Look up a tracked event by ID
+    fn find_event(&self, event_id: &Uuid) -> Option<UniversalEvent> {
+        let (correlation_id, _) = *self.event_index.read().unwrap().get(event_id)?;
+        self.correlations
+            .read()
+            .unwrap()
+            .get(&correlation_id)?
+            .iter()
+            .find(|event| event.id == *event_id)
+            .cloned()
+    }
+
     /// Auto-detect links between events
     fn auto_detect_links(&self, event: &UniversalEvent) {
         let correlation_id = event.metadata.correlation_id;
@@ -431,6 +488,7 @@ impl EventCorrelationTracker {
         let mut event_index = self.event_index.write().unwrap();
         let mut contexts = self.contexts.write().unwrap();
         let mut links = self.links.write().unwrap();
+        let mut causes = self.causes.write().unwrap();
 
         // Remove correlations that are too old
         correlations.retain(|correlation_id, events| {
@@ -440,6 +498,7 @@ impl EventCorrelationTracker {
                     for event in events.iter() {
                         event_index.remove(&event.id);
                         links.remove(&event.id);
+                        causes.remove(&event.id);
                     }
                     contexts.remove(correlation_id);
                     return false;
@@ -466,6 +525,7 @@ impl EventCorrelationTracker {
                     for event in events.iter() {
                         event_index.remove(&event.id);
                         links.remove(&event.id);
+                        causes.remove(&event.id);
                     }
                     contexts.remove(&correlation_id);
                 }
@@ -560,4 +620,31 @@ mod tests {
         assert!(context.has_tag("important"));
         assert!(!context.has_tag("unimportant"));
     }
+    #[test]
+    fn test_causal_chain() {
+        let tracker = EventCorrelationTracker::default();
+        let correlation_id = Uuid::new_v4();
+
+        let events: Vec<UniversalEvent> = ["a", "b", "c"]
+            .iter()
+            .map(|name| {
+                let mut event = UniversalEvent::new(*name, Value::Null, Language::Rust);
+                event.metadata.correlation_id = correlation_id;
+                tracker.track_event(event.clone());
+                event
+            })
+            .collect();
+        let (a, b, c) = (events[0].id, events[1].id, events[2].id);
+
+        tracker.add_link(EventLink::new(a, b, EventRelationship::CausedBy));
+        tracker.add_link(EventLink::new(b, c, EventRelationship::CausedBy));
+
+        let chain: Vec<Uuid> = tracker.causal_chain(&c).iter().map(|e| e.id).collect();
+        assert_eq!(chain, vec![a, b, c]);
+
+        // A malformed cyclic link must not loop forever
+        tracker.add_link(EventLink::new(c, a, EventRelationship::CausedBy));
+        let chain: Vec<Uuid> = tracker.causal_chain(&c).iter().map(|e| e.id).collect();
+        assert_eq!(chain, vec![a, b, c]);
+    }
 }
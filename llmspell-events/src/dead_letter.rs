@@ -0,0 +1,159 @@
+// ABOUTME: Dead-letter queue capturing events that failed delivery on the EventBus
+// ABOUTME: Bounded in-memory sink recording drop reason and target subscriber for inspection
+
+use crate::universal_event::UniversalEvent;
+use chrono::{DateTime, Utc};
+use parking_lot::RwLock;
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicU64, Ordering};
+use uuid::Uuid;
+
+/// Default maximum number of dead letters retained
+pub const DEFAULT_DEAD_LETTER_CAPACITY: usize = 1000;
+
+/// Why an event was dead-lettered
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DeadLetterReason {
+    /// Subscriber channel was at capacity
+    SubscriberFull,
+    /// Subscriber receiver was dropped
+    SubscriberClosed,
+    /// Dropped by the flow controller's overflow strategy
+    Overflow { reason: String },
+    /// Rejected by the flow controller's overflow strategy
+    Rejected { reason: String },
+    /// Publisher was blocked by the flow controller
+    Blocked,
+    /// Exceeded the flow controller's rate limit
+    RateLimited,
+}
+
+/// Subscriber an event failed to reach
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DeadLetterTarget {
+    /// Subscription ID
+    pub subscription_id: Uuid,
+    /// Pattern the subscription was registered with
+    pub pattern: String,
+}
+
+/// An event that failed delivery
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeadLetter {
+    /// The undelivered event
+    pub event: UniversalEvent,
+    /// Why delivery failed
+    pub reason: DeadLetterReason,
+    /// Target subscriber, if the failure was subscriber-specific
+    pub target: Option<DeadLetterTarget>,
+    /// When the event was dead-lettered
+    pub dead_lettered_at: DateTime<Utc>,
+}
+
+/// Bounded dead-letter queue; the oldest entries are evicted once full
+#[derive(Debug)]
+pub struct DeadLetterQueue {
+    entries: RwLock<VecDeque<DeadLetter>>,
+    capacity: usize,
+    evicted: AtomicU64,
+}
+
+impl DeadLetterQueue {
+    /// Create a queue retaining at most `capacity` dead letters
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            entries: RwLock::new(VecDeque::new()),
+            capacity,
+            evicted: AtomicU64::new(0),
+        }
+    }
+
+    /// Record an undelivered event
+    pub fn push(
+        &self,
+        event: UniversalEvent,
+        reason: DeadLetterReason,
+        target: Option<DeadLetterTarget>,
+    ) {
+        if self.capacity == 0 {
+            self.evicted.fetch_add(1, Ordering::Relaxed);
+            return;
+        }
+
+        let mut entries = self.entries.write();
+        while entries.len() >= self.capacity {
+            entries.pop_front();
+            self.evicted.fetch_add(1, Ordering::Relaxed);
+        }
+        entries.push_back(DeadLetter {
+            event,
+            reason,
+            target,
+            dead_lettered_at: Utc::now(),
+        });
+    }
+
+    /// Get a snapshot of all dead letters, oldest first
+    pub fn entries(&self) -> Vec<DeadLetter> {
+        self.entries.read().iter().cloned().collect()
+    }
+
+    /// Remove and return all dead letters for reprocessing
+    pub fn drain(&self) -> Vec<DeadLetter> {
+        self.entries.write().drain(..).collect()
+    }
+
+    /// Number of dead letters currently held
+    pub fn len(&self) -> usize {
+        self.entries.read().len()
+    }
+
+    /// Check if the queue is empty
+    pub fn is_empty(&self) -> bool {
+        self.entries.read().is_empty()
+    }
+
+    /// Maximum number of dead letters retained
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Number of dead letters evicted because the queue was full
+    pub fn evicted_count(&self) -> u64 {
+        self.evicted.load(Ordering::Relaxed)
+    }
+}
+
+impl Default for DeadLetterQueue {
+    fn default() -> Self {
+        Self::new(DEFAULT_DEAD_LETTER_CAPACITY)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::universal_event::Language;
+
+    fn event(event_type: &str) -> UniversalEvent {
+        UniversalEvent::new(event_type, serde_json::Value::Null, Language::Rust)
+    }
+
+    #[test]
+    fn test_queue_is_bounded() {
+        let dlq = DeadLetterQueue::new(2);
+        dlq.push(event("a"), DeadLetterReason::SubscriberFull, None);
+        dlq.push(event("b"), DeadLetterReason::SubscriberFull, None);
+        dlq.push(event("c"), DeadLetterReason::SubscriberClosed, None);
+
+        let entries = dlq.entries();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].event.event_type, "b");
+        assert_eq!(entries[1].event.event_type, "c");
+        assert_eq!(dlq.evicted_count(), 1);
+
+        assert_eq!(dlq.drain().len(), 2);
+        assert!(dlq.is_empty());
+    }
+}
@@ -1,7 +1,9 @@
 // ABOUTME: FlowController for rate limiting and backpressure handling in event bus
 // ABOUTME: Implements token bucket algorithm with configurable rates and burst limits
 
-use crate::overflow::{OverflowConfig, OverflowHandler, OverflowHandlerFactory, OverflowResult};
+use crate::overflow::{
+    EventPriority, OverflowConfig, OverflowHandler, OverflowHandlerFactory, OverflowResult,
+};
 use crate::universal_event::UniversalEvent;
 use parking_lot::RwLock;
 use std::collections::VecDeque;
@@ -92,6 +94,9 @@ pub struct FlowStats {
     pub events_rejected: u64,
     /// Events blocked
     pub events_blocked: u64,
+    /// Events shed by priority, either dropped on arrival or evicted from the
+    /// buffer before delivery
+    pub events_shed: u64,
     /// Rate limit violations
     pub rate_limit_violations: u64,
     /// Current buffer size
@@ -221,6 +226,12 @@ impl FlowController {
         let buffer_size = self.buffer.read().len();
         let max_size = self.config.overflow_config.max_buffer_size;
 
+        if !self.config.overflow_config.priorities.is_empty() {
+            if let Some(result) = self.shed_by_priority(&event, buffer_size) {
+                return result;
+            }
+        }
+
         if !self.config.overflow_config.is_full(buffer_size) {
             // Buffer not full, add event
             self.buffer.write().push_back(event.clone());
@@ -276,6 +287,71 @@ impl FlowController {
         result
     }
 
+    /// Apply priority-aware load shedding
+    ///
+    /// The buffer holds events awaiting delivery, so both paths drop events
+    /// that are never delivered. A full buffer admits an event by evicting its
+    /// oldest lowest-priority event. Low-priority events are dropped while the
+    /// buffer is at or above the high water mark. Returns `None` when the event
+    /// takes the normal overflow path.
+    fn shed_by_priority(
+        &self,
+        event: &UniversalEvent,
+        buffer_size: usize,
+    ) -> Option<OverflowResult> {
+        let overflow_config = &self.config.overflow_config;
+        let priorities = &overflow_config.priorities;
+        let priority = priorities.priority_for(&event.event_type);
+
+        if overflow_config.is_full(buffer_size) {
+            let mut buffer = self.buffer.write();
+            let victim = buffer
+                .iter()
+                .enumerate()
+                .map(|(index, buffered)| (index, priorities.priority_for(&buffered.event_type)))
+                .filter(|(_, buffered_priority)| *buffered_priority < priority)
+                .min_by_key(|(_, buffered_priority)| *buffered_priority)
+                .map(|(index, _)| index);
+
+            if let Some(evicted) = victim.and_then(|index| buffer.remove(index)) {
+                buffer.push_back(event.clone());
+                drop(buffer);
+
+                debug!(
+                    "Evicted {:?}-priority event {} to admit {}",
+                    priorities.priority_for(&evicted.event_type),
+                    evicted.event_type,
+                    event.event_type
+                );
+                self.update_stats(|stats| {
+                    stats.events_processed += 1;
+                    stats.events_dropped += 1;
+                    stats.events_shed += 1;
+                });
+                return Some(OverflowResult::Accepted);
+            }
+        }
+
+        if priority == EventPriority::Low && overflow_config.is_high_water(buffer_size) {
+            debug!(
+                "Shedding low-priority event {} ({}/{})",
+                event.event_type, buffer_size, overflow_config.max_buffer_size
+            );
+            self.update_stats(|stats| {
+                stats.events_dropped += 1;
+                stats.events_shed += 1;
+            });
+            return Some(OverflowResult::Dropped {
+                reason: format!(
+                    "Shedding low-priority event under backpressure ({}/{})",
+                    buffer_size, overflow_config.max_buffer_size
+                ),
+            });
+        }
+
+        None
+    }
+
     /// Get next event from buffer
     pub fn pop_event(&self) -> Option<UniversalEvent> {
         let event = self.buffer.write().pop_front();
@@ -56,9 +56,11 @@
 
 pub mod bus;
 pub mod correlation;
+pub mod dead_letter;
 pub mod flow_controller;
 pub mod handler;
 pub mod metrics;
+pub mod otlp;
 pub mod overflow;
 pub mod pattern;
 pub mod serialization;
@@ -69,16 +71,21 @@ pub mod universal_event;
 // Re-export main types
 pub use bus::{EventBus, EventBusBuilder};
 pub use correlation::{CorrelationContext, EventCorrelationTracker, EventLink, EventRelationship};
+pub use dead_letter::{DeadLetter, DeadLetterQueue, DeadLetterReason, DeadLetterTarget};
 pub use flow_controller::{BackpressureNotification, FlowController};
 pub use handler::{AsyncEventHandler, EventHandler};
 pub use metrics::{EventMetrics, MetricsCollector};
-pub use overflow::{OverflowHandler, OverflowStrategy};
+pub use otlp::{OtlpExportConfig, OtlpExportHandle, OtlpExporter};
+pub use overflow::{EventPriority, EventPriorityMap, OverflowHandler, OverflowStrategy};
 pub use pattern::{EventPattern, PatternMatcher};
-pub use serialization::EventSerializer;
+pub use serialization::{EventCodec, EventSerializer};
 pub use storage_adapter::{
     EventPersistenceManager, EventStorage, EventStorageAdapter, PersistenceConfig, StorageStats,
 };
-pub use stream::{EventStream, HighThroughputProcessor, StreamUtils, ThroughputMeasurement};
+pub use stream::{
+    EventStream, EventWindow, HighThroughputProcessor, StreamUtils, ThroughputMeasurement,
+    WindowAggregate,
+};
 pub use universal_event::{EventMetadata, Language, UniversalEvent};
 
 /// Prelude module for convenient imports
@@ -0,0 +1,501 @@
+// ABOUTME: OTLP exporter shipping EventBus events to an OpenTelemetry collector as log records
+// ABOUTME: Applies type/component filters and batches events, flushing on size, interval and shutdown
+
+use crate::bus::EventBus;
+use crate::universal_event::UniversalEvent;
+use anyhow::Context;
+use llmspell_core::traits::event::EventConfig;
+use serde_json::{json, Value};
+use std::time::Duration;
+use tokio::sync::{broadcast::error::RecvError, oneshot};
+use tokio::task::JoinHandle;
+use tracing::{debug, warn};
+
+/// Default number of events sent per OTLP request
+pub const DEFAULT_OTLP_BATCH_SIZE: usize = 512;
+
+/// Default interval at which partial batches are flushed
+pub const DEFAULT_OTLP_FLUSH_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Default timeout for a single export request
+pub const DEFAULT_OTLP_REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// OTLP/HTTP path for log export
+const LOGS_PATH: &str = "/v1/logs";
+
+/// OTLP severity numbers (see the OpenTelemetry logs data model)
+const SEVERITY_INFO: u8 = 9;
+const SEVERITY_ERROR: u8 = 17;
+
+/// Configuration for [`OtlpExporter`]
+#[derive(Debug, Clone)]
+pub struct OtlpExportConfig {
+    /// Collector base URL (OTLP/HTTP), e.g. `http://localhost:4318`
+    pub endpoint: String,
+    /// Maximum events per request
+    pub batch_size: usize,
+    /// Interval at which partial batches are flushed
+    pub flush_interval: Duration,
+    /// Timeout for a single export request
+    pub request_timeout: Duration,
+    /// Event types to export (glob patterns, empty exports all)
+    pub include_types: Vec<String>,
+    /// Event types never exported (glob patterns)
+    pub exclude_types: Vec<String>,
+    /// Source components to export (glob patterns, empty exports all)
+    pub include_components: Vec<String>,
+    /// Source components never exported (glob patterns)
+    pub exclude_components: Vec<String>,
+    /// `service.name` resource attribute
+    pub service_name: String,
+}
+
+impl OtlpExportConfig {
+    /// Export every event to `endpoint` with default batching
+    pub fn new(endpoint: impl Into<String>) -> Self {
+        Self {
+            endpoint: endpoint.into(),
+            batch_size: DEFAULT_OTLP_BATCH_SIZE,
+            flush_interval: DEFAULT_OTLP_FLUSH_INTERVAL,
+            request_timeout: DEFAULT_OTLP_REQUEST_TIMEOUT,
+            include_types: Vec::new(),
+            exclude_types: Vec::new(),
+            include_components: Vec::new(),
+            exclude_components: Vec::new(),
+            service_name: "llmspell".to_string(),
+        }
+    }
+
+    /// Set the maximum events per request
+    #[must_use]
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size.max(1);
+        self
+    }
+
+    /// Set the interval at which partial batches are flushed
+    #[must_use]
+    pub fn with_flush_interval(mut self, flush_interval: Duration) -> Self {
+        self.flush_interval = flush_interval;
+        self
+    }
+
+    /// Set the timeout for a single export request
+    ///
+    /// An unresponsive collector stalls the exporter for at most this long per batch.
+    #[must_use]
+    pub fn with_request_timeout(mut self, request_timeout: Duration) -> Self {
+        self.request_timeout = request_timeout;
+        self
+    }
+
+    /// Filter exported events by type
+    #[must_use]
+    pub fn with_type_filter(mut self, include: Vec<String>, exclude: Vec<String>) -> Self {
+        self.include_types = include;
+        self.exclude_types = exclude;
+        self
+    }
+
+    /// Filter exported events by source component
+    #[must_use]
+    pub fn with_component_filter(mut self, include: Vec<String>, exclude: Vec<String>) -> Self {
+        self.include_components = include;
+        self.exclude_components = exclude;
+        self
+    }
+}
+
+/// Exports events to an OpenTelemetry collector as OTLP/HTTP JSON log records
+///
+/// Each event becomes one log record: the event type is the `event.name`
+/// attribute, the payload is the body and the correlation ID is the trace ID,
+/// so events of one correlated flow group together in the collector.
+#[derive(Debug)]
+pub struct OtlpExporter {
+    config: OtlpExportConfig,
+    type_filter: EventConfig,
+    component_filter: EventConfig,
+    client: reqwest::Client,
+}
+
+impl OtlpExporter {
+    /// Create an exporter
+    pub fn new(config: OtlpExportConfig) -> Self {
+        // Component IDs use the same glob rules as event types
+        let type_filter = EventConfig {
+            include_types: config.include_types.clone(),
+            exclude_types: config.exclude_types.clone(),
+            ..EventConfig::default()
+        };
+        let component_filter = EventConfig {
+            include_types: config.include_components.clone(),
+            exclude_types: config.exclude_components.clone(),
+            ..EventConfig::default()
+        };
+        let client = reqwest::Client::builder()
+            .timeout(config.request_timeout)
+            .build()
+            .unwrap_or_else(|_| reqwest::Client::new());
+        Self {
+            config,
+            type_filter,
+            component_filter,
+            client,
+        }
+    }
+
+    /// Check an event against the type and component filters
+    ///
+    /// Events without a source component are only subject to the type filter.
+    pub fn should_export(&self, event: &UniversalEvent) -> bool {
+        let component_allowed = match event.metadata.source.as_deref() {
+            Some(source) => self.component_filter.should_emit(source),
+            None => true,
+        };
+        component_allowed && self.type_filter.should_emit(&event.event_type)
+    }
+
+    /// Encode events as an OTLP `ExportLogsServiceRequest` in JSON form
+    pub fn encode(&self, events: &[UniversalEvent]) -> Value {
+        let records: Vec<Value> = events.iter().map(log_record).collect();
+        json!({
+            "resourceLogs": [{
+                "resource": {
+                    "attributes": [string_attribute("service.name", &self.config.service_name)]
+                },
+                "scopeLogs": [{
+                    "scope": {
+                        "name": env!("CARGO_PKG_NAME"),
+                        "version": env!("CARGO_PKG_VERSION")
+                    },
+                    "logRecords": records
+                }]
+            }]
+        })
+    }
+
+    /// Send one batch of events to the collector
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the collector rejects it.
+    pub async fn export(&self, events: &[UniversalEvent]) -> anyhow::Result<()> {
+        if events.is_empty() {
+            return Ok(());
+        }
+        let url = self.logs_url();
+        let response = self
+            .client
+            .post(&url)
+            .json(&self.encode(events))
+            .send()
+            .await
+            .with_context(|| format!("Failed to send events to {}", url))?;
+        let status = response.status();
+        if !status.is_success() {
+            anyhow::bail!("OTLP collector at {} responded with {}", url, status);
+        }
+        debug!("Exported {} events to {}", events.len(), url);
+        Ok(())
+    }
+
+    /// Subscribe to all events on `bus` and export them in the background
+    pub fn spawn(self, bus: &EventBus) -> OtlpExportHandle {
+        let mut receiver = bus.subscribe_all();
+        let (shutdown_tx, mut shutdown_rx) = oneshot::channel();
+        let exporter = self;
+
+        let task = tokio::spawn(async move {
+            let mut batch = Vec::with_capacity(exporter.config.batch_size.min(1024));
+            let mut ticker = tokio::time::interval(exporter.config.flush_interval);
+            ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+            loop {
+                tokio::select! {
+                    received = receiver.recv() => match received {
+                        Ok(event) => {
+                            if exporter.should_export(&event) {
+                                batch.push(event);
+                                if batch.len() >= exporter.config.batch_size {
+                                    exporter.flush(&mut batch).await;
+                                }
+                            }
+                        }
+                        Err(RecvError::Lagged(skipped)) => {
+                            warn!("OTLP exporter lagged behind, {} events not exported", skipped);
+                        }
+                        Err(RecvError::Closed) => break,
+                    },
+                    _ = ticker.tick() => exporter.flush(&mut batch).await,
+                    Ok(()) = &mut shutdown_rx => {
+                        // Drain events published before shutdown was requested
+                        while let Ok(event) = receiver.try_recv() {
+                            if exporter.should_export(&event) {
+                                batch.push(event);
+                                if batch.len() >= exporter.config.batch_size {
+                                    exporter.flush(&mut batch).await;
+                                }
+                            }
+                        }
+                        break;
+                    }
+                }
+            }
+            exporter.flush(&mut batch).await;
+        });
+
+        OtlpExportHandle {
+            shutdown: shutdown_tx,
+            task,
+        }
+    }
+
+    /// Export and clear the pending batch, logging failures
+    async fn flush(&self, batch: &mut Vec<UniversalEvent>) {
+        if batch.is_empty() {
+            return;
+        }
+        if let Err(e) = self.export(batch).await {
+            warn!(
+                "Dropping {} events after OTLP export failure: {:#}",
+                batch.len(),
+                e
+            );
+        }
+        batch.clear();
+    }
+
+    fn logs_url(&self) -> String {
+        let endpoint = self.config.endpoint.trim_end_matches('/');
+        if endpoint.ends_with(LOGS_PATH) {
+            endpoint.to_string()
+        } else {
+            format!("{}{}", endpoint, LOGS_PATH)
+        }
+    }
+}
+
+/// Handle to a running exporter task
+///
+/// Dropping the handle leaves the exporter running until the bus is dropped.
+#[derive(Debug)]
+pub struct OtlpExportHandle {
+    shutdown: oneshot::Sender<()>,
+    task: JoinHandle<()>,
+}
+
+impl OtlpExportHandle {
+    /// Flush pending events and stop the exporter
+    pub async fn shutdown(self) {
+        // The task may already have stopped if the bus was dropped
+        let _ = self.shutdown.send(());
+        if let Err(e) = self.task.await {
+            warn!("OTLP exporter task failed: {}", e);
+        }
+    }
+}
+
+/// Map an event to an OTLP log record
+fn log_record(event: &UniversalEvent) -> Value {
+    let (severity_number, severity_text) = if EventConfig::is_critical_event(&event.event_type) {
+        (SEVERITY_ERROR, "ERROR")
+    } else {
+        (SEVERITY_INFO, "INFO")
+    };
+
+    let mut attributes = vec![
+        string_attribute("event.name", &event.event_type),
+        string_attribute("event.id", &event.id.to_string()),
+        string_attribute("event.language", event.language.as_str()),
+        int_attribute("event.sequence", event.sequence),
+        int_attribute("event.priority", i64::from(event.metadata.priority)),
+    ];
+    if let Some(source) = &event.metadata.source {
+        attributes.push(string_attribute("event.source", source));
+    }
+    if let Some(target) = &event.metadata.target {
+        attributes.push(string_attribute("event.target", target));
+    }
+    if !event.metadata.tags.is_empty() {
+        let tags: Vec<Value> = event
+            .metadata
+            .tags
+            .iter()
+            .map(|tag| json!({ "stringValue": tag }))
+            .collect();
+        attributes.push(json!({
+            "key": "event.tags",
+            "value": { "arrayValue": { "values": tags } }
+        }));
+    }
+
+    // OTLP/JSON encodes 64-bit integers as strings
+    let time_unix_nano = event
+        .timestamp
+        .timestamp_nanos_opt()
+        .unwrap_or_default()
+        .to_string();
+    json!({
+        "timeUnixNano": time_unix_nano,
+        "observedTimeUnixNano": time_unix_nano,
+        "severityNumber": severity_number,
+        "severityText": severity_text,
+        "body": { "stringValue": event.data.to_string() },
+        "attributes": attributes,
+        "traceId": event.metadata.correlation_id.simple().to_string(),
+    })
+}
+
+fn string_attribute(key: &str, value: &str) -> Value {
+    json!({ "key": key, "value": { "stringValue": value } })
+}
+
+fn int_attribute(key: &str, value: impl ToString) -> Value {
+    json!({ "key": key, "value": { "intValue": value.to_string() } })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::universal_event::Language;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+    use tokio::sync::mpsc;
+
+    /// Minimal OTLP/HTTP receiver forwarding each request path and JSON body
+    async fn mock_collector() -> (String, mpsc::UnboundedReceiver<(String, Value)>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let (tx, rx) = mpsc::unbounded_channel();
+
+        tokio::spawn(async move {
+            while let Ok((mut stream, _)) = listener.accept().await {
+                let tx = tx.clone();
+                tokio::spawn(async move {
+                    let mut request = Vec::new();
+                    let mut buf = [0u8; 4096];
+                    loop {
+                        let n = stream.read(&mut buf).await.unwrap();
+                        if n == 0 {
+                            return;
+                        }
+                        request.extend_from_slice(&buf[..n]);
+
+                        let text = String::from_utf8_lossy(&request);
+                        let Some(header_end) = text.find("\r\n\r\n") else {
+                            continue;
+                        };
+                        let headers = text[..header_end].to_ascii_lowercase();
+                        let content_length: usize = headers
+                            .lines()
+                            .find_map(|line| line.strip_prefix("content-length:"))
+                            .map_or(0, |len| len.trim().parse().unwrap());
+                        let body_start = header_end + 4;
+                        if request.len() < body_start + content_length {
+                            continue;
+                        }
+
+                        let path = text.split_whitespace().nth(1).unwrap().to_string();
+                        let body = serde_json::from_slice(
+                            &request[body_start..body_start + content_length],
+                        )
+                        .unwrap();
+                        tx.send((path, body)).unwrap();
+                        stream
+                            .write_all(b"HTTP/1.1 200 OK\r\ncontent-length: 2\r\n\r\n{}")
+                            .await
+                            .unwrap();
+                        request.clear();
+                    }
+                });
+            }
+        });
+
+        (format!("http://{}", addr), rx)
+    }
+
+    fn event_names(body: &Value) -> Vec<String> {
+        body["resourceLogs"][0]["scopeLogs"][0]["logRecords"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|record| {
+                record["attributes"]
+                    .as_array()
+                    .unwrap()
+                    .iter()
+                    .find(|attr| attr["key"] == "event.name")
+                    .unwrap()["value"]["stringValue"]
+                    .as_str()
+                    .unwrap()
+                    .to_string()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_should_export_applies_type_and_component_filters() {
+        let exporter = OtlpExporter::new(
+            OtlpExportConfig::new("http://localhost:4318")
+                .with_type_filter(vec!["agent.*".to_string()], vec!["*.debug".to_string()])
+                .with_component_filter(vec!["*".to_string()], vec!["noisy".to_string()]),
+        );
+        let event = |event_type: &str| UniversalEvent::new(event_type, json!({}), Language::Rust);
+
+        assert!(exporter.should_export(&event("agent.started")));
+        assert!(!exporter.should_export(&event("agent.debug")));
+        assert!(!exporter.should_export(&event("tool.started")));
+        assert!(!exporter.should_export(&event("agent.started").with_source("noisy")));
+        assert!(exporter.should_export(&event("agent.started").with_source("planner")));
+        assert_eq!(exporter.logs_url(), "http://localhost:4318/v1/logs");
+    }
+
+    #[tokio::test]
+    async fn test_exporter_sends_filtered_events_as_otlp_logs() {
+        let (endpoint, mut requests) = mock_collector().await;
+        let bus = EventBus::new();
+        let handle = OtlpExporter::new(
+            OtlpExportConfig::new(endpoint)
+                .with_batch_size(2)
+                .with_flush_interval(Duration::from_secs(60))
+                .with_type_filter(Vec::new(), vec!["*.debug".to_string()]),
+        )
+        .spawn(&bus);
+
+        let correlation_id = uuid::Uuid::new_v4();
+        for event_type in [
+            "agent.started",
+            "agent.debug",
+            "agent.failed",
+            "agent.completed",
+        ] {
+            let event = UniversalEvent::new(event_type, json!({"n": 1}), Language::Lua)
+                .with_correlation_id(correlation_id)
+                .with_source("planner");
+            bus.publish(event).await.unwrap();
+        }
+        handle.shutdown().await;
+
+        // A full batch of two is sent first, the remainder on shutdown
+        let (path, first) = requests.recv().await.unwrap();
+        assert_eq!(path, "/v1/logs");
+        assert_eq!(event_names(&first), vec!["agent.started", "agent.failed"]);
+        let (_, second) = requests.recv().await.unwrap();
+        assert_eq!(event_names(&second), vec!["agent.completed"]);
+        assert!(requests.try_recv().is_err());
+
+        let records = &first["resourceLogs"][0]["scopeLogs"][0]["logRecords"];
+        assert_eq!(
+            first["resourceLogs"][0]["resource"]["attributes"][0]["value"]["stringValue"],
+            "llmspell"
+        );
+        assert_eq!(records[0]["severityText"], "INFO");
+        assert_eq!(records[1]["severityNumber"], SEVERITY_ERROR);
+        assert_eq!(records[0]["body"]["stringValue"], r#"{"n":1}"#);
+        assert_eq!(
+            records[0]["traceId"],
+            correlation_id.simple().to_string().as_str()
+        );
+    }
+}
@@ -1,6 +1,7 @@
 // ABOUTME: Overflow strategies for handling backpressure and buffer limits
 // ABOUTME: Provides 4 strategies: DropOldest, DropNewest, Block, and Reject
 
+use crate::pattern::PatternMatcher;
 use crate::universal_event::UniversalEvent;
 use async_trait::async_trait;
 use std::sync::Arc;
@@ -201,6 +202,61 @@ impl OverflowHandlerFactory {
     }
 }
 
+/// Importance of an event when shedding load
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum EventPriority {
+    /// Shed first, as soon as the buffer reaches its high water mark
+    Low,
+    /// Kept until the buffer is full
+    #[default]
+    Normal,
+    /// Evicts lower-priority events when the buffer is full
+    High,
+}
+
+/// Maps event type patterns to priorities
+///
+/// Rules are checked in order and the first matching pattern wins; event
+/// types matching no rule get the default priority.
+#[derive(Debug, Clone, Default)]
+pub struct EventPriorityMap {
+    rules: Vec<(String, EventPriority)>,
+    default: EventPriority,
+}
+
+impl EventPriorityMap {
+    /// Create an empty map assigning every event the default priority
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Assign `priority` to event types matching `pattern` (e.g. `error.*`)
+    pub fn with_rule(mut self, pattern: impl Into<String>, priority: EventPriority) -> Self {
+        self.rules.push((pattern.into(), priority));
+        self
+    }
+
+    /// Set the priority of event types matching no rule
+    pub fn with_default(mut self, priority: EventPriority) -> Self {
+        self.default = priority;
+        self
+    }
+
+    /// Check if no rules are configured
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    /// Get the priority of an event type
+    pub fn priority_for(&self, event_type: &str) -> EventPriority {
+        let matcher = PatternMatcher::new();
+        self.rules
+            .iter()
+            .find(|(pattern, _)| matcher.matches(event_type, pattern))
+            .map_or(self.default, |(_, priority)| *priority)
+    }
+}
+
 /// Configuration for overflow handling
 #[derive(Debug, Clone)]
 pub struct OverflowConfig {
@@ -212,6 +268,8 @@ pub struct OverflowConfig {
     pub high_water_mark: usize,
     /// Low water mark for backpressure relief
     pub low_water_mark: usize,
+    /// Event priorities for load shedding (empty treats all events alike)
+    pub priorities: EventPriorityMap,
 }
 
 impl Default for OverflowConfig {
@@ -221,6 +279,7 @@ impl Default for OverflowConfig {
             max_buffer_size: 10000,
             high_water_mark: 8000,
             low_water_mark: 2000,
+            priorities: EventPriorityMap::default(),
         }
     }
 }
@@ -240,9 +299,16 @@ impl OverflowConfig {
             max_buffer_size,
             high_water_mark,
             low_water_mark,
+            priorities: EventPriorityMap::default(),
         }
     }
 
+    /// Shed events by priority instead of treating them uniformly
+    pub fn with_priorities(mut self, priorities: EventPriorityMap) -> Self {
+        self.priorities = priorities;
+        self
+    }
+
     /// Check if buffer size is at high water mark
     pub fn is_high_water(&self, size: usize) -> bool {
         size >= self.high_water_mark
@@ -311,6 +377,27 @@ mod tests {
         assert!(!config.is_full(999));
     }
     #[test]
+    fn test_event_priority_map() {
+        let priorities = EventPriorityMap::new()
+            .with_rule("error.critical", EventPriority::High)
+            .with_rule("error.*", EventPriority::Normal)
+            .with_rule("debug.*", EventPriority::Low)
+            .with_default(EventPriority::Low);
+
+        assert_eq!(
+            priorities.priority_for("error.critical"),
+            EventPriority::High
+        );
+        assert_eq!(
+            priorities.priority_for("error.timeout"),
+            EventPriority::Normal
+        );
+        assert_eq!(priorities.priority_for("debug.trace"), EventPriority::Low);
+        assert_eq!(priorities.priority_for("agent.started"), EventPriority::Low);
+        assert!(EventPriorityMap::new().is_empty());
+        assert!(EventPriority::High > EventPriority::Normal);
+    }
+    #[test]
     fn test_overflow_result() {
         assert!(OverflowResult::Accepted.is_success());
         assert!(!OverflowResult::Dropped {
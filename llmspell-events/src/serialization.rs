@@ -1,13 +1,106 @@
-// ABOUTME: Basic event serialization for JSON format
-// ABOUTME: Handles core JSON serialization for UniversalEvent
+// ABOUTME: Event serialization with pluggable codecs (JSON, MessagePack, CBOR)
+// ABOUTME: Binary envelopes carry a codec tag so events decode with the codec they were written with
 
 use crate::universal_event::UniversalEvent;
-use anyhow::Result;
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
 
-/// Event serializer for JSON format
-pub struct EventSerializer;
+/// Wire format used to encode events
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum EventCodec {
+    /// JSON (human-readable, widest interop)
+    #[default]
+    Json,
+    /// MessagePack (compact binary)
+    MessagePack,
+    /// CBOR (compact binary, RFC 8949)
+    Cbor,
+}
+
+impl EventCodec {
+    /// Tag byte written at the start of an envelope
+    pub fn tag(&self) -> u8 {
+        match self {
+            EventCodec::Json => 0x01,
+            EventCodec::MessagePack => 0x02,
+            EventCodec::Cbor => 0x03,
+        }
+    }
+
+    /// Look up a codec by its envelope tag
+    pub fn from_tag(tag: u8) -> Option<Self> {
+        match tag {
+            0x01 => Some(EventCodec::Json),
+            0x02 => Some(EventCodec::MessagePack),
+            0x03 => Some(EventCodec::Cbor),
+            _ => None,
+        }
+    }
+
+    /// Encode an event without an envelope
+    pub fn encode(&self, event: &UniversalEvent) -> Result<Vec<u8>> {
+        match self {
+            EventCodec::Json => serde_json::to_vec(event).map_err(Into::into),
+            EventCodec::MessagePack => rmp_serde::to_vec_named(event).map_err(Into::into),
+            EventCodec::Cbor => {
+                let mut bytes = Vec::new();
+                ciborium::into_writer(event, &mut bytes)?;
+                Ok(bytes)
+            }
+        }
+    }
+
+    /// Decode an event without an envelope
+    pub fn decode(&self, bytes: &[u8]) -> Result<UniversalEvent> {
+        match self {
+            EventCodec::Json => serde_json::from_slice(bytes).map_err(Into::into),
+            EventCodec::MessagePack => rmp_serde::from_slice(bytes).map_err(Into::into),
+            EventCodec::Cbor => ciborium::from_reader(bytes).map_err(Into::into),
+        }
+    }
+}
+
+/// Event serializer
+///
+/// Envelopes produced by [`EventSerializer::serialize`] are a codec tag byte followed
+/// by the encoded event; [`EventSerializer::deserialize`] honours that tag regardless
+/// of the serializer's own codec.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct EventSerializer {
+    codec: EventCodec,
+}
 
 impl EventSerializer {
+    /// Create a serializer writing with the given codec
+    pub fn new(codec: EventCodec) -> Self {
+        Self { codec }
+    }
+
+    /// Codec used when serializing
+    pub fn codec(&self) -> EventCodec {
+        self.codec
+    }
+
+    /// Serialize event into a tagged envelope
+    pub fn serialize(&self, event: &UniversalEvent) -> Result<Vec<u8>> {
+        let payload = self.codec.encode(event)?;
+        let mut envelope = Vec::with_capacity(payload.len() + 1);
+        envelope.push(self.codec.tag());
+        envelope.extend_from_slice(&payload);
+        Ok(envelope)
+    }
+
+    /// Deserialize event from a tagged envelope
+    pub fn deserialize(&self, envelope: &[u8]) -> Result<UniversalEvent> {
+        let (&tag, payload) = envelope
+            .split_first()
+            .ok_or_else(|| anyhow!("Empty event envelope"))?;
+        let codec = EventCodec::from_tag(tag)
+            .ok_or_else(|| anyhow!("Unknown event codec tag: {tag:#04x}"))?;
+        codec.decode(payload)
+    }
+
     /// Serialize event to JSON
     pub fn to_json(event: &UniversalEvent) -> Result<String> {
         serde_json::to_string(event).map_err(Into::into)
@@ -51,4 +144,45 @@ mod tests {
         assert!(pretty_json.contains("\"test.event\""));
         assert!(pretty_json.contains("\n")); // Should have formatting
     }
+    #[test]
+    fn test_codec_round_trip() {
+        let mut event = UniversalEvent::new(
+            "test.codec",
+            serde_json::json!({"count": 3, "ratio": 0.5, "tags": ["a", "b"], "nested": {"ok": true}}),
+            Language::Lua,
+        );
+        event.metadata.source = Some("source".to_string());
+        event.metadata.ttl = Some(60);
+
+        for codec in [EventCodec::Json, EventCodec::MessagePack, EventCodec::Cbor] {
+            let serializer = EventSerializer::new(codec);
+            let envelope = serializer.serialize(&event).unwrap();
+            assert_eq!(envelope[0], codec.tag());
+
+            let deserialized = serializer.deserialize(&envelope).unwrap();
+            assert_eq!(
+                serde_json::to_value(&event).unwrap(),
+                serde_json::to_value(&deserialized).unwrap(),
+                "{codec:?} round trip changed the event"
+            );
+        }
+    }
+    #[test]
+    fn test_envelope_tag_selects_codec() {
+        let event = UniversalEvent::new("test.tag", serde_json::json!({"k": 1}), Language::Rust);
+        let envelope = EventSerializer::new(EventCodec::Cbor)
+            .serialize(&event)
+            .unwrap();
+
+        // A JSON serializer still decodes CBOR envelopes via the tag
+        let deserialized = EventSerializer::new(EventCodec::Json)
+            .deserialize(&envelope)
+            .unwrap();
+        assert_eq!(deserialized.id, event.id);
+
+        let mut bad = envelope.clone();
+        bad[0] = 0xff;
+        assert!(EventSerializer::default().deserialize(&bad).is_err());
+        assert!(EventSerializer::default().deserialize(&[]).is_err());
+    }
 }
@@ -5,14 +5,21 @@ use crate::universal_event::UniversalEvent;
 use anyhow::Result;
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
+use futures::Stream;
 use llmspell_core::traits::storage::StorageBackend;
 use llmspell_storage::StorageSerialize;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
 use std::sync::Arc;
-use tracing::info;
+use tracing::{info, warn};
 use uuid::Uuid;
 
+/// Timestamp format used in event keys; sorts lexicographically in time order
+const EVENT_KEY_TIMESTAMP_FORMAT: &str = "%Y%m%dT%H%M%S%.3fZ";
+
+/// Number of events loaded from storage per replay page
+pub const REPLAY_PAGE_SIZE: usize = 256;
+
 /// Event storage interface (domain-specific)
 #[async_trait]
 pub trait EventStorage: Send + Sync {
@@ -76,7 +83,7 @@ impl<B: StorageBackend> EventStorageAdapter<B> {
     fn event_key(event: &UniversalEvent) -> String {
         format!(
             "event:{}:{}:{}",
-            event.timestamp.format("%Y%m%dT%H%M%S%.3fZ"),
+            event.timestamp.format(EVENT_KEY_TIMESTAMP_FORMAT),
             event.sequence,
             event.id
         )
@@ -303,6 +310,60 @@ impl<B: StorageBackend> EventStorage for EventStorageAdapter<B> {
 }
 
 impl<B: StorageBackend> EventStorageAdapter<B> {
+    /// Keys of events stored within `[from, to]`, in timestamp then sequence order
+    ///
+    /// Only key names are read; the range check uses the millisecond timestamp
+    /// embedded in each key, so the result may include events a few
+    /// sub-milliseconds outside the range.
+    async fn event_keys_in_range(
+        &self,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> Result<Vec<String>> {
+        let lower = from.format(EVENT_KEY_TIMESTAMP_FORMAT).to_string();
+        let upper = to.format(EVENT_KEY_TIMESTAMP_FORMAT).to_string();
+
+        let mut keys: Vec<(String, u64, String)> = self
+            .backend
+            .list_keys("event:")
+            .await?
+            .into_iter()
+            .filter_map(|key| {
+                let mut parts = key.strip_prefix("event:")?.splitn(3, ':');
+                let timestamp = parts.next()?.to_string();
+                let sequence = parts.next()?.parse().ok()?;
+                (timestamp >= lower && timestamp <= upper).then_some((timestamp, sequence, key))
+            })
+            .collect();
+        keys.sort();
+
+        Ok(keys.into_iter().map(|(_, _, key)| key).collect())
+    }
+
+    /// Load one page of events by key, keeping those matching `pattern` within `[from, to]`
+    async fn load_replay_page(
+        &self,
+        keys: &[String],
+        pattern: &str,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> Result<VecDeque<UniversalEvent>> {
+        let mut stored = self.backend.get_batch(keys).await?;
+
+        let mut events = VecDeque::with_capacity(keys.len());
+        for key in keys {
+            // Events deleted since the keys were listed are skipped
+            let Some(event_data) = stored.remove(key) else {
+                continue;
+            };
+            let event = UniversalEvent::from_storage_bytes(&event_data)?;
+            if event.timestamp >= from && event.timestamp <= to && event.matches_pattern(pattern) {
+                events.push_back(event);
+            }
+        }
+        Ok(events)
+    }
+
     /// Update statistics when a new event is stored
     async fn update_stats_for_new_event(&self, event: &UniversalEvent) -> Result<()> {
         let stats_key = Self::stats_key();
@@ -424,6 +485,68 @@ impl<B: StorageBackend + 'static> EventPersistenceManager<B> {
         Ok(true)
     }
 
+    /// Replay stored events matching `pattern` with timestamps in `[from, to]`
+    ///
+    /// Events are yielded in their original order (timestamp, then sequence)
+    /// and loaded from storage [`REPLAY_PAGE_SIZE`] at a time. A storage error
+    /// is logged and ends the stream.
+    pub fn replay(
+        &self,
+        pattern: &str,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> impl Stream<Item = UniversalEvent> + Send + 'static {
+        struct Cursor<B: StorageBackend> {
+            storage: Arc<EventStorageAdapter<B>>,
+            pattern: String,
+            keys: Option<VecDeque<String>>,
+            page: VecDeque<UniversalEvent>,
+        }
+
+        let cursor = Cursor {
+            storage: Arc::clone(&self.storage),
+            pattern: pattern.to_string(),
+            keys: None,
+            page: VecDeque::new(),
+        };
+
+        futures::stream::unfold(cursor, move |mut cursor| async move {
+            loop {
+                if let Some(event) = cursor.page.pop_front() {
+                    return Some((event, cursor));
+                }
+
+                let keys = match cursor.keys {
+                    Some(ref mut keys) => keys,
+                    None => match cursor.storage.event_keys_in_range(from, to).await {
+                        Ok(keys) => cursor.keys.insert(keys.into()),
+                        Err(e) => {
+                            warn!("Event replay failed to list stored events: {}", e);
+                            return None;
+                        }
+                    },
+                };
+                if keys.is_empty() {
+                    return None;
+                }
+
+                let page_keys: Vec<String> =
+                    keys.drain(..keys.len().min(REPLAY_PAGE_SIZE)).collect();
+                match cursor
+                    .storage
+                    .load_replay_page(&page_keys, &cursor.pattern, from, to)
+                    .await
+                {
+                    Ok(page) => cursor.page = page,
+                    Err(e) => {
+                        warn!("Event replay stopped after a storage error: {}", e);
+                        return None;
+                    }
+                }
+            }
+        })
+    }
+
     /// Get storage reference
     pub fn storage(&self) -> &EventStorageAdapter<B> {
         &self.storage
@@ -501,4 +624,47 @@ mod tests {
         assert_eq!(events.len(), 1);
         assert_eq!(events[0].event_type, "important.event");
     }
+    #[tokio::test]
+    async fn test_replay_time_window() {
+        use futures::StreamExt;
+
+        let backend = MemoryBackend::new();
+        let adapter = EventStorageAdapter::new(backend);
+        let config = PersistenceConfig {
+            enabled: true,
+            ..Default::default()
+        };
+        let manager = EventPersistenceManager::new(adapter, config);
+
+        // Store out of order so replay has to restore timestamp order
+        let base = Utc::now() - chrono::Duration::hours(1);
+        let types = [
+            "agent.a", "tool.b", "agent.c", "agent.d", "tool.e", "agent.f",
+        ];
+        for (minute, event_type) in types.iter().enumerate().rev() {
+            let mut event = create_test_event(event_type);
+            event.timestamp = base + chrono::Duration::minutes(minute as i64);
+            manager.maybe_store_event(&event).await.unwrap();
+        }
+
+        let replayed: Vec<UniversalEvent> = manager
+            .replay(
+                "agent.*",
+                base + chrono::Duration::minutes(1),
+                base + chrono::Duration::minutes(4),
+            )
+            .collect()
+            .await;
+
+        let replayed_types: Vec<&str> = replayed.iter().map(|e| e.event_type.as_str()).collect();
+        assert_eq!(replayed_types, vec!["agent.c", "agent.d"]);
+        assert!(replayed.windows(2).all(|w| w[0].timestamp < w[1].timestamp));
+
+        let everything: Vec<UniversalEvent> = manager
+            .replay("*", base, base + chrono::Duration::minutes(5))
+            .collect()
+            .await;
+        assert_eq!(everything.len(), types.len());
+        assert_eq!(everything[0].event_type, "agent.a");
+    }
 }
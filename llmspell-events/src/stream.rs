@@ -3,7 +3,9 @@
 
 use crate::bus::EventBus;
 use crate::universal_event::UniversalEvent;
+use chrono::{DateTime, Utc};
 use futures::{Stream, StreamExt};
+use std::collections::VecDeque;
 use std::pin::Pin;
 use std::task::{Context, Poll};
 use tokio::sync::mpsc;
@@ -246,6 +248,112 @@ impl HighThroughputProcessor {
     }
 }
 
+/// Events grouped into a time window `[start, end)` by event timestamp
+#[derive(Debug, Clone)]
+pub struct EventWindow {
+    /// Inclusive window start
+    pub start: DateTime<Utc>,
+    /// Exclusive window end
+    pub end: DateTime<Utc>,
+    /// Events whose timestamp falls within the window
+    pub events: Vec<UniversalEvent>,
+}
+
+impl EventWindow {
+    fn new(start: DateTime<Utc>, length: chrono::Duration) -> Self {
+        Self {
+            start,
+            end: start + length,
+            events: Vec::new(),
+        }
+    }
+
+    /// Check if a timestamp falls within the window
+    pub fn contains(&self, timestamp: DateTime<Utc>) -> bool {
+        timestamp >= self.start && timestamp < self.end
+    }
+
+    /// Fold the window's events into a single value
+    pub fn aggregate<T, F>(&self, init: T, fold_fn: F) -> T
+    where
+        F: FnMut(T, &UniversalEvent) -> T,
+    {
+        self.events.iter().fold(init, fold_fn)
+    }
+}
+
+/// Aggregated value computed over an event window
+#[derive(Debug, Clone, PartialEq)]
+pub struct WindowAggregate<T> {
+    /// Inclusive window start
+    pub start: DateTime<Utc>,
+    /// Exclusive window end
+    pub end: DateTime<Utc>,
+    /// Number of events in the window
+    pub event_count: usize,
+    /// Folded value
+    pub value: T,
+}
+
+/// Stream of event windows
+pub type EventWindowStream =
+    Pin<Box<dyn Stream<Item = Result<EventWindow, EventStreamError>> + Send>>;
+
+/// Align a timestamp down to a multiple of `step_ms` since the Unix epoch
+fn align_timestamp(timestamp: DateTime<Utc>, step_ms: i64) -> DateTime<Utc> {
+    let aligned = timestamp.timestamp_millis().div_euclid(step_ms) * step_ms;
+    DateTime::from_timestamp_millis(aligned).unwrap_or(timestamp)
+}
+
+/// Convert a window duration to milliseconds (at least 1ms)
+fn window_millis(duration: std::time::Duration) -> i64 {
+    i64::try_from(duration.as_millis())
+        .unwrap_or(i64::MAX)
+        .max(1)
+}
+
+/// State for sliding window computation
+struct SlidingState<S> {
+    inner: S,
+    length: chrono::Duration,
+    slide: chrono::Duration,
+    slide_ms: i64,
+    next_start: Option<DateTime<Utc>>,
+    buffer: VecDeque<UniversalEvent>,
+    ready: VecDeque<EventWindow>,
+    done: bool,
+}
+
+impl<S> SlidingState<S> {
+    /// Emit the window at `next_start` (if non-empty) and advance by one slide
+    fn close_window(&mut self) {
+        let Some(start) = self.next_start else {
+            return;
+        };
+        let mut window = EventWindow::new(start, self.length);
+        window.events = self
+            .buffer
+            .iter()
+            .filter(|e| window.contains(e.timestamp))
+            .cloned()
+            .collect();
+        if !window.events.is_empty() {
+            self.ready.push_back(window);
+        }
+
+        let next = start + self.slide;
+        self.next_start = Some(next);
+        while self.buffer.front().is_some_and(|e| e.timestamp < next) {
+            self.buffer.pop_front();
+        }
+    }
+
+    /// First aligned window start whose window contains `timestamp`
+    fn first_window_for(&self, timestamp: DateTime<Utc>) -> DateTime<Utc> {
+        align_timestamp(timestamp - self.length, self.slide_ms) + self.slide
+    }
+}
+
 /// Stream utilities for high-frequency processing
 pub struct StreamUtils;
 
@@ -271,6 +379,137 @@ impl StreamUtils {
         )
     }
 
+    /// Group events into fixed, non-overlapping windows by event timestamp
+    ///
+    /// Windows are aligned to multiples of `duration` since the Unix epoch and
+    /// emitted once an event for a later window arrives (or the stream ends).
+    /// Events are expected in timestamp order; late events join the open window.
+    /// Empty windows are not emitted.
+    pub fn tumbling_window<S>(stream: S, duration: std::time::Duration) -> EventWindowStream
+    where
+        S: Stream<Item = Result<UniversalEvent, EventStreamError>> + Send + Unpin + 'static,
+    {
+        let length_ms = window_millis(duration);
+        let length = chrono::Duration::milliseconds(length_ms);
+
+        Box::pin(futures::stream::unfold(
+            (stream, None::<EventWindow>, false),
+            move |(mut inner, mut current, done)| async move {
+                if done {
+                    return None;
+                }
+                loop {
+                    match inner.next().await {
+                        Some(Ok(event)) => {
+                            let start = align_timestamp(event.timestamp, length_ms);
+                            let open_start = current.as_ref().map(|w| w.start);
+                            if open_start.is_some_and(|open| start > open) {
+                                let mut next = EventWindow::new(start, length);
+                                next.events.push(event);
+                                let closed = current.replace(next);
+                                return closed.map(|w| (Ok(w), (inner, current, false)));
+                            }
+                            current
+                                .get_or_insert_with(|| EventWindow::new(start, length))
+                                .events
+                                .push(event);
+                        }
+                        Some(Err(e)) => return Some((Err(e), (inner, current, false))),
+                        None => {
+                            return current.take().map(|w| (Ok(w), (inner, None, true)));
+                        }
+                    }
+                }
+            },
+        ))
+    }
+
+    /// Group events into overlapping windows of `duration`, starting every `slide`
+    ///
+    /// Window starts are aligned to multiples of `slide` since the Unix epoch, so
+    /// each event appears in every window covering its timestamp. Events are
+    /// expected in timestamp order. Empty windows are not emitted.
+    pub fn sliding_window<S>(
+        stream: S,
+        duration: std::time::Duration,
+        slide: std::time::Duration,
+    ) -> EventWindowStream
+    where
+        S: Stream<Item = Result<UniversalEvent, EventStreamError>> + Send + Unpin + 'static,
+    {
+        let slide_ms = window_millis(slide);
+        let state = SlidingState {
+            inner: stream,
+            length: chrono::Duration::milliseconds(window_millis(duration)),
+            slide: chrono::Duration::milliseconds(slide_ms),
+            slide_ms,
+            next_start: None,
+            buffer: VecDeque::new(),
+            ready: VecDeque::new(),
+            done: false,
+        };
+
+        Box::pin(futures::stream::unfold(state, |mut state| async move {
+            loop {
+                if let Some(window) = state.ready.pop_front() {
+                    return Some((Ok(window), state));
+                }
+                if state.done {
+                    return None;
+                }
+
+                match state.inner.next().await {
+                    Some(Ok(event)) => {
+                        let timestamp = event.timestamp;
+                        let first = state.first_window_for(timestamp);
+                        if state.buffer.is_empty() {
+                            // Skip straight past gaps with no buffered events
+                            if state.next_start.is_none_or(|next| next < first) {
+                                state.next_start = Some(first);
+                            }
+                        } else {
+                            while state
+                                .next_start
+                                .is_some_and(|start| start + state.length <= timestamp)
+                            {
+                                state.close_window();
+                            }
+                        }
+                        state.buffer.push_back(event);
+                    }
+                    Some(Err(e)) => return Some((Err(e), state)),
+                    None => {
+                        while !state.buffer.is_empty() {
+                            state.close_window();
+                        }
+                        state.done = true;
+                    }
+                }
+            }
+        }))
+    }
+
+    /// Fold each window of a window stream into an aggregate value
+    pub fn aggregate<S, T, F>(
+        windows: S,
+        init: T,
+        fold_fn: F,
+    ) -> Pin<Box<dyn Stream<Item = Result<WindowAggregate<T>, EventStreamError>> + Send>>
+    where
+        S: Stream<Item = Result<EventWindow, EventStreamError>> + Send + 'static,
+        T: Clone + Send + 'static,
+        F: Fn(T, &UniversalEvent) -> T + Send + 'static,
+    {
+        Box::pin(windows.map(move |result| {
+            result.map(|window| WindowAggregate {
+                start: window.start,
+                end: window.end,
+                event_count: window.events.len(),
+                value: window.aggregate(init.clone(), &fold_fn),
+            })
+        }))
+    }
+
     /// Measure stream throughput
     pub async fn measure_throughput<S>(
         mut stream: S,
@@ -386,6 +625,77 @@ mod tests {
         assert!(measurement.event_count > 0);
         assert!(measurement.events_per_second > 0.0);
     }
+    fn event_at(event_type: &str, millis: i64) -> UniversalEvent {
+        let mut event = create_test_event(event_type);
+        event.timestamp = DateTime::from_timestamp_millis(millis).unwrap();
+        event
+    }
+    #[tokio::test]
+    async fn test_tumbling_window() {
+        let base = 1_700_000_000_000; // aligned to 1s
+        let events = vec![
+            event_at("req.ok", base),
+            event_at("req.error", base + 200),
+            event_at("req.ok", base + 999),
+            event_at("req.ok", base + 1_000),
+            event_at("req.error", base + 3_500),
+            event_at("req.error", base + 3_900),
+        ];
+        let stream = tokio_stream::iter(events.into_iter().map(Ok));
+
+        let windows = StreamUtils::tumbling_window(stream, std::time::Duration::from_secs(1));
+        let aggregates: Vec<_> = StreamUtils::aggregate(windows, 0usize, |errors, event| {
+            errors + usize::from(event.event_type.ends_with("error"))
+        })
+        .collect::<Vec<_>>()
+        .await
+        .into_iter()
+        .map(Result::unwrap)
+        .collect();
+
+        assert_eq!(aggregates.len(), 3);
+        let at = |ms| DateTime::from_timestamp_millis(ms).unwrap();
+
+        assert_eq!(aggregates[0].start, at(base));
+        assert_eq!(aggregates[0].end, at(base + 1_000));
+        assert_eq!(aggregates[0].event_count, 3);
+        assert_eq!(aggregates[0].value, 1);
+
+        assert_eq!(aggregates[1].start, at(base + 1_000));
+        assert_eq!(aggregates[1].event_count, 1);
+        assert_eq!(aggregates[1].value, 0);
+
+        assert_eq!(aggregates[2].start, at(base + 3_000));
+        assert_eq!(aggregates[2].event_count, 2);
+        assert_eq!(aggregates[2].value, 2);
+    }
+    #[tokio::test]
+    async fn test_sliding_window() {
+        let base = 1_700_000_000_000;
+        let events = vec![
+            event_at("a", base + 100),
+            event_at("b", base + 600),
+            event_at("c", base + 1_100),
+        ];
+        let stream = tokio_stream::iter(events.into_iter().map(Ok));
+
+        let windows: Vec<_> = StreamUtils::sliding_window(
+            stream,
+            std::time::Duration::from_secs(1),
+            std::time::Duration::from_millis(500),
+        )
+        .collect::<Vec<_>>()
+        .await
+        .into_iter()
+        .map(Result::unwrap)
+        .collect();
+
+        let counts: Vec<_> = windows
+            .iter()
+            .map(|w| (w.start.timestamp_millis() - base, w.events.len()))
+            .collect();
+        assert_eq!(counts, vec![(-500, 1), (0, 2), (500, 2), (1_000, 1)]);
+    }
     #[tokio::test]
     async fn test_high_throughput_processor() {
         let processor = HighThroughputProcessor::new(1000, 4);
@@ -5,6 +5,7 @@
 //! # Extractors
 //!
 //! - `RegexExtractor`: Pattern-based extraction using regular expressions (Phase 13.2.4)
+//! - `Ontology`: Controlled vocabulary restricting extracted entity types and predicates
 //! - Future: LLM-based extraction, coreference resolution, entity linking
 //!
 //! # Usage
@@ -18,6 +19,8 @@
 //! let relationships = extractor.extract_relationships(text);
 //! ```
 
+pub mod ontology;
 pub mod regex;
 
+pub use ontology::Ontology;
 pub use regex::RegexExtractor;
@@ -0,0 +1,227 @@
+//! Controlled vocabulary for entity and relationship extraction
+//!
+//! An `Ontology` restricts extraction output to a domain's allowed entity types
+//! and relationship predicates. Out-of-ontology types are remapped through
+//! aliases when one is configured, and dropped otherwise.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use llmspell_graph::extraction::{Ontology, RegexExtractor};
+//!
+//! let ontology = Ontology::new()
+//!     .with_entity_types(["tool"])
+//!     .with_entity_alias("framework", "tool")
+//!     .with_relationship_types(["is_a"]);
+//!
+//! let extractor = RegexExtractor::new().with_ontology(ontology);
+//! let entities = extractor.extract_entities("Tokio is a framework.");
+//! assert!(entities.iter().all(|e| e.entity_type == "tool"));
+//! ```
+
+use llmspell_core::types::storage::{Entity, Relationship};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::{HashMap, HashSet};
+use tracing::{debug, trace};
+
+/// Allowed entity types and relationship predicates for extraction
+///
+/// An empty set of entity types (or relationship types) leaves that category
+/// unrestricted. Aliases are applied before the allowed-set check, so they
+/// can map extractor output onto the domain vocabulary.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Ontology {
+    /// Allowed entity types
+    pub entity_types: HashSet<String>,
+
+    /// Allowed relationship predicates
+    pub relationship_types: HashSet<String>,
+
+    /// Entity type remappings (extracted type → ontology type)
+    pub entity_aliases: HashMap<String, String>,
+
+    /// Relationship predicate remappings (extracted predicate → ontology predicate)
+    pub relationship_aliases: HashMap<String, String>,
+}
+
+impl Ontology {
+    /// Create an unrestricted ontology
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Allow the given entity types
+    #[must_use]
+    pub fn with_entity_types<I, S>(mut self, types: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.entity_types.extend(types.into_iter().map(Into::into));
+        self
+    }
+
+    /// Allow the given relationship predicates
+    #[must_use]
+    pub fn with_relationship_types<I, S>(mut self, types: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.relationship_types
+            .extend(types.into_iter().map(Into::into));
+        self
+    }
+
+    /// Remap an extracted entity type onto an ontology type
+    #[must_use]
+    pub fn with_entity_alias(mut self, from: impl Into<String>, to: impl Into<String>) -> Self {
+        self.entity_aliases.insert(from.into(), to.into());
+        self
+    }
+
+    /// Remap an extracted relationship predicate onto an ontology predicate
+    #[must_use]
+    pub fn with_relationship_alias(
+        mut self,
+        from: impl Into<String>,
+        to: impl Into<String>,
+    ) -> Self {
+        self.relationship_aliases.insert(from.into(), to.into());
+        self
+    }
+
+    /// Resolve an entity type, returning `None` if it is outside the ontology
+    #[must_use]
+    pub fn resolve_entity_type(&self, entity_type: &str) -> Option<String> {
+        Self::resolve(entity_type, &self.entity_types, &self.entity_aliases)
+    }
+
+    /// Resolve a relationship predicate, returning `None` if it is outside the ontology
+    #[must_use]
+    pub fn resolve_relationship_type(&self, relationship_type: &str) -> Option<String> {
+        Self::resolve(
+            relationship_type,
+            &self.relationship_types,
+            &self.relationship_aliases,
+        )
+    }
+
+    /// Map entities onto the ontology, dropping those that do not conform
+    ///
+    /// Remapped entities keep their extracted type in the `original_type` property.
+    #[must_use]
+    pub fn apply_to_entities(&self, entities: Vec<Entity>) -> Vec<Entity> {
+        let total = entities.len();
+        let conforming: Vec<Entity> = entities
+            .into_iter()
+            .filter_map(|mut entity| {
+                let Some(resolved) = self.resolve_entity_type(&entity.entity_type) else {
+                    trace!(
+                        "Dropped off-ontology entity: name='{}', type={}",
+                        entity.name,
+                        entity.entity_type
+                    );
+                    return None;
+                };
+                if resolved != entity.entity_type {
+                    let original = std::mem::replace(&mut entity.entity_type, resolved);
+                    record_original_type(&mut entity.properties, original);
+                }
+                Some(entity)
+            })
+            .collect();
+
+        debug!(
+            "Ontology kept {} of {} extracted entities",
+            conforming.len(),
+            total
+        );
+        conforming
+    }
+
+    /// Map relationships onto the ontology, dropping those that do not conform
+    ///
+    /// Remapped relationships keep their extracted predicate in the `original_type` property.
+    #[must_use]
+    pub fn apply_to_relationships(&self, relationships: Vec<Relationship>) -> Vec<Relationship> {
+        let total = relationships.len();
+        let conforming: Vec<Relationship> = relationships
+            .into_iter()
+            .filter_map(|mut rel| {
+                let Some(resolved) = self.resolve_relationship_type(&rel.relationship_type) else {
+                    trace!(
+                        "Dropped off-ontology relationship: {}->{}({})",
+                        rel.from_entity,
+                        rel.to_entity,
+                        rel.relationship_type
+                    );
+                    return None;
+                };
+                if resolved != rel.relationship_type {
+                    let original = std::mem::replace(&mut rel.relationship_type, resolved);
+                    record_original_type(&mut rel.properties, original);
+                }
+                Some(rel)
+            })
+            .collect();
+
+        debug!(
+            "Ontology kept {} of {} extracted relationships",
+            conforming.len(),
+            total
+        );
+        conforming
+    }
+
+    fn resolve(
+        extracted: &str,
+        allowed: &HashSet<String>,
+        aliases: &HashMap<String, String>,
+    ) -> Option<String> {
+        let mapped = aliases.get(extracted).map_or(extracted, String::as_str);
+        (allowed.is_empty() || allowed.contains(mapped)).then(|| mapped.to_string())
+    }
+}
+
+/// Record the pre-mapping type on an extracted element's properties
+fn record_original_type(properties: &mut Value, original: String) {
+    if let Value::Object(map) = properties {
+        map.insert("original_type".to_string(), Value::String(original));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_with_aliases() {
+        let ontology = Ontology::new()
+            .with_entity_types(["tool"])
+            .with_entity_alias("framework", "tool")
+            .with_relationship_alias("has_feature", "has_capability");
+
+        assert_eq!(
+            ontology.resolve_entity_type("tool").as_deref(),
+            Some("tool")
+        );
+        assert_eq!(
+            ontology.resolve_entity_type("framework").as_deref(),
+            Some("tool")
+        );
+        assert_eq!(ontology.resolve_entity_type("entity"), None);
+
+        // Relationship types are unrestricted, but aliases still apply
+        assert_eq!(
+            ontology.resolve_relationship_type("has_feature").as_deref(),
+            Some("has_capability")
+        );
+        assert_eq!(
+            ontology.resolve_relationship_type("part_of").as_deref(),
+            Some("part_of")
+        );
+    }
+}
@@ -25,6 +25,7 @@
 //! assert!(relationships.iter().any(|r| r.relationship_type == "is_a"));
 //! ```
 
+use super::ontology::Ontology;
 use llmspell_core::types::storage::{Entity, Relationship};
 use llmspell_utils::text::stopwords::is_stopword;
 use regex::Regex;
@@ -74,6 +75,9 @@ static ENTITY_PATTERN: LazyLock<Regex> =
 pub struct RegexExtractor {
     /// Minimum entity name length to filter noise
     min_entity_length: usize,
+
+    /// Optional controlled vocabulary applied to extraction output
+    ontology: Option<Ontology>,
 }
 
 impl Default for RegexExtractor {
@@ -96,9 +100,20 @@ impl RegexExtractor {
     pub const fn new() -> Self {
         Self {
             min_entity_length: 2,
+            ontology: None,
         }
     }
 
+    /// Restrict extraction output to an ontology
+    ///
+    /// Entities and relationships outside the ontology are remapped via its
+    /// aliases or dropped.
+    #[must_use]
+    pub fn with_ontology(mut self, ontology: Ontology) -> Self {
+        self.ontology = Some(ontology);
+        self
+    }
+
     /// Extract entities from text
     ///
     /// Identifies entity mentions (capitalized words/phrases) and creates Entity objects.
@@ -180,6 +195,10 @@ impl RegexExtractor {
             );
         }
 
+        if let Some(ontology) = &self.ontology {
+            entities = ontology.apply_to_entities(entities);
+        }
+
         Self::log_extraction_summary(&entities, filtered_count);
         entities
     }
@@ -297,6 +316,10 @@ impl RegexExtractor {
             Self::extract_pattern_relationships(text, &OF_PATTERN, "part_of", "of", "of");
         relationships.extend(of_rels);
 
+        if let Some(ontology) = &self.ontology {
+            relationships = ontology.apply_to_relationships(relationships);
+        }
+
         info!("Relationship extraction complete: {} relationships extracted (is_a={}, has={}, in={}, of={})",
             relationships.len(), is_a_count, has_count, in_count, of_count);
         trace!(
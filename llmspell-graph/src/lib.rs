@@ -98,7 +98,7 @@ pub mod traits;
 pub use error::{GraphError, Result};
 
 // Re-export domain-specific storage trait
-pub use storage::GraphBackend;
+pub use storage::{GraphBackend, GraphChangeset, RepairOptions, RepairReport, ValidationReport};
 
 // Re-export core traits and types from llmspell-core
 pub use llmspell_core::traits::storage::KnowledgeGraph;
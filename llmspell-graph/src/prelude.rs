@@ -10,5 +10,7 @@ pub use llmspell_core::traits::storage::KnowledgeGraph;
 pub use llmspell_core::types::storage::{Entity, Relationship, TemporalQuery};
 
 // Re-export domain-specific storage backend trait
-pub use crate::storage::GraphBackend;
+pub use crate::storage::{
+    GraphBackend, GraphChangeset, RepairOptions, RepairReport, ValidationReport,
+};
 // Note: SQLite-based graph storage implemented - use SQLite or PostgreSQL storage via llmspell-storage
@@ -0,0 +1,48 @@
+//! Bi-temporal change history between two ingestion times
+//!
+//! Returned by `GraphBackend::changes_between` for auditing what the graph
+//! learned, corrected or retracted within a window.
+
+use llmspell_core::types::storage::{Entity, Relationship};
+use serde::{Deserialize, Serialize};
+
+/// Entities and relationships changed within a transaction-time window
+///
+/// A closed version counts as superseded when a version with the same key
+/// (`name` and `entity_type` for entities, the relationship ID for
+/// relationships) was recorded during its lifetime, and as deleted otherwise. Superseded and deleted lists hold the closed
+/// versions; their replacements appear in the added lists when recorded in
+/// the window.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct GraphChangeset {
+    /// Entities recorded in the window
+    pub entities_added: Vec<Entity>,
+
+    /// Entity versions closed in the window and replaced by a correction
+    pub entities_superseded: Vec<Entity>,
+
+    /// Entity versions closed in the window without a replacement
+    pub entities_deleted: Vec<Entity>,
+
+    /// Relationships recorded in the window
+    pub relationships_added: Vec<Relationship>,
+
+    /// Relationship versions closed in the window and replaced by a correction
+    pub relationships_superseded: Vec<Relationship>,
+
+    /// Relationship versions closed in the window without a replacement
+    pub relationships_deleted: Vec<Relationship>,
+}
+
+impl GraphChangeset {
+    /// Whether nothing changed in the window
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.entities_added.is_empty()
+            && self.entities_superseded.is_empty()
+            && self.entities_deleted.is_empty()
+            && self.relationships_added.is_empty()
+            && self.relationships_superseded.is_empty()
+            && self.relationships_deleted.is_empty()
+    }
+}
@@ -7,11 +7,22 @@
 
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
+use futures::Stream;
 use std::collections::HashMap;
+use std::pin::Pin;
 
 use anyhow::Result;
 use llmspell_core::types::storage::{Entity, Relationship, TemporalQuery};
 
+pub mod changeset;
+pub mod validation;
+
+pub use changeset::GraphChangeset;
+pub use validation::{RepairOptions, RepairReport, ValidationReport};
+
+/// Stream of relationships yielded incrementally by a backend
+pub type RelationshipStream<'a> = Pin<Box<dyn Stream<Item = Result<Relationship>> + Send + 'a>>;
+
 /// Swappable backend trait for knowledge graph storage
 ///
 /// Enables hot-swapping between different graph databases without
@@ -28,6 +39,31 @@ pub trait GraphBackend: Send + Sync {
         changes: HashMap<String, serde_json::Value>,
     ) -> Result<()>;
 
+    /// Add a batch of entities, merging matches on `(name, entity_type)`
+    ///
+    /// The default implementation only deduplicates within the batch.
+    async fn upsert_entities(&self, entities: Vec<Entity>) -> Result<Vec<String>> {
+        let mut seen: HashMap<(String, String), String> = HashMap::new();
+        let mut ids = Vec::with_capacity(entities.len());
+        for entity in entities {
+            let key = (entity.name.clone(), entity.entity_type.clone());
+            let id = if let Some(id) = seen.get(&key) {
+                let changes = match entity.properties {
+                    serde_json::Value::Object(map) => map.into_iter().collect(),
+                    _ => HashMap::new(),
+                };
+                self.update_entity(id, changes).await?;
+                id.clone()
+            } else {
+                let id = self.add_entity(entity).await?;
+                seen.insert(key, id.clone());
+                id
+            };
+            ids.push(id);
+        }
+        Ok(ids)
+    }
+
     /// Get current version of an entity
     async fn get_entity(&self, id: &str) -> Result<Entity>;
 
@@ -35,11 +71,51 @@ pub trait GraphBackend: Send + Sync {
     async fn get_entity_at(&self, id: &str, event_time: DateTime<Utc>) -> Result<Entity>;
 
     /// Add a relationship
+    ///
+    /// Adding a relationship whose ID is already current records a corrected
+    /// version that supersedes it.
     async fn add_relationship(&self, relationship: Relationship) -> Result<String>;
 
     /// Get related entities
     async fn get_related(&self, entity_id: &str, relationship_type: &str) -> Result<Vec<Entity>>;
 
+    /// Get related entities as of a past time (bi-temporal, one-hop traversal)
+    async fn get_related_at(
+        &self,
+        entity_id: &str,
+        relationship_type: &str,
+        at: DateTime<Utc>,
+    ) -> Result<Vec<Entity>> {
+        let reachable = self
+            .traverse(entity_id, Some(relationship_type), 1, Some(at))
+            .await?;
+        Ok(reachable
+            .into_iter()
+            .filter(|(_, depth, _)| *depth == 1)
+            .map(|(entity, _, _)| entity)
+            .collect())
+    }
+
+    /// Stream outgoing relationships of a type from an entity
+    ///
+    /// Unlike `get_related`, results are not materialized: implementations fetch
+    /// in bounded batches, keeping memory flat for hub entities with many edges.
+    fn stream_related<'a>(
+        &'a self,
+        entity_id: &'a str,
+        relationship_type: &'a str,
+    ) -> RelationshipStream<'a>;
+
+    /// Find the `k` entities most similar to an embedding
+    ///
+    /// Returns `(entity, cosine similarity)` pairs, most similar first. Only
+    /// current entities stored with an embedding of the same dimension match.
+    async fn find_similar_entities(
+        &self,
+        embedding: &[f32],
+        k: usize,
+    ) -> Result<Vec<(Entity, f32)>>;
+
     /// Get all relationships for an entity
     ///
     /// Returns both outgoing (from this entity) and incoming (to this entity) relationships.
@@ -54,6 +130,14 @@ pub trait GraphBackend: Send + Sync {
     /// Execute temporal query
     async fn query_temporal(&self, query: TemporalQuery) -> Result<Vec<Entity>>;
 
+    /// Entities and relationships added, superseded or deleted between two
+    /// ingestion (transaction) times, `from` inclusive and `to` exclusive
+    async fn changes_between(
+        &self,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> Result<GraphChangeset>;
+
     /// Delete entities before timestamp
     async fn delete_before(&self, timestamp: DateTime<Utc>) -> Result<usize>;
 
@@ -89,4 +173,14 @@ pub trait GraphBackend: Send + Sync {
         max_depth: usize,
         at_time: Option<DateTime<Utc>>,
     ) -> Result<Vec<(Entity, usize, String)>>;
+
+    /// Check the current graph for dangling relationships, orphaned entities
+    /// and temporal inconsistencies
+    async fn validate(&self) -> Result<ValidationReport>;
+
+    /// Remove dangling relationships, and orphaned entities if requested
+    ///
+    /// Temporal inconsistencies are reported by `validate` but left untouched,
+    /// since the correct event time cannot be inferred. Dry run by default.
+    async fn repair(&self, options: RepairOptions) -> Result<RepairReport>;
 }
@@ -0,0 +1,80 @@
+//! Graph integrity validation and repair types
+//!
+//! Long-running graphs accumulate inconsistencies as consolidation updates and
+//! deletes entities. `GraphBackend::validate` reports them and
+//! `GraphBackend::repair` fixes the structural ones.
+
+use serde::{Deserialize, Serialize};
+
+/// Integrity issues found in the current state of a graph
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ValidationReport {
+    /// Current relationships whose source or target has no current entity
+    pub dangling_relationships: Vec<String>,
+
+    /// Current entities with no current relationships in either direction
+    pub orphaned_entities: Vec<String>,
+
+    /// Entities and relationships whose event time is after their ingestion time
+    pub temporal_inconsistencies: Vec<String>,
+}
+
+impl ValidationReport {
+    /// Whether no issues were found
+    ///
+    /// Orphaned entities are not counted: an isolated entity is valid, if unused.
+    #[must_use]
+    pub fn is_clean(&self) -> bool {
+        self.dangling_relationships.is_empty() && self.temporal_inconsistencies.is_empty()
+    }
+}
+
+/// Options controlling `GraphBackend::repair`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RepairOptions {
+    /// Report what would be removed without modifying the graph
+    pub dry_run: bool,
+
+    /// Also remove orphaned entities
+    pub prune_orphans: bool,
+}
+
+impl Default for RepairOptions {
+    fn default() -> Self {
+        Self {
+            dry_run: true,
+            prune_orphans: false,
+        }
+    }
+}
+
+impl RepairOptions {
+    /// Apply the repair instead of only reporting it
+    #[must_use]
+    pub const fn apply(mut self) -> Self {
+        self.dry_run = false;
+        self
+    }
+
+    /// Also remove orphaned entities
+    #[must_use]
+    pub const fn with_prune_orphans(mut self, prune_orphans: bool) -> Self {
+        self.prune_orphans = prune_orphans;
+        self
+    }
+}
+
+/// Outcome of `GraphBackend::repair`
+///
+/// In a dry run the lists hold what would have been removed.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RepairReport {
+    /// Whether this was a dry run
+    pub dry_run: bool,
+
+    /// Dangling relationships removed
+    pub removed_relationships: Vec<String>,
+
+    /// Orphaned entities removed
+    pub removed_entities: Vec<String>,
+}
@@ -1,9 +1,9 @@
 //! Integration tests for entity and relationship extraction
 //!
 //! Tests the `RegexExtractor` against real-world text samples to verify
-//! >50% recall and <5ms performance targets.
+//! >50% recall and <5ms performance targets, and ontology-restricted extraction.
 
-use llmspell_graph::extraction::RegexExtractor;
+use llmspell_graph::extraction::{Ontology, RegexExtractor};
 
 #[test]
 fn test_technical_documentation_extraction() {
@@ -419,3 +419,39 @@ fn test_multi_word_entities() {
         "Should find IntelliJ IDEA parts"
     );
 }
+
+#[test]
+fn test_restrictive_ontology_extraction() {
+    let ontology = Ontology::new()
+        .with_entity_types(["tool"])
+        .with_entity_alias("framework", "tool")
+        .with_relationship_types(["is_a", "has_capability"])
+        .with_relationship_alias("has_feature", "has_capability");
+    let extractor = RegexExtractor::new().with_ontology(ontology);
+    let text = "Cargo is a tool. Tokio is a framework. \
+                Tokio has async support. Paris in France is beautiful.";
+
+    let entities = extractor.extract_entities(text);
+    let rels = extractor.extract_relationships(text);
+
+    // Only tools survive; the framework is remapped, places are dropped
+    assert!(entities.iter().all(|e| e.entity_type == "tool"));
+    assert!(entities.iter().any(|e| e.name == "Cargo"));
+    let tokio = entities
+        .iter()
+        .find(|e| e.name == "Tokio")
+        .expect("Tokio should be remapped to tool");
+    assert_eq!(tokio.properties["original_type"], "framework");
+    assert!(!entities
+        .iter()
+        .any(|e| e.name == "Paris" || e.name == "France"));
+
+    // has_feature is remapped, located_in is outside the ontology
+    assert!(rels
+        .iter()
+        .all(|r| r.relationship_type == "is_a" || r.relationship_type == "has_capability"));
+    assert!(rels.iter().any(|r| r.relationship_type == "has_capability"
+        && r.from_entity == "Tokio"
+        && r.properties["original_type"] == "has_feature"));
+    assert!(!rels.iter().any(|r| r.from_entity == "Paris"));
+}
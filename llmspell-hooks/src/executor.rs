@@ -7,10 +7,13 @@ use crate::performance::{PerformanceConfig, PerformanceMetrics, PerformanceMonit
 use crate::persistence::HookPersistenceManager;
 use crate::result::HookResult;
 use crate::traits::Hook;
+use crate::types::{ComponentId, HookPoint};
 use anyhow::Result;
+use chrono::{DateTime, Utc};
 use llmspell_events::{EventCorrelationTracker, EventLink, EventRelationship, UniversalEvent};
 use parking_lot::RwLock;
-use std::collections::HashMap;
+use serde_json::Value as JsonValue;
+use std::collections::{HashMap, VecDeque};
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 use tracing::{info, warn};
@@ -32,6 +35,11 @@ pub struct HookExecutorConfig {
     pub breaker_config: BreakerConfig,
     /// Performance monitoring configuration
     pub performance_config: PerformanceConfig,
+    /// Enforced per-hook timeout; hooks exceeding it are abandoned and recorded
+    /// in the slow-hook log instead of blocking execution
+    pub hook_timeout: Option<Duration>,
+    /// Maximum number of entries retained in the slow-hook log
+    pub slow_hook_log_capacity: usize,
 }
 
 impl Default for HookExecutorConfig {
@@ -44,10 +52,90 @@ impl Default for HookExecutorConfig {
             performance_overhead_target: 0.05, // 5%
             breaker_config: BreakerConfig::default(),
             performance_config: PerformanceConfig::default(),
+            hook_timeout: None,
+            slow_hook_log_capacity: 1000,
         }
     }
 }
 
+/// Record of a hook that exceeded its enforced timeout
+#[derive(Debug, Clone)]
+pub struct SlowHookRecord {
+    /// Name of the offending hook
+    pub hook_name: String,
+    /// Hook point being executed when the timeout fired
+    pub hook_point: HookPoint,
+    /// Component that triggered the hook
+    pub component_id: ComponentId,
+    /// Context data the hook was invoked with
+    pub input: HashMap<String, JsonValue>,
+    /// Correlation ID of the triggering context
+    pub correlation_id: uuid::Uuid,
+    /// Timeout that was exceeded
+    pub timeout: Duration,
+    /// When the timeout was recorded
+    pub recorded_at: DateTime<Utc>,
+}
+
+/// Bounded log of hooks that timed out, kept for later analysis
+#[derive(Debug)]
+pub struct SlowHookLog {
+    entries: RwLock<VecDeque<SlowHookRecord>>,
+    capacity: usize,
+}
+
+impl SlowHookLog {
+    /// Create a log retaining at most `capacity` records (oldest evicted first)
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            entries: RwLock::new(VecDeque::with_capacity(capacity.min(64))),
+            capacity,
+        }
+    }
+
+    /// Record a timed-out hook execution
+    pub fn record(&self, record: SlowHookRecord) {
+        if self.capacity == 0 {
+            return;
+        }
+        let mut entries = self.entries.write();
+        while entries.len() >= self.capacity {
+            entries.pop_front();
+        }
+        entries.push_back(record);
+    }
+
+    /// Get all recorded entries, oldest first
+    pub fn entries(&self) -> Vec<SlowHookRecord> {
+        self.entries.read().iter().cloned().collect()
+    }
+
+    /// Get recorded entries for a specific hook
+    pub fn entries_for(&self, hook_name: &str) -> Vec<SlowHookRecord> {
+        self.entries
+            .read()
+            .iter()
+            .filter(|r| r.hook_name == hook_name)
+            .cloned()
+            .collect()
+    }
+
+    /// Number of recorded entries
+    pub fn len(&self) -> usize {
+        self.entries.read().len()
+    }
+
+    /// Check if the log is empty
+    pub fn is_empty(&self) -> bool {
+        self.entries.read().is_empty()
+    }
+
+    /// Remove all recorded entries
+    pub fn clear(&self) {
+        self.entries.write().clear();
+    }
+}
+
 /// Hook executor with performance protection
 #[derive(Debug)]
 pub struct HookExecutor {
@@ -57,12 +145,14 @@ pub struct HookExecutor {
     hook_configs: Arc<RwLock<HashMap<String, HookExecutionConfig>>>,
     persistence_manager: Option<Arc<HookPersistenceManager>>,
     correlation_tracker: Option<Arc<EventCorrelationTracker>>,
+    slow_hook_log: Arc<SlowHookLog>,
 }
 
 /// Per-hook execution configuration
 #[derive(Debug, Clone)]
 pub struct HookExecutionConfig {
-    /// Custom timeout for this hook
+    /// Custom timeout for this hook (overrides the executor-wide enforced
+    /// timeout when one is configured)
     pub timeout: Option<Duration>,
     /// Whether circuit breaker is enabled for this hook
     pub use_circuit_breaker: bool,
@@ -102,6 +192,8 @@ impl HookExecutor {
             config.performance_config.clone(),
         ));
 
+        let slow_hook_log = Arc::new(SlowHookLog::new(config.slow_hook_log_capacity));
+
         Self {
             config,
             circuit_breakers,
@@ -109,6 +201,7 @@ impl HookExecutor {
             hook_configs: Arc::new(RwLock::new(HashMap::new())),
             persistence_manager: None, // Set later via set_persistence_manager
             correlation_tracker: None, // Set later via set_correlation_tracker
+            slow_hook_log,
         }
     }
 
@@ -185,8 +278,35 @@ impl HookExecutor {
             None
         };
 
-        // Execute the hook
-        let result = hook.execute(context).await;
+        // Execute the hook, abandoning it if it exceeds the enforced timeout
+        let enforced_timeout = self
+            .config
+            .hook_timeout
+            .map(|default| hook_config.timeout.unwrap_or(default));
+        let result = if let Some(limit) = enforced_timeout {
+            let input = context.data.clone();
+            match tokio::time::timeout(limit, hook.execute(context)).await {
+                Ok(result) => result,
+                Err(_) => {
+                    warn!(
+                        "Hook {} timed out after {:?} at {:?}; continuing",
+                        hook_name, limit, context.point
+                    );
+                    self.slow_hook_log.record(SlowHookRecord {
+                        hook_name: hook_name.clone(),
+                        hook_point: context.point.clone(),
+                        component_id: context.component_id.clone(),
+                        input,
+                        correlation_id: context.correlation_id,
+                        timeout: limit,
+                        recorded_at: Utc::now(),
+                    });
+                    Ok(HookResult::Continue)
+                }
+            }
+        } else {
+            hook.execute(context).await
+        };
 
         let duration = start.elapsed();
 
@@ -492,6 +612,11 @@ impl HookExecutor {
         self.correlation_tracker.clone()
     }
 
+    /// Get the log of hooks that exceeded the enforced timeout
+    pub fn slow_hook_log(&self) -> Arc<SlowHookLog> {
+        self.slow_hook_log.clone()
+    }
+
     /// Execute hooks for artifact events
     pub async fn execute_artifact_hooks(
         &self,
@@ -622,6 +747,18 @@ impl HookExecutorBuilder {
         self
     }
 
+    /// Enforce a timeout on every hook execution; timed-out hooks are recorded
+    /// in the slow-hook log and treated as `HookResult::Continue`
+    pub fn with_hook_timeout(mut self, timeout: Duration) -> Self {
+        self.config.hook_timeout = Some(timeout);
+        self
+    }
+
+    pub fn with_slow_hook_log_capacity(mut self, capacity: usize) -> Self {
+        self.config.slow_hook_log_capacity = capacity;
+        self
+    }
+
     pub fn build(self) -> HookExecutor {
         HookExecutor::with_config(self.config)
     }
@@ -773,6 +910,63 @@ mod tests {
         assert!(matches!(results[0], HookResult::Continue));
         assert!(matches!(results[1], HookResult::Cancel(_)));
     }
+    #[derive(Debug)]
+    struct SleepyHook {
+        delay: Duration,
+    }
+
+    #[async_trait::async_trait]
+    impl Hook for SleepyHook {
+        async fn execute(&self, _context: &mut HookContext) -> Result<HookResult> {
+            tokio::time::sleep(self.delay).await;
+            Ok(HookResult::Cancel("should have timed out".to_string()))
+        }
+
+        fn metadata(&self) -> crate::types::HookMetadata {
+            crate::types::HookMetadata {
+                name: "sleepy_hook".to_string(),
+                ..Default::default()
+            }
+        }
+
+        fn as_any(&self) -> &dyn std::any::Any {
+            self
+        }
+    }
+    #[tokio::test]
+    async fn test_hook_timeout_records_slow_hook() {
+        let executor = HookExecutorBuilder::new()
+            .with_hook_timeout(Duration::from_millis(20))
+            .build();
+
+        let hook = SleepyHook {
+            delay: Duration::from_millis(500),
+        };
+
+        let component_id = crate::types::ComponentId::new(
+            crate::types::ComponentType::Tool,
+            "slow_tool".to_string(),
+        );
+        let mut context = HookContext::new(HookPoint::BeforeToolExecution, component_id);
+        context.insert_data("query".to_string(), serde_json::json!("expensive"));
+        let correlation_id = context.correlation_id;
+
+        let result = executor.execute_hook(&hook, &mut context).await.unwrap();
+        assert!(matches!(result, HookResult::Continue));
+
+        let log = executor.slow_hook_log();
+        assert_eq!(log.len(), 1);
+        let record = &log.entries_for("sleepy_hook")[0];
+        assert_eq!(record.hook_name, "sleepy_hook");
+        assert_eq!(record.hook_point, HookPoint::BeforeToolExecution);
+        assert_eq!(record.component_id.name, "slow_tool");
+        assert_eq!(
+            record.input.get("query"),
+            Some(&serde_json::json!("expensive"))
+        );
+        assert_eq!(record.correlation_id, correlation_id);
+        assert_eq!(record.timeout, Duration::from_millis(20));
+    }
     #[tokio::test]
     async fn test_custom_hook_configuration() {
         let executor = HookExecutor::new();
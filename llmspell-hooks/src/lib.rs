@@ -120,7 +120,7 @@ pub use distributed::{
     DistributedHookContext, DistributedHookContextBuilder, PropagationFlags, RemoteAgentId,
     SecurityContext,
 };
-pub use executor::{HookExecutor, HookExecutorBuilder};
+pub use executor::{HookExecutor, HookExecutorBuilder, SlowHookLog, SlowHookRecord};
 pub use performance::{PerformanceMetrics, PerformanceMonitor};
 pub use persistence::{
     HookMetadata as PersistenceHookMetadata, HookPersistenceManager, RetentionManager,
@@ -14,9 +14,13 @@ use llmspell_core::traits::script_executor::ScriptExecutor;
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
+use std::time::Duration;
 use tracing::{debug, error, info, trace, warn};
 use uuid::Uuid;
 
+/// Default time a client waits for an `execute_reply`
+pub const DEFAULT_CLIENT_EXECUTE_TIMEOUT: Duration = Duration::from_secs(300);
+
 /// Configuration for starting a kernel service
 pub struct KernelServiceConfig {
     /// Port to listen on
@@ -850,14 +854,26 @@ pub struct ClientHandle {
     protocol: JupyterProtocol,
     connection_string: String,
     transport: Box<dyn Transport>,
+    execute_timeout: Duration,
 }
 
 impl ClientHandle {
+    /// Set how long `execute` waits for a reply before giving up
+    pub fn set_execute_timeout(&mut self, timeout: Duration) {
+        self.execute_timeout = timeout;
+    }
+
+    /// Time `execute` waits for a reply
+    pub const fn execute_timeout(&self) -> Duration {
+        self.execute_timeout
+    }
+
     /// Execute code on the remote kernel
     ///
     /// # Errors
     ///
-    /// Returns an error if the execution fails or communication with kernel fails
+    /// Returns an error if the execution fails, communication with kernel fails,
+    /// or no reply arrives within the execute timeout
     pub async fn execute(&mut self, code: &str) -> Result<String> {
         debug!("Sending execute request to kernel");
 
@@ -871,24 +887,52 @@ impl ClientHandle {
         });
 
         let request = self.protocol.create_request("execute_request", content)?;
+        let request_id = serde_json::from_slice::<serde_json::Value>(&request)?["header"]["msg_id"]
+            .as_str()
+            .map(str::to_string)
+            .ok_or_else(|| anyhow::anyhow!("execute_request has no msg_id"))?;
 
         // Send request through transport
         self.transport.send("shell", vec![request]).await?;
 
-        // Wait for execute_reply
-        loop {
-            if let Some(reply_parts) = self.transport.recv("shell").await? {
-                if let Some(first_part) = reply_parts.first() {
-                    // Parse reply and extract result
-                    let reply_msg = self.protocol.parse_message(first_part)?;
-                    if let Some(content) = reply_msg.get("content") {
-                        // Extract execution result
-                        return Ok(format!("Result: {content:?}"));
+        // Wait for execute_reply, giving up if the kernel never answers
+        let wait_for_reply = async {
+            loop {
+                if let Some(reply_parts) = self.transport.recv("shell").await? {
+                    if let Some(first_part) = reply_parts.first() {
+                        // Parse reply and extract result
+                        let reply_msg = self.protocol.parse_message(first_part)?;
+
+                        // Replies to earlier requests that timed out are stale
+                        let parent_id = reply_msg
+                            .get("parent_header")
+                            .and_then(|parent| parent.get("msg_id"))
+                            .and_then(serde_json::Value::as_str);
+                        if parent_id != Some(request_id.as_str()) {
+                            debug!(
+                                "Discarding shell reply to {parent_id:?}, awaiting {request_id}"
+                            );
+                            continue;
+                        }
+
+                        if let Some(content) = reply_msg.get("content") {
+                            // Extract execution result
+                            return anyhow::Ok(format!("Result: {content:?}"));
+                        }
                     }
                 }
+                tokio::time::sleep(tokio::time::Duration::from_millis(10)).await;
             }
-            tokio::time::sleep(tokio::time::Duration::from_millis(10)).await;
-        }
+        };
+
+        tokio::time::timeout(self.execute_timeout, wait_for_reply)
+            .await
+            .map_err(|_| {
+                anyhow::anyhow!(
+                    "Timeout waiting for execute_reply after {:?}",
+                    self.execute_timeout
+                )
+            })?
     }
 
     /// Send a tool request to the remote kernel and return the response
@@ -1738,6 +1782,7 @@ pub async fn connect_to_kernel(connection_string: &str) -> Result<ClientHandle>
         protocol,
         connection_string: connection_string.to_string(),
         transport,
+        execute_timeout: DEFAULT_CLIENT_EXECUTE_TIMEOUT,
     })
 }
 
@@ -1844,6 +1889,11 @@ pub async fn start_kernel_service_with_config(
                 );
             }
         }
+
+        // SIGTERM drains running executions before the kernel stops
+        let mut signal_bridge = crate::daemon::SignalBridge::new();
+        signal_bridge.init()?;
+        kernel.set_signal_bridge(Arc::new(signal_bridge));
     }
 
     // Write the connection file
@@ -2005,3 +2055,79 @@ fn build_execution_config(config: &LLMSpellConfig) -> crate::execution::Executio
         health_thresholds: None,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_client_execute_times_out_without_reply() {
+        let (mut kernel_transport, mut client_transport) = InProcessTransport::create_pair();
+        InProcessTransport::setup_paired_channel(
+            &mut kernel_transport,
+            &mut client_transport,
+            "shell",
+        );
+
+        // The kernel side stays alive but never replies
+        let mut client = ClientHandle {
+            protocol: JupyterProtocol::new_client(),
+            connection_string: "inprocess".to_string(),
+            transport: Box::new(client_transport),
+            execute_timeout: DEFAULT_CLIENT_EXECUTE_TIMEOUT,
+        };
+        client.set_execute_timeout(Duration::from_millis(200));
+
+        let start = std::time::Instant::now();
+        let result = tokio::time::timeout(Duration::from_secs(5), client.execute("1 + 1"))
+            .await
+            .expect("execute should not block past its timeout");
+
+        let err = result.unwrap_err();
+        assert!(err.to_string().contains("Timeout"), "{err}");
+        assert!(start.elapsed() >= Duration::from_millis(200));
+        assert!(kernel_transport.recv("shell").await.unwrap().is_some());
+    }
+
+    #[tokio::test]
+    async fn test_client_execute_discards_stale_replies() {
+        let (mut kernel_transport, mut client_transport) = InProcessTransport::create_pair();
+        InProcessTransport::setup_paired_channel(
+            &mut kernel_transport,
+            &mut client_transport,
+            "shell",
+        );
+        let mut client = ClientHandle {
+            protocol: JupyterProtocol::new_client(),
+            connection_string: "inprocess".to_string(),
+            transport: Box::new(client_transport),
+            execute_timeout: Duration::from_secs(5),
+        };
+
+        // A reply to an earlier, timed-out request arrives before the real one
+        let kernel = tokio::spawn(async move {
+            let parts = kernel_transport.recv("shell").await.unwrap().unwrap();
+            let request: serde_json::Value = serde_json::from_slice(&parts[0]).unwrap();
+            let reply = |parent_id: &str, value: i64| {
+                serde_json::to_vec(&serde_json::json!({
+                    "header": {"msg_type": "execute_reply"},
+                    "parent_header": {"msg_id": parent_id},
+                    "content": {"status": "ok", "value": value},
+                }))
+                .unwrap()
+            };
+            for reply in [
+                reply("earlier-request", 1),
+                reply(request["header"]["msg_id"].as_str().unwrap(), 2),
+            ] {
+                kernel_transport.send("shell", vec![reply]).await.unwrap();
+            }
+            kernel_transport
+        });
+
+        let result = client.execute("1 + 1").await.unwrap();
+        assert!(result.contains("Number(2)"), "{result}");
+        assert!(!result.contains("Number(1)"), "{result}");
+        kernel.await.unwrap();
+    }
+}
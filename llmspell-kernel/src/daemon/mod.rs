@@ -24,5 +24,7 @@ pub use logging::{DaemonLogWriter, LogRotationConfig, LogRotator};
 pub use manager::{DaemonConfig, DaemonManager};
 pub use operations::{KernelConfig, SignalOperationsConfig, SignalOperationsHandler};
 pub use pid::PidFile;
-pub use shutdown::{OperationGuard, ShutdownConfig, ShutdownCoordinator, ShutdownPhase};
+pub use shutdown::{
+    ExecutionTracker, OperationGuard, ShutdownConfig, ShutdownCoordinator, ShutdownPhase,
+};
 pub use signals::{KernelMessage, SignalBridge, SignalHandler};
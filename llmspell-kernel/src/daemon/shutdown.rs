@@ -6,12 +6,13 @@
 
 use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::future::Future;
 use std::path::PathBuf;
 use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::Arc;
 use std::time::{Duration, Instant};
-use tokio::sync::{broadcast, RwLock};
+use tokio::sync::{broadcast, watch, RwLock};
 use tokio::time::timeout;
 use tracing::{debug, error, info, warn};
 
@@ -433,6 +434,11 @@ impl ShutdownCoordinator {
         Ok(())
     }
 
+    /// Grace period for the shutdown sequence
+    pub const fn grace_period(&self) -> Duration {
+        Duration::from_secs(self.config.grace_period_secs)
+    }
+
     /// Get current shutdown phase
     pub async fn current_phase(&self) -> ShutdownPhase {
         *self.phase.read().await
@@ -463,6 +469,134 @@ impl Drop for OperationGuard {
     }
 }
 
+/// In-flight execution tracking for draining executions on shutdown
+///
+/// Executions run through [`ExecutionTracker::track`] in the caller's task.
+/// [`ExecutionTracker::drain`] stops new executions, waits for running ones and
+/// cancels whatever is still running when the grace period ends.
+pub struct ExecutionTracker {
+    /// Acceptance flag and running execution IDs
+    state: parking_lot::Mutex<TrackerState>,
+    /// Set once draining gives up on the remaining executions
+    cancel_tx: watch::Sender<bool>,
+}
+
+struct TrackerState {
+    accepting: bool,
+    in_flight: HashSet<String>,
+}
+
+impl ExecutionTracker {
+    /// Create a tracker that accepts executions
+    pub fn new() -> Self {
+        let (cancel_tx, _) = watch::channel(false);
+        Self {
+            state: parking_lot::Mutex::new(TrackerState {
+                accepting: true,
+                in_flight: HashSet::new(),
+            }),
+            cancel_tx,
+        }
+    }
+
+    /// Check if new executions are accepted
+    pub fn is_accepting(&self) -> bool {
+        self.state.lock().accepting
+    }
+
+    /// IDs of running executions, sorted
+    pub fn in_flight(&self) -> Vec<String> {
+        let mut ids: Vec<String> = self.state.lock().in_flight.iter().cloned().collect();
+        ids.sort();
+        ids
+    }
+
+    /// Run an execution to completion unless a drain cancels it
+    ///
+    /// Returns `None` if the execution was force-cancelled.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the tracker is draining and not accepting executions
+    pub async fn track<F: Future>(
+        &self,
+        execution_id: String,
+        execution: F,
+    ) -> Result<Option<F::Output>> {
+        {
+            let mut state = self.state.lock();
+            if !state.accepting {
+                anyhow::bail!("Kernel is shutting down, not accepting new executions");
+            }
+            state.in_flight.insert(execution_id.clone());
+        }
+        // Deregisters on completion, cancellation or the caller dropping us
+        let _entry = InFlightEntry {
+            tracker: self,
+            execution_id,
+        };
+
+        let mut cancel_rx = self.cancel_tx.subscribe();
+        tokio::select! {
+            output = execution => Ok(Some(output)),
+            _ = cancel_rx.wait_for(|cancelled| *cancelled) => Ok(None),
+        }
+    }
+
+    /// Stop accepting executions and wait up to `grace` for running ones
+    ///
+    /// Executions still running after `grace` are cancelled; their IDs are
+    /// returned, sorted.
+    pub async fn drain(&self, grace: Duration) -> Vec<String> {
+        self.state.lock().accepting = false;
+
+        let deadline = Instant::now() + grace;
+        loop {
+            let remaining = self.state.lock().in_flight.len();
+            if remaining == 0 {
+                info!("All executions finished during drain");
+                return Vec::new();
+            }
+            let now = Instant::now();
+            if now >= deadline {
+                break;
+            }
+            debug!("Draining {} running executions", remaining);
+            tokio::time::sleep((deadline - now).min(Duration::from_millis(10))).await;
+        }
+
+        let cancelled = self.in_flight();
+        warn!(
+            "Grace period elapsed, force-cancelling {} executions",
+            cancelled.len()
+        );
+        self.cancel_tx.send_replace(true);
+        cancelled
+    }
+}
+
+impl Default for ExecutionTracker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Registration of one running execution, removed on drop
+struct InFlightEntry<'a> {
+    tracker: &'a ExecutionTracker,
+    execution_id: String,
+}
+
+impl Drop for InFlightEntry<'_> {
+    fn drop(&mut self) {
+        self.tracker
+            .state
+            .lock()
+            .in_flight
+            .remove(&self.execution_id);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
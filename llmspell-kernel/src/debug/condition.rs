@@ -0,0 +1,368 @@
+//! Breakpoint condition and watch expression evaluation
+//!
+//! Evaluates simple Lua-style expressions such as `i == 5`, `name ~= "x"` or
+//! `count > 3 and not done` against the variables of the current stack frame.
+//! Expressions are not run in the script engine, so evaluating one never has
+//! side effects on the paused script.
+
+use super::execution_bridge::Variable;
+use anyhow::{anyhow, bail, Result};
+
+/// Value of a condition operand
+#[derive(Debug, Clone, PartialEq)]
+enum Value {
+    Nil,
+    Bool(bool),
+    Number(f64),
+    Str(String),
+}
+
+impl Value {
+    /// Convert a debugger variable using its reported type
+    fn from_variable(variable: &Variable) -> Self {
+        let raw = variable.value.trim();
+        match variable.var_type.as_str() {
+            "nil" => Self::Nil,
+            "boolean" => Self::Bool(raw == "true"),
+            "string" => Self::Str(unquote(raw).to_string()),
+            _ => match raw {
+                "nil" => Self::Nil,
+                "true" => Self::Bool(true),
+                "false" => Self::Bool(false),
+                _ => raw
+                    .parse()
+                    .map_or_else(|_| Self::Str(unquote(raw).to_string()), Self::Number),
+            },
+        }
+    }
+
+    /// Lua truthiness: only `nil` and `false` are false
+    const fn is_truthy(&self) -> bool {
+        !matches!(self, Self::Nil | Self::Bool(false))
+    }
+
+    /// Lua type name
+    const fn type_name(&self) -> &'static str {
+        match self {
+            Self::Nil => "nil",
+            Self::Bool(_) => "boolean",
+            Self::Number(_) => "number",
+            Self::Str(_) => "string",
+        }
+    }
+
+    /// Display the value the way Lua's `tostring` would
+    fn display(&self) -> String {
+        match self {
+            Self::Nil => "nil".to_string(),
+            Self::Bool(b) => b.to_string(),
+            Self::Number(n) => n.to_string(),
+            Self::Str(s) => s.clone(),
+        }
+    }
+}
+
+fn unquote(value: &str) -> &str {
+    value
+        .strip_prefix('"')
+        .and_then(|v| v.strip_suffix('"'))
+        .or_else(|| value.strip_prefix('\'').and_then(|v| v.strip_suffix('\'')))
+        .unwrap_or(value)
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Ident(String),
+    Number(f64),
+    Str(String),
+    Compare(&'static str),
+    And,
+    Or,
+    Not,
+    LParen,
+    RParen,
+}
+
+fn tokenize(condition: &str) -> Result<Vec<Token>> {
+    let chars: Vec<char> = condition.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+
+    while let Some(&c) = chars.get(i) {
+        let next = chars.get(i + 1).copied();
+        match c {
+            _ if c.is_whitespace() => i += 1,
+            '(' => {
+                tokens.push(Token::LParen);
+                i += 1;
+            }
+            ')' => {
+                tokens.push(Token::RParen);
+                i += 1;
+            }
+            '=' | '~' | '!' | '<' | '>' if next == Some('=') => {
+                tokens.push(Token::Compare(match c {
+                    '=' => "==",
+                    '<' => "<=",
+                    '>' => ">=",
+                    _ => "~=",
+                }));
+                i += 2;
+            }
+            '<' | '>' => {
+                tokens.push(Token::Compare(if c == '<' { "<" } else { ">" }));
+                i += 1;
+            }
+            '!' => {
+                tokens.push(Token::Not);
+                i += 1;
+            }
+            '&' | '|' if next == Some(c) => {
+                tokens.push(if c == '&' { Token::And } else { Token::Or });
+                i += 2;
+            }
+            '"' | '\'' => {
+                let end = chars[i + 1..]
+                    .iter()
+                    .position(|&ch| ch == c)
+                    .ok_or_else(|| anyhow!("Unterminated string in condition"))?;
+                tokens.push(Token::Str(chars[i + 1..i + 1 + end].iter().collect()));
+                i += end + 2;
+            }
+            _ if c.is_ascii_digit() || (c == '-' && next.is_some_and(|n| n.is_ascii_digit())) => {
+                let start = i;
+                i += 1;
+                while chars
+                    .get(i)
+                    .is_some_and(|ch| ch.is_ascii_digit() || *ch == '.')
+                {
+                    i += 1;
+                }
+                let literal: String = chars[start..i].iter().collect();
+                let number = literal
+                    .parse()
+                    .map_err(|_| anyhow!("Invalid number '{literal}' in condition"))?;
+                tokens.push(Token::Number(number));
+            }
+            _ if c.is_alphabetic() || c == '_' => {
+                let start = i;
+                while chars
+                    .get(i)
+                    .is_some_and(|ch| ch.is_alphanumeric() || *ch == '_' || *ch == '.')
+                {
+                    i += 1;
+                }
+                let word: String = chars[start..i].iter().collect();
+                tokens.push(match word.as_str() {
+                    "and" => Token::And,
+                    "or" => Token::Or,
+                    "not" => Token::Not,
+                    _ => Token::Ident(word),
+                });
+            }
+            _ => bail!("Unexpected character '{c}' in condition"),
+        }
+    }
+
+    Ok(tokens)
+}
+
+/// Recursive-descent evaluator over the token stream
+struct Parser<'a> {
+    tokens: &'a [Token],
+    pos: usize,
+    variables: &'a [Variable],
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<&'a Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn advance(&mut self) -> Option<&'a Token> {
+        let token = self.tokens.get(self.pos);
+        self.pos += 1;
+        token
+    }
+
+    fn or(&mut self) -> Result<Value> {
+        let mut value = self.and()?;
+        while self.peek() == Some(&Token::Or) {
+            self.pos += 1;
+            let rhs = self.and()?;
+            value = if value.is_truthy() { value } else { rhs };
+        }
+        Ok(value)
+    }
+
+    fn and(&mut self) -> Result<Value> {
+        let mut value = self.unary()?;
+        while self.peek() == Some(&Token::And) {
+            self.pos += 1;
+            let rhs = self.unary()?;
+            value = if value.is_truthy() { rhs } else { value };
+        }
+        Ok(value)
+    }
+
+    fn unary(&mut self) -> Result<Value> {
+        if self.peek() == Some(&Token::Not) {
+            self.pos += 1;
+            return Ok(Value::Bool(!self.unary()?.is_truthy()));
+        }
+        self.comparison()
+    }
+
+    fn comparison(&mut self) -> Result<Value> {
+        let lhs = self.operand()?;
+        let Some(&Token::Compare(op)) = self.peek() else {
+            return Ok(lhs);
+        };
+        self.pos += 1;
+        let rhs = self.operand()?;
+
+        let result = match (op, &lhs, &rhs) {
+            ("==", _, _) => lhs == rhs,
+            ("~=", _, _) => lhs != rhs,
+            (_, Value::Number(a), Value::Number(b)) => compare(op, a, b),
+            (_, Value::Str(a), Value::Str(b)) => compare(op, a, b),
+            _ => bail!("Cannot compare {lhs:?} {op} {rhs:?}"),
+        };
+        Ok(Value::Bool(result))
+    }
+
+    fn operand(&mut self) -> Result<Value> {
+        match self.advance() {
+            Some(Token::Number(n)) => Ok(Value::Number(*n)),
+            Some(Token::Str(s)) => Ok(Value::Str(s.clone())),
+            Some(Token::Ident(name)) => match name.as_str() {
+                "nil" => Ok(Value::Nil),
+                "true" => Ok(Value::Bool(true)),
+                "false" => Ok(Value::Bool(false)),
+                _ => self
+                    .variables
+                    .iter()
+                    .find(|variable| &variable.name == name)
+                    .map(Value::from_variable)
+                    .ok_or_else(|| anyhow!("Unknown variable '{name}' in condition")),
+            },
+            Some(Token::LParen) => {
+                let value = self.or()?;
+                match self.advance() {
+                    Some(Token::RParen) => Ok(value),
+                    _ => bail!("Missing ')' in condition"),
+                }
+            }
+            Some(token) => bail!("Unexpected {token:?} in condition"),
+            None => bail!("Condition ended unexpectedly"),
+        }
+    }
+}
+
+fn compare<T: PartialOrd + ?Sized>(op: &str, a: &T, b: &T) -> bool {
+    match op {
+        "<" => a < b,
+        "<=" => a <= b,
+        ">" => a > b,
+        _ => a >= b,
+    }
+}
+
+/// Evaluates breakpoint conditions against stack frame variables
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ConditionEvaluator;
+
+impl ConditionEvaluator {
+    /// Create a new condition evaluator
+    pub const fn new() -> Self {
+        Self
+    }
+
+    /// Evaluate a condition with Lua truthiness
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the condition cannot be parsed, references an
+    /// unknown variable, or compares incompatible values
+    pub fn evaluate(&self, condition: &str, variables: &[Variable]) -> Result<bool> {
+        Ok(Self::evaluate_expression(condition, variables)?.is_truthy())
+    }
+
+    /// Evaluate a watch expression into a variable named after it
+    ///
+    /// Evaluation errors are reported as a value of type `error` rather than
+    /// failing, so one bad watch does not hide the others.
+    pub fn evaluate_watch(&self, expression: &str, variables: &[Variable]) -> Variable {
+        let (value, var_type) = match Self::evaluate_expression(expression, variables) {
+            Ok(value) => (value.display(), value.type_name()),
+            Err(e) => (e.to_string(), "error"),
+        };
+        Variable {
+            name: expression.to_string(),
+            value,
+            var_type: var_type.to_string(),
+            has_children: false,
+            reference: None,
+        }
+    }
+
+    fn evaluate_expression(expression: &str, variables: &[Variable]) -> Result<Value> {
+        let tokens = tokenize(expression)?;
+        if tokens.is_empty() {
+            bail!("Condition is empty");
+        }
+
+        let mut parser = Parser {
+            tokens: &tokens,
+            pos: 0,
+            variables,
+        };
+        let value = parser.or()?;
+        if let Some(token) = parser.peek() {
+            bail!("Unexpected {token:?} in condition");
+        }
+        Ok(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn var(name: &str, value: &str, var_type: &str) -> Variable {
+        Variable {
+            name: name.to_string(),
+            value: value.to_string(),
+            var_type: var_type.to_string(),
+            has_children: false,
+            reference: None,
+        }
+    }
+
+    #[test]
+    fn test_evaluate_conditions() {
+        let evaluator = ConditionEvaluator::new();
+        let vars = [
+            var("i", "5", "number"),
+            var("name", "\"loop\"", "string"),
+            var("done", "false", "boolean"),
+            var("item", "nil", "nil"),
+        ];
+
+        assert!(evaluator.evaluate("i == 5", &vars).unwrap());
+        assert!(!evaluator.evaluate("i ~= 5", &vars).unwrap());
+        assert!(evaluator.evaluate("i >= 5 and i < 10", &vars).unwrap());
+        assert!(evaluator.evaluate("i > -1", &vars).unwrap());
+        assert!(evaluator.evaluate("name == 'loop'", &vars).unwrap());
+        assert!(evaluator.evaluate("not done && (i != 4)", &vars).unwrap());
+        assert!(evaluator.evaluate("item == nil or done", &vars).unwrap());
+        assert!(!evaluator.evaluate("item", &vars).unwrap());
+
+        for invalid in ["i ==", "i == 5)", "missing > 1", "name < 3", "", "i = 5"] {
+            assert!(
+                evaluator.evaluate(invalid, &vars).is_err(),
+                "'{invalid}' should be rejected"
+            );
+        }
+    }
+}
@@ -52,6 +52,19 @@ pub enum DebugEvent {
         /// Output text
         output: String,
     },
+    /// Breakpoint condition could not be evaluated, so execution did not pause
+    ConditionError {
+        /// Breakpoint ID
+        breakpoint_id: String,
+        /// Condition expression
+        condition: String,
+        /// Source file
+        source: String,
+        /// Line number
+        line: u32,
+        /// Evaluation error
+        error: String,
+    },
     /// Debug session terminated
     Terminated {
         /// Termination reason
@@ -72,6 +85,8 @@ pub enum DebugResponse {
     StackFrames(Vec<super::execution_bridge::StackFrame>),
     /// Breakpoints response
     Breakpoints(Vec<super::execution_bridge::Breakpoint>),
+    /// Watch expression values at a stop
+    Watches(Vec<Variable>),
 }
 
 /// Debug coordinator for managing debug operations
@@ -107,7 +122,9 @@ impl DebugCoordinator {
     /// Create a new debug coordinator
     pub fn new(session_id: String) -> Self {
         let (event_tx, event_rx) = mpsc::channel(100);
-        let execution_manager = Arc::new(ExecutionManager::new(session_id.clone()));
+        let mut execution_manager = ExecutionManager::new(session_id.clone());
+        execution_manager.set_debug_event_sender(event_tx.clone());
+        let execution_manager = Arc::new(execution_manager);
 
         Self {
             execution_manager,
@@ -300,6 +317,29 @@ impl DebugCoordinator {
         Ok(DebugResponse::StackFrames(frames))
     }
 
+    /// Add a watch expression
+    ///
+    /// Watches are evaluated by the execution manager each time execution
+    /// stops and reported with the stopped event.
+    pub fn add_watch(&self, expression: String) {
+        self.execution_manager.add_watch(expression);
+    }
+
+    /// Remove the watch expression at `index`
+    pub fn remove_watch(&self, index: usize) {
+        self.execution_manager.remove_watch(index);
+    }
+
+    /// Evaluate watch expressions against the current stack frame
+    ///
+    /// # Errors
+    /// Returns an error if the watches cannot be evaluated
+    pub fn get_watches(&self) -> Result<DebugResponse> {
+        Ok(DebugResponse::Watches(
+            self.execution_manager.evaluate_watches(),
+        ))
+    }
+
     /// Handle breakpoint hit
     pub async fn on_breakpoint_hit(&self, source: String, line: u32) {
         self.execution_manager.pause();
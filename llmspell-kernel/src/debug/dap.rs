@@ -444,6 +444,34 @@ impl DAPBridge {
                     }
                 })
             }
+            "source" => {
+                let reference = request
+                    .get("arguments")
+                    .and_then(|a| a.get("sourceReference"))
+                    .and_then(serde_json::Value::as_i64)
+                    .unwrap_or(0);
+                let content = u32::try_from(reference)
+                    .ok()
+                    .and_then(|id| self.map_script_to_source(id))
+                    .and_then(|source| source.content);
+                match content {
+                    Some(content) => serde_json::json!({
+                        "type": "response",
+                        "command": "source",
+                        "success": true,
+                        "body": {
+                            "content": content,
+                            "mimeType": "text/x-lua"
+                        }
+                    }),
+                    None => serde_json::json!({
+                        "type": "response",
+                        "command": "source",
+                        "success": false,
+                        "message": format!("No content for source reference {reference}")
+                    }),
+                }
+            }
             "scopes" => {
                 let frame_id = request
                     .get("arguments")
@@ -580,6 +608,53 @@ impl DAPBridge {
         self.source_map.write().insert(script_id, source_ref);
     }
 
+    /// Build the DAP source for a Lua chunk name
+    ///
+    /// `@path` chunks and bare paths are files the IDE can open directly.
+    /// Inline chunks (`=name`, `[string "..."]`) get a `sourceReference`
+    /// whose content, if registered with [`Self::add_source_mapping`], is
+    /// served by the `source` request.
+    pub fn resolve_source(&self, chunk_name: &str) -> Source {
+        if chunk_name.starts_with('=') || chunk_name.starts_with('[') {
+            let mut source_map = self.source_map.write();
+            let script_id = source_map
+                .values()
+                .find(|source| source.path == chunk_name)
+                .map(|source| source.script_id)
+                .unwrap_or_else(|| {
+                    let script_id = source_map.keys().max().map_or(1, |id| id + 1);
+                    source_map.insert(
+                        script_id,
+                        SourceReference {
+                            script_id,
+                            path: chunk_name.to_string(),
+                            content: None,
+                        },
+                    );
+                    script_id
+                });
+
+            return Source {
+                name: Some(chunk_name.trim_start_matches('=').to_string()),
+                path: None,
+                source_reference: Some(i32::try_from(script_id).unwrap_or(i32::MAX)),
+                presentation_hint: None,
+                origin: Some("inline".to_string()),
+            };
+        }
+
+        let path = chunk_name.strip_prefix('@').unwrap_or(chunk_name);
+        Source {
+            name: std::path::Path::new(path)
+                .file_name()
+                .map(|name| name.to_string_lossy().into_owned()),
+            path: Some(path.to_string()),
+            source_reference: None,
+            presentation_hint: None,
+            origin: None,
+        }
+    }
+
     /// Handle DAP initialize request
     ///
     /// # Errors
@@ -764,7 +839,13 @@ impl DAPBridge {
         let mut dap_breakpoints = Vec::new();
 
         for bp in breakpoints {
-            match manager.set_breakpoint(source_path.clone(), bp.line) {
+            let result = match bp.condition.clone() {
+                Some(condition) => {
+                    manager.set_conditional_breakpoint(source_path.clone(), bp.line, condition)
+                }
+                None => manager.set_breakpoint(source_path.clone(), bp.line),
+            };
+            match result {
                 Ok(breakpoint) => {
                     dap_breakpoints.push(DapBreakpoint {
                         id: Some(breakpoint.id),
@@ -811,20 +892,16 @@ impl DAPBridge {
         let frames = manager.get_stack_frames();
         let mut dap_frames = Vec::new();
 
-        // Reverse the frames to have newest (top of stack) first
+        // Reverse the frames to have newest (top of stack) first, keeping the
+        // manager's frame IDs so scopes requests find the frame's locals
         for (i, frame) in frames.iter().rev().enumerate() {
-            let source = self.map_script_to_source(frame.line).map(|src| Source {
-                name: Some(frame.source.clone()),
-                path: Some(src.path),
-                source_reference: None,
-                presentation_hint: None,
-                origin: None,
-            });
-
             dap_frames.push(DapStackFrame {
-                id: i32::try_from(i).unwrap_or(i32::MAX),
+                id: frame
+                    .id
+                    .parse()
+                    .unwrap_or_else(|_| i32::try_from(i).unwrap_or(i32::MAX)),
                 name: frame.name.clone(),
-                source,
+                source: Some(self.resolve_source(&frame.source)),
                 line: i32::try_from(frame.line).unwrap_or(i32::MAX),
                 column: i32::try_from(frame.column.unwrap_or(0)).unwrap_or(0),
                 end_line: None,
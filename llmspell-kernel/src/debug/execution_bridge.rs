@@ -5,6 +5,8 @@
 //!
 //! Migrated from Phase-9 branch (originally 642 lines)
 
+use super::condition::ConditionEvaluator;
+use super::coordinator::DebugEvent;
 use anyhow::Result;
 use async_trait::async_trait;
 use llmspell_core::traits::debug_context::{
@@ -17,7 +19,7 @@ use std::collections::HashMap;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 use tokio::sync::{mpsc, Notify};
-use tracing::{debug, instrument, trace};
+use tracing::{debug, instrument, trace, warn};
 
 /// Breakpoint information
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -53,7 +55,14 @@ impl Breakpoint {
         }
     }
 
-    /// Check if breakpoint should trigger
+    /// Only break when `condition` evaluates to true
+    #[must_use]
+    pub fn with_condition(mut self, condition: impl Into<String>) -> Self {
+        self.condition = Some(condition.into());
+        self
+    }
+
+    /// Check if breakpoint should trigger, ignoring its condition
     #[must_use]
     pub fn should_break(&self) -> bool {
         if !self.enabled {
@@ -137,6 +146,9 @@ pub struct StoppedEvent {
     pub file: String,
     /// Current line
     pub line: u32,
+    /// Values of the watch expressions at this stop
+    #[serde(default)]
+    pub watches: Vec<Variable>,
 }
 
 /// Pause state for async coordination
@@ -189,6 +201,12 @@ pub struct ExecutionManager {
     debug_enabled: Arc<AtomicBool>,
     /// Current execution location
     current_location: Arc<RwLock<Option<(String, u32)>>>,
+    /// Evaluator for breakpoint conditions
+    condition_evaluator: ConditionEvaluator,
+    /// Channel to report condition evaluation errors
+    debug_event_tx: Option<mpsc::Sender<DebugEvent>>,
+    /// Watch expressions re-evaluated whenever execution stops
+    watch_expressions: Arc<RwLock<Vec<String>>>,
 }
 
 impl ExecutionManager {
@@ -205,6 +223,9 @@ impl ExecutionManager {
             stopped_event_tx: None,
             debug_enabled: Arc::new(AtomicBool::new(false)),
             current_location: Arc::new(RwLock::new(None)),
+            condition_evaluator: ConditionEvaluator::new(),
+            debug_event_tx: None,
+            watch_expressions: Arc::new(RwLock::new(Vec::new())),
         }
     }
 
@@ -213,6 +234,11 @@ impl ExecutionManager {
         self.stopped_event_tx = Some(tx);
     }
 
+    /// Set the sender for debug events such as condition errors
+    pub fn set_debug_event_sender(&mut self, tx: mpsc::Sender<DebugEvent>) {
+        self.debug_event_tx = Some(tx);
+    }
+
     /// Set a breakpoint
     ///
     /// # Errors
@@ -220,11 +246,31 @@ impl ExecutionManager {
     /// Returns an error if the breakpoint already exists
     #[instrument(level = "debug", skip(self))]
     pub fn set_breakpoint(&self, source: String, line: u32) -> Result<Breakpoint> {
-        let breakpoint = Breakpoint::new(source.clone(), line);
+        self.add_breakpoint(Breakpoint::new(source, line))
+    }
+
+    /// Set a breakpoint that only pauses when `condition` is true
+    ///
+    /// The condition is evaluated against the variables of the current stack
+    /// frame each time the line is reached.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the breakpoint already exists
+    #[instrument(level = "debug", skip(self))]
+    pub fn set_conditional_breakpoint(
+        &self,
+        source: String,
+        line: u32,
+        condition: String,
+    ) -> Result<Breakpoint> {
+        self.add_breakpoint(Breakpoint::new(source, line).with_condition(condition))
+    }
 
+    fn add_breakpoint(&self, breakpoint: Breakpoint) -> Result<Breakpoint> {
         let mut breakpoints = self.breakpoints.write();
         breakpoints
-            .entry(source)
+            .entry(breakpoint.source.clone())
             .or_default()
             .push(breakpoint.clone());
 
@@ -235,6 +281,45 @@ impl ExecutionManager {
         Ok(breakpoint)
     }
 
+    /// Whether a breakpoint at the current location should pause execution
+    ///
+    /// A condition that fails to evaluate does not pause; the error is
+    /// reported as a [`DebugEvent::ConditionError`].
+    fn breakpoint_triggers(&self, breakpoint: &Breakpoint) -> bool {
+        if !breakpoint.should_break() {
+            return false;
+        }
+        let Some(ref condition) = breakpoint.condition else {
+            return true;
+        };
+
+        let variables = self
+            .stack_frames
+            .read()
+            .last()
+            .map(|frame| frame.locals.clone())
+            .unwrap_or_default();
+        match self.condition_evaluator.evaluate(condition, &variables) {
+            Ok(result) => result,
+            Err(e) => {
+                warn!(
+                    "Breakpoint condition '{}' at {}:{} failed: {}",
+                    condition, breakpoint.source, breakpoint.line, e
+                );
+                if let Some(ref tx) = self.debug_event_tx {
+                    let _ = tx.try_send(DebugEvent::ConditionError {
+                        breakpoint_id: breakpoint.id.clone(),
+                        condition: condition.clone(),
+                        source: breakpoint.source.clone(),
+                        line: breakpoint.line,
+                        error: e.to_string(),
+                    });
+                }
+                false
+            }
+        }
+    }
+
     /// Remove a breakpoint
     ///
     /// # Errors
@@ -265,7 +350,7 @@ impl ExecutionManager {
         // Check breakpoints
         if let Some(bp_list) = self.breakpoints.read().get(source) {
             for bp in bp_list {
-                if bp.line == line && bp.should_break() {
+                if bp.line == line && self.breakpoint_triggers(bp) {
                     return true;
                 }
             }
@@ -358,7 +443,7 @@ impl ExecutionManager {
             let mut hit_breakpoint = None;
             if let Some(bp_list) = breakpoints.get(file) {
                 for bp in bp_list {
-                    if bp.line == line && bp.enabled && bp.should_break() {
+                    if bp.line == line && self.breakpoint_triggers(bp) {
                         hit_breakpoint = Some(bp.id.clone());
                         break;
                     }
@@ -396,6 +481,7 @@ impl ExecutionManager {
                     breakpoint_id,
                     file: file.to_string(),
                     line,
+                    watches: self.evaluate_watches(),
                 };
 
                 // Send without blocking
@@ -411,6 +497,43 @@ impl ExecutionManager {
         Ok(())
     }
 
+    /// Add a watch expression
+    pub fn add_watch(&self, expression: String) {
+        self.watch_expressions.write().push(expression);
+    }
+
+    /// Remove the watch expression at `index`
+    pub fn remove_watch(&self, index: usize) {
+        let mut watches = self.watch_expressions.write();
+        if index < watches.len() {
+            watches.remove(index);
+        }
+    }
+
+    /// Evaluate watch expressions against the current stack frame
+    pub fn evaluate_watches(&self) -> Vec<Variable> {
+        let watches = self.watch_expressions.read();
+        if watches.is_empty() {
+            return Vec::new();
+        }
+
+        let variables = self
+            .stack_frames
+            .read()
+            .last()
+            .map(|frame| frame.locals.clone())
+            .unwrap_or_default();
+        watches
+            .iter()
+            .map(|expr| self.condition_evaluator.evaluate_watch(expr, &variables))
+            .collect()
+    }
+
+    /// Get the last location reported by the script engine
+    pub fn current_location(&self) -> Option<(String, u32)> {
+        self.current_location.read().clone()
+    }
+
     /// Get the pause state for external coordination
     pub fn pause_state(&self) -> &PauseState {
         &self.pause_state
@@ -473,12 +596,18 @@ impl DebugContext for ExecutionManager {
 
         // Send stopped event if channel available
         if let Some(ref tx) = self.stopped_event_tx {
+            let reason = if self.should_step() {
+                "step"
+            } else {
+                "breakpoint"
+            };
             let event = StoppedEvent {
-                reason: "breakpoint".to_string(),
+                reason: reason.to_string(),
                 thread_id: 1,
                 breakpoint_id: None,
                 file: file.to_string(),
                 line,
+                watches: self.evaluate_watches(),
             };
             let _ = tx.send(event).await;
         }
@@ -573,6 +702,67 @@ impl DebugContext for ExecutionManager {
             None
         };
     }
+
+    fn wants_locals(&self, file: &str, line: u32) -> bool {
+        self.breakpoints.read().get(file).is_some_and(|bp_list| {
+            bp_list
+                .iter()
+                .any(|bp| bp.line == line && bp.enabled && bp.condition.is_some())
+        })
+    }
+
+    fn set_frame_locals(&self, file: &str, line: u32, locals: Vec<DebugVariable>) {
+        let locals = to_variables(locals);
+
+        // The engine reports the frame that is executing, i.e. the top of the stack
+        let mut frames = self.stack_frames.write();
+        if let Some(frame) = frames.last_mut() {
+            frame.source = file.to_string();
+            frame.line = line;
+            frame.locals = locals;
+        } else {
+            frames.push(StackFrame {
+                id: "0".to_string(),
+                name: "main".to_string(),
+                source: file.to_string(),
+                line,
+                column: None,
+                locals,
+            });
+        }
+    }
+
+    fn set_call_stack(&self, frames: Vec<(DebugStackFrame, Vec<DebugVariable>)>) {
+        // Frames are stored outermost first, with IDs matching their position
+        let frames = frames
+            .into_iter()
+            .rev()
+            .enumerate()
+            .map(|(i, (frame, locals))| StackFrame {
+                id: i.to_string(),
+                name: frame.name,
+                source: frame.file,
+                line: frame.line,
+                column: frame.column,
+                locals: to_variables(locals),
+            })
+            .collect();
+        *self.stack_frames.write() = frames;
+    }
+}
+
+/// Convert engine-reported locals into inspectable variables
+fn to_variables(locals: Vec<DebugVariable>) -> Vec<Variable> {
+    locals
+        .into_iter()
+        .map(|var| Variable {
+            name: var.name,
+            value: var.value,
+            var_type: var.var_type,
+            has_children: var.has_children,
+            reference: None,
+        })
+        .collect()
 }
 
 #[cfg(test)]
@@ -605,6 +795,59 @@ mod tests {
         assert!(!manager.should_pause("test.lua", 10));
     }
 
+    #[test]
+    fn test_conditional_breakpoint_in_loop() {
+        let mut manager = ExecutionManager::new("test-session".to_string());
+        let (tx, mut rx) = mpsc::channel(10);
+        manager.set_debug_event_sender(tx);
+        manager
+            .set_conditional_breakpoint("loop.lua".to_string(), 3, "i == 5".to_string())
+            .unwrap();
+
+        // for i = 0, 9 do ... end, with the loop body on line 3
+        let mut paused_iterations = Vec::new();
+        for (iteration, i) in (0..10).enumerate() {
+            manager.update_stack_frames(vec![StackFrame {
+                id: "0".to_string(),
+                name: "main".to_string(),
+                source: "loop.lua".to_string(),
+                line: 3,
+                column: None,
+                locals: vec![Variable {
+                    name: "i".to_string(),
+                    value: i.to_string(),
+                    var_type: "number".to_string(),
+                    has_children: false,
+                    reference: None,
+                }],
+            }]);
+            if manager.should_pause("loop.lua", 3) {
+                paused_iterations.push(iteration + 1);
+            }
+        }
+        assert_eq!(paused_iterations, vec![6]);
+        assert!(rx.try_recv().is_err());
+
+        // An invalid condition reports an event instead of pausing
+        let bp = manager
+            .set_conditional_breakpoint("loop.lua".to_string(), 7, "i ==".to_string())
+            .unwrap();
+        assert!(!manager.should_pause("loop.lua", 7));
+        match rx.try_recv().unwrap() {
+            DebugEvent::ConditionError {
+                breakpoint_id,
+                condition,
+                line,
+                ..
+            } => {
+                assert_eq!(breakpoint_id, bp.id);
+                assert_eq!(condition, "i ==");
+                assert_eq!(line, 7);
+            }
+            other => panic!("Expected condition error, got {other:?}"),
+        }
+    }
+
     #[test]
     fn test_pause_resume() {
         let manager = ExecutionManager::new("test-session".to_string());
@@ -3,6 +3,7 @@
 //! This module consolidates the debug infrastructure from Phase-9 branch,
 //! including execution bridge, debug coordinator, DAP bridge, and language-specific debug support.
 
+pub mod condition;
 pub mod coordinator;
 pub mod dap;
 pub mod execution_bridge;
@@ -10,6 +11,7 @@ pub mod lua;
 pub mod session;
 
 // Re-export main types
+pub use condition::ConditionEvaluator;
 pub use coordinator::{DebugCoordinator, DebugEvent, DebugResponse, MemoryAwareDebugCoordinator};
 pub use dap::{
     Capabilities as DapCapabilities, DAPBridge, DapBreakpoint, DapStackFrame, DapVariable,
@@ -70,8 +70,6 @@ pub struct DebugSession {
     state: Arc<RwLock<SessionState>>,
     /// Configuration
     config: DebugSessionConfig,
-    /// Watch expressions
-    watch_expressions: Arc<RwLock<Vec<String>>>,
     /// Session metadata
     metadata: Arc<RwLock<SessionMetadata>>,
     /// Event receiver
@@ -104,7 +102,6 @@ impl DebugSession {
             coordinator,
             state: Arc::new(RwLock::new(SessionState::Initialized)),
             config,
-            watch_expressions: Arc::new(RwLock::new(Vec::new())),
             metadata: Arc::new(RwLock::new(SessionMetadata {
                 script_path: None,
                 _started_at: Instant::now(),
@@ -223,33 +220,17 @@ impl DebugSession {
 
     /// Add watch expression
     pub fn add_watch(&self, expression: String) {
-        self.watch_expressions.write().push(expression);
+        self.coordinator.add_watch(expression);
     }
 
     /// Remove watch expression
     pub fn remove_watch(&self, index: usize) {
-        let mut watches = self.watch_expressions.write();
-        if index < watches.len() {
-            watches.remove(index);
-        }
+        self.coordinator.remove_watch(index);
     }
 
-    /// Evaluate watch expressions
+    /// Evaluate watch expressions against the current frame
     pub fn evaluate_watches(&self) -> Vec<Variable> {
-        let watches = self.watch_expressions.read().clone();
-        let mut results = Vec::new();
-
-        for (i, expr) in watches.iter().enumerate() {
-            results.push(Variable {
-                name: format!("watch_{i}"),
-                value: format!("<{expr}>"), // Simplified
-                var_type: "watch".to_string(),
-                has_children: false,
-                reference: None,
-            });
-        }
-
-        results
+        self.coordinator.execution_manager().evaluate_watches()
     }
 
     /// Get variables
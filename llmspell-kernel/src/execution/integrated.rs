@@ -22,8 +22,8 @@ use uuid;
 
 use crate::connection::ConnectionFileManager;
 use crate::daemon::{
-    DaemonConfig, KernelMessage, OperationGuard, ShutdownConfig, ShutdownCoordinator, SignalBridge,
-    SignalOperationsConfig, SignalOperationsHandler,
+    DaemonConfig, ExecutionTracker, KernelMessage, OperationGuard, ShutdownConfig,
+    ShutdownCoordinator, SignalBridge, SignalOperationsConfig, SignalOperationsHandler,
 };
 use crate::debug::{DAPBridge, ExecutionManager};
 use crate::events::correlation::{ExecutionState, ExecutionStatus};
@@ -38,6 +38,9 @@ use crate::traits::{Protocol, Transport};
 
 // Session dependencies
 
+/// How often the signal watcher checks for pending Unix signals
+const SIGNAL_POLL_INTERVAL: Duration = Duration::from_millis(50);
+
 /// I/O configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct IOConfig {
@@ -151,8 +154,12 @@ pub struct IntegratedKernel<P: Protocol> {
     shutdown_rx: Option<mpsc::Receiver<()>>,
     /// Shutdown coordinator for graceful shutdown
     shutdown_coordinator: Arc<ShutdownCoordinator>,
+    /// In-flight executions, drained on shutdown
+    execution_tracker: Arc<ExecutionTracker>,
     /// Signal bridge for handling Unix signals
     signal_bridge: Option<Arc<SignalBridge>>,
+    /// Kernel messages forwarded by the signal watcher
+    signal_rx: Option<mpsc::Receiver<KernelMessage>>,
     /// Signal operations handler for SIGUSR1/SIGUSR2
     signal_operations: Arc<SignalOperationsHandler>,
     /// Connection file manager for Jupyter discovery
@@ -439,7 +446,9 @@ impl<P: Protocol + 'static> IntegratedKernel<P> {
             dap_bridge,
             shutdown_rx: None,
             shutdown_coordinator,
+            execution_tracker: Arc::new(ExecutionTracker::new()),
             signal_bridge: None,
+            signal_rx: None,
             signal_operations,
             connection_manager: None,
             health_monitor,
@@ -461,15 +470,71 @@ impl<P: Protocol + 'static> IntegratedKernel<P> {
     }
 
     /// Set signal bridge for Unix signal handling
+    ///
+    /// Signals are watched once [`Self::run`] starts, or earlier through
+    /// [`Self::start_signal_watcher`].
     pub fn set_signal_bridge(&mut self, bridge: Arc<SignalBridge>) {
         self.signal_bridge = Some(bridge);
     }
 
+    /// Start watching the signal bridge in a background task
+    ///
+    /// The kernel loop does not poll while it runs an execution, so the watcher
+    /// drains running executions itself on SIGTERM and forwards the resulting
+    /// kernel messages to [`Self::process_signals`]. Does nothing without a
+    /// signal bridge or if the watcher is already running.
+    pub fn start_signal_watcher(&mut self) {
+        let Some(bridge) = self.signal_bridge.clone() else {
+            return;
+        };
+        if self.signal_rx.is_some() {
+            return;
+        }
+
+        let (tx, rx) = mpsc::channel(16);
+        self.signal_rx = Some(rx);
+        let tracker = self.execution_tracker.clone();
+        let grace = self.shutdown_coordinator.grace_period();
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(SIGNAL_POLL_INTERVAL);
+            while !tx.is_closed() {
+                interval.tick().await;
+                let Some(message) = bridge
+                    .process_signals_to_messages()
+                    .await
+                    .as_ref()
+                    .and_then(SignalBridge::action_to_message)
+                else {
+                    continue;
+                };
+
+                if matches!(message, KernelMessage::ShutdownRequest { .. }) {
+                    let cancelled = tracker.drain(grace).await;
+                    if !cancelled.is_empty() {
+                        warn!("Force-cancelled executions on signal: {:?}", cancelled);
+                    }
+                }
+                if tx.send(message).await.is_err() {
+                    break;
+                }
+            }
+            debug!("Signal watcher stopped");
+        });
+        debug!("Signal watcher started");
+    }
+
     /// Get shutdown coordinator
     pub fn shutdown_coordinator(&self) -> Arc<ShutdownCoordinator> {
         self.shutdown_coordinator.clone()
     }
 
+    /// Get the in-flight execution tracker
+    ///
+    /// The tracker can drain executions while the kernel is busy running one.
+    pub fn execution_tracker(&self) -> Arc<ExecutionTracker> {
+        self.execution_tracker.clone()
+    }
+
     /// Get connection file path if available
     ///
     /// Returns the path to the Jupyter connection file if one was created
@@ -541,8 +606,9 @@ impl<P: Protocol + 'static> IntegratedKernel<P> {
     pub async fn handle_shutdown(&mut self, restart: bool) -> Result<()> {
         info!("Handling shutdown request (restart={})", restart);
 
-        // Initiate graceful shutdown
-        self.shutdown_coordinator.initiate_shutdown().await?;
+        // Drain executions, then run the graceful shutdown sequence
+        self.shutdown(self.shutdown_coordinator.grace_period())
+            .await?;
 
         // Send shutdown reply if transport is available
         if self.transport.is_some() {
@@ -556,41 +622,74 @@ impl<P: Protocol + 'static> IntegratedKernel<P> {
         Ok(())
     }
 
+    /// Gracefully shut down, draining running executions
+    ///
+    /// Stops accepting execute requests, waits up to `grace` for running
+    /// executions to finish and force-cancels the rest, shuts down the script
+    /// executor, then runs the shutdown coordinator's sequence. Returns the IDs
+    /// of force-cancelled executions.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the shutdown coordinator fails to initiate shutdown
+    pub async fn shutdown(&self, grace: Duration) -> Result<Vec<String>> {
+        info!("Draining executions with {:?} grace period", grace);
+        let cancelled = self.execution_tracker.drain(grace).await;
+        if !cancelled.is_empty() {
+            warn!("Force-cancelled executions: {:?}", cancelled);
+        }
+
+        self.script_executor.shutdown().await;
+        self.shutdown_coordinator.initiate_shutdown().await?;
+        Ok(cancelled)
+    }
+
     /// Process signals from signal bridge
     ///
+    /// Takes messages from the signal watcher when it is running, otherwise
+    /// polls the bridge directly. Returns `true` once a shutdown signal has
+    /// been handled and the kernel should stop.
+    ///
     /// # Errors
     ///
     /// Returns an error if signal processing or shutdown handling fails
-    pub async fn process_signals(&mut self) -> Result<()> {
-        if let Some(ref bridge) = self.signal_bridge {
-            if let Some(action) = bridge.process_signals_to_messages().await {
-                // Convert to kernel message
-                if let Some(msg) = SignalBridge::action_to_message(&action) {
-                    match msg {
-                        KernelMessage::ShutdownRequest { restart } => {
-                            self.handle_shutdown(restart).await?;
-                        }
-                        KernelMessage::InterruptRequest => {
-                            info!("Handling interrupt request from signal");
-                            // TODO: Interrupt current execution
-                        }
-                        KernelMessage::ConfigReload => {
-                            info!("Processing config reload from SIGUSR1");
-                            if let Err(e) = self.signal_operations.handle_config_reload().await {
-                                error!("Failed to reload configuration: {}", e);
-                            }
-                        }
-                        KernelMessage::StateDump => {
-                            info!("Processing state dump from SIGUSR2");
-                            if let Err(e) = self.signal_operations.handle_state_dump().await {
-                                error!("Failed to dump state: {}", e);
-                            }
-                        }
-                    }
+    pub async fn process_signals(&mut self) -> Result<bool> {
+        let message = if let Some(ref mut signal_rx) = self.signal_rx {
+            signal_rx.try_recv().ok()
+        } else if let Some(ref bridge) = self.signal_bridge {
+            bridge
+                .process_signals_to_messages()
+                .await
+                .as_ref()
+                .and_then(SignalBridge::action_to_message)
+        } else {
+            None
+        };
+
+        match message {
+            Some(KernelMessage::ShutdownRequest { restart }) => {
+                self.handle_shutdown(restart).await?;
+                return Ok(true);
+            }
+            Some(KernelMessage::InterruptRequest) => {
+                info!("Handling interrupt request from signal");
+                // TODO: Interrupt current execution
+            }
+            Some(KernelMessage::ConfigReload) => {
+                info!("Processing config reload from SIGUSR1");
+                if let Err(e) = self.signal_operations.handle_config_reload().await {
+                    error!("Failed to reload configuration: {}", e);
+                }
+            }
+            Some(KernelMessage::StateDump) => {
+                info!("Processing state dump from SIGUSR2");
+                if let Err(e) = self.signal_operations.handle_state_dump().await {
+                    error!("Failed to dump state: {}", e);
                 }
             }
+            None => {}
         }
-        Ok(())
+        Ok(false)
     }
 
     /// Set transport for message communication
@@ -765,6 +864,7 @@ impl<P: Protocol + 'static> IntegratedKernel<P> {
             "Entering main kernel loop with transport={}",
             self.transport.is_some()
         );
+        self.start_signal_watcher();
 
         loop {
             // Check for shutdown signal
@@ -775,6 +875,12 @@ impl<P: Protocol + 'static> IntegratedKernel<P> {
                 }
             }
 
+            // Handle Unix signals; SIGTERM drains executions and stops the loop
+            if self.process_signals().await? {
+                info!("Kernel shut down by signal");
+                break;
+            }
+
             // Process IOPub messages (Output)
             // This handles captured stdout/stderr and other kernel outputs
             // We do this every loop iteration to ensure outputs are flushed
@@ -1592,6 +1698,12 @@ impl<P: Protocol + 'static> IntegratedKernel<P> {
 
         info!("Executing code [{}]: {} bytes", exec_count, code.len());
 
+        // Replies echo the request header so clients can match them
+        let parent_header = message
+            .get("header")
+            .cloned()
+            .unwrap_or_else(|| serde_json::json!({}));
+
         // Set parent header for message correlation
         if let Some(header) = message.get("header") {
             if let Ok(header) = serde_json::from_value(header.clone()) {
@@ -1627,11 +1739,13 @@ impl<P: Protocol + 'static> IntegratedKernel<P> {
 
         // Execute code in current context (NOT SPAWNED)
         let start_time = std::time::Instant::now();
+        let tracker = self.execution_tracker.clone();
         let result = tokio::time::timeout(
             Duration::from_secs(self.config.execution_timeout_secs),
-            self.execute_code_in_context(code),
+            tracker.track(execution_id.clone(), self.execute_code_in_context(code)),
         )
-        .await;
+        .await
+        .map(|tracked| flatten_tracked(&execution_id, tracked));
 
         let execution_time = start_time.elapsed();
 
@@ -1670,8 +1784,9 @@ impl<P: Protocol + 'static> IntegratedKernel<P> {
                 self.process_iopub_messages().await?;
 
                 // Send execute_reply message through protocol
-                let execute_reply = self.protocol.create_response(
+                let execute_reply = self.protocol.create_reply(
                     "execute_reply",
+                    &parent_header,
                     serde_json::json!({
                         "status": "ok",
                         "execution_count": exec_count,
@@ -1717,8 +1832,9 @@ impl<P: Protocol + 'static> IntegratedKernel<P> {
                 self.process_iopub_messages().await?;
 
                 // Send execute_reply with error status
-                let execute_reply = self.protocol.create_response(
+                let execute_reply = self.protocol.create_reply(
                     "execute_reply",
+                    &parent_header,
                     serde_json::json!({
                         "status": "error",
                         "execution_count": exec_count,
@@ -1765,8 +1881,9 @@ impl<P: Protocol + 'static> IntegratedKernel<P> {
                     .await?;
 
                 // Send timeout execute_reply message through protocol
-                let timeout_reply = self.protocol.create_response(
+                let timeout_reply = self.protocol.create_reply(
                     "execute_reply",
+                    &parent_header,
                     serde_json::json!({
                         "status": "aborted",
                         "execution_count": exec_count,
@@ -2000,7 +2117,9 @@ impl<P: Protocol + 'static> IntegratedKernel<P> {
         args: HashMap<String, String>,
     ) -> Result<String> {
         // Check if we're accepting requests
-        if !self.shutdown_coordinator.is_accepting_requests().await {
+        if !self.execution_tracker.is_accepting()
+            || !self.shutdown_coordinator.is_accepting_requests().await
+        {
             return Err(anyhow::anyhow!(
                 "Kernel is shutting down, not accepting new requests"
             ));
@@ -2024,31 +2143,35 @@ impl<P: Protocol + 'static> IntegratedKernel<P> {
         // Execute code with arguments if provided
         // Clone args for PostCodeExecution hook (Phase 13.7.3a)
         let args_clone = args.clone();
-        let result = if args.is_empty() {
-            // Execute code using the internal method as before
-            self.execute_code_in_context(code).await
-        } else {
-            debug!("Executing script with {} arguments", args.len());
-            // Use the new execute_script_with_args method
-            match self
-                .script_executor
-                .execute_script_with_args(code, args)
-                .await
-            {
-                Ok(output) => {
-                    // Convert ScriptExecutionOutput to String
-                    // Combine console output and result
-                    let mut result = String::new();
-                    if !output.console_output.is_empty() {
-                        result.push_str(&output.console_output.join("\n"));
-                        result.push('\n');
+        let tracker = self.execution_tracker.clone();
+        let execution = async {
+            if args.is_empty() {
+                // Execute code using the internal method as before
+                self.execute_code_in_context(code).await
+            } else {
+                debug!("Executing script with {} arguments", args.len());
+                // Use the new execute_script_with_args method
+                match self
+                    .script_executor
+                    .execute_script_with_args(code, args)
+                    .await
+                {
+                    Ok(output) => {
+                        // Convert ScriptExecutionOutput to String
+                        // Combine console output and result
+                        let mut result = String::new();
+                        if !output.console_output.is_empty() {
+                            result.push_str(&output.console_output.join("\n"));
+                            result.push('\n');
+                        }
+                        result.push_str(&serde_json::to_string(&output.output).unwrap_or_default());
+                        Ok(result)
                     }
-                    result.push_str(&serde_json::to_string(&output.output).unwrap_or_default());
-                    Ok(result)
+                    Err(e) => Err(anyhow::anyhow!("Script execution failed: {e}")),
                 }
-                Err(e) => Err(anyhow::anyhow!("Script execution failed: {e}")),
             }
         };
+        let result = flatten_tracked(&exec_id, tracker.track(exec_id.clone(), execution).await);
 
         // Update state based on result
         match &result {
@@ -4323,6 +4446,15 @@ impl<P: Protocol + 'static> IntegratedKernel<P> {
     }
 }
 
+/// Treat a force-cancelled tracked execution as an execution error
+fn flatten_tracked(execution_id: &str, tracked: Result<Option<Result<String>>>) -> Result<String> {
+    tracked?.unwrap_or_else(|| {
+        Err(anyhow!(
+            "Execution {execution_id} was cancelled during shutdown"
+        ))
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -4374,6 +4506,15 @@ mod tests {
             Ok(vec![])
         }
 
+        fn create_reply(
+            &self,
+            _msg_type: &str,
+            _parent_header: &Value,
+            _content: Value,
+        ) -> Result<Vec<u8>> {
+            Ok(vec![])
+        }
+
         fn create_request(&self, _msg_type: &str, _content: Value) -> Result<Vec<u8>> {
             Ok(vec![])
         }
@@ -70,6 +70,9 @@ pub use transport::zeromq::ZmqTransport;
 
 pub use transport::jupyter::{JupyterConnectionInfo, JupyterTransport};
 
+#[cfg(feature = "websocket")]
+pub use transport::websocket::{WebSocketConnectionInfo, WebSocketTransport};
+
 // Re-export execution types
 pub use execution::{ExecutionConfig, IntegratedKernel};
 
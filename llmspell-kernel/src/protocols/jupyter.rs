@@ -300,9 +300,17 @@ impl Protocol for JupyterProtocol {
     }
 
     fn create_response(&self, msg_type: &str, content: Value) -> Result<Vec<u8>> {
+        self.create_reply(msg_type, &json!({}), content)
+    }
+
+    fn create_reply(
+        &self,
+        msg_type: &str,
+        parent_header: &Value,
+        content: Value,
+    ) -> Result<Vec<u8>> {
         // Create a complete Jupyter wire protocol response with HMAC signature
         let header = self.create_header(msg_type);
-        let parent_header: HashMap<String, Value> = HashMap::new(); // Would be set from request in real impl
         let metadata: HashMap<String, Value> = HashMap::new();
 
         // Calculate signature if key is set
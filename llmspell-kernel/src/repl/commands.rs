@@ -203,6 +203,8 @@ pub enum MetaCommand {
     Run { file: PathBuf, args: Vec<String> },
     /// Toggle performance monitoring
     Perf { enabled: bool },
+    /// Time code execution over one or more runs
+    TimeIt { runs: usize, code: String },
 }
 
 impl MetaCommand {
@@ -293,6 +295,22 @@ impl MetaCommand {
                 };
                 Ok(MetaCommand::Perf { enabled })
             }
+            "timeit" => {
+                let usage = || anyhow::anyhow!("Usage: .timeit [runs] <code>");
+                let rest = input.trim_start()[parts[0].len()..].trim();
+                // A leading count is only treated as such when code follows it
+                let (runs, code) = rest
+                    .split_once(char::is_whitespace)
+                    .and_then(|(count, code)| Some((count.parse().ok()?, code.trim())))
+                    .unwrap_or((1, rest));
+                if runs == 0 || code.is_empty() {
+                    return Err(usage());
+                }
+                Ok(MetaCommand::TimeIt {
+                    runs,
+                    code: code.to_string(),
+                })
+            }
             _ => Err(anyhow::anyhow!("Unknown meta command: {}", parts[0])),
         }
     }
@@ -316,7 +334,8 @@ impl MetaCommand {
   .info                Show system info
   .reset               Reset session state
   .run <script> [args] Run a script file with optional arguments
-  .perf [on|off]       Toggle performance monitoring"
+  .perf [on|off]       Toggle performance monitoring
+  .timeit [n] <code>   Time code execution, reporting min/mean/max over n runs"
     }
 }
 
@@ -872,6 +891,26 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_parse_timeit_command() {
+        match ReplCommand::parse(".timeit 5 x = x + 1").unwrap() {
+            ReplCommand::Meta(MetaCommand::TimeIt { runs, code }) => {
+                assert_eq!(runs, 5);
+                assert_eq!(code, "x = x + 1");
+            }
+            _ => panic!("Expected TimeIt command"),
+        }
+        match ReplCommand::parse(".timeit 42").unwrap() {
+            ReplCommand::Meta(MetaCommand::TimeIt { runs, code }) => {
+                assert_eq!(runs, 1);
+                assert_eq!(code, "42");
+            }
+            _ => panic!("Expected TimeIt command"),
+        }
+        assert!(ReplCommand::parse(".timeit").is_err());
+        assert!(ReplCommand::parse(".timeit 0 print(1)").is_err());
+    }
+
     #[test]
     fn test_existing_debug_command() {
         let result = ReplCommand::parse("debug: break 10");
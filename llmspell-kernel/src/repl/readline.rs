@@ -47,7 +47,6 @@ impl ScriptCompletionProvider for ScriptExecutorCompletionAdapter {
 /// REPL readline interface with history support
 pub struct ReplReadline {
     editor: Editor<ReplHelper, rustyline::history::FileHistory>,
-    state: Arc<RwLock<ReplState>>,
 }
 
 impl ReplReadline {
@@ -81,7 +80,7 @@ impl ReplReadline {
         }
         drop(repl_state); // Release read lock
 
-        Ok(Self { editor, state })
+        Ok(Self { editor })
     }
 
     /// Read a line with the given prompt
@@ -89,17 +88,13 @@ impl ReplReadline {
     /// # Errors
     ///
     /// Returns an error if the readline operation fails or is interrupted
+    #[allow(clippy::unused_async)]
     pub async fn readline(&mut self, prompt: &str) -> Result<String> {
         match self.editor.readline(prompt) {
             Ok(line) => {
-                // Add to rustyline history
+                // Add to rustyline history; the session records SessionHistory
+                // once the line is parsed, so meta commands can be excluded
                 let _ = self.editor.add_history_entry(&line);
-
-                // Add to SessionHistory
-                let mut repl_state = self.state.write().await;
-                repl_state.history.add(line.clone());
-                drop(repl_state); // Release write lock
-
                 Ok(line)
             }
             Err(ReadlineError::Interrupted) => {
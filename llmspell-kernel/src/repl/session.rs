@@ -8,7 +8,7 @@ use crate::execution::IntegratedKernel;
 use crate::protocols::jupyter::JupyterProtocol;
 use crate::repl::commands::{DebugCommand, MetaCommand, ReplCommand};
 use crate::repl::readline::{ReplReadline, ScriptExecutorCompletionAdapter};
-use crate::repl::state::{Breakpoint, ReplState};
+use crate::repl::state::{Breakpoint, ReplState, SessionHistory, DEFAULT_MAX_HISTORY};
 use anyhow::Result;
 use chrono::{DateTime, Utc};
 use llmspell_core::traits::agent::Agent;
@@ -20,7 +20,7 @@ use std::fmt::Write as _;
 use std::path::PathBuf;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
-use std::time::Instant;
+use std::time::{Duration, Instant};
 use tokio::sync::{mpsc, RwLock};
 use tracing::{debug, error, info, warn};
 
@@ -38,6 +38,22 @@ pub type AgentCreator = Arc<
         + Sync,
 >;
 
+/// History compactor callback run before each chat message is sent to the agent
+///
+/// Takes the current conversation history and returns the history to keep, e.g. with
+/// older turns replaced by a summary
+pub type HistoryCompactor = Arc<
+    dyn Fn(
+            Vec<ConversationTurn>,
+        ) -> std::pin::Pin<
+            Box<
+                dyn std::future::Future<Output = Result<Vec<ConversationTurn>, LLMSpellError>>
+                    + Send,
+            >,
+        > + Send
+        + Sync,
+>;
+
 /// REPL session configuration
 #[derive(Debug, Clone)]
 pub struct ReplSessionConfig {
@@ -45,8 +61,10 @@ pub struct ReplSessionConfig {
     pub enable_performance_monitoring: bool,
     /// Enable debug commands
     pub enable_debug_commands: bool,
-    /// History file path
+    /// History file path; commands are appended as they are entered
     pub history_file: Option<PathBuf>,
+    /// Maximum number of history entries kept in memory and in the history file
+    pub max_history: usize,
     /// Maximum execution time (seconds)
     pub execution_timeout_secs: u64,
     /// Enable session persistence
@@ -59,6 +77,7 @@ impl Default for ReplSessionConfig {
             enable_performance_monitoring: true,
             enable_debug_commands: true,
             history_file: dirs::cache_dir().map(|d| d.join("llmspell_history")),
+            max_history: DEFAULT_MAX_HISTORY,
             execution_timeout_secs: 300,
             enable_persistence: true,
         }
@@ -175,6 +194,35 @@ impl Default for SessionStatistics {
     }
 }
 
+/// Wall-clock timings from a `.timeit` run
+#[derive(Debug, Clone)]
+pub struct TimingReport {
+    /// Result of the last run, unchanged
+    pub result: String,
+    /// Duration of each run
+    pub durations: Vec<Duration>,
+}
+
+impl TimingReport {
+    /// Fastest run
+    pub fn min(&self) -> Duration {
+        self.durations.iter().min().copied().unwrap_or_default()
+    }
+
+    /// Average run duration
+    pub fn mean(&self) -> Duration {
+        let runs = u32::try_from(self.durations.len())
+            .unwrap_or(u32::MAX)
+            .max(1);
+        self.durations.iter().sum::<Duration>() / runs
+    }
+
+    /// Slowest run
+    pub fn max(&self) -> Duration {
+        self.durations.iter().max().copied().unwrap_or_default()
+    }
+}
+
 /// Conversation turn for LLM chat history
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct ConversationTurn {
@@ -241,6 +289,8 @@ pub struct InteractiveSession {
     rag: Option<Arc<dyn std::any::Any + Send + Sync>>,
     /// Agent creator callback (optional - for auto-creating agents)
     agent_creator: Option<AgentCreator>,
+    /// History compactor callback (optional - keeps chat history within the context window)
+    history_compactor: Option<HistoryCompactor>,
 }
 
 impl InteractiveSession {
@@ -254,6 +304,7 @@ impl InteractiveSession {
         config: ReplSessionConfig,
     ) -> Result<Self> {
         let mut state = ReplState::new();
+        state.history = SessionHistory::with_max_entries(config.max_history);
 
         // Load history if configured
         if let Some(ref history_file) = config.history_file {
@@ -265,15 +316,9 @@ impl InteractiveSession {
         // Create shared state
         let state_arc = Arc::new(RwLock::new(state));
 
-        // Create readline interface
+        // Create readline interface, seeded from the loaded history
         let mut readline = match ReplReadline::new(state_arc.clone()).await {
-            Ok(mut rl) => {
-                // Load history file if configured
-                if let Some(ref history_file) = config.history_file {
-                    let _ = rl.load_history(history_file);
-                }
-                Some(rl)
-            }
+            Ok(rl) => Some(rl),
             Err(e) => {
                 warn!(
                     "Failed to initialize readline, falling back to stdin: {}",
@@ -327,6 +372,7 @@ impl InteractiveSession {
             allowed_tools: Arc::new(RwLock::new(Vec::new())),
             rag: None,
             agent_creator: None,
+            history_compactor: None,
         })
     }
 
@@ -383,6 +429,13 @@ impl InteractiveSession {
         self
     }
 
+    /// Set history compactor callback run before each chat message
+    #[must_use]
+    pub fn with_history_compactor(mut self, compactor: HistoryCompactor) -> Self {
+        self.history_compactor = Some(compactor);
+        self
+    }
+
     /// Run the REPL loop
     ///
     /// # Errors
@@ -451,6 +504,7 @@ impl InteractiveSession {
                     break;
                 }
                 Ok(command) => {
+                    self.record_history(&full_input, &command).await;
                     if let Err(e) = self.handle_command(command).await {
                         error!("Command execution error: {e}");
                     }
@@ -461,17 +515,30 @@ impl InteractiveSession {
             }
         }
 
-        // Save history on exit
-        if let Some(ref mut readline) = self.readline {
+        info!("REPL session ended");
+        Ok(())
+    }
+
+    /// Record an entered command in history and append it to the history file
+    ///
+    /// Meta commands (`.help`, `.model`, ...) are not recorded.
+    async fn record_history(&self, input: &str, command: &ReplCommand) {
+        if matches!(
+            command,
+            ReplCommand::Meta(_) | ReplCommand::ChatMeta(_) | ReplCommand::Empty
+        ) {
+            return;
+        }
+
+        let input = input.trim();
+        let added = self.state.write().await.history.add(input.to_string());
+        if added {
             if let Some(ref history_file) = self.config.history_file {
-                if let Err(e) = readline.save_history(history_file) {
-                    debug!("Failed to save history: {}", e);
+                if let Err(e) = SessionHistory::append_to_file(history_file, input) {
+                    debug!("Failed to append history: {}", e);
                 }
             }
         }
-
-        info!("REPL session ended");
-        Ok(())
     }
 
     /// Handle a parsed command
@@ -499,12 +566,6 @@ impl InteractiveSession {
         self.execution_count += 1;
         self.session_stats.commands_executed += 1;
 
-        // Add to history
-        {
-            let mut state = self.state.write().await;
-            state.history.add(code.to_string());
-        }
-
         // Memory tracking - before execution
         let mem_before = get_current_memory_usage();
 
@@ -586,6 +647,25 @@ impl InteractiveSession {
         }
     }
 
+    /// Execute code `runs` times, timing each execution
+    ///
+    /// Runs go through the same kernel path as normal execution.
+    pub async fn time_code(&mut self, code: &str, runs: usize) -> TimingReport {
+        let mut durations = Vec::with_capacity(runs);
+        let mut result = String::new();
+        for _ in 0..runs {
+            self.executing.store(true, Ordering::Relaxed);
+            let start = Instant::now();
+            result = self.execute_via_kernel(code).await;
+            durations.push(start.elapsed());
+            self.executing.store(false, Ordering::Relaxed);
+        }
+        self.execution_count += 1;
+        self.session_stats.commands_executed += 1;
+
+        TimingReport { result, durations }
+    }
+
     /// Handle meta commands
     #[allow(clippy::too_many_lines)]
     async fn handle_meta_command(&mut self, command: MetaCommand) -> Result<()> {
@@ -706,6 +786,20 @@ impl InteractiveSession {
                     if enabled { "enabled" } else { "disabled" }
                 );
             }
+            MetaCommand::TimeIt { runs, code } => {
+                let report = self.time_code(&code, runs).await;
+                println!("{}", report.result);
+                if runs == 1 {
+                    println!("⏱️ {:?}", report.min());
+                } else {
+                    println!(
+                        "⏱️ {runs} runs: min {:?}, mean {:?}, max {:?}",
+                        report.min(),
+                        report.mean(),
+                        report.max()
+                    );
+                }
+            }
             MetaCommand::Exit => unreachable!(), // Handled in run_repl
         }
         Ok(())
@@ -1581,6 +1675,29 @@ impl InteractiveSession {
         context
     }
 
+    /// Get conversation history turns
+    pub async fn get_conversation_history(&self) -> Vec<ConversationTurn> {
+        self.conversation_history.read().await.clone()
+    }
+
+    /// Compact conversation history with the configured compactor, if any
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the compactor fails; the history is left unchanged
+    pub async fn compact_conversation(&self) -> Result<()> {
+        let Some(ref compactor) = self.history_compactor else {
+            return Ok(());
+        };
+
+        let history = self.get_conversation_history().await;
+        let compacted = compactor(history)
+            .await
+            .map_err(|e| anyhow::anyhow!("Failed to compact conversation history: {e}"))?;
+        *self.conversation_history.write().await = compacted;
+        Ok(())
+    }
+
     /// Clear conversation history (keeps session active)
     pub async fn clear_conversation(&self) {
         let mut history = self.conversation_history.write().await;
@@ -1677,6 +1794,9 @@ impl InteractiveSession {
             }
         };
 
+        // Keep conversation history within the context window
+        self.compact_conversation().await?;
+
         // Build prompt with conversation context
         let system_prompt = self.get_system_prompt().await;
         let conversation_context = self.get_conversation_context().await;
@@ -1955,6 +2075,70 @@ mod tests {
         assert_eq!(history[1].token_count, Some(10));
     }
 
+    #[tokio::test]
+    async fn test_history_persists_across_sessions() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = ReplSessionConfig {
+            history_file: Some(dir.path().join("history")),
+            max_history: 3,
+            ..Default::default()
+        };
+
+        let session = InteractiveSession::new(create_test_kernel().await, config.clone())
+            .await
+            .unwrap();
+        for input in [
+            "x = 1",
+            ".help",
+            "x = 1",
+            "for i = 1, 2 do\n  print(i)\nend",
+            ".model gpt-4",
+            "print(x)",
+        ] {
+            let command = ReplCommand::parse(input).unwrap();
+            session.record_history(input, &command).await;
+        }
+        drop(session);
+
+        let restored = InteractiveSession::new(create_test_kernel().await, config.clone())
+            .await
+            .unwrap();
+        assert_eq!(
+            restored.state.read().await.history.entries(),
+            vec!["x = 1", "for i = 1, 2 do\n  print(i)\nend", "print(x)"]
+        );
+
+        // Appending past the cap is trimmed on the next load
+        restored
+            .record_history("y = 2", &ReplCommand::parse("y = 2").unwrap())
+            .await;
+        drop(restored);
+
+        let trimmed = InteractiveSession::new(create_test_kernel().await, config)
+            .await
+            .unwrap();
+        assert_eq!(
+            trimmed.state.read().await.history.entries(),
+            vec!["for i = 1, 2 do\n  print(i)\nend", "print(x)", "y = 2"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_timeit_reports_duration() {
+        let kernel = create_test_kernel().await;
+        let mut session = InteractiveSession::new(kernel, ReplSessionConfig::default())
+            .await
+            .unwrap();
+        let expected = session.execute_via_kernel("return 1 + 1").await;
+
+        let report = session.time_code("return 1 + 1", 5).await;
+        assert_eq!(report.result, expected);
+        assert_eq!(report.durations.len(), 5);
+        assert!(report.min() > Duration::ZERO);
+        assert!(report.min() <= report.mean());
+        assert!(report.mean() <= report.max());
+    }
+
     #[tokio::test]
     async fn test_get_conversation_context() {
         let kernel = create_test_kernel().await;
@@ -2102,6 +2286,38 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_compact_conversation() {
+        let kernel = create_test_kernel().await;
+        let config = ReplSessionConfig::default();
+        let session = InteractiveSession::new(kernel, config).await.unwrap();
+
+        // Without a compactor the history is untouched
+        session.add_to_history("user", "Hello", None).await;
+        session.add_to_history("assistant", "Hi!", None).await;
+        session.compact_conversation().await.unwrap();
+        assert_eq!(session.get_conversation_history().await.len(), 2);
+
+        // Compactor replaces all but the latest turn with a summary
+        let compactor: HistoryCompactor = Arc::new(|mut history: Vec<ConversationTurn>| {
+            Box::pin(async move {
+                let latest = history.split_off(history.len() - 1);
+                let mut summary = latest[0].clone();
+                summary.role = "summary".to_string();
+                summary.content = format!("{} earlier turns", history.len());
+                Ok::<_, LLMSpellError>([vec![summary], latest].concat())
+            })
+        });
+        let session = session.with_history_compactor(compactor);
+        session.compact_conversation().await.unwrap();
+
+        let history = session.get_conversation_history().await;
+        assert_eq!(history.len(), 2);
+        assert_eq!(history[0].role, "summary");
+        assert_eq!(history[0].content, "1 earlier turns");
+        assert_eq!(history[1].content, "Hi!");
+    }
+
     #[tokio::test]
     async fn test_handle_system_command() {
         let kernel = create_test_kernel().await;
@@ -4,10 +4,14 @@
 
 use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, VecDeque};
-use std::path::PathBuf;
+use std::io::Write;
+use std::path::{Path, PathBuf};
 
-/// Maximum history entries to keep in memory
-const MAX_HISTORY_SIZE: usize = 1000;
+/// Default maximum number of history entries to keep
+pub const DEFAULT_MAX_HISTORY: usize = 1000;
+
+/// Header written by rustyline history files, skipped when loading
+const RUSTYLINE_HISTORY_HEADER: &str = "#V2";
 
 /// REPL session state
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -73,37 +77,58 @@ impl Default for ReplState {
 }
 
 /// Session command history
+///
+/// History files hold one command per line, with backslashes and newlines
+/// escaped so multi-line commands survive a round trip.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SessionHistory {
     entries: VecDeque<HistoryEntry>,
     current_index: Option<usize>,
+    #[serde(default = "default_max_entries")]
+    max_entries: usize,
+}
+
+const fn default_max_entries() -> usize {
+    DEFAULT_MAX_HISTORY
 }
 
 impl SessionHistory {
     /// Create new history
     pub fn new() -> Self {
+        Self::with_max_entries(DEFAULT_MAX_HISTORY)
+    }
+
+    /// Create new history keeping at most `max_entries` commands
+    pub fn with_max_entries(max_entries: usize) -> Self {
         Self {
             entries: VecDeque::new(),
             current_index: None,
+            max_entries,
         }
     }
 
     /// Add command to history
-    pub fn add(&mut self, command: String) {
+    ///
+    /// Returns `false` if the command repeats the previous entry and was skipped.
+    pub fn add(&mut self, command: String) -> bool {
+        self.current_index = None;
+
         // Don't add duplicate consecutive commands
-        if self.entries.back().map(|e| &e.command) != Some(&command) {
-            let entry = HistoryEntry {
-                command,
-                timestamp: chrono::Utc::now(),
-            };
-            self.entries.push_back(entry);
-
-            // Limit history size
-            while self.entries.len() > MAX_HISTORY_SIZE {
-                self.entries.pop_front();
-            }
+        if self.entries.back().map(|e| &e.command) == Some(&command) {
+            return false;
         }
-        self.current_index = None;
+
+        let entry = HistoryEntry {
+            command,
+            timestamp: chrono::Utc::now(),
+        };
+        self.entries.push_back(entry);
+
+        // Limit history size
+        while self.entries.len() > self.max_entries {
+            self.entries.pop_front();
+        }
+        true
     }
 
     /// Get previous command
@@ -153,30 +178,81 @@ impl SessionHistory {
     /// # Errors
     ///
     /// Returns an error if the file cannot be written
-    pub fn save_to_file(&self, path: &std::path::Path) -> anyhow::Result<()> {
-        let content = self.entries().join("\n");
+    pub fn save_to_file(&self, path: &Path) -> anyhow::Result<()> {
+        let mut content = String::new();
+        for entry in &self.entries {
+            content.push_str(&escape_history_line(&entry.command));
+            content.push('\n');
+        }
         std::fs::write(path, content)?;
         Ok(())
     }
 
+    /// Append a single command to a history file, creating it if needed
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be opened or written
+    pub fn append_to_file(path: &Path, command: &str) -> anyhow::Result<()> {
+        let mut file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)?;
+        writeln!(file, "{}", escape_history_line(command))?;
+        Ok(())
+    }
+
     /// Load history from file
     ///
+    /// Files that have grown past the history limit through appends are
+    /// rewritten with only the retained entries.
+    ///
     /// # Errors
     ///
     /// Returns an error if the file cannot be read or parsed
-    pub fn load_from_file(&mut self, path: &std::path::Path) -> anyhow::Result<()> {
+    pub fn load_from_file(&mut self, path: &Path) -> anyhow::Result<()> {
         if path.exists() {
             let content = std::fs::read_to_string(path)?;
+            let mut lines = 0;
             for line in content.lines() {
-                if !line.trim().is_empty() {
-                    self.add(line.to_string());
+                if line.trim().is_empty() || line == RUSTYLINE_HISTORY_HEADER {
+                    continue;
                 }
+                lines += 1;
+                self.add(unescape_history_line(line));
+            }
+
+            if lines > self.max_entries {
+                self.save_to_file(path)?;
             }
         }
         Ok(())
     }
 }
 
+/// Escape a command so it occupies a single history file line
+fn escape_history_line(command: &str) -> String {
+    command.replace('\\', "\\\\").replace('\n', "\\n")
+}
+
+/// Reverse [`escape_history_line`]
+fn unescape_history_line(line: &str) -> String {
+    let mut command = String::with_capacity(line.len());
+    let mut chars = line.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            command.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => command.push('\n'),
+            Some(other) => command.push(other),
+            None => command.push('\\'),
+        }
+    }
+    command
+}
+
 impl Default for SessionHistory {
     fn default() -> Self {
         Self::new()
@@ -6,11 +6,13 @@ use llmspell_hooks::{HookPoint, HookRegistry};
 use std::sync::Arc;
 
 pub mod session_metrics;
+pub mod session_report;
 
 pub use session_metrics::{
     SessionAnalytics, SessionAnalyticsConfig, SessionMetricType, SessionMetricsCollector,
     SessionMetricsSummary,
 };
+pub use session_report::SessionAnalyticsReport;
 
 /// Create default session analytics
 ///
@@ -0,0 +1,128 @@
+//! ABOUTME: Per-session analytics report of cost, token usage, invocations and duration
+//! ABOUTME: Aggregated from a session's recorded hook executions and exportable as JSON
+
+use crate::sessions::SessionId;
+use crate::state::manager::SerializedHookExecution;
+use llmspell_hooks::{builtin::cost_tracking::TokenUsage, HookContext, HookPoint};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::time::{Duration, SystemTime};
+
+/// Consolidated usage report for a single session
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SessionAnalyticsReport {
+    /// Session the report covers
+    pub session_id: SessionId,
+    /// Total recorded hook executions
+    pub hook_executions: usize,
+    /// Completed agent executions
+    pub agent_invocations: u64,
+    /// Completed tool executions
+    pub tool_invocations: u64,
+    /// Tool executions by tool name
+    pub tool_breakdown: HashMap<String, u64>,
+    /// Input/prompt tokens across all agent executions
+    pub input_tokens: u64,
+    /// Output/completion tokens across all agent executions
+    pub output_tokens: u64,
+    /// Total tokens across all agent executions
+    pub total_tokens: u64,
+    /// Total cost in USD as recorded by cost tracking
+    pub total_cost_usd: f64,
+    /// Time spanned by the recorded activity
+    pub duration: Duration,
+    /// Timestamp of the first recorded activity
+    pub first_activity: Option<SystemTime>,
+    /// Timestamp of the last recorded activity
+    pub last_activity: Option<SystemTime>,
+}
+
+impl SessionAnalyticsReport {
+    /// Aggregate a report from a session's hook executions
+    ///
+    /// Executions whose context cannot be decoded still count towards
+    /// `hook_executions` and the session duration.
+    pub fn from_executions(session_id: SessionId, executions: &[SerializedHookExecution]) -> Self {
+        let mut report = Self {
+            session_id,
+            hook_executions: executions.len(),
+            agent_invocations: 0,
+            tool_invocations: 0,
+            tool_breakdown: HashMap::new(),
+            input_tokens: 0,
+            output_tokens: 0,
+            total_tokens: 0,
+            total_cost_usd: 0.0,
+            duration: Duration::ZERO,
+            first_activity: None,
+            last_activity: None,
+        };
+
+        for execution in executions {
+            // Executions are timestamped on completion
+            let started = execution
+                .timestamp
+                .checked_sub(execution.duration)
+                .unwrap_or(execution.timestamp);
+            report.first_activity = Some(report.first_activity.map_or(started, |t| t.min(started)));
+            report.last_activity = Some(
+                report
+                    .last_activity
+                    .map_or(execution.timestamp, |t| t.max(execution.timestamp)),
+            );
+
+            let Ok(context) = serde_json::from_slice::<HookContext>(&execution.hook_context) else {
+                continue;
+            };
+            report.record(&context);
+        }
+
+        if let (Some(first), Some(last)) = (report.first_activity, report.last_activity) {
+            report.duration = last.duration_since(first).unwrap_or_default();
+        }
+
+        report
+    }
+
+    /// Export the report as pretty-printed JSON
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if serialization fails
+    pub fn to_json(&self) -> serde_json::Result<String> {
+        serde_json::to_string_pretty(self)
+    }
+
+    fn record(&mut self, context: &HookContext) {
+        match context.point {
+            HookPoint::AfterAgentExecution => {
+                self.agent_invocations += 1;
+
+                if let Some(usage) = context
+                    .data
+                    .get("token_usage")
+                    .and_then(|v| serde_json::from_value::<TokenUsage>(v.clone()).ok())
+                {
+                    self.input_tokens += usage.input_tokens;
+                    self.output_tokens += usage.output_tokens;
+                    self.total_tokens += usage.total_tokens;
+                }
+
+                if let Some(cost) = context
+                    .get_metadata("cost_total")
+                    .and_then(|c| c.parse::<f64>().ok())
+                {
+                    self.total_cost_usd += cost;
+                }
+            }
+            HookPoint::AfterToolExecution => {
+                self.tool_invocations += 1;
+                *self
+                    .tool_breakdown
+                    .entry(context.component_id.name.clone())
+                    .or_insert(0) += 1;
+            }
+            _ => {}
+        }
+    }
+}
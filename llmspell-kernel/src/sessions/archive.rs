@@ -0,0 +1,38 @@
+//! ABOUTME: Portable session archive format used by session export and import
+//! ABOUTME: A header record followed by one record per artifact, streamed as MessagePack
+
+use crate::sessions::{
+    artifact::{ArtifactMetadata, ContentHash},
+    session::SessionSnapshot,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Current archive format version
+pub const ARCHIVE_VERSION: u32 = 1;
+
+/// Leading record of a session archive
+///
+/// The header is followed by one [`ArchivedArtifact`] record per artifact,
+/// in storage order, and a terminating nil record. Records are written and
+/// read one at a time, so neither side holds every artifact in memory.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArchiveHeader {
+    /// Archive format version
+    pub version: u32,
+    /// Session snapshot (metadata, config and in-session state)
+    pub snapshot: SessionSnapshot,
+    /// Values in the session's `StateScope::Session` scope
+    pub scoped_state: HashMap<String, serde_json::Value>,
+}
+
+/// Artifact record in a session archive
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArchivedArtifact {
+    /// Hash of the artifact content
+    pub content_hash: ContentHash,
+    /// Artifact metadata, describing the uncompressed content
+    pub metadata: ArtifactMetadata,
+    /// Uncompressed content, omitted when an earlier record carried the same hash
+    pub content: Option<Vec<u8>>,
+}
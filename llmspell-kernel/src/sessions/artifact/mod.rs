@@ -13,6 +13,7 @@ pub use search::{ArtifactSearch, ArtifactSearchQuery, SearchResult, SortOrder};
 pub use session_artifact::SessionArtifact;
 pub use storage::{
     ArtifactQuery, ArtifactStorage, ArtifactStorageConfig, ArtifactStorageOps, SessionStorageStats,
+    VersionSelector,
 };
 pub use types::{
     ArtifactId, ArtifactMetadata, ArtifactType, ArtifactVersion, ContentHash,
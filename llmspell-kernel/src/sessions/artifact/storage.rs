@@ -1,7 +1,7 @@
 //! ABOUTME: Artifact storage system that provides content-addressed storage with any backend
 //! ABOUTME: Abstracts over `StorageBackend` to support local, S3, cloud storage, etc.
 
-use super::access::{AccessControlConfig, AccessControlManager};
+use super::access::{AccessControlConfig, AccessControlManager, AccessType};
 use super::session_artifact::SessionArtifact;
 use super::types::{ArtifactId, ArtifactMetadata, ArtifactType, ContentHash};
 use super::versioning::VersionManager;
@@ -645,6 +645,87 @@ impl ArtifactStorage {
         Ok(artifacts)
     }
 
+    /// List the metadata of every retained version of a named artifact
+    ///
+    /// Versions are returned oldest first. Content is not loaded.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if version history or metadata cannot be loaded
+    pub async fn list_versions(
+        &self,
+        session_id: &SessionId,
+        name: &str,
+    ) -> Result<Vec<ArtifactMetadata>> {
+        let history = self
+            .version_manager
+            .get_version_history(session_id, name)
+            .await?;
+
+        let mut versions = Vec::new();
+        for version in 1..=history.current_version {
+            if let Some(artifact_id) = history.versions.get(&version) {
+                if let Some(index_entry) = self.load_metadata(artifact_id).await? {
+                    versions.push(index_entry.metadata);
+                }
+            }
+        }
+
+        Ok(versions)
+    }
+
+    /// Find the latest version of a named artifact if it has the given content
+    ///
+    /// Storing unchanged content under the same name reuses this version instead
+    /// of creating a new one.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if version history or metadata cannot be loaded
+    pub async fn find_unchanged_version(
+        &self,
+        session_id: &SessionId,
+        name: &str,
+        content_hash: &ContentHash,
+    ) -> Result<Option<ArtifactId>> {
+        let history = self
+            .version_manager
+            .get_version_history(session_id, name)
+            .await?;
+
+        if history.version_hashes.get(&history.current_version) != Some(content_hash) {
+            return Ok(None);
+        }
+        let Some(artifact_id) = history.versions.get(&history.current_version) else {
+            return Ok(None);
+        };
+
+        // The latest version may have been deleted since it was recorded
+        if self.load_metadata(artifact_id).await?.is_none() {
+            return Ok(None);
+        }
+        Ok(Some(artifact_id.clone()))
+    }
+
+    /// Revert a named artifact to an earlier version
+    ///
+    /// The content of `version` is stored again as a new latest version, so the
+    /// history is preserved and the content is deduplicated. Reverting to content
+    /// that is already the latest leaves the history unchanged.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the version is not found or storing fails
+    pub async fn revert_to_version(
+        &self,
+        session_id: &SessionId,
+        name: &str,
+        version: u32,
+    ) -> Result<ArtifactId> {
+        let artifact = self.get_specific_version(session_id, name, version).await?;
+        self.store_artifact(&artifact).await
+    }
+
     /// Batch retrieve multiple artifacts by their IDs
     ///
     /// # Errors
@@ -784,6 +865,56 @@ impl ArtifactStorage {
         Ok(final_content)
     }
 
+    /// List the IDs of a session's artifacts, oldest first
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the session's artifact list cannot be loaded
+    pub async fn list_session_artifact_ids(
+        &self,
+        session_id: &SessionId,
+    ) -> Result<Vec<ArtifactId>> {
+        let key = self.session_artifacts_key(session_id);
+        match self.storage_backend.get(&key).await {
+            Ok(Some(data)) => bincode::deserialize(&data).map_err(|e| {
+                SessionError::Deserialization(format!("Failed to deserialize artifact list: {e}"))
+            }),
+            Ok(None) => Ok(Vec::new()),
+            Err(e) => Err(SessionError::Storage(format!(
+                "Failed to get artifact list: {e}"
+            ))),
+        }
+    }
+
+    /// Group a session's artifact IDs by artifact name
+    ///
+    /// Each entry holds every stored version of one named artifact. Entries
+    /// are ordered by when their name was first stored.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the session's artifact list or metadata cannot be loaded
+    pub async fn list_session_artifacts_by_name(
+        &self,
+        session_id: &SessionId,
+    ) -> Result<Vec<(String, Vec<ArtifactId>)>> {
+        let mut positions: HashMap<String, usize> = HashMap::new();
+        let mut by_name: Vec<(String, Vec<ArtifactId>)> = Vec::new();
+        for artifact_id in self.list_session_artifact_ids(session_id).await? {
+            let Some(index) = self.load_metadata(&artifact_id).await? else {
+                continue;
+            };
+            let name = index.metadata.name;
+            if let Some(&position) = positions.get(&name) {
+                by_name[position].1.push(index.artifact_id);
+            } else {
+                positions.insert(name.clone(), by_name.len());
+                by_name.push((name, vec![index.artifact_id]));
+            }
+        }
+        Ok(by_name)
+    }
+
     /// Check if adding an artifact would exceed storage limits
     #[allow(dead_code)]
     async fn check_storage_limits(
@@ -898,6 +1029,16 @@ pub trait ArtifactStorageOps: Send + Sync {
     /// Retrieve an artifact
     async fn get_artifact(&self, artifact_id: &ArtifactId) -> Result<Option<SessionArtifact>>;
 
+    /// Retrieve an artifact on behalf of a session
+    ///
+    /// Fails with `AccessDenied` unless the session owns the artifact or has been
+    /// granted read access to it.
+    async fn get_artifact_for_session(
+        &self,
+        requesting_session_id: &SessionId,
+        artifact_id: &ArtifactId,
+    ) -> Result<Option<SessionArtifact>>;
+
     /// Delete an artifact
     async fn delete_artifact(&self, artifact_id: &ArtifactId) -> Result<bool>;
 
@@ -933,12 +1074,37 @@ pub struct ArtifactQuery {
     pub max_size: Option<usize>,
     /// Maximum number of results to return
     pub limit: Option<usize>,
+    /// Filter by artifact version (all versions when unset)
+    pub version: Option<VersionSelector>,
+}
+
+/// Selects which versions of a named artifact a query returns
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum VersionSelector {
+    /// Only the current version of each artifact name
+    Latest,
+    /// Only the given version number
+    Specific(u32),
+    /// Every retained version
+    All,
 }
 
 // We'll implement the actual storage operations in the next task (6.2.4)
 #[async_trait]
 impl ArtifactStorageOps for ArtifactStorage {
     async fn store_artifact(&self, artifact: &SessionArtifact) -> Result<ArtifactId> {
+        // Unchanged content under the same name does not create a new version
+        if let Some(existing) = self
+            .find_unchanged_version(
+                &artifact.id.session_id,
+                &artifact.metadata.name,
+                &artifact.id.content_hash,
+            )
+            .await?
+        {
+            return Ok(existing);
+        }
+
         // Check storage limits first (use original size if compressed)
         let actual_size = artifact
             .metadata
@@ -1080,6 +1246,29 @@ impl ArtifactStorageOps for ArtifactStorage {
         Ok(Some(artifact))
     }
 
+    async fn get_artifact_for_session(
+        &self,
+        requesting_session_id: &SessionId,
+        artifact_id: &ArtifactId,
+    ) -> Result<Option<SessionArtifact>> {
+        // Ownership is part of the ID; anyone else needs an explicit grant
+        let has_permission = self
+            .access_control_manager
+            .check_permission(artifact_id, requesting_session_id, AccessType::Read)
+            .await?;
+        if !has_permission && artifact_id.session_id != *requesting_session_id {
+            return Err(SessionError::AccessDenied {
+                message: format!(
+                    "Session {} does not have read permission for artifact {}",
+                    requesting_session_id,
+                    artifact_id.storage_key()
+                ),
+            });
+        }
+
+        self.get_artifact(artifact_id).await
+    }
+
     #[allow(clippy::manual_let_else)]
     async fn delete_artifact(&self, artifact_id: &ArtifactId) -> Result<bool> {
         // Load metadata to get content hash and session info
@@ -1139,19 +1328,7 @@ impl ArtifactStorageOps for ArtifactStorage {
         &self,
         session_id: &SessionId,
     ) -> Result<Vec<ArtifactMetadata>> {
-        // Get the list of artifact IDs for this session
-        let key = self.session_artifacts_key(session_id);
-        let artifact_ids: Vec<ArtifactId> = match self.storage_backend.get(&key).await {
-            Ok(Some(data)) => bincode::deserialize(&data).map_err(|e| {
-                SessionError::Deserialization(format!("Failed to deserialize artifact list: {e}"))
-            })?,
-            Ok(None) => return Ok(Vec::new()),
-            Err(e) => {
-                return Err(SessionError::Storage(format!(
-                    "Failed to get artifact list: {e}"
-                )))
-            }
-        };
+        let artifact_ids = self.list_session_artifact_ids(session_id).await?;
 
         // Load metadata for each artifact
         let mut metadata_list = Vec::with_capacity(artifact_ids.len());
@@ -1199,9 +1376,34 @@ impl ArtifactStorageOps for ArtifactStorage {
                 }
             };
 
+            // Current version per artifact name, loaded lazily for the Latest selector
+            let mut current_versions: HashMap<String, u32> = HashMap::new();
+
             // Add each artifact's metadata to the global index
             for artifact_id in artifact_ids {
-                if let Some(index_entry) = self.load_metadata(&artifact_id).await? {
+                let Some(index_entry) = self.load_metadata(&artifact_id).await? else {
+                    continue;
+                };
+                let version = index_entry.metadata.version.version;
+                let selected = match query.version {
+                    None | Some(VersionSelector::All) => true,
+                    Some(VersionSelector::Specific(wanted)) => version == wanted,
+                    Some(VersionSelector::Latest) => {
+                        let name = &index_entry.metadata.name;
+                        let current = if let Some(current) = current_versions.get(name) {
+                            *current
+                        } else {
+                            let history = self
+                                .version_manager
+                                .get_version_history(&session_id, name)
+                                .await?;
+                            current_versions.insert(name.clone(), history.current_version);
+                            history.current_version
+                        };
+                        version == current
+                    }
+                };
+                if selected {
                     global_index.add_metadata(artifact_id, index_entry.metadata);
                 }
             }
@@ -1515,6 +1717,82 @@ mod tests {
         assert_eq!(all_versions.len(), 2);
     }
     #[tokio::test]
+    async fn test_version_history_query_and_revert() {
+        let backend = Arc::new(MemoryBackend::new());
+        let storage = Arc::new(ArtifactStorage::with_backend(backend));
+
+        let session_id = SessionId::new();
+        let artifact_name = "report.md";
+        let contents: Vec<Vec<u8>> = (1..=3)
+            .map(|v| format!("Report draft {v}").into_bytes())
+            .collect();
+
+        for (sequence, content) in (1u64..).zip(&contents) {
+            let artifact = SessionArtifact::new(
+                session_id,
+                sequence,
+                ArtifactType::UserInput,
+                artifact_name.to_string(),
+                content.clone(),
+            )
+            .unwrap();
+            storage.store_artifact(&artifact).await.unwrap();
+        }
+
+        // Every version is retained
+        for (version, content) in (1u32..).zip(&contents) {
+            let artifact = storage
+                .get_specific_version(&session_id, artifact_name, version)
+                .await
+                .unwrap();
+            assert_eq!(artifact.metadata.version.version, version);
+            assert_eq!(&artifact.get_content().unwrap(), content);
+        }
+
+        let query = |version| ArtifactQuery {
+            session_id: Some(session_id),
+            version: Some(version),
+            ..Default::default()
+        };
+        let latest = storage
+            .query_artifacts(query(VersionSelector::Latest))
+            .await
+            .unwrap();
+        assert_eq!(latest.len(), 1);
+        assert_eq!(latest[0].version.version, 3);
+
+        let second = storage
+            .query_artifacts(query(VersionSelector::Specific(2)))
+            .await
+            .unwrap();
+        assert_eq!(second.len(), 1);
+        assert_eq!(second[0].version.version, 2);
+
+        let all = storage
+            .query_artifacts(query(VersionSelector::All))
+            .await
+            .unwrap();
+        assert_eq!(all.len(), 3);
+
+        // Reverting re-stores version 1 as version 4, deduplicating its content
+        let reverted_id = storage
+            .revert_to_version(&session_id, artifact_name, 1)
+            .await
+            .unwrap();
+        assert_eq!(reverted_id.sequence, 4);
+
+        let latest = storage
+            .get_latest_version(&session_id, artifact_name)
+            .await
+            .unwrap();
+        assert_eq!(latest.metadata.version.version, 4);
+        assert_eq!(latest.get_content().unwrap(), contents[0]);
+
+        let stats = storage.get_storage_stats(&session_id).await.unwrap();
+        assert_eq!(stats.artifact_count, 4);
+        assert_eq!(stats.deduplicated_count, 1);
+    }
+    #[tokio::test]
     async fn test_batch_retrieval() {
         let backend = Arc::new(MemoryBackend::new());
         let storage = Arc::new(ArtifactStorage::with_backend(backend));
@@ -1677,6 +1955,51 @@ mod tests {
         assert!(result.is_err());
     }
     #[tokio::test]
+    async fn test_cross_session_retrieval_requires_grant() {
+        use crate::sessions::artifact::access::Permission;
+
+        let backend = Arc::new(MemoryBackend::new());
+        let storage = Arc::new(ArtifactStorage::with_backend(backend));
+
+        let owner = SessionId::new();
+        let other = SessionId::new();
+        let artifact = SessionArtifact::new(
+            owner,
+            1,
+            ArtifactType::UserInput,
+            "secret.txt".to_string(),
+            b"owner only".to_vec(),
+        )
+        .unwrap();
+        let artifact_id = storage.store_artifact(&artifact).await.unwrap();
+
+        // The owner can read it
+        assert!(storage
+            .get_artifact_for_session(&owner, &artifact_id)
+            .await
+            .unwrap()
+            .is_some());
+
+        // Another session cannot, even with the exact ID
+        assert!(matches!(
+            storage.get_artifact_for_session(&other, &artifact_id).await,
+            Err(SessionError::AccessDenied { .. })
+        ));
+
+        // Until the owner explicitly grants access
+        storage
+            .access_control_manager()
+            .grant_permission(&artifact_id, other, Permission::Read, owner)
+            .await
+            .unwrap();
+        let shared = storage
+            .get_artifact_for_session(&other, &artifact_id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(shared.get_content().unwrap(), b"owner only".to_vec());
+    }
+    #[tokio::test]
     async fn test_list_session_artifacts() {
         let backend = Arc::new(MemoryBackend::new());
         let storage = Arc::new(ArtifactStorage::with_backend(backend));
@@ -1,6 +1,7 @@
 //! ABOUTME: Configuration types for SessionManager providing operational parameters and limits
 //! ABOUTME: Defines all configurable aspects of session management behavior
 
+use crate::sessions::policies::RetentionConfig;
 use chrono::Duration;
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
@@ -35,6 +36,8 @@ pub struct SessionManagerConfig {
     pub hook_config: HookExecutionConfig,
     /// Event publishing configuration
     pub event_config: EventConfig,
+    /// Artifact retention configuration
+    pub retention_config: RetentionConfig,
 }
 
 impl SessionManagerConfig {
@@ -75,6 +78,7 @@ impl Default for SessionManagerConfig {
             cleanup_config: CleanupConfig::default(),
             hook_config: HookExecutionConfig::default(),
             event_config: EventConfig::default(),
+            retention_config: RetentionConfig::default(),
         }
     }
 }
@@ -257,6 +261,12 @@ impl SessionManagerConfigBuilder {
         self
     }
 
+    /// Set artifact retention configuration
+    pub fn retention_config(mut self, config: RetentionConfig) -> Self {
+        self.config.retention_config = config;
+        self
+    }
+
     /// Build the configuration
     pub fn build(self) -> SessionManagerConfig {
         self.config
@@ -2,6 +2,8 @@
 //! ABOUTME: Coordinates with state persistence, storage backends, hooks, and events
 
 use crate::sessions::{
+    analytics::SessionAnalyticsReport,
+    archive::{ArchiveHeader, ArchivedArtifact, ARCHIVE_VERSION},
     artifact::{
         access::AccessType, ArtifactId, ArtifactMetadata, ArtifactQuery, ArtifactStorage,
         ArtifactStorageOps, ArtifactType, SessionArtifact,
@@ -12,6 +14,7 @@ use crate::sessions::{
         register_artifact_collectors, ArtifactCollectionProcessor, CollectorConfig,
         SessionHookContextHelper,
     },
+    policies::SessionRetentionPolicy,
     replay::ReplayEngine,
     security::SessionSecurityManager,
     session::{Session, SessionSnapshot},
@@ -22,7 +25,7 @@ use crate::state::{StateManager, StateScope};
 use chrono::{DateTime, Utc};
 use llmspell_events::{bus::EventBus, correlation::EventCorrelationTracker};
 use llmspell_hooks::{HookExecutor, HookPoint, HookRegistry, LoggingHook, MetricsHook};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::str::FromStr;
 use std::sync::Arc;
 use tokio::sync::RwLock;
@@ -57,6 +60,8 @@ pub struct SessionManager {
     /// Artifact collection processor
     #[allow(dead_code)]
     artifact_collector: Option<Arc<ArtifactCollectionProcessor>>,
+    /// Artifact retention policy
+    retention_policy: SessionRetentionPolicy,
     /// Manager configuration
     config: SessionManagerConfig,
     /// Session security and isolation manager
@@ -173,6 +178,7 @@ impl SessionManager {
             replay_engine,
             artifact_collector,
             security_manager: Arc::new(RwLock::new(SessionSecurityManager::new(true))), // Strict isolation by default
+            retention_policy: SessionRetentionPolicy::new(config.retention_config.clone()),
             config,
             shutdown: Arc::new(RwLock::new(false)),
         };
@@ -195,6 +201,17 @@ impl SessionManager {
     ///
     /// Returns an error if the maximum number of active sessions is reached
     pub async fn create_session(&self, options: CreateSessionOptions) -> Result<SessionId> {
+        // A session that may hold no artifacts can never store one
+        if options
+            .config
+            .as_ref()
+            .is_some_and(|config| config.max_artifacts == Some(0))
+        {
+            return Err(SessionError::Configuration(
+                "max_artifacts must be greater than 0".to_string(),
+            ));
+        }
+
         // Check session limits
         let active_count = self.active_sessions.read().await.len();
         if active_count >= self.config.max_active_sessions {
@@ -365,7 +382,7 @@ impl SessionManager {
         })
     }
 
-    /// List sessions matching query
+    /// List active sessions matching query
     ///
     /// # Errors
     ///
@@ -376,62 +393,64 @@ impl SessionManager {
 
         for session in sessions.values() {
             let metadata = session.metadata.read().await;
-
-            // Apply filters
-            if let Some(status) = query.status {
-                if metadata.status != status {
-                    continue;
-                }
-            }
-
-            if let Some(ref created_by) = query.created_by {
-                if metadata.created_by.as_ref() != Some(created_by) {
-                    continue;
-                }
+            if query.matches(&metadata) {
+                results.push(metadata.clone());
             }
+        }
 
-            if !query.tags.is_empty() {
-                let has_all_tags = query.tags.iter().all(|tag| metadata.tags.contains(tag));
-                if !has_all_tags {
-                    continue;
-                }
-            }
+        Ok(Self::sort_and_paginate(results, &query))
+    }
 
-            if let Some(ref parent_id) = query.parent_session_id {
-                if metadata.parent_session_id.as_ref() != Some(parent_id) {
-                    continue;
-                }
-            }
+    /// Query active and persisted sessions
+    ///
+    /// All filters in `query` are combined with AND semantics, including
+    /// equality on each `metadata` key. Persisted sessions are read without
+    /// being activated; active sessions take precedence over their stored copy.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if persisted sessions cannot be listed
+    pub async fn query_sessions(&self, query: SessionQuery) -> Result<Vec<SessionMetadata>> {
+        let mut results = Vec::new();
+        let mut seen = std::collections::HashSet::new();
 
-            if let Some(created_after) = query.created_after {
-                if metadata.created_at < created_after {
-                    continue;
-                }
+        for (session_id, session) in self.active_sessions.read().await.iter() {
+            seen.insert(*session_id);
+            let metadata = session.metadata.read().await;
+            if query.matches(&metadata) {
+                results.push(metadata.clone());
             }
+        }
 
-            if let Some(created_before) = query.created_before {
-                if metadata.created_at > created_before {
-                    continue;
-                }
+        let keys = self
+            .storage_backend
+            .list_keys("session:")
+            .await
+            .map_err(|e| SessionError::Storage(e.to_string()))?;
+        for key in keys {
+            let Some(Ok(session_id)) = key.strip_prefix("session:").map(SessionId::from_str) else {
+                continue;
+            };
+            if seen.contains(&session_id) {
+                continue;
             }
-
-            if let Some(ref search_text) = query.search_text {
-                let name_match = metadata
-                    .name
-                    .as_ref()
-                    .is_some_and(|n| n.contains(search_text));
-                let desc_match = metadata
-                    .description
-                    .as_ref()
-                    .is_some_and(|d| d.contains(search_text));
-                if !name_match && !desc_match {
-                    continue;
+            match self.read_snapshot(&session_id).await {
+                Ok(snapshot) if query.matches(&snapshot.metadata) => {
+                    results.push(snapshot.metadata);
                 }
+                Ok(_) => {}
+                Err(e) => warn!("Skipping unreadable session {session_id}: {e}"),
             }
-
-            results.push(metadata.clone());
         }
 
+        Ok(Self::sort_and_paginate(results, &query))
+    }
+
+    /// Apply the query's sort order, offset and limit
+    fn sort_and_paginate(
+        mut results: Vec<SessionMetadata>,
+        query: &SessionQuery,
+    ) -> Vec<SessionMetadata> {
         // Sort results
         results.sort_by(|a, b| match query.sort_by {
             SessionSortBy::CreatedAt => {
@@ -482,7 +501,7 @@ impl SessionManager {
             results.truncate(limit);
         }
 
-        Ok(results)
+        results
     }
 
     /// Suspend a session
@@ -781,6 +800,24 @@ impl SessionManager {
     ///
     /// Returns an error if the session is not found or deserialization fails
     pub async fn load_session(&self, session_id: &SessionId) -> Result<Session> {
+        let snapshot = self.read_snapshot(session_id).await?;
+
+        // Restore session
+        let session = Session::from_snapshot(snapshot);
+
+        // Add to active sessions if not already there
+        self.active_sessions
+            .write()
+            .await
+            .entry(*session_id)
+            .or_insert(session.clone());
+
+        debug!("Loaded session {session_id} from storage");
+        Ok(session)
+    }
+
+    /// Read and decode a session snapshot from storage
+    async fn read_snapshot(&self, session_id: &SessionId) -> Result<SessionSnapshot> {
         let key = format!("session:{session_id}");
 
         // Get from storage
@@ -813,18 +850,7 @@ impl SessionManager {
             )));
         }
 
-        // Restore session
-        let session = Session::from_snapshot(snapshot);
-
-        // Add to active sessions if not already there
-        self.active_sessions
-            .write()
-            .await
-            .entry(*session_id)
-            .or_insert(session.clone());
-
-        debug!("Loaded session {session_id} from storage");
-        Ok(session)
+        Ok(snapshot)
     }
 
     /// Delete a session
@@ -859,6 +885,246 @@ impl SessionManager {
         Ok(())
     }
 
+    /// Export a session as a portable archive
+    ///
+    /// The archive holds the session snapshot, its `StateScope::Session` state,
+    /// and every stored artifact. Artifacts are read and written one at a time,
+    /// and content is written once per content hash. Use
+    /// [`Self::import_session`] to restore it on another manager.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the session is not found, an artifact cannot be
+    /// read, or writing the archive fails
+    pub async fn export_session(
+        &self,
+        session_id: &SessionId,
+        writer: impl std::io::Write,
+    ) -> Result<()> {
+        let session = self.get_session(session_id).await?;
+        let snapshot = session.snapshot().await;
+
+        let scoped_state = self
+            .state_manager
+            .get_all_in_scope(StateScope::Session(session_id.to_string()))
+            .await
+            .map_err(SessionError::State)?;
+
+        let mut serializer = rmp_serde::Serializer::new(writer).with_struct_map();
+        let header = ArchiveHeader {
+            version: ARCHIVE_VERSION,
+            snapshot,
+            scoped_state,
+        };
+        serde::Serialize::serialize(&header, &mut serializer)
+            .map_err(|e| SessionError::Serialization(e.to_string()))?;
+
+        let mut written = HashSet::new();
+        let mut artifact_count = 0;
+        for artifact_id in self
+            .artifact_storage
+            .list_session_artifact_ids(session_id)
+            .await?
+        {
+            let Some(artifact) = self.artifact_storage.get_artifact(&artifact_id).await? else {
+                warn!("Skipping missing artifact {artifact_id} during export");
+                continue;
+            };
+            let content = artifact.get_content()?;
+
+            let mut metadata = artifact.metadata;
+            metadata.size = content.len();
+            metadata.is_compressed = false;
+            metadata.original_size = None;
+
+            let record = ArchivedArtifact {
+                content: written
+                    .insert(artifact_id.content_hash.clone())
+                    .then_some(content),
+                content_hash: artifact_id.content_hash,
+                metadata,
+            };
+            serde::Serialize::serialize(&Some(record), &mut serializer)
+                .map_err(|e| SessionError::Serialization(e.to_string()))?;
+            artifact_count += 1;
+        }
+        serde::Serialize::serialize(&None::<ArchivedArtifact>, &mut serializer)
+            .map_err(|e| SessionError::Serialization(e.to_string()))?;
+
+        info!(
+            "Exported session {session_id} ({artifact_count} artifacts, {} blobs)",
+            written.len()
+        );
+        Ok(())
+    }
+
+    /// Import a session from an archive written by [`Self::export_session`]
+    ///
+    /// The session keeps its original ID unless a session with that ID already
+    /// exists, in which case a new ID is assigned. Artifacts are read one at a
+    /// time and stored through [`Self::store_artifact`]'s path, so the session's
+    /// artifact limits apply; content already present in artifact storage is
+    /// deduplicated rather than written again. A failed import removes the
+    /// partially imported session.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the archive cannot be read, is from a newer format
+    /// version, has content that does not match its hash, an artifact exceeds
+    /// the session's limits, or the active session limit is reached
+    pub async fn import_session(&self, reader: impl std::io::Read) -> Result<SessionId> {
+        let mut deserializer = rmp_serde::Deserializer::new(reader);
+        let header: ArchiveHeader = serde::Deserialize::deserialize(&mut deserializer)
+            .map_err(|e| SessionError::Deserialization(e.to_string()))?;
+
+        if header.version > ARCHIVE_VERSION {
+            return Err(SessionError::Configuration(format!(
+                "Session archive version {} is newer than supported version {ARCHIVE_VERSION}",
+                header.version
+            )));
+        }
+
+        let active_count = self.active_sessions.read().await.len();
+        if active_count >= self.config.max_active_sessions {
+            return Err(SessionError::ResourceLimitExceeded {
+                resource: "active_sessions".to_string(),
+                message: format!(
+                    "Maximum active sessions ({}) reached",
+                    self.config.max_active_sessions
+                ),
+            });
+        }
+
+        let ArchiveHeader {
+            mut snapshot,
+            scoped_state,
+            ..
+        } = header;
+
+        let original_id = snapshot.metadata.id;
+        let session_id = if self.session_exists(&original_id).await? {
+            let reassigned = SessionId::new();
+            info!("Session {original_id} already exists, importing as {reassigned}");
+            reassigned
+        } else {
+            original_id
+        };
+        snapshot.metadata.id = session_id;
+        // Counted again as each artifact is stored
+        snapshot.metadata.artifact_count = 0;
+
+        let session = Session::from_snapshot(snapshot);
+        self.active_sessions
+            .write()
+            .await
+            .insert(session_id, session.clone());
+        self.security_manager
+            .write()
+            .await
+            .register_session(&session_id);
+
+        let mut imported = Vec::new();
+        let result = self
+            .import_archived_state(&session_id, scoped_state, &mut deserializer, &mut imported)
+            .await;
+        if let Err(e) = result {
+            for artifact_id in &imported {
+                if let Err(cleanup) = self.delete_artifact(&session_id, artifact_id).await {
+                    warn!("Failed to remove artifact {artifact_id} after failed import: {cleanup}");
+                }
+            }
+            if let Err(cleanup) = self.delete_session(&session_id).await {
+                warn!("Failed to remove session {session_id} after failed import: {cleanup}");
+            }
+            return Err(e);
+        }
+
+        if self.config.auto_persist {
+            self.save_session(&session).await?;
+        }
+
+        info!(
+            "Imported session {session_id} ({} artifacts)",
+            imported.len()
+        );
+        Ok(session_id)
+    }
+
+    /// Restore scoped state and stream artifact records into an imported session
+    ///
+    /// IDs of stored artifacts are pushed to `imported` as they are stored, so
+    /// the caller can roll back a partial import.
+    async fn import_archived_state<R: std::io::Read>(
+        &self,
+        session_id: &SessionId,
+        scoped_state: HashMap<String, serde_json::Value>,
+        deserializer: &mut rmp_serde::Deserializer<rmp_serde::decode::ReadReader<R>>,
+        imported: &mut Vec<ArtifactId>,
+    ) -> Result<()> {
+        let scope = StateScope::Session(session_id.to_string());
+        for (key, value) in scoped_state {
+            self.state_manager
+                .set(scope.clone(), &key, value)
+                .await
+                .map_err(SessionError::State)?;
+        }
+
+        // Content already imported, for records that reference an earlier blob
+        let mut stored_content = HashMap::new();
+        while let Some(archived) =
+            <Option<ArchivedArtifact> as serde::Deserialize>::deserialize(&mut *deserializer)
+                .map_err(|e| SessionError::Deserialization(e.to_string()))?
+        {
+            let ArchivedArtifact {
+                content_hash,
+                metadata,
+                content,
+            } = archived;
+            let content = match content {
+                Some(content) => {
+                    if SessionArtifact::calculate_hash(&content) != content_hash {
+                        return Err(SessionError::IntegrityError {
+                            message: format!(
+                                "Content hash mismatch for artifact '{}'",
+                                metadata.name
+                            ),
+                        });
+                    }
+                    content
+                }
+                None => {
+                    let earlier = stored_content.get(&content_hash).ok_or_else(|| {
+                        SessionError::IntegrityError {
+                            message: format!(
+                                "Archive is missing content {content_hash} for artifact '{}'",
+                                metadata.name
+                            ),
+                        }
+                    })?;
+                    self.get_artifact_content(session_id, earlier).await?
+                }
+            };
+
+            let artifact_id = self
+                .store_artifact_with_metadata(session_id, metadata, content)
+                .await?;
+            stored_content.insert(content_hash, artifact_id.clone());
+            imported.push(artifact_id);
+        }
+        Ok(())
+    }
+
+    /// Whether a session is active or persisted under this ID
+    async fn session_exists(&self, session_id: &SessionId) -> Result<bool> {
+        if self.active_sessions.read().await.contains_key(session_id) {
+            return Ok(true);
+        }
+        self.storage_backend
+            .exists(&format!("session:{session_id}"))
+            .await
+            .map_err(|e| SessionError::Storage(e.to_string()))
+    }
+
     /// Start auto-persist background task
     fn start_auto_persist_task(&self) {
         let manager = self.clone();
@@ -1117,27 +1383,7 @@ impl SessionManager {
         content: Vec<u8>,
         metadata: Option<HashMap<String, serde_json::Value>>,
     ) -> Result<ArtifactId> {
-        // Verify session exists and is active
-        let session = self.get_session(session_id).await?;
-        let status = session.status().await;
-        if !status.is_active() {
-            return Err(SessionError::InvalidOperation {
-                reason: format!("Cannot store artifacts in {status:?} session"),
-            });
-        }
-
-        // Get next sequence number for this session
-        let sequence = session.increment_operation_count().await?;
-
-        // Create the artifact with metadata
-        let mut artifact = SessionArtifact::create_with_metadata(
-            *session_id,
-            sequence,
-            artifact_type.clone(),
-            name.clone(),
-            content,
-            None, // created_by will be set by session metadata if available
-        )?;
+        let mut artifact_metadata = ArtifactMetadata::new(name, artifact_type, content.len());
 
         // Add custom metadata if provided
         if let Some(custom_metadata) = metadata {
@@ -1146,13 +1392,13 @@ impl SessionManager {
                     // Special handling for mime_type - set it on the artifact metadata directly
                     "mime_type" => {
                         if let Some(mime_type_str) = value.as_str() {
-                            artifact.metadata.mime_type = mime_type_str.to_string();
+                            artifact_metadata.mime_type = mime_type_str.to_string();
                         }
                     }
                     // Special handling for tags - set them on the artifact metadata directly
                     "tags" => {
                         if let Some(tags_array) = value.as_array() {
-                            artifact.metadata.tags = tags_array
+                            artifact_metadata.tags = tags_array
                                 .iter()
                                 .filter_map(|v| v.as_str().map(String::from))
                                 .collect();
@@ -1160,18 +1406,97 @@ impl SessionManager {
                     }
                     // Everything else goes to custom metadata
                     _ => {
-                        artifact.metadata.custom.insert(key, value);
+                        artifact_metadata.custom.insert(key, value);
                     }
                 }
             }
         }
 
+        self.store_artifact_with_metadata(session_id, artifact_metadata, content)
+            .await
+    }
+
+    /// Store an artifact with prepared metadata, applying the session's artifact limits
+    ///
+    /// The artifact's ID, size and version are assigned here; the rest of the
+    /// metadata is stored as given.
+    async fn store_artifact_with_metadata(
+        &self,
+        session_id: &SessionId,
+        mut metadata: ArtifactMetadata,
+        content: Vec<u8>,
+    ) -> Result<ArtifactId> {
+        let name = metadata.name.clone();
+        let artifact_type = metadata.artifact_type.clone();
+
+        // Verify session exists and is active
+        let session = self.get_session(session_id).await?;
+        let status = session.status().await;
+        if !status.is_active() {
+            return Err(SessionError::InvalidOperation {
+                reason: format!("Cannot store artifacts in {status:?} session"),
+            });
+        }
+
+        // Re-storing the latest content of a named artifact keeps its version
+        let content_hash = SessionArtifact::calculate_hash(&content);
+        if let Some(existing) = self
+            .artifact_storage
+            .find_unchanged_version(session_id, &name, &content_hash)
+            .await?
+        {
+            debug!("Artifact '{name}' unchanged, keeping {existing}");
+            return Ok(existing);
+        }
+
+        // Refuse stores into a full session when retention rejects them
+        self.retention_policy
+            .check_capacity(
+                &self.artifact_storage,
+                session_id,
+                session.config.max_artifacts,
+                &name,
+            )
+            .await?;
+
+        // Get next sequence number for this session
+        let sequence = session.increment_operation_count().await?;
+
+        // Create the artifact with metadata
+        metadata.size = content.len();
+        let artifact = SessionArtifact::from_parts(
+            ArtifactId::new(content_hash, *session_id, sequence),
+            metadata,
+            content,
+            Utc::now(),
+        )?;
+
         // Store the artifact
         let artifact_id = self.artifact_storage.store_artifact(&artifact).await?;
+        self.retention_policy.record_access(&artifact_id).await;
 
         // Update session metadata
         session.increment_artifact_count().await?;
 
+        // Evict down to the retention limit only once the new artifact is stored
+        match self
+            .retention_policy
+            .evict_excess(
+                &self.artifact_storage,
+                session_id,
+                session.config.max_artifacts,
+                &name,
+            )
+            .await
+        {
+            Ok(evicted) => {
+                for _ in &evicted {
+                    session.decrement_artifact_count().await?;
+                }
+            }
+            Err(e) => warn!("Failed to evict artifacts from session {session_id}: {e}"),
+        }
+
         // Fire artifact creation hooks if enabled
         if self.config.hook_config.enable_artifact_collection {
             let hooks = self.hook_registry.get_hooks(&HookPoint::AfterToolExecution);
@@ -1248,30 +1573,63 @@ impl SessionManager {
         session_id: &SessionId,
         artifact_id: &ArtifactId,
     ) -> Result<SessionArtifact> {
-        // Check access permission
-        let has_permission = self
+        // Retrieve the artifact, enforcing session isolation
+        let artifact = self
             .artifact_storage
-            .access_control_manager()
-            .check_permission(artifact_id, session_id, AccessType::Read)
-            .await?;
-
-        if !has_permission {
-            return Err(SessionError::AccessDenied {
-                message: format!(
-                    "Session {} does not have read permission for artifact {}",
-                    session_id,
-                    artifact_id.storage_key()
-                ),
-            });
-        }
-
-        // Retrieve the artifact
-        self.artifact_storage
-            .get_artifact(artifact_id)
+            .get_artifact_for_session(session_id, artifact_id)
             .await?
             .ok_or_else(|| SessionError::ArtifactNotFound {
                 id: artifact_id.to_string(),
-            })
+            })?;
+        self.retention_policy.record_access(artifact_id).await;
+
+        Ok(artifact)
+    }
+
+    /// Retrieve a named artifact by version
+    ///
+    /// With `version` unset, the latest version is returned.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the session or artifact version is not found
+    pub async fn get_artifact_version(
+        &self,
+        session_id: &SessionId,
+        name: &str,
+        version: Option<u32>,
+    ) -> Result<SessionArtifact> {
+        self.get_session(session_id).await?;
+
+        let artifact = match version {
+            Some(version) => {
+                self.artifact_storage
+                    .get_specific_version(session_id, name, version)
+                    .await?
+            }
+            None => {
+                self.artifact_storage
+                    .get_latest_version(session_id, name)
+                    .await?
+            }
+        };
+        self.retention_policy.record_access(&artifact.id).await;
+
+        Ok(artifact)
+    }
+
+    /// List the metadata of every version of a named artifact, oldest first
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the session is not found or version history cannot be loaded
+    pub async fn list_artifact_versions(
+        &self,
+        session_id: &SessionId,
+        name: &str,
+    ) -> Result<Vec<ArtifactMetadata>> {
+        self.get_session(session_id).await?;
+        self.artifact_storage.list_versions(session_id, name).await
     }
 
     /// Retrieve artifact content only (without metadata)
@@ -1351,6 +1709,8 @@ impl SessionManager {
             });
         }
 
+        self.retention_policy.forget(artifact_id).await;
+
         // Update session metadata
         session.decrement_artifact_count().await?;
 
@@ -1606,6 +1966,22 @@ impl SessionManager {
         self.replay_engine.get_session_timeline(session_id).await
     }
 
+    /// Aggregate cost, token usage, invocations and duration for a session
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the session timeline cannot be retrieved.
+    pub async fn session_analytics(
+        &self,
+        session_id: &SessionId,
+    ) -> Result<SessionAnalyticsReport> {
+        let executions = self.get_session_timeline(session_id).await?;
+        Ok(SessionAnalyticsReport::from_executions(
+            *session_id,
+            &executions,
+        ))
+    }
+
     /// Get replay status for a session
     pub fn get_replay_status(
         &self,
@@ -1973,4 +2349,532 @@ mod tests {
         let artifacts = manager.query_artifacts(query).await.unwrap();
         assert_eq!(artifacts.len(), 3);
     }
+    #[tokio::test]
+    async fn test_session_analytics_report() {
+        use crate::state::backend_adapter::StateStorageAdapter;
+        use crate::state::manager::SerializedHookExecution;
+        use llmspell_hooks::{ComponentId, ComponentType, HookContext};
+        use llmspell_storage::StorageBackend;
+
+        let storage_backend = Arc::new(MemoryBackend::new());
+        let manager = SessionManager::new(
+            Arc::new(StateManager::new(None).await.unwrap()),
+            storage_backend.clone(),
+            Arc::new(HookRegistry::new()),
+            Arc::new(HookExecutor::new()),
+            &Arc::new(EventBus::new()),
+            SessionManagerConfig::default(),
+        )
+        .unwrap();
+
+        let session_id = manager
+            .create_session(CreateSessionOptions::default())
+            .await
+            .unwrap();
+        let session = manager.get_session(&session_id).await.unwrap();
+        manager.save_session(&session).await.unwrap();
+
+        let correlation_bytes = storage_backend
+            .get(&format!("session_correlation:{session_id}"))
+            .await
+            .unwrap()
+            .unwrap();
+        let correlation: serde_json::Value = serde_json::from_slice(&correlation_bytes).unwrap();
+        let correlation_id =
+            uuid::Uuid::parse_str(correlation["correlation_id"].as_str().unwrap()).unwrap();
+
+        // Record two agent runs and three tool calls, one second apart
+        let adapter = StateStorageAdapter::new(storage_backend.clone(), "sessions".to_string());
+        let start = std::time::SystemTime::now();
+        let activity = [
+            (
+                HookPoint::AfterAgentExecution,
+                ComponentType::Agent,
+                "assistant",
+            ),
+            (
+                HookPoint::AfterToolExecution,
+                ComponentType::Tool,
+                "calculator",
+            ),
+            (
+                HookPoint::AfterToolExecution,
+                ComponentType::Tool,
+                "web_search",
+            ),
+            (
+                HookPoint::AfterToolExecution,
+                ComponentType::Tool,
+                "calculator",
+            ),
+            (
+                HookPoint::AfterAgentExecution,
+                ComponentType::Agent,
+                "assistant",
+            ),
+        ];
+        for (i, (point, component_type, name)) in (0u64..).zip(activity) {
+            let mut context = HookContext::new(
+                point.clone(),
+                ComponentId::new(component_type, name.to_string()),
+            );
+            if point == HookPoint::AfterAgentExecution {
+                context.insert_data(
+                    "token_usage".to_string(),
+                    serde_json::json!({
+                        "input_tokens": 100,
+                        "output_tokens": 50,
+                        "total_tokens": 150,
+                        "model": "gpt-4",
+                        "provider": "openai",
+                    }),
+                );
+                context.insert_metadata("cost_total".to_string(), "0.250000".to_string());
+            }
+            let execution = SerializedHookExecution {
+                hook_id: format!("{name}_hook"),
+                execution_id: uuid::Uuid::new_v4(),
+                correlation_id,
+                hook_context: serde_json::to_vec(&context).unwrap(),
+                result: "\"Continue\"".to_string(),
+                timestamp: start + std::time::Duration::from_secs(i),
+                duration: std::time::Duration::from_millis(10),
+                metadata: HashMap::new(),
+            };
+            adapter
+                .store(
+                    &format!("hook_history:{correlation_id}:{}", execution.execution_id),
+                    &execution,
+                )
+                .await
+                .unwrap();
+        }
+
+        let report = manager.session_analytics(&session_id).await.unwrap();
+        assert_eq!(report.session_id, session_id);
+        assert_eq!(report.hook_executions, 5);
+        assert_eq!(report.agent_invocations, 2);
+        assert_eq!(report.tool_invocations, 3);
+        assert_eq!(report.tool_breakdown["calculator"], 2);
+        assert_eq!(report.tool_breakdown["web_search"], 1);
+        assert_eq!(report.input_tokens, 200);
+        assert_eq!(report.output_tokens, 100);
+        assert_eq!(report.total_tokens, 300);
+        assert!((report.total_cost_usd - 0.5).abs() < f64::EPSILON);
+        assert_eq!(
+            report.duration,
+            std::time::Duration::from_secs(4) + std::time::Duration::from_millis(10)
+        );
+
+        let exported: serde_json::Value = serde_json::from_str(&report.to_json().unwrap()).unwrap();
+        assert_eq!(exported["total_tokens"], 300);
+    }
+
+    #[tokio::test]
+    async fn test_artifact_retention_policy() {
+        use crate::sessions::policies::{EvictionMode, RetentionConfig};
+        use crate::sessions::SessionConfig;
+
+        async fn manager_with_retention(eviction_mode: EvictionMode) -> SessionManager {
+            let config = SessionManagerConfig::builder()
+                .retention_config(RetentionConfig { eviction_mode })
+                .build();
+            SessionManager::new(
+                Arc::new(StateManager::new(None).await.unwrap()),
+                Arc::new(MemoryBackend::new()),
+                Arc::new(HookRegistry::new()),
+                Arc::new(HookExecutor::new()),
+                &Arc::new(EventBus::new()),
+                config,
+            )
+            .unwrap()
+        }
+
+        async fn session_with_limit(manager: &SessionManager, max_artifacts: usize) -> SessionId {
+            let options = CreateSessionOptions::builder()
+                .config(SessionConfig {
+                    max_artifacts: Some(max_artifacts),
+                    ..SessionConfig::default()
+                })
+                .build();
+            manager.create_session(options).await.unwrap()
+        }
+
+        async fn store(
+            manager: &SessionManager,
+            session_id: &SessionId,
+            i: usize,
+        ) -> Result<ArtifactId> {
+            manager
+                .store_artifact(
+                    session_id,
+                    ArtifactType::UserInput,
+                    format!("artifact_{i}.txt"),
+                    format!("content {i}").into_bytes(),
+                    None,
+                )
+                .await
+        }
+
+        // Least-recently-accessed artifacts are evicted
+        let manager = manager_with_retention(EvictionMode::LeastRecentlyAccessed).await;
+        let session_id = session_with_limit(&manager, 3).await;
+        let mut ids = Vec::new();
+        for i in 0..3 {
+            ids.push(store(&manager, &session_id, i).await.unwrap());
+        }
+        // Reading the oldest artifact makes it the most recently accessed
+        manager.get_artifact(&session_id, &ids[0]).await.unwrap();
+        for i in 3..5 {
+            ids.push(store(&manager, &session_id, i).await.unwrap());
+        }
+
+        let mut names: Vec<String> = manager
+            .list_artifacts(&session_id)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|m| m.name)
+            .collect();
+        names.sort();
+        assert_eq!(
+            names,
+            vec!["artifact_0.txt", "artifact_3.txt", "artifact_4.txt"]
+        );
+        assert!(manager.get_artifact(&session_id, &ids[1]).await.is_err());
+        assert!(manager.get_artifact(&session_id, &ids[2]).await.is_err());
+
+        // Versions of one name count once, and eviction removes all of them
+        let manager = manager_with_retention(EvictionMode::LeastRecentlyAccessed).await;
+        let session_id = session_with_limit(&manager, 2).await;
+        for version in 0..3 {
+            manager
+                .store_artifact(
+                    &session_id,
+                    ArtifactType::UserInput,
+                    "draft.txt".to_string(),
+                    format!("draft {version}").into_bytes(),
+                    None,
+                )
+                .await
+                .unwrap();
+        }
+        store(&manager, &session_id, 0).await.unwrap();
+        assert_eq!(manager.list_artifacts(&session_id).await.unwrap().len(), 4);
+        store(&manager, &session_id, 1).await.unwrap();
+        let mut names: Vec<String> = manager
+            .list_artifacts(&session_id)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|m| m.name)
+            .collect();
+        names.sort();
+        assert_eq!(names, vec!["artifact_0.txt", "artifact_1.txt"]);
+
+        // Reject mode is the default and refuses the store instead
+        assert_eq!(
+            RetentionConfig::default().eviction_mode,
+            EvictionMode::Reject
+        );
+        let manager = manager_with_retention(EvictionMode::Reject).await;
+        let session_id = session_with_limit(&manager, 3).await;
+        for i in 0..3 {
+            store(&manager, &session_id, i).await.unwrap();
+        }
+        assert!(matches!(
+            store(&manager, &session_id, 3).await,
+            Err(SessionError::ResourceLimitExceeded { .. })
+        ));
+        // A new version of a stored name still fits
+        manager
+            .store_artifact(
+                &session_id,
+                ArtifactType::UserInput,
+                "artifact_0.txt".to_string(),
+                b"revised".to_vec(),
+                None,
+            )
+            .await
+            .unwrap();
+        let mut names: Vec<String> = manager
+            .list_artifacts(&session_id)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|m| m.name)
+            .collect();
+        names.sort();
+        names.dedup();
+        assert_eq!(
+            names,
+            vec!["artifact_0.txt", "artifact_1.txt", "artifact_2.txt"]
+        );
+
+        // A zero limit is rejected when the session is created
+        let manager = manager_with_retention(EvictionMode::LeastRecentlyAccessed).await;
+        let options = CreateSessionOptions::builder()
+            .config(SessionConfig {
+                max_artifacts: Some(0),
+                ..SessionConfig::default()
+            })
+            .build();
+        assert!(matches!(
+            manager.create_session(options).await,
+            Err(SessionError::Configuration(_))
+        ));
+    }
+    #[tokio::test]
+    async fn test_export_import_session_round_trip() {
+        let source = create_test_manager().await;
+        let session_id = source
+            .create_session(CreateSessionOptions {
+                name: Some("Portable".to_string()),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        let scope = StateScope::Session(session_id.to_string());
+        source
+            .state_manager
+            .set(scope.clone(), "topic", serde_json::json!("rust"))
+            .await
+            .unwrap();
+
+        let notes_id = source
+            .store_artifact(
+                &session_id,
+                ArtifactType::UserInput,
+                "notes.txt".to_string(),
+                b"first artifact".to_vec(),
+                None,
+            )
+            .await
+            .unwrap();
+        let data_id = source
+            .store_artifact(
+                &session_id,
+                ArtifactType::ToolResult,
+                "data.json".to_string(),
+                br#"{"second": true}"#.to_vec(),
+                Some(HashMap::from([(
+                    "tags".to_string(),
+                    serde_json::json!(["export"]),
+                )])),
+            )
+            .await
+            .unwrap();
+
+        let mut archive = Vec::new();
+        source
+            .export_session(&session_id, &mut archive)
+            .await
+            .unwrap();
+
+        // A fresh manager keeps the original session ID
+        let target = create_test_manager().await;
+        let imported = target.import_session(archive.as_slice()).await.unwrap();
+        assert_eq!(imported, session_id);
+
+        let session = target.get_session(&imported).await.unwrap();
+        assert_eq!(
+            session.snapshot().await.metadata.name.as_deref(),
+            Some("Portable")
+        );
+        assert_eq!(
+            target.state_manager.get(scope, "topic").await.unwrap(),
+            Some(serde_json::json!("rust"))
+        );
+
+        assert_eq!(target.list_artifacts(&imported).await.unwrap().len(), 2);
+        assert_eq!(
+            target
+                .get_artifact_content(&imported, &notes_id)
+                .await
+                .unwrap(),
+            b"first artifact"
+        );
+        let data = target.get_artifact(&imported, &data_id).await.unwrap();
+        assert_eq!(data.get_content().unwrap(), br#"{"second": true}"#);
+        assert_eq!(data.metadata.tags, vec!["export"]);
+
+        // Importing again collides, so the session gets a new ID and reuses stored content
+        let reimported = target.import_session(archive.as_slice()).await.unwrap();
+        assert_ne!(reimported, session_id);
+        assert_eq!(target.list_artifacts(&reimported).await.unwrap().len(), 2);
+        let stats = target
+            .artifact_storage
+            .get_storage_stats(&reimported)
+            .await
+            .unwrap();
+        assert_eq!(stats.deduplicated_count, 2);
+        // Imported artifacts are counted once
+        let reimported_session = target.get_session(&reimported).await.unwrap();
+        assert_eq!(
+            reimported_session.snapshot().await.metadata.artifact_count,
+            2
+        );
+
+        // A truncated archive fails without leaving a partial session behind
+        let sessions_before = target
+            .list_sessions(SessionQuery::default())
+            .await
+            .unwrap()
+            .len();
+        assert!(target
+            .import_session(&archive[..archive.len() - 1])
+            .await
+            .is_err());
+        assert_eq!(
+            target
+                .list_sessions(SessionQuery::default())
+                .await
+                .unwrap()
+                .len(),
+            sessions_before
+        );
+    }
+    #[tokio::test]
+    async fn test_artifact_versions_by_name() {
+        let manager = create_test_manager().await;
+        let session_id = manager
+            .create_session(CreateSessionOptions::default())
+            .await
+            .unwrap();
+
+        let revisions = [
+            "# Notes\n",
+            "# Notes\n- first\n",
+            "# Notes\n- first\n- second\n",
+        ];
+        let mut ids = Vec::new();
+        for revision in revisions {
+            let id = manager
+                .store_artifact(
+                    &session_id,
+                    ArtifactType::UserInput,
+                    "notes.md".to_string(),
+                    revision.as_bytes().to_vec(),
+                    None,
+                )
+                .await
+                .unwrap();
+            ids.push(id);
+        }
+
+        // Re-storing unchanged content keeps the latest version
+        let unchanged = manager
+            .store_artifact(
+                &session_id,
+                ArtifactType::UserInput,
+                "notes.md".to_string(),
+                revisions[2].as_bytes().to_vec(),
+                None,
+            )
+            .await
+            .unwrap();
+        assert_eq!(unchanged, ids[2]);
+
+        let versions = manager
+            .list_artifact_versions(&session_id, "notes.md")
+            .await
+            .unwrap();
+        assert_eq!(
+            versions
+                .iter()
+                .map(|m| m.version.version)
+                .collect::<Vec<_>>(),
+            vec![1, 2, 3]
+        );
+
+        let latest = manager
+            .get_artifact_version(&session_id, "notes.md", None)
+            .await
+            .unwrap();
+        assert_eq!(latest.metadata.version.version, 3);
+        assert_eq!(latest.get_content_string().unwrap(), revisions[2]);
+
+        let first = manager
+            .get_artifact_version(&session_id, "notes.md", Some(1))
+            .await
+            .unwrap();
+        assert_eq!(first.id, ids[0]);
+        assert_eq!(first.get_content_string().unwrap(), revisions[0]);
+
+        assert!(matches!(
+            manager
+                .get_artifact_version(&session_id, "notes.md", Some(4))
+                .await,
+            Err(SessionError::ArtifactNotFound { .. })
+        ));
+    }
+    #[tokio::test]
+    async fn test_query_sessions_by_metadata_and_time_range() {
+        let manager = create_test_manager().await;
+        let now = Utc::now();
+
+        let create = |user: &str, name: &str, age_days: i64| {
+            let manager = &manager;
+            let options = CreateSessionOptions {
+                name: Some(name.to_string()),
+                metadata: HashMap::from([("user".to_string(), serde_json::json!(user))]),
+                ..Default::default()
+            };
+            async move {
+                let session_id = manager.create_session(options).await.unwrap();
+                let session = manager.get_session(&session_id).await.unwrap();
+                session.metadata.write().await.created_at = now - chrono::Duration::days(age_days);
+                session_id
+            }
+        };
+
+        let alice_recent = create("alice", "alice-recent", 1).await;
+        create("alice", "alice-old", 10).await;
+        create("bob", "bob-recent", 2).await;
+
+        // A persisted but inactive session is also searched
+        let alice_stored = create("alice", "alice-stored", 3).await;
+        let stored = manager.get_session(&alice_stored).await.unwrap();
+        manager.save_session(&stored).await.unwrap();
+        manager.active_sessions.write().await.remove(&alice_stored);
+
+        let query = SessionQuery {
+            metadata: HashMap::from([("user".to_string(), serde_json::json!("alice"))]),
+            created_after: Some(now - chrono::Duration::days(7)),
+            sort_by: SessionSortBy::CreatedAt,
+            sort_desc: true,
+            ..Default::default()
+        };
+        let results = manager.query_sessions(query.clone()).await.unwrap();
+        assert_eq!(
+            results.iter().map(|m| m.id).collect::<Vec<_>>(),
+            vec![alice_recent, alice_stored]
+        );
+        // Querying does not activate persisted sessions
+        assert!(!manager
+            .active_sessions
+            .read()
+            .await
+            .contains_key(&alice_stored));
+
+        manager.suspend_session(&alice_recent).await.unwrap();
+        let active_only = manager
+            .query_sessions(SessionQuery {
+                status: Some(SessionStatus::Active),
+                ..query.clone()
+            })
+            .await
+            .unwrap();
+        assert_eq!(active_only.len(), 1);
+        assert_eq!(active_only[0].id, alice_stored);
+
+        let limited = manager
+            .query_sessions(SessionQuery {
+                limit: Some(1),
+                ..query
+            })
+            .await
+            .unwrap();
+        assert_eq!(limited.len(), 1);
+    }
 }
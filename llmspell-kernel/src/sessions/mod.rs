@@ -19,6 +19,7 @@
 //! - `manager`: Core session management orchestration
 //! - `session`: Session types and state management
 //! - `artifact`: Artifact storage and retrieval
+//! - `archive`: Portable session export/import format
 //! - `replay`: Session replay engine
 //! - `bridge`: Script language integration (Lua)
 //! - `error`: Error types and handling
@@ -42,6 +43,9 @@ pub mod manager;
 /// Artifact storage system
 pub mod artifact;
 
+/// Portable session archive format
+pub mod archive;
+
 /// Session replay engine
 pub mod replay;
 
@@ -67,9 +71,10 @@ pub mod analytics;
 pub mod security;
 
 // Re-export commonly used types
+pub use archive::{ArchiveHeader, ArchivedArtifact};
 pub use artifact::{
     ArtifactId, ArtifactQuery, ArtifactStorage, ArtifactStorageConfig, ArtifactStorageOps,
-    ArtifactType, SessionArtifact,
+    ArtifactType, SessionArtifact, VersionSelector,
 };
 pub use config::{SessionManagerConfig, SessionManagerConfigBuilder};
 pub use error::{Result, SessionError};
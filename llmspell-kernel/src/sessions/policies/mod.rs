@@ -1,5 +1,5 @@
 //! ABOUTME: Session policy system built on existing hook patterns
-//! ABOUTME: Provides timeout, resource limit, and rate limiting policies using llmspell-hooks
+//! ABOUTME: Provides timeout, resource limit, rate limiting and artifact retention policies
 
 use anyhow::Result;
 use llmspell_hooks::{
@@ -10,10 +10,12 @@ use std::sync::Arc;
 
 pub mod rate_limit;
 pub mod resource_limit;
+pub mod retention;
 pub mod timeout;
 
 pub use rate_limit::SessionRateLimitPolicy;
 pub use resource_limit::SessionResourcePolicy;
+pub use retention::{EvictionMode, RetentionConfig, SessionRetentionPolicy};
 pub use timeout::SessionTimeoutPolicy;
 
 /// Session policy configuration
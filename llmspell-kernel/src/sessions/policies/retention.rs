@@ -0,0 +1,176 @@
+//! ABOUTME: Session artifact retention policy enforcing per-session artifact count limits
+//! ABOUTME: Rejects stores once a session is full, or optionally evicts least-recently-accessed artifacts
+
+use crate::sessions::artifact::{ArtifactId, ArtifactStorage, ArtifactStorageOps};
+use crate::sessions::{Result, SessionError, SessionId};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use tokio::sync::RwLock;
+use tracing::debug;
+
+/// What to do when a store would exceed the artifact limit
+///
+/// Eviction deletes stored data, so it is opt-in.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub enum EvictionMode {
+    /// Evict the least-recently-accessed artifacts to make room
+    LeastRecentlyAccessed,
+    /// Reject the store with a resource limit error
+    #[default]
+    Reject,
+}
+
+/// Retention configuration
+///
+/// The limit itself is each session's `SessionConfig::max_artifacts`, counted
+/// per artifact name: every version of a named artifact counts once.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RetentionConfig {
+    /// Behavior once a session reaches its artifact limit
+    pub eviction_mode: EvictionMode,
+}
+
+/// Session artifact retention policy
+///
+/// Tracks when artifacts were last stored or read. A named artifact was last
+/// accessed when any of its versions was; artifacts with no recorded access
+/// (e.g. stored before a restart) are treated as the oldest.
+#[derive(Debug)]
+pub struct SessionRetentionPolicy {
+    /// Retention configuration
+    config: RetentionConfig,
+    /// Last access time per artifact
+    last_access: RwLock<HashMap<ArtifactId, DateTime<Utc>>>,
+}
+
+impl SessionRetentionPolicy {
+    /// Create a new retention policy
+    pub fn new(config: RetentionConfig) -> Self {
+        Self {
+            config,
+            last_access: RwLock::new(HashMap::new()),
+        }
+    }
+
+    /// Get the retention configuration
+    pub fn config(&self) -> &RetentionConfig {
+        &self.config
+    }
+
+    /// Record that an artifact was stored or read
+    pub async fn record_access(&self, artifact_id: &ArtifactId) {
+        self.last_access
+            .write()
+            .await
+            .insert(artifact_id.clone(), Utc::now());
+    }
+
+    /// Stop tracking a deleted artifact
+    pub async fn forget(&self, artifact_id: &ArtifactId) {
+        self.last_access.write().await.remove(artifact_id);
+    }
+
+    /// Reject a store into a full session when the eviction mode is [`EvictionMode::Reject`]
+    ///
+    /// Storing a new version of an existing artifact `name` never counts
+    /// against the limit.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ResourceLimitExceeded` if the session already holds
+    /// `max_artifacts` named artifacts, or an error if its artifacts cannot be listed
+    pub async fn check_capacity(
+        &self,
+        storage: &ArtifactStorage,
+        session_id: &SessionId,
+        max_artifacts: Option<usize>,
+        name: &str,
+    ) -> Result<()> {
+        let Some(max_artifacts) = max_artifacts else {
+            return Ok(());
+        };
+        if self.config.eviction_mode != EvictionMode::Reject {
+            return Ok(());
+        }
+
+        let artifacts = storage.list_session_artifacts_by_name(session_id).await?;
+        if artifacts.iter().any(|(existing, _)| existing == name) {
+            return Ok(());
+        }
+        let count = artifacts.len();
+        if count >= max_artifacts {
+            return Err(SessionError::ResourceLimitExceeded {
+                resource: "artifacts".to_string(),
+                message: format!(
+                    "Session {session_id} already holds {count} artifacts (limit {max_artifacts})"
+                ),
+            });
+        }
+        Ok(())
+    }
+
+    /// Evict least-recently-accessed named artifacts over the limit after a store
+    ///
+    /// Evicting a named artifact deletes all of its versions. The just-stored
+    /// `name` is never evicted. Returns the IDs of evicted artifact versions.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the session's artifacts cannot be listed or deleted
+    pub async fn evict_excess(
+        &self,
+        storage: &ArtifactStorage,
+        session_id: &SessionId,
+        max_artifacts: Option<usize>,
+        stored: &str,
+    ) -> Result<Vec<ArtifactId>> {
+        let Some(max_artifacts) = max_artifacts else {
+            return Ok(Vec::new());
+        };
+        if self.config.eviction_mode != EvictionMode::LeastRecentlyAccessed {
+            return Ok(Vec::new());
+        }
+
+        let artifacts = storage.list_session_artifacts_by_name(session_id).await?;
+        if artifacts.len() <= max_artifacts {
+            return Ok(Vec::new());
+        }
+        let excess = artifacts.len() - max_artifacts;
+
+        // Stable sort keeps storage order among artifacts with equal access times
+        let mut candidates = {
+            let last_access = self.last_access.read().await;
+            artifacts
+                .into_iter()
+                .filter(|(name, _)| name != stored)
+                .map(|(_, versions)| {
+                    let accessed = versions
+                        .iter()
+                        .filter_map(|id| last_access.get(id).copied())
+                        .max();
+                    (accessed, versions)
+                })
+                .collect::<Vec<_>>()
+        };
+        candidates.sort_by_key(|(accessed, _)| *accessed);
+
+        let mut evicted = Vec::new();
+        for (_, versions) in candidates.into_iter().take(excess) {
+            for artifact_id in versions {
+                if storage.delete_artifact(&artifact_id).await? {
+                    self.forget(&artifact_id).await;
+                    evicted.push(artifact_id);
+                }
+            }
+        }
+
+        debug!(
+            "Evicted {} artifact versions from session {} to stay within limit of {}",
+            evicted.len(),
+            session_id,
+            max_artifacts
+        );
+        Ok(evicted)
+    }
+}
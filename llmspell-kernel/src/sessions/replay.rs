@@ -5,6 +5,7 @@ mod hook_replay_bridge;
 pub mod session_adapter;
 pub mod session_controls;
 pub mod session_debug;
+pub mod session_player;
 
 #[cfg(test)]
 mod tests;
@@ -100,6 +101,17 @@ impl ReplayEngine {
         self.session_adapter.get_session_timeline(session_id).await
     }
 
+    /// Create an interactive replay player for a session
+    ///
+    /// # Errors
+    /// Returns error if session adapter fails to retrieve the session timeline
+    pub async fn create_replay_player(
+        &self,
+        session_id: &SessionId,
+    ) -> Result<session_player::SessionReplayPlayer> {
+        self.session_adapter.create_replay_player(session_id).await
+    }
+
     /// Get replay status for a session
     pub fn get_replay_status(
         &self,
@@ -22,6 +22,7 @@ use super::session_controls::{
     SessionBreakpoint, SessionReplayControlConfig, SessionReplayControls, SessionReplayProgress,
 };
 use super::session_debug::{ErrorAnalysis, SessionDebugger, SessionState, StateComparison};
+use super::session_player::SessionReplayPlayer;
 
 /// Session-specific replay configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -397,6 +398,25 @@ impl SessionReplayAdapter {
         Ok(executions)
     }
 
+    /// Create an interactive replay player over a session's timeline
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the session timeline cannot be loaded or is empty.
+    pub async fn create_replay_player(
+        &self,
+        session_id: &SessionId,
+    ) -> Result<SessionReplayPlayer> {
+        let executions = self.get_session_timeline(session_id).await?;
+        if executions.is_empty() {
+            return Err(SessionError::replay("No hook executions found for session"));
+        }
+
+        let mut player = SessionReplayPlayer::new(*session_id, executions);
+        player.set_speed(self.controls.default_speed_multiplier());
+        Ok(player)
+    }
+
     /// Get current replay status for a session
     ///
     /// # Panics
@@ -517,6 +517,11 @@ impl SessionReplayControls {
         Ok(())
     }
 
+    /// Default speed multiplier for new replays
+    pub fn default_speed_multiplier(&self) -> f64 {
+        self.config.default_speed_multiplier
+    }
+
     /// Get current progress
     ///
     /// # Panics
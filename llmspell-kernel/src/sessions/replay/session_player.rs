@@ -0,0 +1,320 @@
+//! ABOUTME: Interactive session replay player with speed control, stepping and breakpoints
+//! ABOUTME: Walks a recorded session timeline so replay can be paused, inspected and seeked
+
+use crate::sessions::SessionId;
+use crate::state::manager::SerializedHookExecution;
+use std::collections::HashMap;
+use std::time::SystemTime;
+use tracing::debug;
+use uuid::Uuid;
+
+use super::session_controls::{
+    SessionBreakpoint, SessionBreakpointCondition, SessionReplaySpeed, SessionReplayState,
+};
+use super::session_debug::SessionState;
+
+/// Handler invoked for every execution the player replays
+pub type ReplayHandler = Box<dyn Fn(&SerializedHookExecution) + Send + Sync>;
+
+/// Why a call to [`SessionReplayPlayer::play`] returned
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ReplayStop {
+    /// One execution was replayed in step-by-step mode
+    Stepped,
+    /// Paused after replaying an execution that hit a breakpoint
+    Breakpoint(Uuid),
+    /// Every execution has been replayed
+    Completed,
+}
+
+/// Current position of a replay within the session timeline
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReplayPosition {
+    /// Number of executions replayed so far (index of the next execution)
+    pub index: usize,
+    /// Total executions in the timeline
+    pub total: usize,
+    /// Timestamp of the last replayed execution
+    pub timestamp: Option<SystemTime>,
+    /// Hook of the last replayed execution
+    pub hook_id: Option<String>,
+}
+
+/// Replays a recorded session timeline under user control
+///
+/// Executions are replayed in timestamp order. Gaps between executions are
+/// reproduced, scaled by the speed multiplier; in step-by-step mode each call to
+/// [`play`](Self::play) replays a single execution. Breakpoints pause replay
+/// right after the matching execution so its state can be inspected.
+pub struct SessionReplayPlayer {
+    session_id: SessionId,
+    executions: Vec<SerializedHookExecution>,
+    position: usize,
+    speed: SessionReplaySpeed,
+    step_mode: bool,
+    breakpoints: Vec<SessionBreakpoint>,
+    state: SessionReplayState,
+    handler: Option<ReplayHandler>,
+}
+
+impl SessionReplayPlayer {
+    /// Create a player over a recorded session timeline
+    pub fn new(session_id: SessionId, mut executions: Vec<SerializedHookExecution>) -> Self {
+        executions.sort_by_key(|e| e.timestamp);
+        Self {
+            session_id,
+            executions,
+            position: 0,
+            speed: SessionReplaySpeed::default(),
+            step_mode: false,
+            breakpoints: Vec::new(),
+            state: SessionReplayState::Scheduled,
+            handler: None,
+        }
+    }
+
+    /// Set the handler invoked for each replayed execution
+    #[must_use]
+    pub fn with_handler(mut self, handler: ReplayHandler) -> Self {
+        self.handler = Some(handler);
+        self
+    }
+
+    /// Session being replayed
+    pub fn session_id(&self) -> SessionId {
+        self.session_id
+    }
+
+    /// Set the speed multiplier (clamped to the supported range)
+    pub fn set_speed(&mut self, multiplier: f64) {
+        self.speed.set_speed(multiplier);
+    }
+
+    /// Current speed multiplier
+    pub fn speed(&self) -> f64 {
+        self.speed.multiplier()
+    }
+
+    /// Enable or disable step-by-step replay
+    pub fn set_step_mode(&mut self, step_mode: bool) {
+        self.step_mode = step_mode;
+    }
+
+    /// Add a breakpoint
+    pub fn add_breakpoint(&mut self, breakpoint: SessionBreakpoint) {
+        self.breakpoints.push(breakpoint);
+    }
+
+    /// Remove a breakpoint, returning whether it existed
+    pub fn remove_breakpoint(&mut self, breakpoint_id: Uuid) -> bool {
+        let before = self.breakpoints.len();
+        self.breakpoints.retain(|bp| bp.id != breakpoint_id);
+        self.breakpoints.len() != before
+    }
+
+    /// Current replay state
+    pub fn state(&self) -> &SessionReplayState {
+        &self.state
+    }
+
+    /// Current position in the timeline
+    pub fn position(&self) -> ReplayPosition {
+        let last = self.last_replayed();
+        ReplayPosition {
+            index: self.position,
+            total: self.executions.len(),
+            timestamp: last.map(|e| e.timestamp),
+            hook_id: last.map(|e| e.hook_id.clone()),
+        }
+    }
+
+    /// Move the position so the next execution replayed is the first at or after `timestamp`
+    pub fn seek(&mut self, timestamp: SystemTime) -> ReplayPosition {
+        self.position = self.executions.partition_point(|e| e.timestamp < timestamp);
+        if self.position < self.executions.len() {
+            self.state = SessionReplayState::Paused;
+        } else {
+            self.state = SessionReplayState::Completed;
+        }
+        debug!(
+            "Seeked session {} replay to position {}",
+            self.session_id, self.position
+        );
+        self.position()
+    }
+
+    /// State captured by the last replayed execution
+    pub fn inspect_state(&self) -> Option<SessionState> {
+        self.last_replayed().map(Self::state_of)
+    }
+
+    /// Replay from the current position until completion, a breakpoint or one step
+    pub async fn play(&mut self) -> ReplayStop {
+        self.state = SessionReplayState::Running;
+
+        while self.position < self.executions.len() {
+            if let Some(previous) = self.last_replayed() {
+                let gap = self.executions[self.position]
+                    .timestamp
+                    .duration_since(previous.timestamp)
+                    .unwrap_or_default();
+                let delay = self.speed.apply_to_duration(gap);
+                if !self.step_mode && !delay.is_zero() {
+                    tokio::time::sleep(delay).await;
+                }
+            }
+
+            let execution = &self.executions[self.position];
+            if let Some(handler) = &self.handler {
+                handler(execution);
+            }
+            self.position += 1;
+
+            if let Some(breakpoint_id) = self.hit_breakpoint() {
+                self.state = SessionReplayState::Paused;
+                return ReplayStop::Breakpoint(breakpoint_id);
+            }
+
+            if self.step_mode {
+                self.state = SessionReplayState::Paused;
+                return ReplayStop::Stepped;
+            }
+        }
+
+        self.state = SessionReplayState::Completed;
+        ReplayStop::Completed
+    }
+
+    fn last_replayed(&self) -> Option<&SerializedHookExecution> {
+        self.position
+            .checked_sub(1)
+            .and_then(|i| self.executions.get(i))
+    }
+
+    /// Check breakpoints against the execution just replayed
+    fn hit_breakpoint(&mut self) -> Option<Uuid> {
+        let (hook_id, timestamp) = self
+            .last_replayed()
+            .map(|e| (e.hook_id.clone(), e.timestamp))?;
+        let previous_timestamp = self
+            .position
+            .checked_sub(2)
+            .map(|i| self.executions[i].timestamp);
+        let replayed = self.position;
+
+        let hit = self.breakpoints.iter_mut().find(|bp| {
+            bp.enabled
+                && match &bp.condition {
+                    SessionBreakpointCondition::HookExecution { hook_id: target } => {
+                        hook_id == *target
+                    }
+                    SessionBreakpointCondition::Timestamp { timestamp: target } => {
+                        timestamp >= *target && previous_timestamp.is_none_or(|prev| prev < *target)
+                    }
+                    SessionBreakpointCondition::HookCount { count } => replayed == *count,
+                    _ => false, // Other conditions need replay results
+                }
+        })?;
+
+        if hit.one_shot {
+            hit.enabled = false;
+        }
+        debug!(
+            "Session {} replay hit breakpoint {} at position {}",
+            self.session_id, hit.id, replayed
+        );
+        Some(hit.id)
+    }
+
+    fn state_of(execution: &SerializedHookExecution) -> SessionState {
+        SessionState {
+            timestamp: execution.timestamp,
+            execution_id: execution.execution_id,
+            hook_id: execution.hook_id.clone(),
+            context: serde_json::from_slice(&execution.hook_context)
+                .unwrap_or(serde_json::Value::Null),
+            result: execution.result.clone(),
+            metadata: execution
+                .metadata
+                .iter()
+                .map(|(k, v)| (k.clone(), v.to_string()))
+                .collect::<HashMap<_, _>>(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    fn recorded_session(start: SystemTime) -> Vec<SerializedHookExecution> {
+        (0..5u64)
+            .map(|i| SerializedHookExecution {
+                hook_id: format!("hook_{i}"),
+                execution_id: Uuid::new_v4(),
+                correlation_id: Uuid::nil(),
+                hook_context: serde_json::to_vec(&json!({"step": i})).unwrap(),
+                result: "\"Continue\"".to_string(),
+                timestamp: start + Duration::from_millis(i * 20),
+                duration: Duration::from_millis(1),
+                metadata: HashMap::new(),
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_step_breakpoint_and_continue() {
+        let start = SystemTime::now();
+        let replayed = Arc::new(AtomicUsize::new(0));
+        let counter = replayed.clone();
+
+        let mut player = SessionReplayPlayer::new(SessionId::new(), recorded_session(start))
+            .with_handler(Box::new(move |_| {
+                counter.fetch_add(1, Ordering::SeqCst);
+            }));
+        let breakpoint_id = Uuid::new_v4();
+        player.add_breakpoint(SessionBreakpoint {
+            id: breakpoint_id,
+            session_id: player.session_id(),
+            condition: SessionBreakpointCondition::HookExecution {
+                hook_id: "hook_2".to_string(),
+            },
+            enabled: true,
+            one_shot: false,
+            callback_data: None,
+        });
+
+        // Step-by-step
+        player.set_step_mode(true);
+        assert_eq!(player.play().await, ReplayStop::Stepped);
+        assert_eq!(player.position().index, 1);
+        assert_eq!(player.state(), &SessionReplayState::Paused);
+
+        // Run fast until the breakpoint
+        player.set_step_mode(false);
+        player.set_speed(10.0);
+        assert_eq!(player.play().await, ReplayStop::Breakpoint(breakpoint_id));
+        let position = player.position();
+        assert_eq!(position.index, 3);
+        assert_eq!(position.hook_id.as_deref(), Some("hook_2"));
+
+        let state = player.inspect_state().unwrap();
+        assert_eq!(state.hook_id, "hook_2");
+        assert_eq!(state.context, json!({"step": 2}));
+
+        // Continue to completion
+        assert_eq!(player.play().await, ReplayStop::Completed);
+        assert_eq!(player.position().index, 5);
+        assert_eq!(player.state(), &SessionReplayState::Completed);
+        assert_eq!(replayed.load(Ordering::SeqCst), 5);
+
+        // Seek back to replay from the second execution
+        let position = player.seek(start + Duration::from_millis(20));
+        assert_eq!(position.index, 1);
+        assert_eq!(position.hook_id.as_deref(), Some("hook_0"));
+    }
+}
@@ -416,6 +416,9 @@ pub struct SessionQuery {
     pub created_before: Option<DateTime<Utc>>,
     /// Search in name and description
     pub search_text: Option<String>,
+    /// Filter by custom metadata (sessions must have every key with an equal value)
+    #[serde(default)]
+    pub metadata: HashMap<String, serde_json::Value>,
     /// Maximum number of results
     pub limit: Option<usize>,
     /// Offset for pagination
@@ -426,6 +429,59 @@ pub struct SessionQuery {
     pub sort_desc: bool,
 }
 
+impl SessionQuery {
+    /// Whether session metadata passes every filter in this query
+    ///
+    /// Sorting and pagination are not applied here.
+    pub fn matches(&self, metadata: &SessionMetadata) -> bool {
+        if self.status.is_some_and(|status| metadata.status != status) {
+            return false;
+        }
+        if self
+            .created_by
+            .as_ref()
+            .is_some_and(|created_by| metadata.created_by.as_ref() != Some(created_by))
+        {
+            return false;
+        }
+        if !self.tags.iter().all(|tag| metadata.tags.contains(tag)) {
+            return false;
+        }
+        if self
+            .parent_session_id
+            .as_ref()
+            .is_some_and(|parent_id| metadata.parent_session_id.as_ref() != Some(parent_id))
+        {
+            return false;
+        }
+        if self
+            .created_after
+            .is_some_and(|after| metadata.created_at < after)
+            || self
+                .created_before
+                .is_some_and(|before| metadata.created_at > before)
+        {
+            return false;
+        }
+        if let Some(ref search_text) = self.search_text {
+            let name_match = metadata
+                .name
+                .as_ref()
+                .is_some_and(|n| n.contains(search_text));
+            let desc_match = metadata
+                .description
+                .as_ref()
+                .is_some_and(|d| d.contains(search_text));
+            if !name_match && !desc_match {
+                return false;
+            }
+        }
+        self.metadata
+            .iter()
+            .all(|(key, value)| metadata.custom_metadata.get(key) == Some(value))
+    }
+}
+
 /// Sort options for session queries
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
 pub enum SessionSortBy {
@@ -0,0 +1,370 @@
+// ABOUTME: Encrypted file-backed storage backend for the API key manager
+// ABOUTME: Derives an AEAD key from a passphrase so key material is never written in plaintext
+
+use super::config::{EncryptionAlgorithm, EncryptionConfig, KeyDerivationConfig};
+use super::{StateError, StateResult};
+use aes_gcm::Aes256Gcm;
+use chacha20poly1305::aead::{Aead, KeyInit};
+use chacha20poly1305::{ChaCha20Poly1305, Nonce};
+use llmspell_utils::api_key_manager::{ApiKeyMetadata, ApiKeyStorage};
+use llmspell_utils::file_utils::write_file_atomic;
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use std::collections::HashMap;
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+/// On-disk format version
+const FILE_VERSION: u32 = 1;
+const SALT_LEN: usize = 16;
+const NONCE_LEN: usize = 12;
+const KEY_LEN: usize = 32;
+
+/// Encrypted key file as written to disk
+///
+/// Only the header needed to derive the key is stored in the clear; key
+/// material and metadata live inside `ciphertext`.
+#[derive(Serialize, Deserialize)]
+struct EncryptedKeyFile {
+    version: u32,
+    algorithm: EncryptionAlgorithm,
+    key_derivation: KeyDerivationConfig,
+    salt: String,
+    nonce: String,
+    ciphertext: String,
+}
+
+/// Decrypted key entry
+#[derive(Clone, Serialize, Deserialize)]
+struct StoredKey {
+    key: String,
+    metadata: ApiKeyMetadata,
+}
+
+/// File-backed `ApiKeyStorage` that encrypts stored keys at rest
+///
+/// The encryption key is derived from a passphrase with the configured key
+/// derivation (`pbkdf2-sha256`) and a random per-file salt. Every write
+/// re-encrypts the whole store with a fresh nonce and replaces the file
+/// atomically. When opening an existing file, the algorithm and key
+/// derivation recorded in its header take precedence over the config.
+pub struct PersistentApiKeyStorage {
+    path: PathBuf,
+    algorithm: EncryptionAlgorithm,
+    key_derivation: KeyDerivationConfig,
+    salt: [u8; SALT_LEN],
+    key: [u8; KEY_LEN],
+    entries: HashMap<String, StoredKey>,
+}
+
+impl PersistentApiKeyStorage {
+    /// Open or create an encrypted key file using `passphrase`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the key derivation is unsupported, the file cannot
+    /// be read or parsed, or it cannot be decrypted with `passphrase`
+    pub fn open(
+        path: impl Into<PathBuf>,
+        passphrase: &str,
+        config: &EncryptionConfig,
+    ) -> StateResult<Self> {
+        let path = path.into();
+        if passphrase.is_empty() {
+            return Err(StateError::ConfigurationError(
+                "API key storage passphrase cannot be empty".to_string(),
+            ));
+        }
+
+        if !path.exists() {
+            let salt = rand::random::<[u8; SALT_LEN]>();
+            let key = derive_key(passphrase, &salt, &config.key_derivation)?;
+            return Ok(Self {
+                path,
+                algorithm: config.algorithm.clone(),
+                key_derivation: config.key_derivation.clone(),
+                salt,
+                key,
+                entries: HashMap::new(),
+            });
+        }
+
+        let raw = std::fs::read(&path)
+            .map_err(|e| StateError::IoError(format!("Failed to read {}: {e}", path.display())))?;
+        let file: EncryptedKeyFile = serde_json::from_slice(&raw)
+            .map_err(|e| StateError::SerializationError(format!("Invalid key file: {e}")))?;
+        if file.version != FILE_VERSION {
+            return Err(StateError::MigrationError(format!(
+                "Unsupported key file version {}",
+                file.version
+            )));
+        }
+
+        let salt: [u8; SALT_LEN] = decode_hex(&file.salt, "salt")?;
+        let nonce: [u8; NONCE_LEN] = decode_hex(&file.nonce, "nonce")?;
+        let ciphertext = hex::decode(&file.ciphertext)
+            .map_err(|e| StateError::SerializationError(format!("Invalid ciphertext: {e}")))?;
+        let key = derive_key(passphrase, &salt, &file.key_derivation)?;
+
+        let plaintext = cipher_for(&file.algorithm, &key)?
+            .decrypt(&nonce, &ciphertext)
+            .ok_or_else(|| {
+                StateError::PermissionDenied(
+                    "Failed to decrypt API key file (wrong passphrase or corrupted file)"
+                        .to_string(),
+                )
+            })?;
+        let entries = serde_json::from_slice(&plaintext)
+            .map_err(|e| StateError::SerializationError(format!("Invalid key entries: {e}")))?;
+
+        Ok(Self {
+            path,
+            algorithm: file.algorithm,
+            key_derivation: file.key_derivation,
+            salt,
+            key,
+            entries,
+        })
+    }
+
+    /// Open or create an encrypted key file using the secret in `env_var`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `env_var` is unset, or as for [`Self::open`]
+    pub fn open_with_env_secret(
+        path: impl Into<PathBuf>,
+        env_var: &str,
+        config: &EncryptionConfig,
+    ) -> StateResult<Self> {
+        let secret = std::env::var(env_var).map_err(|_| {
+            StateError::ConfigurationError(format!(
+                "Environment variable {env_var} with the API key storage secret is not set"
+            ))
+        })?;
+        Self::open(path, &secret, config)
+    }
+
+    /// Path of the encrypted key file
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Encrypt all entries and atomically replace the key file
+    fn save(&self) -> Result<(), String> {
+        let plaintext = serde_json::to_vec(&self.entries)
+            .map_err(|e| format!("Failed to serialize API keys: {e}"))?;
+        let nonce = rand::random::<[u8; NONCE_LEN]>();
+        let ciphertext = cipher_for(&self.algorithm, &self.key)
+            .map_err(|e| e.to_string())?
+            .encrypt(&nonce, &plaintext)
+            .ok_or_else(|| "Failed to encrypt API keys".to_string())?;
+
+        let file = EncryptedKeyFile {
+            version: FILE_VERSION,
+            algorithm: self.algorithm.clone(),
+            key_derivation: self.key_derivation.clone(),
+            salt: hex::encode(self.salt),
+            nonce: hex::encode(nonce),
+            ciphertext: hex::encode(ciphertext),
+        };
+        let data = serde_json::to_vec_pretty(&file)
+            .map_err(|e| format!("Failed to serialize key file: {e}"))?;
+        write_file_atomic(&self.path, &data)
+            .map_err(|e| format!("Failed to write {}: {e}", self.path.display()))
+    }
+}
+
+impl fmt::Debug for PersistentApiKeyStorage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PersistentApiKeyStorage")
+            .field("path", &self.path)
+            .field("algorithm", &self.algorithm)
+            .field("keys", &self.entries.len())
+            .finish_non_exhaustive()
+    }
+}
+
+impl ApiKeyStorage for PersistentApiKeyStorage {
+    fn store(&mut self, key_id: &str, key: &str, metadata: &ApiKeyMetadata) -> Result<(), String> {
+        self.entries.insert(
+            key_id.to_string(),
+            StoredKey {
+                key: key.to_string(),
+                metadata: metadata.clone(),
+            },
+        );
+        self.save()
+    }
+
+    fn get(&self, key_id: &str) -> Result<Option<String>, String> {
+        Ok(self.entries.get(key_id).map(|entry| entry.key.clone()))
+    }
+
+    fn get_metadata(&self, key_id: &str) -> Result<Option<ApiKeyMetadata>, String> {
+        Ok(self.entries.get(key_id).map(|entry| entry.metadata.clone()))
+    }
+
+    fn update_metadata(&mut self, key_id: &str, metadata: &ApiKeyMetadata) -> Result<(), String> {
+        let entry = self
+            .entries
+            .get_mut(key_id)
+            .ok_or_else(|| format!("Key '{key_id}' not found"))?;
+        entry.metadata = metadata.clone();
+        self.save()
+    }
+
+    fn delete(&mut self, key_id: &str) -> Result<(), String> {
+        self.entries
+            .remove(key_id)
+            .ok_or_else(|| format!("Key '{key_id}' not found"))?;
+        self.save()
+    }
+
+    fn list_keys(&self) -> Result<Vec<String>, String> {
+        Ok(self.entries.keys().cloned().collect())
+    }
+}
+
+/// AEAD cipher selected by `EncryptionAlgorithm`
+enum KeyCipher {
+    ChaCha20Poly1305(ChaCha20Poly1305),
+    Aes256Gcm(Aes256Gcm),
+}
+
+impl KeyCipher {
+    fn encrypt(&self, nonce: &[u8; NONCE_LEN], plaintext: &[u8]) -> Option<Vec<u8>> {
+        let nonce = Nonce::from_slice(nonce);
+        match self {
+            Self::ChaCha20Poly1305(cipher) => cipher.encrypt(nonce, plaintext).ok(),
+            Self::Aes256Gcm(cipher) => cipher.encrypt(nonce, plaintext).ok(),
+        }
+    }
+
+    fn decrypt(&self, nonce: &[u8; NONCE_LEN], ciphertext: &[u8]) -> Option<Vec<u8>> {
+        let nonce = Nonce::from_slice(nonce);
+        match self {
+            Self::ChaCha20Poly1305(cipher) => cipher.decrypt(nonce, ciphertext).ok(),
+            Self::Aes256Gcm(cipher) => cipher.decrypt(nonce, ciphertext).ok(),
+        }
+    }
+}
+
+fn cipher_for(algorithm: &EncryptionAlgorithm, key: &[u8; KEY_LEN]) -> StateResult<KeyCipher> {
+    let invalid = |e| StateError::Internal(format!("Invalid encryption key: {e}"));
+    Ok(match algorithm {
+        EncryptionAlgorithm::ChaCha20Poly1305 => {
+            KeyCipher::ChaCha20Poly1305(ChaCha20Poly1305::new_from_slice(key).map_err(invalid)?)
+        }
+        EncryptionAlgorithm::AES256GCM => {
+            KeyCipher::Aes256Gcm(Aes256Gcm::new_from_slice(key).map_err(invalid)?)
+        }
+    })
+}
+
+/// Derive the encryption key from a passphrase
+fn derive_key(
+    passphrase: &str,
+    salt: &[u8],
+    config: &KeyDerivationConfig,
+) -> StateResult<[u8; KEY_LEN]> {
+    match config.method.to_ascii_lowercase().as_str() {
+        "pbkdf2" | "pbkdf2-sha256" | "pbkdf2_sha256" => {}
+        other => {
+            return Err(StateError::ConfigurationError(format!(
+                "Unsupported key derivation method '{other}'"
+            )))
+        }
+    }
+    if config.iterations == 0 {
+        return Err(StateError::ConfigurationError(
+            "Key derivation iterations must be greater than zero".to_string(),
+        ));
+    }
+
+    let mut key = [0u8; KEY_LEN];
+    pbkdf2::pbkdf2_hmac::<Sha256>(passphrase.as_bytes(), salt, config.iterations, &mut key);
+    Ok(key)
+}
+
+fn decode_hex<const N: usize>(value: &str, field: &str) -> StateResult<[u8; N]> {
+    hex::decode(value)
+        .ok()
+        .and_then(|bytes| bytes.try_into().ok())
+        .ok_or_else(|| StateError::SerializationError(format!("Invalid {field} in key file")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use std::time::Duration;
+
+    fn encryption_config(algorithm: EncryptionAlgorithm) -> EncryptionConfig {
+        EncryptionConfig {
+            algorithm,
+            key_derivation: KeyDerivationConfig {
+                method: "pbkdf2-sha256".to_string(),
+                iterations: 1_000,
+            },
+            rotation_interval: Duration::from_secs(86_400),
+        }
+    }
+
+    fn metadata(key_id: &str) -> ApiKeyMetadata {
+        ApiKeyMetadata {
+            key_id: key_id.to_string(),
+            service: "openai".to_string(),
+            created_at: Utc::now(),
+            last_used: None,
+            expires_at: None,
+            is_active: true,
+            usage_count: 0,
+            rotated_to: None,
+        }
+    }
+
+    #[test]
+    fn test_keys_encrypted_at_rest_and_reloaded() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("api_keys.enc");
+        let secret = "sk-live-9f8e7d6c5b4a";
+
+        for algorithm in [
+            EncryptionAlgorithm::ChaCha20Poly1305,
+            EncryptionAlgorithm::AES256GCM,
+        ] {
+            let _ = std::fs::remove_file(&path);
+            let config = encryption_config(algorithm);
+
+            let mut storage =
+                PersistentApiKeyStorage::open(&path, "correct horse", &config).unwrap();
+            storage
+                .store("openai_prod", secret, &metadata("openai_prod"))
+                .unwrap();
+            drop(storage);
+
+            let raw = std::fs::read_to_string(&path).unwrap();
+            assert!(!raw.contains(secret));
+            assert!(!raw.contains(&hex::encode(secret)));
+
+            let reloaded = PersistentApiKeyStorage::open(&path, "correct horse", &config).unwrap();
+            assert_eq!(
+                reloaded.get("openai_prod").unwrap().as_deref(),
+                Some(secret)
+            );
+            assert_eq!(
+                reloaded
+                    .get_metadata("openai_prod")
+                    .unwrap()
+                    .unwrap()
+                    .service,
+                "openai"
+            );
+
+            let wrong = PersistentApiKeyStorage::open(&path, "wrong passphrase", &config);
+            assert!(matches!(wrong, Err(StateError::PermissionDenied(_))));
+        }
+    }
+}
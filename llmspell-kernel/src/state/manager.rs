@@ -173,6 +173,12 @@ pub struct StateManager {
 
     // Memory manager for state-memory synchronization (Phase 13.7.4)
     memory_manager: Option<Arc<dyn llmspell_memory::MemoryManager>>,
+
+    // Serializes compare-and-swap so the read, swap and persist happen as one step
+    cas_lock: tokio::sync::Mutex<()>,
+
+    // Expiration times for keys set with a TTL, keyed by scoped key
+    expirations: Arc<RwLock<HashMap<String, SystemTime>>>,
 }
 
 impl std::fmt::Debug for StateManager {
@@ -260,6 +266,8 @@ impl StateManager {
             async_hook_processor: None,
             artifact_correlation_manager: Arc::new(ArtifactCorrelationManager::new()),
             memory_manager: None, // No memory manager for benchmarks
+            cas_lock: tokio::sync::Mutex::new(()),
+            expirations: Arc::new(RwLock::new(HashMap::new())),
         })
     }
 
@@ -388,6 +396,8 @@ impl StateManager {
             async_hook_processor,
             artifact_correlation_manager: Arc::new(ArtifactCorrelationManager::new()),
             memory_manager,
+            cas_lock: tokio::sync::Mutex::new(()),
+            expirations: Arc::new(RwLock::new(HashMap::new())),
         })
     }
 
@@ -749,6 +759,12 @@ impl StateManager {
         value: Value,
         class: Option<StateClass>,
     ) -> StateResult<()> {
+        // A plain set replaces any earlier TTL
+        if !self.expirations.read().is_empty() {
+            let scoped_key = KeyManager::create_scoped_key(&scope, key)?;
+            self.expirations.write().remove(&scoped_key);
+        }
+
         // Determine state class
         let state_class = class.unwrap_or_else(|| {
             // Infer from key patterns for benchmarks and common cases
@@ -784,6 +800,97 @@ impl StateManager {
         }
     }
 
+    /// Set state value that expires after `ttl`
+    ///
+    /// Once expired the key reads as absent and is purged lazily on access, or
+    /// eagerly via [`StateManager::purge_expired`]. Expiry times are kept in memory
+    /// only, so a restarted manager treats previously persisted keys as permanent.
+    ///
+    /// # Errors
+    ///
+    /// Returns `StateError` if:
+    /// - Key validation fails
+    /// - Failed to store value in storage backend
+    /// - Hook execution fails
+    #[instrument(level = "trace", skip(self, value), fields(scope = ?scope, key = %key, ttl = ?ttl))]
+    pub async fn set_with_ttl(
+        &self,
+        scope: StateScope,
+        key: &str,
+        value: Value,
+        ttl: Duration,
+    ) -> StateResult<()> {
+        let scoped_key = KeyManager::create_scoped_key(&scope, key)?;
+        self.set(scope, key, value).await?;
+        self.expirations
+            .write()
+            .insert(scoped_key, SystemTime::now() + ttl);
+        Ok(())
+    }
+
+    /// Remove every expired key, returning how many were purged
+    ///
+    /// # Errors
+    ///
+    /// Returns `StateError` if deleting a key from the storage backend fails
+    pub async fn purge_expired(&self) -> StateResult<usize> {
+        let now = SystemTime::now();
+        let expired: Vec<String> = {
+            let mut expirations = self.expirations.write();
+            let expired = expirations
+                .iter()
+                .filter(|(_, expires_at)| **expires_at <= now)
+                .map(|(key, _)| key.clone())
+                .collect::<Vec<_>>();
+            for key in &expired {
+                expirations.remove(key);
+            }
+            expired
+        };
+
+        for scoped_key in &expired {
+            self.remove_scoped_key(scoped_key).await?;
+        }
+
+        if !expired.is_empty() {
+            debug!("Purged {} expired state keys", expired.len());
+        }
+        Ok(expired.len())
+    }
+
+    /// Purge a key if its TTL has elapsed, returning whether it was expired
+    async fn purge_if_expired(&self, scope: &StateScope, key: &str) -> StateResult<bool> {
+        if self.expirations.read().is_empty() {
+            return Ok(false);
+        }
+
+        let scoped_key = KeyManager::create_scoped_key(scope, key)?;
+        let expired = {
+            let mut expirations = self.expirations.write();
+            match expirations.get(&scoped_key) {
+                Some(expires_at) if *expires_at <= SystemTime::now() => {
+                    expirations.remove(&scoped_key);
+                    true
+                }
+                _ => false,
+            }
+        };
+
+        if expired {
+            self.remove_scoped_key(&scoped_key).await?;
+        }
+        Ok(expired)
+    }
+
+    /// Remove a scoped key from memory and storage
+    async fn remove_scoped_key(&self, scoped_key: &str) -> StateResult<()> {
+        self.in_memory.write().remove(scoped_key);
+        if self.persistence_config.enabled {
+            self.storage_adapter.delete(scoped_key).await?;
+        }
+        Ok(())
+    }
+
     /// Fast path for trusted data with minimal overhead
     #[instrument(level = "trace", skip(self, value), fields(
         scope = ?scope,
@@ -880,6 +987,10 @@ impl StateManager {
         key: &str,
         class: Option<StateClass>,
     ) -> StateResult<Option<Value>> {
+        if self.purge_if_expired(&scope, key).await? {
+            return Ok(None);
+        }
+
         // Determine state class
         let state_class = class.unwrap_or_else(|| StateClass::infer_from_key(key));
 
@@ -963,6 +1074,120 @@ impl StateManager {
         Ok(None)
     }
 
+    /// Atomically replace a value only if it currently equals `expected`
+    ///
+    /// `expected = None` means the key must not exist yet. Returns whether the swap
+    /// happened. The comparison and write are done under the in-memory lock, so
+    /// concurrent read-modify-write callers never clobber each other. State change
+    /// hooks are not run for swaps.
+    ///
+    /// # Errors
+    ///
+    /// Returns `StateError` if:
+    /// - Key validation fails
+    /// - The key is ephemeral (`temp:`/`cache:`), which bypasses the shared cache
+    /// - Failed to load or store the value in the storage backend
+    #[instrument(level = "trace", skip(self, expected, new), fields(scope = ?scope, key = %key))]
+    pub async fn compare_and_swap(
+        &self,
+        scope: StateScope,
+        key: &str,
+        expected: Option<Value>,
+        new: Value,
+    ) -> StateResult<bool> {
+        let swapped = self
+            .update_atomically(scope, key, "compare_and_swap", |current| {
+                Ok((current == expected.as_ref()).then_some(new))
+            })
+            .await?;
+        Ok(swapped.is_some())
+    }
+
+    /// Atomically add `delta` to an integer counter and return the new total
+    ///
+    /// A missing key counts as 0. Shares the compare-and-swap lock, so concurrent
+    /// increments and swaps on the same key never lose updates.
+    ///
+    /// # Errors
+    ///
+    /// Returns `StateError` if:
+    /// - Key validation fails
+    /// - The key is ephemeral (`temp:`/`cache:`), which bypasses the shared cache
+    /// - The current value is not an integer, or the addition overflows
+    /// - Failed to load or store the value in the storage backend
+    #[instrument(level = "trace", skip(self), fields(scope = ?scope, key = %key, delta = delta))]
+    pub async fn increment(&self, scope: StateScope, key: &str, delta: i64) -> StateResult<i64> {
+        let updated = self
+            .update_atomically(scope, key, "increment", |current| {
+                let total = match current {
+                    None => 0,
+                    Some(value) => value.as_i64().ok_or_else(|| {
+                        StateError::validation_error(format!(
+                            "Cannot increment non-integer value at '{key}'"
+                        ))
+                    })?,
+                };
+                let total = total.checked_add(delta).ok_or_else(|| {
+                    StateError::validation_error(format!("Increment overflow at '{key}'"))
+                })?;
+                Ok(Some(json!(total)))
+            })
+            .await?;
+        Ok(updated.and_then(|v| v.as_i64()).unwrap_or_default())
+    }
+
+    /// Read-modify-write a key under the compare-and-swap lock
+    ///
+    /// `update` sees the current value and returns the value to store, or `None` to
+    /// leave it untouched. Returns the stored value, if any.
+    async fn update_atomically<F>(
+        &self,
+        scope: StateScope,
+        key: &str,
+        operation: &str,
+        update: F,
+    ) -> StateResult<Option<Value>>
+    where
+        F: FnOnce(Option<&Value>) -> StateResult<Option<Value>>,
+    {
+        if StateClass::infer_from_key(key) == StateClass::Ephemeral {
+            return Err(StateError::validation_error(format!(
+                "{operation} is not supported for ephemeral key '{key}'"
+            )));
+        }
+        let scoped_key = KeyManager::create_scoped_key(&scope, key)?;
+
+        let _guard = self.cas_lock.lock().await;
+
+        // Warm the cache so the update also sees persisted values
+        self.get_with_class(scope, key, Some(StateClass::Standard))
+            .await?;
+
+        let new = {
+            let mut memory = self.in_memory.write();
+            let Some(new) = update(memory.get(&scoped_key))? else {
+                return Ok(None);
+            };
+            memory.insert(scoped_key.clone(), new.clone());
+            new
+        };
+
+        if self.persistence_config.enabled {
+            let serialized_state = SerializableState {
+                key: scoped_key.clone(),
+                value: new.clone(),
+                timestamp: SystemTime::now(),
+                schema_version: self.state_schema.version,
+            };
+
+            self.storage_adapter
+                .store(&scoped_key, &serialized_state)
+                .await?;
+        }
+
+        Ok(Some(new))
+    }
+
     /// Delete state value
     ///
     /// # Errors
@@ -974,6 +1199,7 @@ impl StateManager {
     #[instrument(level = "debug", skip(self), fields(scope = ?scope, key = %key))]
     pub async fn delete(&self, scope: StateScope, key: &str) -> StateResult<bool> {
         let scoped_key = KeyManager::create_scoped_key(&scope, key)?;
+        self.expirations.write().remove(&scoped_key);
 
         // Remove from memory
         let existed = {
@@ -1602,6 +1828,9 @@ impl StateManager {
     /// - Key validation fails
     /// - Failed to check existence in storage backend
     pub async fn exists_in_scope(&self, scope: StateScope, key: &str) -> StateResult<bool> {
+        if self.purge_if_expired(&scope, key).await? {
+            return Ok(false);
+        }
         let scoped_key = KeyManager::create_scoped_key(&scope, key)?;
 
         // Check memory first
@@ -2292,4 +2521,103 @@ mod tests {
             .await
             .unwrap();
     }
+    #[tokio::test]
+    async fn test_compare_and_swap_race() {
+        let manager = Arc::new(StateManager::new(None).await.unwrap());
+
+        assert!(manager
+            .compare_and_swap(StateScope::Global, "counter", None, json!(0))
+            .await
+            .unwrap());
+
+        for expected in 0..10 {
+            let tasks: Vec<_> = (0..2)
+                .map(|_| {
+                    let manager = manager.clone();
+                    tokio::spawn(async move {
+                        manager
+                            .compare_and_swap(
+                                StateScope::Global,
+                                "counter",
+                                Some(json!(expected)),
+                                json!(expected + 1),
+                            )
+                            .await
+                            .unwrap()
+                    })
+                })
+                .collect();
+
+            let mut successes = 0;
+            for task in tasks {
+                if task.await.unwrap() {
+                    successes += 1;
+                }
+            }
+            assert_eq!(successes, 1, "exactly one swap should win for {expected}");
+        }
+
+        let value = manager.get(StateScope::Global, "counter").await.unwrap();
+        assert_eq!(value, Some(json!(10)));
+    }
+    #[tokio::test]
+    async fn test_set_with_ttl_expires() {
+        let manager = StateManager::new(None).await.unwrap();
+
+        manager
+            .set_with_ttl(
+                StateScope::Global,
+                "scratch",
+                json!("value"),
+                Duration::from_millis(50),
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            manager.get(StateScope::Global, "scratch").await.unwrap(),
+            Some(json!("value"))
+        );
+
+        tokio::time::sleep(Duration::from_millis(100)).await;
+
+        assert_eq!(
+            manager.get(StateScope::Global, "scratch").await.unwrap(),
+            None
+        );
+        assert!(!manager
+            .exists_in_scope(StateScope::Global, "scratch")
+            .await
+            .unwrap());
+    }
+    #[tokio::test]
+    async fn test_concurrent_increment() {
+        let manager = Arc::new(StateManager::new(None).await.unwrap());
+
+        let tasks: Vec<_> = (0..100)
+            .map(|_| {
+                let manager = manager.clone();
+                tokio::spawn(async move {
+                    manager
+                        .increment(StateScope::Global, "requests", 1)
+                        .await
+                        .unwrap()
+                })
+            })
+            .collect();
+        for task in tasks {
+            task.await.unwrap();
+        }
+
+        let value = manager.get(StateScope::Global, "requests").await.unwrap();
+        assert_eq!(value, Some(json!(100)));
+
+        manager
+            .set(StateScope::Global, "label", json!("text"))
+            .await
+            .unwrap();
+        assert!(manager
+            .increment(StateScope::Global, "label", 1)
+            .await
+            .is_err());
+    }
 }
@@ -3,15 +3,19 @@
 
 use super::{
     events::{MigrationEvent, MigrationEventBuilder},
-    MigrationConfig, MigrationContext, MigrationResult, ValidationLevel,
+    transforms::{DataTransformer, StateTransformation},
+    MigrationConfig, MigrationContext, MigrationPreview, MigrationResult, MigrationSample,
+    ValidationLevel,
 };
 use crate::state::backend_adapter::StateStorageAdapter;
+use crate::state::config::MigrationStep;
 use crate::state::manager::SerializableState;
 use crate::state::schema::{MigrationPlan, MigrationPlanner, SchemaRegistry, SemanticVersion};
 use crate::state::{StateError, StateResult};
 use llmspell_events::{EventBus, EventCorrelationTracker, UniversalEvent};
 use llmspell_hooks::{ComponentId, ComponentType, HookContext, HookExecutor, HookPoint};
 use parking_lot::RwLock;
+use serde_json::Value;
 use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::{Duration, Instant};
@@ -71,6 +75,18 @@ pub struct MigrationEngine {
     active_migrations: Arc<RwLock<HashMap<Uuid, MigrationContext>>>,
 }
 
+/// Outcome of running a migration's transformations over one state key
+enum ItemOutcome {
+    /// Key holds no state
+    Missing,
+    /// Transformations left the state unchanged
+    Unchanged,
+    /// Transformations changed the state
+    Changed { before: Value, after: Value },
+    /// The transformer rejected the state
+    Failed(String),
+}
+
 /// Parameters for handling migration success
 struct MigrationSuccessParams<'a> {
     result: &'a mut MigrationResult,
@@ -99,6 +115,9 @@ struct MigrationFailureParams<'a> {
 }
 
 impl MigrationEngine {
+    /// Maximum before/after samples collected by [`dry_run`](Self::dry_run)
+    pub const DRY_RUN_SAMPLE_LIMIT: usize = 10;
+
     /// Create new migration engine with existing infrastructure
     pub fn new(
         storage_adapter: Arc<StateStorageAdapter>,
@@ -187,6 +206,51 @@ impl MigrationEngine {
         Ok(result)
     }
 
+    /// Preview a migration plan without writing to storage
+    ///
+    /// Runs every step's transformations in memory over each state key and
+    /// reports affected, changed and errored keys with a sample of changed values.
+    ///
+    /// # Errors
+    ///
+    /// Returns `StateError` if state keys cannot be listed
+    pub async fn dry_run(&self, plan: &MigrationPlan) -> StateResult<MigrationResult> {
+        let start_time = Instant::now();
+        let config = MigrationConfig {
+            dry_run: true,
+            ..MigrationConfig::default()
+        };
+        let transformations: Vec<_> = plan
+            .steps
+            .iter()
+            .enumerate()
+            .map(|(step_index, step)| Self::step_transformation(step_index, step))
+            .collect();
+
+        let preview = self
+            .traverse_state_items(&transformations, &config, false)
+            .await?;
+
+        info!(
+            "Dry run from {} to {}: {} keys affected, {} changed, {} errored",
+            plan.from_version,
+            plan.to_version,
+            preview.affected_keys,
+            preview.changed_keys,
+            preview.errored_keys
+        );
+
+        let mut result = MigrationResult::new(
+            plan.from_version.clone(),
+            plan.to_version.clone(),
+            plan.steps.len(),
+        );
+        result.warnings.extend(plan.warnings.iter().cloned());
+        result.mark_completed(0, start_time.elapsed());
+        result.preview = Some(preview);
+        Ok(result)
+    }
+
     /// Execute the migration plan step by step
     async fn execute_migration_plan(
         &self,
@@ -214,12 +278,7 @@ impl MigrationEngine {
             context.current_step = step_index + 1;
 
             // Create transformation for this step
-            let transformation = super::transforms::StateTransformation::new(
-                format!("migration_step_{step_index}"),
-                step.description.clone(),
-                step.from_version,
-                step.to_version,
-            );
+            let transformation = Self::step_transformation(step_index, step);
 
             // Execute transformation
             let items_migrated = self
@@ -239,10 +298,20 @@ impl MigrationEngine {
         Ok(total_items_migrated)
     }
 
+    /// Build the transformation for a plan step, shared by `migrate` and `dry_run`
+    fn step_transformation(step_index: usize, step: &MigrationStep) -> StateTransformation {
+        StateTransformation::new(
+            format!("migration_step_{step_index}"),
+            step.description.clone(),
+            step.from_version,
+            step.to_version,
+        )
+    }
+
     /// Execute a single transformation step
     async fn execute_transformation(
         &self,
-        transformation: &super::transforms::StateTransformation,
+        transformation: &StateTransformation,
         config: &MigrationConfig,
     ) -> Result<usize, MigrationEngineError> {
         if config.dry_run {
@@ -250,7 +319,20 @@ impl MigrationEngine {
             return Ok(0);
         }
 
-        let mut items_processed = 0;
+        let report = self
+            .traverse_state_items(std::slice::from_ref(transformation), config, true)
+            .await?;
+        Ok(report.changed_keys)
+    }
+
+    /// Run transformations over every state key, writing changes back when `apply` is set
+    async fn traverse_state_items(
+        &self,
+        transformations: &[StateTransformation],
+        config: &MigrationConfig,
+        apply: bool,
+    ) -> Result<MigrationPreview, MigrationEngineError> {
+        let mut report = MigrationPreview::default();
 
         // Get all state keys to migrate
         let all_keys = self.storage_adapter.list_keys("").await.map_err(|e| {
@@ -267,15 +349,29 @@ impl MigrationEngine {
                     continue;
                 }
 
-                match self.transform_state_item(key, transformation).await {
-                    Ok(transformed) => {
-                        if transformed {
-                            items_processed += 1;
+                match self.transform_state_item(key, transformations, apply).await {
+                    Ok(ItemOutcome::Missing) => {}
+                    Ok(ItemOutcome::Unchanged) => report.affected_keys += 1,
+                    Ok(ItemOutcome::Changed { before, after }) => {
+                        report.affected_keys += 1;
+                        report.changed_keys += 1;
+                        if !apply && report.samples.len() < Self::DRY_RUN_SAMPLE_LIMIT {
+                            report.samples.push(MigrationSample {
+                                key: key.clone(),
+                                before,
+                                after,
+                            });
                         }
                     }
+                    Ok(ItemOutcome::Failed(e)) => {
+                        warn!("State transformation failed for key '{}': {}", key, e);
+                        report.affected_keys += 1;
+                        report.errored_keys += 1;
+                    }
                     Err(e) => {
                         warn!("Failed to transform state item '{}': {}", key, e);
-                        if config.validation_level == ValidationLevel::Strict {
+                        report.errored_keys += 1;
+                        if apply && config.validation_level == ValidationLevel::Strict {
                             return Err(MigrationEngineError::MigrationFailed {
                                 reason: format!("Transformation failed for key '{key}': {e}"),
                             });
@@ -285,40 +381,44 @@ impl MigrationEngine {
             }
         }
 
-        Ok(items_processed)
+        Ok(report)
     }
 
-    /// Transform a single state item
+    /// Transform a single state item, storing the result when `apply` is set
     async fn transform_state_item(
         &self,
         key: &str,
-        transformation: &super::transforms::StateTransformation,
-    ) -> StateResult<bool> {
+        transformations: &[StateTransformation],
+        apply: bool,
+    ) -> StateResult<ItemOutcome> {
         // Load the current state item
-        let current_state: Option<SerializableState> = self.storage_adapter.load(key).await?;
-
-        if let Some(mut state) = current_state {
-            // Use the DataTransformer to transform the state
-            let transformer = super::transforms::DataTransformer::new();
+        let Some(mut state) = self.storage_adapter.load::<SerializableState>(key).await? else {
+            return Ok(ItemOutcome::Missing);
+        };
+        let before = state.value.clone();
 
+        // Use the DataTransformer to transform the state in memory
+        let transformer = DataTransformer::new();
+        let mut changed = false;
+        for transformation in transformations {
             match transformer.transform_state(&mut state, transformation) {
-                Ok(result) => {
-                    if result.success && result.fields_transformed > 0 {
-                        // Store the transformed state
-                        self.storage_adapter.store(key, &state).await?;
-                        Ok(true)
-                    } else {
-                        Ok(false)
-                    }
-                }
-                Err(e) => {
-                    warn!("State transformation failed for key '{}': {}", key, e);
-                    Ok(false)
-                }
+                Ok(result) => changed |= result.success && result.fields_transformed > 0,
+                Err(e) => return Ok(ItemOutcome::Failed(e.to_string())),
             }
-        } else {
-            Ok(false)
         }
+
+        if !changed {
+            return Ok(ItemOutcome::Unchanged);
+        }
+
+        if apply {
+            // Store the transformed state
+            self.storage_adapter.store(key, &state).await?;
+        }
+        Ok(ItemOutcome::Changed {
+            before,
+            after: state.value,
+        })
     }
 
     /// Execute migration hooks
@@ -764,6 +864,109 @@ mod tests {
 
         assert!(engine.get_active_migrations().is_empty());
     }
+    #[tokio::test]
+    async fn test_dry_run_reports_without_writing() {
+        use crate::state::config::FieldSchema;
+        use crate::state::migration::transforms::FieldTransform;
+        use crate::state::schema::EnhancedStateSchema;
+
+        let storage_adapter = Arc::new(StateStorageAdapter::new(
+            Arc::new(llmspell_storage::MemoryBackend::new()),
+            "test".to_string(),
+        ));
+
+        let v1 = SemanticVersion::new(1, 0, 0);
+        let v1_1 = SemanticVersion::new(1, 1, 0);
+        let field = |default_value| FieldSchema {
+            field_type: "number".to_string(),
+            required: false,
+            default_value,
+            validators: vec![],
+        };
+        let mut schema_v1 = EnhancedStateSchema::new(v1.clone());
+        schema_v1.add_field("count".to_string(), field(None));
+        let mut schema_v1_1 = EnhancedStateSchema::new(v1_1.clone());
+        schema_v1_1.add_field("count".to_string(), field(None));
+        schema_v1_1.add_field("limit".to_string(), field(Some(serde_json::json!(10))));
+
+        let schema_registry = SchemaRegistry::new();
+        schema_registry.register_schema(schema_v1, None).unwrap();
+        schema_registry.register_schema(schema_v1_1, None).unwrap();
+
+        let engine = MigrationEngine::new(
+            storage_adapter.clone(),
+            schema_registry,
+            Arc::new(HookExecutor::new()),
+            Arc::new(EventCorrelationTracker::default()),
+            Arc::new(EventBus::new()),
+        );
+
+        // Two states lack the new field, one already has it
+        let values = [
+            ("counter:a", serde_json::json!({"count": 1})),
+            ("counter:b", serde_json::json!({"count": 2})),
+            ("counter:c", serde_json::json!({"count": 3, "limit": 5})),
+        ];
+        for (key, value) in &values {
+            let state = SerializableState {
+                key: (*key).to_string(),
+                value: value.clone(),
+                timestamp: std::time::SystemTime::now(),
+                schema_version: 1,
+            };
+            storage_adapter.store(key, &state).await.unwrap();
+        }
+
+        let plan = engine.create_migration_plan(&v1, &v1_1).unwrap();
+        let result = engine.dry_run(&plan).await.unwrap();
+
+        // Dry run uses the same step transformations as `migrate`, which
+        // carry no field transforms: every key is affected, none changes
+        let preview = result.preview.unwrap();
+        assert_eq!(preview.affected_keys, 3);
+        assert_eq!(preview.changed_keys, 0);
+        assert_eq!(preview.errored_keys, 0);
+        assert!(preview.samples.is_empty());
+
+        // The same dry-run traversal with a value-changing transformation
+        // reports the keys it would change
+        let mut transformation = StateTransformation::new(
+            "add_limit".to_string(),
+            "Default the new limit field".to_string(),
+            1,
+            2,
+        );
+        transformation.add_transform(FieldTransform::Default {
+            field: "limit".to_string(),
+            value: serde_json::json!(10),
+        });
+        let config = MigrationConfig {
+            dry_run: true,
+            ..MigrationConfig::default()
+        };
+        let preview = engine
+            .traverse_state_items(&[transformation], &config, false)
+            .await
+            .unwrap();
+        assert_eq!(preview.affected_keys, 3);
+        assert_eq!(preview.changed_keys, 2);
+        assert_eq!(preview.errored_keys, 0);
+        let mut sampled: Vec<&str> = preview.samples.iter().map(|s| s.key.as_str()).collect();
+        sampled.sort_unstable();
+        assert_eq!(sampled, vec!["counter:a", "counter:b"]);
+        for sample in &preview.samples {
+            assert!(sample.before.get("limit").is_none());
+            assert_eq!(sample.after["limit"], serde_json::json!(10));
+            assert_eq!(sample.after["count"], sample.before["count"]);
+        }
+
+        // Storage is untouched
+        for (key, value) in &values {
+            let state: SerializableState = storage_adapter.load(key).await.unwrap().unwrap();
+            assert_eq!(&state.value, value);
+            assert_eq!(state.schema_version, 1);
+        }
+    }
     #[test]
     fn test_migration_engine_error_conversion() {
         let error = MigrationEngineError::MigrationFailed {
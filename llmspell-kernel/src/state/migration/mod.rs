@@ -42,6 +42,8 @@ pub struct MigrationResult {
     pub duration: std::time::Duration,
     pub warnings: Vec<String>,
     pub errors: Vec<String>,
+    /// Preview of affected keys, set by dry runs
+    pub preview: Option<MigrationPreview>,
 }
 
 impl MigrationResult {
@@ -60,6 +62,7 @@ impl MigrationResult {
             duration: std::time::Duration::from_secs(0),
             warnings: Vec::new(),
             errors: Vec::new(),
+            preview: None,
         }
     }
 
@@ -99,6 +102,27 @@ impl MigrationResult {
     }
 }
 
+/// Before and after values of a key a migration would change
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MigrationSample {
+    pub key: String,
+    pub before: serde_json::Value,
+    pub after: serde_json::Value,
+}
+
+/// Keys a migration touches, as reported by a dry run
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MigrationPreview {
+    /// State keys the migration would process
+    pub affected_keys: usize,
+    /// Keys whose value would change
+    pub changed_keys: usize,
+    /// Keys the transformer failed on
+    pub errored_keys: usize,
+    /// Sample of changed values, capped at `MigrationEngine::DRY_RUN_SAMPLE_LIMIT`
+    pub samples: Vec<MigrationSample>,
+}
+
 /// Migration configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MigrationConfig {
@@ -34,6 +34,9 @@ pub mod backend_adapter;
 /// Comprehensive storage backends from consolidated crates
 pub mod backends;
 
+/// Encrypted file-backed storage for API keys
+pub mod api_key_storage;
+
 /// Backup and restore functionality for state persistence
 pub mod backup;
 
@@ -76,6 +79,7 @@ pub use types::{DebugState, ExecutionState, SessionState};
 pub use agent_state::{
     AgentMetadata, AgentStateData, MessageRole, PersistentAgentState, ToolUsageStats,
 };
+pub use api_key_storage::PersistentApiKeyStorage;
 pub use backend_adapter::StateStorageAdapter;
 pub use config::{
     BackupConfig, EncryptionConfig, PerformanceConfig, PersistenceConfig, SqliteConfig,
@@ -99,8 +103,8 @@ pub use llmspell_core::types::storage::{
 
 // Migration and schema types
 pub use migration::{
-    DataTransformer, MigrationConfig, MigrationEngine, MigrationResult, MigrationStatus,
-    ValidationLevel, ValidationResult,
+    DataTransformer, MigrationConfig, MigrationEngine, MigrationPreview, MigrationResult,
+    MigrationSample, MigrationStatus, ValidationLevel, ValidationResult,
 };
 pub use schema::{
     CompatibilityChecker, CompatibilityResult, EnhancedStateSchema, MigrationPlan,
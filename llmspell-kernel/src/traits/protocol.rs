@@ -23,6 +23,18 @@ pub trait Protocol: Send + Sync {
     /// Returns an error if the response cannot be created
     fn create_response(&self, msg_type: &str, content: Value) -> Result<Vec<u8>>;
 
+    /// Create a reply to a request, echoing the request's header as `parent_header`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the reply cannot be created
+    fn create_reply(
+        &self,
+        msg_type: &str,
+        parent_header: &Value,
+        content: Value,
+    ) -> Result<Vec<u8>>;
+
     /// Create a request message
     ///
     /// # Errors
@@ -121,7 +121,17 @@ pub fn create_transport(transport_type: &str) -> Result<BoxedTransport> {
                 Err(anyhow::anyhow!("ZeroMQ support not compiled in"))
             }
         }
-        "websocket" | "ws" => Err(anyhow::anyhow!("WebSocket support not yet implemented")),
+        "websocket" | "ws" => {
+            #[cfg(feature = "websocket")]
+            {
+                use crate::transport::websocket::WebSocketTransport;
+                Ok(Box::new(WebSocketTransport::new()))
+            }
+            #[cfg(not(feature = "websocket"))]
+            {
+                Err(anyhow::anyhow!("WebSocket support not compiled in"))
+            }
+        }
         _ => Err(anyhow::anyhow!("Unknown transport type: {transport_type}")),
     }
 }
@@ -16,9 +16,15 @@ pub mod zeromq;
 pub mod inprocess;
 pub mod jupyter;
 
+#[cfg(feature = "websocket")]
+pub mod websocket;
+
 // Re-export commonly used types
 #[cfg(feature = "zeromq")]
 pub use zeromq::ZmqTransport;
 
 pub use inprocess::InProcessTransport;
 pub use jupyter::JupyterTransport;
+
+#[cfg(feature = "websocket")]
+pub use websocket::{WebSocketConnectionInfo, WebSocketTransport, DEFAULT_MAX_FRAME_BYTES};
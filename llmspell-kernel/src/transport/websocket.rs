@@ -0,0 +1,687 @@
+//! WebSocket transport implementation
+//!
+//! This module provides a transport that carries all Jupyter channels (shell,
+//! iopub, stdin, control, heartbeat) over a single WebSocket connection, so
+//! browser-based frontends can drive the kernel. Like the other transports it
+//! knows NOTHING about protocol semantics - it only moves multipart messages.
+//!
+//! ## Framing
+//!
+//! Each multipart message travels as one binary WebSocket message, using an
+//! offset table modelled on Jupyter's `v1.kernel.websocket.jupyter.org`
+//! subprotocol:
+//!
+//! ```text
+//! [n: u64 LE][offset_0 .. offset_{n-1}: u64 LE][channel][part_1]...[part_{n-1}]
+//! ```
+//!
+//! Offsets are measured from the start of the message, and segment `i` runs
+//! from `offset_i` to the next offset (or the end of the message). The first
+//! segment is the channel name; the rest are the message parts.
+//!
+//! ## Routing
+//!
+//! In server mode any number of clients may connect. `iopub` messages are
+//! broadcast to every client; messages on other channels go to the client
+//! that last sent on that channel, mirroring ROUTER reply semantics.
+//!
+//! ## Size limits
+//!
+//! Frames larger than the configured `max_frame_bytes` are refused in both
+//! directions. Inbound, the limit is enforced by tungstenite from the frame
+//! header, before the payload buffer is allocated, so a malformed or hostile
+//! length cannot exhaust memory.
+
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use futures::{SinkExt, StreamExt};
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+use std::future::Future;
+use std::path::Path;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use tokio::io::{AsyncRead, AsyncWrite};
+use tokio::net::TcpListener;
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+use tokio_tungstenite::tungstenite::protocol::WebSocketConfig;
+use tokio_tungstenite::tungstenite::Message;
+use tokio_tungstenite::WebSocketStream;
+use tracing::{debug, info, instrument, trace, warn};
+
+use crate::traits::transport::BoundPorts;
+use crate::traits::{ChannelConfig, Transport, TransportConfig};
+
+/// Channels multiplexed on the WebSocket connection
+const JUPYTER_CHANNELS: [&str; 5] = ["shell", "iopub", "stdin", "control", "heartbeat"];
+
+/// Default maximum size of a single frame (64 MiB)
+pub const DEFAULT_MAX_FRAME_BYTES: usize = 64 * 1024 * 1024;
+
+/// WebSocket connection file structure
+///
+/// The WebSocket counterpart of `JupyterConnectionInfo`: all channels share
+/// one port, so only a single port is recorded.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WebSocketConnectionInfo {
+    /// Transport type (always "ws")
+    pub transport: String,
+    /// IP address to bind/connect to
+    pub ip: String,
+    /// Port carrying all channels
+    pub port: u16,
+    /// Authentication key for message signing
+    pub key: String,
+    /// Signature scheme (e.g., "hmac-sha256")
+    pub signature_scheme: String,
+    /// Kernel ID
+    pub kernel_id: String,
+}
+
+impl WebSocketConnectionInfo {
+    /// Create connection info; port 0 lets the OS assign one on bind
+    pub fn new(
+        ip: impl Into<String>,
+        port: u16,
+        key: impl Into<String>,
+        kernel_id: impl Into<String>,
+    ) -> Self {
+        Self {
+            transport: "ws".to_string(),
+            ip: ip.into(),
+            port,
+            key: key.into(),
+            signature_scheme: "hmac-sha256".to_string(),
+            kernel_id: kernel_id.into(),
+        }
+    }
+
+    /// WebSocket URL for clients
+    pub fn url(&self) -> String {
+        format!("ws://{}:{}", self.ip, self.port)
+    }
+
+    /// Build transport configuration with all five channels on the shared port
+    pub fn build_config(&self) -> TransportConfig {
+        let channels = JUPYTER_CHANNELS
+            .iter()
+            .map(|channel| {
+                (
+                    (*channel).to_string(),
+                    ChannelConfig {
+                        endpoint: self.port.to_string(),
+                        // Channels share one socket, so socket patterns do not apply
+                        pattern: String::new(),
+                        options: HashMap::new(),
+                    },
+                )
+            })
+            .collect();
+
+        TransportConfig {
+            transport_type: self.transport.clone(),
+            base_address: self.ip.clone(),
+            channels,
+            auth_key: Some(self.key.clone()),
+        }
+    }
+
+    /// Read connection info from a connection file
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or parsed
+    pub async fn from_connection_file(path: &Path) -> Result<Self> {
+        let contents = tokio::fs::read_to_string(path)
+            .await
+            .with_context(|| format!("Failed to read connection file: {}", path.display()))?;
+
+        serde_json::from_str(&contents)
+            .with_context(|| format!("Failed to parse connection file: {}", path.display()))
+    }
+
+    /// Write connection info to a connection file for client discovery
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be written
+    pub async fn write_connection_file(&self, path: &Path) -> Result<()> {
+        let json = serde_json::to_string_pretty(self)?;
+        tokio::fs::write(path, json)
+            .await
+            .with_context(|| format!("Failed to write connection file: {}", path.display()))
+    }
+}
+
+/// State shared between the transport handle and its connection tasks
+#[derive(Default)]
+struct Shared {
+    /// Configured channel names
+    channels: Mutex<Vec<String>>,
+    /// Received messages per channel, tagged with the sending peer
+    inbox: Mutex<HashMap<String, VecDeque<(u64, Vec<Vec<u8>>)>>>,
+    /// Outgoing message queues of connected peers
+    peers: Mutex<HashMap<u64, mpsc::UnboundedSender<Message>>>,
+    /// Peer that last sent on each channel, used to route replies
+    reply_to: Mutex<HashMap<String, u64>>,
+    /// Source of peer IDs
+    next_peer: AtomicU64,
+    /// Accept loop or client connection task
+    tasks: Mutex<Vec<JoinHandle<()>>>,
+    /// Connection info recorded on bind
+    connection_info: Mutex<Option<WebSocketConnectionInfo>>,
+}
+
+/// WebSocket transport multiplexing all channels on one connection
+#[derive(Clone)]
+pub struct WebSocketTransport {
+    shared: Arc<Shared>,
+    /// Largest frame accepted or sent, in bytes
+    max_frame_bytes: usize,
+}
+
+impl Default for WebSocketTransport {
+    fn default() -> Self {
+        Self {
+            shared: Arc::default(),
+            max_frame_bytes: DEFAULT_MAX_FRAME_BYTES,
+        }
+    }
+}
+
+impl WebSocketTransport {
+    /// Create a new, unbound WebSocket transport
+    #[instrument(level = "debug")]
+    pub fn new() -> Self {
+        debug!("Creating new WebSocket transport");
+        Self::default()
+    }
+
+    /// Set the largest frame accepted or sent; applies to later bind/connect
+    #[must_use]
+    pub const fn with_max_frame_bytes(mut self, max_frame_bytes: usize) -> Self {
+        self.max_frame_bytes = max_frame_bytes;
+        self
+    }
+
+    /// Largest frame accepted or sent, in bytes
+    pub const fn max_frame_bytes(&self) -> usize {
+        self.max_frame_bytes
+    }
+
+    /// tungstenite configuration enforcing the frame limit on reads
+    fn socket_config(&self) -> WebSocketConfig {
+        let mut config = WebSocketConfig::default();
+        config.max_message_size = Some(self.max_frame_bytes);
+        config.max_frame_size = Some(self.max_frame_bytes);
+        config
+    }
+
+    /// Create a transport bound according to connection info
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if binding fails
+    #[instrument(level = "info", skip_all)]
+    pub async fn from_connection_info(info: WebSocketConnectionInfo) -> Result<Self> {
+        let mut transport = Self::new();
+        let config = info.build_config();
+        *transport.shared.connection_info.lock() = Some(info);
+        transport.bind(&config).await?;
+        Ok(transport)
+    }
+
+    /// Create a transport bound according to a connection file
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the connection file cannot be read or binding fails
+    pub async fn from_connection_file(path: &Path) -> Result<Self> {
+        info!("Loading WebSocket connection from {:?}", path);
+        let info = WebSocketConnectionInfo::from_connection_file(path).await?;
+        Self::from_connection_info(info).await
+    }
+
+    /// Get connection info, with the actual bound port, once bound
+    pub fn connection_info(&self) -> Option<WebSocketConnectionInfo> {
+        self.shared.connection_info.lock().clone()
+    }
+
+    /// Number of connected peers
+    pub fn peer_count(&self) -> usize {
+        self.shared.peers.lock().len()
+    }
+
+    /// Shared port from the configuration (0 if unset)
+    fn config_port(config: &TransportConfig) -> Result<u16> {
+        let endpoint = config
+            .channels
+            .get("shell")
+            .or_else(|| config.channels.values().next())
+            .map_or("", |channel| channel.endpoint.as_str());
+
+        if endpoint.is_empty() {
+            return Ok(0);
+        }
+        endpoint
+            .parse()
+            .with_context(|| format!("Invalid WebSocket port: {endpoint}"))
+    }
+
+    fn set_channels(&self, config: &TransportConfig) {
+        let mut channels: Vec<String> = config.channels.keys().cloned().collect();
+        channels.sort();
+        *self.shared.channels.lock() = channels;
+    }
+
+    fn require_channel(&self, channel: &str) -> Result<()> {
+        if self.has_channel(channel) {
+            Ok(())
+        } else {
+            Err(anyhow::anyhow!("Channel {channel} not found"))
+        }
+    }
+}
+
+/// Register a connected peer and pump messages between it and the shared state
+///
+/// The peer is registered before this returns, so sends issued right after
+/// reach it even before the returned future is first polled.
+fn serve_peer<S>(
+    shared: Arc<Shared>,
+    socket: WebSocketStream<S>,
+    max_frame_bytes: usize,
+) -> impl Future<Output = ()>
+where
+    S: AsyncRead + AsyncWrite + Unpin + Send + 'static,
+{
+    let peer = shared.next_peer.fetch_add(1, Ordering::SeqCst);
+    let (tx, rx) = mpsc::unbounded_channel::<Message>();
+    shared.peers.lock().insert(peer, tx);
+    debug!("WebSocket peer {} connected", peer);
+
+    pump_peer(shared, socket, peer, rx, max_frame_bytes)
+}
+
+async fn pump_peer<S>(
+    shared: Arc<Shared>,
+    socket: WebSocketStream<S>,
+    peer: u64,
+    mut rx: mpsc::UnboundedReceiver<Message>,
+    max_frame_bytes: usize,
+) where
+    S: AsyncRead + AsyncWrite + Unpin + Send + 'static,
+{
+    let (mut sink, mut stream) = socket.split();
+    let writer = tokio::spawn(async move {
+        while let Some(message) = rx.recv().await {
+            if let Err(e) = sink.send(message).await {
+                debug!("WebSocket send failed: {}", e);
+                break;
+            }
+        }
+        let _ = sink.close().await;
+    });
+
+    while let Some(message) = stream.next().await {
+        match message {
+            Ok(Message::Binary(data)) => match decode_frame(&data, max_frame_bytes) {
+                Ok((channel, parts)) => {
+                    trace!("Peer {} sent {} parts on {}", peer, parts.len(), channel);
+                    shared
+                        .inbox
+                        .lock()
+                        .entry(channel)
+                        .or_default()
+                        .push_back((peer, parts));
+                }
+                Err(e) => warn!("Dropping malformed WebSocket frame: {}", e),
+            },
+            Ok(Message::Close(_)) => break,
+            // Pings are answered by tungstenite; text frames are not part of the framing
+            Ok(_) => {}
+            Err(e) => {
+                debug!("WebSocket peer {} read failed: {}", peer, e);
+                break;
+            }
+        }
+    }
+
+    shared.peers.lock().remove(&peer);
+    writer.abort();
+    debug!("WebSocket peer {} disconnected", peer);
+}
+
+/// Encode a channel and multipart message into one binary frame
+///
+/// Fails without allocating the frame if it would exceed `max_frame_bytes`.
+fn encode_frame(channel: &str, parts: &[Vec<u8>], max_frame_bytes: usize) -> Result<Vec<u8>> {
+    let segments: Vec<&[u8]> = std::iter::once(channel.as_bytes())
+        .chain(parts.iter().map(Vec::as_slice))
+        .collect();
+
+    let header_len = 8 * (segments.len() + 1);
+    let body_len: usize = segments.iter().map(|segment| segment.len()).sum();
+    if header_len + body_len > max_frame_bytes {
+        return Err(anyhow::anyhow!(
+            "Frame of {} bytes exceeds limit of {max_frame_bytes} bytes",
+            header_len + body_len
+        ));
+    }
+    let mut frame = Vec::with_capacity(header_len + body_len);
+
+    frame.extend_from_slice(&(segments.len() as u64).to_le_bytes());
+    let mut offset = header_len;
+    for segment in &segments {
+        frame.extend_from_slice(&(offset as u64).to_le_bytes());
+        offset += segment.len();
+    }
+    for segment in segments {
+        frame.extend_from_slice(segment);
+    }
+    Ok(frame)
+}
+
+/// Decode a binary frame into its channel and multipart message
+fn decode_frame(frame: &[u8], max_frame_bytes: usize) -> Result<(String, Vec<Vec<u8>>)> {
+    if frame.len() > max_frame_bytes {
+        return Err(anyhow::anyhow!(
+            "Frame of {} bytes exceeds limit of {max_frame_bytes} bytes",
+            frame.len()
+        ));
+    }
+    let read_u64 = |at: usize| -> Result<usize> {
+        let bytes: [u8; 8] = frame
+            .get(at..at + 8)
+            .and_then(|slice| slice.try_into().ok())
+            .ok_or_else(|| anyhow::anyhow!("Truncated frame header"))?;
+        usize::try_from(u64::from_le_bytes(bytes)).context("Frame offset out of range")
+    };
+
+    let count = read_u64(0)?;
+    if count == 0 {
+        return Err(anyhow::anyhow!("Frame has no channel segment"));
+    }
+    let header_len = count
+        .checked_add(1)
+        .and_then(|n| n.checked_mul(8))
+        .filter(|len| *len <= frame.len())
+        .ok_or_else(|| anyhow::anyhow!("Frame offset table exceeds frame length"))?;
+
+    let mut offsets = (1..=count)
+        .map(|i| read_u64(8 * i))
+        .collect::<Result<Vec<_>>>()?;
+    offsets.push(frame.len());
+
+    if offsets[0] < header_len || offsets.windows(2).any(|pair| pair[0] > pair[1]) {
+        return Err(anyhow::anyhow!("Frame offsets are out of order"));
+    }
+
+    let mut segments = offsets
+        .windows(2)
+        .map(|pair| frame[pair[0]..pair[1]].to_vec());
+    let channel = segments.next().unwrap_or_default();
+    let channel = String::from_utf8(channel).context("Channel name is not UTF-8")?;
+    Ok((channel, segments.collect()))
+}
+
+#[async_trait]
+impl Transport for WebSocketTransport {
+    #[instrument(level = "info", skip(self, config))]
+    async fn bind(&mut self, config: &TransportConfig) -> Result<Option<BoundPorts>> {
+        let port = Self::config_port(config)?;
+        let listener = TcpListener::bind((config.base_address.as_str(), port))
+            .await
+            .with_context(|| {
+                format!(
+                    "Failed to bind WebSocket transport to {}:{}",
+                    config.base_address, port
+                )
+            })?;
+        let port = listener
+            .local_addr()
+            .context("Failed to get bound WebSocket address")?
+            .port();
+        info!(
+            "WebSocket transport listening on ws://{}:{} for {} channels",
+            config.base_address,
+            port,
+            config.channels.len()
+        );
+
+        self.set_channels(config);
+        {
+            let mut connection_info = self.shared.connection_info.lock();
+            let info = connection_info.get_or_insert_with(|| {
+                WebSocketConnectionInfo::new(
+                    config.base_address.clone(),
+                    port,
+                    config.auth_key.clone().unwrap_or_default(),
+                    String::new(),
+                )
+            });
+            info.port = port;
+        }
+
+        let shared = self.shared.clone();
+        let socket_config = self.socket_config();
+        let max_frame_bytes = self.max_frame_bytes;
+        let accept_loop = tokio::spawn(async move {
+            loop {
+                match listener.accept().await {
+                    Ok((stream, addr)) => {
+                        let shared = shared.clone();
+                        tokio::spawn(async move {
+                            match tokio_tungstenite::accept_async_with_config(
+                                stream,
+                                Some(socket_config),
+                            )
+                            .await
+                            {
+                                Ok(socket) => serve_peer(shared, socket, max_frame_bytes).await,
+                                Err(e) => warn!("WebSocket handshake with {} failed: {}", addr, e),
+                            }
+                        });
+                    }
+                    Err(e) => warn!("Failed to accept WebSocket connection: {}", e),
+                }
+            }
+        });
+        self.shared.tasks.lock().push(accept_loop);
+
+        Ok(Some(BoundPorts {
+            shell: port,
+            iopub: port,
+            stdin: port,
+            control: port,
+            hb: port,
+        }))
+    }
+
+    #[instrument(level = "info", skip(self, config))]
+    async fn connect(&mut self, config: &TransportConfig) -> Result<()> {
+        let url = format!(
+            "ws://{}:{}",
+            config.base_address,
+            Self::config_port(config)?
+        );
+        info!("Connecting WebSocket transport to {}", url);
+
+        let (socket, _) = tokio_tungstenite::connect_async_with_config(
+            url.as_str(),
+            Some(self.socket_config()),
+            false,
+        )
+        .await
+        .with_context(|| format!("Failed to connect to {url}"))?;
+
+        self.set_channels(config);
+        let connection = tokio::spawn(serve_peer(
+            self.shared.clone(),
+            socket,
+            self.max_frame_bytes,
+        ));
+        self.shared.tasks.lock().push(connection);
+        Ok(())
+    }
+
+    async fn recv(&self, channel: &str) -> Result<Option<Vec<Vec<u8>>>> {
+        self.require_channel(channel)?;
+
+        let received = self
+            .shared
+            .inbox
+            .lock()
+            .get_mut(channel)
+            .and_then(VecDeque::pop_front);
+
+        Ok(received.map(|(peer, parts)| {
+            self.shared
+                .reply_to
+                .lock()
+                .insert(channel.to_string(), peer);
+            parts
+        }))
+    }
+
+    async fn send(&self, channel: &str, parts: Vec<Vec<u8>>) -> Result<()> {
+        self.require_channel(channel)?;
+
+        let frame = encode_frame(channel, &parts, self.max_frame_bytes)?;
+        let reply_to = if channel == "iopub" {
+            None
+        } else {
+            self.shared.reply_to.lock().get(channel).copied()
+        };
+
+        let peers = self.shared.peers.lock();
+        let targets: Vec<&mpsc::UnboundedSender<Message>> =
+            match reply_to.and_then(|peer| peers.get(&peer)) {
+                Some(sender) => vec![sender],
+                None => peers.values().collect(),
+            };
+
+        if targets.is_empty() {
+            debug!("No WebSocket peers connected, dropping {} message", channel);
+        }
+        for sender in targets {
+            // A closed queue means the peer is disconnecting; its task cleans up
+            let _ = sender.send(Message::Binary(frame.clone()));
+        }
+        drop(peers);
+        Ok(())
+    }
+
+    async fn heartbeat(&self) -> Result<bool> {
+        if !self.has_channel("heartbeat") {
+            return Ok(false);
+        }
+
+        // Echo heartbeat pings back to the peer that sent them
+        if let Some(ping) = self.recv("heartbeat").await? {
+            self.send("heartbeat", ping).await?;
+            trace!("Heartbeat echoed");
+            return Ok(true);
+        }
+        Ok(false)
+    }
+
+    fn has_channel(&self, channel: &str) -> bool {
+        self.shared
+            .channels
+            .lock()
+            .iter()
+            .any(|name| name == channel)
+    }
+
+    fn channels(&self) -> Vec<String> {
+        self.shared.channels.lock().clone()
+    }
+
+    async fn shutdown(&mut self) -> Result<()> {
+        info!("Shutting down WebSocket transport");
+
+        for task in self.shared.tasks.lock().drain(..) {
+            task.abort();
+        }
+        for (_, sender) in self.shared.peers.lock().drain() {
+            let _ = sender.send(Message::Close(None));
+        }
+        self.shared.inbox.lock().clear();
+        self.shared.reply_to.lock().clear();
+        Ok(())
+    }
+
+    fn box_clone(&self) -> Box<dyn Transport> {
+        Box::new(self.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_frame_round_trip() {
+        let parts = vec![b"<IDS|MSG>".to_vec(), Vec::new(), b"{\"a\":1}".to_vec()];
+        let frame = encode_frame("shell", &parts, DEFAULT_MAX_FRAME_BYTES).unwrap();
+
+        let (channel, decoded) = decode_frame(&frame, DEFAULT_MAX_FRAME_BYTES).unwrap();
+        assert_eq!(channel, "shell");
+        assert_eq!(decoded, parts);
+
+        // Truncated and garbage frames are rejected
+        assert!(decode_frame(&frame[..12], DEFAULT_MAX_FRAME_BYTES).is_err());
+        assert!(decode_frame(&[0xff; 16], DEFAULT_MAX_FRAME_BYTES).is_err());
+        assert!(decode_frame(&0u64.to_le_bytes(), DEFAULT_MAX_FRAME_BYTES).is_err());
+    }
+
+    #[test]
+    fn test_frame_size_limit() {
+        let parts = vec![vec![0u8; 64]];
+        assert!(encode_frame("shell", &parts, 32).is_err());
+
+        let frame = encode_frame("shell", &parts, DEFAULT_MAX_FRAME_BYTES).unwrap();
+        assert!(decode_frame(&frame, frame.len()).is_ok());
+        assert!(decode_frame(&frame, frame.len() - 1).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_oversized_frame_header_rejected() {
+        use tokio::io::AsyncWriteExt;
+        use tokio_tungstenite::tungstenite::protocol::Role;
+
+        let transport = WebSocketTransport::new().with_max_frame_bytes(1024);
+        let (mut client, server) = tokio::io::duplex(64);
+        let mut socket =
+            WebSocketStream::from_raw_socket(server, Role::Server, Some(transport.socket_config()))
+                .await;
+
+        // Masked binary frame header declaring a 1 TiB payload, with no payload
+        let mut header = vec![0x82, 0x80 | 127];
+        header.extend_from_slice(&(1u64 << 40).to_be_bytes());
+        header.extend_from_slice(&[0, 0, 0, 0]);
+        client.write_all(&header).await.unwrap();
+
+        let result = tokio::time::timeout(std::time::Duration::from_secs(5), socket.next())
+            .await
+            .expect("oversized frame should be rejected without waiting for its payload");
+        assert!(matches!(result, Some(Err(_))));
+    }
+
+    #[test]
+    fn test_connection_info_config() {
+        let info = WebSocketConnectionInfo::new("127.0.0.1", 8765, "key", "kernel-1");
+        assert_eq!(info.url(), "ws://127.0.0.1:8765");
+
+        let config = info.build_config();
+        assert_eq!(config.channels.len(), 5);
+        assert!(config
+            .channels
+            .values()
+            .all(|channel| channel.endpoint == "8765"));
+        assert_eq!(WebSocketTransport::config_port(&config).unwrap(), 8765);
+    }
+}
@@ -177,6 +177,7 @@ mod dap_tests {
             breakpoint_id: Some("bp-1".to_string()),
             file: "/test.lua".to_string(),
             line: 10,
+            watches: Vec::new(),
         };
 
         // Send event through DAP (returns the JSON value)
@@ -286,6 +287,112 @@ mod dap_tests {
         assert_eq!(frames[1].name, "main", "Bottom frame should be main");
     }
 
+    /// Test: Pausing a real Lua script in nested calls captures every frame and its locals
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn test_lua_nested_call_stack_trace() {
+        use llmspell_bridge::engine::{factory::LuaConfig, ScriptEngineBridge};
+        use llmspell_bridge::lua::LuaEngine;
+        use llmspell_kernel::debug::dap::{DebugAdapter, LuaDebugAdapter};
+        use std::time::Duration;
+
+        let engine = Arc::new(LuaEngine::new(&LuaConfig::default()).unwrap());
+        let (tx, mut rx) = mpsc::channel::<StoppedEvent>(10);
+        let mut exec_mgr = ExecutionManager::new("test-session".to_string());
+        exec_mgr.set_stopped_event_sender(tx);
+        let exec_mgr = Arc::new(exec_mgr);
+        exec_mgr.enable_debug_mode();
+        engine.set_debug_context(Some(exec_mgr.clone()));
+
+        engine.execute_script("return 0").await.unwrap();
+        let (source, _) = exec_mgr.current_location().unwrap();
+
+        // Pause on the body of `inner`, two calls deep
+        exec_mgr.set_breakpoint(source, 2).unwrap();
+        let script = concat!(
+            "local function inner(x)\n",
+            "  return x * 2\n",
+            "end\n",
+            "local function outer(y)\n",
+            "  return inner(y + 1) + 1\n",
+            "end\n",
+            "return outer(20)\n",
+        );
+        let runner = {
+            let engine = engine.clone();
+            tokio::spawn(async move { engine.execute_script(script).await })
+        };
+        let stopped = next_stop(&mut rx).await;
+        assert_eq!(stopped.line, 2);
+
+        let mut adapter = LuaDebugAdapter::new("test-session".to_string());
+        adapter.connect_execution_manager(Arc::clone(&exec_mgr));
+        let frames = adapter.stack_trace(1).unwrap();
+
+        assert_eq!(frames.len(), 3, "inner, outer and the main chunk");
+        let names: Vec<&str> = frames.iter().map(|f| f.name.as_str()).collect();
+        assert_eq!(names, vec!["inner", "outer", "main chunk"]);
+        let lines: Vec<i32> = frames.iter().map(|f| f.line).collect();
+        assert_eq!(lines, vec![2, 5, 7]);
+        let top_source = frames[0].source.clone().expect("frame has a source");
+        for frame in &frames {
+            let source = frame.source.as_ref().expect("frame has a source");
+            assert_eq!(source.name, top_source.name);
+            assert_eq!(source.path, top_source.path);
+        }
+
+        // Each frame's scopes expose its own locals
+        let local_value = |frame_id: i32, name: &str| {
+            let scopes = adapter.scopes(frame_id).unwrap();
+            adapter
+                .variables(scopes[0].variables_reference)
+                .unwrap()
+                .into_iter()
+                .find(|var| var.name == name)
+                .map(|var| var.value)
+        };
+        assert_eq!(local_value(frames[0].id, "x").as_deref(), Some("21"));
+        assert_eq!(local_value(frames[0].id, "y"), None);
+        assert_eq!(local_value(frames[1].id, "y").as_deref(), Some("20"));
+        assert_eq!(
+            local_value(frames[2].id, "outer").as_deref(),
+            Some("function")
+        );
+
+        exec_mgr.resume(StepMode::Continue);
+        let output = tokio::time::timeout(Duration::from_secs(5), runner)
+            .await
+            .expect("script should finish after resuming")
+            .unwrap()
+            .unwrap();
+        assert_eq!(output.output, json!(43));
+
+        // File chunks resolve to a path the IDE can open
+        let dir = tempfile::tempdir().unwrap();
+        let script_path = dir.path().join("nested.lua");
+        std::fs::write(&script_path, script).unwrap();
+        let dap = create_test_dap();
+        let file_source = dap.resolve_source(&format!("@{}", script_path.display()));
+        let path = file_source
+            .path
+            .as_deref()
+            .expect("file chunks resolve to a path");
+        assert!(std::path::Path::new(path).exists(), "{path} should exist");
+        assert_eq!(file_source.name.as_deref(), Some("nested.lua"));
+        assert!(file_source.source_reference.is_none());
+
+        // Inline chunks get a stable source reference instead of a path
+        let inline = dap.resolve_source("=repl");
+        assert!(inline.path.is_none());
+        assert_eq!(inline.name.as_deref(), Some("repl"));
+        let reference = inline
+            .source_reference
+            .expect("inline source has a reference");
+        assert_eq!(
+            dap.resolve_source("=repl").source_reference,
+            Some(reference)
+        );
+    }
+
     /// Test: Disconnect cleans up properly
     #[test]
     fn test_disconnect() {
@@ -504,4 +611,138 @@ mod dap_tests {
         assert_eq!(resp2["request_seq"].as_i64(), Some(2));
         assert_eq!(resp3["request_seq"].as_i64(), Some(3));
     }
+
+    /// Test: A conditional breakpoint pauses a real Lua loop at the matching iteration
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn test_conditional_breakpoint_pauses_lua_loop() {
+        use llmspell_bridge::engine::{factory::LuaConfig, ScriptEngineBridge};
+        use llmspell_bridge::lua::LuaEngine;
+        use std::time::Duration;
+
+        let engine = Arc::new(LuaEngine::new(&LuaConfig::default()).unwrap());
+        let (tx, mut rx) = mpsc::channel::<StoppedEvent>(10);
+        let mut exec_mgr = ExecutionManager::new("test-session".to_string());
+        exec_mgr.set_stopped_event_sender(tx);
+        let exec_mgr = Arc::new(exec_mgr);
+        exec_mgr.enable_debug_mode();
+        engine.set_debug_context(Some(exec_mgr.clone()));
+
+        // Learn the chunk name the engine reports for inline scripts
+        engine.execute_script("return 0").await.unwrap();
+        let (source, _) = exec_mgr.current_location().unwrap();
+
+        exec_mgr
+            .set_conditional_breakpoint(source.clone(), 3, "i == 5".to_string())
+            .unwrap();
+        let script = "local total = 0\nfor i = 1, 10 do\n  total = total + i\nend\nreturn total";
+        let runner = {
+            let engine = engine.clone();
+            tokio::spawn(async move { engine.execute_script(script).await })
+        };
+
+        let stopped = tokio::time::timeout(Duration::from_secs(5), rx.recv())
+            .await
+            .expect("script should pause at the breakpoint")
+            .unwrap();
+        assert_eq!(stopped.file, source);
+        assert_eq!(stopped.line, 3);
+
+        let locals = exec_mgr.get_stack_frames().last().unwrap().locals.clone();
+        let value_of = |name: &str| {
+            locals
+                .iter()
+                .find(|var| var.name == name)
+                .map(|var| var.value.clone())
+        };
+        assert_eq!(value_of("i").as_deref(), Some("5"));
+        assert_eq!(value_of("total").as_deref(), Some("10"));
+
+        exec_mgr.resume(StepMode::Continue);
+        let output = tokio::time::timeout(Duration::from_secs(5), runner)
+            .await
+            .expect("script should finish after resuming")
+            .unwrap()
+            .unwrap();
+        assert_eq!(output.output, json!(55));
+        assert!(rx.try_recv().is_err(), "breakpoint should pause only once");
+    }
+
+    async fn next_stop(rx: &mut mpsc::Receiver<StoppedEvent>) -> StoppedEvent {
+        tokio::time::timeout(std::time::Duration::from_secs(5), rx.recv())
+            .await
+            .expect("script should stop")
+            .unwrap()
+    }
+
+    /// Test: Watch expressions are re-evaluated at every stop of a real Lua script
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn test_watches_reevaluated_while_stepping_lua() {
+        use llmspell_bridge::engine::{factory::LuaConfig, ScriptEngineBridge};
+        use llmspell_bridge::lua::LuaEngine;
+        use std::time::Duration;
+
+        let engine = Arc::new(LuaEngine::new(&LuaConfig::default()).unwrap());
+        let (tx, mut rx) = mpsc::channel::<StoppedEvent>(10);
+        let mut exec_mgr = ExecutionManager::new("test-session".to_string());
+        exec_mgr.set_stopped_event_sender(tx);
+        let exec_mgr = Arc::new(exec_mgr);
+        exec_mgr.enable_debug_mode();
+        engine.set_debug_context(Some(exec_mgr.clone()));
+
+        engine.execute_script("return 0").await.unwrap();
+        let (source, _) = exec_mgr.current_location().unwrap();
+
+        exec_mgr.set_breakpoint(source, 2).unwrap();
+        exec_mgr.add_watch("b".to_string());
+        exec_mgr.add_watch("c".to_string());
+        let script = "local a = 1\nlocal b = a + 1\nlocal c = b + 1\nreturn c";
+        let runner = {
+            let engine = engine.clone();
+            tokio::spawn(async move { engine.execute_script(script).await })
+        };
+
+        let watch_values = |stopped: &StoppedEvent| {
+            stopped
+                .watches
+                .iter()
+                .map(|w| {
+                    let value = if w.var_type == "error" {
+                        "<error>".to_string()
+                    } else {
+                        w.value.clone()
+                    };
+                    (w.name.clone(), value)
+                })
+                .collect::<Vec<_>>()
+        };
+        let pair = |b: &str, c: &str| {
+            vec![
+                ("b".to_string(), b.to_string()),
+                ("c".to_string(), c.to_string()),
+            ]
+        };
+
+        // Neither local is in scope yet, which is reported per watch
+        let stopped = next_stop(&mut rx).await;
+        assert_eq!((stopped.reason.as_str(), stopped.line), ("breakpoint", 2));
+        assert_eq!(watch_values(&stopped), pair("<error>", "<error>"));
+
+        exec_mgr.resume(StepMode::StepOver);
+        let stopped = next_stop(&mut rx).await;
+        assert_eq!((stopped.reason.as_str(), stopped.line), ("step", 3));
+        assert_eq!(watch_values(&stopped), pair("2", "<error>"));
+
+        exec_mgr.resume(StepMode::StepOver);
+        let stopped = next_stop(&mut rx).await;
+        assert_eq!((stopped.reason.as_str(), stopped.line), ("step", 4));
+        assert_eq!(watch_values(&stopped), pair("2", "3"));
+
+        exec_mgr.resume(StepMode::Continue);
+        let output = tokio::time::timeout(Duration::from_secs(5), runner)
+            .await
+            .expect("script should finish after resuming")
+            .unwrap()
+            .unwrap();
+        assert_eq!(output.output, json!(3));
+    }
 }
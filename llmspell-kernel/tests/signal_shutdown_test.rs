@@ -0,0 +1,129 @@
+//! SIGTERM shutdown integration test
+//!
+//! Sends a real SIGTERM to the test process while an `IntegratedKernel` is
+//! executing and checks that running executions are drained. Kept in its own
+//! test binary because signal flags are process-wide.
+
+use anyhow::Result;
+use llmspell_core::error::LLMSpellError;
+use llmspell_core::traits::script_executor::{
+    ScriptExecutionMetadata, ScriptExecutionOutput, ScriptExecutor,
+};
+use llmspell_kernel::execution::IntegratedKernelParams;
+use llmspell_kernel::protocols::jupyter::JupyterProtocol;
+use llmspell_kernel::{
+    ExecutionConfig, IntegratedKernel, SessionManager, SessionManagerConfig, SignalBridge,
+};
+use nix::sys::signal::Signal;
+use serde_json::Value;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Executor sleeping for the number of milliseconds given as the script
+struct SleepingExecutor;
+
+#[async_trait::async_trait]
+impl ScriptExecutor for SleepingExecutor {
+    async fn execute_script(&self, script: &str) -> Result<ScriptExecutionOutput, LLMSpellError> {
+        let millis = script.parse().unwrap_or(0);
+        tokio::time::sleep(Duration::from_millis(millis)).await;
+        Ok(ScriptExecutionOutput {
+            output: Value::Null,
+            console_output: vec![],
+            metadata: ScriptExecutionMetadata {
+                duration: Duration::from_millis(millis),
+                language: "test".to_string(),
+                exit_code: Some(0),
+                warnings: vec![],
+            },
+        })
+    }
+
+    fn language(&self) -> &'static str {
+        "test"
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+async fn create_session_manager() -> Arc<SessionManager> {
+    let state_manager = Arc::new(
+        llmspell_kernel::state::StateManager::new(None)
+            .await
+            .unwrap(),
+    );
+    let event_bus = Arc::new(llmspell_events::bus::EventBus::new());
+
+    Arc::new(
+        SessionManager::new(
+            state_manager,
+            Arc::new(llmspell_storage::MemoryBackend::new()),
+            Arc::new(llmspell_hooks::HookRegistry::new()),
+            Arc::new(llmspell_hooks::HookExecutor::new()),
+            &event_bus,
+            SessionManagerConfig::default(),
+        )
+        .unwrap(),
+    )
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_sigterm_drains_running_execution() -> Result<()> {
+    let mut kernel = IntegratedKernel::new(IntegratedKernelParams {
+        protocol: JupyterProtocol::new("signal-session".to_string(), "signal-kernel".to_string()),
+        config: ExecutionConfig::default(),
+        session_id: "signal-session".to_string(),
+        script_executor: Arc::new(SleepingExecutor),
+        provider_manager: None,
+        session_manager: create_session_manager().await,
+        memory_manager: None,
+        hook_system: None,
+        event_bus: None,
+    })
+    .await?;
+
+    let mut bridge = SignalBridge::new();
+    bridge.init()?;
+    kernel.set_signal_bridge(Arc::new(bridge));
+    kernel.start_signal_watcher();
+
+    // A quick execution finishes within the grace period while the long one
+    // outlives it and is cancelled
+    let tracker = kernel.execution_tracker();
+    let quick = tracker.track("quick".to_string(), async {
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        "done"
+    });
+    let sigterm = async {
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        let pid = i32::try_from(std::process::id()).unwrap();
+        SignalBridge::send_signal(pid, Signal::SIGTERM).unwrap();
+    };
+    let (long, quick, ()) = tokio::time::timeout(Duration::from_secs(30), async {
+        tokio::join!(kernel.execute_direct("60000"), quick, sigterm)
+    })
+    .await
+    .expect("SIGTERM should end the long execution");
+
+    assert_eq!(quick?, Some("done"));
+    let err = long.unwrap_err().to_string();
+    assert!(err.contains("cancelled during shutdown"), "{err}");
+    assert!(tracker.in_flight().is_empty());
+
+    // The kernel loop sees the forwarded shutdown and stops accepting work
+    let shutting_down = tokio::time::timeout(Duration::from_secs(5), async {
+        loop {
+            if kernel.process_signals().await? {
+                return anyhow::Ok(true);
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+    })
+    .await??;
+    assert!(shutting_down);
+    assert!(kernel.execute_direct("0").await.is_err());
+
+    Ok(())
+}
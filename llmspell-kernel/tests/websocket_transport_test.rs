@@ -0,0 +1,130 @@
+//! WebSocket transport integration test
+//!
+//! Drives an `IntegratedKernel` bound to a `WebSocketTransport` from a
+//! WebSocket client over a real socket.
+
+#![cfg(feature = "websocket")]
+
+use anyhow::Result;
+use llmspell_core::error::LLMSpellError;
+use llmspell_core::traits::script_executor::{
+    ScriptExecutionMetadata, ScriptExecutionOutput, ScriptExecutor,
+};
+use llmspell_kernel::execution::IntegratedKernelParams;
+use llmspell_kernel::protocols::jupyter::JupyterProtocol;
+use llmspell_kernel::{
+    ExecutionConfig, IntegratedKernel, Protocol, SessionManager, SessionManagerConfig, Transport,
+    WebSocketConnectionInfo, WebSocketTransport,
+};
+use serde_json::{json, Value};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Executor that is never called by `kernel_info_request`
+struct NullExecutor;
+
+#[async_trait::async_trait]
+impl ScriptExecutor for NullExecutor {
+    async fn execute_script(&self, _script: &str) -> Result<ScriptExecutionOutput, LLMSpellError> {
+        Ok(ScriptExecutionOutput {
+            output: Value::Null,
+            console_output: vec![],
+            metadata: ScriptExecutionMetadata {
+                duration: Duration::ZERO,
+                language: "test".to_string(),
+                exit_code: Some(0),
+                warnings: vec![],
+            },
+        })
+    }
+
+    fn language(&self) -> &'static str {
+        "test"
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+async fn create_session_manager() -> Arc<SessionManager> {
+    let state_manager = Arc::new(
+        llmspell_kernel::state::StateManager::new(None)
+            .await
+            .unwrap(),
+    );
+    let event_bus = Arc::new(llmspell_events::bus::EventBus::new());
+
+    Arc::new(
+        SessionManager::new(
+            state_manager,
+            Arc::new(llmspell_storage::MemoryBackend::new()),
+            Arc::new(llmspell_hooks::HookRegistry::new()),
+            Arc::new(llmspell_hooks::HookExecutor::new()),
+            &event_bus,
+            SessionManagerConfig::default(),
+        )
+        .unwrap(),
+    )
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_kernel_info_over_websocket() -> Result<()> {
+    // Bind on an OS-assigned port and discover it through the connection info
+    let server = WebSocketTransport::from_connection_info(WebSocketConnectionInfo::new(
+        "127.0.0.1",
+        0,
+        "",
+        "ws-kernel",
+    ))
+    .await?;
+    let info = server.connection_info().expect("bound transport has info");
+    assert_ne!(info.port, 0);
+    assert_eq!(info.kernel_id, "ws-kernel");
+
+    let protocol = JupyterProtocol::new("ws-session".to_string(), info.kernel_id.clone());
+    let mut kernel = IntegratedKernel::new(IntegratedKernelParams {
+        protocol: protocol.clone(),
+        config: ExecutionConfig::default(),
+        session_id: "ws-session".to_string(),
+        script_executor: Arc::new(NullExecutor),
+        provider_manager: None,
+        session_manager: create_session_manager().await,
+        memory_manager: None,
+        hook_system: None,
+        event_bus: None,
+    })
+    .await?;
+    kernel.set_transport(Box::new(server));
+    let kernel_task = tokio::spawn(kernel.run());
+
+    let mut client = WebSocketTransport::new();
+    client.connect(&info.build_config()).await?;
+    let request = protocol.create_request("kernel_info_request", json!({}))?;
+    client.send("shell", vec![request]).await?;
+
+    let reply = tokio::time::timeout(Duration::from_secs(10), async {
+        loop {
+            if let Some(parts) = client.recv("shell").await? {
+                let idx = parts
+                    .iter()
+                    .position(|part| part.as_slice() == b"<IDS|MSG>")
+                    .expect("reply uses the Jupyter wire format");
+                let header: Value = serde_json::from_slice(&parts[idx + 2])?;
+                if header["msg_type"] == "kernel_info_reply" {
+                    return anyhow::Ok(serde_json::from_slice::<Value>(&parts[idx + 5])?);
+                }
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+    })
+    .await??;
+
+    assert_eq!(reply["protocol_version"], "5.3");
+    assert_eq!(reply["implementation"], "llmspell");
+    assert_eq!(reply["language_info"]["name"], "test");
+
+    kernel_task.abort();
+    client.shutdown().await?;
+    Ok(())
+}
@@ -258,6 +258,7 @@ mod tests {
                 properties: json!({"paradigm": "multi-paradigm"}),
                 event_time: None,
                 ingestion_time: Utc::now(),
+                embedding: None,
             },
             Entity {
                 id: "python-1".to_string(),
@@ -266,6 +267,7 @@ mod tests {
                 properties: json!({"paradigm": "object-oriented"}),
                 event_time: None,
                 ingestion_time: Utc::now(),
+                embedding: None,
             },
         ];
 
@@ -324,6 +326,7 @@ mod tests {
             properties: json!({"feature": "memory safety"}),
             event_time: None,
             ingestion_time: Utc::now(),
+            embedding: None,
         };
 
         let keywords = vec![
@@ -345,6 +348,7 @@ mod tests {
             properties: json!({"key": "value"}),
             event_time: None,
             ingestion_time: Utc::now(),
+            embedding: None,
         };
 
         let formatted = ContextAssembler::format_entity(&entity);
@@ -518,7 +518,7 @@ mod tests {
     use crate::consolidation::llm_engine::LLMConsolidationEngine;
     use crate::consolidation::prompts::PromptVersion;
     use crate::consolidation::LLMConsolidationConfig;
-    use crate::types::EpisodicEntry;
+    use crate::types::{DecayPolicy, EpisodicEntry};
     use async_trait::async_trait;
     use chrono::Utc;
     use llmspell_graph::traits::KnowledgeGraph;
@@ -585,6 +585,10 @@ mod tests {
             Ok(0)
         }
 
+        async fn prune(&self, _policy: DecayPolicy) -> Result<usize> {
+            Ok(0)
+        }
+
         async fn list_sessions_with_unprocessed(&self) -> Result<Vec<String>> {
             use std::collections::HashSet;
 
@@ -612,6 +612,7 @@ impl LLMConsolidationEngine {
             properties: entity_payload.properties.clone(),
             event_time: entity_payload.event_time,
             ingestion_time: chrono::Utc::now(),
+            embedding: None,
         };
 
         // Add to knowledge graph
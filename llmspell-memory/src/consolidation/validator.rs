@@ -262,6 +262,7 @@ mod tests {
                     properties: serde_json::json!({}),
                     event_time: Some(Utc::now()),
                     ingestion_time: Utc::now(),
+                    embedding: None,
                 })
             } else {
                 Err(anyhow::anyhow!("Entity not found: {id}"))
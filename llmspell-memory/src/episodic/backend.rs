@@ -14,7 +14,7 @@ use crate::episodic::PostgreSQLEpisodicMemory;
 use crate::episodic::{InMemoryEpisodicMemory, SqliteEpisodicMemory};
 use crate::error::{MemoryError, Result};
 use crate::traits::EpisodicMemory;
-use crate::types::EpisodicEntry;
+use crate::types::{DecayPolicy, EpisodicEntry};
 
 /// Episodic memory backend (enum dispatch pattern)
 ///
@@ -287,6 +287,15 @@ impl EpisodicMemory for EpisodicBackend {
         }
     }
 
+    async fn prune(&self, policy: DecayPolicy) -> Result<usize> {
+        match self {
+            Self::InMemory(backend) => backend.prune(policy).await,
+            Self::Sqlite(backend) => backend.prune(policy).await,
+            #[cfg(feature = "postgres")]
+            Self::PostgreSQL(backend) => backend.prune(policy).await,
+        }
+    }
+
     async fn list_sessions_with_unprocessed(&self) -> Result<Vec<String>> {
         match self {
             Self::InMemory(backend) => backend.list_sessions_with_unprocessed().await,
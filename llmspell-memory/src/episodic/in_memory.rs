@@ -19,7 +19,7 @@ use tracing::{debug, info, trace, warn};
 use crate::embeddings::EmbeddingService;
 use crate::error::{MemoryError, Result};
 use crate::traits::EpisodicMemory;
-use crate::types::EpisodicEntry;
+use crate::types::{DecayPolicy, EpisodicEntry};
 
 /// In-memory episodic memory storage
 ///
@@ -54,6 +54,9 @@ pub struct InMemoryEpisodicMemory {
     /// Entry storage indexed by ID
     entries: Arc<RwLock<HashMap<String, EpisodicEntry>>>,
 
+    /// Read count per entry (via `get` and `search`), used by decay policies
+    access_counts: Arc<RwLock<HashMap<String, u64>>>,
+
     /// Optional embedding service (if None, uses test embeddings)
     embedding_service: Option<Arc<EmbeddingService>>,
 }
@@ -66,6 +69,7 @@ impl InMemoryEpisodicMemory {
     pub fn new() -> Self {
         Self {
             entries: Arc::new(RwLock::new(HashMap::new())),
+            access_counts: Arc::new(RwLock::new(HashMap::new())),
             embedding_service: None,
         }
     }
@@ -81,10 +85,19 @@ impl InMemoryEpisodicMemory {
         );
         Self {
             entries: Arc::new(RwLock::new(HashMap::new())),
+            access_counts: Arc::new(RwLock::new(HashMap::new())),
             embedding_service: Some(embedding_service),
         }
     }
 
+    /// Count a read of each entry
+    fn record_access<'a>(&self, ids: impl IntoIterator<Item = &'a str>) {
+        let mut access_counts = self.access_counts.write();
+        for id in ids {
+            *access_counts.entry(id.to_string()).or_insert(0) += 1;
+        }
+    }
+
     /// Calculate cosine similarity between two vectors
     fn cosine_similarity(a: &[f32], b: &[f32]) -> f32 {
         if a.len() != b.len() {
@@ -167,11 +180,14 @@ impl EpisodicMemory for InMemoryEpisodicMemory {
     }
 
     async fn get(&self, id: &str) -> Result<EpisodicEntry> {
-        self.entries
+        let entry = self
+            .entries
             .read()
             .get(id)
             .cloned()
-            .ok_or_else(|| MemoryError::NotFound(format!("Entry not found: {id}")))
+            .ok_or_else(|| MemoryError::NotFound(format!("Entry not found: {id}")))?;
+        self.record_access([id]);
+        Ok(entry)
     }
 
     async fn search(&self, query: &str, top_k: usize) -> Result<Vec<EpisodicEntry>> {
@@ -230,11 +246,14 @@ impl EpisodicMemory for InMemoryEpisodicMemory {
         }
 
         // Take top_k results
-        Ok(results
+        let results: Vec<EpisodicEntry> = results
             .into_iter()
             .take(top_k)
             .map(|(_, entry)| entry)
-            .collect())
+            .collect();
+        self.record_access(results.iter().map(|entry| entry.id.as_str()));
+
+        Ok(results)
     }
 
     async fn list_unprocessed(&self, session_id: &str) -> Result<Vec<EpisodicEntry>> {
@@ -298,11 +317,44 @@ impl EpisodicMemory for InMemoryEpisodicMemory {
 
         {
             let mut entries = self.entries.write();
+            let mut access_counts = self.access_counts.write();
+            for id in to_delete {
+                entries.remove(&id);
+                access_counts.remove(&id);
+            }
+        } // Write locks dropped here
+
+        Ok(count)
+    }
+
+    async fn prune(&self, policy: DecayPolicy) -> Result<usize> {
+        debug!("Pruning episodic memory with policy: {:?}", policy);
+
+        let to_delete = {
+            let entries = self.entries.read();
+            let access_counts = self.access_counts.read();
+            let candidates = entries
+                .values()
+                .map(|entry| {
+                    let accesses = access_counts.get(&entry.id).copied().unwrap_or(0);
+                    (entry.id.clone(), entry.timestamp, accesses)
+                })
+                .collect();
+            policy.select_for_removal(candidates, Utc::now())
+        }; // Read locks dropped here
+
+        let count = to_delete.len();
+
+        {
+            let mut entries = self.entries.write();
+            let mut access_counts = self.access_counts.write();
             for id in to_delete {
                 entries.remove(&id);
+                access_counts.remove(&id);
             }
-        } // Write lock dropped here
+        } // Write locks dropped here
 
+        info!("Pruned {} episodic entries", count);
         Ok(count)
     }
 
@@ -490,6 +542,38 @@ mod tests {
         assert_eq!(remaining[0].content, "new");
     }
 
+    #[tokio::test]
+    async fn test_prune_keeps_newest_entries() {
+        let memory = InMemoryEpisodicMemory::new();
+        let base = Utc::now() - chrono::Duration::hours(1);
+
+        let mut ids = Vec::new();
+        for i in 0..100 {
+            let mut entry =
+                EpisodicEntry::new("session-1".into(), "user".into(), format!("message {i}"));
+            entry.timestamp = base + chrono::Duration::seconds(i);
+            ids.push(memory.add(entry).await.unwrap());
+        }
+
+        let pruned = memory
+            .prune(DecayPolicy::default().with_max_count(20))
+            .await
+            .unwrap();
+        assert_eq!(pruned, 80);
+
+        let survivors = &ids[80..];
+        let results = memory.search("message", 100).await.unwrap();
+        assert_eq!(results.len(), 20);
+        assert!(results.iter().all(|entry| survivors.contains(&entry.id)));
+
+        // Each survivor was read once by search, below the minimum of two
+        let pruned = memory
+            .prune(DecayPolicy::default().with_min_access_count(2))
+            .await
+            .unwrap();
+        assert_eq!(pruned, 20);
+    }
+
     #[tokio::test]
     async fn test_cosine_similarity() {
         let vec_a = vec![1.0, 0.0, 0.0];
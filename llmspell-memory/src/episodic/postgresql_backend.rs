@@ -46,7 +46,7 @@ use crate::error::{MemoryError, Result};
 #[cfg(feature = "postgres")]
 use crate::traits::EpisodicMemory;
 #[cfg(feature = "postgres")]
-use crate::types::EpisodicEntry;
+use crate::types::{DecayPolicy, EpisodicEntry};
 
 /// Production episodic memory using `PostgreSQL` with `pgvector`
 ///
@@ -190,6 +190,14 @@ impl PostgreSQLEpisodicMemory {
         Ok(())
     }
 
+    /// Count reads of entries in `PostgreSQL` so decay policies survive restarts
+    async fn record_access(&self, ids: &[String]) -> Result<()> {
+        self.storage
+            .record_access(ids)
+            .await
+            .map_err(|e| MemoryError::Storage(format!("PostgreSQL access tracking failed: {e}")))
+    }
+
     /// Convert `EpisodicEntry` to `VectorEntry` for `PostgreSQL` storage
     ///
     /// Serializes the entry (excluding embedding) into metadata field.
@@ -217,7 +225,8 @@ impl PostgreSQLEpisodicMemory {
 
         Ok(VectorEntry::new(entry.id.clone(), embedding)
             .with_scope(StateScope::Session(entry.session_id.clone()))
-            .with_metadata(metadata))
+            .with_metadata(metadata)
+            .with_event_time(entry.timestamp.into()))
     }
 
     /// Convert `VectorEntry` metadata back to `EpisodicEntry`
@@ -308,7 +317,9 @@ impl EpisodicMemory for PostgreSQLEpisodicMemory {
         // Try cache first (O(1))
         if let Some(entry) = self.entries.get(id) {
             trace!("Cache hit for entry: id={}", id);
-            return Ok(entry.clone());
+            let entry = entry.clone();
+            self.record_access(&[entry.id.clone()]).await?;
+            return Ok(entry);
         }
 
         // Cache miss - this is expected for PostgreSQL-backed storage
@@ -356,6 +367,9 @@ impl EpisodicMemory for PostgreSQLEpisodicMemory {
             }
         }
 
+        let ids: Vec<String> = entries.iter().map(|entry| entry.id.clone()).collect();
+        self.record_access(&ids).await?;
+
         debug!("Found {} matching entries", entries.len());
         Ok(entries)
     }
@@ -452,6 +466,28 @@ impl EpisodicMemory for PostgreSQLEpisodicMemory {
         Ok(count)
     }
 
+    async fn prune(&self, policy: DecayPolicy) -> Result<usize> {
+        debug!("Pruning entries with policy: {:?}", policy);
+
+        // Select and delete in PostgreSQL across all sessions, using the
+        // persisted access counts
+        let pruned = self
+            .storage
+            .prune(None, &policy.to_prune_filter(Utc::now()))
+            .await
+            .map_err(|e| MemoryError::Storage(format!("PostgreSQL prune failed: {e}")))?;
+
+        // Delete from cache
+        for id in &pruned {
+            self.entries.remove(id);
+        }
+
+        let count = pruned.len();
+
+        info!("Pruned {} entries", count);
+        Ok(count)
+    }
+
     async fn list_sessions_with_unprocessed(&self) -> Result<Vec<String>> {
         debug!("Listing sessions with unprocessed entries");
 
@@ -73,7 +73,7 @@ use tracing::{debug, info, trace, warn};
 use crate::embeddings::EmbeddingService;
 use crate::error::{MemoryError, Result};
 use crate::traits::EpisodicMemory;
-use crate::types::EpisodicEntry;
+use crate::types::{DecayPolicy, EpisodicEntry};
 
 /// Production episodic memory using `SQLite` with HNSW vector index
 ///
@@ -159,6 +159,27 @@ impl SqliteEpisodicMemory {
         })
     }
 
+    /// Count reads of entries in `SQLite` so decay policies survive restarts
+    async fn record_access(&self, ids: &[String]) -> Result<()> {
+        self.storage
+            .record_access(ids)
+            .await
+            .map_err(|e| MemoryError::Storage(format!("SQLite access tracking failed: {e}")))
+    }
+
+    /// Remove entries from the `SQLite` storage (including HNSW index) and the cache
+    async fn remove_entries(&self, ids: &[String]) -> Result<()> {
+        self.storage
+            .delete(ids)
+            .await
+            .map_err(|e| MemoryError::Storage(format!("SQLite delete failed: {e}")))?;
+
+        for id in ids {
+            self.entries.remove(id);
+        }
+        Ok(())
+    }
+
     /// Convert `EpisodicEntry` to `VectorEntry` for `SQLite` storage
     ///
     /// Serializes the entry (excluding embedding) into metadata field.
@@ -301,13 +322,16 @@ impl EpisodicMemory for SqliteEpisodicMemory {
         debug!("Retrieving entry from DashMap cache: id={}", id);
 
         // O(1) lookup in DashMap cache
-        self.entries
+        let entry = self
+            .entries
             .get(id)
             .map(|entry_ref| entry_ref.value().clone())
             .ok_or_else(|| {
                 debug!("Entry not found in cache: id={}", id);
                 MemoryError::NotFound(format!("Entry not found: {id}"))
-            })
+            })?;
+        self.record_access(&[entry.id.clone()]).await?;
+        Ok(entry)
     }
 
     async fn search(&self, query: &str, top_k: usize) -> Result<Vec<EpisodicEntry>> {
@@ -344,6 +368,9 @@ impl EpisodicMemory for SqliteEpisodicMemory {
             })
             .collect::<Vec<_>>();
 
+        let ids: Vec<String> = entries.iter().map(|entry| entry.id.clone()).collect();
+        self.record_access(&ids).await?;
+
         debug!("HNSW search complete: found {} entries", entries.len());
 
         Ok(entries)
@@ -459,20 +486,8 @@ impl EpisodicMemory for SqliteEpisodicMemory {
 
         debug!("Found {} entries to delete", count);
 
-        // Remove from DashMap cache
-        for id in &ids_to_delete {
-            self.entries.remove(id);
-        }
-
-        // Remove from SQLite storage (batch deletion + HNSW index cleanup)
-        if let Err(e) = self.storage.delete(&ids_to_delete).await {
-            warn!(
-                "Failed to delete {} entries from SQLite: {}",
-                ids_to_delete.len(),
-                e
-            );
-            // Continue anyway - entries already removed from cache
-        }
+        // Remove from SQLite storage (batch deletion + HNSW index cleanup) and DashMap cache
+        self.remove_entries(&ids_to_delete).await?;
 
         debug!(
             "Successfully deleted {} entries before timestamp: {}",
@@ -482,6 +497,26 @@ impl EpisodicMemory for SqliteEpisodicMemory {
         Ok(count)
     }
 
+    async fn prune(&self, policy: DecayPolicy) -> Result<usize> {
+        debug!("Pruning entries with policy: {:?}", policy);
+
+        // Select and delete in SQLite, using the persisted access counts
+        let pruned = self
+            .storage
+            .prune(&StateScope::Global, &policy.to_prune_filter(Utc::now()))
+            .await
+            .map_err(|e| MemoryError::Storage(format!("SQLite prune failed: {e}")))?;
+
+        for id in &pruned {
+            self.entries.remove(id);
+        }
+
+        let count = pruned.len();
+
+        info!("Pruned {} entries from SQLite episodic memory", count);
+        Ok(count)
+    }
+
     async fn list_sessions_with_unprocessed(&self) -> Result<Vec<String>> {
         debug!("Listing sessions with unprocessed entries");
 
@@ -516,7 +551,7 @@ fn datetime_to_systemtime(dt: &DateTime<Utc>) -> SystemTime {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::types::EpisodicEntry;
+    use crate::types::{DecayPolicy, EpisodicEntry};
     use llmspell_core::error::LLMSpellError;
     use llmspell_core::traits::embedding::EmbeddingProvider;
     use llmspell_storage::backends::sqlite::SqliteConfig;
@@ -668,4 +703,34 @@ mod tests {
         let deleted = memory.delete_before(future).await.unwrap();
         assert_eq!(deleted, 1);
     }
+
+    #[tokio::test]
+    async fn test_sqlite_prune_removes_from_index() {
+        let memory = create_test_memory().await.unwrap();
+        let base = Utc::now() - chrono::Duration::hours(1);
+
+        let mut ids = Vec::new();
+        for i in 0..100 {
+            let mut entry = EpisodicEntry::new(
+                "session-1".to_string(),
+                "user".to_string(),
+                format!("Message {i}"),
+            );
+            entry.timestamp = base + chrono::Duration::seconds(i);
+            ids.push(memory.add(entry).await.unwrap());
+        }
+
+        let pruned = memory
+            .prune(DecayPolicy::default().with_max_count(20))
+            .await
+            .unwrap();
+        assert_eq!(pruned, 80);
+
+        // Pruned entries must be gone from the HNSW index, not just the cache
+        let survivors = &ids[80..];
+        let results = memory.search("Message", 100).await.unwrap();
+        assert_eq!(results.len(), 20);
+        assert!(results.iter().all(|entry| survivors.contains(&entry.id)));
+        assert!(memory.get(&ids[0]).await.is_err());
+    }
 }
@@ -69,4 +69,4 @@ pub use traits::{
     ConsolidationDecision, Entity, EpisodicMemory, MemoryManager, Pattern, ProceduralMemory,
     Relationship, SemanticMemory,
 };
-pub use types::{ConsolidationMode, ConsolidationResult, EpisodicEntry};
+pub use types::{ConsolidationMode, ConsolidationResult, DecayPolicy, EpisodicEntry};
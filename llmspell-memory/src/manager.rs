@@ -787,6 +787,97 @@ mod tests {
         assert_eq!(result.entities_added, 0);
     }
 
+    #[tokio::test]
+    async fn test_consolidate_now_drains_pending_entries() {
+        use crate::consolidation::ConsolidationEngine;
+
+        /// Mock engine folding each batch of entries into a single fact
+        struct FactEngine {
+            semantic: Arc<GraphSemanticMemory>,
+        }
+
+        #[async_trait]
+        impl ConsolidationEngine for FactEngine {
+            async fn consolidate(
+                &self,
+                _session_ids: &[&str],
+                entries: &mut [EpisodicEntry],
+            ) -> Result<ConsolidationResult> {
+                let contents: Vec<&str> = entries.iter().map(|e| e.content.as_str()).collect();
+                let fact = llmspell_graph::Entity::new(
+                    "Alice".into(),
+                    "fact".into(),
+                    json!({"derived_from": contents}),
+                );
+                self.semantic.upsert_entity(fact).await?;
+
+                for entry in entries.iter_mut() {
+                    entry.mark_processed();
+                }
+
+                Ok(ConsolidationResult {
+                    entries_processed: entries.len(),
+                    entities_added: 1,
+                    ..ConsolidationResult::empty()
+                })
+            }
+        }
+
+        let sqlite_backend = Arc::new(
+            llmspell_storage::backends::sqlite::SqliteBackend::new(
+                llmspell_storage::backends::sqlite::SqliteConfig::in_memory(),
+            )
+            .await
+            .unwrap(),
+        );
+        sqlite_backend.run_migrations().await.unwrap();
+        let semantic = Arc::new(GraphSemanticMemory::new_with_sqlite(sqlite_backend));
+        let engine = Arc::new(FactEngine {
+            semantic: Arc::clone(&semantic),
+        });
+        let manager = DefaultMemoryManager::with_consolidation(
+            Arc::new(InMemoryEpisodicMemory::new()),
+            semantic,
+            Arc::new(NoopProceduralMemory),
+            engine,
+        );
+
+        for content in ["My name is Alice", "I work on the compiler"] {
+            manager
+                .episodic()
+                .add(EpisodicEntry::new(
+                    "session-1".into(),
+                    "user".into(),
+                    content.into(),
+                ))
+                .await
+                .unwrap();
+        }
+
+        let result = manager
+            .consolidate_now(ConsolidationMode::Manual)
+            .await
+            .unwrap();
+        assert_eq!(result.entries_processed, 2);
+        assert_eq!(result.entities_added, 1);
+
+        let facts = manager.semantic().query_by_type("fact").await.unwrap();
+        assert_eq!(facts.len(), 1);
+        assert!(manager
+            .episodic()
+            .list_unprocessed("session-1")
+            .await
+            .unwrap()
+            .is_empty());
+
+        // Nothing left to drain
+        let result = manager
+            .consolidate_now(ConsolidationMode::Manual)
+            .await
+            .unwrap();
+        assert_eq!(result.entries_processed, 0);
+    }
+
     #[tokio::test]
     async fn test_shutdown() {
         let manager = DefaultMemoryManager::new_in_memory().await.unwrap();
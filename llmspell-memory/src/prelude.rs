@@ -11,4 +11,4 @@ pub use crate::traits::{
     ConsolidationDecision, Entity, EpisodicMemory, MemoryManager, ProceduralMemory, Relationship,
     SemanticMemory,
 };
-pub use crate::types::{ConsolidationMode, ConsolidationResult, EpisodicEntry};
+pub use crate::types::{ConsolidationMode, ConsolidationResult, DecayPolicy, EpisodicEntry};
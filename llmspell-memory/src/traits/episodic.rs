@@ -15,7 +15,7 @@ use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 
 use crate::error::Result;
-use crate::types::EpisodicEntry;
+use crate::types::{DecayPolicy, EpisodicEntry};
 
 /// Episodic memory stores vector-indexed interaction history
 ///
@@ -140,6 +140,20 @@ pub trait EpisodicMemory: Send + Sync {
     /// Number of entries deleted
     async fn delete_before(&self, timestamp: DateTime<Utc>) -> Result<usize>;
 
+    /// Prune entries selected by a forgetting policy
+    ///
+    /// Pruned entries are removed from storage and from the vector index, so
+    /// they no longer appear in search results.
+    ///
+    /// # Arguments
+    ///
+    /// * `policy` - Age, count and access-count rules selecting entries to forget
+    ///
+    /// # Returns
+    ///
+    /// Number of entries pruned
+    async fn prune(&self, policy: DecayPolicy) -> Result<usize>;
+
     /// List all sessions with unprocessed entries
     ///
     /// Returns session IDs that have at least one unprocessed entry.
@@ -81,6 +81,34 @@ pub trait MemoryManager: Send + Sync {
         priority_entries: Option<&[String]>,
     ) -> Result<ConsolidationResult>;
 
+    /// Run a consolidation pass over all pending episodic entries now
+    ///
+    /// Drains every session with unprocessed entries through the configured
+    /// consolidation engine and returns the combined statistics. Entries are
+    /// marked processed per session, so a later pass (manual or daemon) skips
+    /// them. Intended for tests and batch jobs; safe to call while the
+    /// consolidation daemon is idle.
+    ///
+    /// # Arguments
+    ///
+    /// * `mode` - Consolidation mode passed through to each session's pass
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error from listing sessions or consolidating a session;
+    /// sessions consolidated before the error stay processed.
+    async fn consolidate_now(&self, mode: ConsolidationMode) -> Result<ConsolidationResult> {
+        let sessions = self.episodic().list_sessions_with_unprocessed().await?;
+
+        let mut total = ConsolidationResult::empty();
+        for session_id in &sessions {
+            let result = self.consolidate(session_id, mode, None).await?;
+            total.accumulate(&result);
+        }
+
+        Ok(total)
+    }
+
     // ========== Phase 13.7.1: Kernel Integration Helpers ==========
 
     /// Check if episodic memory is present
@@ -1,6 +1,7 @@
 //! Core types for memory system
 
 use chrono::{DateTime, Utc};
+use llmspell_core::types::storage::VectorPruneFilter;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use uuid::Uuid;
@@ -114,6 +115,101 @@ impl ConsolidationResult {
             duration_ms: 0,
         }
     }
+
+    /// Add another result's counts and duration to this one
+    pub const fn accumulate(&mut self, other: &Self) {
+        self.entries_processed += other.entries_processed;
+        self.entities_added += other.entities_added;
+        self.entities_updated += other.entities_updated;
+        self.entities_deleted += other.entities_deleted;
+        self.entries_skipped += other.entries_skipped;
+        self.entries_failed += other.entries_failed;
+        self.duration_ms += other.duration_ms;
+    }
+}
+
+/// Forgetting policy for pruning episodic memory
+///
+/// Each rule is optional and applied independently; an entry is removed if any
+/// rule selects it. `max_count` ranks entries by `timestamp` (newest first).
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DecayPolicy {
+    /// Remove entries whose `timestamp` is older than this
+    pub max_age: Option<std::time::Duration>,
+
+    /// Keep only the newest N entries
+    pub max_count: Option<usize>,
+
+    /// Remove entries read fewer times than this (via `get` or `search`)
+    pub min_access_count: Option<u64>,
+}
+
+impl DecayPolicy {
+    /// Remove entries older than `max_age`
+    #[must_use]
+    pub const fn with_max_age(mut self, max_age: std::time::Duration) -> Self {
+        self.max_age = Some(max_age);
+        self
+    }
+
+    /// Keep only the newest `max_count` entries
+    #[must_use]
+    pub const fn with_max_count(mut self, max_count: usize) -> Self {
+        self.max_count = Some(max_count);
+        self
+    }
+
+    /// Remove entries accessed fewer than `min_access_count` times
+    #[must_use]
+    pub const fn with_min_access_count(mut self, min_access_count: u64) -> Self {
+        self.min_access_count = Some(min_access_count);
+        self
+    }
+
+    /// Convert to the equivalent storage prune filter evaluated at `now`
+    ///
+    /// Storage backends rank entries by the event time they were stored with,
+    /// which is the entry `timestamp`.
+    #[must_use]
+    pub fn to_prune_filter(&self, now: DateTime<Utc>) -> VectorPruneFilter {
+        VectorPruneFilter {
+            older_than: self
+                .max_age
+                .and_then(|age| chrono::Duration::from_std(age).ok())
+                .map(|age| (now - age).into()),
+            keep_newest: self.max_count,
+            min_access_count: self.min_access_count,
+        }
+    }
+
+    /// Select the entries this policy removes
+    ///
+    /// Candidates are `(id, timestamp, access_count)` tuples.
+    #[must_use]
+    pub fn select_for_removal(
+        &self,
+        mut candidates: Vec<(String, DateTime<Utc>, u64)>,
+        now: DateTime<Utc>,
+    ) -> Vec<String> {
+        let age_cutoff = self
+            .max_age
+            .and_then(|age| chrono::Duration::from_std(age).ok())
+            .map(|age| now - age);
+
+        // Newest first so max_count keeps the head
+        candidates.sort_by(|a, b| b.1.cmp(&a.1));
+
+        candidates
+            .into_iter()
+            .enumerate()
+            .filter(|(rank, (_, timestamp, accesses))| {
+                age_cutoff.is_some_and(|cutoff| *timestamp < cutoff)
+                    || self.max_count.is_some_and(|max| *rank >= max)
+                    || self.min_access_count.is_some_and(|min| *accesses < min)
+            })
+            .map(|(_, (id, _, _))| id)
+            .collect()
+    }
 }
 
 /// Generate a unique ID
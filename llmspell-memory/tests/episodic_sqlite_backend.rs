@@ -86,7 +86,10 @@ async fn create_test_tables(backend: &SqliteBackend, _dimension: usize) {
             dimension INTEGER NOT NULL CHECK (dimension IN (384, 768, 1536, 3072)),
             metadata TEXT NOT NULL DEFAULT '{}',
             created_at INTEGER NOT NULL,
-            updated_at INTEGER NOT NULL
+            updated_at INTEGER NOT NULL,
+            event_time INTEGER,
+            access_count INTEGER NOT NULL DEFAULT 0,
+            last_accessed INTEGER
         )",
         (),
     )
@@ -308,6 +311,44 @@ async fn test_sqlite_list_sessions_with_unprocessed() {
     assert!(!sessions.contains(&"session-3".to_string()));
 }
 
+#[tokio::test]
+async fn test_sqlite_prune_uses_persisted_access_counts() {
+    let temp_dir = tempfile::tempdir().expect("Failed to create temp dir");
+    let db_path = temp_dir.path().join("test.db");
+    let config = SqliteConfig::new(&db_path).with_max_connections(5);
+    let sqlite_backend = Arc::new(
+        SqliteBackend::new(config)
+            .await
+            .expect("Failed to create SqliteBackend"),
+    );
+    let provider: Arc<dyn EmbeddingProvider> = Arc::new(TestEmbeddingProvider);
+    let embedding_service = Arc::new(EmbeddingService::new(provider));
+    create_test_tables(&sqlite_backend, embedding_service.dimensions()).await;
+
+    // Read two of three entries, then drop the instance
+    {
+        let memory =
+            SqliteEpisodicMemory::new(Arc::clone(&sqlite_backend), Arc::clone(&embedding_service))
+                .await
+                .expect("Failed to create SqliteEpisodicMemory");
+        let mut ids = Vec::new();
+        for content in ["first", "second", "third"] {
+            let entry = EpisodicEntry::new("session-1".into(), "user".into(), content.into());
+            ids.push(memory.add(entry).await.expect("add failed"));
+        }
+        memory.get(&ids[0]).await.expect("get failed");
+        memory.get(&ids[1]).await.expect("get failed");
+    }
+
+    // A fresh instance (as after a restart) still sees the access counts
+    let memory = SqliteEpisodicMemory::new(sqlite_backend, embedding_service)
+        .await
+        .expect("Failed to create SqliteEpisodicMemory");
+    let policy = DecayPolicy::default().with_min_access_count(1);
+    assert_eq!(memory.prune(policy.clone()).await.expect("prune failed"), 1);
+    assert_eq!(memory.prune(policy).await.expect("prune failed"), 0);
+}
+
 #[tokio::test]
 #[ignore = "Persistence across instances requires cache hydration - deferred to future task"]
 async fn test_sqlite_persistence_across_instances() {
@@ -31,6 +31,7 @@ fn test_trait_types_compile() {
         properties: json!({"key": "value"}),
         event_time: Some(Utc::now()),
         ingestion_time: Utc::now(),
+        embedding: None,
     };
     assert_eq!(entity.id, "test-123");
     assert_eq!(entity.entity_type, "person");
@@ -112,6 +113,7 @@ fn test_entity_serialization() {
         properties: json!({"role": "engineer"}),
         event_time: Some(Utc::now()),
         ingestion_time: Utc::now(),
+        embedding: None,
     };
 
     // Serialize
@@ -36,6 +36,39 @@ pub struct ProviderCapabilities {
     pub custom_features: HashMap<String, serde_json::Value>,
 }
 
+impl ProviderCapabilities {
+    /// Ensure this provider can consume the attachments carried by `input`
+    ///
+    /// # Errors
+    ///
+    /// Returns a provider error naming the attachment kinds when `input`
+    /// has attachments but the provider is text-only
+    pub fn check_attachments(
+        &self,
+        input: &AgentInput,
+        provider: &str,
+    ) -> Result<(), LLMSpellError> {
+        if !input.has_attachments() || self.supports_multimodal {
+            return Ok(());
+        }
+
+        let kinds = input
+            .attachments()
+            .iter()
+            .map(|attachment| attachment.kind.to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+        Err(LLMSpellError::Provider {
+            message: format!(
+                "Provider '{provider}' does not support attachments ({kinds}); \
+                 use a multimodal provider or remove the attachments"
+            ),
+            provider: Some(provider.to_string()),
+            source: None,
+        })
+    }
+}
+
 /// Configuration for a provider instance
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ProviderConfig {
@@ -748,6 +781,29 @@ mod tests {
         assert!(caps.available_models.is_empty());
     }
     #[test]
+    fn test_text_only_provider_rejects_attachments() {
+        use llmspell_core::types::{Attachment, MediaType};
+
+        let input = AgentInput::text("What is in this picture?").with_attachment(Attachment::uri(
+            MediaType::Image,
+            "https://example.com/cat.png",
+        ));
+
+        let text_only = ProviderCapabilities::default();
+        let err = text_only.check_attachments(&input, "cohere").unwrap_err();
+        assert!(matches!(err, LLMSpellError::Provider { .. }));
+        assert!(err.to_string().contains("does not support attachments"));
+        assert!(text_only
+            .check_attachments(&AgentInput::text("plain"), "cohere")
+            .is_ok());
+
+        let vision = ProviderCapabilities {
+            supports_multimodal: true,
+            ..ProviderCapabilities::default()
+        };
+        assert!(vision.check_attachments(&input, "openai").is_ok());
+    }
+    #[test]
     fn test_provider_config_creation() {
         let config = ProviderConfig::new("openai", "gpt-4");
         assert_eq!(config.name, "openai");
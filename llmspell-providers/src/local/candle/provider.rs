@@ -489,6 +489,7 @@ impl ProviderInstance for CandleProvider {
             "CandleProvider completion request for model: {}",
             self.default_model
         );
+        self.capabilities.check_attachments(input, "candle")?;
 
         // Determine model path
         let model_path = self.model_directory.join(&self.default_model);
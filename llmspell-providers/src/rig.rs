@@ -205,11 +205,8 @@ impl RigProvider {
         trace!("Setting provider capabilities for {}", config.provider_type);
         // Set capabilities based on provider type and model
         let capabilities = ProviderCapabilities {
-            supports_streaming: false, // Rig doesn't expose streaming yet
-            supports_multimodal: matches!(
-                config.provider_type.as_str(),
-                "openai" | "anthropic" | "gemini"
-            ),
+            supports_streaming: false,  // Rig doesn't expose streaming yet
+            supports_multimodal: false, // Completions are sent as a single text prompt
             max_context_tokens: Some(match config.provider_type.as_str() {
                 "openai" => match config.model.as_str() {
                     "gpt-4" | "gpt-4-turbo" => 128000,
@@ -707,6 +704,10 @@ impl ProviderInstance for RigProvider {
         let span = span!(Level::INFO, "provider_completion");
         let _enter = span.enter();
 
+        // Rig completions are text-only, so attachments would otherwise be dropped
+        self.capabilities
+            .check_attachments(input, &self.config.name)?;
+
         // Record input metrics
         let input_length = input.text.len();
         span.record("input_length", input_length);
@@ -920,12 +921,34 @@ mod tests {
         if let Ok(provider) = RigProvider::new(config) {
             let caps = provider.capabilities();
             assert!(!caps.supports_streaming); // Rig doesn't support streaming yet
-            assert!(caps.supports_multimodal); // OpenAI supports multimodal
+            assert!(!caps.supports_multimodal); // Rig completions are text-only
             assert_eq!(caps.max_context_tokens, Some(128000)); // GPT-4 context size
             assert_eq!(caps.max_output_tokens, Some(4096));
             assert_eq!(caps.available_models, vec!["gpt-4"]);
         }
     }
+    #[tokio::test]
+    async fn test_attachments_rejected_before_request() {
+        use llmspell_core::types::{Attachment, MediaType};
+
+        let mut config = ProviderConfig::new("anthropic", "claude-3-opus");
+        config.api_key = Some("test-key".to_string());
+        // Unroutable endpoint: any request that is sent fails with a completion error
+        config.endpoint = Some("http://127.0.0.1:9".to_string());
+        let provider = RigProvider::new(config).unwrap();
+        assert!(!provider.capabilities().supports_multimodal);
+
+        let input = AgentInput::text("What is in this picture?").with_attachment(Attachment::uri(
+            MediaType::Image,
+            "https://example.com/cat.png",
+        ));
+        let err = provider.complete(&input).await.unwrap_err();
+        assert!(matches!(err, LLMSpellError::Provider { .. }));
+        assert!(err
+            .to_string()
+            .contains("does not support attachments (Image)"));
+        assert_eq!(provider.total_requests.load(Ordering::SeqCst), 0);
+    }
     #[test]
     fn test_anthropic_capabilities() {
         let mut config = ProviderConfig::new("anthropic", "claude-3-opus");
@@ -933,7 +956,7 @@ mod tests {
 
         if let Ok(provider) = RigProvider::new(config) {
             let caps = provider.capabilities();
-            assert!(caps.supports_multimodal); // Anthropic supports multimodal
+            assert!(!caps.supports_multimodal); // Rig completions are text-only
             assert_eq!(caps.max_context_tokens, Some(200000)); // Claude 3 Opus context size
         }
     }
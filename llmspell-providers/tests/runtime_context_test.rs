@@ -55,6 +55,7 @@ async fn test_provider_runtime_context_60_seconds() {
             context: None,
             parameters: HashMap::new(),
             output_modalities: vec![MediaType::Text],
+            attachments: vec![],
         };
 
         // Test provider completion - this exercises the HTTP client with global runtime
@@ -1,6 +1,6 @@
 //! Hybrid retrieval traits combining vector, keyword, and metadata search
 
-use anyhow::Result;
+use anyhow::{bail, Result};
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
@@ -14,6 +14,10 @@ use llmspell_core::types::storage::VectorResult;
 #[async_trait]
 pub trait HybridStorage: VectorStorage {
     /// Perform hybrid search combining vector, keyword, and metadata
+    ///
+    /// Per-query weights in `query.weights` override the configured weights;
+    /// implementations score with [`HybridQuery::fuse`] or
+    /// [`HybridQuery::resolve_weights`].
     async fn hybrid_search(&self, query: &HybridQuery) -> Result<Vec<HybridResult>>;
 
     /// Configure retrieval weights for different methods
@@ -70,6 +74,10 @@ pub struct HybridQuery {
 
     /// Whether to include explanations in results
     pub include_explanations: bool,
+
+    /// Weights for this query, overriding the storage's configured weights
+    #[serde(default)]
+    pub weights: Option<RetrievalWeights>,
 }
 
 impl HybridQuery {
@@ -85,6 +93,7 @@ impl HybridQuery {
             threshold: None,
             strategy: RetrievalStrategy::default(),
             include_explanations: false,
+            weights: None,
         }
     }
 
@@ -115,6 +124,51 @@ impl HybridQuery {
         self.strategy = strategy;
         self
     }
+
+    /// Override the storage's retrieval weights for this query
+    #[must_use]
+    pub const fn with_weights(mut self, weights: RetrievalWeights) -> Self {
+        self.weights = Some(weights);
+        self
+    }
+
+    /// Resolve the weights for this query, normalized to sum to 1.0
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the effective weights are invalid (see
+    /// [`RetrievalWeights::normalized`])
+    pub fn resolve_weights(&self, defaults: &RetrievalWeights) -> Result<RetrievalWeights> {
+        self.weights
+            .clone()
+            .unwrap_or_else(|| defaults.clone())
+            .normalized()
+    }
+
+    /// Score results by their component scores and the resolved weights
+    ///
+    /// Returns the top `k` results above the threshold, best first. Component
+    /// scores are kept so callers can see how each signal contributed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the effective weights are invalid
+    pub fn fuse(
+        &self,
+        mut results: Vec<HybridResult>,
+        defaults: &RetrievalWeights,
+    ) -> Result<Vec<HybridResult>> {
+        let weights = self.resolve_weights(defaults)?;
+        for result in &mut results {
+            result.score = weights.combine(&result.component_scores);
+        }
+        if let Some(threshold) = self.threshold {
+            results.retain(|r| r.score >= threshold);
+        }
+        results.sort_by(|a, b| b.score.total_cmp(&a.score));
+        results.truncate(self.k);
+        Ok(results)
+    }
 }
 
 /// Result from hybrid search
@@ -218,6 +272,40 @@ impl RetrievalWeights {
             self.metadata_weight /= sum;
         }
     }
+
+    /// Validate and normalize weights to sum to 1.0
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any weight is negative or not finite, or if all are zero
+    pub fn normalized(mut self) -> Result<Self> {
+        let weights = [
+            self.vector_weight,
+            self.keyword_weight,
+            self.metadata_weight,
+        ];
+        if weights.iter().any(|w| !w.is_finite() || *w < 0.0) {
+            bail!("Retrieval weights must be finite and non-negative: {self:?}");
+        }
+        if weights.iter().sum::<f32>() <= 0.0 {
+            bail!("Retrieval weights must not all be zero");
+        }
+        self.normalize();
+        Ok(self)
+    }
+
+    /// Weighted sum of component scores; missing components score zero
+    #[must_use]
+    pub fn combine(&self, scores: &ComponentScores) -> f32 {
+        [
+            (self.vector_weight, scores.vector_score),
+            (self.keyword_weight, scores.keyword_score),
+            (self.metadata_weight, scores.metadata_score),
+        ]
+        .iter()
+        .map(|(weight, score)| weight * score.unwrap_or(0.0))
+        .sum()
+    }
 }
 
 /// Retrieval strategy
@@ -309,4 +397,51 @@ mod tests {
         assert!((weights.keyword_weight - 0.25).abs() < 0.001);
         assert!((weights.metadata_weight - 0.25).abs() < 0.001);
     }
+
+    #[test]
+    fn test_per_query_weights_change_ordering() {
+        let candidate = |id: &str, vector: f32, keyword: f32| HybridResult {
+            id: id.to_string(),
+            score: 0.0,
+            component_scores: ComponentScores {
+                vector_score: Some(vector),
+                keyword_score: Some(keyword),
+                ..Default::default()
+            },
+            content: None,
+            metadata: None,
+            explanation: None,
+        };
+        let candidates = vec![
+            candidate("semantic-match", 0.9, 0.1),
+            candidate("exact-match", 0.2, 0.95),
+        ];
+        let defaults = RetrievalWeights::default();
+
+        let vector_heavy = HybridQuery::new(10).with_weights(RetrievalWeights {
+            vector_weight: 8.0,
+            keyword_weight: 2.0,
+            metadata_weight: 0.0,
+        });
+        let results = vector_heavy.fuse(candidates.clone(), &defaults).unwrap();
+        assert_eq!(results[0].id, "semantic-match");
+        assert!((results[0].score - 0.74).abs() < 0.001);
+        assert_eq!(results[0].component_scores.keyword_score, Some(0.1));
+
+        let keyword_heavy = HybridQuery::new(10).with_weights(RetrievalWeights {
+            vector_weight: 0.2,
+            keyword_weight: 0.8,
+            metadata_weight: 0.0,
+        });
+        let results = keyword_heavy.fuse(candidates.clone(), &defaults).unwrap();
+        assert_eq!(results[0].id, "exact-match");
+        assert_eq!(results[0].component_scores.vector_score, Some(0.2));
+
+        let all_zero = HybridQuery::new(10).with_weights(RetrievalWeights {
+            vector_weight: 0.0,
+            keyword_weight: 0.0,
+            metadata_weight: 0.0,
+        });
+        assert!(all_zero.fuse(candidates, &defaults).is_err());
+    }
 }
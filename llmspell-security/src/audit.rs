@@ -4,6 +4,7 @@ use anyhow::Result;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::time::{Duration, Instant};
 use tokio::sync::mpsc;
 use tracing::{error, info};
 
@@ -62,6 +63,38 @@ pub enum AuditEvent {
     },
 }
 
+impl AuditEvent {
+    /// Key identifying repeats of this event for coalescing
+    ///
+    /// Only violations are coalesced, since those are what a misbehaving
+    /// script emits in a loop. Metadata and the current rate-limit count are
+    /// excluded so otherwise identical violations share a key.
+    pub fn coalesce_key(&self) -> Option<String> {
+        match self {
+            Self::AccessDenied {
+                principal,
+                operation,
+                resource,
+                reason,
+                ..
+            } => Some(format!(
+                "access_denied:{principal}:{operation}:{resource}:{reason}"
+            )),
+            Self::RateLimitExceeded {
+                principal, limit, ..
+            } => Some(format!("rate_limit_exceeded:{principal}:{limit}")),
+            Self::SuspiciousActivity {
+                principal,
+                activity,
+                details,
+            } => Some(format!(
+                "suspicious_activity:{principal}:{activity}:{details}"
+            )),
+            _ => None,
+        }
+    }
+}
+
 /// Audit log entry
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AuditEntry {
@@ -82,6 +115,14 @@ pub struct AuditEntry {
 
     /// Correlation ID for related events
     pub correlation_id: Option<String>,
+
+    /// Number of identical events this entry stands for
+    #[serde(default = "default_count")]
+    pub count: u32,
+}
+
+const fn default_count() -> u32 {
+    1
 }
 
 impl AuditEntry {
@@ -94,6 +135,7 @@ impl AuditEntry {
             source_ip: None,
             session_id: None,
             correlation_id: None,
+            count: 1,
         }
     }
 
@@ -116,6 +158,87 @@ impl AuditEntry {
     }
 }
 
+/// Coalesces repeated violations into a single audit entry
+///
+/// The first occurrence of a violation opens a window for its
+/// [`AuditEvent::coalesce_key`]. Identical violations within the window only
+/// increment the held entry's `count`, and the entry is released once the
+/// window closes. Events without a key pass through immediately.
+#[derive(Debug)]
+pub struct AuditCoalescer {
+    /// Window over which identical violations are collapsed
+    window: Duration,
+    /// Held entries keyed by coalesce key, with the time their window opened
+    pending: HashMap<String, (AuditEntry, Instant)>,
+}
+
+impl AuditCoalescer {
+    /// Create a coalescer with the given window
+    pub fn new(window: Duration) -> Self {
+        Self {
+            window,
+            pending: HashMap::new(),
+        }
+    }
+
+    /// Coalescing window
+    pub fn window(&self) -> Duration {
+        self.window
+    }
+
+    /// Observe an entry, returning any entry ready to be written now
+    pub fn observe(&mut self, entry: AuditEntry) -> Option<AuditEntry> {
+        self.observe_at(entry, Instant::now())
+    }
+
+    /// Observe an entry at a given time
+    ///
+    /// Returns the entry itself when it is not coalesced, or a held entry
+    /// whose window had already closed when a repeat arrived.
+    pub fn observe_at(&mut self, entry: AuditEntry, now: Instant) -> Option<AuditEntry> {
+        let Some(key) = entry.event.coalesce_key() else {
+            return Some(entry);
+        };
+
+        match self.pending.get_mut(&key) {
+            Some((held, opened)) if now.duration_since(*opened) < self.window => {
+                held.count = held.count.saturating_add(1);
+                None
+            }
+            _ => self
+                .pending
+                .insert(key, (entry, now))
+                .map(|(expired, _)| expired),
+        }
+    }
+
+    /// Release held entries whose window has closed, oldest first
+    pub fn flush_expired(&mut self, now: Instant) -> Vec<AuditEntry> {
+        let expired: Vec<String> = self
+            .pending
+            .iter()
+            .filter(|(_, (_, opened))| now.duration_since(*opened) >= self.window)
+            .map(|(key, _)| key.clone())
+            .collect();
+
+        let mut entries: Vec<AuditEntry> = expired
+            .iter()
+            .filter_map(|key| self.pending.remove(key))
+            .map(|(entry, _)| entry)
+            .collect();
+        entries.sort_by_key(|entry| entry.timestamp);
+        entries
+    }
+
+    /// Release every held entry regardless of its window
+    pub fn flush_all(&mut self) -> Vec<AuditEntry> {
+        let mut entries: Vec<AuditEntry> =
+            self.pending.drain().map(|(_, (entry, _))| entry).collect();
+        entries.sort_by_key(|entry| entry.timestamp);
+        entries
+    }
+}
+
 /// Audit logger
 pub struct AuditLogger {
     /// Channel for async logging
@@ -130,114 +253,40 @@ impl AuditLogger {
         // Spawn background task for processing audit logs
         tokio::spawn(async move {
             while let Some(entry) = receiver.recv().await {
-                // Log to tracing
-                match &entry.event {
-                    AuditEvent::AccessGranted {
-                        principal,
-                        operation,
-                        resource,
-                        ..
-                    } => {
-                        info!(
-                            audit = true,
-                            event_type = "access_granted",
-                            principal = %principal,
-                            operation = %operation,
-                            resource = %resource,
-                            "Access granted"
-                        );
-                    }
-                    AuditEvent::AccessDenied {
-                        principal,
-                        operation,
-                        resource,
-                        reason,
-                        ..
-                    } => {
-                        info!(
-                            audit = true,
-                            event_type = "access_denied",
-                            principal = %principal,
-                            operation = %operation,
-                            resource = %resource,
-                            reason = %reason,
-                            "Access denied"
-                        );
-                    }
-                    AuditEvent::RateLimitExceeded {
-                        principal,
-                        limit,
-                        current,
-                    } => {
-                        info!(
-                            audit = true,
-                            event_type = "rate_limit_exceeded",
-                            principal = %principal,
-                            limit = limit,
-                            current = current,
-                            "Rate limit exceeded"
-                        );
-                    }
-                    AuditEvent::TenantCreated {
-                        tenant_id,
-                        created_by,
-                    } => {
-                        info!(
-                            audit = true,
-                            event_type = "tenant_created",
-                            tenant_id = %tenant_id,
-                            created_by = %created_by,
-                            "Tenant created"
-                        );
-                    }
-                    AuditEvent::TenantDeleted {
-                        tenant_id,
-                        deleted_by,
-                        vectors_removed,
-                    } => {
-                        info!(
-                            audit = true,
-                            event_type = "tenant_deleted",
-                            tenant_id = %tenant_id,
-                            deleted_by = %deleted_by,
-                            vectors_removed = vectors_removed,
-                            "Tenant deleted"
-                        );
-                    }
-                    AuditEvent::ConfigurationChanged {
-                        tenant_id,
-                        changed_by,
-                        ..
-                    } => {
-                        info!(
-                            audit = true,
-                            event_type = "configuration_changed",
-                            tenant_id = %tenant_id,
-                            changed_by = %changed_by,
-                            "Configuration changed"
-                        );
-                    }
-                    AuditEvent::SuspiciousActivity {
-                        principal,
-                        activity,
-                        details,
-                    } => {
-                        info!(
-                            audit = true,
-                            event_type = "suspicious_activity",
-                            principal = %principal,
-                            activity = %activity,
-                            details = %details,
-                            "Suspicious activity detected"
-                        );
+                record(&entry);
+            }
+        });
+
+        Self { sender }
+    }
+
+    /// Create an audit logger that coalesces repeated violations
+    ///
+    /// Identical violations within `window` are written as one entry with an
+    /// occurrence count; see [`AuditCoalescer`].
+    pub fn with_coalescing(window: Duration) -> Self {
+        let (sender, mut receiver) = mpsc::unbounded_channel::<AuditEntry>();
+        let mut coalescer = AuditCoalescer::new(window);
+
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(window.max(Duration::from_millis(1)));
+            loop {
+                tokio::select! {
+                    received = receiver.recv() => match received {
+                        Some(entry) => {
+                            if let Some(ready) = coalescer.observe(entry) {
+                                record(&ready);
+                            }
+                        }
+                        None => {
+                            coalescer.flush_all().iter().for_each(record);
+                            break;
+                        }
+                    },
+                    _ = ticker.tick() => {
+                        coalescer.flush_expired(Instant::now()).iter().for_each(record);
                     }
                 }
-
-                // Here you could also:
-                // - Write to a database
-                // - Send to an external audit service
-                // - Write to a file
-                // - Send alerts for critical events
             }
         });
 
@@ -291,6 +340,127 @@ impl Default for AuditLogger {
     }
 }
 
+/// Write an audit entry to the configured outputs
+fn record(entry: &AuditEntry) {
+    let count = entry.count;
+
+    // Log to tracing
+    match &entry.event {
+        AuditEvent::AccessGranted {
+            principal,
+            operation,
+            resource,
+            ..
+        } => {
+            info!(
+                audit = true,
+                count = count,
+                event_type = "access_granted",
+                principal = %principal,
+                operation = %operation,
+                resource = %resource,
+                "Access granted"
+            );
+        }
+        AuditEvent::AccessDenied {
+            principal,
+            operation,
+            resource,
+            reason,
+            ..
+        } => {
+            info!(
+                audit = true,
+                count = count,
+                event_type = "access_denied",
+                principal = %principal,
+                operation = %operation,
+                resource = %resource,
+                reason = %reason,
+                "Access denied"
+            );
+        }
+        AuditEvent::RateLimitExceeded {
+            principal,
+            limit,
+            current,
+        } => {
+            info!(
+                audit = true,
+                count = count,
+                event_type = "rate_limit_exceeded",
+                principal = %principal,
+                limit = limit,
+                current = current,
+                "Rate limit exceeded"
+            );
+        }
+        AuditEvent::TenantCreated {
+            tenant_id,
+            created_by,
+        } => {
+            info!(
+                audit = true,
+                count = count,
+                event_type = "tenant_created",
+                tenant_id = %tenant_id,
+                created_by = %created_by,
+                "Tenant created"
+            );
+        }
+        AuditEvent::TenantDeleted {
+            tenant_id,
+            deleted_by,
+            vectors_removed,
+        } => {
+            info!(
+                audit = true,
+                count = count,
+                event_type = "tenant_deleted",
+                tenant_id = %tenant_id,
+                deleted_by = %deleted_by,
+                vectors_removed = vectors_removed,
+                "Tenant deleted"
+            );
+        }
+        AuditEvent::ConfigurationChanged {
+            tenant_id,
+            changed_by,
+            ..
+        } => {
+            info!(
+                audit = true,
+                count = count,
+                event_type = "configuration_changed",
+                tenant_id = %tenant_id,
+                changed_by = %changed_by,
+                "Configuration changed"
+            );
+        }
+        AuditEvent::SuspiciousActivity {
+            principal,
+            activity,
+            details,
+        } => {
+            info!(
+                audit = true,
+                count = count,
+                event_type = "suspicious_activity",
+                principal = %principal,
+                activity = %activity,
+                details = %details,
+                "Suspicious activity detected"
+            );
+        }
+    }
+
+    // Here you could also:
+    // - Write to a database
+    // - Send to an external audit service
+    // - Write to a file
+    // - Send alerts for critical events
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -346,4 +516,52 @@ mod tests {
         // Give time for async processing
         tokio::time::sleep(tokio::time::Duration::from_millis(10)).await;
     }
+
+    #[test]
+    fn test_coalesces_repeated_violations() {
+        let violation = |principal: &str| {
+            AuditEntry::new(AuditEvent::AccessDenied {
+                principal: principal.to_string(),
+                operation: "read".to_string(),
+                resource: "/etc/passwd".to_string(),
+                reason: "Path outside sandbox".to_string(),
+                metadata: HashMap::new(),
+            })
+        };
+        let mut coalescer = AuditCoalescer::new(Duration::from_secs(1));
+        let start = Instant::now();
+
+        for i in 0..100 {
+            let now = start + Duration::from_millis(i);
+            assert!(coalescer.observe_at(violation("script"), now).is_none());
+        }
+
+        // Distinct events are not held back or merged
+        let granted = coalescer
+            .observe_at(
+                AuditEntry::new(AuditEvent::AccessGranted {
+                    principal: "script".to_string(),
+                    operation: "read".to_string(),
+                    resource: "/tmp/data".to_string(),
+                    metadata: HashMap::new(),
+                }),
+                start,
+            )
+            .unwrap();
+        assert_eq!(granted.count, 1);
+        assert!(coalescer
+            .observe_at(violation("other"), start + Duration::from_millis(200))
+            .is_none());
+
+        assert!(coalescer
+            .flush_expired(start + Duration::from_millis(500))
+            .is_empty());
+        let flushed = coalescer.flush_expired(start + Duration::from_secs(1));
+        assert_eq!(flushed.len(), 1);
+        assert_eq!(flushed[0].count, 100);
+
+        let remaining = coalescer.flush_all();
+        assert_eq!(remaining.len(), 1);
+        assert_eq!(remaining[0].count, 1);
+    }
 }
@@ -10,8 +10,8 @@ pub use access_control::{
     AccessDecision, OperationContext, SecurityFilter, SecurityPolicy, VectorAccessPolicy,
     VectorSecurityManager,
 };
-pub use audit::{AuditEntry, AuditEvent, AuditLogger};
+pub use audit::{AuditCoalescer, AuditEntry, AuditEvent, AuditLogger};
 pub use sandbox::{
-    FileSandbox, IntegratedSandbox, NetworkSandbox, ResourceMonitor, SandboxContext,
+    EnvSandbox, FileSandbox, IntegratedSandbox, NetworkSandbox, ResourceMonitor, SandboxContext,
     SandboxViolation,
 };
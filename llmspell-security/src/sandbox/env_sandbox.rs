@@ -0,0 +1,138 @@
+//! ABOUTME: Environment variable access control sandbox
+//! ABOUTME: Restricts which process environment variables sandboxed code can read
+
+use super::{SandboxContext, SandboxViolation};
+use llmspell_core::{error::LLMSpellError, Result};
+use std::collections::HashMap;
+use tracing::{debug, warn};
+
+/// Environment sandbox for controlling environment variable access
+///
+/// Variable names are matched against an allowlist and an optional
+/// denylist. Patterns are exact names or use `*` as a prefix, suffix or
+/// infix wildcard (`"LC_*"`, `"*_TOKEN"`, `"*SECRET*"`). The denylist takes
+/// precedence, and an empty allowlist permits nothing.
+#[derive(Debug, Clone, Default)]
+pub struct EnvSandbox {
+    allowed: Vec<String>,
+    denied: Vec<String>,
+}
+
+impl EnvSandbox {
+    /// Create an environment sandbox with the given allowlist
+    pub fn new(allowed: Vec<String>) -> Self {
+        Self {
+            allowed,
+            denied: Vec::new(),
+        }
+    }
+
+    /// Create an environment sandbox from a sandbox context's allowed variables
+    pub fn from_context(context: &SandboxContext) -> Self {
+        Self::new(context.allowed_env_vars.clone())
+    }
+
+    /// Set the denylist, which overrides the allowlist
+    #[must_use]
+    pub fn with_denylist(mut self, denied: Vec<String>) -> Self {
+        self.denied = denied;
+        self
+    }
+
+    /// Allowed variable patterns
+    pub fn allowed(&self) -> &[String] {
+        &self.allowed
+    }
+
+    /// Denied variable patterns
+    pub fn denied(&self) -> &[String] {
+        &self.denied
+    }
+
+    /// Check if an environment variable may be read
+    pub fn is_allowed(&self, name: &str) -> bool {
+        if let Some(pattern) = self.denied.iter().find(|p| matches_pattern(name, p)) {
+            debug!(
+                "Environment variable '{}' denied by pattern '{}'",
+                name, pattern
+            );
+            return false;
+        }
+
+        self.allowed.iter().any(|p| matches_pattern(name, p))
+    }
+
+    /// Validate that an environment variable may be read
+    pub fn check_access(&self, name: &str) -> Result<()> {
+        if self.is_allowed(name) {
+            return Ok(());
+        }
+
+        let violation = SandboxViolation::EnvironmentAccess {
+            variable: name.to_string(),
+            reason: "Variable not in allowed list".to_string(),
+        };
+        warn!("Environment access violation: {}", violation);
+        Err(LLMSpellError::Security {
+            message: violation.to_string(),
+            violation_type: Some("env_access".to_string()),
+        })
+    }
+
+    /// Keep only the variables this sandbox allows
+    pub fn filter<I>(&self, vars: I) -> HashMap<String, String>
+    where
+        I: IntoIterator<Item = (String, String)>,
+    {
+        vars.into_iter()
+            .filter(|(name, _)| self.is_allowed(name))
+            .collect()
+    }
+}
+
+/// Simple glob pattern matching for environment variable names
+fn matches_pattern(name: &str, pattern: &str) -> bool {
+    if pattern == "*" {
+        return true;
+    }
+
+    if let Some(middle) = pattern
+        .strip_prefix('*')
+        .and_then(|rest| rest.strip_suffix('*'))
+    {
+        return name.contains(middle);
+    }
+    if let Some(suffix) = pattern.strip_prefix('*') {
+        return name.ends_with(suffix);
+    }
+    if let Some(prefix) = pattern.strip_suffix('*') {
+        return name.starts_with(prefix);
+    }
+
+    name == pattern
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test_allowlist_and_denylist() {
+        let sandbox = EnvSandbox::new(vec!["PATH".to_string(), "APP_*".to_string()])
+            .with_denylist(vec!["*SECRET*".to_string()]);
+
+        assert!(sandbox.is_allowed("PATH"));
+        assert!(sandbox.is_allowed("APP_MODE"));
+        assert!(!sandbox.is_allowed("APP_SECRET_KEY"));
+        assert!(!sandbox.is_allowed("HOME"));
+        assert!(sandbox.check_access("SECRET").is_err());
+
+        let filtered = sandbox.filter([
+            ("PATH".to_string(), "/usr/bin".to_string()),
+            ("AWS_SECRET_ACCESS_KEY".to_string(), "x".to_string()),
+        ]);
+        assert_eq!(filtered.len(), 1);
+        assert!(filtered.contains_key("PATH"));
+
+        assert!(!EnvSandbox::default().is_allowed("PATH"));
+    }
+}
@@ -1,10 +1,12 @@
 //! ABOUTME: Security sandbox for safe tool execution
-//! ABOUTME: Provides file system, network, and resource monitoring controls
+//! ABOUTME: Provides file system, network, environment, and resource monitoring controls
 
+pub mod env_sandbox;
 pub mod file_sandbox;
 pub mod network_sandbox;
 pub mod resource_monitor;
 
+pub use env_sandbox::EnvSandbox;
 pub use file_sandbox::FileSandbox;
 pub use network_sandbox::NetworkSandbox;
 pub use resource_monitor::ResourceMonitor;
@@ -76,7 +76,10 @@ mod sqlite_benchmarks {
                 dimension INTEGER NOT NULL CHECK (dimension IN (384, 768, 1536, 3072)),
                 metadata TEXT NOT NULL DEFAULT '{}',
                 created_at INTEGER NOT NULL,
-                updated_at INTEGER NOT NULL
+                updated_at INTEGER NOT NULL,
+                event_time INTEGER,
+                access_count INTEGER NOT NULL DEFAULT 0,
+                last_accessed INTEGER
             )",
             (),
         )
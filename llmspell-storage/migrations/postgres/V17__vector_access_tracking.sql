@@ -0,0 +1,42 @@
+-- Migration V17: Vector Access Tracking (Phase 13b - Vector Storage)
+--
+-- Records event time and read statistics per vector so pruning by age, count
+-- and access count can run as a single query and survives restarts.
+--
+-- Design:
+--   - event_time: when the event occurred, NULL falls back to created_at
+--   - access_count: number of recorded reads, starting at 0
+--   - last_accessed: time of the most recent read, NULL if never read
+--
+-- Dependencies:
+--   - V3: Vector embeddings (vector_embeddings_* tables)
+
+ALTER TABLE llmspell.vector_embeddings_384
+    ADD COLUMN IF NOT EXISTS event_time TIMESTAMPTZ,
+    ADD COLUMN IF NOT EXISTS access_count BIGINT NOT NULL DEFAULT 0,
+    ADD COLUMN IF NOT EXISTS last_accessed TIMESTAMPTZ;
+
+ALTER TABLE llmspell.vector_embeddings_768
+    ADD COLUMN IF NOT EXISTS event_time TIMESTAMPTZ,
+    ADD COLUMN IF NOT EXISTS access_count BIGINT NOT NULL DEFAULT 0,
+    ADD COLUMN IF NOT EXISTS last_accessed TIMESTAMPTZ;
+
+ALTER TABLE llmspell.vector_embeddings_1536
+    ADD COLUMN IF NOT EXISTS event_time TIMESTAMPTZ,
+    ADD COLUMN IF NOT EXISTS access_count BIGINT NOT NULL DEFAULT 0,
+    ADD COLUMN IF NOT EXISTS last_accessed TIMESTAMPTZ;
+
+ALTER TABLE llmspell.vector_embeddings_3072
+    ADD COLUMN IF NOT EXISTS event_time TIMESTAMPTZ,
+    ADD COLUMN IF NOT EXISTS access_count BIGINT NOT NULL DEFAULT 0,
+    ADD COLUMN IF NOT EXISTS last_accessed TIMESTAMPTZ;
+
+-- Pruning ranks a scope's vectors by event time
+CREATE INDEX IF NOT EXISTS idx_vector_384_scope_event_time
+    ON llmspell.vector_embeddings_384(scope, event_time);
+CREATE INDEX IF NOT EXISTS idx_vector_768_scope_event_time
+    ON llmspell.vector_embeddings_768(scope, event_time);
+CREATE INDEX IF NOT EXISTS idx_vector_1536_scope_event_time
+    ON llmspell.vector_embeddings_1536(scope, event_time);
+CREATE INDEX IF NOT EXISTS idx_vector_3072_scope_event_time
+    ON llmspell.vector_embeddings_3072(scope, event_time);
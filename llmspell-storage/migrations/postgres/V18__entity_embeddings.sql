@@ -0,0 +1,79 @@
+-- Migration V18: Entity Embeddings (Phase 13b - Graph Storage)
+--
+-- Stores optional entity embeddings for "find entities like this one" queries
+-- over the knowledge graph.
+--
+-- Design:
+--   - One embedding per logical entity (entity_id), shared by all its versions
+--   - Untyped VECTOR column so any dimension can be stored; similarity search
+--     compares only equal dimensions
+--   - Partial HNSW indexes per common dimension (pgvector cannot index an
+--     untyped column, and HNSW is limited to 2000 dimensions)
+--   - No foreign key: entity_id is not unique since V15 (application-enforced)
+--
+-- Dependencies:
+--   - V1: Initial setup (pgvector extension, llmspell schema)
+--   - V15: Bi-temporal composite keys
+
+CREATE TABLE IF NOT EXISTS llmspell.entity_embeddings (
+    entity_id UUID PRIMARY KEY,
+    tenant_id VARCHAR(255) NOT NULL,
+    dimension INTEGER NOT NULL CHECK (dimension > 0),
+    embedding VECTOR NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+
+    CHECK (vector_dims(embedding) = dimension)
+);
+
+-- Similarity search filters a tenant's embeddings of one dimension
+CREATE INDEX IF NOT EXISTS idx_entity_embeddings_tenant_dimension
+    ON llmspell.entity_embeddings(tenant_id, dimension);
+
+-- HNSW indexes (cosine distance); queries must cast to the same dimension
+CREATE INDEX IF NOT EXISTS idx_entity_embeddings_384_hnsw
+    ON llmspell.entity_embeddings
+    USING hnsw ((embedding::vector(384)) vector_cosine_ops)
+    WHERE dimension = 384;
+
+CREATE INDEX IF NOT EXISTS idx_entity_embeddings_768_hnsw
+    ON llmspell.entity_embeddings
+    USING hnsw ((embedding::vector(768)) vector_cosine_ops)
+    WHERE dimension = 768;
+
+CREATE INDEX IF NOT EXISTS idx_entity_embeddings_1536_hnsw
+    ON llmspell.entity_embeddings
+    USING hnsw ((embedding::vector(1536)) vector_cosine_ops)
+    WHERE dimension = 1536;
+
+-- Enable RLS and apply policies (Phase 13b.3 pattern: DROP before CREATE)
+ALTER TABLE llmspell.entity_embeddings ENABLE ROW LEVEL SECURITY;
+
+DROP POLICY IF EXISTS tenant_isolation_select ON llmspell.entity_embeddings;
+CREATE POLICY tenant_isolation_select ON llmspell.entity_embeddings
+    FOR SELECT
+    USING (tenant_id = current_setting('app.current_tenant_id', true));
+
+DROP POLICY IF EXISTS tenant_isolation_insert ON llmspell.entity_embeddings;
+CREATE POLICY tenant_isolation_insert ON llmspell.entity_embeddings
+    FOR INSERT
+    WITH CHECK (tenant_id = current_setting('app.current_tenant_id', true));
+
+DROP POLICY IF EXISTS tenant_isolation_update ON llmspell.entity_embeddings;
+CREATE POLICY tenant_isolation_update ON llmspell.entity_embeddings
+    FOR UPDATE
+    USING (tenant_id = current_setting('app.current_tenant_id', true))
+    WITH CHECK (tenant_id = current_setting('app.current_tenant_id', true));
+
+DROP POLICY IF EXISTS tenant_isolation_delete ON llmspell.entity_embeddings;
+CREATE POLICY tenant_isolation_delete ON llmspell.entity_embeddings
+    FOR DELETE
+    USING (tenant_id = current_setting('app.current_tenant_id', true));
+
+-- Grant table permissions to llmspell_app role (created in V12)
+DO $$
+BEGIN
+    IF EXISTS (SELECT 1 FROM pg_roles WHERE rolname = 'llmspell_app') THEN
+        GRANT SELECT, INSERT, UPDATE, DELETE ON llmspell.entity_embeddings TO llmspell_app;
+    END IF;
+END $$;
@@ -0,0 +1,29 @@
+-- Migration V16: Entity Embeddings (SQLite)
+--
+-- Stores optional entity embeddings for "find entities like this one" queries
+-- over the knowledge graph.
+--
+-- Design:
+--   - One embedding per entity, removed with the entity (ON DELETE CASCADE)
+--   - Embeddings are JSON arrays, matching the vec_embeddings_* encoding
+--   - Any dimension; similarity search compares only equal dimensions
+--
+-- Dependencies:
+--   - V1: Initial setup (PRAGMA, _migrations table)
+--   - V4: Temporal graph (entities table)
+
+CREATE TABLE IF NOT EXISTS entity_embeddings (
+    entity_id TEXT PRIMARY KEY REFERENCES entities(entity_id) ON DELETE CASCADE,
+    tenant_id TEXT NOT NULL,
+    dimension INTEGER NOT NULL CHECK (dimension > 0),
+    embedding TEXT NOT NULL,
+    created_at INTEGER NOT NULL
+);
+
+-- Similarity search scans a tenant's embeddings of one dimension
+CREATE INDEX IF NOT EXISTS idx_entity_embeddings_tenant_dimension
+ON entity_embeddings(tenant_id, dimension);
+
+-- Insert V16 migration record
+INSERT OR IGNORE INTO _migrations (version, name, checksum)
+VALUES (16, 'entity_embeddings', 'v16-entity-embeddings');
@@ -0,0 +1,28 @@
+-- Migration V17: Vector Access Tracking (SQLite)
+--
+-- Records event time and read statistics per vector so pruning by age, count
+-- and access count can run as a single query and survives restarts.
+--
+-- Design:
+--   - event_time: when the event occurred (Unix seconds), NULL falls back to created_at
+--   - access_count: number of recorded reads, starting at 0
+--   - last_accessed: Unix seconds of the most recent read, NULL if never read
+--
+-- SQLite has no ADD COLUMN IF NOT EXISTS, so SqliteBackend::run_migrations()
+-- applies this file only when version 17 is not yet recorded.
+--
+-- Dependencies:
+--   - V1: Initial setup (PRAGMA, _migrations table)
+--   - V3: Vector embeddings (vector_metadata table)
+
+ALTER TABLE vector_metadata ADD COLUMN event_time INTEGER;
+ALTER TABLE vector_metadata ADD COLUMN access_count INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE vector_metadata ADD COLUMN last_accessed INTEGER;
+
+-- Pruning ranks a scope's vectors by event time
+CREATE INDEX IF NOT EXISTS idx_vector_metadata_scope_event_time
+ON vector_metadata(scope, event_time);
+
+-- Insert V17 migration record
+INSERT OR IGNORE INTO _migrations (version, name, checksum)
+VALUES (17, 'vector_access_tracking', 'v17-vector-access-tracking');
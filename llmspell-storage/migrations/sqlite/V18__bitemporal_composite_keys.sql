@@ -0,0 +1,157 @@
+-- Migration V18: Bi-Temporal Composite Keys (SQLite)
+--
+-- Problem:
+--   V4 created entities and relationships with single-column primary keys
+--   (entity_id and relationship_id). This prevents storing multiple temporal
+--   versions of the same logical entity/relationship.
+--
+-- Solution:
+--   Rebuild the tables with composite primary keys (id, transaction_time_start),
+--   mirroring PostgreSQL V15. SQLite cannot alter primary keys in place.
+--
+-- Trade-offs:
+--   - Foreign keys to entities(entity_id) are dropped (relationships and
+--     entity_embeddings), since entity_id is no longer unique
+--   - Referential integrity and cleanup become application-enforced
+--
+-- SqliteBackend::run_migrations() applies this file only when version 18 is
+-- not yet recorded.
+--
+-- Dependencies:
+--   - V4: Temporal graph (entities, relationships)
+--   - V16: Entity embeddings
+
+PRAGMA foreign_keys = OFF;
+
+BEGIN;
+
+-- ============================================================================
+-- Step 1: Entities keyed by (entity_id, transaction_time_start)
+-- ============================================================================
+
+CREATE TABLE entities_v18 (
+    entity_id TEXT NOT NULL,  -- UUID as TEXT
+    tenant_id TEXT NOT NULL,
+    entity_type TEXT NOT NULL,
+    name TEXT NOT NULL,
+    properties TEXT NOT NULL DEFAULT '{}',  -- JSON as TEXT
+
+    valid_time_start INTEGER NOT NULL,
+    valid_time_end INTEGER NOT NULL DEFAULT 9999999999,
+    transaction_time_start INTEGER NOT NULL DEFAULT (strftime('%s', 'now')),
+    transaction_time_end INTEGER NOT NULL DEFAULT 9999999999,
+
+    created_at INTEGER NOT NULL DEFAULT (strftime('%s', 'now')),
+
+    PRIMARY KEY (entity_id, transaction_time_start),
+    CHECK (valid_time_start < valid_time_end),
+    CHECK (transaction_time_start < transaction_time_end)
+);
+
+INSERT INTO entities_v18
+SELECT entity_id, tenant_id, entity_type, name, properties,
+       valid_time_start, valid_time_end,
+       transaction_time_start, transaction_time_end, created_at
+FROM entities;
+
+-- ============================================================================
+-- Step 2: Relationships keyed by (relationship_id, transaction_time_start)
+-- ============================================================================
+
+CREATE TABLE relationships_v18 (
+    relationship_id TEXT NOT NULL,  -- UUID as TEXT
+    tenant_id TEXT NOT NULL,
+    from_entity TEXT NOT NULL,
+    to_entity TEXT NOT NULL,
+    relationship_type TEXT NOT NULL,
+    properties TEXT NOT NULL DEFAULT '{}',  -- JSON as TEXT
+
+    valid_time_start INTEGER NOT NULL,
+    valid_time_end INTEGER NOT NULL DEFAULT 9999999999,
+    transaction_time_start INTEGER NOT NULL DEFAULT (strftime('%s', 'now')),
+    transaction_time_end INTEGER NOT NULL DEFAULT 9999999999,
+
+    created_at INTEGER NOT NULL DEFAULT (strftime('%s', 'now')),
+
+    PRIMARY KEY (relationship_id, transaction_time_start),
+    CHECK (valid_time_start < valid_time_end),
+    CHECK (transaction_time_start < transaction_time_end)
+);
+
+INSERT INTO relationships_v18
+SELECT relationship_id, tenant_id, from_entity, to_entity, relationship_type, properties,
+       valid_time_start, valid_time_end,
+       transaction_time_start, transaction_time_end, created_at
+FROM relationships;
+
+-- ============================================================================
+-- Step 3: Entity embeddings without the foreign key
+-- ============================================================================
+
+CREATE TABLE entity_embeddings_v18 (
+    entity_id TEXT PRIMARY KEY,
+    tenant_id TEXT NOT NULL,
+    dimension INTEGER NOT NULL CHECK (dimension > 0),
+    embedding TEXT NOT NULL,
+    created_at INTEGER NOT NULL
+);
+
+INSERT INTO entity_embeddings_v18
+SELECT entity_id, tenant_id, dimension, embedding, created_at
+FROM entity_embeddings;
+
+-- ============================================================================
+-- Step 4: Swap tables and recreate indexes
+-- ============================================================================
+
+DROP TABLE entity_embeddings;
+DROP TABLE relationships;
+DROP TABLE entities;
+
+ALTER TABLE entities_v18 RENAME TO entities;
+ALTER TABLE relationships_v18 RENAME TO relationships;
+ALTER TABLE entity_embeddings_v18 RENAME TO entity_embeddings;
+
+CREATE INDEX IF NOT EXISTS idx_entities_tenant ON entities(tenant_id);
+CREATE INDEX IF NOT EXISTS idx_entities_type ON entities(entity_type);
+CREATE INDEX IF NOT EXISTS idx_entities_name ON entities(name);
+CREATE INDEX IF NOT EXISTS idx_entities_valid_time_start ON entities(valid_time_start);
+CREATE INDEX IF NOT EXISTS idx_entities_valid_time_end ON entities(valid_time_end);
+CREATE INDEX IF NOT EXISTS idx_entities_tx_time_start ON entities(transaction_time_start);
+CREATE INDEX IF NOT EXISTS idx_entities_tx_time_end ON entities(transaction_time_end);
+
+-- Lookup of all versions of an entity
+CREATE INDEX IF NOT EXISTS idx_entities_id_lookup ON entities(entity_id);
+
+CREATE INDEX IF NOT EXISTS idx_relationships_tenant ON relationships(tenant_id);
+CREATE INDEX IF NOT EXISTS idx_relationships_from ON relationships(from_entity);
+CREATE INDEX IF NOT EXISTS idx_relationships_to ON relationships(to_entity);
+CREATE INDEX IF NOT EXISTS idx_relationships_type ON relationships(relationship_type);
+CREATE INDEX IF NOT EXISTS idx_relationships_from_type ON relationships(from_entity, relationship_type);
+CREATE INDEX IF NOT EXISTS idx_relationships_valid_time_start ON relationships(valid_time_start);
+CREATE INDEX IF NOT EXISTS idx_relationships_valid_time_end ON relationships(valid_time_end);
+CREATE INDEX IF NOT EXISTS idx_relationships_tx_time_start ON relationships(transaction_time_start);
+CREATE INDEX IF NOT EXISTS idx_relationships_tx_time_end ON relationships(transaction_time_end);
+
+-- Lookup of all versions of a relationship
+CREATE INDEX IF NOT EXISTS idx_relationships_id_lookup ON relationships(relationship_id);
+
+CREATE INDEX IF NOT EXISTS idx_entity_embeddings_tenant_dimension
+ON entity_embeddings(tenant_id, dimension);
+
+-- Insert V18 migration record
+INSERT OR IGNORE INTO _migrations (version, name, checksum)
+VALUES (18, 'bitemporal_composite_keys', 'v18-bitemporal-composite-keys');
+
+COMMIT;
+
+PRAGMA foreign_keys = ON;
+
+-- ============================================================================
+-- Migration Notes
+-- ============================================================================
+--
+-- Application code must enforce:
+--   1. Relationships only reference existing entity_ids
+--   2. Relationships and embeddings of deleted entities are removed
+--      (delete_before() and repair() in SqliteGraphStorage)
@@ -5,15 +5,33 @@ use super::backend::PostgresBackend;
 use super::error::PostgresError;
 use anyhow::Result;
 use async_trait::async_trait;
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, SubsecRound, Utc};
+use futures::{Stream, StreamExt, TryStreamExt};
 use llmspell_core::types::storage::{Entity, Relationship, TemporalQuery};
+use llmspell_graph::storage::RelationshipStream;
 use llmspell_graph::traits::KnowledgeGraph;
+use pgvector::Vector;
 use serde_json::Value;
 use std::collections::HashMap;
 use std::sync::Arc;
+use tokio_postgres::types::ToSql;
 use tokio_postgres::Row;
 use uuid::Uuid;
 
+/// Insert or replace an entity's embedding
+/// (`$1` tenant_id, `$2` entity_id, `$3` dimension, `$4` embedding)
+const UPSERT_EMBEDDING_SQL: &str = "INSERT INTO llmspell.entity_embeddings
+     (tenant_id, entity_id, dimension, embedding)
+     VALUES ($1, $2, $3, $4)
+     ON CONFLICT (entity_id) DO UPDATE
+     SET tenant_id = EXCLUDED.tenant_id,
+         dimension = EXCLUDED.dimension,
+         embedding = EXCLUDED.embedding,
+         updated_at = now()";
+
+/// Dimensions with a partial HNSW index in `llmspell.entity_embeddings` (V18)
+const INDEXED_EMBEDDING_DIMENSIONS: [usize; 3] = [384, 768, 1536];
+
 /// PostgreSQL-backed graph storage with bi-temporal support
 ///
 /// Maps between llmspell-graph types and PostgreSQL bi-temporal schema:
@@ -120,10 +138,12 @@ impl PostgresGraphStorage {
             .await
             .map_err(|e| PostgresError::Query(format!("Failed to query entity: {}", e)))?;
 
-        match row_opt {
-            Some(row) => Ok(Some(Self::entity_from_row(row)?)),
-            None => Ok(None),
-        }
+        let Some(row) = row_opt else {
+            return Ok(None);
+        };
+        let mut entity = Self::entity_from_row(row)?;
+        Self::attach_embeddings(&client, &tenant_id, std::iter::once(&mut entity)).await?;
+        Ok(Some(entity))
     }
 
     /// Query entities using temporal range filters
@@ -272,9 +292,12 @@ impl PostgresGraphStorage {
         })?;
 
         // Convert rows to entities
-        rows.into_iter()
+        let mut entities = rows
+            .into_iter()
             .map(Self::entity_from_row)
-            .collect::<Result<Vec<Entity>>>()
+            .collect::<Result<Vec<Entity>>>()?;
+        Self::attach_embeddings(&client, &tenant_id, &mut entities).await?;
+        Ok(entities)
     }
 
     /// Get related entities via graph traversal with recursive CTEs
@@ -423,6 +446,7 @@ impl PostgresGraphStorage {
                 properties,
                 event_time: Some(valid_time_start),
                 ingestion_time: transaction_time_start,
+                embedding: None,
             };
 
             let path_strings: Vec<String> = path.iter().map(|u| u.to_string()).collect();
@@ -430,6 +454,13 @@ impl PostgresGraphStorage {
             results.push((entity, depth as u32, path_strings));
         }
 
+        Self::attach_embeddings(
+            &client,
+            &tenant_id,
+            results.iter_mut().map(|(entity, _, _)| entity),
+        )
+        .await?;
+
         Ok(results)
     }
 
@@ -460,8 +491,214 @@ impl PostgresGraphStorage {
             properties,
             event_time,
             ingestion_time,
+            embedding: None,
         })
     }
+
+    /// Fill in the stored embedding of each entity with a single query
+    async fn attach_embeddings<'a>(
+        client: &tokio_postgres::Client,
+        tenant_id: &str,
+        entities: impl IntoIterator<Item = &'a mut Entity>,
+    ) -> Result<()> {
+        let mut entities: Vec<&mut Entity> = entities.into_iter().collect();
+        if entities.is_empty() {
+            return Ok(());
+        }
+
+        let ids: Vec<Uuid> = entities
+            .iter()
+            .filter_map(|entity| Uuid::parse_str(&entity.id).ok())
+            .collect();
+        let rows = client
+            .query(
+                "SELECT entity_id, embedding
+                 FROM llmspell.entity_embeddings
+                 WHERE tenant_id = $1 AND entity_id = ANY($2)",
+                &[&tenant_id, &ids],
+            )
+            .await
+            .map_err(|e| anyhow::anyhow!(format!("Failed to query entity embeddings: {}", e)))?;
+
+        // Versions of one entity share its embedding
+        let embeddings: HashMap<String, Vec<f32>> = rows
+            .into_iter()
+            .map(|row| {
+                let entity_id: Uuid = row.get("entity_id");
+                let embedding: Vector = row.get("embedding");
+                (entity_id.to_string(), embedding.to_vec())
+            })
+            .collect();
+        for entity in &mut entities {
+            entity.embedding = embeddings.get(&entity.id).cloned();
+        }
+        Ok(())
+    }
+
+    /// Find the `k` current entities whose embeddings are closest by cosine similarity
+    ///
+    /// Returns `(entity, cosine similarity)` pairs, most similar first. Only
+    /// entities stored with an embedding of the same dimension match; 384, 768
+    /// and 1536 dimensions are searched through HNSW indexes.
+    pub async fn find_similar_entities(
+        &self,
+        embedding: &[f32],
+        k: usize,
+    ) -> Result<Vec<(Entity, f32)>> {
+        if embedding.is_empty() || k == 0 {
+            return Ok(Vec::new());
+        }
+
+        let tenant_id = self.backend.get_tenant_context().await.ok_or_else(|| {
+            anyhow::anyhow!("Tenant context not set - call set_tenant_context() first".to_string(),)
+        })?;
+
+        let client = self
+            .backend
+            .get_client()
+            .await
+            .map_err(|e| anyhow::anyhow!(format!("Failed to get client: {}", e)))?;
+
+        // The partial HNSW indexes only match distance expressions with the same cast
+        let dimension = embedding.len();
+        let cast = if INDEXED_EMBEDDING_DIMENSIONS.contains(&dimension) {
+            format!("::vector({})", dimension)
+        } else {
+            "::vector".to_string()
+        };
+        // Embeddings of retracted entities are skipped before the limit, so
+        // they cannot crowd current entities out of the top k
+        let sql = format!(
+            "SELECT e.entity_id, e.entity_type, e.name, e.properties,
+                    e.valid_time_start, e.transaction_time_start,
+                    n.embedding, n.distance
+             FROM (
+                 SELECT m.entity_id, m.embedding, m.embedding{cast} <=> $1{cast} AS distance
+                 FROM llmspell.entity_embeddings m
+                 WHERE m.tenant_id = $2 AND m.dimension = $3
+                   AND EXISTS (SELECT 1 FROM llmspell.entities c
+                               WHERE c.entity_id = m.entity_id
+                                 AND c.tenant_id = $2
+                                 AND c.valid_time_end = 'infinity'
+                                 AND c.transaction_time_end = 'infinity')
+                 ORDER BY m.embedding{cast} <=> $1{cast}
+                 LIMIT $4
+             ) n
+             JOIN llmspell.entities e ON e.entity_id = n.entity_id
+             WHERE e.tenant_id = $2
+               AND e.valid_time_end = 'infinity'
+               AND e.transaction_time_end = 'infinity'
+             ORDER BY n.distance"
+        );
+
+        let rows = client
+            .query(
+                &sql,
+                &[
+                    &Vector::from(embedding.to_vec()),
+                    &tenant_id,
+                    &(dimension as i32),
+                    &(k as i64),
+                ],
+            )
+            .await
+            .map_err(|e| anyhow::anyhow!(format!("Failed to run similarity query: {}", e)))?;
+
+        rows.into_iter()
+            .map(|row| {
+                let distance: f64 = row.get("distance");
+                let stored: Vector = row.get("embedding");
+                let mut entity = Self::entity_from_row(row)?;
+                entity.embedding = Some(stored.to_vec());
+                Ok((entity, 1.0 - distance as f32))
+            })
+            .collect()
+    }
+
+    /// Stream outgoing relationships of a type from an entity
+    ///
+    /// Runs a single query and decodes rows as they are read off the
+    /// connection instead of collecting them first. This is not a server-side
+    /// cursor: the server executes the whole query up front. The pooled
+    /// connection is held until the stream is exhausted or dropped.
+    pub fn stream_related<'a>(
+        &'a self,
+        entity_id: &'a str,
+        relationship_type: &'a str,
+    ) -> RelationshipStream<'a> {
+        futures::stream::once(self.open_related_rows(entity_id, relationship_type))
+            .try_flatten()
+            .boxed()
+    }
+
+    /// Start the row stream backing `stream_related`
+    async fn open_related_rows(
+        &self,
+        entity_id: &str,
+        relationship_type: &str,
+    ) -> Result<impl Stream<Item = Result<Relationship>> + Send> {
+        let uuid = Uuid::parse_str(entity_id)
+            .map_err(|e| anyhow::anyhow!(format!("Invalid entity ID (not a UUID): {}", e)))?;
+
+        let tenant_id = self.backend.get_tenant_context().await.ok_or_else(|| {
+            anyhow::anyhow!("Tenant context not set - call set_tenant_context() first".to_string(),)
+        })?;
+
+        let client = self
+            .backend
+            .get_client()
+            .await
+            .map_err(|e| anyhow::anyhow!(format!("Failed to get client: {}", e)))?;
+
+        let now = Utc::now();
+        let params: [&(dyn ToSql + Sync); 4] = [&uuid, &relationship_type, &tenant_id, &now];
+
+        let rows = client
+            .query_raw(
+                "SELECT relationship_id, from_entity, to_entity, relationship_type, properties,
+                        valid_time_start, transaction_time_start
+                 FROM llmspell.relationships
+                 WHERE from_entity = $1
+                   AND relationship_type = $2
+                   AND tenant_id = $3
+                   AND valid_time_start <= $4 AND valid_time_end > $4
+                   AND transaction_time_end = 'infinity'::timestamptz",
+                params,
+            )
+            .await
+            .map_err(|e| anyhow::anyhow!(format!("Failed to query relationships: {}", e)))?;
+
+        // The client travels with the row stream so it is not returned to the pool early
+        Ok(futures::stream::try_unfold(
+            (client, Box::pin(rows)),
+            |(client, mut rows)| async move {
+                let row = rows.try_next().await.map_err(|e| {
+                    anyhow::anyhow!(format!("Failed to read relationship row: {}", e))
+                })?;
+                Ok::<_, anyhow::Error>(
+                    row.map(|row| (Self::relationship_from_row(&row), (client, rows))),
+                )
+            },
+        ))
+    }
+
+    /// Convert a relationships row into a `Relationship`
+    fn relationship_from_row(row: &Row) -> Relationship {
+        let relationship_id: Uuid = row.get("relationship_id");
+        let from_entity: Uuid = row.get("from_entity");
+        let to_entity: Uuid = row.get("to_entity");
+        let valid_time_start: DateTime<Utc> = row.get("valid_time_start");
+
+        Relationship {
+            id: relationship_id.to_string(),
+            from_entity: from_entity.to_string(),
+            to_entity: to_entity.to_string(),
+            relationship_type: row.get("relationship_type"),
+            properties: row.get("properties"),
+            event_time: Some(valid_time_start),
+            ingestion_time: row.get("transaction_time_start"),
+        }
+    }
 }
 
 // KnowledgeGraph trait implementation (Phase 13b.5.4)
@@ -512,6 +749,21 @@ impl KnowledgeGraph for PostgresGraphStorage {
                 anyhow::anyhow!(format!("Failed to insert entity: {}", e))
             })?;
 
+        if let Some(embedding) = entity.embedding {
+            client
+                .execute(
+                    UPSERT_EMBEDDING_SQL,
+                    &[
+                        &tenant_id,
+                        &entity_id,
+                        &(embedding.len() as i32),
+                        &Vector::from(embedding),
+                    ],
+                )
+                .await
+                .map_err(|e| anyhow::anyhow!(format!("Failed to store entity embedding: {}", e)))?;
+        }
+
         Ok(entity_id.to_string())
     }
 
@@ -611,6 +863,146 @@ impl KnowledgeGraph for PostgresGraphStorage {
         Ok(())
     }
 
+    /// Add a batch of entities in a single transaction
+    ///
+    /// Entities matching a current entity on `(name, entity_type)`, including earlier
+    /// entries in the same batch, get a new version with merged properties:
+    /// 1. End the current version's transaction time
+    /// 2. Insert the new version with the same valid time and a new transaction time
+    ///
+    /// Each version in the batch gets a distinct transaction time.
+    async fn upsert_entities(&self, entities: Vec<Entity>) -> Result<Vec<String>> {
+        let tenant_id = self.backend.get_tenant_context().await.ok_or_else(|| {
+            anyhow::anyhow!("Tenant context not set - call set_tenant_context() first".to_string(),)
+        })?;
+
+        let mut client = self
+            .backend
+            .get_client()
+            .await
+            .map_err(|e| anyhow::anyhow!(format!("Failed to get client: {}", e)))?;
+
+        let tx = client
+            .transaction()
+            .await
+            .map_err(|e| anyhow::anyhow!(format!("Failed to start transaction: {}", e)))?;
+
+        let mut ids = Vec::with_capacity(entities.len());
+        let mut last_recorded: Option<DateTime<Utc>> = None;
+        for entity in entities {
+            // Versions are keyed by (entity_id, transaction_time_start), stored to the microsecond
+            let now = Utc::now().trunc_subsecs(6);
+            let recorded_at = match last_recorded {
+                Some(last) if now <= last => last + chrono::Duration::microseconds(1),
+                _ => now,
+            };
+            last_recorded = Some(recorded_at);
+
+            let existing = tx
+                .query_opt(
+                    "SELECT entity_id, properties, valid_time_start
+                     FROM llmspell.entities
+                     WHERE tenant_id = $1
+                       AND name = $2
+                       AND entity_type = $3
+                       AND valid_time_end = 'infinity'
+                       AND transaction_time_end = 'infinity'
+                     LIMIT 1",
+                    &[&tenant_id, &entity.name, &entity.entity_type],
+                )
+                .await
+                .map_err(|e| anyhow::anyhow!(format!("Failed to query matching entity: {}", e)))?;
+
+            let entity_id: Uuid = if let Some(row) = existing {
+                let entity_id: Uuid = row.get("entity_id");
+                let mut properties: Value = row.get("properties");
+                let valid_time_start: DateTime<Utc> = row.get("valid_time_start");
+
+                if let (Value::Object(current), Value::Object(changes)) =
+                    (&mut properties, entity.properties)
+                {
+                    current.extend(changes);
+                }
+
+                // End current version
+                tx.execute(
+                    "UPDATE llmspell.entities
+                     SET transaction_time_end = $1
+                     WHERE entity_id = $2
+                       AND tenant_id = $3
+                       AND valid_time_end = 'infinity'
+                       AND transaction_time_end = 'infinity'",
+                    &[&recorded_at, &entity_id, &tenant_id],
+                )
+                .await
+                .map_err(|e| anyhow::anyhow!(format!("Failed to end current version: {}", e)))?;
+
+                // Insert new version with merged properties
+                tx.execute(
+                    "INSERT INTO llmspell.entities
+                     (tenant_id, entity_id, entity_type, name, properties, valid_time_start, valid_time_end, transaction_time_start)
+                     VALUES ($1, $2, $3, $4, $5, $6, 'infinity', $7)",
+                    &[
+                        &tenant_id,
+                        &entity_id,
+                        &entity.entity_type,
+                        &entity.name,
+                        &properties,
+                        &valid_time_start,
+                        &recorded_at,
+                    ],
+                )
+                .await
+                .map_err(|e| anyhow::anyhow!(format!("Failed to insert new version: {}", e)))?;
+
+                entity_id
+            } else {
+                let entity_id = Uuid::new_v4();
+                let valid_time_start = entity.event_time.unwrap_or(recorded_at);
+
+                tx.execute(
+                    "INSERT INTO llmspell.entities
+                     (tenant_id, entity_id, entity_type, name, properties, valid_time_start, valid_time_end, transaction_time_start)
+                     VALUES ($1, $2, $3, $4, $5, $6, 'infinity', $7)",
+                    &[
+                        &tenant_id,
+                        &entity_id,
+                        &entity.entity_type,
+                        &entity.name,
+                        &entity.properties,
+                        &valid_time_start,
+                        &recorded_at,
+                    ],
+                )
+                .await
+                .map_err(|e| anyhow::anyhow!(format!("Failed to insert entity: {}", e)))?;
+
+                entity_id
+            };
+
+            if let Some(embedding) = entity.embedding {
+                tx.execute(
+                    UPSERT_EMBEDDING_SQL,
+                    &[
+                        &tenant_id,
+                        &entity_id,
+                        &(embedding.len() as i32),
+                        &Vector::from(embedding),
+                    ],
+                )
+                .await
+                .map_err(|e| anyhow::anyhow!(format!("Failed to store entity embedding: {}", e)))?;
+            }
+            ids.push(entity_id.to_string());
+        }
+
+        tx.commit()
+            .await
+            .map_err(|e| anyhow::anyhow!(format!("Failed to commit transaction: {}", e)))?;
+
+        Ok(ids)
+    }
+
     /// Get the current version of an entity
     ///
     /// Returns the entity with `valid_time_end = infinity` and `transaction_time_end = infinity`
@@ -645,8 +1037,10 @@ impl KnowledgeGraph for PostgresGraphStorage {
 
         let row = row.ok_or_else(|| anyhow::anyhow!(format!("Entity {} not found", id)))?;
 
-        Self::entity_from_row(row)
-            .map_err(|e| anyhow::anyhow!(format!("Failed to parse entity: {}", e)))
+        let mut entity = Self::entity_from_row(row)
+            .map_err(|e| anyhow::anyhow!(format!("Failed to parse entity: {}", e)))?;
+        Self::attach_embeddings(&client, &tenant_id, std::iter::once(&mut entity)).await?;
+        Ok(entity)
     }
 
     /// Get entity as it was known at a specific event time
@@ -843,6 +1237,18 @@ impl KnowledgeGraph for PostgresGraphStorage {
             .await
             .map_err(|e| anyhow::anyhow!(format!("Failed to delete entities: {}", e)))?;
 
+        // Embeddings are shared by an entity's versions, so remove them only
+        // once no version of their entity is left
+        tx.execute(
+            "DELETE FROM llmspell.entity_embeddings m
+             WHERE m.tenant_id = $1
+               AND NOT EXISTS (SELECT 1 FROM llmspell.entities e
+                               WHERE e.entity_id = m.entity_id AND e.tenant_id = $1)",
+            &[&tenant_id],
+        )
+        .await
+        .map_err(|e| anyhow::anyhow!(format!("Failed to delete entity embeddings: {}", e)))?;
+
         tx.commit()
             .await
             .map_err(|e| anyhow::anyhow!(format!("Failed to commit transaction: {}", e)))?;
@@ -989,11 +1395,19 @@ impl KnowledgeGraph for PostgresGraphStorage {
                 properties,
                 event_time: Some(valid_time_start),
                 ingestion_time: transaction_time_start,
+                embedding: None,
             };
 
             results.push((entity, depth as usize, path_json));
         }
 
+        Self::attach_embeddings(
+            &client,
+            &tenant_id,
+            results.iter_mut().map(|(entity, _, _)| entity),
+        )
+        .await?;
+
         Ok(results)
     }
 }
@@ -15,7 +15,7 @@ use async_trait::async_trait;
 use llmspell_core::state::StateScope;
 use llmspell_core::traits::storage::VectorStorage;
 use llmspell_core::types::storage::{
-    ScopedStats, StorageStats, VectorEntry, VectorQuery, VectorResult,
+    ScopedStats, StorageStats, VectorEntry, VectorPruneFilter, VectorQuery, VectorResult,
 };
 use pgvector::Vector;
 use serde_json::Value;
@@ -58,6 +58,111 @@ impl PostgreSQLVectorStorage {
             )),
         }
     }
+
+    /// Parse vector IDs into the UUIDs stored in the tables
+    fn parse_ids(ids: &[String]) -> Result<Vec<uuid::Uuid>> {
+        ids.iter()
+            .map(|id| {
+                uuid::Uuid::parse_str(id)
+                    .map_err(|e| anyhow!("Invalid UUID for id '{}': {}", id, e))
+            })
+            .collect()
+    }
+
+    /// Record a read of each vector for access-based pruning
+    ///
+    /// Unknown IDs are ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if an ID is not a UUID or the update fails
+    pub async fn record_access(&self, ids: &[String]) -> Result<()> {
+        if ids.is_empty() {
+            return Ok(());
+        }
+
+        let ids = Self::parse_ids(ids)?;
+        let client = self.backend.get_client().await?;
+
+        // Vectors could be in any dimension table
+        for dimension in [384, 768, 1536, 3072] {
+            let table = Self::get_table_name(dimension)?;
+            let query = format!(
+                "UPDATE llmspell.{} SET access_count = access_count + 1, last_accessed = now()
+                 WHERE id = ANY($1)",
+                table
+            );
+            client.execute(&query, &[&ids]).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Delete the vectors in `scope` (or in every scope when `None`) selected by `filter`
+    ///
+    /// `keep_newest` ranks vectors within each dimension table.
+    ///
+    /// # Returns
+    ///
+    /// IDs of the deleted vectors
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the delete fails
+    pub async fn prune(
+        &self,
+        scope: Option<&StateScope>,
+        filter: &VectorPruneFilter,
+    ) -> Result<Vec<String>> {
+        if filter.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let client = self.backend.get_client().await?;
+        let keep_newest = filter.keep_newest.map(|n| n as i64);
+        let min_access_count = filter.min_access_count.map(|n| n as i64);
+        let mut pruned = Vec::new();
+
+        for dimension in [384, 768, 1536, 3072] {
+            let table = Self::get_table_name(dimension)?;
+            let query = format!(
+                "WITH ranked AS (
+                     SELECT id, access_count,
+                            COALESCE(event_time, created_at) AS event_time,
+                            row_number() OVER (
+                                ORDER BY COALESCE(event_time, created_at) DESC, created_at DESC
+                            ) AS rank
+                     FROM llmspell.{table}
+                     WHERE $1::text IS NULL OR scope = $1
+                 )
+                 DELETE FROM llmspell.{table} v
+                 USING ranked r
+                 WHERE v.id = r.id
+                   AND (($2::timestamptz IS NOT NULL AND r.event_time < $2)
+                        OR ($3::bigint IS NOT NULL AND r.rank > $3)
+                        OR ($4::bigint IS NOT NULL AND r.access_count < $4))
+                 RETURNING v.id"
+            );
+
+            let rows = client
+                .query(
+                    &query,
+                    &[
+                        &scope.map(ToString::to_string),
+                        &filter.older_than,
+                        &keep_newest,
+                        &min_access_count,
+                    ],
+                )
+                .await?;
+            pruned.extend(
+                rows.iter()
+                    .map(|row| row.get::<_, uuid::Uuid>(0).to_string()),
+            );
+        }
+
+        Ok(pruned)
+    }
 }
 
 #[async_trait]
@@ -72,8 +177,8 @@ impl VectorStorage for PostgreSQLVectorStorage {
 
             // Tenant context automatically applied via RLS
             let query = format!(
-                "INSERT INTO llmspell.{} (id, tenant_id, scope, embedding, metadata, created_at, updated_at)
-                 VALUES ($1, current_setting('app.current_tenant_id', true), $2, $3, $4, $5, $6)
+                "INSERT INTO llmspell.{} (id, tenant_id, scope, embedding, metadata, created_at, updated_at, event_time)
+                 VALUES ($1, current_setting('app.current_tenant_id', true), $2, $3, $4, $5, $6, $7)
                  RETURNING id",
                 table
             );
@@ -93,6 +198,7 @@ impl VectorStorage for PostgreSQLVectorStorage {
                         &serde_json::to_value(&entry.metadata)?,
                         &std::time::SystemTime::now(),
                         &std::time::SystemTime::now(),
+                        &entry.event_time,
                     ],
                 )
                 .await?;
@@ -297,6 +403,32 @@ impl VectorStorage for PostgreSQLVectorStorage {
         Ok(total_deleted)
     }
 
+    async fn delete_by_filter(
+        &self,
+        scope: &StateScope,
+        filter: HashMap<String, Value>,
+    ) -> Result<usize> {
+        let client = self.backend.get_client().await?;
+        let filter = serde_json::to_value(&filter)?;
+        let mut total_deleted = 0;
+
+        // JSONB containment matches every filter key/value pair
+        for dimension in [384, 768, 1536, 3072] {
+            let table = Self::get_table_name(dimension)?;
+            let query = format!(
+                "DELETE FROM llmspell.{} WHERE scope = $1 AND metadata @> $2",
+                table
+            );
+
+            let rows_affected = client
+                .execute(&query, &[&scope.to_string(), &filter])
+                .await?;
+            total_deleted += rows_affected as usize;
+        }
+
+        Ok(total_deleted)
+    }
+
     async fn stats(&self) -> Result<StorageStats> {
         let client = self.backend.get_client().await?;
         let mut total_vectors = 0;
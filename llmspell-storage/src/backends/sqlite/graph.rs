@@ -39,6 +39,8 @@
 
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
+use dashmap::DashMap;
+use futures::{StreamExt, TryStreamExt};
 use serde_json::Value;
 use std::collections::HashMap;
 use std::sync::Arc;
@@ -47,10 +49,23 @@ use uuid::Uuid;
 
 use anyhow::Result;
 use llmspell_core::types::storage::{Entity, Relationship, TemporalQuery};
-use llmspell_graph::storage::GraphBackend;
+use llmspell_graph::storage::{
+    GraphBackend, GraphChangeset, RelationshipStream, RepairOptions, RepairReport, ValidationReport,
+};
+use rusqlite::OptionalExtension;
+use vectorlite_rs::{DistanceMetric as VectorliteMetric, HnswIndex};
 
 use super::backend::SqliteBackend;
 
+/// Default number of relationships fetched per batch by `stream_related`
+pub const DEFAULT_STREAM_BATCH_SIZE: usize = 500;
+
+/// HNSW parameters for entity embedding indices (same defaults as `SqliteVectorStorage`)
+const EMBEDDING_INDEX_MAX_ELEMENTS: usize = 100_000;
+const EMBEDDING_INDEX_M: usize = 16;
+const EMBEDDING_INDEX_EF_CONSTRUCTION: usize = 200;
+const EMBEDDING_INDEX_EF_SEARCH: usize = 50;
+
 /// SQLite-based graph storage with bi-temporal semantics
 ///
 /// Implements bi-temporal graph storage using SQLite tables and recursive CTEs
@@ -70,10 +85,20 @@ use super::backend::SqliteBackend;
 ///   valid_time_start/end, transaction_time_start/end (Unix timestamps)
 /// - `relationships`: relationship_id (TEXT), tenant_id, from_entity, to_entity,
 ///   relationship_type, properties (JSON), temporal timestamps
+/// - `entity_embeddings`: one embedding per entity_id (JSON), searched through
+///   in-memory HNSW indices (vectorlite-rs)
 #[derive(Clone)]
 pub struct SqliteGraphStorage {
     /// Reference to SQLite backend
     backend: Arc<SqliteBackend>,
+    /// Relationships fetched per batch by `stream_related`
+    stream_batch_size: usize,
+    /// HNSW indices over `entity_embeddings`, keyed by (tenant_id, dimension)
+    ///
+    /// Built from the table on first search. HNSW indices cannot remove
+    /// vectors, so a tenant's indices are dropped whenever one of its
+    /// embeddings is replaced or deleted, and rebuilt on the next search.
+    embedding_indices: Arc<DashMap<(String, usize), HnswIndex>>,
 }
 
 impl SqliteGraphStorage {
@@ -87,7 +112,17 @@ impl SqliteGraphStorage {
     ///
     /// New SqliteGraphStorage instance
     pub fn new(backend: Arc<SqliteBackend>) -> Self {
-        Self { backend }
+        Self {
+            backend,
+            stream_batch_size: DEFAULT_STREAM_BATCH_SIZE,
+            embedding_indices: Arc::new(DashMap::new()),
+        }
+    }
+
+    /// Set the number of relationships fetched per batch by `stream_related`
+    pub fn with_stream_batch_size(mut self, batch_size: usize) -> Self {
+        self.stream_batch_size = batch_size.max(1);
+        self
     }
 
     /// Get tenant ID from backend context
@@ -105,6 +140,331 @@ impl SqliteGraphStorage {
     fn unix_to_datetime(timestamp: i64) -> DateTime<Utc> {
         DateTime::from_timestamp(timestamp, 0).unwrap_or_else(Utc::now)
     }
+
+    /// Fetch one batch of outgoing relationships ordered by ID (keyset pagination)
+    async fn fetch_related_batch(
+        &self,
+        entity_id: &str,
+        relationship_type: &str,
+        after_id: &str,
+        now: i64,
+    ) -> Result<Vec<Relationship>> {
+        let conn =
+            self.backend.get_connection().await.map_err(|e| {
+                anyhow::anyhow!(format!("Failed to get database connection: {}", e))
+            })?;
+
+        let tenant_id = self.get_tenant_id();
+
+        let mut stmt = conn.prepare(
+            "SELECT relationship_id, from_entity, to_entity, relationship_type, properties,
+                    valid_time_start, transaction_time_start
+             FROM relationships
+             WHERE from_entity = ?1
+               AND tenant_id = ?2
+               AND relationship_type = ?3
+               AND relationship_id > ?4
+               AND valid_time_start <= ?5 AND valid_time_end > ?5
+               AND transaction_time_end = 9999999999
+             ORDER BY relationship_id
+             LIMIT ?6",
+        )?;
+
+        let rows = stmt
+            .query_map(
+                rusqlite::params![
+                    entity_id,
+                    tenant_id,
+                    relationship_type,
+                    after_id,
+                    now,
+                    self.stream_batch_size as i64
+                ],
+                Self::relationship_from_row,
+            )
+            .map_err(|e| anyhow::anyhow!(format!("Failed to query relationships: {}", e)))?;
+
+        let batch = rows
+            .collect::<std::result::Result<Vec<_>, _>>()
+            .map_err(|e| anyhow::anyhow!(format!("Failed to read relationship row: {}", e)))?;
+
+        debug!(
+            "Fetched batch of {} {} relationships from entity {}",
+            batch.len(),
+            relationship_type,
+            entity_id
+        );
+        Ok(batch)
+    }
+
+    /// Map an entity row selected as
+    /// `entity_id, name, entity_type, properties, valid_time_start, transaction_time_start`
+    fn entity_from_row(row: &rusqlite::Row<'_>) -> rusqlite::Result<Entity> {
+        let properties_str: String = row.get(3)?;
+        Ok(Entity {
+            id: row.get(0)?,
+            name: row.get(1)?,
+            entity_type: row.get(2)?,
+            properties: serde_json::from_str(&properties_str).unwrap_or(Value::Null),
+            event_time: Some(Self::unix_to_datetime(row.get(4)?)),
+            ingestion_time: Self::unix_to_datetime(row.get(5)?),
+            embedding: None,
+        })
+    }
+
+    /// Map a relationship row selected as `relationship_id, from_entity, to_entity,
+    /// relationship_type, properties, valid_time_start, transaction_time_start`
+    fn relationship_from_row(row: &rusqlite::Row<'_>) -> rusqlite::Result<Relationship> {
+        let properties_str: String = row.get(4)?;
+        Ok(Relationship {
+            id: row.get(0)?,
+            from_entity: row.get(1)?,
+            to_entity: row.get(2)?,
+            relationship_type: row.get(3)?,
+            properties: serde_json::from_str(&properties_str).unwrap_or(Value::Null),
+            event_time: Some(Self::unix_to_datetime(row.get(5)?)),
+            ingestion_time: Self::unix_to_datetime(row.get(6)?),
+        })
+    }
+
+    /// Run a query over a `[from, to)` transaction-time window for the given tenant
+    fn query_window<T>(
+        conn: &rusqlite::Connection,
+        sql: &str,
+        tenant_id: &str,
+        from: i64,
+        to: i64,
+        map_row: fn(&rusqlite::Row<'_>) -> rusqlite::Result<T>,
+    ) -> Result<Vec<T>> {
+        let mut stmt = conn
+            .prepare(sql)
+            .map_err(|e| anyhow::anyhow!(format!("Failed to prepare changeset query: {}", e)))?;
+        let rows = stmt
+            .query_map(rusqlite::params![tenant_id, from, to], map_row)
+            .map_err(|e| anyhow::anyhow!(format!("Failed to run changeset query: {}", e)))?;
+        rows.collect::<std::result::Result<Vec<T>, _>>()
+            .map_err(|e| anyhow::anyhow!(format!("Failed to read changeset row: {}", e)))
+    }
+
+    /// Store or replace an entity's embedding (JSON-encoded, like vec_embeddings_*)
+    ///
+    /// Returns the rowid of a newly stored embedding, or `None` if an existing
+    /// one was replaced.
+    fn store_embedding(
+        conn: &rusqlite::Connection,
+        entity_id: &str,
+        tenant_id: &str,
+        embedding: &[f32],
+        now: i64,
+    ) -> Result<Option<i64>> {
+        if embedding.is_empty() {
+            return Err(anyhow::anyhow!("Entity embedding must not be empty"));
+        }
+        let embedding_json = serde_json::to_string(embedding)
+            .map_err(|e| anyhow::anyhow!(format!("Failed to serialize embedding: {}", e)))?;
+        let params = rusqlite::params![
+            entity_id,
+            tenant_id,
+            embedding.len() as i64,
+            embedding_json,
+            now
+        ];
+
+        let replaced = conn
+            .execute(
+                "UPDATE entity_embeddings
+                 SET tenant_id = ?2, dimension = ?3, embedding = ?4, created_at = ?5
+                 WHERE entity_id = ?1",
+                params,
+            )
+            .map_err(|e| anyhow::anyhow!(format!("Failed to store entity embedding: {}", e)))?;
+        if replaced > 0 {
+            return Ok(None);
+        }
+
+        conn.execute(
+            "INSERT INTO entity_embeddings
+             (entity_id, tenant_id, dimension, embedding, created_at)
+             VALUES (?1, ?2, ?3, ?4, ?5)",
+            params,
+        )
+        .map_err(|e| anyhow::anyhow!(format!("Failed to store entity embedding: {}", e)))?;
+        Ok(Some(conn.last_insert_rowid()))
+    }
+
+    /// Load an entity's embedding, if it has one
+    fn load_embedding(conn: &rusqlite::Connection, entity_id: &str) -> Result<Option<Vec<f32>>> {
+        let mut stmt = conn
+            .prepare_cached("SELECT embedding FROM entity_embeddings WHERE entity_id = ?1")
+            .map_err(|e| anyhow::anyhow!(format!("Failed to query entity embedding: {}", e)))?;
+        let embedding_json: Option<String> = stmt
+            .query_row(rusqlite::params![entity_id], |row| row.get(0))
+            .optional()
+            .map_err(|e| anyhow::anyhow!(format!("Failed to query entity embedding: {}", e)))?;
+        embedding_json
+            .map(|json| serde_json::from_str(&json))
+            .transpose()
+            .map_err(|e| anyhow::anyhow!(format!("Failed to parse entity embedding: {}", e)))
+    }
+
+    /// Fill in the stored embedding of each entity
+    fn attach_embeddings<'a>(
+        conn: &rusqlite::Connection,
+        entities: impl IntoIterator<Item = &'a mut Entity>,
+    ) -> Result<()> {
+        for entity in entities {
+            entity.embedding = Self::load_embedding(conn, &entity.id)?;
+        }
+        Ok(())
+    }
+
+    /// Add a newly stored embedding to its index, if the index is loaded
+    ///
+    /// An index that cannot take the vector is dropped and rebuilt on the next search.
+    fn index_embedding(&self, tenant_id: &str, rowid: i64, embedding: &[f32]) {
+        let key = (tenant_id.to_string(), embedding.len());
+        let failed = self
+            .embedding_indices
+            .get(&key)
+            .is_some_and(|index| index.insert(rowid, embedding.to_vec()).is_err());
+        if failed {
+            self.embedding_indices.remove(&key);
+        }
+    }
+
+    /// Drop a tenant's embedding indices after embeddings were replaced or deleted
+    fn invalidate_embedding_indices(&self, tenant_id: &str) {
+        self.embedding_indices
+            .retain(|(index_tenant, _), _| index_tenant != tenant_id);
+    }
+
+    /// Get the HNSW index over a tenant's embeddings of one dimension
+    ///
+    /// Builds it from `entity_embeddings` on first use. Returns `None` if the
+    /// tenant has no embeddings of that dimension.
+    fn embedding_index(
+        &self,
+        conn: &rusqlite::Connection,
+        tenant_id: &str,
+        dimension: usize,
+    ) -> Result<Option<HnswIndex>> {
+        let key = (tenant_id.to_string(), dimension);
+        if let Some(index) = self.embedding_indices.get(&key) {
+            return Ok(Some(index.value().clone()));
+        }
+
+        let mut stmt = conn
+            .prepare(
+                "SELECT rowid, embedding FROM entity_embeddings
+                 WHERE tenant_id = ?1 AND dimension = ?2",
+            )
+            .map_err(|e| anyhow::anyhow!(format!("Failed to prepare embedding query: {}", e)))?;
+        let rows = stmt
+            .query_map(rusqlite::params![tenant_id, dimension as i64], |row| {
+                Ok((row.get::<_, i64>(0)?, row.get::<_, String>(1)?))
+            })
+            .map_err(|e| anyhow::anyhow!(format!("Failed to query entity embeddings: {}", e)))?
+            .collect::<std::result::Result<Vec<_>, _>>()
+            .map_err(|e| anyhow::anyhow!(format!("Failed to read entity embedding: {}", e)))?;
+
+        if rows.is_empty() {
+            return Ok(None);
+        }
+
+        let index = HnswIndex::new(
+            dimension,
+            rows.len().max(EMBEDDING_INDEX_MAX_ELEMENTS),
+            EMBEDDING_INDEX_M,
+            EMBEDDING_INDEX_EF_CONSTRUCTION,
+            VectorliteMetric::Cosine,
+        )
+        .map_err(|e| anyhow::anyhow!("Failed to create HNSW index: {}", e))?;
+        for (rowid, embedding_json) in rows {
+            let embedding: Vec<f32> = serde_json::from_str(&embedding_json)
+                .map_err(|e| anyhow::anyhow!(format!("Failed to parse entity embedding: {}", e)))?;
+            index.insert(rowid, embedding).map_err(|e| {
+                anyhow::anyhow!(
+                    "Failed to insert embedding {} into HNSW index: {}",
+                    rowid,
+                    e
+                )
+            })?;
+        }
+
+        debug!(
+            "Built entity embedding index (dimension {}) with {} vectors",
+            dimension,
+            index.len()
+        );
+
+        self.embedding_indices.insert(key, index.clone());
+        Ok(Some(index))
+    }
+
+    /// Run a query selecting a single ID column for the given tenant
+    fn query_ids(conn: &rusqlite::Connection, sql: &str, tenant_id: &str) -> Result<Vec<String>> {
+        let mut stmt = conn
+            .prepare(sql)
+            .map_err(|e| anyhow::anyhow!(format!("Failed to prepare validation query: {}", e)))?;
+        let rows = stmt
+            .query_map(rusqlite::params![tenant_id], |row| row.get(0))
+            .map_err(|e| anyhow::anyhow!(format!("Failed to run validation query: {}", e)))?;
+        rows.collect::<std::result::Result<Vec<String>, _>>()
+            .map_err(|e| anyhow::anyhow!(format!("Failed to read validation row: {}", e)))
+    }
+
+    /// Collect integrity issues over current (non-superseded) rows
+    fn collect_validation_report(
+        conn: &rusqlite::Connection,
+        tenant_id: &str,
+    ) -> Result<ValidationReport> {
+        let dangling_relationships = Self::query_ids(
+            conn,
+            "SELECT r.relationship_id FROM relationships r
+             WHERE r.tenant_id = ?1
+               AND r.transaction_time_end = 9999999999
+               AND (NOT EXISTS (SELECT 1 FROM entities e
+                                WHERE e.entity_id = r.from_entity
+                                  AND e.transaction_time_end = 9999999999)
+                    OR NOT EXISTS (SELECT 1 FROM entities e
+                                   WHERE e.entity_id = r.to_entity
+                                     AND e.transaction_time_end = 9999999999))
+             ORDER BY r.relationship_id",
+            tenant_id,
+        )?;
+
+        let orphaned_entities = Self::query_ids(
+            conn,
+            "SELECT e.entity_id FROM entities e
+             WHERE e.tenant_id = ?1
+               AND e.transaction_time_end = 9999999999
+               AND NOT EXISTS (SELECT 1 FROM relationships r
+                               WHERE (r.from_entity = e.entity_id OR r.to_entity = e.entity_id)
+                                 AND r.transaction_time_end = 9999999999)
+             ORDER BY e.entity_id",
+            tenant_id,
+        )?;
+
+        let temporal_inconsistencies = Self::query_ids(
+            conn,
+            "SELECT entity_id FROM entities
+             WHERE tenant_id = ?1
+               AND transaction_time_end = 9999999999
+               AND valid_time_start > transaction_time_start
+             UNION ALL
+             SELECT relationship_id FROM relationships
+             WHERE tenant_id = ?1
+               AND transaction_time_end = 9999999999
+               AND valid_time_start > transaction_time_start",
+            tenant_id,
+        )?;
+
+        Ok(ValidationReport {
+            dangling_relationships,
+            orphaned_entities,
+            temporal_inconsistencies,
+        })
+    }
 }
 
 #[async_trait]
@@ -161,6 +521,13 @@ impl GraphBackend for SqliteGraphStorage {
         )
         .map_err(|e| anyhow::anyhow!(format!("Failed to insert entity: {}", e)))?;
 
+        if let Some(embedding) = &entity.embedding {
+            match Self::store_embedding(&conn, &entity_id, &tenant_id, embedding, now)? {
+                Some(rowid) => self.index_embedding(&tenant_id, rowid, embedding),
+                None => self.invalidate_embedding_indices(&tenant_id),
+            }
+        }
+
         debug!(
             "Added entity: id={}, type={}, name={}",
             entity_id, entity_type, entity_name
@@ -240,6 +607,174 @@ impl GraphBackend for SqliteGraphStorage {
         Ok(())
     }
 
+    /// Add a batch of entities in a single transaction
+    ///
+    /// Entities matching a current entity on `(name, entity_type)`, including earlier
+    /// entries in the same batch, get a new version with merged properties:
+    /// 1. End the current version's transaction time
+    /// 2. Insert the new version with the same valid time and a new transaction time
+    ///
+    /// Timestamps have second precision, so a version recorded within the same
+    /// second as its predecessor starts one second after it.
+    ///
+    /// # Returns
+    ///
+    /// Entity IDs in input order
+    async fn upsert_entities(&self, entities: Vec<Entity>) -> Result<Vec<String>> {
+        let mut conn =
+            self.backend.get_connection().await.map_err(|e| {
+                anyhow::anyhow!(format!("Failed to get database connection: {}", e))
+            })?;
+
+        let tenant_id = self.get_tenant_id();
+        let now = Utc::now().timestamp();
+
+        let tx = conn
+            .transaction()
+            .map_err(|e| anyhow::anyhow!(format!("Failed to start transaction: {}", e)))?;
+
+        let mut ids = Vec::with_capacity(entities.len());
+        // Embeddings are indexed once the transaction commits
+        let mut new_embeddings = Vec::new();
+        let mut embeddings_replaced = false;
+        for entity in entities {
+            let existing: Option<(String, String, i64, i64, i64)> = tx
+                .query_row(
+                    "SELECT entity_id, properties, valid_time_start, valid_time_end,
+                            transaction_time_start
+                     FROM entities
+                     WHERE tenant_id = ?1 AND name = ?2 AND entity_type = ?3
+                       AND transaction_time_end = 9999999999
+                     LIMIT 1",
+                    rusqlite::params![tenant_id, entity.name, entity.entity_type],
+                    |row| {
+                        Ok((
+                            row.get(0)?,
+                            row.get(1)?,
+                            row.get(2)?,
+                            row.get(3)?,
+                            row.get(4)?,
+                        ))
+                    },
+                )
+                .optional()
+                .map_err(|e| anyhow::anyhow!(format!("Failed to query matching entity: {}", e)))?;
+
+            let entity_id = if let Some((
+                entity_id,
+                properties_str,
+                valid_time_start,
+                valid_time_end,
+                transaction_time_start,
+            )) = existing
+            {
+                let mut properties: Value = serde_json::from_str(&properties_str).map_err(|e| {
+                    anyhow::anyhow!(format!("Failed to parse current properties: {}", e))
+                })?;
+                if let (Value::Object(current), Value::Object(changes)) =
+                    (&mut properties, entity.properties)
+                {
+                    current.extend(changes);
+                }
+                let updated_properties = serde_json::to_string(&properties).map_err(|e| {
+                    anyhow::anyhow!(format!("Failed to serialize updated properties: {}", e))
+                })?;
+
+                // Versions are keyed by (entity_id, transaction_time_start)
+                let recorded_at = now.max(transaction_time_start + 1);
+
+                // End current version
+                tx.execute(
+                    "UPDATE entities
+                     SET transaction_time_end = ?1
+                     WHERE entity_id = ?2 AND tenant_id = ?3
+                       AND transaction_time_end = 9999999999",
+                    rusqlite::params![recorded_at, entity_id, tenant_id],
+                )
+                .map_err(|e| anyhow::anyhow!(format!("Failed to end current version: {}", e)))?;
+
+                // Insert new version with merged properties
+                tx.execute(
+                    "INSERT INTO entities
+                     (entity_id, tenant_id, entity_type, name, properties,
+                      valid_time_start, valid_time_end,
+                      transaction_time_start, transaction_time_end, created_at)
+                     VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, 9999999999, ?9)",
+                    rusqlite::params![
+                        entity_id,
+                        tenant_id,
+                        entity.entity_type,
+                        entity.name,
+                        updated_properties,
+                        valid_time_start,
+                        valid_time_end,
+                        recorded_at,
+                        now,
+                    ],
+                )
+                .map_err(|e| anyhow::anyhow!(format!("Failed to insert new version: {}", e)))?;
+                if let Some(embedding) = entity.embedding {
+                    match Self::store_embedding(&tx, &entity_id, &tenant_id, &embedding, now)? {
+                        Some(rowid) => new_embeddings.push((rowid, embedding)),
+                        None => embeddings_replaced = true,
+                    }
+                }
+                entity_id
+            } else {
+                let entity_id = if entity.id.is_empty() {
+                    Uuid::new_v4().to_string()
+                } else {
+                    entity.id.clone()
+                };
+                let valid_time_start = entity.event_time.map(Self::datetime_to_unix).unwrap_or(now);
+                let properties = serde_json::to_string(&entity.properties).map_err(|e| {
+                    anyhow::anyhow!(format!("Failed to serialize entity properties: {}", e))
+                })?;
+
+                tx.execute(
+                    "INSERT INTO entities
+                     (entity_id, tenant_id, entity_type, name, properties,
+                      valid_time_start, valid_time_end,
+                      transaction_time_start, transaction_time_end, created_at)
+                     VALUES (?1, ?2, ?3, ?4, ?5, ?6, 9999999999, ?7, 9999999999, ?7)",
+                    rusqlite::params![
+                        entity_id,
+                        tenant_id,
+                        entity.entity_type,
+                        entity.name,
+                        properties,
+                        valid_time_start,
+                        now,
+                    ],
+                )
+                .map_err(|e| anyhow::anyhow!(format!("Failed to insert entity: {}", e)))?;
+                if let Some(embedding) = entity.embedding {
+                    match Self::store_embedding(&tx, &entity_id, &tenant_id, &embedding, now)? {
+                        Some(rowid) => new_embeddings.push((rowid, embedding)),
+                        None => embeddings_replaced = true,
+                    }
+                }
+                entity_id
+            };
+            ids.push(entity_id);
+        }
+
+        tx.commit()
+            .map_err(|e| anyhow::anyhow!(format!("Failed to commit transaction: {}", e)))?;
+
+        if embeddings_replaced {
+            self.invalidate_embedding_indices(&tenant_id);
+        } else {
+            for (rowid, embedding) in &new_embeddings {
+                self.index_embedding(&tenant_id, *rowid, embedding);
+            }
+        }
+
+        debug!("Upserted {} entities", ids.len());
+
+        Ok(ids)
+    }
+
     /// Get current version of an entity
     ///
     /// Returns the entity with transaction_time_end = 'infinity' (9999999999)
@@ -298,6 +833,7 @@ impl GraphBackend for SqliteGraphStorage {
 
         let properties: Value = serde_json::from_str(&properties_str)
             .map_err(|e| anyhow::anyhow!(format!("Failed to parse properties JSON: {}", e)))?;
+        let embedding = Self::load_embedding(&conn, &entity_id)?;
 
         Ok(Entity {
             id: entity_id,
@@ -306,6 +842,7 @@ impl GraphBackend for SqliteGraphStorage {
             properties,
             event_time: Some(Self::unix_to_datetime(valid_time_start)),
             ingestion_time: Self::unix_to_datetime(transaction_time_start),
+            embedding,
         })
     }
 
@@ -371,6 +908,7 @@ impl GraphBackend for SqliteGraphStorage {
 
         let properties: Value = serde_json::from_str(&properties_str)
             .map_err(|e| anyhow::anyhow!(format!("Failed to parse properties JSON: {}", e)))?;
+        let embedding = Self::load_embedding(&conn, &entity_id)?;
 
         Ok(Entity {
             id: entity_id,
@@ -379,6 +917,7 @@ impl GraphBackend for SqliteGraphStorage {
             properties,
             event_time: Some(Self::unix_to_datetime(valid_time_start)),
             ingestion_time: Self::unix_to_datetime(transaction_time_start),
+            embedding,
         })
     }
 
@@ -387,6 +926,10 @@ impl GraphBackend for SqliteGraphStorage {
     /// Creates relationship with bi-temporal timestamps. Validates that
     /// both entities exist before creating relationship.
     ///
+    /// A relationship whose ID has a current version is a correction: the
+    /// current version's transaction time is ended and the new version starts
+    /// at least one second after it, as in `upsert_entities`.
+    ///
     /// # Arguments
     ///
     /// * `relationship` - Relationship to add (from_entity, to_entity, type, properties)
@@ -395,7 +938,7 @@ impl GraphBackend for SqliteGraphStorage {
     ///
     /// UUID of created relationship
     async fn add_relationship(&self, relationship: Relationship) -> Result<String> {
-        let conn =
+        let mut conn =
             self.backend.get_connection().await.map_err(|e| {
                 anyhow::anyhow!(format!("Failed to get database connection: {}", e))
             })?;
@@ -423,13 +966,45 @@ impl GraphBackend for SqliteGraphStorage {
             ))
         })?;
 
+        let tx = conn
+            .transaction()
+            .map_err(|e| anyhow::anyhow!(format!("Failed to start transaction: {}", e)))?;
+
+        // Versions are keyed by (relationship_id, transaction_time_start)
+        let current_start: Option<i64> = tx
+            .query_row(
+                "SELECT transaction_time_start FROM relationships
+                 WHERE relationship_id = ?1 AND tenant_id = ?2
+                   AND transaction_time_end = 9999999999
+                 LIMIT 1",
+                rusqlite::params![relationship_id, tenant_id],
+                |row| row.get(0),
+            )
+            .optional()
+            .map_err(|e| anyhow::anyhow!(format!("Failed to query current relationship: {}", e)))?;
+        let recorded_at = match current_start {
+            Some(transaction_time_start) => {
+                let recorded_at = now.max(transaction_time_start + 1);
+                tx.execute(
+                    "UPDATE relationships
+                     SET transaction_time_end = ?1
+                     WHERE relationship_id = ?2 AND tenant_id = ?3
+                       AND transaction_time_end = 9999999999",
+                    rusqlite::params![recorded_at, relationship_id, tenant_id],
+                )
+                .map_err(|e| anyhow::anyhow!(format!("Failed to end current version: {}", e)))?;
+                recorded_at
+            }
+            None => now,
+        };
+
         // Insert relationship (foreign key constraints will validate entity existence)
-        conn.execute(
+        tx.execute(
             "INSERT INTO relationships
              (relationship_id, tenant_id, from_entity, to_entity, relationship_type, properties,
               valid_time_start, valid_time_end,
               transaction_time_start, transaction_time_end, created_at)
-             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, 9999999999, ?8, 9999999999, ?8)",
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, 9999999999, ?8, 9999999999, ?9)",
             rusqlite::params![
                 relationship_id.clone(),
                 tenant_id,
@@ -438,11 +1013,15 @@ impl GraphBackend for SqliteGraphStorage {
                 relationship.relationship_type,
                 properties,
                 valid_time_start,
+                recorded_at,
                 now,
             ],
         )
         .map_err(|e| anyhow::anyhow!(format!("Failed to insert relationship: {}", e)))?;
 
+        tx.commit()
+            .map_err(|e| anyhow::anyhow!(format!("Failed to commit transaction: {}", e)))?;
+
         debug!(
             "Added relationship: id={}, type={}, from={}, to={}",
             relationship_id, rel_type, from_entity, to_entity
@@ -531,9 +1110,12 @@ impl GraphBackend for SqliteGraphStorage {
                 properties,
                 event_time: Some(Self::unix_to_datetime(valid_time_start)),
                 ingestion_time: Self::unix_to_datetime(transaction_time_start),
+                embedding: None,
             });
         }
 
+        Self::attach_embeddings(&conn, &mut entities)?;
+
         let count = entities.len();
         debug!(
             "Found {} related entities for entity {} via {}",
@@ -543,6 +1125,40 @@ impl GraphBackend for SqliteGraphStorage {
         Ok(entities)
     }
 
+    /// Stream outgoing relationships in batches of `stream_batch_size`
+    ///
+    /// Each batch is a separate query resuming after the last relationship ID,
+    /// so no connection is held between batches.
+    fn stream_related<'a>(
+        &'a self,
+        entity_id: &'a str,
+        relationship_type: &'a str,
+    ) -> RelationshipStream<'a> {
+        let now = Utc::now().timestamp();
+
+        // State: ID to resume after, or None once a short batch ends the stream
+        let batches =
+            futures::stream::try_unfold(Some(String::new()), move |after_id| async move {
+                let Some(after_id) = after_id else {
+                    return Ok(None);
+                };
+                let batch = self
+                    .fetch_related_batch(entity_id, relationship_type, &after_id, now)
+                    .await?;
+                let next = if batch.len() < self.stream_batch_size {
+                    None
+                } else {
+                    batch.last().map(|r| r.id.clone())
+                };
+                Ok::<_, anyhow::Error>(Some((batch, next)))
+            });
+
+        batches
+            .map_ok(|batch| futures::stream::iter(batch.into_iter().map(Ok)))
+            .try_flatten()
+            .boxed()
+    }
+
     /// Get all relationships for an entity
     ///
     /// Returns both outgoing (from this entity) and incoming (to this entity) relationships.
@@ -745,15 +1361,230 @@ impl GraphBackend for SqliteGraphStorage {
                 properties,
                 event_time: Some(Self::unix_to_datetime(valid_time_start)),
                 ingestion_time: Self::unix_to_datetime(transaction_time_start),
+                embedding: None,
             });
         }
 
+        Self::attach_embeddings(&conn, &mut entities)?;
+
         let count = entities.len();
         debug!("Temporal query returned {} entities", count);
 
         Ok(entities)
     }
 
+    /// Find the `k` current entities whose embeddings are closest by cosine similarity
+    ///
+    /// Searches the tenant's HNSW index for the query dimension; entities without
+    /// an embedding are never returned.
+    async fn find_similar_entities(
+        &self,
+        embedding: &[f32],
+        k: usize,
+    ) -> Result<Vec<(Entity, f32)>> {
+        if embedding.is_empty() || k == 0 {
+            return Ok(Vec::new());
+        }
+
+        let conn =
+            self.backend.get_connection().await.map_err(|e| {
+                anyhow::anyhow!(format!("Failed to get database connection: {}", e))
+            })?;
+
+        let tenant_id = self.get_tenant_id();
+
+        let Some(index) = self.embedding_index(&conn, &tenant_id, embedding.len())? else {
+            return Ok(Vec::new());
+        };
+        let neighbors = index
+            .search(embedding, k, EMBEDDING_INDEX_EF_SEARCH.max(k))
+            .map_err(|e| anyhow::anyhow!("HNSW search failed: {}", e))?;
+
+        let mut stmt = conn
+            .prepare(
+                "SELECT e.entity_id, e.name, e.entity_type, e.properties,
+                        e.valid_time_start, e.transaction_time_start
+                 FROM entity_embeddings v
+                 JOIN entities e ON e.entity_id = v.entity_id
+                 WHERE v.rowid = ?1
+                   AND e.tenant_id = ?2
+                   AND e.transaction_time_end = 9999999999",
+            )
+            .map_err(|e| anyhow::anyhow!(format!("Failed to prepare similarity query: {}", e)))?;
+
+        // Neighbors arrive nearest first
+        let mut similar = Vec::with_capacity(neighbors.len());
+        for (rowid, distance) in neighbors {
+            let entity = stmt
+                .query_row(rusqlite::params![rowid, tenant_id], Self::entity_from_row)
+                .optional()
+                .map_err(|e| anyhow::anyhow!(format!("Failed to read similar entity: {}", e)))?;
+            if let Some(mut entity) = entity {
+                entity.embedding = index.get_vector(rowid).ok();
+                similar.push((entity, 1.0 - distance));
+            }
+        }
+
+        debug!(
+            "Found {} similar entities (dimension {})",
+            similar.len(),
+            embedding.len()
+        );
+
+        Ok(similar)
+    }
+
+    /// Changes recorded in the `[from, to)` transaction-time window
+    ///
+    /// Closed versions are superseded if a version with the same key was
+    /// recorded between their opening and closing, and deleted otherwise.
+    async fn changes_between(
+        &self,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> Result<GraphChangeset> {
+        let conn =
+            self.backend.get_connection().await.map_err(|e| {
+                anyhow::anyhow!(format!("Failed to get database connection: {}", e))
+            })?;
+
+        let tenant_id = self.get_tenant_id();
+        let from = Self::datetime_to_unix(from);
+        let to = Self::datetime_to_unix(to);
+
+        let entity_columns = "SELECT e.entity_id, e.name, e.entity_type, e.properties,
+                    e.valid_time_start, e.transaction_time_start
+             FROM entities e
+             WHERE e.tenant_id = ?1";
+        let entity_successor = "EXISTS (SELECT 1 FROM entities n
+                        WHERE n.tenant_id = e.tenant_id
+                          AND n.name = e.name AND n.entity_type = e.entity_type
+                          AND NOT (n.entity_id = e.entity_id
+                                   AND n.transaction_time_start = e.transaction_time_start)
+                          AND n.transaction_time_start >= e.transaction_time_start
+                          AND n.transaction_time_start <= e.transaction_time_end)";
+        let relationship_columns = "SELECT r.relationship_id, r.from_entity, r.to_entity,
+                    r.relationship_type, r.properties,
+                    r.valid_time_start, r.transaction_time_start
+             FROM relationships r
+             WHERE r.tenant_id = ?1";
+        // Relationships are corrected by re-adding them under the same ID
+        let relationship_successor = "EXISTS (SELECT 1 FROM relationships n
+                        WHERE n.tenant_id = r.tenant_id
+                          AND n.relationship_id = r.relationship_id
+                          AND n.transaction_time_start > r.transaction_time_start
+                          AND n.transaction_time_start <= r.transaction_time_end)";
+
+        let entities_added = Self::query_window(
+            &conn,
+            &format!(
+                "{entity_columns}
+               AND e.transaction_time_start >= ?2 AND e.transaction_time_start < ?3
+             ORDER BY e.transaction_time_start, e.entity_id"
+            ),
+            &tenant_id,
+            from,
+            to,
+            Self::entity_from_row,
+        )?;
+        let entities_superseded = Self::query_window(
+            &conn,
+            &format!(
+                "{entity_columns}
+               AND e.transaction_time_end >= ?2 AND e.transaction_time_end < ?3
+               AND {entity_successor}
+             ORDER BY e.transaction_time_end, e.entity_id"
+            ),
+            &tenant_id,
+            from,
+            to,
+            Self::entity_from_row,
+        )?;
+        let entities_deleted = Self::query_window(
+            &conn,
+            &format!(
+                "{entity_columns}
+               AND e.transaction_time_end >= ?2 AND e.transaction_time_end < ?3
+               AND NOT {entity_successor}
+             ORDER BY e.transaction_time_end, e.entity_id"
+            ),
+            &tenant_id,
+            from,
+            to,
+            Self::entity_from_row,
+        )?;
+
+        let relationships_added = Self::query_window(
+            &conn,
+            &format!(
+                "{relationship_columns}
+               AND r.transaction_time_start >= ?2 AND r.transaction_time_start < ?3
+             ORDER BY r.transaction_time_start, r.relationship_id"
+            ),
+            &tenant_id,
+            from,
+            to,
+            Self::relationship_from_row,
+        )?;
+        let relationships_superseded = Self::query_window(
+            &conn,
+            &format!(
+                "{relationship_columns}
+               AND r.transaction_time_end >= ?2 AND r.transaction_time_end < ?3
+               AND {relationship_successor}
+             ORDER BY r.transaction_time_end, r.relationship_id"
+            ),
+            &tenant_id,
+            from,
+            to,
+            Self::relationship_from_row,
+        )?;
+        let relationships_deleted = Self::query_window(
+            &conn,
+            &format!(
+                "{relationship_columns}
+               AND r.transaction_time_end >= ?2 AND r.transaction_time_end < ?3
+               AND NOT {relationship_successor}
+             ORDER BY r.transaction_time_end, r.relationship_id"
+            ),
+            &tenant_id,
+            from,
+            to,
+            Self::relationship_from_row,
+        )?;
+
+        let mut changeset = GraphChangeset {
+            entities_added,
+            entities_superseded,
+            entities_deleted,
+            relationships_added,
+            relationships_superseded,
+            relationships_deleted,
+        };
+        Self::attach_embeddings(
+            &conn,
+            changeset
+                .entities_added
+                .iter_mut()
+                .chain(&mut changeset.entities_superseded)
+                .chain(&mut changeset.entities_deleted),
+        )?;
+
+        debug!(
+            "Changes between {} and {}: {} entities added, {} superseded, {} deleted; {} relationships added, {} superseded, {} deleted",
+            from,
+            to,
+            changeset.entities_added.len(),
+            changeset.entities_superseded.len(),
+            changeset.entities_deleted.len(),
+            changeset.relationships_added.len(),
+            changeset.relationships_superseded.len(),
+            changeset.relationships_deleted.len()
+        );
+
+        Ok(changeset)
+    }
+
     /// Delete entities before timestamp (retention policy)
     ///
     /// Deletes entities with ingestion_time (transaction_time_start) before
@@ -767,7 +1598,7 @@ impl GraphBackend for SqliteGraphStorage {
     ///
     /// Number of entities deleted
     async fn delete_before(&self, timestamp: DateTime<Utc>) -> Result<usize> {
-        let conn =
+        let mut conn =
             self.backend.get_connection().await.map_err(|e| {
                 anyhow::anyhow!(format!("Failed to get database connection: {}", e))
             })?;
@@ -775,7 +1606,11 @@ impl GraphBackend for SqliteGraphStorage {
         let tenant_id = self.get_tenant_id();
         let cutoff = Self::datetime_to_unix(timestamp);
 
-        let rows_affected = conn
+        let tx = conn
+            .transaction()
+            .map_err(|e| anyhow::anyhow!(format!("Failed to start transaction: {}", e)))?;
+
+        let rows_affected = tx
             .execute(
                 "DELETE FROM entities
                  WHERE tenant_id = ?1
@@ -786,6 +1621,31 @@ impl GraphBackend for SqliteGraphStorage {
                 anyhow::anyhow!(format!("Failed to delete entities before timestamp: {}", e))
             })?;
 
+        // Entities are versioned, so remove relationships and embeddings only
+        // once no version of their entity is left
+        tx.execute(
+            "DELETE FROM relationships
+             WHERE tenant_id = ?1
+               AND (NOT EXISTS (SELECT 1 FROM entities e
+                                WHERE e.entity_id = relationships.from_entity)
+                    OR NOT EXISTS (SELECT 1 FROM entities e
+                                   WHERE e.entity_id = relationships.to_entity))",
+            rusqlite::params![tenant_id],
+        )
+        .map_err(|e| anyhow::anyhow!(format!("Failed to delete relationships: {}", e)))?;
+        tx.execute(
+            "DELETE FROM entity_embeddings
+             WHERE tenant_id = ?1
+               AND NOT EXISTS (SELECT 1 FROM entities e
+                               WHERE e.entity_id = entity_embeddings.entity_id)",
+            rusqlite::params![tenant_id],
+        )
+        .map_err(|e| anyhow::anyhow!(format!("Failed to delete entity embeddings: {}", e)))?;
+
+        tx.commit()
+            .map_err(|e| anyhow::anyhow!(format!("Failed to commit transaction: {}", e)))?;
+        self.invalidate_embedding_indices(&tenant_id);
+
         info!(
             "Deleted {} entities before timestamp {}",
             rows_affected, cutoff
@@ -804,6 +1664,8 @@ impl GraphBackend for SqliteGraphStorage {
     /// * `start_entity_id` - Starting entity ID
     /// * `max_depth` - Maximum traversal depth
     /// * `relationship_type` - Optional relationship type filter
+    /// * `at` - Optional point in time; both valid and transaction time must include it
+    ///   (None = now)
     ///
     /// # Returns
     ///
@@ -813,7 +1675,7 @@ impl GraphBackend for SqliteGraphStorage {
         start_entity_id: &str,
         relationship_type: Option<&str>,
         max_depth: usize,
-        _at: Option<DateTime<Utc>>,
+        at: Option<DateTime<Utc>>,
     ) -> Result<Vec<(Entity, usize, String)>> {
         let conn =
             self.backend.get_connection().await.map_err(|e| {
@@ -821,7 +1683,7 @@ impl GraphBackend for SqliteGraphStorage {
             })?;
 
         let tenant_id = self.get_tenant_id();
-        let now = Utc::now().timestamp();
+        let query_time = at.map_or_else(|| Utc::now().timestamp(), Self::datetime_to_unix);
 
         // Use a recursive CTE for traversal
         // Note: SQLite supports recursive CTEs.
@@ -833,25 +1695,25 @@ impl GraphBackend for SqliteGraphStorage {
                FROM entities
                WHERE entity_id = ?1 AND tenant_id = ?2
                  AND valid_time_start <= ?3 AND valid_time_end > ?3
-                 AND transaction_time_end = 9999999999
+                 AND transaction_time_start <= ?3 AND transaction_time_end > ?3
 
                UNION ALL
 
                -- Recursive step: follow relationships through 'to_entity'
-               SELECT r.to_entity, t.depth + 1, json_group_array(r.to_entity)
+               SELECT r.to_entity, t.depth + 1, json_insert(t.path, '$[#]', r.to_entity)
                FROM traversal t
                JOIN relationships r ON t.entity_id = r.from_entity
                WHERE t.depth < ?4
                  AND r.tenant_id = ?2
                  AND r.valid_time_start <= ?3 AND r.valid_time_end > ?3
-                 AND r.transaction_time_end = 9999999999
+                 AND r.transaction_time_start <= ?3 AND r.transaction_time_end > ?3
         ",
         );
 
         let mut params: Vec<Box<dyn rusqlite::ToSql>> = vec![
             Box::new(start_entity_id.to_string()),
             Box::new(tenant_id.clone()),
-            Box::new(now),
+            Box::new(query_time),
             Box::new(max_depth as i64),
         ];
         let param_idx = 5;
@@ -879,7 +1741,7 @@ impl GraphBackend for SqliteGraphStorage {
              JOIN entities e ON t.entity_id = e.entity_id
              WHERE e.tenant_id = ?2
                AND e.valid_time_start <= ?3 AND e.valid_time_end > ?3
-               AND e.transaction_time_end = 9999999999
+               AND e.transaction_time_start <= ?3 AND e.transaction_time_end > ?3
              ORDER BY t.depth ASC",
         );
 
@@ -928,13 +1790,102 @@ impl GraphBackend for SqliteGraphStorage {
                 properties,
                 event_time: Some(Self::unix_to_datetime(valid_time_start)),
                 ingestion_time: Self::unix_to_datetime(transaction_time_start),
+                embedding: None,
             };
 
             results.push((entity, depth as usize, String::new()));
         }
 
+        Self::attach_embeddings(&conn, results.iter_mut().map(|(entity, _, _)| entity))?;
+
         Ok(results)
     }
+
+    /// Check current rows for dangling relationships, orphaned entities and
+    /// event times later than ingestion times
+    async fn validate(&self) -> Result<ValidationReport> {
+        let conn =
+            self.backend.get_connection().await.map_err(|e| {
+                anyhow::anyhow!(format!("Failed to get database connection: {}", e))
+            })?;
+
+        let report = Self::collect_validation_report(&conn, &self.get_tenant_id())?;
+
+        debug!(
+            "Graph validation: {} dangling relationships, {} orphaned entities, {} temporal inconsistencies",
+            report.dangling_relationships.len(),
+            report.orphaned_entities.len(),
+            report.temporal_inconsistencies.len()
+        );
+
+        Ok(report)
+    }
+
+    /// Delete dangling relationships (and orphaned entities if requested)
+    ///
+    /// Rows are hard-deleted, like `delete_before`. Detection and deletion
+    /// share one transaction.
+    async fn repair(&self, options: RepairOptions) -> Result<RepairReport> {
+        let mut conn =
+            self.backend.get_connection().await.map_err(|e| {
+                anyhow::anyhow!(format!("Failed to get database connection: {}", e))
+            })?;
+
+        let tenant_id = self.get_tenant_id();
+
+        let tx = conn
+            .transaction()
+            .map_err(|e| anyhow::anyhow!(format!("Failed to start transaction: {}", e)))?;
+
+        let validation = Self::collect_validation_report(&tx, &tenant_id)?;
+        let report = RepairReport {
+            dry_run: options.dry_run,
+            removed_relationships: validation.dangling_relationships,
+            removed_entities: if options.prune_orphans {
+                validation.orphaned_entities
+            } else {
+                Vec::new()
+            },
+        };
+
+        if options.dry_run {
+            return Ok(report);
+        }
+
+        for relationship_id in &report.removed_relationships {
+            tx.execute(
+                "DELETE FROM relationships WHERE relationship_id = ?1 AND tenant_id = ?2",
+                rusqlite::params![relationship_id, tenant_id],
+            )
+            .map_err(|e| anyhow::anyhow!(format!("Failed to delete relationship: {}", e)))?;
+        }
+        for entity_id in &report.removed_entities {
+            tx.execute(
+                "DELETE FROM entities WHERE entity_id = ?1 AND tenant_id = ?2",
+                rusqlite::params![entity_id, tenant_id],
+            )
+            .map_err(|e| anyhow::anyhow!(format!("Failed to delete entity: {}", e)))?;
+            tx.execute(
+                "DELETE FROM entity_embeddings WHERE entity_id = ?1 AND tenant_id = ?2",
+                rusqlite::params![entity_id, tenant_id],
+            )
+            .map_err(|e| anyhow::anyhow!(format!("Failed to delete entity embedding: {}", e)))?;
+        }
+
+        tx.commit()
+            .map_err(|e| anyhow::anyhow!(format!("Failed to commit transaction: {}", e)))?;
+        if !report.removed_entities.is_empty() {
+            self.invalidate_embedding_indices(&tenant_id);
+        }
+
+        info!(
+            "Repaired graph: removed {} dangling relationships and {} orphaned entities",
+            report.removed_relationships.len(),
+            report.removed_entities.len()
+        );
+
+        Ok(report)
+    }
 }
 
 #[async_trait]
@@ -951,6 +1902,10 @@ impl llmspell_core::traits::storage::KnowledgeGraph for SqliteGraphStorage {
         GraphBackend::update_entity(self, id, changes).await
     }
 
+    async fn upsert_entities(&self, entities: Vec<Entity>) -> Result<Vec<String>> {
+        GraphBackend::upsert_entities(self, entities).await
+    }
+
     async fn get_entity(&self, id: &str) -> Result<Entity> {
         GraphBackend::get_entity(self, id).await
     }
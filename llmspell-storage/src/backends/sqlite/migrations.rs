@@ -73,6 +73,42 @@ impl SqliteBackend {
         ))
         .map_err(|e| anyhow::anyhow!("V13 migration failed: {}", e))?;
 
+        // V16: Entity embeddings
+        conn.execute_batch(include_str!(
+            "../../../migrations/sqlite/V16__entity_embeddings.sql"
+        ))
+        .map_err(|e| anyhow::anyhow!("V16 migration failed: {}", e))?;
+
+        // V17: Vector access tracking (ALTER TABLE is not repeatable, so apply once)
+        let v17_applied: bool = conn
+            .query_row(
+                "SELECT EXISTS(SELECT 1 FROM _migrations WHERE version = 17)",
+                [],
+                |row| row.get(0),
+            )
+            .map_err(|e| anyhow::anyhow!("Failed to check V17 migration: {}", e))?;
+        if !v17_applied {
+            conn.execute_batch(include_str!(
+                "../../../migrations/sqlite/V17__vector_access_tracking.sql"
+            ))
+            .map_err(|e| anyhow::anyhow!("V17 migration failed: {}", e))?;
+        }
+
+        // V18: Bi-temporal composite keys (table rebuild, so apply once)
+        let v18_applied: bool = conn
+            .query_row(
+                "SELECT EXISTS(SELECT 1 FROM _migrations WHERE version = 18)",
+                [],
+                |row| row.get(0),
+            )
+            .map_err(|e| anyhow::anyhow!("Failed to check V18 migration: {}", e))?;
+        if !v18_applied {
+            conn.execute_batch(include_str!(
+                "../../../migrations/sqlite/V18__bitemporal_composite_keys.sql"
+            ))
+            .map_err(|e| anyhow::anyhow!("V18 migration failed: {}", e))?;
+        }
+
         Ok(())
     }
 
@@ -109,7 +145,7 @@ mod tests {
 
         // Verify migration version
         let version = backend.migration_version().await.unwrap();
-        assert_eq!(version, 13, "Should have applied migration V13");
+        assert_eq!(version, 18, "Should have applied migration V18");
 
         // Verify tables exist
         let conn = backend.get_connection().await.unwrap();
@@ -147,8 +183,8 @@ mod tests {
         backend.run_migrations().await.unwrap();
         backend.run_migrations().await.unwrap();
 
-        // Should still be at version 13
+        // Should still be at version 18
         let version = backend.migration_version().await.unwrap();
-        assert_eq!(version, 13);
+        assert_eq!(version, 18);
     }
 }
@@ -28,7 +28,8 @@ use super::backend::SqliteBackend;
 use llmspell_core::state::StateScope;
 use llmspell_core::traits::storage::VectorStorage;
 use llmspell_core::types::storage::vector::{
-    DistanceMetric, ScopedStats, StorageStats, VectorEntry, VectorQuery, VectorResult,
+    DistanceMetric, ScopedStats, StorageStats, VectorEntry, VectorPruneFilter, VectorQuery,
+    VectorResult,
 };
 
 /// Hybrid SQLite vector storage with HNSW indexing
@@ -427,6 +428,121 @@ impl SqliteVectorStorage {
 
         Ok(())
     }
+
+    /// Record a read of each vector for access-based pruning
+    ///
+    /// Unknown IDs are ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the access statistics cannot be updated
+    pub async fn record_access(&self, ids: &[String]) -> Result<()> {
+        if ids.is_empty() {
+            return Ok(());
+        }
+
+        let mut conn = self.backend.get_connection().await?;
+        let tx = conn.transaction()?;
+        let now = unix_seconds(std::time::SystemTime::now());
+        {
+            let mut stmt = tx.prepare(
+                "UPDATE vector_metadata SET access_count = access_count + 1, last_accessed = ?1 WHERE id = ?2",
+            )?;
+            for id in ids {
+                stmt.execute(params![now, id.as_str()])?;
+            }
+        }
+        tx.commit()?;
+
+        Ok(())
+    }
+
+    /// Delete the vectors in `scope` selected by `filter`
+    ///
+    /// Selection and deletion run in one transaction against the tables, so
+    /// access counts recorded before a restart are taken into account.
+    ///
+    /// # Returns
+    ///
+    /// IDs of the deleted vectors
+    ///
+    /// # Errors
+    ///
+    /// Returns error if selecting or deleting vectors fails
+    pub async fn prune(
+        &self,
+        scope: &StateScope,
+        filter: &VectorPruneFilter,
+    ) -> Result<Vec<String>> {
+        if filter.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let namespace = Self::scope_to_namespace(scope);
+        let older_than = filter.older_than.map(unix_seconds);
+        let keep_newest = filter.keep_newest.map(|n| n as i64);
+        let min_access_count = filter.min_access_count.map(|n| n as i64);
+
+        let mut conn = self.backend.get_connection().await?;
+        let tx = conn.transaction()?;
+
+        let mut pruned = Vec::new();
+        {
+            let mut stmt = tx.prepare(
+                "SELECT rowid, id FROM (
+                    SELECT rowid, id, access_count,
+                           COALESCE(event_time, created_at) AS event_time,
+                           ROW_NUMBER() OVER (
+                               ORDER BY COALESCE(event_time, created_at) DESC, rowid DESC
+                           ) AS rank
+                    FROM vector_metadata
+                    WHERE scope = ?1 AND dimension = ?2
+                 )
+                 WHERE (?3 IS NOT NULL AND event_time < ?3)
+                    OR (?4 IS NOT NULL AND rank > ?4)
+                    OR (?5 IS NOT NULL AND access_count < ?5)",
+            )?;
+            let mut rows = stmt.query(params![
+                namespace,
+                self.dimension as i64,
+                older_than,
+                keep_newest,
+                min_access_count
+            ])?;
+            while let Some(row) = rows.next()? {
+                let rowid: i64 = row.get(0)?;
+                let id: String = row.get(1)?;
+                pruned.push((rowid, id));
+            }
+        }
+
+        if pruned.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let delete_vec_sql = format!("DELETE FROM {} WHERE rowid = ?", self.table_name());
+        for (rowid, _) in &pruned {
+            tx.execute(
+                "DELETE FROM vector_metadata WHERE rowid = ?",
+                params![rowid],
+            )?;
+            tx.execute(&delete_vec_sql, params![rowid])?;
+        }
+        tx.commit()?;
+
+        // Clear the HNSW index for this namespace to force rebuild
+        self.hnsw_indices.remove(&namespace);
+
+        info!("Pruned {} vectors for scope {:?}", pruned.len(), scope);
+
+        Ok(pruned.into_iter().map(|(_, id)| id).collect())
+    }
+}
+
+/// Seconds since the Unix epoch, the timestamp encoding of `vector_metadata`
+fn unix_seconds(time: std::time::SystemTime) -> i64 {
+    time.duration_since(std::time::UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs() as i64)
 }
 
 #[async_trait]
@@ -486,9 +602,10 @@ impl VectorStorage for SqliteVectorStorage {
                 .duration_since(std::time::UNIX_EPOCH)
                 .unwrap()
                 .as_secs() as i64;
+            let event_time = entry.event_time.map(unix_seconds);
 
             tx.execute(
-                "INSERT INTO vector_metadata (rowid, id, tenant_id, scope, dimension, metadata, created_at, updated_at) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+                "INSERT INTO vector_metadata (rowid, id, tenant_id, scope, dimension, metadata, created_at, updated_at, event_time) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
                 params![
                     rowid,
                     id.clone(),
@@ -498,6 +615,7 @@ impl VectorStorage for SqliteVectorStorage {
                     metadata_json,
                     created_at,
                     updated_at,
+                    event_time,
                 ],
             )
             .with_context(|| "Failed to insert into vector_metadata")?;
@@ -750,6 +868,61 @@ impl VectorStorage for SqliteVectorStorage {
         Ok(count)
     }
 
+    async fn delete_by_filter(
+        &self,
+        scope: &StateScope,
+        filter: HashMap<String, Value>,
+    ) -> Result<usize> {
+        let namespace = Self::scope_to_namespace(scope);
+        let conn = self.backend.get_connection().await?;
+
+        // Match metadata in Rust so filter values compare as JSON, not SQL values
+        let mut stmt = conn.prepare(
+            "SELECT rowid, metadata FROM vector_metadata WHERE scope = ? AND dimension = ?",
+        )?;
+        let mut rows = stmt.query(params![namespace.clone(), self.dimension as i64])?;
+
+        let mut rowids = Vec::new();
+        while let Some(row) = rows.next()? {
+            let rowid: i64 = row.get(0)?;
+            let metadata_json: String = row.get(1)?;
+            let metadata: HashMap<String, Value> =
+                serde_json::from_str(&metadata_json).unwrap_or_default();
+            if filter
+                .iter()
+                .all(|(key, value)| metadata.get(key) == Some(value))
+            {
+                rowids.push(rowid);
+            }
+        }
+
+        let count = rowids.len();
+
+        if count == 0 {
+            return Ok(0);
+        }
+
+        let vec_table = self.table_name();
+        let delete_vec_sql = format!("DELETE FROM {} WHERE rowid = ?", vec_table);
+        for rowid in rowids {
+            conn.execute(
+                "DELETE FROM vector_metadata WHERE rowid = ?",
+                params![rowid],
+            )?;
+            conn.execute(&delete_vec_sql, params![rowid])?;
+        }
+
+        // Clear the HNSW index for this namespace to force rebuild
+        self.hnsw_indices.remove(&namespace);
+
+        info!(
+            "Deleted {} vectors matching filter for scope {:?}",
+            count, scope
+        );
+
+        Ok(count)
+    }
+
     async fn stats(&self) -> Result<StorageStats> {
         let conn = self.backend.get_connection().await?;
 
@@ -903,7 +1076,10 @@ mod tests {
                 dimension INTEGER NOT NULL CHECK (dimension IN (384, 768, 1536, 3072)),
                 metadata TEXT NOT NULL DEFAULT '{}',
                 created_at INTEGER NOT NULL,
-                updated_at INTEGER NOT NULL
+                updated_at INTEGER NOT NULL,
+                event_time INTEGER,
+                access_count INTEGER NOT NULL DEFAULT 0,
+                last_accessed INTEGER
             )",
             (),
         )
@@ -1048,6 +1224,50 @@ mod tests {
         assert_eq!(deleted, 2);
     }
 
+    #[tokio::test]
+    async fn test_delete_by_filter() {
+        let (storage, _temp) = create_test_storage(768).await;
+
+        let user = StateScope::User("user123".to_string());
+        let source = |name: &str| HashMap::from([("source".to_string(), serde_json::json!(name))]);
+
+        let entries = vec![
+            VectorEntry::new("doc42-1".to_string(), create_test_vector(768, 1.0))
+                .with_scope(user.clone())
+                .with_metadata(source("doc-42.pdf")),
+            VectorEntry::new("doc42-2".to_string(), create_test_vector(768, 2.0))
+                .with_scope(user.clone())
+                .with_metadata(source("doc-42.pdf")),
+            VectorEntry::new("doc7-1".to_string(), create_test_vector(768, 3.0))
+                .with_scope(user.clone())
+                .with_metadata(source("doc-7.pdf")),
+            // Same source in another scope must survive
+            VectorEntry::new("other-doc42".to_string(), create_test_vector(768, 1.0))
+                .with_scope(StateScope::User("user456".to_string()))
+                .with_metadata(source("doc-42.pdf")),
+        ];
+
+        storage.insert(entries).await.unwrap();
+
+        let deleted = storage
+            .delete_by_filter(&user, source("doc-42.pdf"))
+            .await
+            .unwrap();
+        assert_eq!(deleted, 2);
+
+        let query = VectorQuery::new(create_test_vector(768, 1.0), 10);
+        let remaining = storage.search_scoped(&query, &user).await.unwrap();
+        assert_eq!(remaining.len(), 1);
+        assert_eq!(remaining[0].id, "doc7-1");
+
+        let other = storage
+            .search_scoped(&query, &StateScope::User("user456".to_string()))
+            .await
+            .unwrap();
+        assert_eq!(other.len(), 1);
+        assert_eq!(other[0].id, "other-doc42");
+    }
+
     #[tokio::test]
     async fn test_update_metadata() {
         let (storage, _temp) = create_test_storage(768).await;
@@ -6,10 +6,14 @@
 //! - add_relationship() and get_related()
 //! - delete_before() data retention
 //! - Trait method delegation to existing implementations
+//! - stream_related() yielding every edge of a hub entity
+//! - Entity embeddings persisted, read back and ranked by find_similar_entities()
+//! - Retracted entities excluded from similarity search and their embeddings removed
 
 #![cfg(feature = "postgres")]
 
 use chrono::{Duration, Utc};
+use futures::TryStreamExt;
 use llmspell_graph::traits::KnowledgeGraph;
 use llmspell_graph::{Entity, Relationship};
 use llmspell_storage::backends::postgres::{PostgresBackend, PostgresConfig, PostgresGraphStorage};
@@ -71,6 +75,7 @@ async fn test_add_and_get_entity() {
         properties: json!({"age": 30, "city": "SF"}),
         event_time: Some(now),
         ingestion_time: now,
+        embedding: None,
     };
 
     let entity_id = graph.add_entity(entity).await.expect("add_entity");
@@ -107,6 +112,7 @@ async fn test_update_entity_bi_temporal() {
         properties: json!({"age": 30, "city": "SF"}),
         event_time: Some(now),
         ingestion_time: now,
+        embedding: None,
     };
 
     let entity_id = graph.add_entity(entity).await.expect("add_entity");
@@ -152,6 +158,7 @@ async fn test_add_relationship_and_get_related() {
         properties: json!({}),
         event_time: Some(now),
         ingestion_time: now,
+        embedding: None,
     };
 
     let company = Entity {
@@ -161,6 +168,7 @@ async fn test_add_relationship_and_get_related() {
         properties: json!({}),
         event_time: Some(now),
         ingestion_time: now,
+        embedding: None,
     };
 
     let person_id = graph.add_entity(person).await.expect("add person");
@@ -216,6 +224,7 @@ async fn test_delete_before() {
         properties: json!({"version": 1}),
         event_time: Some(old_time),
         ingestion_time: old_time,
+        embedding: None,
     };
 
     let entity_id = graph.add_entity(entity).await.expect("add_entity");
@@ -283,6 +292,7 @@ async fn test_tenant_isolation() {
         properties: json!({}),
         event_time: Some(now),
         ingestion_time: now,
+        embedding: None,
     };
 
     let id_a = graph_a.add_entity(entity_a).await.expect("add entity A");
@@ -323,6 +333,7 @@ async fn test_get_relationships() {
         properties: json!({}),
         event_time: Some(now),
         ingestion_time: now,
+        embedding: None,
     };
 
     let company1 = Entity {
@@ -332,6 +343,7 @@ async fn test_get_relationships() {
         properties: json!({}),
         event_time: Some(now),
         ingestion_time: now,
+        embedding: None,
     };
 
     let company2 = Entity {
@@ -341,6 +353,7 @@ async fn test_get_relationships() {
         properties: json!({}),
         event_time: Some(now),
         ingestion_time: now,
+        embedding: None,
     };
 
     let person_id = graph.add_entity(person).await.expect("add person");
@@ -419,3 +432,204 @@ async fn test_get_relationships() {
     assert_eq!(company1_rels.len(), 1);
     assert_eq!(company1_rels[0].relationship_type, "works_at");
 }
+
+#[tokio::test]
+async fn test_upsert_entities_merges_duplicates() {
+    ensure_migrations_run_once().await;
+
+    let tenant_id = unique_tenant_id("kg-upsert");
+    let config = PostgresConfig::new(APP_CONNECTION_STRING);
+    let backend = Arc::new(PostgresBackend::new(config).await.expect("create backend"));
+    backend
+        .set_tenant_context(&tenant_id)
+        .await
+        .expect("set tenant");
+
+    let graph = PostgresGraphStorage::new(Arc::clone(&backend));
+
+    // Same entity extracted from two documents, plus an unrelated one
+    let ids = graph
+        .upsert_entities(vec![
+            Entity::new(
+                "Rust".to_string(),
+                "programming_language".to_string(),
+                json!({"paradigm": "systems"}),
+            ),
+            Entity::new(
+                "Python".to_string(),
+                "programming_language".to_string(),
+                json!({}),
+            ),
+            Entity::new(
+                "Rust".to_string(),
+                "programming_language".to_string(),
+                json!({"memory_safe": true}),
+            ),
+        ])
+        .await
+        .expect("upsert_entities");
+
+    assert_eq!(ids.len(), 3);
+    assert_eq!(ids[0], ids[2], "Duplicate should map to the same node");
+    assert_ne!(ids[0], ids[1]);
+
+    // Merged properties on the current version
+    let rust = graph.get_entity(&ids[0]).await.expect("get_entity");
+    assert_eq!(rust.properties["paradigm"], "systems");
+    assert_eq!(rust.properties["memory_safe"], true);
+
+    // One logical node with two temporal versions
+    let client = backend.get_client().await.expect("get client");
+    let row = client
+        .query_one(
+            "SELECT COUNT(*) AS versions,
+                    COUNT(*) FILTER (WHERE transaction_time_end = 'infinity') AS current
+             FROM llmspell.entities
+             WHERE tenant_id = $1 AND name = 'Rust'",
+            &[&tenant_id],
+        )
+        .await
+        .expect("count versions");
+    let versions: i64 = row.get("versions");
+    let current: i64 = row.get("current");
+    assert_eq!(versions, 2);
+    assert_eq!(current, 1);
+}
+
+#[tokio::test]
+async fn test_stream_related_yields_all_relationships() {
+    ensure_migrations_run_once().await;
+
+    let tenant_id = unique_tenant_id("kg-stream");
+    let config = PostgresConfig::new(APP_CONNECTION_STRING);
+    let backend = Arc::new(PostgresBackend::new(config).await.expect("create backend"));
+    backend
+        .set_tenant_context(&tenant_id)
+        .await
+        .expect("set tenant");
+
+    let graph = PostgresGraphStorage::new(Arc::clone(&backend));
+
+    let hub = graph
+        .add_entity(Entity::new(
+            "Hub".to_string(),
+            "page".to_string(),
+            json!({}),
+        ))
+        .await
+        .expect("add hub");
+    for i in 0..200 {
+        let target = graph
+            .add_entity(Entity::new(
+                format!("Page {i}"),
+                "page".to_string(),
+                json!({}),
+            ))
+            .await
+            .expect("add target");
+        graph
+            .add_relationship(Relationship::new(
+                hub.clone(),
+                target,
+                "links_to".to_string(),
+                json!({}),
+            ))
+            .await
+            .expect("add relationship");
+    }
+
+    let relationships: Vec<Relationship> = graph
+        .stream_related(&hub, "links_to")
+        .try_collect()
+        .await
+        .expect("stream relationships");
+
+    assert_eq!(relationships.len(), 200);
+    assert!(relationships.iter().all(|r| r.from_entity == hub));
+}
+
+#[tokio::test]
+async fn test_entity_embeddings_and_similarity() {
+    ensure_migrations_run_once().await;
+
+    let tenant_id = unique_tenant_id("kg-embeddings");
+    let config = PostgresConfig::new(APP_CONNECTION_STRING);
+    let backend = Arc::new(PostgresBackend::new(config).await.expect("create backend"));
+    backend
+        .set_tenant_context(&tenant_id)
+        .await
+        .expect("set tenant");
+
+    let graph = PostgresGraphStorage::new(Arc::clone(&backend));
+
+    let mut ids = Vec::new();
+    for (name, embedding) in [
+        ("Orthogonal", vec![0.0, 1.0, 0.0]),
+        ("Close", vec![0.9, 0.1, 0.0]),
+        ("Exact", vec![2.0, 0.0, 0.0]),
+    ] {
+        let entity = Entity::new(name.to_string(), "concept".to_string(), json!({}))
+            .with_embedding(embedding);
+        ids.push(graph.add_entity(entity).await.expect("add_entity"));
+    }
+    graph
+        .add_entity(Entity::new(
+            "Unembedded".to_string(),
+            "concept".to_string(),
+            json!({}),
+        ))
+        .await
+        .expect("add_entity");
+
+    let stored = graph.get_entity(&ids[1]).await.expect("get_entity");
+    assert_eq!(stored.embedding, Some(vec![0.9, 0.1, 0.0]));
+
+    let similar = graph
+        .find_similar_entities(&[1.0, 0.0, 0.0], 2)
+        .await
+        .expect("find_similar_entities");
+    let similar_ids: Vec<&str> = similar.iter().map(|(e, _)| e.id.as_str()).collect();
+    assert_eq!(similar_ids, vec![ids[2].as_str(), ids[1].as_str()]);
+    assert!((similar[0].1 - 1.0).abs() < 1e-6);
+
+    // Retract the closest entity (the trait has no retraction API)
+    let exact_id = Uuid::parse_str(&ids[2]).expect("entity UUID");
+    let client = backend.get_client().await.expect("get client");
+    client
+        .execute(
+            "UPDATE llmspell.entities
+             SET transaction_time_end = now()
+             WHERE tenant_id = $1 AND entity_id = $2",
+            &[&tenant_id, &exact_id],
+        )
+        .await
+        .expect("retract entity");
+
+    // A retracted entity does not take one of the k slots
+    let similar = graph
+        .find_similar_entities(&[1.0, 0.0, 0.0], 1)
+        .await
+        .expect("find_similar_entities");
+    assert_eq!(similar.len(), 1);
+    assert_eq!(similar[0].0.id, ids[1]);
+
+    // Removing the entity's last version removes its embedding
+    graph
+        .delete_before(Utc::now() + Duration::hours(1))
+        .await
+        .expect("delete_before");
+    let row = client
+        .query_one(
+            "SELECT COUNT(*) FILTER (WHERE entity_id = $2) AS retracted,
+                    COUNT(*) AS remaining
+             FROM llmspell.entity_embeddings
+             WHERE tenant_id = $1",
+            &[&tenant_id, &exact_id],
+        )
+        .await
+        .expect("count embeddings");
+    let retracted: i64 = row.get("retracted");
+    let remaining: i64 = row.get("remaining");
+    assert_eq!(retracted, 0);
+    assert_eq!(remaining, 2);
+}
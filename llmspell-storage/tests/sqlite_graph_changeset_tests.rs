@@ -0,0 +1,143 @@
+//! Integration tests for SQLite knowledge graph change history
+//!
+//! Verifies:
+//! - changes_between lists entities and relationships recorded in the window
+//! - Corrections are reported as superseded, new relationships from the same entity are not
+//! - Changes before or after the window are excluded
+
+use chrono::Utc;
+use llmspell_core::types::storage::{Entity, Relationship};
+use llmspell_graph::storage::GraphBackend;
+use llmspell_storage::backends::sqlite::{SqliteBackend, SqliteConfig, SqliteGraphStorage};
+use serde_json::json;
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::time::Duration;
+use tempfile::TempDir;
+
+/// Create test graph storage with temporary database
+async fn create_test_graph() -> (TempDir, SqliteGraphStorage) {
+    let temp_dir = TempDir::new().expect("create temp dir");
+    let db_path = temp_dir.path().join("test_graph.db");
+
+    let config = SqliteConfig::new(db_path.to_str().unwrap()).with_max_connections(5);
+    let backend = Arc::new(SqliteBackend::new(config).await.expect("create backend"));
+
+    backend.run_migrations().await.expect("run migrations");
+
+    let graph = SqliteGraphStorage::new(backend);
+
+    (temp_dir, graph)
+}
+
+async fn add_entity(graph: &SqliteGraphStorage, name: &str, entity_type: &str) -> String {
+    graph
+        .add_entity(Entity::new(
+            name.to_string(),
+            entity_type.to_string(),
+            json!({}),
+        ))
+        .await
+        .expect("add entity")
+}
+
+/// Record a corrected version of the entity with this name and type
+async fn correct_entity(graph: &SqliteGraphStorage, name: &str, entity_type: &str) -> String {
+    graph
+        .upsert_entities(vec![Entity::new(
+            name.to_string(),
+            entity_type.to_string(),
+            json!({"corrected": true}),
+        )])
+        .await
+        .expect("upsert entity")
+        .remove(0)
+}
+
+/// Add a relationship, or correct it when `id` is already current
+async fn add_relationship(graph: &SqliteGraphStorage, id: &str, from: &str, to: &str) -> String {
+    graph
+        .add_relationship(
+            Relationship::new(
+                from.to_string(),
+                to.to_string(),
+                "works_at".to_string(),
+                json!({}),
+            )
+            .with_id(id.to_string()),
+        )
+        .await
+        .expect("add relationship")
+}
+
+fn ids<'a>(ids: impl IntoIterator<Item = &'a String>) -> HashSet<&'a str> {
+    ids.into_iter().map(String::as_str).collect()
+}
+
+#[tokio::test]
+async fn test_changes_between_lists_window_changes() {
+    let (_temp_dir, graph) = create_test_graph().await;
+
+    // Before the window (timestamps are stored with second precision)
+    let alice = add_entity(&graph, "Alice", "person").await;
+    let bob = add_entity(&graph, "Bob", "person").await;
+    let acme = add_entity(&graph, "Acme", "company").await;
+    let globex = add_entity(&graph, "Globex", "company").await;
+    let rust = add_entity(&graph, "Rust", "language").await;
+    let alice_job = add_relationship(&graph, "alice-job", &alice, &acme).await;
+    let bob_job = add_relationship(&graph, "bob-job", &bob, &acme).await;
+    tokio::time::sleep(Duration::from_millis(1100)).await;
+    let t1 = Utc::now();
+
+    // In the window: two corrections, one new entity and one new relationship
+    // from an entity that already has a current relationship of that type
+    add_relationship(&graph, &alice_job, &alice, &globex).await;
+    assert_eq!(correct_entity(&graph, "Rust", "language").await, rust);
+    let carol = add_entity(&graph, "Carol", "person").await;
+    let bob_side_job = add_relationship(&graph, "bob-side-job", &bob, &globex).await;
+    tokio::time::sleep(Duration::from_millis(1100)).await;
+    let t2 = Utc::now();
+
+    // After the window, including a correction of a version recorded in it
+    add_entity(&graph, "Dave", "person").await;
+    add_relationship(&graph, "carol-job", &carol, &acme).await;
+    add_relationship(&graph, &alice_job, &alice, &acme).await;
+
+    let changes = graph
+        .changes_between(t1, t2)
+        .await
+        .expect("changes between");
+
+    assert_eq!(
+        ids(changes.entities_added.iter().map(|e| &e.id)),
+        ids([&rust, &carol])
+    );
+    assert_eq!(changes.entities_superseded.len(), 1);
+    assert_eq!(changes.entities_superseded[0].id, rust);
+    assert_eq!(changes.entities_superseded[0].properties, json!({}));
+    assert!(changes.entities_deleted.is_empty());
+
+    assert_eq!(
+        ids(changes.relationships_added.iter().map(|r| &r.id)),
+        ids([&alice_job, &bob_side_job])
+    );
+    // Only the corrected relationship is superseded; Bob's original job stays current
+    assert_eq!(changes.relationships_superseded.len(), 1);
+    assert_eq!(changes.relationships_superseded[0].id, alice_job);
+    assert_eq!(changes.relationships_superseded[0].to_entity, acme);
+    assert!(changes.relationships_deleted.is_empty());
+    let bob_relationships = graph
+        .get_relationships(&bob)
+        .await
+        .expect("bob relationships");
+    assert!(bob_relationships.iter().any(|r| r.id == bob_job));
+
+    let before = graph
+        .changes_between(
+            t1 - chrono::Duration::hours(1),
+            t1 - chrono::Duration::minutes(30),
+        )
+        .await
+        .expect("changes before graph existed");
+    assert!(before.is_empty());
+}
@@ -0,0 +1,109 @@
+//! Integration tests for streaming SQLite graph relationships
+//!
+//! Verifies:
+//! - stream_related yields every relationship of a hub entity
+//! - Relationships are fetched lazily in bounded batches
+
+use futures::{StreamExt, TryStreamExt};
+use llmspell_core::types::storage::{Entity, Relationship};
+use llmspell_graph::storage::GraphBackend;
+use llmspell_storage::backends::sqlite::{SqliteBackend, SqliteConfig, SqliteGraphStorage};
+use serde_json::json;
+use std::collections::HashSet;
+use std::sync::Arc;
+use tempfile::TempDir;
+
+const EDGES: usize = 250;
+const BATCH_SIZE: usize = 50;
+
+/// Create graph storage holding a hub entity with `EDGES` outgoing "links_to" relationships
+async fn create_hub_graph() -> (TempDir, Arc<SqliteBackend>, SqliteGraphStorage, String) {
+    let temp_dir = TempDir::new().expect("create temp dir");
+    let db_path = temp_dir.path().join("test_graph_stream.db");
+
+    let config = SqliteConfig::new(db_path.to_str().unwrap()).with_max_connections(5);
+    let backend = Arc::new(SqliteBackend::new(config).await.expect("create backend"));
+    backend.run_migrations().await.expect("run migrations");
+
+    let graph = SqliteGraphStorage::new(Arc::clone(&backend)).with_stream_batch_size(BATCH_SIZE);
+
+    let hub = graph
+        .add_entity(Entity::new(
+            "Hub".to_string(),
+            "page".to_string(),
+            json!({}),
+        ))
+        .await
+        .expect("add hub");
+    for i in 0..EDGES {
+        let target = graph
+            .add_entity(Entity::new(
+                format!("Page {i}"),
+                "page".to_string(),
+                json!({}),
+            ))
+            .await
+            .expect("add target");
+        graph
+            .add_relationship(Relationship::new(
+                hub.clone(),
+                target,
+                "links_to".to_string(),
+                json!({}),
+            ))
+            .await
+            .expect("add relationship");
+    }
+
+    (temp_dir, backend, graph, hub)
+}
+
+#[tokio::test]
+async fn test_stream_related_yields_all_relationships() {
+    let (_temp_dir, _backend, graph, hub) = create_hub_graph().await;
+
+    let relationships: Vec<Relationship> = graph
+        .stream_related(&hub, "links_to")
+        .try_collect()
+        .await
+        .expect("stream relationships");
+
+    assert_eq!(relationships.len(), EDGES);
+    let unique: HashSet<&str> = relationships.iter().map(|r| r.id.as_str()).collect();
+    assert_eq!(unique.len(), EDGES);
+    assert!(relationships.iter().all(|r| r.from_entity == hub));
+
+    let other: Vec<Relationship> = graph
+        .stream_related(&hub, "cites")
+        .try_collect()
+        .await
+        .expect("stream other type");
+    assert!(other.is_empty());
+}
+
+#[tokio::test]
+async fn test_stream_related_fetches_in_batches() {
+    let (_temp_dir, backend, graph, hub) = create_hub_graph().await;
+
+    let mut stream = graph.stream_related(&hub, "links_to");
+    let first = stream
+        .next()
+        .await
+        .expect("first relationship")
+        .expect("stream ok");
+    assert_eq!(first.from_entity, hub);
+
+    // Close every relationship after the first batch was fetched; only the
+    // already-loaded batch can still be yielded
+    {
+        let conn = backend.get_connection().await.expect("get connection");
+        conn.execute(
+            "UPDATE relationships SET transaction_time_end = transaction_time_start + 1",
+            [],
+        )
+        .expect("close relationships");
+    }
+
+    let rest: Vec<Relationship> = stream.try_collect().await.expect("stream rest");
+    assert_eq!(rest.len() + 1, BATCH_SIZE);
+}
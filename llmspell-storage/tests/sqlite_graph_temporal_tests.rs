@@ -0,0 +1,231 @@
+//! Integration tests for SQLite knowledge graph temporal queries and batch ingestion
+//!
+//! Verifies:
+//! - get_related_at returns relationships as they were recorded at a past time
+//! - Corrections recorded after the query time are excluded
+//! - upsert_entities merges entities matching on (name, entity_type)
+//! - upsert_entities keeps the previous version for past-time queries
+
+use chrono::Utc;
+use llmspell_core::traits::storage::KnowledgeGraph;
+use llmspell_core::types::storage::{Entity, Relationship};
+use llmspell_storage::backends::sqlite::{SqliteBackend, SqliteConfig, SqliteGraphStorage};
+use serde_json::json;
+use std::sync::Arc;
+use std::time::Duration;
+use tempfile::TempDir;
+
+/// Create test graph storage with temporary database
+async fn create_test_graph() -> (TempDir, Arc<SqliteBackend>, SqliteGraphStorage) {
+    let temp_dir = TempDir::new().expect("create temp dir");
+    let db_path = temp_dir.path().join("test_graph.db");
+
+    let config = SqliteConfig::new(db_path.to_str().unwrap()).with_max_connections(5);
+    let backend = Arc::new(SqliteBackend::new(config).await.expect("create backend"));
+
+    backend.run_migrations().await.expect("run migrations");
+
+    let graph = SqliteGraphStorage::new(Arc::clone(&backend));
+
+    (temp_dir, backend, graph)
+}
+
+#[tokio::test]
+async fn test_get_related_at_excludes_later_corrections() {
+    let (_temp_dir, backend, graph) = create_test_graph().await;
+
+    let alice = KnowledgeGraph::add_entity(
+        &graph,
+        Entity::new("Alice".to_string(), "person".to_string(), json!({})),
+    )
+    .await
+    .expect("add alice");
+    let acme = KnowledgeGraph::add_entity(
+        &graph,
+        Entity::new("Acme".to_string(), "company".to_string(), json!({})),
+    )
+    .await
+    .expect("add acme");
+    let globex = KnowledgeGraph::add_entity(
+        &graph,
+        Entity::new("Globex".to_string(), "company".to_string(), json!({})),
+    )
+    .await
+    .expect("add globex");
+
+    let original = KnowledgeGraph::add_relationship(
+        &graph,
+        Relationship::new(
+            alice.clone(),
+            acme.clone(),
+            "works_at".to_string(),
+            json!({}),
+        ),
+    )
+    .await
+    .expect("add original relationship");
+
+    // Timestamps are stored with second precision
+    let before_correction = Utc::now();
+    tokio::time::sleep(Duration::from_secs(2)).await;
+
+    // Correct the relationship: close the original record and add the new one
+    {
+        let conn = backend.get_connection().await.expect("get connection");
+        conn.execute(
+            "UPDATE relationships SET transaction_time_end = ?1 WHERE relationship_id = ?2",
+            rusqlite::params![Utc::now().timestamp(), original],
+        )
+        .expect("close original relationship");
+    }
+    KnowledgeGraph::add_relationship(
+        &graph,
+        Relationship::new(
+            alice.clone(),
+            globex.clone(),
+            "works_at".to_string(),
+            json!({}),
+        ),
+    )
+    .await
+    .expect("add corrected relationship");
+
+    let then = graph
+        .get_related_at(&alice, "works_at", before_correction)
+        .await
+        .expect("query before correction");
+    assert_eq!(then.len(), 1);
+    assert_eq!(then[0].id, acme);
+
+    let now = graph
+        .get_related_at(&alice, "works_at", Utc::now())
+        .await
+        .expect("query after correction");
+    assert_eq!(now.len(), 1);
+    assert_eq!(now[0].id, globex);
+}
+
+#[tokio::test]
+async fn test_upsert_entities_merges_duplicates() {
+    let (_temp_dir, backend, graph) = create_test_graph().await;
+
+    let existing = KnowledgeGraph::add_entity(
+        &graph,
+        Entity::new(
+            "Rust".to_string(),
+            "programming_language".to_string(),
+            json!({"paradigm": "systems"}),
+        ),
+    )
+    .await
+    .expect("add existing entity");
+
+    let ids = graph
+        .upsert_entities(vec![
+            Entity::new(
+                "Python".to_string(),
+                "programming_language".to_string(),
+                json!({}),
+            ),
+            Entity::new(
+                "Rust".to_string(),
+                "programming_language".to_string(),
+                json!({"memory_safe": true}),
+            ),
+            Entity::new(
+                "Python".to_string(),
+                "programming_language".to_string(),
+                json!({"typing": "dynamic"}),
+            ),
+        ])
+        .await
+        .expect("upsert entities");
+
+    assert_eq!(ids.len(), 3);
+    assert_eq!(ids[1], existing);
+    assert_eq!(ids[0], ids[2]);
+
+    let rust = graph.get_entity(&ids[1]).await.expect("get rust");
+    assert_eq!(rust.properties["paradigm"], "systems");
+    assert_eq!(rust.properties["memory_safe"], true);
+    let python = graph.get_entity(&ids[0]).await.expect("get python");
+    assert_eq!(python.properties["typing"], "dynamic");
+
+    let conn = backend.get_connection().await.expect("get connection");
+    let nodes: i64 = conn
+        .query_row(
+            "SELECT COUNT(DISTINCT entity_id) FROM entities",
+            [],
+            |row| row.get(0),
+        )
+        .expect("count entities");
+    assert_eq!(nodes, 2);
+    let versions: i64 = conn
+        .query_row(
+            "SELECT COUNT(*) FROM entities WHERE entity_id = ?1",
+            rusqlite::params![ids[1]],
+            |row| row.get(0),
+        )
+        .expect("count versions");
+    assert_eq!(versions, 2);
+}
+
+#[tokio::test]
+async fn test_upsert_entities_keeps_previous_version() {
+    let (_temp_dir, backend, graph) = create_test_graph().await;
+
+    let id = KnowledgeGraph::add_entity(
+        &graph,
+        Entity::new(
+            "Rust".to_string(),
+            "programming_language".to_string(),
+            json!({"paradigm": "systems"}),
+        ),
+    )
+    .await
+    .expect("add entity");
+
+    // Timestamps are stored with second precision
+    tokio::time::sleep(Duration::from_secs(2)).await;
+    let before_upsert = Utc::now();
+    tokio::time::sleep(Duration::from_secs(2)).await;
+
+    let ids = graph
+        .upsert_entities(vec![Entity::new(
+            "Rust".to_string(),
+            "programming_language".to_string(),
+            json!({"paradigm": "multi"}),
+        )])
+        .await
+        .expect("upsert entity");
+    assert_eq!(ids, vec![id.clone()]);
+
+    // The previous version is closed when the new one is recorded
+    let conn = backend.get_connection().await.expect("get connection");
+    let mut stmt = conn
+        .prepare(
+            "SELECT properties, transaction_time_start, transaction_time_end FROM entities
+             WHERE entity_id = ?1 ORDER BY transaction_time_start",
+        )
+        .expect("prepare versions query");
+    let versions: Vec<(String, i64, i64)> = stmt
+        .query_map(rusqlite::params![id], |row| {
+            Ok((row.get(0)?, row.get(1)?, row.get(2)?))
+        })
+        .expect("query versions")
+        .collect::<Result<_, _>>()
+        .expect("read versions");
+    assert_eq!(versions.len(), 2);
+    assert_eq!(versions[0].2, versions[1].1);
+    assert_eq!(versions[1].2, 9_999_999_999);
+
+    let current = graph.get_entity(&id).await.expect("get current");
+    assert_eq!(current.properties["paradigm"], "multi");
+
+    let then = graph
+        .traverse(&id, None, 0, Some(before_upsert))
+        .await
+        .expect("query before upsert");
+    assert_eq!(then.len(), 1);
+    assert_eq!(then[0].0.properties["paradigm"], "systems");
+}
@@ -0,0 +1,142 @@
+//! Integration tests for SQLite knowledge graph validation and repair
+//!
+//! Verifies:
+//! - validate reports relationships whose endpoint entity was deleted
+//! - repair is a dry run by default and removes dangling edges when applied
+//! - orphaned entities are only pruned on request
+
+use llmspell_core::traits::storage::KnowledgeGraph;
+use llmspell_core::types::storage::{Entity, Relationship};
+use llmspell_graph::storage::{GraphBackend, RepairOptions};
+use llmspell_storage::backends::sqlite::{SqliteBackend, SqliteConfig, SqliteGraphStorage};
+use serde_json::json;
+use std::sync::Arc;
+use tempfile::TempDir;
+
+/// Create test graph storage with temporary database
+async fn create_test_graph() -> (TempDir, Arc<SqliteBackend>, SqliteGraphStorage) {
+    let temp_dir = TempDir::new().expect("create temp dir");
+    let db_path = temp_dir.path().join("test_graph.db");
+
+    let config = SqliteConfig::new(db_path.to_str().unwrap()).with_max_connections(5);
+    let backend = Arc::new(SqliteBackend::new(config).await.expect("create backend"));
+
+    backend.run_migrations().await.expect("run migrations");
+
+    let graph = SqliteGraphStorage::new(Arc::clone(&backend));
+
+    (temp_dir, backend, graph)
+}
+
+#[tokio::test]
+async fn test_repair_removes_dangling_relationship() {
+    let (_temp_dir, backend, graph) = create_test_graph().await;
+
+    let rust = KnowledgeGraph::add_entity(
+        &graph,
+        Entity::new("Rust".to_string(), "language".to_string(), json!({})),
+    )
+    .await
+    .expect("add rust");
+    let cargo = KnowledgeGraph::add_entity(
+        &graph,
+        Entity::new("Cargo".to_string(), "tool".to_string(), json!({})),
+    )
+    .await
+    .expect("add cargo");
+    let tokio = KnowledgeGraph::add_entity(
+        &graph,
+        Entity::new("Tokio".to_string(), "library".to_string(), json!({})),
+    )
+    .await
+    .expect("add tokio");
+    let lonely = KnowledgeGraph::add_entity(
+        &graph,
+        Entity::new("Lonely".to_string(), "concept".to_string(), json!({})),
+    )
+    .await
+    .expect("add lonely");
+
+    KnowledgeGraph::add_relationship(
+        &graph,
+        Relationship::new(cargo.clone(), rust.clone(), "builds".to_string(), json!({})),
+    )
+    .await
+    .expect("add intact relationship");
+    let dangling = KnowledgeGraph::add_relationship(
+        &graph,
+        Relationship::new(
+            tokio.clone(),
+            rust.clone(),
+            "written_in".to_string(),
+            json!({}),
+        ),
+    )
+    .await
+    .expect("add relationship to be left dangling");
+
+    // Delete Tokio without closing its relationships
+    {
+        let conn = backend.get_connection().await.expect("get connection");
+        conn.execute(
+            "UPDATE entities SET transaction_time_end = transaction_time_start + 1
+             WHERE entity_id = ?1",
+            rusqlite::params![tokio],
+        )
+        .expect("close tokio");
+    }
+
+    let report = graph.validate().await.expect("validate");
+    assert_eq!(report.dangling_relationships, vec![dangling.clone()]);
+    assert_eq!(report.orphaned_entities, vec![lonely.clone()]);
+    assert!(report.temporal_inconsistencies.is_empty());
+    assert!(!report.is_clean());
+
+    // Dry run reports but does not remove
+    let preview = graph
+        .repair(RepairOptions::default())
+        .await
+        .expect("dry-run repair");
+    assert!(preview.dry_run);
+    assert_eq!(preview.removed_relationships, vec![dangling.clone()]);
+    assert!(preview.removed_entities.is_empty());
+    assert_eq!(
+        graph
+            .validate()
+            .await
+            .expect("validate after dry run")
+            .dangling_relationships,
+        vec![dangling.clone()]
+    );
+
+    let applied = graph
+        .repair(RepairOptions::default().apply())
+        .await
+        .expect("apply repair");
+    assert!(!applied.dry_run);
+    assert_eq!(applied.removed_relationships, vec![dangling]);
+
+    let after = graph.validate().await.expect("validate after repair");
+    assert!(after.is_clean());
+    assert_eq!(after.orphaned_entities, vec![lonely.clone()]);
+    assert_eq!(
+        GraphBackend::get_relationships(&graph, &rust)
+            .await
+            .expect("get rust relationships")
+            .len(),
+        1
+    );
+
+    let pruned = graph
+        .repair(RepairOptions::default().apply().with_prune_orphans(true))
+        .await
+        .expect("prune orphans");
+    assert_eq!(pruned.removed_entities, vec![lonely.clone()]);
+    assert!(GraphBackend::get_entity(&graph, &lonely).await.is_err());
+    assert!(graph
+        .validate()
+        .await
+        .expect("final validate")
+        .orphaned_entities
+        .is_empty());
+}
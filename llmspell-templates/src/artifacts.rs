@@ -4,6 +4,12 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::path::PathBuf;
 
+/// Artifact metadata key naming the execution phase that produced it
+pub const PHASE_METADATA_KEY: &str = "phase";
+
+/// Artifact metadata key listing the errors of artifact processors that failed on it
+pub const PROCESSING_ERRORS_METADATA_KEY: &str = "processing_errors";
+
 /// Template execution artifact
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Artifact {
@@ -62,6 +68,18 @@ impl Artifact {
         self
     }
 
+    /// Tag the artifact with the execution phase that produced it
+    pub fn with_phase(self, phase: impl Into<String>) -> Self {
+        self.with_metadata(PHASE_METADATA_KEY, serde_json::Value::String(phase.into()))
+    }
+
+    /// Execution phase that produced this artifact, if tagged
+    pub fn phase(&self) -> Option<&str> {
+        self.metadata
+            .get(PHASE_METADATA_KEY)
+            .and_then(serde_json::Value::as_str)
+    }
+
     /// Write artifact to file
     pub fn write_to_file(&self, base_path: &std::path::Path) -> std::io::Result<PathBuf> {
         let file_path = base_path.join(&self.filename);
@@ -111,6 +129,11 @@ impl ArtifactCollection {
         self.artifacts.iter().find(|a| a.filename == filename)
     }
 
+    /// Get the artifact produced by an execution phase
+    pub fn get_phase(&self, phase: &str) -> Option<&Artifact> {
+        self.artifacts.iter().find(|a| a.phase() == Some(phase))
+    }
+
     /// Get all artifacts
     pub fn all(&self) -> &[Artifact] {
         &self.artifacts
@@ -136,6 +159,20 @@ impl ArtifactCollection {
         Ok(paths)
     }
 
+    /// Save the collection, including artifact metadata, as a JSON file
+    pub fn save(&self, path: &std::path::Path) -> std::io::Result<()> {
+        let json = serde_json::to_string_pretty(self)?;
+        std::fs::write(path, json)
+    }
+
+    /// Load a collection saved with [`ArtifactCollection::save`]
+    ///
+    /// A serialized `TemplateOutput` also loads, yielding its artifacts.
+    pub fn load(path: &std::path::Path) -> std::io::Result<Self> {
+        let json = std::fs::read_to_string(path)?;
+        Ok(serde_json::from_str(&json)?)
+    }
+
     /// Get total size of all artifacts
     pub fn total_size(&self) -> usize {
         self.artifacts.iter().map(|a| a.size()).sum()
@@ -4,6 +4,9 @@
 //! 1. Specification agent: design from description
 //! 2. Implementation agent: code from spec
 //! 3. Test agent: tests for code
+//!
+//! Generated tests are then run against the implementation, with one refinement
+//! of the implementation if they fail (requires `allow_process_spawn`).
 
 use crate::{
     artifacts::Artifact,
@@ -17,19 +20,27 @@ use crate::{
 };
 use async_trait::async_trait;
 use serde_json::json;
+use std::sync::Arc;
 use std::time::Instant;
 use tracing::{debug, info, warn};
 
+mod verification;
+
+use verification::{format_test_results, verify_with_refinement, Verification};
+pub use verification::{SubprocessTestRunner, TestOutcome, TestRun, TestRunner};
+
 /// Code Generator Template
 ///
 /// Automated code generation with specification, implementation, and testing:
 /// - Generates design specification from description
 /// - Implements code following the specification
 /// - Creates comprehensive tests for the code
+/// - Runs the tests and refines failing code once
 /// - Supports multiple programming languages
 #[derive(Debug)]
 pub struct CodeGeneratorTemplate {
     metadata: TemplateMetadata,
+    test_runner: Arc<dyn TestRunner>,
 }
 
 impl CodeGeneratorTemplate {
@@ -55,8 +66,15 @@ impl CodeGeneratorTemplate {
                     "automation".to_string(),
                 ],
             },
+            test_runner: Arc::new(SubprocessTestRunner::new()),
         }
     }
+
+    /// Run generated tests with `runner` instead of the language toolchain
+    pub fn with_test_runner(mut self, runner: Arc<dyn TestRunner>) -> Self {
+        self.test_runner = runner;
+        self
+    }
 }
 
 impl Default for CodeGeneratorTemplate {
@@ -204,8 +222,31 @@ impl crate::core::Template for CodeGeneratorTemplate {
             None
         };
 
-        // Phase 4: Run linter/formatter (optional)
-        info!("Phase 4: Running code quality checks...");
+        // Phase 4: Run the generated tests, refining the implementation once on failure
+        let (impl_result, verification) = match &test_result {
+            Some(tests) => {
+                let verification = self
+                    .verify_implementation(
+                        impl_result.code,
+                        tests,
+                        &language,
+                        &provider_config,
+                        &context,
+                    )
+                    .await?;
+                output.metrics.agents_invoked += verification.refinements; // refinement agent
+                (
+                    ImplementationResult {
+                        code: verification.code.clone(),
+                    },
+                    Some(verification),
+                )
+            }
+            None => (impl_result, None),
+        };
+
+        // Phase 5: Run linter/formatter (optional)
+        info!("Phase 5: Running code quality checks...");
         let lint_result = self
             .run_quality_checks(&impl_result, &language, &context)
             .await?;
@@ -218,8 +259,12 @@ impl crate::core::Template for CodeGeneratorTemplate {
             &spec_result,
             &impl_result,
             &test_result,
+            verification.as_ref(),
             &lint_result,
         );
+        let test_results = verification
+            .as_ref()
+            .map(|v| format_test_results(&language, v));
 
         // Save artifacts
         if let Some(output_dir) = &context.output_dir {
@@ -229,8 +274,11 @@ impl crate::core::Template for CodeGeneratorTemplate {
                 &spec_result.content,
                 &impl_result.code,
                 test_result.as_ref().map(|t| t.code.as_str()),
+                test_results.as_deref(),
                 &mut output,
             )?;
+        } else if let Some(results) = test_results {
+            output.add_artifact(Artifact::markdown("test_results.md", results));
         }
 
         // Set result and metrics
@@ -243,6 +291,10 @@ impl crate::core::Template for CodeGeneratorTemplate {
         if let Some(test) = &test_result {
             output.add_metric("test_lines", json!(test.code.lines().count()));
         }
+        if let Some(verification) = &verification {
+            output.add_metric("test_outcome", json!(verification.run.outcome.to_string()));
+            output.add_metric("refinement_attempts", json!(verification.refinements));
+        }
 
         info!(
             "Code generation complete (duration: {}ms, agents: {})",
@@ -677,7 +729,135 @@ impl CodeGeneratorTemplate {
         Ok(TestResult { code })
     }
 
-    /// Phase 4: Run code quality checks (linting/formatting)
+    /// Phase 4: Run the generated tests against `code`, refining it once if they fail
+    ///
+    /// Nothing is run unless the context allows process spawning.
+    async fn verify_implementation(
+        &self,
+        code: String,
+        tests: &TestResult,
+        language: &str,
+        provider_config: &llmspell_config::ProviderConfig,
+        context: &ExecutionContext,
+    ) -> Result<Verification> {
+        if !context.allow_process_spawn {
+            info!("Phase 4: Skipping test execution (allow_process_spawn=false)");
+            return Ok(Verification {
+                code,
+                run: TestRun::unavailable(
+                    "Process spawning is disabled (allow_process_spawn = false)",
+                ),
+                refinements: 0,
+            });
+        }
+
+        info!("Phase 4: Running generated tests...");
+        verify_with_refinement(
+            self.test_runner.as_ref(),
+            language,
+            code,
+            &tests.code,
+            |code, failure| async move {
+                self.refine_implementation(
+                    &code,
+                    &tests.code,
+                    &failure,
+                    language,
+                    provider_config,
+                    context,
+                )
+                .await
+                .map(|refined| refined.code)
+            },
+        )
+        .await
+    }
+
+    /// Phase 4: Revise the implementation using the output of its failing tests
+    async fn refine_implementation(
+        &self,
+        code: &str,
+        tests: &str,
+        failure_output: &str,
+        language: &str,
+        provider_config: &llmspell_config::ProviderConfig,
+        context: &ExecutionContext,
+    ) -> Result<ImplementationResult> {
+        use llmspell_agents::factory::{AgentConfig, ModelConfig, ResourceLimits};
+        use llmspell_core::types::AgentInput;
+
+        info!("Creating refinement agent for {} code", language);
+
+        let model = provider_config
+            .default_model
+            .as_ref()
+            .ok_or_else(|| TemplateError::Config("provider missing model".into()))?;
+
+        let (provider, model_id) = if let Some(slash_pos) = model.find('/') {
+            (
+                model[..slash_pos].to_string(),
+                model[slash_pos + 1..].to_string(),
+            )
+        } else {
+            (provider_config.provider_type.clone(), model.to_string())
+        };
+
+        let agent_config = AgentConfig {
+            name: "code-refine-agent".to_string(),
+            description: format!("Refinement agent for failing {} code", language),
+            agent_type: "llm".to_string(),
+            model: Some(ModelConfig {
+                provider,
+                model_id,
+                temperature: provider_config.temperature.or(Some(0.3)), // Focused fixes, not rewrites
+                max_tokens: provider_config.max_tokens.or(Some(3000)),
+                settings: serde_json::Map::new(),
+            }),
+            allowed_tools: vec![],
+            custom_config: serde_json::Map::new(),
+            resource_limits: ResourceLimits {
+                max_execution_time_secs: 180,
+                max_memory_mb: 512,
+                max_tool_calls: 0,
+                max_recursion_depth: 1,
+            },
+        };
+
+        let agent = context
+            .agent_registry()
+            .create_agent(agent_config)
+            .await
+            .map_err(|e| {
+                warn!("Failed to create refinement agent: {}", e);
+                TemplateError::ExecutionFailed(format!("Agent creation failed: {}", e))
+            })?;
+
+        let refine_prompt = format!(
+            "You are an expert {} programmer. The following implementation fails its tests.\n\n\
+             **IMPLEMENTATION**:\n{}\n\n\
+             **TESTS**:\n{}\n\n\
+             **TEST OUTPUT**:\n{}\n\n\
+             Fix the implementation so that all tests pass. Do not change the tests.\n\n\
+             Provide ONLY the corrected implementation code (no tests, no explanations):",
+            language, code, tests, failure_output
+        );
+
+        info!("Executing refinement agent...");
+        let agent_input = AgentInput::builder().text(refine_prompt).build();
+        let agent_output = agent
+            .execute(agent_input, llmspell_core::ExecutionContext::default())
+            .await
+            .map_err(|e| {
+                warn!("Refinement agent execution failed: {}", e);
+                TemplateError::ExecutionFailed(format!("Agent execution failed: {}", e))
+            })?;
+
+        Ok(ImplementationResult {
+            code: agent_output.text,
+        })
+    }
+
+    /// Phase 5: Run code quality checks (linting/formatting)
     async fn run_quality_checks(
         &self,
         implementation: &ImplementationResult,
@@ -771,6 +951,7 @@ impl CodeGeneratorTemplate {
         spec: &SpecificationResult,
         implementation: &ImplementationResult,
         tests: &Option<TestResult>,
+        verification: Option<&Verification>,
         lint: &LintResult,
     ) -> String {
         let mut report = format!(
@@ -800,6 +981,16 @@ impl CodeGeneratorTemplate {
             ));
         }
 
+        if let Some(verification) = verification {
+            report.push_str(&format!(
+                "## Test Results\n\n\
+                 **Outcome**: {}\n\
+                 **Refinement attempts**: {}\n\n\
+                 ---\n\n",
+                verification.run.outcome, verification.refinements
+            ));
+        }
+
         report.push_str(&format!(
             "## Quality Report\n\n\
              {}\n\n\
@@ -819,6 +1010,7 @@ impl CodeGeneratorTemplate {
         spec: &str,
         code: &str,
         tests: Option<&str>,
+        test_results: Option<&str>,
         output: &mut TemplateOutput,
     ) -> Result<()> {
         use std::fs;
@@ -874,6 +1066,19 @@ impl CodeGeneratorTemplate {
             ));
         }
 
+        // Save test results if the tests were verified
+        if let Some(results) = test_results {
+            let results_path = output_dir.join("test_results.md");
+            fs::write(&results_path, results).map_err(|e| {
+                TemplateError::ExecutionFailed(format!("Failed to write test results: {}", e))
+            })?;
+            output.add_artifact(Artifact::new(
+                results_path.to_string_lossy().to_string(),
+                results.to_string(),
+                "text/markdown".to_string(),
+            ));
+        }
+
         Ok(())
     }
 }
@@ -1117,6 +1322,7 @@ mod tests {
             &spec,
             &implementation,
             &tests,
+            None,
             &lint,
         );
 
@@ -1147,10 +1353,79 @@ mod tests {
             &spec,
             &implementation,
             &None,
+            None,
             &lint,
         );
 
         assert!(report.contains("# Code Generation Report"));
         assert!(!report.contains("## Tests"));
     }
+
+    /// Runner that passes every run and counts them
+    #[derive(Debug, Default)]
+    struct CountingRunner {
+        runs: std::sync::atomic::AtomicUsize,
+    }
+
+    #[async_trait]
+    impl TestRunner for CountingRunner {
+        async fn run(&self, _language: &str, _code: &str, _tests: &str) -> Result<TestRun> {
+            self.runs.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            Ok(TestRun {
+                outcome: TestOutcome::Passed,
+                output: String::new(),
+            })
+        }
+    }
+
+    fn verification_context(allow_process_spawn: bool) -> ExecutionContext {
+        ExecutionContext::builder()
+            .with_tool_registry(Arc::new(llmspell_tools::ToolRegistry::new()))
+            .with_agent_registry(Arc::new(llmspell_agents::FactoryRegistry::new()))
+            .with_workflow_factory(Arc::new(llmspell_workflows::DefaultWorkflowFactory::new()))
+            .with_providers(Arc::new(llmspell_providers::ProviderManager::new()))
+            .with_provider_config(Arc::new(
+                llmspell_config::providers::ProviderManagerConfig::default(),
+            ))
+            .with_process_spawn(allow_process_spawn)
+            .build()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_verification_uses_configured_runner_only_when_spawn_allowed() {
+        let runner = Arc::new(CountingRunner::default());
+        let template = CodeGeneratorTemplate::new().with_test_runner(runner.clone());
+        let tests = TestResult {
+            code: "#[test] fn t() {}".to_string(),
+        };
+
+        let denied = template
+            .verify_implementation(
+                "fn main() {}".to_string(),
+                &tests,
+                "rust",
+                &test_provider_config(),
+                &verification_context(false),
+            )
+            .await
+            .unwrap();
+        assert_eq!(denied.run.outcome, TestOutcome::Unavailable);
+        assert_eq!(runner.runs.load(std::sync::atomic::Ordering::SeqCst), 0);
+
+        let allowed = template
+            .verify_implementation(
+                "fn main() {}".to_string(),
+                &tests,
+                "rust",
+                &test_provider_config(),
+                &verification_context(true),
+            )
+            .await
+            .unwrap();
+        assert_eq!(allowed.run.outcome, TestOutcome::Passed);
+        assert_eq!(allowed.refinements, 0);
+        assert_eq!(allowed.code, "fn main() {}");
+        assert_eq!(runner.runs.load(std::sync::atomic::Ordering::SeqCst), 1);
+    }
 }
@@ -0,0 +1,425 @@
+//! Test-execution verification for generated code
+//!
+//! Runs the generated tests against the generated implementation through a
+//! restricted `ProcessExecutorTool` and, when they fail, feeds the failure output
+//! back for a refinement.
+
+use crate::error::{Result, TemplateError};
+use async_trait::async_trait;
+use llmspell_core::traits::tool::{ResourceLimits, SecurityRequirements};
+use llmspell_core::types::AgentInput;
+use llmspell_core::{BaseAgent, LLMSpellError};
+use llmspell_security::sandbox::{FileSandbox, SandboxContext};
+use llmspell_tools::system::process_executor::{ProcessExecutorConfig, ProcessExecutorTool};
+use serde_json::{json, Value};
+use std::future::Future;
+use std::path::Path;
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::{debug, info, warn};
+
+/// Number of refinement attempts made after a failing test run
+pub const MAX_REFINEMENTS: usize = 1;
+
+/// Default time limit for one compile-and-test run
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Maximum bytes of test output kept for the report and refinement prompt
+const MAX_OUTPUT_BYTES: usize = 8 * 1024;
+
+/// Outcome of one test run
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TestOutcome {
+    /// All generated tests passed
+    Passed,
+    /// Compilation or at least one test failed
+    Failed,
+    /// Tests could not be run (unsupported language or missing toolchain)
+    Unavailable,
+}
+
+impl std::fmt::Display for TestOutcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Passed => write!(f, "passed"),
+            Self::Failed => write!(f, "failed"),
+            Self::Unavailable => write!(f, "unavailable"),
+        }
+    }
+}
+
+/// Captured result of one test run
+#[derive(Debug, Clone)]
+pub struct TestRun {
+    /// Pass/fail outcome
+    pub outcome: TestOutcome,
+    /// Combined stdout and stderr, truncated
+    pub output: String,
+}
+
+impl TestRun {
+    /// A run that could not take place, with the reason as output
+    pub fn unavailable(reason: impl Into<String>) -> Self {
+        Self {
+            outcome: TestOutcome::Unavailable,
+            output: reason.into(),
+        }
+    }
+}
+
+/// Executes generated tests against a generated implementation
+#[async_trait]
+pub trait TestRunner: Send + Sync + std::fmt::Debug {
+    /// Run `tests` against `code` written in `language`
+    async fn run(&self, language: &str, code: &str, tests: &str) -> Result<TestRun>;
+}
+
+/// Runs tests with the language toolchain in a throwaway directory
+///
+/// Each command goes through a [`ProcessExecutorTool`] whose allowlist holds only
+/// that command's executable and whose file sandbox holds only the temporary
+/// directory. Processes get `PATH` and `HOME` (the temporary directory) as their
+/// environment, no stdin, and are killed at the time limit.
+#[derive(Debug, Clone)]
+pub struct SubprocessTestRunner {
+    timeout: Duration,
+}
+
+impl SubprocessTestRunner {
+    /// Create a runner with the default time limit
+    pub fn new() -> Self {
+        Self {
+            timeout: DEFAULT_TIMEOUT,
+        }
+    }
+
+    /// Set the time limit for each compile or test command (whole seconds, at least one)
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Source file name and the commands that build and run it, in order
+    fn commands(language: &str) -> Option<(&'static str, Vec<Vec<&'static str>>)> {
+        match language {
+            "rust" => Some((
+                "main.rs",
+                vec![
+                    vec![
+                        "rustc",
+                        "--edition",
+                        "2021",
+                        "--test",
+                        "main.rs",
+                        "-o",
+                        "main_tests",
+                    ],
+                    vec!["./main_tests"],
+                ],
+            )),
+            "python" => Some((
+                "test_main.py",
+                vec![vec!["python3", "-m", "pytest", "-q", "test_main.py"]],
+            )),
+            "javascript" => Some(("main.js", vec![vec!["node", "main.js"]])),
+            "lua" => Some(("main.lua", vec![vec!["lua", "main.lua"]])),
+            _ => None,
+        }
+    }
+
+    /// Process executor that may only run `program`, with `dir` as working directory
+    fn executor(&self, dir: &Path, program: &str) -> Result<ProcessExecutorTool> {
+        let dir_str = dir.to_string_lossy().into_owned();
+        let sandbox = FileSandbox::new(SandboxContext::new(
+            format!("code-generator-tests-{}", uuid::Uuid::new_v4()),
+            SecurityRequirements::default().with_file_access(dir_str),
+            ResourceLimits::default(),
+        ))
+        .map_err(|e| {
+            TemplateError::ExecutionFailed(format!("Failed to create test sandbox: {}", e))
+        })?;
+
+        let config = ProcessExecutorConfig {
+            max_execution_time_seconds: self.timeout.as_secs().max(1),
+            max_output_size: MAX_OUTPUT_BYTES,
+            allowed_executables: vec![program.trim_start_matches("./").to_string()],
+            default_working_directory: Some(dir.to_path_buf()),
+            allowed_env_vars: vec!["PATH".to_string(), "HOME".to_string()],
+            ..ProcessExecutorConfig::default()
+        };
+        Ok(ProcessExecutorTool::new(config, Arc::new(sandbox)))
+    }
+
+    async fn run_command(&self, dir: &Path, argv: &[&str]) -> Result<Option<TestRun>> {
+        debug!("Running generated tests: {}", argv.join(" "));
+        let executable = match argv[0].strip_prefix("./") {
+            Some(local) => dir.join(local).to_string_lossy().into_owned(),
+            None => argv[0].to_string(),
+        };
+        let mut environment = serde_json::Map::new();
+        environment.insert("HOME".to_string(), json!(dir.to_string_lossy()));
+        if let Some(path) = std::env::var_os("PATH") {
+            environment.insert("PATH".to_string(), json!(path.to_string_lossy()));
+        }
+        let input = AgentInput::builder()
+            .text("run generated tests")
+            .parameter(
+                "parameters",
+                json!({
+                    "executable": executable,
+                    "arguments": &argv[1..],
+                    "environment": environment,
+                }),
+            )
+            .build();
+
+        let executor = self.executor(dir, argv[0])?;
+        let output = match executor
+            .execute(input, llmspell_core::ExecutionContext::default())
+            .await
+        {
+            Ok(output) => output,
+            // Missing toolchain executables are reported as validation errors
+            Err(LLMSpellError::Validation { message, .. }) => {
+                return Ok(Some(TestRun::unavailable(message)))
+            }
+            Err(e) => {
+                return Err(TemplateError::ExecutionFailed(format!(
+                    "Failed to run `{}`: {}",
+                    argv[0], e
+                )))
+            }
+        };
+
+        let response: Value = serde_json::from_str(&output.text).map_err(|e| {
+            TemplateError::ExecutionFailed(format!("Invalid process executor response: {}", e))
+        })?;
+        let result = &response["result"];
+        if result["success"].as_bool() == Some(true) {
+            return Ok(None);
+        }
+        if result["timed_out"].as_bool() == Some(true) {
+            return Ok(Some(TestRun {
+                outcome: TestOutcome::Failed,
+                output: format!("Timed out after {}s", self.timeout.as_secs().max(1)),
+            }));
+        }
+        let mut text = result["stdout"].as_str().unwrap_or_default().to_string();
+        text.push_str(result["stderr"].as_str().unwrap_or_default());
+        Ok(Some(TestRun {
+            outcome: TestOutcome::Failed,
+            output: truncate_output(text),
+        }))
+    }
+}
+
+impl Default for SubprocessTestRunner {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl TestRunner for SubprocessTestRunner {
+    async fn run(&self, language: &str, code: &str, tests: &str) -> Result<TestRun> {
+        let Some((file_name, commands)) = Self::commands(language) else {
+            return Ok(TestRun::unavailable(format!(
+                "Running {} tests is not supported",
+                language
+            )));
+        };
+
+        let dir = std::env::temp_dir().join(format!("llmspell-codegen-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).map_err(|e| {
+            TemplateError::ExecutionFailed(format!("Failed to create test directory: {}", e))
+        })?;
+
+        let source = format!(
+            "{}\n\n{}\n",
+            strip_code_fences(code),
+            strip_code_fences(tests)
+        );
+        let result = match std::fs::write(dir.join(file_name), source) {
+            Ok(()) => {
+                let mut result = Ok(None);
+                for argv in &commands {
+                    result = self.run_command(&dir, argv).await;
+                    if !matches!(result, Ok(None)) {
+                        break;
+                    }
+                }
+                result
+            }
+            Err(e) => Err(TemplateError::ExecutionFailed(format!(
+                "Failed to write test source: {}",
+                e
+            ))),
+        };
+
+        if let Err(e) = std::fs::remove_dir_all(&dir) {
+            warn!("Failed to remove test directory {}: {}", dir.display(), e);
+        }
+
+        Ok(result?.unwrap_or(TestRun {
+            outcome: TestOutcome::Passed,
+            output: String::new(),
+        }))
+    }
+}
+
+/// Final verification state after running tests and any refinement
+#[derive(Debug, Clone)]
+pub struct Verification {
+    /// Implementation the final run was made against
+    pub code: String,
+    /// Final test run
+    pub run: TestRun,
+    /// Number of refinement attempts made
+    pub refinements: usize,
+}
+
+/// Run `tests` against `code`, refining once with the failure output if they fail
+///
+/// `refine` receives the failing code and test output and returns a revised implementation.
+pub async fn verify_with_refinement<F, Fut>(
+    runner: &dyn TestRunner,
+    language: &str,
+    code: String,
+    tests: &str,
+    mut refine: F,
+) -> Result<Verification>
+where
+    F: FnMut(String, String) -> Fut,
+    Fut: Future<Output = Result<String>>,
+{
+    let mut code = code;
+    let mut run = runner.run(language, &code, tests).await?;
+    let mut refinements = 0;
+
+    while run.outcome == TestOutcome::Failed && refinements < MAX_REFINEMENTS {
+        refinements += 1;
+        info!(
+            "Generated tests failed, refining implementation (attempt {})",
+            refinements
+        );
+        code = refine(code, run.output.clone()).await?;
+        run = runner.run(language, &code, tests).await?;
+    }
+
+    Ok(Verification {
+        code,
+        run,
+        refinements,
+    })
+}
+
+/// Format the test-results artifact
+pub fn format_test_results(language: &str, verification: &Verification) -> String {
+    let mut report = format!(
+        "# Test Results\n\n\
+         **Language**: {}\n\
+         **Outcome**: {}\n\
+         **Refinement attempts**: {}\n",
+        language, verification.run.outcome, verification.refinements
+    );
+    if !verification.run.output.is_empty() {
+        report.push_str(&format!(
+            "\n## Output\n\n```\n{}\n```\n",
+            verification.run.output.trim_end()
+        ));
+    }
+    report
+}
+
+/// Remove a surrounding markdown code fence, if present
+fn strip_code_fences(code: &str) -> &str {
+    let trimmed = code.trim();
+    let Some(rest) = trimmed.strip_prefix("```") else {
+        return code;
+    };
+    let body = rest.split_once('\n').map_or("", |(_, body)| body);
+    body.trim_end().strip_suffix("```").unwrap_or(body)
+}
+
+fn truncate_output(mut text: String) -> String {
+    if text.len() > MAX_OUTPUT_BYTES {
+        let mut end = MAX_OUTPUT_BYTES;
+        while !text.is_char_boundary(end) {
+            end -= 1;
+        }
+        text.truncate(end);
+        text.push_str("\n... (output truncated)");
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// Runner that fails any implementation containing `BROKEN`
+    #[derive(Debug, Default)]
+    struct MockRunner {
+        runs: AtomicUsize,
+    }
+
+    #[async_trait]
+    impl TestRunner for MockRunner {
+        async fn run(&self, _language: &str, code: &str, _tests: &str) -> Result<TestRun> {
+            self.runs.fetch_add(1, Ordering::SeqCst);
+            let outcome = if code.contains("BROKEN") {
+                TestOutcome::Failed
+            } else {
+                TestOutcome::Passed
+            };
+            Ok(TestRun {
+                outcome,
+                output: format!("test result: {}", outcome),
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_failing_code_is_refined_once_then_passes() {
+        let runner = MockRunner::default();
+        let refine_calls = AtomicUsize::new(0);
+
+        let verification = verify_with_refinement(
+            &runner,
+            "rust",
+            "fn add() { BROKEN }".to_string(),
+            "#[test] fn t() {}",
+            |code, failure| {
+                refine_calls.fetch_add(1, Ordering::SeqCst);
+                assert!(code.contains("BROKEN"));
+                assert!(failure.contains("failed"));
+                async { Ok("fn add() {}".to_string()) }
+            },
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(refine_calls.load(Ordering::SeqCst), 1);
+        assert_eq!(runner.runs.load(Ordering::SeqCst), 2);
+        assert_eq!(verification.refinements, 1);
+        assert_eq!(verification.run.outcome, TestOutcome::Passed);
+        assert_eq!(verification.code, "fn add() {}");
+        assert!(format_test_results("rust", &verification).contains("**Outcome**: passed"));
+    }
+
+    #[tokio::test]
+    async fn test_unsupported_language_is_unavailable() {
+        let run = SubprocessTestRunner::new()
+            .run("cobol", "code", "tests")
+            .await
+            .unwrap();
+        assert_eq!(run.outcome, TestOutcome::Unavailable);
+    }
+
+    #[test]
+    fn test_strip_code_fences() {
+        assert_eq!(strip_code_fences("```rust\nfn a() {}\n```"), "fn a() {}");
+        assert_eq!(strip_code_fences("fn a() {}"), "fn a() {}");
+    }
+}
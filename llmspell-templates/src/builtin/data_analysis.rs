@@ -2,7 +2,7 @@
 //!
 //! Sequential agent workflow for data analysis and visualization:
 //! 1. Load data from file
-//! 2. Analyzer agent: statistical analysis
+//! 2. Statistical tests chosen from column types, interpreted by the analyzer agent
 //! 3. Visualizer agent: chart generation
 //! 4. Save report + chart artifacts
 
@@ -21,6 +21,10 @@ use serde_json::json;
 use std::time::Instant;
 use tracing::{debug, info, warn};
 
+mod stats;
+
+pub use stats::{Column, ColumnKind, StatTest, TestResult};
+
 /// Data Analysis Template
 ///
 /// Statistical analysis and visualization workflow:
@@ -327,6 +331,11 @@ impl DataAnalysisTemplate {
         })?;
 
         // Parse based on format
+        let table = match extension {
+            "csv" => stats::parse_table(&contents, ','),
+            "tsv" => stats::parse_table(&contents, '\t'),
+            _ => Vec::new(),
+        };
         let (rows, columns, preview) = match extension {
             "csv" | "tsv" => self.parse_csv_data(&contents, extension)?,
             "json" => self.parse_json_data(&contents)?,
@@ -357,6 +366,7 @@ impl DataAnalysisTemplate {
             columns,
             format: extension.to_string(),
             preview,
+            table,
         })
     }
 
@@ -459,9 +469,12 @@ impl DataAnalysisTemplate {
         use llmspell_agents::factory::{AgentConfig, ModelConfig, ResourceLimits};
         use llmspell_core::types::AgentInput;
 
+        // Run the tests suited to each column pair before asking the agent to interpret them
+        let tests = stats::run_recommended_tests(&dataset.table);
         info!(
-            "Creating statistical analysis agent (type: {})",
-            analysis_type
+            "Creating statistical analysis agent (type: {}, tests: {})",
+            analysis_type,
+            tests.len()
         );
 
         // Extract model from provider config
@@ -560,6 +573,7 @@ impl DataAnalysisTemplate {
              - Columns: {}\n\
              - Format: {}\n\n\
              **DATA PREVIEW**:\n{}\n\n\
+             {}\
              **ANALYSIS TYPE**: {}\n\n\
              **INSTRUCTIONS**:\n{}\n\n\
              **REQUIREMENTS**:\n\
@@ -575,6 +589,7 @@ impl DataAnalysisTemplate {
             dataset.columns,
             dataset.format,
             dataset.preview,
+            format_test_section(&dataset.table, &tests),
             analysis_type,
             analysis_instructions
         );
@@ -638,6 +653,7 @@ impl DataAnalysisTemplate {
                 ("rows".to_string(), dataset.rows as f64),
                 ("columns".to_string(), dataset.columns as f64),
             ],
+            tests,
         })
     }
 
@@ -867,6 +883,7 @@ impl DataAnalysisTemplate {
              ---\n\n\
              ## Statistical Analysis\n\n\
              {}\n\n\
+             {}\
              ---\n\n\
              ## Visualization\n\n\
              {}\n\n\
@@ -877,6 +894,7 @@ impl DataAnalysisTemplate {
             analysis_type,
             chart_type,
             analysis.text,
+            format_test_results(&analysis.tests),
             chart.description,
             chart.chart_data
         )
@@ -923,6 +941,56 @@ impl DataAnalysisTemplate {
     }
 }
 
+/// Prompt section describing column types and computed test results
+fn format_test_section(table: &[Column], tests: &[TestResult]) -> String {
+    if table.is_empty() {
+        return String::new();
+    }
+    let columns = table
+        .iter()
+        .map(|c| format!("- {} ({})", c.name, c.kind))
+        .collect::<Vec<_>>()
+        .join("\n");
+    let results = if tests.is_empty() {
+        "No statistical test applies to these column types.".to_string()
+    } else {
+        tests
+            .iter()
+            .map(|t| format!("- {}", t))
+            .collect::<Vec<_>>()
+            .join("\n")
+    };
+    format!(
+        "**COLUMN TYPES**:\n{}\n\n\
+         **STATISTICAL TESTS** (computed; interpret these, do not recompute):\n{}\n\n",
+        columns, results
+    )
+}
+
+/// Report section listing the statistical tests that were run
+fn format_test_results(tests: &[TestResult]) -> String {
+    if tests.is_empty() {
+        return String::new();
+    }
+    let rows = tests
+        .iter()
+        .map(|t| {
+            format!(
+                "| {} | {} × {} | {:.4} | {:.4} | {} |",
+                t.test, t.columns.0, t.columns.1, t.statistic, t.p_value, t.interpretation
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    format!(
+        "### Statistical Tests\n\n\
+         | Test | Columns | Statistic | p-value | Interpretation |\n\
+         |------|---------|-----------|---------|----------------|\n\
+         {}\n\n",
+        rows
+    )
+}
+
 /// Dataset loaded from file
 #[derive(Debug, Clone)]
 struct DataSet {
@@ -936,6 +1004,8 @@ struct DataSet {
     /// Placeholder for future data loading
     #[allow(dead_code)]
     preview: String,
+    /// Typed columns (CSV/TSV only)
+    table: Vec<Column>,
 }
 
 /// Analysis result from analyzer agent
@@ -947,6 +1017,8 @@ struct AnalysisResult {
     /// Placeholder for future agent integration
     #[allow(dead_code)]
     metrics: Vec<(String, f64)>,
+    /// Statistical tests run on the dataset
+    tests: Vec<TestResult>,
 }
 
 /// Chart result from visualizer agent
@@ -1103,6 +1175,7 @@ mod tests {
             columns: 5,
             format: "csv".to_string(),
             preview: "".to_string(),
+            table: vec![],
         };
 
         let result = template
@@ -1136,11 +1209,13 @@ mod tests {
             columns: 5,
             format: "csv".to_string(),
             preview: "".to_string(),
+            table: vec![],
         };
 
         let analysis = AnalysisResult {
             text: "Test analysis".to_string(),
             metrics: vec![],
+            tests: vec![],
         };
 
         let result = template
@@ -1167,6 +1242,7 @@ mod tests {
         let analysis = AnalysisResult {
             text: "Test analysis text".to_string(),
             metrics: vec![],
+            tests: vec![],
         };
         let chart = ChartResult {
             chart_type: "bar".to_string(),
@@ -0,0 +1,662 @@
+//! Column type inference and statistical test selection for tabular data
+//!
+//! Each column pair gets the test its types call for:
+//! - numeric × numeric → Pearson correlation
+//! - numeric × categorical (two groups) → Welch's t-test
+//! - categorical × categorical → chi-square test of independence
+
+use crate::error::{Result, TemplateError};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+use tracing::debug;
+
+/// Significance level used when interpreting p-values
+pub const SIGNIFICANCE_LEVEL: f64 = 0.05;
+
+/// Categorical columns with more distinct values than this are not tested
+const MAX_CATEGORIES: usize = 20;
+
+/// Inferred type of a data column
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ColumnKind {
+    /// Every non-empty value parses as a number
+    Numeric,
+    /// Any other column
+    Categorical,
+}
+
+impl fmt::Display for ColumnKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ColumnKind::Numeric => write!(f, "numeric"),
+            ColumnKind::Categorical => write!(f, "categorical"),
+        }
+    }
+}
+
+/// A parsed data column with its inferred type
+#[derive(Debug, Clone)]
+pub struct Column {
+    /// Column header
+    pub name: String,
+    /// Inferred type
+    pub kind: ColumnKind,
+    /// Raw cell values; empty cells are missing
+    pub values: Vec<String>,
+}
+
+impl Column {
+    /// Build a column, inferring its type from the values
+    pub fn new(name: impl Into<String>, values: Vec<String>) -> Self {
+        let mut present = values.iter().filter(|v| !v.is_empty()).peekable();
+        let kind = if present.peek().is_some() && present.all(|v| v.parse::<f64>().is_ok()) {
+            ColumnKind::Numeric
+        } else {
+            ColumnKind::Categorical
+        };
+        Self {
+            name: name.into(),
+            kind,
+            values,
+        }
+    }
+
+    fn number(&self, row: usize) -> Option<f64> {
+        self.values.get(row).and_then(|v| v.parse().ok())
+    }
+
+    fn category(&self, row: usize) -> Option<&str> {
+        self.values
+            .get(row)
+            .map(String::as_str)
+            .filter(|v| !v.is_empty())
+    }
+
+    fn levels(&self) -> Vec<&str> {
+        let mut levels: Vec<&str> = (0..self.values.len())
+            .filter_map(|row| self.category(row))
+            .collect();
+        levels.sort_unstable();
+        levels.dedup();
+        levels
+    }
+
+    /// Whether the column has a usable number of categories for grouping
+    fn is_groupable(&self) -> bool {
+        let levels = self.levels().len();
+        (2..=MAX_CATEGORIES).contains(&levels) && levels < self.values.len()
+    }
+}
+
+/// Parse delimited text (header line first) into typed columns
+pub fn parse_table(contents: &str, delimiter: char) -> Vec<Column> {
+    let mut lines = contents.lines().filter(|l| !l.trim().is_empty());
+    let Some(header) = lines.next() else {
+        return Vec::new();
+    };
+    let names: Vec<&str> = header.split(delimiter).map(clean_cell).collect();
+    let mut values: Vec<Vec<String>> = vec![Vec::new(); names.len()];
+    for line in lines {
+        let mut cells = line.split(delimiter).map(clean_cell);
+        for column in values.iter_mut() {
+            column.push(cells.next().unwrap_or_default().to_string());
+        }
+    }
+
+    names
+        .into_iter()
+        .zip(values)
+        .map(|(name, values)| Column::new(name, values))
+        .collect()
+}
+
+fn clean_cell(cell: &str) -> &str {
+    cell.trim().trim_matches('"')
+}
+
+/// Statistical test applied to a pair of columns
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StatTest {
+    /// Pearson correlation between two numeric columns
+    Correlation,
+    /// Welch's t-test of a numeric column across two groups
+    TTest,
+    /// Chi-square test of independence between two categorical columns
+    ChiSquare,
+}
+
+impl fmt::Display for StatTest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StatTest::Correlation => write!(f, "Pearson correlation"),
+            StatTest::TTest => write!(f, "Welch's t-test"),
+            StatTest::ChiSquare => write!(f, "Chi-square test of independence"),
+        }
+    }
+}
+
+/// Outcome of a statistical test
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TestResult {
+    /// Test that was run
+    pub test: StatTest,
+    /// Names of the tested columns
+    pub columns: (String, String),
+    /// Test statistic (r, t or χ²)
+    pub statistic: f64,
+    /// Two-sided p-value
+    pub p_value: f64,
+    /// Plain-language reading of the result
+    pub interpretation: String,
+}
+
+impl TestResult {
+    /// Whether the result is significant at [`SIGNIFICANCE_LEVEL`]
+    pub fn is_significant(&self) -> bool {
+        self.p_value < SIGNIFICANCE_LEVEL
+    }
+}
+
+impl fmt::Display for TestResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} ({} vs {}): statistic = {:.4}, p = {:.4}. {}",
+            self.test,
+            self.columns.0,
+            self.columns.1,
+            self.statistic,
+            self.p_value,
+            self.interpretation
+        )
+    }
+}
+
+/// Choose the test suited to a column pair, or `None` if no test applies
+pub fn recommend_test(a: &Column, b: &Column) -> Option<StatTest> {
+    match (a.kind, b.kind) {
+        (ColumnKind::Numeric, ColumnKind::Numeric) => Some(StatTest::Correlation),
+        (ColumnKind::Numeric, ColumnKind::Categorical)
+        | (ColumnKind::Categorical, ColumnKind::Numeric) => {
+            let groups = if a.kind == ColumnKind::Categorical {
+                a
+            } else {
+                b
+            };
+            (groups.levels().len() == 2 && groups.is_groupable()).then_some(StatTest::TTest)
+        }
+        (ColumnKind::Categorical, ColumnKind::Categorical) => {
+            (a.is_groupable() && b.is_groupable()).then_some(StatTest::ChiSquare)
+        }
+    }
+}
+
+/// Run `test` on a column pair
+///
+/// # Errors
+///
+/// Returns an error if the columns' types do not fit the test (e.g. correlation on
+/// categorical data) or there is too little data to compute it
+pub fn run_test(test: StatTest, a: &Column, b: &Column) -> Result<TestResult> {
+    match test {
+        StatTest::Correlation => correlation(a, b),
+        StatTest::TTest => match (a.kind, b.kind) {
+            (ColumnKind::Numeric, ColumnKind::Categorical) => t_test(a, b),
+            (ColumnKind::Categorical, ColumnKind::Numeric) => t_test(b, a),
+            _ => Err(unsuitable(
+                test,
+                a,
+                b,
+                "one numeric and one categorical column",
+            )),
+        },
+        StatTest::ChiSquare => chi_square(a, b),
+    }
+}
+
+/// Run the recommended test for every column pair, skipping pairs without one
+pub fn run_recommended_tests(columns: &[Column]) -> Vec<TestResult> {
+    let mut results = Vec::new();
+    for (i, a) in columns.iter().enumerate() {
+        for b in &columns[i + 1..] {
+            let Some(test) = recommend_test(a, b) else {
+                continue;
+            };
+            match run_test(test, a, b) {
+                Ok(result) => results.push(result),
+                Err(e) => debug!("Skipping {} on {}/{}: {}", test, a.name, b.name, e),
+            }
+        }
+    }
+    results
+}
+
+fn unsuitable(test: StatTest, a: &Column, b: &Column, requirement: &str) -> TemplateError {
+    TemplateError::ExecutionFailed(format!(
+        "{} requires {}, got '{}' ({}) and '{}' ({})",
+        test, requirement, a.name, a.kind, b.name, b.kind
+    ))
+}
+
+fn too_little_data(test: StatTest, a: &Column, b: &Column) -> TemplateError {
+    TemplateError::ExecutionFailed(format!(
+        "Not enough data for {} on '{}' and '{}'",
+        test, a.name, b.name
+    ))
+}
+
+fn significance(p_value: f64) -> String {
+    if p_value < SIGNIFICANCE_LEVEL {
+        format!("significant at α = {}", SIGNIFICANCE_LEVEL)
+    } else {
+        format!("not significant at α = {}", SIGNIFICANCE_LEVEL)
+    }
+}
+
+fn correlation(a: &Column, b: &Column) -> Result<TestResult> {
+    if a.kind != ColumnKind::Numeric || b.kind != ColumnKind::Numeric {
+        return Err(unsuitable(
+            StatTest::Correlation,
+            a,
+            b,
+            "two numeric columns",
+        ));
+    }
+    let pairs: Vec<(f64, f64)> = (0..a.values.len())
+        .filter_map(|row| Some((a.number(row)?, b.number(row)?)))
+        .collect();
+    let n = pairs.len() as f64;
+    if pairs.len() < 3 {
+        return Err(too_little_data(StatTest::Correlation, a, b));
+    }
+
+    let mean_x = pairs.iter().map(|(x, _)| x).sum::<f64>() / n;
+    let mean_y = pairs.iter().map(|(_, y)| y).sum::<f64>() / n;
+    let (mut cov, mut var_x, mut var_y) = (0.0, 0.0, 0.0);
+    for (x, y) in &pairs {
+        cov += (x - mean_x) * (y - mean_y);
+        var_x += (x - mean_x).powi(2);
+        var_y += (y - mean_y).powi(2);
+    }
+    if var_x == 0.0 || var_y == 0.0 {
+        return Err(too_little_data(StatTest::Correlation, a, b));
+    }
+
+    let r = (cov / (var_x * var_y).sqrt()).clamp(-1.0, 1.0);
+    let df = n - 2.0;
+    let p_value = if r.abs() >= 1.0 {
+        0.0
+    } else {
+        t_two_sided_p(r * (df / (1.0 - r * r)).sqrt(), df)
+    };
+
+    let strength = match r.abs() {
+        x if x < 0.1 => "negligible",
+        x if x < 0.3 => "weak",
+        x if x < 0.5 => "moderate",
+        _ => "strong",
+    };
+    let direction = if r >= 0.0 { "positive" } else { "negative" };
+    Ok(TestResult {
+        test: StatTest::Correlation,
+        columns: (a.name.clone(), b.name.clone()),
+        statistic: r,
+        p_value,
+        interpretation: format!(
+            "{} {} relationship between '{}' and '{}' (r = {:.3}, n = {}), {}.",
+            capitalize(strength),
+            direction,
+            a.name,
+            b.name,
+            r,
+            pairs.len(),
+            significance(p_value)
+        ),
+    })
+}
+
+/// Welch's t-test of `values` between the two groups of `groups`
+fn t_test(values: &Column, groups: &Column) -> Result<TestResult> {
+    let levels = groups.levels();
+    if levels.len() != 2 {
+        return Err(unsuitable(
+            StatTest::TTest,
+            values,
+            groups,
+            "a categorical column with exactly two groups",
+        ));
+    }
+
+    let samples: Vec<Vec<f64>> = levels
+        .iter()
+        .map(|level| {
+            (0..values.values.len())
+                .filter(|&row| groups.category(row) == Some(*level))
+                .filter_map(|row| values.number(row))
+                .collect()
+        })
+        .collect();
+    if samples.iter().any(|s| s.len() < 2) {
+        return Err(too_little_data(StatTest::TTest, values, groups));
+    }
+
+    let (mean_1, var_1, n_1) = mean_and_variance(&samples[0]);
+    let (mean_2, var_2, n_2) = mean_and_variance(&samples[1]);
+    let (se_1, se_2) = (var_1 / n_1, var_2 / n_2);
+    if se_1 + se_2 == 0.0 {
+        return Err(too_little_data(StatTest::TTest, values, groups));
+    }
+
+    let t = (mean_1 - mean_2) / (se_1 + se_2).sqrt();
+    let df = (se_1 + se_2).powi(2) / (se_1.powi(2) / (n_1 - 1.0) + se_2.powi(2) / (n_2 - 1.0));
+    let p_value = t_two_sided_p(t, df);
+
+    let finding = if p_value < SIGNIFICANCE_LEVEL {
+        "differs"
+    } else {
+        "does not differ"
+    };
+    Ok(TestResult {
+        test: StatTest::TTest,
+        columns: (values.name.clone(), groups.name.clone()),
+        statistic: t,
+        p_value,
+        interpretation: format!(
+            "Mean '{}' {} between '{}' ({:.3}) and '{}' ({:.3}), {}.",
+            values.name,
+            finding,
+            levels[0],
+            mean_1,
+            levels[1],
+            mean_2,
+            significance(p_value)
+        ),
+    })
+}
+
+fn chi_square(a: &Column, b: &Column) -> Result<TestResult> {
+    if a.kind != ColumnKind::Categorical || b.kind != ColumnKind::Categorical {
+        return Err(unsuitable(
+            StatTest::ChiSquare,
+            a,
+            b,
+            "two categorical columns",
+        ));
+    }
+
+    let mut observed: BTreeMap<(&str, &str), f64> = BTreeMap::new();
+    let mut row_totals: BTreeMap<&str, f64> = BTreeMap::new();
+    let mut col_totals: BTreeMap<&str, f64> = BTreeMap::new();
+    for row in 0..a.values.len() {
+        if let (Some(x), Some(y)) = (a.category(row), b.category(row)) {
+            *observed.entry((x, y)).or_default() += 1.0;
+            *row_totals.entry(x).or_default() += 1.0;
+            *col_totals.entry(y).or_default() += 1.0;
+        }
+    }
+    if row_totals.len() < 2 || col_totals.len() < 2 {
+        return Err(too_little_data(StatTest::ChiSquare, a, b));
+    }
+
+    let total: f64 = row_totals.values().sum();
+    let mut statistic = 0.0;
+    for (x, row_total) in &row_totals {
+        for (y, col_total) in &col_totals {
+            let expected = row_total * col_total / total;
+            let count = observed.get(&(*x, *y)).copied().unwrap_or(0.0);
+            statistic += (count - expected).powi(2) / expected;
+        }
+    }
+    let df = ((row_totals.len() - 1) * (col_totals.len() - 1)) as f64;
+    let p_value = upper_incomplete_gamma(df / 2.0, statistic / 2.0);
+
+    let finding = if p_value < SIGNIFICANCE_LEVEL {
+        "are associated"
+    } else {
+        "show no association"
+    };
+    Ok(TestResult {
+        test: StatTest::ChiSquare,
+        columns: (a.name.clone(), b.name.clone()),
+        statistic,
+        p_value,
+        interpretation: format!(
+            "'{}' and '{}' {} (χ² = {:.3}, df = {}), {}.",
+            a.name,
+            b.name,
+            finding,
+            statistic,
+            df,
+            significance(p_value)
+        ),
+    })
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    chars
+        .next()
+        .map(|first| first.to_uppercase().chain(chars).collect())
+        .unwrap_or_default()
+}
+
+/// Sample mean, unbiased variance and size
+fn mean_and_variance(sample: &[f64]) -> (f64, f64, f64) {
+    let n = sample.len() as f64;
+    let mean = sample.iter().sum::<f64>() / n;
+    let variance = sample.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (n - 1.0);
+    (mean, variance, n)
+}
+
+/// Two-sided p-value of Student's t distribution with `df` degrees of freedom
+fn t_two_sided_p(t: f64, df: f64) -> f64 {
+    incomplete_beta(df / 2.0, 0.5, df / (df + t * t))
+}
+
+/// Natural log of the gamma function (Lanczos approximation)
+fn ln_gamma(x: f64) -> f64 {
+    const COEFFICIENTS: [f64; 6] = [
+        76.180_091_729_471_46,
+        -86.505_320_329_416_77,
+        24.014_098_240_830_91,
+        -1.231_739_572_450_155,
+        0.120_865_097_386_617_9e-2,
+        -0.539_523_938_495_3e-5,
+    ];
+    let tmp = x + 5.5 - (x + 0.5) * (x + 5.5).ln();
+    let mut series = 1.000_000_000_190_015;
+    let mut y = x;
+    for c in COEFFICIENTS {
+        y += 1.0;
+        series += c / y;
+    }
+    -tmp + (2.506_628_274_631_000_5 * series / x).ln()
+}
+
+/// Regularized incomplete beta function I_x(a, b)
+fn incomplete_beta(a: f64, b: f64, x: f64) -> f64 {
+    if x <= 0.0 {
+        return 0.0;
+    }
+    if x >= 1.0 {
+        return 1.0;
+    }
+    let front =
+        (ln_gamma(a + b) - ln_gamma(a) - ln_gamma(b) + a * x.ln() + b * (1.0 - x).ln()).exp();
+    if x < (a + 1.0) / (a + b + 2.0) {
+        front * beta_continued_fraction(a, b, x) / a
+    } else {
+        1.0 - front * beta_continued_fraction(b, a, 1.0 - x) / b
+    }
+}
+
+fn beta_continued_fraction(a: f64, b: f64, x: f64) -> f64 {
+    const MAX_ITERATIONS: usize = 200;
+    const EPSILON: f64 = 3e-14;
+    const TINY: f64 = 1e-300;
+
+    let mut c = 1.0;
+    let mut d = 1.0 - (a + b) * x / (a + 1.0);
+    if d.abs() < TINY {
+        d = TINY;
+    }
+    d = 1.0 / d;
+    let mut h = d;
+    for m in 1..=MAX_ITERATIONS {
+        let m = m as f64;
+        let m2 = 2.0 * m;
+        for numerator in [
+            m * (b - m) * x / ((a + m2 - 1.0) * (a + m2)),
+            -(a + m) * (a + b + m) * x / ((a + m2) * (a + m2 + 1.0)),
+        ] {
+            d = 1.0 + numerator * d;
+            if d.abs() < TINY {
+                d = TINY;
+            }
+            c = 1.0 + numerator / c;
+            if c.abs() < TINY {
+                c = TINY;
+            }
+            d = 1.0 / d;
+            h *= d * c;
+        }
+        if (d * c - 1.0).abs() < EPSILON {
+            break;
+        }
+    }
+    h
+}
+
+/// Regularized upper incomplete gamma function Q(a, x)
+fn upper_incomplete_gamma(a: f64, x: f64) -> f64 {
+    const MAX_ITERATIONS: usize = 200;
+    const EPSILON: f64 = 3e-14;
+    const TINY: f64 = 1e-300;
+
+    if x <= 0.0 {
+        return 1.0;
+    }
+    let log_prefix = -x + a * x.ln() - ln_gamma(a);
+    if x < a + 1.0 {
+        // Series for the lower function P(a, x)
+        let mut term = 1.0 / a;
+        let mut sum = term;
+        let mut denominator = a;
+        for _ in 0..MAX_ITERATIONS {
+            denominator += 1.0;
+            term *= x / denominator;
+            sum += term;
+            if term.abs() < sum.abs() * EPSILON {
+                break;
+            }
+        }
+        1.0 - sum * log_prefix.exp()
+    } else {
+        // Continued fraction for Q(a, x)
+        let mut b = x + 1.0 - a;
+        let mut c = 1.0 / TINY;
+        let mut d = 1.0 / b;
+        let mut h = d;
+        for i in 1..=MAX_ITERATIONS {
+            let i = i as f64;
+            let an = -i * (i - a);
+            b += 2.0;
+            d = an * d + b;
+            if d.abs() < TINY {
+                d = TINY;
+            }
+            c = b + an / c;
+            if c.abs() < TINY {
+                c = TINY;
+            }
+            d = 1.0 / d;
+            let delta = d * c;
+            h *= delta;
+            if (delta - 1.0).abs() < EPSILON {
+                break;
+            }
+        }
+        log_prefix.exp() * h
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn column(name: &str, values: &[&str]) -> Column {
+        Column::new(name, values.iter().map(|v| v.to_string()).collect())
+    }
+
+    #[test]
+    fn test_distribution_functions() {
+        // t = 2.228 with 10 df is the two-sided 5% critical value
+        assert!((t_two_sided_p(2.228, 10.0) - 0.05).abs() < 1e-3);
+        // χ² = 3.841 with 1 df is the 5% critical value
+        assert!((upper_incomplete_gamma(0.5, 3.841 / 2.0) - 0.05).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_two_numeric_columns_are_correlated() {
+        let columns = parse_table(
+            "hours,score\n1,52\n2,55\n3,61\n4,64\n5,70\n6,72\n7,79\n8,83\n",
+            ',',
+        );
+        assert!(columns.iter().all(|c| c.kind == ColumnKind::Numeric));
+
+        let results = run_recommended_tests(&columns);
+        assert_eq!(results.len(), 1);
+        let result = &results[0];
+        assert_eq!(result.test, StatTest::Correlation);
+        assert_eq!(result.columns, ("hours".to_string(), "score".to_string()));
+        assert!(result.statistic > 0.95);
+        assert!(result.is_significant());
+        assert!(result.interpretation.starts_with("Strong positive"));
+    }
+
+    #[test]
+    fn test_numeric_and_categorical_columns_use_t_test() {
+        let columns = parse_table(
+            "group,score\n\
+             control,50\ncontrol,52\ncontrol,49\ncontrol,51\ncontrol,48\n\
+             treated,60\ntreated,63\ntreated,59\ntreated,62\ntreated,61\n",
+            ',',
+        );
+        assert_eq!(columns[0].kind, ColumnKind::Categorical);
+        assert_eq!(columns[1].kind, ColumnKind::Numeric);
+        assert_eq!(
+            recommend_test(&columns[0], &columns[1]),
+            Some(StatTest::TTest)
+        );
+
+        let results = run_recommended_tests(&columns);
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].test, StatTest::TTest);
+        assert_eq!(
+            results[0].columns,
+            ("score".to_string(), "group".to_string())
+        );
+        assert!(results[0].is_significant());
+
+        // Correlation is refused on categorical data
+        assert!(run_test(StatTest::Correlation, &columns[0], &columns[1]).is_err());
+    }
+
+    #[test]
+    fn test_categorical_columns_use_chi_square() {
+        let smoker = column("smoker", &["yes", "yes", "no", "no", "yes", "no"]);
+        let cough = column("cough", &["yes", "yes", "no", "no", "no", "yes"]);
+        assert_eq!(recommend_test(&smoker, &cough), Some(StatTest::ChiSquare));
+
+        let result = run_test(StatTest::ChiSquare, &smoker, &cough).unwrap();
+        assert!(result.statistic >= 0.0);
+        assert!((0.0..=1.0).contains(&result.p_value));
+
+        // Identifier-like columns have too many categories to test
+        let id = column("id", &["a", "b", "c", "d", "e", "f"]);
+        assert_eq!(recommend_test(&id, &cough), None);
+    }
+}
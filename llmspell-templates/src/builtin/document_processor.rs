@@ -1,8 +1,8 @@
 //! Document Processor Template
 //!
 //! Parallel workflow for document processing and transformation:
-//! 1. Load documents from files
-//! 2. Extractor agent: extract text from PDFs/images (parallel)
+//! 1. Load documents from files and detect their format from magic bytes
+//! 2. Extract text with the extractor for each format (parallel)
 //! 3. Transformer agent: transform/enhance extracted content
 //! 4. Save processed documents
 
@@ -18,19 +18,29 @@ use crate::{
 };
 use async_trait::async_trait;
 use serde_json::json;
+use std::collections::HashMap;
+use std::sync::Arc;
 use std::time::Instant;
 use tracing::{debug, info, warn};
 
+mod extraction;
+
+pub use extraction::{
+    DocumentExtractor, DocumentFormat, ExtractionState, ExtractionStatus, HtmlExtractor,
+    TextExtractor, ToolExtractor,
+};
+
 /// Document Processor Template
 ///
 /// Automated document processing with extraction and transformation:
-/// - Loads documents from various formats (PDF, images, text)
-/// - Extracts text using OCR and PDF parsers (parallel processing)
+/// - Loads documents from various formats (PDF, DOCX, HTML, images, text)
+/// - Routes each file to the extractor for its detected format (parallel processing)
 /// - Transforms content with AI-powered enhancement
 /// - Supports batch processing of multiple documents
 #[derive(Debug)]
 pub struct DocumentProcessorTemplate {
     metadata: TemplateMetadata,
+    extractors: HashMap<DocumentFormat, Arc<dyn DocumentExtractor>>,
 }
 
 impl DocumentProcessorTemplate {
@@ -57,8 +67,32 @@ impl DocumentProcessorTemplate {
                     "transformation".to_string(),
                 ],
             },
+            extractors: HashMap::from([
+                (
+                    DocumentFormat::Text,
+                    Arc::new(TextExtractor) as Arc<dyn DocumentExtractor>,
+                ),
+                (DocumentFormat::Html, Arc::new(HtmlExtractor)),
+                (
+                    DocumentFormat::Pdf,
+                    Arc::new(ToolExtractor::new("pdf-processor").with_operation("extract_text")),
+                ),
+            ]),
         }
     }
+
+    /// Route documents of `format` to `extractor`, replacing any existing one
+    ///
+    /// DOCX and images have no default extractor (no OCR tool ships with llmspell) and
+    /// are reported as unsupported until one is set.
+    pub fn with_extractor(
+        mut self,
+        format: DocumentFormat,
+        extractor: Arc<dyn DocumentExtractor>,
+    ) -> Self {
+        self.extractors.insert(format, extractor);
+        self
+    }
 }
 
 impl Default for DocumentProcessorTemplate {
@@ -195,12 +229,15 @@ impl crate::core::Template for DocumentProcessorTemplate {
             "Phase 1: Extracting text from {} documents...",
             document_paths.len()
         );
-        let extracted_docs = if parallel_processing {
+        let (extracted_docs, extraction_status) = if parallel_processing {
             self.extract_parallel(&document_paths, &context).await?
         } else {
             self.extract_sequential(&document_paths, &context).await?
         };
-        output.metrics.tools_invoked += document_paths.len(); // extraction tools
+        output.metrics.tools_invoked += extraction_status
+            .iter()
+            .filter(|status| status.extractor.is_some())
+            .count(); // extraction tools
 
         // Phase 2: Transform content with transformer agent
         info!("Phase 2: Transforming extracted content...");
@@ -229,6 +266,8 @@ impl crate::core::Template for DocumentProcessorTemplate {
         output.result = TemplateResult::text(formatted_output);
         output.set_duration(start_time.elapsed().as_millis() as u64);
         output.add_metric("documents_processed", json!(document_paths.len()));
+        output.add_metric("documents_extracted", json!(extracted_docs.len()));
+        output.add_metric("extraction_status", json!(extraction_status));
         output.add_metric("transformation_type", json!(transformation_type));
         output.add_metric("output_format", json!(output_format));
         output.add_metric("parallel_processing", json!(parallel_processing));
@@ -302,65 +341,103 @@ impl crate::core::Template for DocumentProcessorTemplate {
 }
 
 impl DocumentProcessorTemplate {
-    /// Helper: Read document file from path (supports .txt, .md files)
-    fn read_document_file(path: &str) -> Result<ExtractedDocument> {
-        use std::fs;
-
-        // Read file content
-        let content = fs::read_to_string(path).map_err(|e| {
-            TemplateError::ExecutionFailed(format!("Failed to read file '{}': {}", path, e))
-        })?;
+    /// Detect the format of one file and extract it with the matching extractor
+    async fn extract_document(
+        path: &str,
+        extractors: &HashMap<DocumentFormat, Arc<dyn DocumentExtractor>>,
+        context: &ExecutionContext,
+    ) -> (Option<ExtractedDocument>, ExtractionStatus) {
+        let mut status = ExtractionStatus {
+            path: path.to_string(),
+            format: None,
+            extractor: None,
+            state: ExtractionState::Unsupported,
+            message: None,
+        };
 
-        // Count words (split on whitespace)
-        let word_count = content.split_whitespace().count();
+        let bytes = match tokio::fs::read(path).await {
+            Ok(bytes) => bytes,
+            Err(e) => {
+                status.state = ExtractionState::Failed;
+                status.message = Some(format!("Failed to read file: {}", e));
+                return (None, status);
+            }
+        };
 
-        // Calculate "pages" (500 words per page)
-        let page_count = (word_count as f64 / 500.0).ceil() as usize;
-        let page_count = if page_count == 0 { 1 } else { page_count };
+        let Some(format) = DocumentFormat::detect(&bytes) else {
+            status.message = Some("Unrecognized file format".to_string());
+            return (None, status);
+        };
+        status.format = Some(format);
 
-        Ok(ExtractedDocument {
-            source_path: path.to_string(),
-            extracted_text: content,
-            page_count,
-            word_count,
-        })
+        let Some(extractor) = extractors.get(&format) else {
+            status.message = Some(match format {
+                DocumentFormat::Image => "No OCR extractor available for image files".to_string(),
+                _ => format!("No extractor registered for {} files", format),
+            });
+            return (None, status);
+        };
+        status.extractor = Some(extractor.name().to_string());
+
+        match extractor.extract(path, &bytes, context).await {
+            Ok(text) => {
+                status.state = ExtractionState::Extracted;
+                (
+                    Some(ExtractedDocument::from_text(path, format, &text)),
+                    status,
+                )
+            }
+            Err(e) => {
+                status.state = ExtractionState::Failed;
+                status.message = Some(e.to_string());
+                (None, status)
+            }
+        }
     }
 
     /// Phase 1: Extract text from documents in parallel
+    ///
+    /// Files that fail or have an unsupported format are reported in the returned
+    /// statuses without aborting the batch.
     async fn extract_parallel(
         &self,
         document_paths: &[String],
-        _context: &ExecutionContext,
-    ) -> Result<Vec<ExtractedDocument>> {
-        // Real file extraction for text/markdown files
-        // Note: PDF/OCR support deferred to Phase 14
+        context: &ExecutionContext,
+    ) -> Result<(Vec<ExtractedDocument>, Vec<ExtractionStatus>)> {
         info!(
-            "Extracting text from {} documents (text/markdown files supported)",
+            "Extracting text from {} documents in parallel",
             document_paths.len()
         );
 
-        let mut extracted = Vec::new();
-        for (idx, path) in document_paths.iter().enumerate() {
-            match Self::read_document_file(path) {
-                Ok(doc) => {
-                    info!(
-                        "Extracted document {}/{}: {} ({} words, {} pages)",
-                        idx + 1,
-                        document_paths.len(),
-                        path,
-                        doc.word_count,
-                        doc.page_count
-                    );
-                    extracted.push(doc);
-                }
-                Err(e) => {
-                    warn!("Failed to extract document {}: {}", path, e);
-                    return Err(e);
-                }
-            }
+        let handles: Vec<_> = document_paths
+            .iter()
+            .map(|path| {
+                let path = path.clone();
+                let extractors = self.extractors.clone();
+                let context = context.clone();
+                tokio::spawn(
+                    async move { Self::extract_document(&path, &extractors, &context).await },
+                )
+            })
+            .collect();
+
+        let mut results = Vec::with_capacity(handles.len());
+        for (path, handle) in document_paths.iter().zip(handles) {
+            results.push(handle.await.unwrap_or_else(|e| {
+                (
+                    None,
+                    ExtractionStatus {
+                        path: path.clone(),
+                        format: None,
+                        extractor: None,
+                        state: ExtractionState::Failed,
+                        message: Some(format!("Extraction task failed: {}", e)),
+                    },
+                )
+            }));
         }
 
-        Ok(extracted)
+        Self::collect_extracted(results)
     }
 
     /// Phase 1 (alternative): Extract text from documents sequentially
@@ -368,10 +445,59 @@ impl DocumentProcessorTemplate {
         &self,
         document_paths: &[String],
         context: &ExecutionContext,
-    ) -> Result<Vec<ExtractedDocument>> {
-        // Note: Currently same as parallel (both read synchronously)
-        // Future Phase 14: Use tokio::spawn for true parallel file I/O
-        self.extract_parallel(document_paths, context).await
+    ) -> Result<(Vec<ExtractedDocument>, Vec<ExtractionStatus>)> {
+        info!(
+            "Extracting text from {} documents sequentially",
+            document_paths.len()
+        );
+
+        let mut results = Vec::with_capacity(document_paths.len());
+        for path in document_paths {
+            results.push(Self::extract_document(path, &self.extractors, context).await);
+        }
+
+        Self::collect_extracted(results)
+    }
+
+    /// Split per-file results into extracted documents and statuses
+    ///
+    /// Fails only when no document could be extracted.
+    fn collect_extracted(
+        results: Vec<(Option<ExtractedDocument>, ExtractionStatus)>,
+    ) -> Result<(Vec<ExtractedDocument>, Vec<ExtractionStatus>)> {
+        let total = results.len();
+        let mut documents = Vec::new();
+        let mut statuses = Vec::with_capacity(total);
+
+        for (idx, (document, status)) in results.into_iter().enumerate() {
+            match &document {
+                Some(doc) => info!(
+                    "Extracted document {}/{}: {} ({}, {} words, {} pages)",
+                    idx + 1,
+                    total,
+                    doc.source_path,
+                    doc.format,
+                    doc.word_count,
+                    doc.page_count
+                ),
+                None => warn!(
+                    "Skipping document {}: {}",
+                    status.path,
+                    status.message.as_deref().unwrap_or("extraction failed")
+                ),
+            }
+            documents.extend(document);
+            statuses.push(status);
+        }
+
+        if documents.is_empty() && total > 0 {
+            return Err(TemplateError::ExecutionFailed(format!(
+                "None of the {} documents could be extracted",
+                total
+            )));
+        }
+
+        Ok((documents, statuses))
     }
 
     /// Phase 2: Transform content with transformer agent
@@ -531,7 +657,7 @@ impl DocumentProcessorTemplate {
                 "{}\
                  You are an expert document processor specializing in {} transformations.\n\n\
                  **SOURCE DOCUMENT**: {}\n\
-                 **DOCUMENT STATISTICS**: {} words, {} pages\n\n\
+                 **DOCUMENT STATISTICS**: {} document, {} words, {} pages\n\n\
                  **DOCUMENT CONTENT**:\n{}\n\n\
                  **TRANSFORMATION TYPE**: {}\n\n\
                  **INSTRUCTIONS**:\n{}\n\n\
@@ -548,6 +674,7 @@ impl DocumentProcessorTemplate {
                 },
                 transformation_type,
                 doc.source_path,
+                doc.format,
                 doc.word_count,
                 doc.page_count,
                 doc.extracted_text,
@@ -715,11 +842,13 @@ impl DocumentProcessorTemplate {
     }
 }
 
-/// Extracted document from Phase 1
+/// Extracted document from Phase 1, normalized across source formats
 #[derive(Debug, Clone)]
 struct ExtractedDocument {
     /// Source file path
     source_path: String,
+    /// Detected source format
+    format: DocumentFormat,
     /// Extracted text content
     extracted_text: String,
     /// Number of pages
@@ -728,6 +857,36 @@ struct ExtractedDocument {
     word_count: usize,
 }
 
+impl ExtractedDocument {
+    /// Normalize extracted text: trim line ends and collapse runs of blank lines
+    fn from_text(path: &str, format: DocumentFormat, text: &str) -> Self {
+        let mut extracted_text = String::with_capacity(text.len());
+        let mut blank_run = 0;
+        for line in text.trim().lines().map(str::trim_end) {
+            blank_run = if line.is_empty() { blank_run + 1 } else { 0 };
+            if blank_run <= 1 {
+                extracted_text.push_str(line);
+                extracted_text.push('\n');
+            }
+        }
+
+        // Count words (split on whitespace)
+        let word_count = extracted_text.split_whitespace().count();
+
+        // Calculate "pages" (500 words per page)
+        let page_count = (word_count as f64 / 500.0).ceil() as usize;
+        let page_count = if page_count == 0 { 1 } else { page_count };
+
+        Self {
+            source_path: path.to_string(),
+            format,
+            extracted_text,
+            page_count,
+            word_count,
+        }
+    }
+}
+
 /// Transformed document from Phase 2
 #[derive(Debug, Clone)]
 struct TransformedDocument {
@@ -864,7 +1023,7 @@ mod tests {
         let paths = vec!["doc1.pdf".to_string(), "doc2.pdf".to_string()];
         let result = template.extract_parallel(&paths, &context).await;
         assert!(result.is_ok());
-        let docs = result.unwrap();
+        let (docs, _) = result.unwrap();
         assert_eq!(docs.len(), 2);
         assert_eq!(docs[0].source_path, "doc1.pdf");
         assert_eq!(docs[1].source_path, "doc2.pdf");
@@ -881,6 +1040,7 @@ mod tests {
 
         let extracted = vec![ExtractedDocument {
             source_path: "test.pdf".to_string(),
+            format: DocumentFormat::Pdf,
             extracted_text: "Sample text".to_string(),
             page_count: 2,
             word_count: 100,
@@ -947,10 +1107,17 @@ mod tests {
 
     // Integration tests for real file I/O and agent execution
 
-    #[test]
-    fn test_read_document_file_with_real_file() {
+    #[tokio::test]
+    async fn test_extract_document_with_real_file() {
         use std::fs;
 
+        let template = DocumentProcessorTemplate::new();
+        let context = ExecutionContext::builder().build();
+        if context.is_err() {
+            return;
+        }
+        let context = context.unwrap();
+
         // Create temp file with test content
         let temp_dir = std::env::temp_dir();
         let test_file = temp_dir.join("llmspell_test_doc.txt");
@@ -958,11 +1125,17 @@ mod tests {
 
         fs::write(&test_file, test_content).expect("Failed to write test file");
 
-        // Test reading the file
-        let result = DocumentProcessorTemplate::read_document_file(test_file.to_str().unwrap());
+        // Test extracting the file
+        let (doc, status) = DocumentProcessorTemplate::extract_document(
+            test_file.to_str().unwrap(),
+            &template.extractors,
+            &context,
+        )
+        .await;
 
-        assert!(result.is_ok(), "Failed to read test file");
-        let doc = result.unwrap();
+        assert_eq!(status.state, ExtractionState::Extracted);
+        assert_eq!(status.format, Some(DocumentFormat::Text));
+        let doc = doc.expect("Failed to extract test file");
         assert_eq!(doc.source_path, test_file.to_string_lossy().to_string());
         assert!(!doc.extracted_text.is_empty());
         assert!(doc.word_count > 0);
@@ -1003,7 +1176,7 @@ mod tests {
         let result = template.extract_parallel(&paths, &context).await;
         assert!(result.is_ok(), "Extraction failed");
 
-        let docs = result.unwrap();
+        let (docs, _) = result.unwrap();
         assert_eq!(docs.len(), 2);
         assert!(docs[0].word_count > 0);
         assert!(docs[1].word_count > 0);
@@ -1013,6 +1186,124 @@ mod tests {
         fs::remove_file(&file2).ok();
     }
 
+    /// Extractor that records the files routed to it
+    #[derive(Debug)]
+    struct RecordingExtractor {
+        name: &'static str,
+        calls: std::sync::Mutex<Vec<String>>,
+    }
+
+    impl RecordingExtractor {
+        fn new(name: &'static str) -> Arc<Self> {
+            Arc::new(Self {
+                name,
+                calls: std::sync::Mutex::new(Vec::new()),
+            })
+        }
+    }
+
+    #[async_trait]
+    impl DocumentExtractor for RecordingExtractor {
+        fn name(&self) -> &str {
+            self.name
+        }
+
+        async fn extract(
+            &self,
+            path: &str,
+            _bytes: &[u8],
+            _context: &ExecutionContext,
+        ) -> Result<String> {
+            self.calls.lock().unwrap().push(path.to_string());
+            Ok(format!("text from {}", self.name))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_mixed_formats_routed_to_extractors() {
+        use std::fs;
+
+        let pdf = RecordingExtractor::new("mock-pdf");
+        let ocr = RecordingExtractor::new("mock-ocr");
+        let template = DocumentProcessorTemplate::new()
+            .with_extractor(DocumentFormat::Pdf, pdf.clone())
+            .with_extractor(DocumentFormat::Image, ocr.clone());
+        let context = ExecutionContext::builder()
+            .with_tool_registry(Arc::new(llmspell_tools::ToolRegistry::new()))
+            .with_agent_registry(Arc::new(llmspell_agents::FactoryRegistry::new()))
+            .with_workflow_factory(Arc::new(llmspell_workflows::DefaultWorkflowFactory::new()))
+            .with_providers(Arc::new(llmspell_providers::ProviderManager::new()))
+            .with_provider_config(Arc::new(
+                llmspell_config::providers::ProviderManagerConfig::default(),
+            ))
+            .build()
+            .unwrap();
+
+        let dir = std::env::temp_dir().join(format!("llmspell_docs_{}", uuid::Uuid::new_v4()));
+        fs::create_dir_all(&dir).unwrap();
+        let files = [
+            ("report.pdf", b"%PDF-1.4\n1 0 obj\n".to_vec()),
+            ("scan.png", b"\x89PNG\r\n\x1a\n\x00\x00\x00\rIHDR".to_vec()),
+            ("blob.bin", vec![0x00, 0x01, 0x02, 0xFF]),
+        ];
+        let paths: Vec<String> = files
+            .iter()
+            .map(|(name, bytes)| {
+                let path = dir.join(name);
+                fs::write(&path, bytes).unwrap();
+                path.to_string_lossy().to_string()
+            })
+            .collect();
+
+        let (docs, statuses) = template.extract_parallel(&paths, &context).await.unwrap();
+        fs::remove_dir_all(&dir).ok();
+
+        assert_eq!(*pdf.calls.lock().unwrap(), vec![paths[0].clone()]);
+        assert_eq!(*ocr.calls.lock().unwrap(), vec![paths[1].clone()]);
+        assert_eq!(docs.len(), 2);
+        assert_eq!(docs[0].format, DocumentFormat::Pdf);
+        assert_eq!(docs[1].extracted_text.trim(), "text from mock-ocr");
+
+        assert_eq!(statuses.len(), 3);
+        assert_eq!(statuses[0].state, ExtractionState::Extracted);
+        assert_eq!(statuses[0].extractor.as_deref(), Some("mock-pdf"));
+        assert_eq!(statuses[1].format, Some(DocumentFormat::Image));
+        assert_eq!(statuses[1].extractor.as_deref(), Some("mock-ocr"));
+        assert_eq!(statuses[2].state, ExtractionState::Unsupported);
+        assert!(statuses[2].format.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_images_unsupported_without_ocr_extractor() {
+        let template = DocumentProcessorTemplate::new();
+        let context = ExecutionContext::builder()
+            .with_tool_registry(Arc::new(llmspell_tools::ToolRegistry::new()))
+            .with_agent_registry(Arc::new(llmspell_agents::FactoryRegistry::new()))
+            .with_workflow_factory(Arc::new(llmspell_workflows::DefaultWorkflowFactory::new()))
+            .with_providers(Arc::new(llmspell_providers::ProviderManager::new()))
+            .with_provider_config(Arc::new(
+                llmspell_config::providers::ProviderManagerConfig::default(),
+            ))
+            .build()
+            .unwrap();
+
+        let path = std::env::temp_dir().join(format!("llmspell_scan_{}.png", uuid::Uuid::new_v4()));
+        std::fs::write(&path, b"\x89PNG\r\n\x1a\n\x00\x00\x00\rIHDR").unwrap();
+        let (doc, status) = DocumentProcessorTemplate::extract_document(
+            path.to_str().unwrap(),
+            &template.extractors,
+            &context,
+        )
+        .await;
+        std::fs::remove_file(&path).ok();
+
+        assert!(doc.is_none());
+        assert_eq!(status.format, Some(DocumentFormat::Image));
+        assert_eq!(status.state, ExtractionState::Unsupported);
+        assert!(status.extractor.is_none());
+        assert!(status.message.unwrap().contains("OCR"));
+    }
+
     #[tokio::test]
     #[ignore = "Requires full infrastructure (AgentRegistry, ProviderManager) for real LLM execution"]
     async fn test_end_to_end_with_real_agent() {
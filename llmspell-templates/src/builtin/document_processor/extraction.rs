@@ -0,0 +1,350 @@
+//! Format detection and text extraction for mixed document sets
+//!
+//! Each input is identified by its magic bytes and routed to the extractor
+//! registered for its format.
+
+use crate::{
+    context::ExecutionContext,
+    error::{Result, TemplateError},
+};
+use async_trait::async_trait;
+use serde::Serialize;
+use std::fmt;
+
+/// Number of leading bytes inspected when sniffing text formats
+const SNIFF_BYTES: usize = 8 * 1024;
+
+/// Document format detected from file contents
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DocumentFormat {
+    /// PDF document
+    Pdf,
+    /// Word (Office Open XML) document
+    Docx,
+    /// HTML page
+    Html,
+    /// Raster image (PNG, JPEG, GIF, TIFF, WebP)
+    Image,
+    /// Plain text or markdown
+    Text,
+}
+
+impl DocumentFormat {
+    /// Detect the format from magic bytes, falling back to text sniffing
+    ///
+    /// Returns `None` for binary content of an unknown format.
+    pub fn detect(bytes: &[u8]) -> Option<Self> {
+        const IMAGE_SIGNATURES: [&[u8]; 6] = [
+            b"\x89PNG\r\n\x1a\n",
+            b"\xFF\xD8\xFF",
+            b"GIF87a",
+            b"GIF89a",
+            b"II*\x00",
+            b"MM\x00*",
+        ];
+
+        if bytes.starts_with(b"%PDF-") {
+            return Some(Self::Pdf);
+        }
+        if IMAGE_SIGNATURES.iter().any(|sig| bytes.starts_with(sig))
+            || (bytes.starts_with(b"RIFF") && bytes.get(8..12) == Some(b"WEBP".as_slice()))
+        {
+            return Some(Self::Image);
+        }
+        if bytes.starts_with(b"PK\x03\x04") {
+            // DOCX is a zip archive whose entries live under word/
+            let is_docx = bytes.windows(5).any(|w| w == b"word/");
+            return is_docx.then_some(Self::Docx);
+        }
+
+        let head = &bytes[..bytes.len().min(SNIFF_BYTES)];
+        if head.contains(&0) {
+            return None;
+        }
+        let text = match std::str::from_utf8(head) {
+            Ok(text) => text,
+            // A multi-byte character may be cut at the sniff boundary
+            Err(e) if e.error_len().is_none() => {
+                std::str::from_utf8(&head[..e.valid_up_to()]).unwrap_or_default()
+            }
+            Err(_) => return None,
+        };
+        let lower = text
+            .trim_start_matches('\u{feff}')
+            .trim_start()
+            .to_lowercase();
+        if lower.starts_with("<!doctype html") || lower.starts_with("<html") {
+            Some(Self::Html)
+        } else {
+            Some(Self::Text)
+        }
+    }
+}
+
+impl fmt::Display for DocumentFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::Pdf => "pdf",
+            Self::Docx => "docx",
+            Self::Html => "html",
+            Self::Image => "image",
+            Self::Text => "text",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// Extracts plain text from one document format
+#[async_trait]
+pub trait DocumentExtractor: Send + Sync + fmt::Debug {
+    /// Name reported in the per-file extraction status
+    fn name(&self) -> &str;
+
+    /// Extract the text of the document at `path`, whose contents are `bytes`
+    async fn extract(&self, path: &str, bytes: &[u8], context: &ExecutionContext)
+        -> Result<String>;
+}
+
+/// Decodes plain text and markdown files
+#[derive(Debug, Default)]
+pub struct TextExtractor;
+
+#[async_trait]
+impl DocumentExtractor for TextExtractor {
+    fn name(&self) -> &str {
+        "text"
+    }
+
+    async fn extract(
+        &self,
+        _path: &str,
+        bytes: &[u8],
+        _context: &ExecutionContext,
+    ) -> Result<String> {
+        Ok(String::from_utf8_lossy(bytes).into_owned())
+    }
+}
+
+/// Strips markup, scripts and styles from HTML pages
+#[derive(Debug, Default)]
+pub struct HtmlExtractor;
+
+#[async_trait]
+impl DocumentExtractor for HtmlExtractor {
+    fn name(&self) -> &str {
+        "html"
+    }
+
+    async fn extract(
+        &self,
+        _path: &str,
+        bytes: &[u8],
+        _context: &ExecutionContext,
+    ) -> Result<String> {
+        Ok(html_to_text(&String::from_utf8_lossy(bytes)))
+    }
+}
+
+/// Extracts text by running a registered tool with the file path as `input`
+///
+/// The tool's `result.text` field is used when it answers with JSON, otherwise its raw text.
+#[derive(Debug)]
+pub struct ToolExtractor {
+    tool: String,
+    operation: Option<String>,
+}
+
+impl ToolExtractor {
+    /// Extract with the tool registered as `tool`
+    pub fn new(tool: impl Into<String>) -> Self {
+        Self {
+            tool: tool.into(),
+            operation: None,
+        }
+    }
+
+    /// Pass `operation` to the tool
+    pub fn with_operation(mut self, operation: impl Into<String>) -> Self {
+        self.operation = Some(operation.into());
+        self
+    }
+}
+
+#[async_trait]
+impl DocumentExtractor for ToolExtractor {
+    fn name(&self) -> &str {
+        &self.tool
+    }
+
+    async fn extract(
+        &self,
+        path: &str,
+        _bytes: &[u8],
+        context: &ExecutionContext,
+    ) -> Result<String> {
+        use llmspell_core::types::AgentInput;
+
+        let tool_registry = context.tool_registry();
+        if tool_registry.get_tool(&self.tool).await.is_none() {
+            return Err(TemplateError::ExecutionFailed(format!(
+                "Extraction tool '{}' is not registered",
+                self.tool
+            )));
+        }
+
+        let mut params = serde_json::json!({ "input": path });
+        if let Some(operation) = &self.operation {
+            params["operation"] = serde_json::json!(operation);
+        }
+        let input = AgentInput::builder()
+            .text("")
+            .parameter("parameters", params)
+            .build();
+
+        let output = tool_registry
+            .execute_tool(
+                &self.tool,
+                input,
+                llmspell_core::ExecutionContext::default(),
+            )
+            .await
+            .map_err(|e| {
+                TemplateError::ExecutionFailed(format!("Tool '{}' failed: {}", self.tool, e))
+            })?;
+
+        let text = serde_json::from_str::<serde_json::Value>(&output.text)
+            .ok()
+            .and_then(|response| {
+                response
+                    .get("result")
+                    .and_then(|r| r.get("text"))
+                    .and_then(serde_json::Value::as_str)
+                    .map(str::to_string)
+            })
+            .unwrap_or(output.text);
+        Ok(text)
+    }
+}
+
+/// Outcome of extracting one input file
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ExtractionState {
+    /// Text was extracted
+    Extracted,
+    /// The format was recognized but extraction failed
+    Failed,
+    /// The format is unknown or has no extractor
+    Unsupported,
+}
+
+/// Per-file extraction status reported with the template output
+#[derive(Debug, Clone, Serialize)]
+pub struct ExtractionStatus {
+    /// Input file path
+    pub path: String,
+    /// Detected format, if recognized
+    pub format: Option<DocumentFormat>,
+    /// Extractor the file was routed to
+    pub extractor: Option<String>,
+    /// Extraction outcome
+    pub state: ExtractionState,
+    /// Failure reason, if not extracted
+    pub message: Option<String>,
+}
+
+/// Convert HTML to readable text, dropping scripts, styles and tags
+fn html_to_text(html: &str) -> String {
+    let mut text = String::with_capacity(html.len() / 2);
+    // ASCII lowercasing keeps byte offsets aligned with `html`
+    let lower = html.to_ascii_lowercase();
+    let mut pos = 0;
+
+    while let Some(offset) = html[pos..].find('<') {
+        let start = pos + offset;
+        text.push_str(&html[pos..start]);
+
+        let skip_until = ["script", "style"].iter().find_map(|tag| {
+            lower[start + 1..]
+                .starts_with(tag)
+                .then(|| format!("</{}", tag))
+        });
+        let end = match skip_until {
+            Some(closing) => lower[start..].find(&closing).and_then(|close| {
+                lower[start + close..]
+                    .find('>')
+                    .map(|gt| start + close + gt)
+            }),
+            None => html[start..].find('>').map(|gt| start + gt),
+        };
+        let Some(end) = end else {
+            pos = html.len();
+            break;
+        };
+
+        let tag = &lower[start + 1..end];
+        let is_block = ["p", "/p", "br", "div", "/div", "li", "h1", "h2", "h3", "tr"]
+            .iter()
+            .any(|block| {
+                tag.strip_prefix(block).is_some_and(|rest| {
+                    rest.is_empty() || rest.starts_with(' ') || rest.starts_with('/')
+                })
+            });
+        if is_block {
+            text.push('\n');
+        }
+        pos = end + 1;
+    }
+    text.push_str(&html[pos..]);
+
+    let text = text
+        .replace("&nbsp;", " ")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&");
+    text.lines()
+        .map(|line| line.split_whitespace().collect::<Vec<_>>().join(" "))
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detect_formats_from_magic_bytes() {
+        assert_eq!(
+            DocumentFormat::detect(b"%PDF-1.7\n..."),
+            Some(DocumentFormat::Pdf)
+        );
+        assert_eq!(
+            DocumentFormat::detect(b"\x89PNG\r\n\x1a\n\x00\x00"),
+            Some(DocumentFormat::Image)
+        );
+        assert_eq!(
+            DocumentFormat::detect(b"PK\x03\x04....word/document.xml"),
+            Some(DocumentFormat::Docx)
+        );
+        assert_eq!(
+            DocumentFormat::detect(b"  <!DOCTYPE html><html></html>"),
+            Some(DocumentFormat::Html)
+        );
+        assert_eq!(
+            DocumentFormat::detect(b"# Notes\n\nplain"),
+            Some(DocumentFormat::Text)
+        );
+        assert_eq!(DocumentFormat::detect(b"\x00\x01\x02\xFF"), None);
+    }
+
+    #[test]
+    fn test_html_to_text() {
+        let html = "<html><head><style>p { color: red; }</style><script>var x = '<p>';</script>\
+                    <title>T</title></head><body><h1>Title</h1><p>Fish &amp; chips</p></body></html>";
+        assert_eq!(html_to_text(html), "T\nTitle\nFish & chips");
+    }
+}
@@ -1,7 +1,7 @@
 //! Interactive Chat Template
 //!
 //! Session-based conversation template with:
-//! - Persistent conversation history
+//! - Persistent conversation history, with older turns summarized near the context limit
 //! - Optional tool integration
 //! - Two modes: interactive (REPL with readline) vs programmatic (single message)
 //! - Memory placeholder for Phase 13
@@ -26,6 +26,70 @@ use std::sync::Arc;
 use std::time::Instant;
 use tracing::{debug, info, warn};
 
+/// Recent turns kept verbatim when older history is summarized
+const KEEP_RECENT_TURNS: usize = 6;
+
+/// Fraction of the context window at which older turns are summarized
+const SUMMARIZE_THRESHOLD: f64 = 0.8;
+
+/// Role of the turn that replaces summarized older turns
+const SUMMARY_ROLE: &str = "summary";
+
+/// Session state key of the turns replaced by summaries
+const SUMMARIZED_TURNS_KEY: &str = "conversation_summarized_turns";
+
+/// Session artifact holding the untrimmed conversation history
+const FULL_HISTORY_ARTIFACT: &str = "conversation-full-history.json";
+
+/// Summarizes older conversation turns once the history nears the context window
+#[async_trait]
+pub trait ConversationSummarizer: Send + Sync + std::fmt::Debug {
+    /// Condense a transcript of older turns into a recap that replaces them
+    async fn summarize(&self, transcript: &str) -> Result<String>;
+}
+
+/// Summarizer that asks an agent (by default the chat agent) for the recap
+pub struct AgentSummarizer {
+    agent: Arc<dyn llmspell_core::traits::agent::Agent>,
+}
+
+impl std::fmt::Debug for AgentSummarizer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AgentSummarizer")
+            .field("agent", &self.agent.metadata().name)
+            .finish()
+    }
+}
+
+impl AgentSummarizer {
+    /// Summarize with the given agent
+    pub fn new(agent: Arc<dyn llmspell_core::traits::agent::Agent>) -> Self {
+        Self { agent }
+    }
+}
+
+#[async_trait]
+impl ConversationSummarizer for AgentSummarizer {
+    async fn summarize(&self, transcript: &str) -> Result<String> {
+        let prompt = format!(
+            "Summarize the following conversation so the summary can replace it as context. \
+             Keep facts, decisions, names, and open questions.\n\n{}",
+            transcript
+        );
+        let input = llmspell_core::types::AgentInput::builder()
+            .text(prompt)
+            .build();
+        let output = self
+            .agent
+            .execute(input, llmspell_core::ExecutionContext::default())
+            .await
+            .map_err(|e| {
+                TemplateError::ExecutionFailed(format!("Conversation summary failed: {}", e))
+            })?;
+        Ok(output.text)
+    }
+}
+
 /// Minimal no-op script executor for chat-only REPL mode (Subtask 12.9.5)
 ///
 /// This executor provides minimal script execution for REPL infrastructure
@@ -70,6 +134,7 @@ impl ScriptExecutor for NoOpScriptExecutor {
 #[derive(Debug)]
 pub struct InteractiveChatTemplate {
     metadata: TemplateMetadata,
+    summarizer: Option<Arc<dyn ConversationSummarizer>>,
 }
 
 impl InteractiveChatTemplate {
@@ -96,8 +161,15 @@ impl InteractiveChatTemplate {
                     "tools".to_string(),
                 ],
             },
+            summarizer: None,
         }
     }
+
+    /// Summarize older turns with `summarizer` instead of the chat agent
+    pub fn with_summarizer(mut self, summarizer: Arc<dyn ConversationSummarizer>) -> Self {
+        self.summarizer = Some(summarizer);
+        self
+    }
 }
 
 impl Default for InteractiveChatTemplate {
@@ -147,6 +219,17 @@ impl crate::core::Template for InteractiveChatTemplate {
                 ParameterType::Array,
                 json!([]),
             ),
+            // context_window (optional with default and range)
+            ParameterSchema::optional(
+                "context_window",
+                "Model context window in tokens; older turns are summarized as history nears it",
+                ParameterType::Integer,
+                json!(4096),
+            )
+            .with_constraints(ParameterConstraints {
+                min: Some(256.0),
+                ..Default::default()
+            }),
             // enable_memory (optional boolean)
             ParameterSchema::optional(
                 "enable_memory",
@@ -197,6 +280,7 @@ impl crate::core::Template for InteractiveChatTemplate {
                 .to_string(),
         );
         let max_turns: i64 = params.get_or("max_turns", 10);
+        let context_window: usize = params.get_or("context_window", 4096);
         let tools: Vec<String> = params.get_or("tools", Vec::new());
         let message: Option<String> = params.get_optional("message");
         let session_id_param: Option<String> = params.get_optional("session_id");
@@ -266,6 +350,7 @@ impl crate::core::Template for InteractiveChatTemplate {
                     &context,
                     memory_enabled,
                     context_budget,
+                    context_window,
                 )
                 .await?
             }
@@ -279,6 +364,7 @@ impl crate::core::Template for InteractiveChatTemplate {
                     &context,
                     memory_enabled,
                     context_budget,
+                    context_window,
                 )
                 .await?
             }
@@ -595,6 +681,95 @@ impl InteractiveChatTemplate {
         Ok(())
     }
 
+    /// Load the turns that summaries replaced from session state
+    ///
+    /// Returns an empty list for non-UUID memory anchors and for sessions that never had
+    /// older turns summarized.
+    async fn load_summarized_turns(
+        &self,
+        session_id: &str,
+        context: &ExecutionContext,
+    ) -> Result<Vec<ConversationTurn>> {
+        use llmspell_kernel::sessions::SessionId;
+        use std::str::FromStr;
+
+        let Ok(sid) = SessionId::from_str(session_id) else {
+            return Ok(Vec::new());
+        };
+        let session = context
+            .require_sessions()?
+            .get_session(&sid)
+            .await
+            .map_err(|e| TemplateError::ExecutionFailed(format!("Failed to get session: {}", e)))?;
+
+        match session.get_state(SUMMARIZED_TURNS_KEY).await {
+            Some(value) => serde_json::from_value(value).map_err(|e| {
+                TemplateError::ExecutionFailed(format!(
+                    "Failed to deserialize summarized conversation turns: {}",
+                    e
+                ))
+            }),
+            None => Ok(Vec::new()),
+        }
+    }
+
+    /// Save the summarized turns to session state and the untrimmed history as a session artifact
+    ///
+    /// Called when programmatic mode summarizes older turns and once at the end of a REPL
+    /// session that summarized, never on every turn.
+    /// Non-UUID memory anchors are skipped.
+    async fn save_full_history(
+        &self,
+        session_id: &str,
+        history: &ChatHistory,
+        context: &ExecutionContext,
+    ) -> Result<()> {
+        use llmspell_kernel::sessions::{ArtifactType, SessionId};
+        use std::str::FromStr;
+
+        let Ok(sid) = SessionId::from_str(session_id) else {
+            return Ok(());
+        };
+        let session_manager = context.require_sessions()?;
+        let session = session_manager
+            .get_session(&sid)
+            .await
+            .map_err(|e| TemplateError::ExecutionFailed(format!("Failed to get session: {}", e)))?;
+
+        let value = serde_json::to_value(&history.summarized)
+            .map_err(|e| TemplateError::SerializationError(e.to_string()))?;
+        session
+            .set_state(SUMMARIZED_TURNS_KEY.to_string(), value)
+            .await
+            .map_err(|e| {
+                TemplateError::ExecutionFailed(format!(
+                    "Failed to save summarized conversation turns: {}",
+                    e
+                ))
+            })?;
+
+        let full = history.full();
+        let artifact = full_history_artifact(&full)?;
+        session_manager
+            .store_artifact(
+                &sid,
+                ArtifactType::SystemGenerated,
+                artifact.filename,
+                artifact.content.into_bytes(),
+                None,
+            )
+            .await
+            .map_err(|e| {
+                TemplateError::ExecutionFailed(format!(
+                    "Failed to store conversation history artifact: {}",
+                    e
+                ))
+            })?;
+
+        debug!("Archived {} turns of full conversation history", full.len());
+        Ok(())
+    }
+
     /// Phase 4a: Run interactive mode using full REPL (Subtask 12.9.5 - ACTUAL IMPLEMENTATION)
     ///
     /// Uses InteractiveSession.run_repl() for production-grade UX:
@@ -613,6 +788,7 @@ impl InteractiveChatTemplate {
         context: &ExecutionContext,
         _memory_enabled: bool, // Memory integration TODO: integrate at kernel level
         _context_budget: i64,  // Memory integration TODO: integrate at kernel level
+        context_window: usize,
     ) -> Result<ConversationResult> {
         use llmspell_agents::factory::{AgentConfig, ModelConfig, ResourceLimits};
         use llmspell_kernel::execution::ExecutionConfig;
@@ -673,6 +849,7 @@ impl InteractiveChatTemplate {
             enable_performance_monitoring: false,
             execution_timeout_secs: 300,
             enable_persistence: false,
+            max_history: llmspell_kernel::repl::state::DEFAULT_MAX_HISTORY,
         };
 
         // Create interactive session
@@ -728,6 +905,18 @@ impl InteractiveChatTemplate {
         session = session.with_model(model).await;
         session = session.with_system_prompt(system_prompt).await;
         session = session.with_tools(tools.to_vec()).await;
+
+        // Summarize older turns as the REPL history nears the context window
+        let summarizer: Arc<dyn ConversationSummarizer> = match &self.summarizer {
+            Some(summarizer) => summarizer.clone(),
+            None => Arc::new(AgentSummarizer::new(agent.clone())),
+        };
+        let summarized = Arc::new(tokio::sync::Mutex::new(Vec::new()));
+        session = session.with_history_compactor(repl_history_compactor(
+            HistoryWindow::new(context_window),
+            summarizer,
+            summarized.clone(),
+        ));
         session = session.with_initial_agent(agent).await;
 
         // Create agent creator callback for auto-recreation (Subtask 12.9.5)
@@ -812,6 +1001,15 @@ impl InteractiveChatTemplate {
             .await
             .map_err(|e| TemplateError::ExecutionFailed(format!("REPL session failed: {}", e)))?;
 
+        // Archive the untrimmed history if any turns were summarized
+        let summarized = std::mem::take(&mut *summarized.lock().await);
+        if !summarized.is_empty() {
+            let window = from_repl_turns(session.get_conversation_history().await, &summarized);
+            let history = ChatHistory::restore(window, summarized);
+            self.save_full_history(session_id, &history, context)
+                .await?;
+        }
+
         // Extract conversation data for result
         let turn_count = session.get_conversation_context().await.lines().count() / 2;
         let total_tokens = session.get_token_count().await;
@@ -839,6 +1037,7 @@ impl InteractiveChatTemplate {
         context: &ExecutionContext,
         memory_enabled: bool,
         context_budget: i64,
+        context_window: usize,
     ) -> Result<ConversationResult> {
         use llmspell_agents::factory::{AgentConfig, ModelConfig, ResourceLimits};
         use llmspell_core::types::AgentInput;
@@ -880,9 +1079,11 @@ impl InteractiveChatTemplate {
             String::new()
         };
 
-        // Load conversation history from session
-        let mut history = self.load_conversation_history(session_id, context).await?;
-        let turn_number = (history.len() + 1) as u64;
+        // Load conversation history (possibly summarized) and the turns summaries replaced
+        let window = self.load_conversation_history(session_id, context).await?;
+        let summarized = self.load_summarized_turns(session_id, context).await?;
+        let mut history = ChatHistory::restore(window, summarized);
+        let turn_number = history.next_turn_number();
 
         // Add user message to history
         let user_turn = ConversationTurn::user(user_message, turn_number);
@@ -937,14 +1138,19 @@ impl InteractiveChatTemplate {
                 TemplateError::ExecutionFailed(format!("Chat agent creation failed: {}", e))
             })?;
 
+        // Summarize older turns if the history is nearing the context window
+        let summarizer: Arc<dyn ConversationSummarizer> = match &self.summarizer {
+            Some(summarizer) => summarizer.clone(),
+            None => Arc::new(AgentSummarizer::new(agent.clone())),
+        };
+        let compacted = HistoryWindow::new(context_window)
+            .compact(&mut history, summarizer.as_ref())
+            .await?;
+
         // Build prompt with system instructions and conversation history
-        let conversation_context = if !history.is_empty() {
-            // Include all conversation turns (including first message) for context
-            let history_text: String = history
-                .iter()
-                .map(|turn| format!("{}: {}", turn.role, turn.content))
-                .collect::<Vec<_>>()
-                .join("\n\n");
+        let conversation_context = if !history.window.is_empty() {
+            // Include the summary and every recent turn (including this message) for context
+            let history_text = transcript_of(&history.window);
             format!("\n\nConversation History:\n{}\n", history_text)
         } else {
             String::new()
@@ -982,9 +1188,13 @@ impl InteractiveChatTemplate {
         let assistant_turn = ConversationTurn::assistant(&output.text, turn_number + 1);
         history.push(assistant_turn);
 
-        // Save updated history to session
-        self.save_conversation_history(session_id, &history, context)
+        // Save updated history to session, archiving the untrimmed copy after a summary
+        self.save_conversation_history(session_id, &history.window, context)
             .await?;
+        if compacted {
+            self.save_full_history(session_id, &history, context)
+                .await?;
+        }
 
         // Build transcript
         let transcript = format!(
@@ -1132,6 +1342,198 @@ struct ConversationResult {
     total_tokens: usize,
 }
 
+/// Conversation history: the (possibly summarized) window sent to the model and the
+/// original turns its summary replaced
+#[derive(Debug, Clone, Default)]
+struct ChatHistory {
+    /// Turns sent to the model, led by a summary turn once older turns are summarized
+    window: Vec<ConversationTurn>,
+    /// Original turns replaced by the summary, oldest first
+    summarized: Vec<ConversationTurn>,
+}
+
+impl ChatHistory {
+    /// Restore from persisted state
+    fn restore(window: Vec<ConversationTurn>, summarized: Vec<ConversationTurn>) -> Self {
+        Self { window, summarized }
+    }
+
+    /// Append a turn to the window
+    fn push(&mut self, turn: ConversationTurn) {
+        self.window.push(turn);
+    }
+
+    /// Every turn of the conversation, without summaries
+    fn full(&self) -> Vec<ConversationTurn> {
+        self.summarized
+            .iter()
+            .chain(self.window.iter().filter(|turn| turn.role != SUMMARY_ROLE))
+            .cloned()
+            .collect()
+    }
+
+    /// Number for the next turn, continuing after summarized turns
+    fn next_turn_number(&self) -> u64 {
+        self.window
+            .last()
+            .or(self.summarized.last())
+            .map_or(1, |turn| turn.turn_number + 1)
+    }
+}
+
+/// Keeps the history window within the model's context by summarizing older turns
+#[derive(Debug, Clone, Copy)]
+struct HistoryWindow {
+    /// Model context window in tokens
+    context_window: usize,
+    /// Recent turns always kept verbatim
+    keep_recent: usize,
+}
+
+impl HistoryWindow {
+    fn new(context_window: usize) -> Self {
+        Self {
+            context_window,
+            keep_recent: KEEP_RECENT_TURNS,
+        }
+    }
+
+    /// Whether the window has grown close enough to the context limit to summarize
+    fn near_limit(&self, turns: &[ConversationTurn]) -> bool {
+        let tokens: usize = turns.iter().map(ConversationTurn::estimated_tokens).sum();
+        tokens as f64 >= self.context_window as f64 * SUMMARIZE_THRESHOLD
+    }
+
+    /// Replace all but the most recent turns with a summary once near the limit
+    ///
+    /// An earlier summary is folded into the new one. Returns whether the window changed.
+    async fn compact(
+        &self,
+        history: &mut ChatHistory,
+        summarizer: &dyn ConversationSummarizer,
+    ) -> Result<bool> {
+        if history.window.len() <= self.keep_recent || !self.near_limit(&history.window) {
+            return Ok(false);
+        }
+
+        let split = history.window.len() - self.keep_recent;
+        let older: Vec<ConversationTurn> = history.window.drain(..split).collect();
+        let summary = summarizer.summarize(&transcript_of(&older)).await?;
+        history.summarized.extend(
+            older
+                .iter()
+                .filter(|turn| turn.role != SUMMARY_ROLE)
+                .cloned(),
+        );
+        info!(
+            "Summarized {} older conversation turns, keeping {} recent",
+            older.len(),
+            history.window.len()
+        );
+
+        let mut summary_turn = ConversationTurn::assistant(summary, older[0].turn_number);
+        summary_turn.role = SUMMARY_ROLE.to_string();
+        history.window.insert(0, summary_turn);
+        Ok(true)
+    }
+}
+
+/// History compactor for the REPL, applying `window` to the kernel's chat history
+///
+/// Turns replaced by a summary are appended to `summarized`. Kept turns are returned
+/// unchanged so the kernel's token counts survive compaction.
+fn repl_history_compactor(
+    window: HistoryWindow,
+    summarizer: Arc<dyn ConversationSummarizer>,
+    summarized: Arc<tokio::sync::Mutex<Vec<ConversationTurn>>>,
+) -> llmspell_kernel::repl::session::HistoryCompactor {
+    Arc::new(
+        move |turns: Vec<llmspell_kernel::repl::session::ConversationTurn>| {
+            let summarizer = summarizer.clone();
+            let summarized = summarized.clone();
+            Box::pin(async move {
+                let mut summarized = summarized.lock().await;
+                let window_turns = from_repl_turns(turns.clone(), &summarized);
+                let mut history =
+                    ChatHistory::restore(window_turns, std::mem::take(&mut *summarized));
+                let compacted = window.compact(&mut history, summarizer.as_ref()).await;
+                *summarized = std::mem::take(&mut history.summarized);
+
+                match compacted {
+                    Ok(true) => {
+                        let replaced = turns.len() + 1 - history.window.len();
+                        let summary = to_repl_turn(history.window.remove(0));
+                        Ok(std::iter::once(summary)
+                            .chain(turns.into_iter().skip(replaced))
+                            .collect())
+                    }
+                    Ok(false) => Ok(turns),
+                    Err(e) => Err(llmspell_core::LLMSpellError::Component {
+                        message: format!("Failed to summarize conversation: {e}"),
+                        source: None,
+                    }),
+                }
+            })
+        },
+    )
+}
+
+/// Convert REPL chat turns, numbering them after the `summarized` turns
+///
+/// REPL token counts cover the whole prompt rather than the turn, so they are dropped
+/// and tokens are estimated from the content instead.
+fn from_repl_turns(
+    turns: Vec<llmspell_kernel::repl::session::ConversationTurn>,
+    summarized: &[ConversationTurn],
+) -> Vec<ConversationTurn> {
+    let first = summarized.first().map_or(1, |turn| turn.turn_number);
+    let mut next = summarized.last().map_or(1, |turn| turn.turn_number + 1);
+    turns
+        .into_iter()
+        .map(|turn| {
+            let turn_number = if turn.role == SUMMARY_ROLE {
+                first
+            } else {
+                next += 1;
+                next - 1
+            };
+            ConversationTurn {
+                role: turn.role,
+                content: turn.content,
+                timestamp: turn.timestamp,
+                turn_number,
+                token_count: None,
+            }
+        })
+        .collect()
+}
+
+/// Convert a turn into a REPL chat turn
+fn to_repl_turn(turn: ConversationTurn) -> llmspell_kernel::repl::session::ConversationTurn {
+    llmspell_kernel::repl::session::ConversationTurn {
+        role: turn.role,
+        content: turn.content,
+        token_count: turn.token_count.map(|count| count as usize),
+        timestamp: turn.timestamp,
+    }
+}
+
+/// Render turns as "role: content" paragraphs
+fn transcript_of(turns: &[ConversationTurn]) -> String {
+    turns
+        .iter()
+        .map(|turn| format!("{}: {}", turn.role, turn.content))
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+/// Artifact holding the untrimmed conversation history as JSON
+fn full_history_artifact(full: &[ConversationTurn]) -> Result<Artifact> {
+    let content = serde_json::to_string_pretty(full)
+        .map_err(|e| TemplateError::SerializationError(e.to_string()))?;
+    Ok(Artifact::json(FULL_HISTORY_ARTIFACT, content))
+}
+
 /// Single conversation turn for history tracking
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 struct ConversationTurn {
@@ -1171,6 +1573,12 @@ impl ConversationTurn {
         }
     }
 
+    /// Token count, estimated at ~4 characters per token when not recorded
+    fn estimated_tokens(&self) -> usize {
+        self.token_count
+            .map_or(self.content.len() / 4, |count| count as usize)
+    }
+
     /// Set token count
     #[allow(dead_code)]
     fn with_token_count(mut self, count: u64) -> Self {
@@ -1586,6 +1994,103 @@ mod tests {
         assert_eq!(tools, expected);
     }
 
+    /// Summarizer recording how many turns each summary replaced
+    #[derive(Debug, Default)]
+    struct CountingSummarizer {
+        calls: std::sync::Mutex<Vec<usize>>,
+    }
+
+    #[async_trait]
+    impl ConversationSummarizer for CountingSummarizer {
+        async fn summarize(&self, transcript: &str) -> Result<String> {
+            let turns = transcript.split("\n\n").count();
+            self.calls.lock().unwrap().push(turns);
+            Ok(format!("recap of {} turns", turns))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_history_summarized_near_context_limit() {
+        let summarizer = CountingSummarizer::default();
+        // Each turn is ~25 tokens, so 20 turns reach 80% of a 600-token window
+        let window = HistoryWindow::new(600);
+        let mut history = ChatHistory::restore(Vec::new(), Vec::new());
+
+        for _ in 0..19 {
+            let number = history.next_turn_number();
+            history.push(ConversationTurn::user("x".repeat(100), number));
+            assert!(!window.compact(&mut history, &summarizer).await.unwrap());
+        }
+        assert_eq!(history.window.len(), 19);
+
+        let number = history.next_turn_number();
+        history.push(ConversationTurn::assistant("y".repeat(100), number));
+        assert!(window.compact(&mut history, &summarizer).await.unwrap());
+
+        // Older turns are replaced by one summary; the last N stay verbatim
+        assert_eq!(history.window.len(), KEEP_RECENT_TURNS + 1);
+        assert_eq!(history.window[0].role, SUMMARY_ROLE);
+        assert_eq!(history.window[0].content, "recap of 14 turns");
+        assert_eq!(history.window[0].turn_number, 1);
+        let recent: Vec<u64> = history.window[1..].iter().map(|t| t.turn_number).collect();
+        assert_eq!(recent, (15..=20).collect::<Vec<_>>());
+        assert_eq!(history.window.last().unwrap().content, "y".repeat(100));
+        assert_eq!(*summarizer.calls.lock().unwrap(), vec![14]);
+
+        // The full history artifact still holds every original turn
+        assert_eq!(history.summarized.len(), 14);
+        assert_eq!(history.full().len(), 20);
+        let artifact = full_history_artifact(&history.full()).unwrap();
+        assert_eq!(artifact.filename, FULL_HISTORY_ARTIFACT);
+        let archived: Vec<ConversationTurn> = serde_json::from_str(&artifact.content).unwrap();
+        assert_eq!(archived.len(), 20);
+        assert!(archived.iter().all(|t| t.role != SUMMARY_ROLE));
+        assert_eq!(history.next_turn_number(), 21);
+    }
+
+    #[tokio::test]
+    async fn test_repl_history_compacted_near_context_limit() {
+        let summarizer = Arc::new(CountingSummarizer::default());
+        let summarized = Arc::new(tokio::sync::Mutex::new(Vec::new()));
+        let compactor = repl_history_compactor(
+            HistoryWindow::new(600),
+            summarizer.clone(),
+            summarized.clone(),
+        );
+        let repl_turn = |i: usize| llmspell_kernel::repl::session::ConversationTurn {
+            role: if i % 2 == 0 { "user" } else { "assistant" }.to_string(),
+            content: format!("{:0>100}", i),
+            token_count: Some(10_000),
+            timestamp: chrono::Utc::now(),
+        };
+
+        // Below the limit the history is returned unchanged; REPL token counts are ignored
+        let turns: Vec<_> = (0..19).map(repl_turn).collect();
+        assert_eq!(compactor(turns).await.unwrap().len(), 19);
+        assert!(summarized.lock().await.is_empty());
+
+        let turns: Vec<_> = (0..20).map(repl_turn).collect();
+        let compacted = compactor(turns).await.unwrap();
+        assert_eq!(compacted.len(), KEEP_RECENT_TURNS + 1);
+        assert_eq!(compacted[0].role, SUMMARY_ROLE);
+        assert_eq!(compacted[0].content, "recap of 14 turns");
+        // Kept turns come back verbatim, including their token counts
+        assert_eq!(compacted[1].content, format!("{:0>100}", 14));
+        assert!(compacted[1..].iter().all(|t| t.token_count == Some(10_000)));
+
+        let archived = summarized.lock().await.clone();
+        assert_eq!(archived.len(), 14);
+        assert_eq!(archived[13].turn_number, 14);
+
+        // Numbering continues after the summarized turns when the session ends
+        let window = from_repl_turns(compacted, &archived);
+        assert_eq!(window[0].turn_number, 1);
+        assert_eq!(window[1].turn_number, 15);
+        let history = ChatHistory::restore(window, archived);
+        assert_eq!(history.full().len(), 20);
+        assert_eq!(history.next_turn_number(), 21);
+    }
+
     #[test]
     fn test_token_estimation_logic() {
         // Test the token estimation logic used in run_programmatic_mode
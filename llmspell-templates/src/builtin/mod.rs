@@ -32,7 +32,7 @@ pub use content_generation::ContentGenerationTemplate;
 pub use data_analysis::DataAnalysisTemplate;
 pub use document_processor::DocumentProcessorTemplate;
 pub use file_classification::FileClassificationTemplate;
-pub use interactive_chat::InteractiveChatTemplate;
+pub use interactive_chat::{AgentSummarizer, ConversationSummarizer, InteractiveChatTemplate};
 pub use knowledge_management::KnowledgeManagementTemplate;
 pub use research_assistant::ResearchAssistantTemplate;
 pub use workflow_orchestrator::WorkflowOrchestratorTemplate;
@@ -17,6 +17,7 @@ use crate::{
     validation::{ConfigSchema, ParameterConstraints, ParameterSchema, ParameterType},
 };
 use async_trait::async_trait;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use serde_json::json;
 use std::time::Instant;
 use tracing::{debug, info, warn};
@@ -174,35 +175,60 @@ impl crate::core::Template for ResearchAssistantTemplate {
             params,
         );
 
+        // Phases resumed from a previous partial run are skipped; completed phases
+        // are checkpointed so a failed run can resume from the failing phase
+
         // Phase 1: Gather sources via web search
-        info!("Phase 1: Gathering sources...");
-        let sources = self
-            .gather_sources(&topic, max_sources as usize, &context)
-            .await?;
-        output.metrics.tools_invoked += sources.len();
+        let sources: Vec<Source> = match resumed_phase(&context, "gather") {
+            Some(sources) => sources,
+            None => {
+                info!("Phase 1: Gathering sources...");
+                let sources = self
+                    .gather_sources(&topic, max_sources as usize, &context)
+                    .await?;
+                output.metrics.tools_invoked += sources.len();
+                checkpoint_phase(&context, "gather", &sources).await;
+                sources
+            }
+        };
 
         // Phase 2: Ingest sources into RAG
-        info!("Phase 2: Ingesting sources into RAG...");
-        let session_tag = format!("research-{}", uuid::Uuid::new_v4());
-        let rag_result = self
-            .ingest_sources(&sources, &session_tag, &context)
-            .await?;
-        output.metrics.rag_queries += 1;
+        let rag_result: RagIngestionResult = match resumed_phase(&context, "ingest") {
+            Some(rag_result) => rag_result,
+            None => {
+                info!("Phase 2: Ingesting sources into RAG...");
+                let session_tag = format!("research-{}", uuid::Uuid::new_v4());
+                let rag_result = self
+                    .ingest_sources(&sources, &session_tag, &context)
+                    .await?;
+                output.metrics.rag_queries += 1;
+                checkpoint_phase(&context, "ingest", &rag_result).await;
+                rag_result
+            }
+        };
+        let session_tag = rag_result.session_tag.clone();
 
         // Phase 3: Synthesize findings with agent
-        info!("Phase 3: Synthesizing findings...");
-        let synthesis = self
-            .synthesize_findings(
-                &topic,
-                &session_tag,
-                &provider_config,
-                &context,
-                session_id.as_deref(),
-                memory_enabled,
-                context_budget,
-            )
-            .await?;
-        output.metrics.agents_invoked += 1;
+        let synthesis: String = match resumed_phase(&context, "synthesize") {
+            Some(synthesis) => synthesis,
+            None => {
+                info!("Phase 3: Synthesizing findings...");
+                let synthesis = self
+                    .synthesize_findings(
+                        &topic,
+                        &session_tag,
+                        &provider_config,
+                        &context,
+                        session_id.as_deref(),
+                        memory_enabled,
+                        context_budget,
+                    )
+                    .await?;
+                output.metrics.agents_invoked += 1;
+                checkpoint_phase(&context, "synthesize", &synthesis).await;
+                synthesis
+            }
+        };
 
         // Phase 4: Validate citations
         info!("Phase 4: Validating citations...");
@@ -1005,8 +1031,40 @@ impl ResearchAssistantTemplate {
     }
 }
 
+/// Phase output recorded by a previous partial run, if resuming
+fn resumed_phase<T: DeserializeOwned>(context: &ExecutionContext, phase: &str) -> Option<T> {
+    let artifact = context.resumed_artifact(phase)?;
+    match serde_json::from_str(&artifact.content) {
+        Ok(value) => {
+            info!("Resuming: skipping completed phase '{}'", phase);
+            Some(value)
+        }
+        Err(e) => {
+            warn!(
+                "Ignoring unreadable checkpoint for phase '{}': {}",
+                phase, e
+            );
+            None
+        }
+    }
+}
+
+/// Checkpoint a completed phase's output so a failed run can resume after it
+async fn checkpoint_phase<T: Serialize>(context: &ExecutionContext, phase: &str, value: &T) {
+    if context.checkpoint().is_none() {
+        return;
+    }
+    match serde_json::to_string(value) {
+        Ok(content) => {
+            let artifact = Artifact::json(format!("{}.json", phase), content).with_phase(phase);
+            context.checkpoint_phase(&artifact).await;
+        }
+        Err(e) => warn!("Failed to serialize phase '{}' output: {}", phase, e),
+    }
+}
+
 /// Source document from web search
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 struct Source {
     title: String,
     url: String,
@@ -1017,11 +1075,10 @@ struct Source {
 }
 
 /// RAG ingestion result
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 struct RagIngestionResult {
     count: usize,
-    /// Placeholder for future RAG integration
-    #[allow(dead_code)]
+    /// Tag the ingested sources are stored under
     session_tag: String,
 }
 
@@ -1,6 +1,7 @@
 //! Workflow Orchestrator Template
 //!
 //! User-configurable workflow orchestration with custom patterns:
+//! 0. Optionally generate the workflow configuration from a natural-language description
 //! 1. Parse workflow configuration (agents, tools, execution pattern)
 //! 2. Build dynamic workflow (parallel, sequential, or hybrid)
 //! 3. Execute workflow with state tracking
@@ -29,7 +30,13 @@ use llmspell_workflows::{
 };
 use serde_json::json;
 use std::{collections::HashMap, sync::Arc, time::Instant};
-use tracing::{debug, info};
+use tracing::{debug, info, warn};
+
+/// Execution modes accepted in `execution_mode` and generated workflow specs
+const EXECUTION_MODES: [&str; 4] = ["parallel", "sequential", "hybrid", "loop"];
+
+/// Maximum characters of raw LLM output quoted in generation errors
+const MAX_QUOTED_OUTPUT: usize = 2000;
 
 /// Simple component registry for workflow agents
 /// Enables workflows to resolve agent_id strings to real agent instances
@@ -144,16 +151,31 @@ impl crate::core::Template for WorkflowOrchestratorTemplate {
 
     fn config_schema(&self) -> ConfigSchema {
         let mut params = vec![
-            // workflow_config (required object)
-            ParameterSchema::required(
+            // workflow_config (optional object - required unless workflow_description is given)
+            ParameterSchema::optional(
                 "workflow_config",
                 "Workflow configuration with agents, tools, and execution pattern",
                 ParameterType::Object,
+                json!(null),
             )
             .with_constraints(ParameterConstraints {
                 min_length: Some(1),
                 ..Default::default()
             }),
+            // workflow_description (optional - generate workflow_config with an LLM)
+            ParameterSchema::optional(
+                "workflow_description",
+                "Natural-language description of the pipeline to generate workflow_config from",
+                ParameterType::String,
+                json!(null),
+            ),
+            // confirm_workflow (optional boolean with default)
+            ParameterSchema::optional(
+                "confirm_workflow",
+                "Return a generated workflow spec for review instead of running it",
+                ParameterType::Boolean,
+                json!(true),
+            ),
             // execution_mode (optional enum with default)
             ParameterSchema::optional(
                 "execution_mode",
@@ -162,12 +184,7 @@ impl crate::core::Template for WorkflowOrchestratorTemplate {
                 json!("sequential"),
             )
             .with_constraints(ParameterConstraints {
-                allowed_values: Some(vec![
-                    json!("parallel"),
-                    json!("sequential"),
-                    json!("hybrid"),
-                    json!("loop"),
-                ]),
+                allowed_values: Some(EXECUTION_MODES.iter().map(|mode| json!(mode)).collect()),
                 ..Default::default()
             }),
             // collect_intermediate (optional boolean with default)
@@ -211,6 +228,24 @@ impl crate::core::Template for WorkflowOrchestratorTemplate {
         ConfigSchema::new(params)
     }
 
+    fn validate(&self, params: &TemplateParams) -> Result<()> {
+        self.config_schema().validate(&params.values)?;
+
+        let has_value = |key: &str| {
+            params
+                .get_optional::<serde_json::Value>(key)
+                .is_some_and(|value| !value.is_null())
+        };
+        if !has_value("workflow_config") && !has_value("workflow_description") {
+            return Err(ValidationError::invalid_value(
+                "workflow_config",
+                "either 'workflow_config' or 'workflow_description' is required",
+            )
+            .into());
+        }
+        Ok(())
+    }
+
     async fn execute(
         &self,
         params: TemplateParams,
@@ -219,8 +254,12 @@ impl crate::core::Template for WorkflowOrchestratorTemplate {
         let start_time = Instant::now();
 
         // Extract and validate parameters
-        let workflow_config: serde_json::Value = params.get("workflow_config")?;
-        let execution_mode: String = params.get_or("execution_mode", "sequential".to_string());
+        let workflow_config = params
+            .get_optional::<serde_json::Value>("workflow_config")
+            .filter(|config| !config.is_null());
+        let workflow_description: Option<String> = params.get_optional("workflow_description");
+        let confirm_workflow: bool = params.get_or("confirm_workflow", true);
+        let execution_mode: Option<String> = params.get_optional("execution_mode");
         let collect_intermediate: bool = params.get_or("collect_intermediate", true);
         let max_steps: i64 = params.get_or("max_steps", 10);
 
@@ -236,11 +275,6 @@ impl crate::core::Template for WorkflowOrchestratorTemplate {
             .as_ref()
             .ok_or_else(|| TemplateError::Config("provider missing model".into()))?;
 
-        info!(
-            "Starting workflow orchestration (mode={}, max_steps={}, model={})",
-            execution_mode, max_steps, model_str
-        );
-
         // Initialize output
         let mut output = TemplateOutput::new(
             TemplateResult::text(""), // Will be replaced
@@ -249,6 +283,50 @@ impl crate::core::Template for WorkflowOrchestratorTemplate {
             params,
         );
 
+        // Phase 0: Generate the workflow configuration from a description if none was given
+        let (workflow_config, generated) = match (workflow_config, workflow_description) {
+            (Some(config), _) => (config, false),
+            (None, Some(description)) => {
+                info!("Phase 0: Generating workflow from description...");
+                let spec = self
+                    .generate_workflow_spec(&description, max_steps, &provider_config, &context)
+                    .await?;
+
+                if confirm_workflow {
+                    info!("Generated workflow spec returned for confirmation");
+                    self.present_generated_workflow(&spec, &context, &mut output)?;
+                    output.set_duration(start_time.elapsed().as_millis() as u64);
+                    output.add_metric("workflow_generated", json!(true));
+                    output.add_metric("awaiting_confirmation", json!(true));
+                    return Ok(output);
+                }
+                (spec, true)
+            }
+            (None, None) => {
+                return Err(ValidationError::invalid_value(
+                    "workflow_config",
+                    "either 'workflow_config' or 'workflow_description' is required",
+                )
+                .into())
+            }
+        };
+
+        // A generated spec may choose its mode; an explicit parameter always wins
+        let execution_mode = execution_mode
+            .or_else(|| {
+                workflow_config
+                    .get("execution_mode")
+                    .and_then(|mode| mode.as_str())
+                    .filter(|_| generated)
+                    .map(str::to_string)
+            })
+            .unwrap_or_else(|| "sequential".to_string());
+
+        info!(
+            "Starting workflow orchestration (mode={}, max_steps={}, model={})",
+            execution_mode, max_steps, model_str
+        );
+
         // Phase 1: Parse and validate workflow configuration
         info!("Phase 1: Parsing workflow configuration...");
         let workflow = self.parse_workflow(&workflow_config)?;
@@ -296,6 +374,7 @@ impl crate::core::Template for WorkflowOrchestratorTemplate {
         output.add_metric("workflow_steps", json!(workflow.steps.len()));
         output.add_metric("execution_mode", json!(execution_mode));
         output.add_metric("steps_executed", json!(execution_result.steps_executed));
+        output.add_metric("workflow_generated", json!(generated));
 
         info!(
             "Workflow orchestration complete (duration: {}ms, steps: {})",
@@ -375,6 +454,187 @@ impl crate::core::Template for WorkflowOrchestratorTemplate {
 }
 
 impl WorkflowOrchestratorTemplate {
+    /// Phase 0: Ask an LLM to turn a pipeline description into a workflow spec
+    async fn generate_workflow_spec(
+        &self,
+        description: &str,
+        max_steps: i64,
+        provider_config: &llmspell_config::ProviderConfig,
+        context: &ExecutionContext,
+    ) -> Result<serde_json::Value> {
+        let model = provider_config
+            .default_model
+            .as_ref()
+            .ok_or_else(|| TemplateError::Config("provider missing model".into()))?;
+        let (provider, model_id) = parse_model_spec(model);
+
+        let agent_config = AgentConfig {
+            name: "workflow-planner-agent".to_string(),
+            description: "Generates workflow specs from pipeline descriptions".to_string(),
+            agent_type: "llm".to_string(),
+            model: Some(ModelConfig {
+                provider,
+                model_id,
+                temperature: provider_config.temperature.or(Some(0.2)), // Structured output
+                max_tokens: provider_config.max_tokens.or(Some(1500)),
+                settings: serde_json::Map::new(),
+            }),
+            allowed_tools: vec![],
+            custom_config: serde_json::Map::new(),
+            resource_limits: ResourceLimits {
+                max_execution_time_secs: 120,
+                max_memory_mb: 256,
+                max_tool_calls: 0,
+                max_recursion_depth: 1,
+            },
+        };
+
+        let agent = context
+            .agent_registry()
+            .create_agent(agent_config)
+            .await
+            .map_err(|e| {
+                warn!("Failed to create workflow planner agent: {}", e);
+                TemplateError::ExecutionFailed(format!("Agent creation failed: {}", e))
+            })?;
+
+        let prompt = format!(
+            "You are a workflow planner. Convert the pipeline description below into a workflow spec.\n\n\
+             **DESCRIPTION**:\n{}\n\n\
+             Respond with ONLY a JSON object of this form (no explanations):\n\
+             {{\n  \"name\": \"short-kebab-case-name\",\n  \"execution_mode\": \"sequential\",\n  \
+             \"steps\": [\n    {{\"step_type\": \"agent\", \"name\": \"step-name\", \
+             \"description\": \"what the step does\"}}\n  ]\n}}\n\n\
+             Rules:\n\
+             - execution_mode is one of: {}\n\
+             - step_type is \"agent\" for reasoning or writing, \"tool\" for tool operations\n\
+             - Use at most {} steps",
+            description,
+            EXECUTION_MODES.join(", "),
+            max_steps
+        );
+
+        let agent_output = agent
+            .execute(
+                AgentInput::builder().text(prompt).build(),
+                llmspell_core::ExecutionContext::default(),
+            )
+            .await
+            .map_err(|e| {
+                warn!("Workflow planner agent execution failed: {}", e);
+                TemplateError::ExecutionFailed(format!("Agent execution failed: {}", e))
+            })?;
+
+        self.parse_generated_workflow(&agent_output.text)
+    }
+
+    /// Parse and validate an LLM-generated workflow spec, reporting every problem found
+    fn parse_generated_workflow(&self, raw: &str) -> Result<serde_json::Value> {
+        // Models often wrap the object in prose or a code fence
+        let json_text = match (raw.find('{'), raw.rfind('}')) {
+            (Some(start), Some(end)) if start < end => &raw[start..=end],
+            _ => raw.trim(),
+        };
+
+        let errors = match serde_json::from_str::<serde_json::Value>(json_text) {
+            Ok(spec) => {
+                let errors = self.validate_generated_workflow(&spec);
+                if errors.is_empty() {
+                    return Ok(spec);
+                }
+                errors
+            }
+            Err(e) => vec![format!("output is not valid JSON: {}", e)],
+        };
+
+        let quoted: String = raw.chars().take(MAX_QUOTED_OUTPUT).collect();
+        Err(TemplateError::ExecutionFailed(format!(
+            "Generated workflow spec is invalid:\n- {}\n\nLLM output:\n{}",
+            errors.join("\n- "),
+            quoted
+        )))
+    }
+
+    /// Check a generated spec against what `parse_workflow` and the execution modes accept
+    fn validate_generated_workflow(&self, spec: &serde_json::Value) -> Vec<String> {
+        let Some(spec) = spec.as_object() else {
+            return vec!["spec must be a JSON object".to_string()];
+        };
+
+        let mut errors = Vec::new();
+        if let Some(mode) = spec.get("execution_mode") {
+            if !mode
+                .as_str()
+                .is_some_and(|mode| EXECUTION_MODES.contains(&mode))
+            {
+                errors.push(format!(
+                    "execution_mode must be one of {}, got {}",
+                    EXECUTION_MODES.join(", "),
+                    mode
+                ));
+            }
+        }
+
+        match spec.get("steps").and_then(|steps| steps.as_array()) {
+            None => errors.push("spec must contain a 'steps' array".to_string()),
+            Some(steps) if steps.is_empty() => {
+                errors.push("'steps' must have at least one step".to_string())
+            }
+            Some(steps) => {
+                for (idx, step) in steps.iter().enumerate() {
+                    let Some(step_type) = step.get("step_type") else {
+                        errors.push(format!("steps[{}] is missing 'step_type'", idx));
+                        continue;
+                    };
+                    match self.parse_step_type(step_type, idx) {
+                        Ok(_) => {}
+                        Err(TemplateError::ValidationFailed(e)) => errors.push(e.to_string()),
+                        Err(e) => errors.push(e.to_string()),
+                    }
+                }
+            }
+        }
+        errors
+    }
+
+    /// Return a generated spec for review, as the result and a JSON artifact
+    fn present_generated_workflow(
+        &self,
+        spec: &serde_json::Value,
+        context: &ExecutionContext,
+        output: &mut TemplateOutput,
+    ) -> Result<()> {
+        let spec_json = serde_json::to_string_pretty(spec).map_err(|e| {
+            TemplateError::ExecutionFailed(format!("Failed to serialize workflow spec: {}", e))
+        })?;
+
+        if let Some(output_dir) = &context.output_dir {
+            std::fs::create_dir_all(output_dir).map_err(|e| {
+                TemplateError::ExecutionFailed(format!("Failed to create output directory: {}", e))
+            })?;
+            let spec_path = output_dir.join("generated_workflow.json");
+            std::fs::write(&spec_path, &spec_json).map_err(|e| {
+                TemplateError::ExecutionFailed(format!("Failed to write workflow spec: {}", e))
+            })?;
+            output.add_artifact(Artifact::new(
+                spec_path.to_string_lossy().to_string(),
+                spec_json.clone(),
+                "application/json".to_string(),
+            ));
+        } else {
+            output.add_artifact(Artifact::json("generated_workflow.json", spec_json.clone()));
+        }
+
+        output.result = TemplateResult::text(format!(
+            "# Generated Workflow\n\n\
+             Review the spec below. To run it, pass it as `workflow_config`, \
+             or re-run with `confirm_workflow` set to false.\n\n\
+             ```json\n{}\n```\n",
+            spec_json
+        ));
+        Ok(())
+    }
+
     /// Phase 1: Parse workflow configuration
     fn parse_workflow(&self, config: &serde_json::Value) -> Result<WorkflowDefinition> {
         info!("Parsing workflow configuration from JSON");
@@ -1072,9 +1332,10 @@ mod tests {
         assert!(schema.get_parameter("max_steps").is_some());
         assert!(schema.get_parameter("model").is_some());
 
-        // workflow_config is required
+        // workflow_config is optional since it can be generated from workflow_description
         let config_param = schema.get_parameter("workflow_config").unwrap();
-        assert!(config_param.required);
+        assert!(!config_param.required);
+        assert!(schema.get_parameter("workflow_description").is_some());
 
         // others are optional
         let mode_param = schema.get_parameter("execution_mode").unwrap();
@@ -1111,12 +1372,15 @@ mod tests {
     #[test]
     fn test_parameter_validation_missing_required() {
         let template = WorkflowOrchestratorTemplate::new();
-        let schema = template.config_schema();
-        let params = std::collections::HashMap::new();
+        let params = TemplateParams::new();
 
-        // Should fail - missing required "workflow_config" parameter
-        let result = schema.validate(&params);
+        // Should fail - neither "workflow_config" nor "workflow_description" given
+        let result = template.validate(&params);
         assert!(result.is_err());
+
+        let mut params = TemplateParams::new();
+        params.insert("workflow_description", json!("Summarize, then translate"));
+        assert!(template.validate(&params).is_ok());
     }
 
     #[test]
@@ -1281,6 +1545,181 @@ mod tests {
         assert!(execution.intermediate_results.is_some());
     }
 
+    #[test]
+    fn test_parse_generated_workflow_reports_all_errors() {
+        let template = WorkflowOrchestratorTemplate::new();
+
+        let fenced = "Here you go:\n```json\n{\"steps\": [{\"step_type\": \"agent\"}]}\n```";
+        assert!(template.parse_generated_workflow(fenced).is_ok());
+
+        let err = template
+            .parse_generated_workflow("I cannot do that")
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("not valid JSON"));
+        assert!(err.contains("I cannot do that"));
+
+        let err = template
+            .parse_generated_workflow(
+                r#"{"execution_mode": "batch", "steps": [{"step_type": "agent"}, {"step_type": "robot"}, {}]}"#,
+            )
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("execution_mode must be one of"));
+        assert!(err.contains("steps[1].step_type"));
+        assert!(err.contains("steps[2] is missing 'step_type'"));
+    }
+
+    mod planner {
+        use llmspell_agents::factory::{AgentConfig, AgentFactory};
+        use llmspell_agents::testing::mocks::MockAgentBuilder;
+        use llmspell_core::Agent;
+        use std::sync::Arc;
+
+        /// Agent factory whose planner agent answers with a fixed spec
+        pub struct MockAgentFactory {
+            pub plan: String,
+        }
+
+        #[async_trait::async_trait]
+        impl AgentFactory for MockAgentFactory {
+            async fn create_agent(&self, config: AgentConfig) -> anyhow::Result<Arc<dyn Agent>> {
+                let response = if config.name == "workflow-planner-agent" {
+                    self.plan.as_str()
+                } else {
+                    "step done"
+                };
+                Ok(Arc::new(
+                    MockAgentBuilder::new(&config.name)
+                        .with_response(None, response)
+                        .build(),
+                ))
+            }
+
+            async fn create_from_template(&self, name: &str) -> anyhow::Result<Arc<dyn Agent>> {
+                anyhow::bail!("No template '{}'", name)
+            }
+
+            fn list_templates(&self) -> Vec<&str> {
+                vec![]
+            }
+
+            fn validate_config(&self, _config: &AgentConfig) -> anyhow::Result<()> {
+                Ok(())
+            }
+        }
+
+        pub async fn context(plan: &str) -> crate::context::ExecutionContext {
+            let agents = llmspell_agents::FactoryRegistry::new();
+            agents
+                .register_factory(
+                    "mock".to_string(),
+                    Arc::new(MockAgentFactory {
+                        plan: plan.to_string(),
+                    }),
+                )
+                .await
+                .unwrap();
+
+            crate::context::ExecutionContext::builder()
+                .with_tool_registry(Arc::new(llmspell_tools::ToolRegistry::new()))
+                .with_agent_registry(Arc::new(agents))
+                .with_workflow_factory(Arc::new(llmspell_workflows::DefaultWorkflowFactory::new()))
+                .with_providers(Arc::new(llmspell_providers::ProviderManager::new()))
+                .with_provider_config(Arc::new(
+                    llmspell_config::providers::ProviderManagerConfig::default(),
+                ))
+                .build()
+                .unwrap()
+        }
+    }
+
+    fn description_params(confirm: Option<bool>) -> TemplateParams {
+        let mut params = TemplateParams::new();
+        params.insert(
+            "workflow_description",
+            json!("Research a topic, then write a summary"),
+        );
+        params.insert("model", json!("mock/planner"));
+        params.insert("memory_enabled", json!(false));
+        if let Some(confirm) = confirm {
+            params.insert("confirm_workflow", json!(confirm));
+        }
+        params
+    }
+
+    #[tokio::test]
+    async fn test_generated_workflow_is_confirmed_then_executed() {
+        let template = WorkflowOrchestratorTemplate::new();
+        let plan = r#"{
+            "name": "research-and-summarize",
+            "execution_mode": "sequential",
+            "steps": [
+                {"step_type": "agent", "name": "research", "description": "Research the topic"},
+                {"step_type": "agent", "name": "summarize", "description": "Write a summary"}
+            ]
+        }"#;
+
+        // By default the generated spec is shown for confirmation, not run
+        let output = template
+            .execute(description_params(None), planner::context(plan).await)
+            .await
+            .unwrap();
+        let TemplateResult::Text(text) = &output.result else {
+            panic!("expected text result");
+        };
+        assert!(text.contains("research-and-summarize"));
+        assert_eq!(
+            output.metrics.custom_metrics.get("awaiting_confirmation"),
+            Some(&json!(true))
+        );
+        assert_eq!(output.metrics.agents_invoked, 0);
+        assert!(output
+            .artifacts
+            .iter()
+            .any(|a| a.filename == "generated_workflow.json"));
+
+        // Once confirmed, the spec parses into steps and runs
+        let output = template
+            .execute(
+                description_params(Some(false)),
+                planner::context(plan).await,
+            )
+            .await
+            .unwrap();
+        assert_eq!(output.metrics.agents_invoked, 2);
+        assert_eq!(
+            output.metrics.custom_metrics.get("workflow_generated"),
+            Some(&json!(true))
+        );
+        assert_eq!(
+            output.metrics.custom_metrics.get("execution_mode"),
+            Some(&json!("sequential"))
+        );
+        let TemplateResult::Text(report) = &output.result else {
+            panic!("expected text result");
+        };
+        assert!(report.contains("# Workflow Execution Report"));
+        assert!(report.contains("research-and-summarize"));
+    }
+
+    #[tokio::test]
+    async fn test_invalid_generated_workflow_reports_errors() {
+        let template = WorkflowOrchestratorTemplate::new();
+
+        let err = template
+            .execute(
+                description_params(Some(false)),
+                planner::context("Sure! Step one: research.").await,
+            )
+            .await
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("Generated workflow spec is invalid"));
+        assert!(err.contains("not valid JSON"));
+        assert!(err.contains("Sure! Step one: research."));
+    }
+
     #[test]
     fn test_aggregate_results() {
         let template = WorkflowOrchestratorTemplate::new();
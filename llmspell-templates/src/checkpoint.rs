@@ -0,0 +1,478 @@
+//! Phase checkpointing and resuming of partial template runs
+
+use crate::{
+    artifacts::{Artifact, ArtifactCollection},
+    context::ExecutionContext,
+    core::{Template, TemplateOutput, TemplateParams},
+    error::{Result, TemplateError, ValidationError},
+};
+use llmspell_core::{
+    state::StateScope,
+    traits::tool::{ResourceLimits, SecurityRequirements},
+};
+use llmspell_kernel::state::StateManager;
+use llmspell_security::sandbox::{FileSandbox, SandboxContext};
+use llmspell_utils::encoding::{hash_string, to_hex_string, HashAlgorithm};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use tracing::{debug, warn};
+
+/// Parameter selecting what a run resumes from
+///
+/// `true` resumes from the template's checkpoint, a string names a saved artifact collection
+/// inside the resume directory.
+pub const RESUME_PARAM: &str = "resume";
+
+/// State key prefix for persisted checkpoints
+const CHECKPOINT_KEY_PREFIX: &str = "template:checkpoint:";
+
+/// Where a run resumes from, as selected by [`RESUME_PARAM`]
+#[derive(Debug, Clone)]
+pub enum ResumeFrom {
+    /// Phases completed by the last run with the same parameters
+    Checkpoint,
+
+    /// Artifacts saved from a previous partial run
+    Artifacts(Vec<Artifact>),
+}
+
+/// Persisted phase outputs of a template run
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct CheckpointRecord {
+    params_hash: String,
+    artifacts: Vec<Artifact>,
+}
+
+/// Checkpoint of one template run, persisted in the state manager
+///
+/// Templates record each phase's artifact as the phase completes (see
+/// [`ExecutionContext::checkpoint_phase`]). A checkpoint is keyed by the hash of the
+/// parameters it was recorded with, so runs with other parameters neither see nor
+/// clear it.
+#[derive(Clone)]
+pub struct PhaseCheckpoint {
+    state: Arc<StateManager>,
+    template_id: String,
+    params_hash: String,
+}
+
+impl PhaseCheckpoint {
+    /// Create the checkpoint for running `template_id` with `params`
+    pub fn new(state: Arc<StateManager>, template_id: &str, params: &TemplateParams) -> Self {
+        Self {
+            state,
+            template_id: template_id.to_string(),
+            params_hash: params_hash(params),
+        }
+    }
+
+    /// Hash of the parameters this checkpoint belongs to
+    pub fn params_hash(&self) -> &str {
+        &self.params_hash
+    }
+
+    fn key(&self) -> String {
+        format!(
+            "{}{}:{}",
+            CHECKPOINT_KEY_PREFIX, self.template_id, self.params_hash
+        )
+    }
+
+    /// Load the stored record for these parameters
+    async fn record(&self) -> Result<CheckpointRecord> {
+        let stored: Option<CheckpointRecord> = self
+            .state
+            .get(StateScope::Global, &self.key())
+            .await?
+            .and_then(|value| serde_json::from_value(value).ok());
+
+        Ok(stored
+            .filter(|record| record.params_hash == self.params_hash)
+            .unwrap_or_default())
+    }
+
+    /// Artifacts of the phases completed with these parameters
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the state manager fails
+    pub async fn load(&self) -> Result<Vec<Artifact>> {
+        Ok(self.record().await?.artifacts)
+    }
+
+    /// Persist a completed phase's artifact, replacing any earlier one for the same phase
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the artifact has no phase or the state manager fails
+    pub async fn save_phase(&self, artifact: &Artifact) -> Result<()> {
+        let phase = artifact.phase().ok_or_else(|| {
+            TemplateError::ExecutionFailed(format!(
+                "Cannot checkpoint artifact '{}' without a phase",
+                artifact.filename
+            ))
+        })?;
+
+        let mut record = self.record().await?;
+        record.params_hash.clone_from(&self.params_hash);
+        record.artifacts.retain(|a| a.phase() != Some(phase));
+        record.artifacts.push(artifact.clone());
+
+        let value = serde_json::to_value(&record)
+            .map_err(|e| TemplateError::SerializationError(e.to_string()))?;
+        self.state
+            .set(StateScope::Global, &self.key(), value)
+            .await?;
+        debug!(
+            "Checkpointed phase '{}' of template '{}'",
+            phase, self.template_id
+        );
+        Ok(())
+    }
+
+    /// Remove the checkpoint for these parameters
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the state manager fails
+    pub async fn clear(&self) -> Result<()> {
+        self.state.delete(StateScope::Global, &self.key()).await?;
+        Ok(())
+    }
+}
+
+/// Stable hash of template parameters, ignoring [`RESUME_PARAM`]
+pub fn params_hash(params: &TemplateParams) -> String {
+    let sorted: BTreeMap<_, _> = params
+        .values
+        .iter()
+        .filter(|(key, _)| key.as_str() != RESUME_PARAM)
+        .collect();
+    // Top-level keys are sorted so the hash does not depend on map iteration order
+    let canonical = serde_json::to_string(&sorted).unwrap_or_default();
+    to_hex_string(&hash_string(&canonical, HashAlgorithm::Sha256))
+}
+
+/// Remove the [`RESUME_PARAM`] parameter and resolve what it resumes from
+///
+/// A saved artifact collection path is resolved against `resume_dir` and must stay
+/// inside it, so a run can only resume from collections in its own output directory.
+///
+/// # Errors
+///
+/// Returns error if the parameter is neither a boolean nor a path, the path is outside
+/// `resume_dir` (or there is none), or the saved artifact collection cannot be loaded
+pub fn take_resume_param(
+    params: &mut TemplateParams,
+    resume_dir: Option<&Path>,
+) -> Result<Option<ResumeFrom>> {
+    match params.values.remove(RESUME_PARAM) {
+        None | Some(serde_json::Value::Bool(false)) => Ok(None),
+        Some(serde_json::Value::Bool(true)) => Ok(Some(ResumeFrom::Checkpoint)),
+        Some(serde_json::Value::String(path)) => {
+            let resolved = resolve_resume_path(resume_dir, &path)?;
+            let collection = ArtifactCollection::load(&resolved)?;
+            debug!(
+                "Resuming from {} artifacts in {}",
+                collection.count(),
+                resolved.display()
+            );
+            Ok(Some(ResumeFrom::Artifacts(collection.into())))
+        }
+        Some(other) => {
+            let actual = other.to_string();
+            Err(ValidationError::type_mismatch(RESUME_PARAM, "boolean or path", actual).into())
+        }
+    }
+}
+
+/// Resolve a saved artifact collection path, confined to `resume_dir` by a file sandbox
+fn resolve_resume_path(resume_dir: Option<&Path>, path: &str) -> Result<PathBuf> {
+    let invalid = |message: String| ValidationError::invalid_value(RESUME_PARAM, message);
+    let resume_dir = resume_dir.ok_or_else(|| {
+        invalid("resuming from saved artifacts requires an output directory".to_string())
+    })?;
+    let base = resume_dir.canonicalize().map_err(|e| {
+        invalid(format!(
+            "resume directory {} is not accessible: {}",
+            resume_dir.display(),
+            e
+        ))
+    })?;
+
+    let mut sandbox_context = SandboxContext::new(
+        format!("template-resume-{}", uuid::Uuid::new_v4()),
+        SecurityRequirements::default().with_file_access(format!(
+            "{}{}",
+            base.display(),
+            std::path::MAIN_SEPARATOR
+        )),
+        ResourceLimits::default(),
+    );
+    sandbox_context.working_directory = base.to_string_lossy().into_owned();
+    let sandbox = FileSandbox::new(sandbox_context)
+        .map_err(|e| TemplateError::ExecutionFailed(format!("Failed to create sandbox: {}", e)))?;
+    let validated = sandbox
+        .validate_path(Path::new(path))
+        .map_err(|_| invalid(format!("'{}' is outside {}", path, base.display())))?;
+
+    // Symlinks inside the directory must not lead out of it either
+    let resolved = validated.canonicalize()?;
+    if !resolved.starts_with(&base) {
+        return Err(invalid(format!("'{}' is outside {}", path, base.display())).into());
+    }
+    Ok(resolved)
+}
+
+/// Execute a template with checkpointing, resuming as requested
+///
+/// When the context has a state manager, completed phases are checkpointed and the
+/// checkpoint is removed once the run succeeds. A run that does not resume starts over,
+/// discarding only the checkpoint recorded with the same parameters.
+/// The context's artifact processors are applied to the output's artifacts.
+///
+/// # Errors
+///
+/// Returns error if resuming from a checkpoint without a state manager, the checkpoint
+/// cannot be loaded, or the template fails
+pub async fn execute_resumable(
+    template: &dyn Template,
+    params: TemplateParams,
+    context: ExecutionContext,
+    resume: Option<ResumeFrom>,
+) -> Result<TemplateOutput> {
+    let checkpoint = context
+        .state_manager()
+        .map(|state| PhaseCheckpoint::new(state.clone(), &template.metadata().id, &params));
+
+    let prior = match resume {
+        Some(ResumeFrom::Artifacts(artifacts)) => Some(artifacts),
+        Some(ResumeFrom::Checkpoint) => {
+            let checkpoint = checkpoint.as_ref().ok_or_else(|| {
+                TemplateError::InfrastructureUnavailable(
+                    "StateManager required to resume from a checkpoint".to_string(),
+                )
+            })?;
+            Some(checkpoint.load().await?)
+        }
+        None => {
+            if let Some(checkpoint) = &checkpoint {
+                checkpoint.clear().await?;
+            }
+            None
+        }
+    };
+
+    let context = match &checkpoint {
+        Some(checkpoint) => context.with_checkpoint(checkpoint.clone()),
+        None => context,
+    };
+    let processing = context.clone();
+    let mut output = match prior {
+        Some(prior) => template.resume(params, context, prior).await?,
+        None => template.execute(params, context).await?,
+    };
+    processing.process_artifacts(&mut output);
+
+    // A completed run has nothing left to resume
+    if let Some(checkpoint) = checkpoint {
+        if let Err(e) = checkpoint.clear().await {
+            warn!("Failed to clear checkpoint: {}", e);
+        }
+    }
+    Ok(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{TemplateCategory, TemplateMetadata, TemplateResult};
+    use crate::registry::TemplateRegistry;
+    use crate::validation::ConfigSchema;
+    use async_trait::async_trait;
+    use serde_json::json;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    const PHASES: [&str; 4] = ["gather", "ingest", "synthesize", "validate"];
+
+    /// Four-phase template counting phase executions, optionally failing one phase once
+    #[derive(Debug)]
+    struct FlakyPhasedTemplate {
+        metadata: TemplateMetadata,
+        runs: [AtomicUsize; 4],
+        /// 1-based phase to fail on its next execution, 0 for none
+        fail_phase: AtomicUsize,
+    }
+
+    impl FlakyPhasedTemplate {
+        fn new() -> Self {
+            Self {
+                metadata: TemplateMetadata {
+                    id: "flaky-phased".to_string(),
+                    name: "Flaky Phased".to_string(),
+                    description: "Test template with four phases".to_string(),
+                    category: TemplateCategory::Workflow,
+                    version: "0.1.0".to_string(),
+                    author: None,
+                    requires: vec![],
+                    tags: vec![],
+                },
+                runs: Default::default(),
+                fail_phase: AtomicUsize::new(0),
+            }
+        }
+
+        fn runs(&self) -> Vec<usize> {
+            self.runs.iter().map(|r| r.load(Ordering::SeqCst)).collect()
+        }
+    }
+
+    #[async_trait]
+    impl Template for FlakyPhasedTemplate {
+        fn metadata(&self) -> &TemplateMetadata {
+            &self.metadata
+        }
+
+        fn config_schema(&self) -> ConfigSchema {
+            ConfigSchema::new(vec![])
+        }
+
+        async fn execute(
+            &self,
+            params: TemplateParams,
+            context: ExecutionContext,
+        ) -> Result<TemplateOutput> {
+            let topic: String = params.get("topic")?;
+            let mut artifacts = Vec::new();
+            for (index, phase) in PHASES.iter().enumerate() {
+                if let Some(artifact) = context.resumed_artifact(phase) {
+                    artifacts.push(artifact.clone());
+                    continue;
+                }
+
+                self.runs[index].fetch_add(1, Ordering::SeqCst);
+                if self.fail_phase.load(Ordering::SeqCst) == index + 1 {
+                    self.fail_phase.store(0, Ordering::SeqCst);
+                    return Err(TemplateError::ExecutionFailed(format!("{} failed", phase)));
+                }
+
+                let artifact =
+                    Artifact::text(format!("{}.txt", phase), format!("{}: {}", phase, topic))
+                        .with_phase(*phase);
+                context.checkpoint_phase(&artifact).await;
+                artifacts.push(artifact);
+            }
+
+            let mut output = TemplateOutput::new(
+                TemplateResult::text(topic),
+                self.metadata.id.clone(),
+                self.metadata.version.clone(),
+                params,
+            );
+            for artifact in artifacts {
+                output.add_artifact(artifact);
+            }
+            Ok(output)
+        }
+    }
+
+    fn context(state: Arc<StateManager>) -> ExecutionContext {
+        ExecutionContext::builder()
+            .with_tool_registry(Arc::new(llmspell_tools::ToolRegistry::new()))
+            .with_agent_registry(Arc::new(llmspell_agents::FactoryRegistry::new()))
+            .with_workflow_factory(Arc::new(llmspell_workflows::DefaultWorkflowFactory::new()))
+            .with_providers(Arc::new(llmspell_providers::ProviderManager::new()))
+            .with_provider_config(Arc::new(
+                llmspell_config::providers::ProviderManagerConfig::default(),
+            ))
+            .with_state_manager(state)
+            .build()
+            .expect("Failed to build context")
+    }
+
+    fn params(topic: &str, resume: bool) -> TemplateParams {
+        TemplateParams::from(json!({"topic": topic, RESUME_PARAM: resume}))
+    }
+
+    #[tokio::test]
+    async fn test_resume_skips_checkpointed_phases() {
+        let state = Arc::new(StateManager::new(None).await.unwrap());
+        let registry = TemplateRegistry::new();
+        let template = Arc::new(FlakyPhasedTemplate::new());
+        registry.register(template.clone()).unwrap();
+        let id = "flaky-phased";
+
+        // Phase 3 fails after phases 1-2 were checkpointed
+        template.fail_phase.store(3, Ordering::SeqCst);
+        let result = registry
+            .execute(id, params("rust", false), context(state.clone()))
+            .await;
+        assert!(result.is_err());
+        assert_eq!(template.runs(), vec![1, 1, 1, 0]);
+
+        // Resuming skips phases 1-2 and continues from phase 3
+        let output = registry
+            .execute(id, params("rust", true), context(state.clone()))
+            .await
+            .unwrap();
+        assert_eq!(template.runs(), vec![1, 1, 2, 1]);
+        let phases: Vec<_> = output.artifacts.iter().filter_map(|a| a.phase()).collect();
+        assert_eq!(phases, PHASES);
+        assert_eq!(output.artifacts[0].content, "gather: rust");
+
+        // A completed run leaves no checkpoint behind
+        let checkpoint = PhaseCheckpoint::new(state.clone(), id, &params("rust", false));
+        assert!(checkpoint.load().await.unwrap().is_empty());
+
+        // Changed params invalidate the checkpoint, so every phase runs again
+        template.fail_phase.store(3, Ordering::SeqCst);
+        let result = registry
+            .execute(id, params("rust", false), context(state.clone()))
+            .await;
+        assert!(result.is_err());
+        registry
+            .execute(id, params("go", true), context(state.clone()))
+            .await
+            .unwrap();
+        assert_eq!(template.runs(), vec![3, 3, 4, 2]);
+        assert_ne!(
+            params_hash(&params("rust", true)),
+            params_hash(&params("go", true))
+        );
+        assert_eq!(
+            params_hash(&params("rust", true)),
+            params_hash(&params("rust", false))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_interleaved_runs_keep_separate_checkpoints() {
+        let state = Arc::new(StateManager::new(None).await.unwrap());
+        let registry = TemplateRegistry::new();
+        let template = Arc::new(FlakyPhasedTemplate::new());
+        registry.register(template.clone()).unwrap();
+        let id = "flaky-phased";
+
+        // Both runs fail at phase 3, the second one starting over with other params
+        for topic in ["rust", "go"] {
+            template.fail_phase.store(3, Ordering::SeqCst);
+            let result = registry
+                .execute(id, params(topic, false), context(state.clone()))
+                .await;
+            assert!(result.is_err());
+        }
+        assert_eq!(template.runs(), vec![2, 2, 2, 0]);
+
+        // Each run resumes from its own checkpoint
+        for topic in ["rust", "go"] {
+            let output = registry
+                .execute(id, params(topic, true), context(state.clone()))
+                .await
+                .unwrap();
+            assert_eq!(output.artifacts[0].content, format!("gather: {}", topic));
+        }
+        assert_eq!(template.runs(), vec![2, 2, 4, 2]);
+    }
+}
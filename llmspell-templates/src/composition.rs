@@ -0,0 +1,189 @@
+//! Template composition - invoking one template from another
+
+use crate::{
+    context::ExecutionContext,
+    core::{TemplateOutput, TemplateParams},
+    error::{Result, TemplateError},
+};
+use std::collections::HashMap;
+use tracing::debug;
+
+/// A step that executes another template as part of a parent template
+///
+/// The sub-template is resolved from the context's template registry when the step runs.
+/// Its parameters are built from fixed values plus values mapped from the parent's
+/// parameters. Merge the returned output into the parent's with [`TemplateOutput::absorb`]
+/// to propagate the sub-template's artifacts.
+#[derive(Debug, Clone)]
+pub struct TemplateStep {
+    /// ID of the template to invoke
+    template_id: String,
+
+    /// Parent parameter name -> sub-template parameter name
+    mappings: Vec<(String, String)>,
+
+    /// Fixed sub-template parameter values
+    fixed: HashMap<String, serde_json::Value>,
+}
+
+impl TemplateStep {
+    /// Create a step invoking the template with the given ID
+    pub fn new(template_id: impl Into<String>) -> Self {
+        Self {
+            template_id: template_id.into(),
+            mappings: Vec::new(),
+            fixed: HashMap::new(),
+        }
+    }
+
+    /// Pass the parent's `from` parameter to the sub-template as `to`
+    pub fn map_param(mut self, from: impl Into<String>, to: impl Into<String>) -> Self {
+        self.mappings.push((from.into(), to.into()));
+        self
+    }
+
+    /// Set a fixed sub-template parameter value
+    pub fn with_param(mut self, name: impl Into<String>, value: serde_json::Value) -> Self {
+        self.fixed.insert(name.into(), value);
+        self
+    }
+
+    /// ID of the template this step invokes
+    pub fn template_id(&self) -> &str {
+        &self.template_id
+    }
+
+    /// Build the sub-template's parameters from the parent's
+    ///
+    /// Mapped values override fixed ones; mappings whose source is absent are skipped.
+    pub fn child_params(&self, params: &TemplateParams) -> TemplateParams {
+        let mut child = TemplateParams::from(self.fixed.clone());
+        for (from, to) in &self.mappings {
+            if let Some(value) = params.values.get(from) {
+                child.insert(to.clone(), value.clone());
+            }
+        }
+        child
+    }
+
+    /// Resolve and execute the sub-template on behalf of the `caller` template
+    ///
+    /// # Errors
+    ///
+    /// Returns `RecursiveComposition` if the sub-template is already executing in this
+    /// call chain, `NotFound` if it is not registered, or any validation or execution
+    /// error from the sub-template itself.
+    pub async fn execute(
+        &self,
+        caller: &str,
+        params: &TemplateParams,
+        context: &ExecutionContext,
+    ) -> Result<TemplateOutput> {
+        let mut stack = context.template_stack().to_vec();
+        if stack.last().map(String::as_str) != Some(caller) {
+            stack.push(caller.to_string());
+        }
+        if stack.contains(&self.template_id) {
+            stack.push(self.template_id.clone());
+            return Err(TemplateError::RecursiveComposition(stack.join(" -> ")));
+        }
+
+        let template = context.template_registry().get(&self.template_id)?;
+        let child_params = self.child_params(params);
+        template.validate(&child_params)?;
+
+        debug!(
+            "Template '{}' invoking sub-template '{}'",
+            caller, self.template_id
+        );
+        stack.push(self.template_id.clone());
+        let mut child_context = context.clone();
+        child_context.template_stack = stack;
+        // Resume artifacts and checkpoints are keyed by the parent's phases
+        child_context.resume_artifacts.clear();
+        child_context.checkpoint = None;
+
+        template.execute(child_params, child_context).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{Template, TemplateResult};
+    use crate::registry::TemplateRegistry;
+    use crate::testing::{NotesTemplate, StepTemplate};
+    use serde_json::json;
+    use std::sync::Arc;
+
+    fn context(registry: Arc<TemplateRegistry>) -> ExecutionContext {
+        ExecutionContext::builder()
+            .with_tool_registry(Arc::new(llmspell_tools::ToolRegistry::new()))
+            .with_agent_registry(Arc::new(llmspell_agents::FactoryRegistry::new()))
+            .with_workflow_factory(Arc::new(llmspell_workflows::DefaultWorkflowFactory::new()))
+            .with_providers(Arc::new(llmspell_providers::ProviderManager::new()))
+            .with_provider_config(Arc::new(
+                llmspell_config::providers::ProviderManagerConfig::default(),
+            ))
+            .with_template_registry(registry)
+            .build()
+            .expect("Failed to build context")
+    }
+
+    #[tokio::test]
+    async fn test_composition_propagates_artifacts_and_rejects_cycles() {
+        let registry = Arc::new(TemplateRegistry::new());
+        registry
+            .register(Arc::new(NotesTemplate::new("child")))
+            .unwrap();
+        registry
+            .register(Arc::new(StepTemplate::new(
+                "parent",
+                TemplateStep::new("child").map_param("subject", "topic"),
+            )))
+            .unwrap();
+        registry
+            .register(Arc::new(StepTemplate::new(
+                "recursive",
+                TemplateStep::new("recursive"),
+            )))
+            .unwrap();
+        let context = context(registry.clone());
+
+        let mut params = TemplateParams::new();
+        params.insert("subject", json!("rust"));
+        let output = registry
+            .get("parent")
+            .unwrap()
+            .execute(params.clone(), context.clone())
+            .await
+            .unwrap();
+
+        let child_artifact = output
+            .artifacts
+            .iter()
+            .find(|a| a.filename == "child.md")
+            .expect("child artifact propagated");
+        assert_eq!(child_artifact.content, "# rust");
+        assert_eq!(child_artifact.metadata["source_template"], json!("child"));
+        assert!(output.artifacts.iter().any(|a| a.filename == "parent.txt"));
+        assert_eq!(output.metrics.agents_invoked, 1);
+        assert!(matches!(
+            &output.result,
+            TemplateResult::Multiple(results)
+                if matches!(&results[1], TemplateResult::Text(t) if t == "notes on rust")
+        ));
+
+        let result = registry
+            .get("recursive")
+            .unwrap()
+            .execute(params, context)
+            .await;
+        match result {
+            Err(TemplateError::RecursiveComposition(chain)) => {
+                assert_eq!(chain, "recursive -> recursive")
+            }
+            other => panic!("expected recursive composition error, got {:?}", other),
+        }
+    }
+}
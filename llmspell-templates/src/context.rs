@@ -2,7 +2,16 @@
 
 use std::path::PathBuf;
 use std::sync::Arc;
-use tracing::{debug, trace};
+use tracing::{debug, trace, warn};
+
+/// Post-processor applied to each artifact a template produces
+///
+/// Returns the transformed artifact, or an error recorded on the unprocessed one.
+pub type ArtifactProcessor = Arc<
+    dyn Fn(crate::artifacts::Artifact) -> crate::error::Result<crate::artifacts::Artifact>
+        + Send
+        + Sync,
+>;
 
 /// Execution context providing access to all infrastructure for template execution
 ///
@@ -53,6 +62,24 @@ pub struct ExecutionContext {
 
     /// Callback for step execution events (optional, Task 14.6.9)
     pub step_callback: Option<Arc<dyn Fn(crate::core::StepEvent) + Send + Sync>>,
+
+    /// Registry used to resolve composed sub-templates (optional, falls back to global)
+    pub template_registry: Option<Arc<crate::registry::TemplateRegistry>>,
+
+    /// IDs of the templates currently executing, outermost first
+    pub template_stack: Vec<String>,
+
+    /// Artifacts from a previous partial run, tagged by phase, for resuming
+    pub resume_artifacts: Vec<crate::artifacts::Artifact>,
+
+    /// Checkpoint recording completed phases of this run (optional)
+    pub checkpoint: Option<crate::checkpoint::PhaseCheckpoint>,
+
+    /// Processors applied, in order, to every produced artifact
+    pub artifact_processors: Vec<ArtifactProcessor>,
+
+    /// Whether templates may spawn subprocesses, e.g. to run generated tests
+    pub allow_process_spawn: bool,
 }
 
 impl ExecutionContext {
@@ -118,6 +145,74 @@ impl ExecutionContext {
         self.step_callback.as_ref()
     }
 
+    /// Get the registry for resolving sub-templates, falling back to the global registry
+    pub fn template_registry(&self) -> &crate::registry::TemplateRegistry {
+        match &self.template_registry {
+            Some(registry) => registry,
+            None => crate::registry::global_registry(),
+        }
+    }
+
+    /// Get the IDs of the templates currently executing, outermost first
+    pub fn template_stack(&self) -> &[String] {
+        &self.template_stack
+    }
+
+    /// Get the artifact a previous partial run produced for `phase`, if resuming
+    pub fn resumed_artifact(&self, phase: &str) -> Option<&crate::artifacts::Artifact> {
+        self.resume_artifacts
+            .iter()
+            .find(|artifact| artifact.phase() == Some(phase))
+    }
+
+    /// Get the checkpoint recording completed phases, if checkpointing
+    pub fn checkpoint(&self) -> Option<&crate::checkpoint::PhaseCheckpoint> {
+        self.checkpoint.as_ref()
+    }
+
+    /// Record a completed phase's artifact in the run's checkpoint, if checkpointing
+    ///
+    /// Failures are logged rather than returned, so checkpointing never fails a run.
+    pub async fn checkpoint_phase(&self, artifact: &crate::artifacts::Artifact) {
+        if let Some(checkpoint) = &self.checkpoint {
+            if let Err(e) = checkpoint.save_phase(artifact).await {
+                warn!("Failed to checkpoint phase: {}", e);
+            }
+        }
+    }
+
+    /// Run the artifact processors over every artifact of a finished template's output
+    ///
+    /// A failing processor leaves the artifact as it was; the error is logged and recorded
+    /// under [`crate::artifacts::PROCESSING_ERRORS_METADATA_KEY`] on that artifact, and the
+    /// remaining processors and artifacts are still processed.
+    pub fn process_artifacts(&self, output: &mut crate::core::TemplateOutput) {
+        if self.artifact_processors.is_empty() {
+            return;
+        }
+
+        for artifact in &mut output.artifacts {
+            for processor in &self.artifact_processors {
+                match processor(artifact.clone()) {
+                    Ok(processed) => *artifact = processed,
+                    Err(e) => {
+                        warn!(
+                            "Artifact processor failed on '{}': {}",
+                            artifact.filename, e
+                        );
+                        let errors = artifact
+                            .metadata
+                            .entry(crate::artifacts::PROCESSING_ERRORS_METADATA_KEY.to_string())
+                            .or_insert_with(|| serde_json::Value::Array(Vec::new()));
+                        if let serde_json::Value::Array(errors) = errors {
+                            errors.push(serde_json::Value::String(e.to_string()));
+                        }
+                    }
+                }
+            }
+        }
+    }
+
     /// Check if infrastructure component is available
     pub fn has_state(&self) -> bool {
         self.state_manager.is_some()
@@ -252,6 +347,38 @@ impl ExecutionContext {
         self
     }
 
+    /// Add artifacts from a previous partial run to resume from
+    pub fn with_resume_artifacts(mut self, artifacts: Vec<crate::artifacts::Artifact>) -> Self {
+        debug!(
+            "ExecutionContext: Resuming from {} artifacts",
+            artifacts.len()
+        );
+        self.resume_artifacts = artifacts;
+        self
+    }
+
+    /// Record completed phases in a checkpoint
+    pub fn with_checkpoint(mut self, checkpoint: crate::checkpoint::PhaseCheckpoint) -> Self {
+        self.checkpoint = Some(checkpoint);
+        self
+    }
+
+    /// Add a processor applied to each produced artifact before it reaches the output
+    ///
+    /// Processors run in the order they were added, e.g. to convert or upload artifacts
+    /// without modifying the template.
+    pub fn with_artifact_processor(
+        mut self,
+        processor: Box<
+            dyn Fn(crate::artifacts::Artifact) -> crate::error::Result<crate::artifacts::Artifact>
+                + Send
+                + Sync,
+        >,
+    ) -> Self {
+        self.artifact_processors.push(Arc::from(processor));
+        self
+    }
+
     /// Check if memory is available (Task 13.11.0)
     pub fn has_memory(&self) -> bool {
         self.memory_manager.is_some() && self.context_bridge.is_some()
@@ -509,6 +636,8 @@ pub struct ExecutionContextBuilder {
     memory_manager: Option<Arc<dyn llmspell_memory::MemoryManager>>,
     context_bridge: Option<Arc<dyn llmspell_core::ContextAssembler>>,
     step_callback: Option<Arc<dyn Fn(crate::core::StepEvent) + Send + Sync>>,
+    template_registry: Option<Arc<crate::registry::TemplateRegistry>>,
+    allow_process_spawn: bool,
 }
 
 impl ExecutionContextBuilder {
@@ -632,6 +761,21 @@ impl ExecutionContextBuilder {
         self
     }
 
+    /// Set the registry used to resolve composed sub-templates
+    pub fn with_template_registry(
+        mut self,
+        template_registry: Arc<crate::registry::TemplateRegistry>,
+    ) -> Self {
+        self.template_registry = Some(template_registry);
+        self
+    }
+
+    /// Allow or deny templates spawning subprocesses (denied by default)
+    pub fn with_process_spawn(mut self, allowed: bool) -> Self {
+        self.allow_process_spawn = allowed;
+        self
+    }
+
     /// Build the execution context
     ///
     /// # Errors
@@ -679,6 +823,12 @@ impl ExecutionContextBuilder {
             memory_manager: self.memory_manager,
             context_bridge: self.context_bridge,
             step_callback: self.step_callback,
+            template_registry: self.template_registry,
+            template_stack: Vec::new(),
+            resume_artifacts: Vec::new(),
+            checkpoint: None,
+            artifact_processors: Vec::new(),
+            allow_process_spawn: self.allow_process_spawn,
         })
     }
 }
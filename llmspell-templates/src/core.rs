@@ -31,6 +31,21 @@ pub trait Template: Send + Sync + std::fmt::Debug {
         context: ExecutionContext,
     ) -> Result<TemplateOutput>;
 
+    /// Optional: Resume a partial run from the artifacts of a previous execution
+    ///
+    /// Prior artifacts are keyed by the phase that produced them (see [`Artifact::with_phase`]).
+    /// The default exposes them through [`ExecutionContext::resumed_artifact`] and runs
+    /// `execute`; multi-phase templates skip every phase whose artifact is present.
+    async fn resume(
+        &self,
+        params: TemplateParams,
+        context: ExecutionContext,
+        prior: Vec<Artifact>,
+    ) -> Result<TemplateOutput> {
+        self.execute(params, context.with_resume_artifacts(prior))
+            .await
+    }
+
     /// Optional: Validate parameters before execution
     fn validate(&self, params: &TemplateParams) -> Result<()> {
         // Default: validate against config_schema
@@ -266,6 +281,35 @@ impl TemplateOutput {
     pub fn add_metric(&mut self, key: impl Into<String>, value: serde_json::Value) {
         self.metrics.custom_metrics.insert(key.into(), value);
     }
+
+    /// Merge a sub-template's output into this one
+    ///
+    /// Child artifacts are appended and tagged with the child's template ID, and the
+    /// child's usage metrics are added to this output's. Returns the child's result.
+    pub fn absorb(&mut self, child: TemplateOutput) -> TemplateResult {
+        let source = serde_json::Value::String(child.metadata.template_id.clone());
+        self.artifacts
+            .extend(child.artifacts.into_iter().map(|mut artifact| {
+                artifact
+                    .metadata
+                    .entry("source_template".to_string())
+                    .or_insert_with(|| source.clone());
+                artifact
+            }));
+
+        let metrics = &mut self.metrics;
+        metrics.agents_invoked += child.metrics.agents_invoked;
+        metrics.tools_invoked += child.metrics.tools_invoked;
+        metrics.rag_queries += child.metrics.rag_queries;
+        if let Some(tokens) = child.metrics.tokens_used {
+            metrics.tokens_used = Some(metrics.tokens_used.unwrap_or(0) + tokens);
+        }
+        if let Some(cost) = child.metrics.cost_usd {
+            metrics.cost_usd = Some(metrics.cost_usd.unwrap_or(0.0) + cost);
+        }
+
+        child.result
+    }
 }
 
 /// Template execution result
@@ -24,6 +24,10 @@ pub enum TemplateError {
     #[error("Template execution failed: {0}")]
     ExecutionFailed(String),
 
+    /// Template composition would invoke a template already on the call stack
+    #[error("Recursive template composition: {0}")]
+    RecursiveComposition(String),
+
     /// Required infrastructure not available
     #[error("Required infrastructure not available: {0}")]
     InfrastructureUnavailable(String),
@@ -106,6 +110,18 @@ pub enum ValidationError {
     /// JSON schema validation failed
     #[error("JSON schema validation failed: {0}")]
     SchemaValidation(String),
+
+    /// Every parameter error found in one validation pass
+    #[error("Invalid parameters: {}", join_errors(.errors))]
+    Collected { errors: Vec<ValidationError> },
+}
+
+fn join_errors(errors: &[ValidationError]) -> String {
+    errors
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join("; ")
 }
 
 impl ValidationError {
@@ -149,6 +165,31 @@ impl ValidationError {
     pub fn multiple(errors: Vec<String>) -> Self {
         Self::Multiple { errors }
     }
+
+    /// Create a container for the structured errors of one validation pass
+    pub fn collected(errors: Vec<ValidationError>) -> Self {
+        Self::Collected { errors }
+    }
+
+    /// Name of the parameter this error refers to, if it refers to exactly one
+    pub fn parameter(&self) -> Option<&str> {
+        match self {
+            Self::MissingRequired { parameter }
+            | Self::TypeMismatch { parameter, .. }
+            | Self::OutOfRange { parameter, .. }
+            | Self::InvalidValue { parameter, .. }
+            | Self::UnsupportedParameter(parameter) => Some(parameter),
+            Self::Multiple { .. } | Self::SchemaValidation(_) | Self::Collected { .. } => None,
+        }
+    }
+
+    /// Individual errors: the collected ones, or this error alone
+    pub fn errors(&self) -> &[ValidationError] {
+        match self {
+            Self::Collected { errors } => errors,
+            _ => std::slice::from_ref(self),
+        }
+    }
 }
 
 #[cfg(test)]
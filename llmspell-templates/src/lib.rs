@@ -103,6 +103,9 @@
 //! - Parameter validation: <5ms
 //! - Competitive advantage: 10-100x faster than Python frameworks
 //!
+//! `TemplateMetrics::measure` records these timings for a run and
+//! `TemplateMetrics::assert_within_targets` checks them against `PerformanceTargets`.
+//!
 //! ## Phase 13 Memory Synergy
 //!
 //! Templates are designed for zero-breaking-change memory enhancement with A-TKG:
@@ -119,18 +122,25 @@
 
 pub mod artifacts;
 pub mod builtin;
+pub mod checkpoint;
+pub mod composition;
 pub mod context;
 pub mod core;
 pub mod error;
+pub mod metrics;
 pub mod registry;
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
 pub mod validation;
 
 // Re-exports for convenience
 pub use artifacts::Artifact;
-pub use context::{assemble_template_context, ContextMessage, ExecutionContext};
+pub use composition::TemplateStep;
+pub use context::{assemble_template_context, ArtifactProcessor, ContextMessage, ExecutionContext};
 pub use core::{
     CostEstimate, Template, TemplateCategory, TemplateMetadata, TemplateOutput, TemplateParams,
 };
 pub use error::{Result, TemplateError, ValidationError};
+pub use metrics::{PerformanceTargets, TemplateMetrics, Violation};
 pub use registry::TemplateRegistry;
 pub use validation::ConfigSchema;
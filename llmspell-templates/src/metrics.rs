@@ -0,0 +1,240 @@
+//! Template performance metrics and target checks
+
+use crate::{
+    context::ExecutionContext,
+    core::{TemplateOutput, TemplateParams},
+    error::Result,
+    registry::TemplateRegistry,
+};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::time::Instant;
+
+/// Performance targets for template infrastructure, in milliseconds
+///
+/// Defaults are the crate's documented targets.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct PerformanceTargets {
+    /// Maximum template execution time
+    pub execution_ms: f64,
+
+    /// Maximum template discovery (registry lookup) time
+    pub discovery_ms: f64,
+
+    /// Maximum parameter validation time
+    pub validation_ms: f64,
+}
+
+impl Default for PerformanceTargets {
+    fn default() -> Self {
+        Self {
+            execution_ms: 100.0,
+            discovery_ms: 10.0,
+            validation_ms: 5.0,
+        }
+    }
+}
+
+/// A measured time that exceeded its target
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Violation {
+    /// Name of the measured metric
+    pub metric: String,
+
+    /// Measured time in milliseconds
+    pub actual_ms: f64,
+
+    /// Target time in milliseconds
+    pub target_ms: f64,
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} took {:.3}ms, target is {:.3}ms",
+            self.metric, self.actual_ms, self.target_ms
+        )
+    }
+}
+
+/// Timings recorded for one template run
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TemplateMetrics {
+    /// Template execution time in milliseconds
+    pub execution_time_ms: f64,
+
+    /// Template discovery (registry lookup) time in milliseconds
+    pub discovery_time_ms: f64,
+
+    /// Parameter validation time in milliseconds
+    pub validation_time_ms: f64,
+}
+
+impl TemplateMetrics {
+    /// Execute a template from the registry, timing discovery, validation and execution
+    ///
+    /// Runs [`TemplateRegistry::execute`], recording the time of each step.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the template is not found, the resume source cannot be loaded,
+    /// or validation or execution fails
+    pub async fn measure(
+        registry: &TemplateRegistry,
+        id: &str,
+        params: TemplateParams,
+        context: ExecutionContext,
+    ) -> Result<(TemplateOutput, Self)> {
+        let mut metrics = Self::default();
+        let output = registry
+            .execute_timed(id, params, context, &mut metrics)
+            .await?;
+        Ok((output, metrics))
+    }
+
+    /// Compare the recorded timings against `targets`
+    ///
+    /// # Errors
+    ///
+    /// Returns every timing that exceeds its target
+    pub fn assert_within_targets(
+        &self,
+        targets: PerformanceTargets,
+    ) -> std::result::Result<(), Vec<Violation>> {
+        let violations: Vec<Violation> = [
+            ("execution", self.execution_time_ms, targets.execution_ms),
+            ("discovery", self.discovery_time_ms, targets.discovery_ms),
+            ("validation", self.validation_time_ms, targets.validation_ms),
+        ]
+        .into_iter()
+        .filter(|(_, actual_ms, target_ms)| actual_ms > target_ms)
+        .map(|(metric, actual_ms, target_ms)| Violation {
+            metric: metric.to_string(),
+            actual_ms,
+            target_ms,
+        })
+        .collect();
+
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(violations)
+        }
+    }
+}
+
+pub(crate) fn elapsed_ms(start: Instant) -> f64 {
+    start.elapsed().as_secs_f64() * 1000.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{Template, TemplateCategory, TemplateMetadata, TemplateResult};
+    use crate::validation::ConfigSchema;
+    use async_trait::async_trait;
+    use serde_json::json;
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    /// Template that only waits for its `delay_ms` parameter
+    #[derive(Debug)]
+    struct LightweightTemplate {
+        metadata: TemplateMetadata,
+    }
+
+    #[async_trait]
+    impl Template for LightweightTemplate {
+        fn metadata(&self) -> &TemplateMetadata {
+            &self.metadata
+        }
+
+        fn config_schema(&self) -> ConfigSchema {
+            ConfigSchema::new(vec![])
+        }
+
+        async fn execute(
+            &self,
+            params: TemplateParams,
+            _context: ExecutionContext,
+        ) -> Result<TemplateOutput> {
+            let delay_ms: u64 = params.get_or("delay_ms", 0);
+            tokio::time::sleep(Duration::from_millis(delay_ms)).await;
+            Ok(TemplateOutput::new(
+                TemplateResult::text("done"),
+                self.metadata.id.clone(),
+                self.metadata.version.clone(),
+                params,
+            ))
+        }
+    }
+
+    fn registry() -> TemplateRegistry {
+        let registry = TemplateRegistry::new();
+        registry
+            .register(Arc::new(LightweightTemplate {
+                metadata: TemplateMetadata {
+                    id: "lightweight".to_string(),
+                    name: "Lightweight".to_string(),
+                    description: "Test template: lightweight".to_string(),
+                    category: TemplateCategory::Workflow,
+                    version: "0.1.0".to_string(),
+                    author: None,
+                    requires: vec![],
+                    tags: vec![],
+                },
+            }))
+            .unwrap();
+        registry
+    }
+
+    fn context() -> ExecutionContext {
+        ExecutionContext::builder()
+            .with_tool_registry(Arc::new(llmspell_tools::ToolRegistry::new()))
+            .with_agent_registry(Arc::new(llmspell_agents::FactoryRegistry::new()))
+            .with_workflow_factory(Arc::new(llmspell_workflows::DefaultWorkflowFactory::new()))
+            .with_providers(Arc::new(llmspell_providers::ProviderManager::new()))
+            .with_provider_config(Arc::new(
+                llmspell_config::providers::ProviderManagerConfig::default(),
+            ))
+            .build()
+            .expect("Failed to build context")
+    }
+
+    #[tokio::test]
+    async fn test_lightweight_template_within_targets() {
+        let (output, metrics) =
+            TemplateMetrics::measure(&registry(), "lightweight", TemplateParams::new(), context())
+                .await
+                .unwrap();
+        assert!(matches!(output.result, TemplateResult::Text(ref t) if t == "done"));
+
+        let generous = PerformanceTargets {
+            execution_ms: 1000.0,
+            discovery_ms: 100.0,
+            validation_ms: 50.0,
+        };
+        assert_eq!(metrics.assert_within_targets(generous), Ok(()));
+    }
+
+    #[tokio::test]
+    async fn test_tight_target_reports_violation() {
+        let mut params = TemplateParams::new();
+        params.insert("delay_ms", json!(20));
+        let (_, metrics) = TemplateMetrics::measure(&registry(), "lightweight", params, context())
+            .await
+            .unwrap();
+
+        let tight = PerformanceTargets {
+            execution_ms: 1.0,
+            discovery_ms: 100.0,
+            validation_ms: 50.0,
+        };
+        let violations = metrics.assert_within_targets(tight).unwrap_err();
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].metric, "execution");
+        assert!(violations[0].actual_ms >= 20.0);
+        assert_eq!(violations[0].target_ms, 1.0);
+    }
+}
@@ -1,11 +1,25 @@
 //! Template registry for discovery and management
 
 use crate::{
-    core::{Template, TemplateCategory, TemplateMetadata},
+    checkpoint::{execute_resumable, take_resume_param},
+    context::ExecutionContext,
+    core::{Template, TemplateCategory, TemplateMetadata, TemplateOutput, TemplateParams},
     error::{Result, TemplateError},
+    metrics::{elapsed_ms, TemplateMetrics},
 };
 use dashmap::DashMap;
+use llmspell_utils::string_utils::fuzzy_similarity;
+use std::cmp::Ordering;
 use std::sync::{Arc, LazyLock};
+use std::time::Instant;
+
+/// Relevance weights for name (and ID), tag and description matches
+const NAME_WEIGHT: f64 = 0.5;
+const TAG_WEIGHT: f64 = 0.3;
+const DESCRIPTION_WEIGHT: f64 = 0.2;
+
+/// Minimum word similarity for a fuzzy match
+const FUZZY_THRESHOLD: f64 = 0.75;
 
 /// Template registry for storing and discovering templates
 ///
@@ -86,6 +100,54 @@ impl TemplateRegistry {
             .ok_or_else(|| TemplateError::NotFound(id.to_string()))
     }
 
+    /// Execute a template by ID
+    ///
+    /// Completed phases are checkpointed when the context has a state manager. If `params`
+    /// contains [`RESUME_PARAM`](crate::checkpoint::RESUME_PARAM), it is removed and the
+    /// template resumes from the checkpoint or from artifacts saved in the context's output
+    /// directory instead of starting over.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the template is not found, the resume source cannot be loaded,
+    /// or validation or execution fails
+    pub async fn execute(
+        &self,
+        id: &str,
+        params: TemplateParams,
+        context: ExecutionContext,
+    ) -> Result<TemplateOutput> {
+        self.execute_timed(id, params, context, &mut TemplateMetrics::default())
+            .await
+    }
+
+    /// Execute a template by ID, recording discovery, validation and execution times
+    ///
+    /// The single implementation behind [`execute`](Self::execute) and
+    /// [`TemplateMetrics::measure`].
+    pub(crate) async fn execute_timed(
+        &self,
+        id: &str,
+        mut params: TemplateParams,
+        context: ExecutionContext,
+        metrics: &mut TemplateMetrics,
+    ) -> Result<TemplateOutput> {
+        let start = Instant::now();
+        let template = self.get(id)?;
+        metrics.discovery_time_ms = elapsed_ms(start);
+
+        let resume = take_resume_param(&mut params, context.output_dir())?;
+        let start = Instant::now();
+        template.validate(&params)?;
+        metrics.validation_time_ms = elapsed_ms(start);
+
+        let start = Instant::now();
+        let output = execute_resumable(template.as_ref(), params, context, resume).await?;
+        metrics.execution_time_ms = elapsed_ms(start);
+
+        Ok(output)
+    }
+
     /// Check if template exists
     pub fn contains(&self, id: &str) -> bool {
         self.templates.contains_key(id)
@@ -116,23 +178,37 @@ impl TemplateRegistry {
             .collect()
     }
 
-    /// Search templates by query (searches name, description, and tags)
-    pub fn search(&self, query: &str) -> Vec<TemplateMetadata> {
-        let query_lower = query.to_lowercase();
+    /// Search templates by query, ranked by relevance
+    ///
+    /// Matches the query against names and IDs, tags, and descriptions. Each
+    /// field scores exact matches highest, then prefix and substring matches,
+    /// then fuzzy word matches for typos. Field scores are weighted and summed
+    /// into a relevance in `0.0..=1.0`. Returns at most `limit` results, best
+    /// first; templates that do not match at all are omitted.
+    pub fn search(&self, query: &str, limit: usize) -> Vec<(TemplateMetadata, f64)> {
+        let query_lower = query.trim().to_lowercase();
+        if query_lower.is_empty() {
+            return Vec::new();
+        }
 
-        self.templates
+        let mut results: Vec<(TemplateMetadata, f64)> = self
+            .templates
             .iter()
-            .filter(|entry| {
+            .filter_map(|entry| {
                 let metadata = entry.value().metadata();
-                metadata.name.to_lowercase().contains(&query_lower)
-                    || metadata.description.to_lowercase().contains(&query_lower)
-                    || metadata
-                        .tags
-                        .iter()
-                        .any(|tag| tag.to_lowercase().contains(&query_lower))
+                let score = relevance(&query_lower, metadata);
+                (score > 0.0).then(|| (metadata.clone(), score))
             })
-            .map(|entry| entry.value().metadata().clone())
-            .collect()
+            .collect();
+
+        results.sort_by(|(a, a_score), (b, b_score)| {
+            b_score
+                .partial_cmp(a_score)
+                .unwrap_or(Ordering::Equal)
+                .then_with(|| a.id.cmp(&b.id))
+        });
+        results.truncate(limit);
+        results
     }
 
     /// Find templates by tag
@@ -176,6 +252,61 @@ impl TemplateRegistry {
     }
 }
 
+/// Weighted relevance of a template for a lowercase query
+fn relevance(query: &str, metadata: &TemplateMetadata) -> f64 {
+    let name = field_score(query, &metadata.name.to_lowercase())
+        .max(field_score(query, &metadata.id.to_lowercase()));
+    let tags = metadata
+        .tags
+        .iter()
+        .map(|tag| field_score(query, &tag.to_lowercase()))
+        .fold(0.0, f64::max);
+    let description = field_score(query, &metadata.description.to_lowercase());
+
+    NAME_WEIGHT * name + TAG_WEIGHT * tags + DESCRIPTION_WEIGHT * description
+}
+
+/// Score a field, averaging over terms when the whole query does not match
+#[allow(clippy::cast_precision_loss)]
+fn field_score(query: &str, text: &str) -> f64 {
+    let whole = match_score(query, text);
+    let terms: Vec<&str> = query.split_whitespace().collect();
+    if terms.len() < 2 {
+        return whole;
+    }
+
+    let per_term = terms
+        .iter()
+        .map(|term| match_score(term, text))
+        .sum::<f64>()
+        / terms.len() as f64;
+    whole.max(per_term)
+}
+
+/// Score one term against text: exact, prefix, substring, then fuzzy word match
+fn match_score(term: &str, text: &str) -> f64 {
+    if text == term {
+        return 1.0;
+    }
+    if text.starts_with(term) {
+        return 0.9;
+    }
+    if text.contains(term) {
+        return 0.7;
+    }
+
+    let best = text
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(|word| fuzzy_similarity(term, word))
+        .fold(0.0, f64::max);
+    if best >= FUZZY_THRESHOLD {
+        0.5 * best
+    } else {
+        0.0
+    }
+}
+
 /// Global template registry
 static GLOBAL_REGISTRY: LazyLock<TemplateRegistry> = LazyLock::new(|| {
     TemplateRegistry::with_builtin_templates()
@@ -190,10 +321,16 @@ pub fn global_registry() -> &'static TemplateRegistry {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::artifacts::{Artifact, ArtifactCollection, PROCESSING_ERRORS_METADATA_KEY};
+    use crate::checkpoint::RESUME_PARAM;
     use crate::context::ExecutionContext;
-    use crate::core::{CostEstimate, TemplateOutput, TemplateParams};
+    use crate::core::{CostEstimate, TemplateOutput, TemplateParams, TemplateResult};
+    use crate::error::ValidationError;
     use crate::validation::ConfigSchema;
     use async_trait::async_trait;
+    use serde_json::json;
+    use std::path::Path;
+    use std::sync::atomic::{AtomicUsize, Ordering as AtomicOrdering};
 
     // Mock template for testing
     #[derive(Debug)]
@@ -245,6 +382,86 @@ mod tests {
         }
     }
 
+    /// Two-phase template that skips phases resumed from prior artifacts
+    #[derive(Debug)]
+    struct PhasedTemplate {
+        metadata: TemplateMetadata,
+        runs: [AtomicUsize; 2],
+    }
+
+    impl PhasedTemplate {
+        fn new() -> Self {
+            Self {
+                metadata: MockTemplate::new("phased", "Phased", TemplateCategory::Workflow)
+                    .metadata,
+                runs: [AtomicUsize::new(0), AtomicUsize::new(0)],
+            }
+        }
+
+        fn runs(&self) -> (usize, usize) {
+            (
+                self.runs[0].load(AtomicOrdering::SeqCst),
+                self.runs[1].load(AtomicOrdering::SeqCst),
+            )
+        }
+    }
+
+    #[async_trait]
+    impl Template for PhasedTemplate {
+        fn metadata(&self) -> &TemplateMetadata {
+            &self.metadata
+        }
+
+        fn config_schema(&self) -> ConfigSchema {
+            ConfigSchema::new(vec![])
+        }
+
+        async fn execute(
+            &self,
+            params: TemplateParams,
+            context: ExecutionContext,
+        ) -> Result<TemplateOutput> {
+            let outline = match context.resumed_artifact("outline") {
+                Some(artifact) => artifact.clone(),
+                None => {
+                    self.runs[0].fetch_add(1, AtomicOrdering::SeqCst);
+                    Artifact::text("outline.txt", "outline").with_phase("outline")
+                }
+            };
+            let draft = match context.resumed_artifact("draft") {
+                Some(artifact) => artifact.clone(),
+                None => {
+                    self.runs[1].fetch_add(1, AtomicOrdering::SeqCst);
+                    Artifact::text("draft.txt", format!("draft from {}", outline.content))
+                        .with_phase("draft")
+                }
+            };
+
+            let mut output = TemplateOutput::new(
+                TemplateResult::text(draft.content.clone()),
+                self.metadata.id.clone(),
+                self.metadata.version.clone(),
+                params,
+            );
+            output.add_artifact(outline);
+            output.add_artifact(draft);
+            Ok(output)
+        }
+    }
+
+    fn test_context() -> ExecutionContext {
+        ExecutionContext::builder()
+            .with_tool_registry(Arc::new(llmspell_tools::ToolRegistry::new()))
+            .with_agent_registry(Arc::new(llmspell_agents::FactoryRegistry::new()))
+            .with_workflow_factory(Arc::new(llmspell_workflows::DefaultWorkflowFactory::new()))
+            .with_providers(Arc::new(llmspell_providers::ProviderManager::new()))
+            .with_provider_config(Arc::new(
+                llmspell_config::providers::ProviderManagerConfig::default(),
+            ))
+            .build()
+            .expect("Failed to build context")
+    }
+
     #[test]
     fn test_registry_register_and_get() {
         let registry = TemplateRegistry::new();
@@ -409,18 +626,38 @@ mod tests {
         registry.register(Arc::new(template2)).unwrap();
 
         // Search by name
-        let results = registry.search("research");
+        let results = registry.search("research", 10);
         assert_eq!(results.len(), 1);
-        assert_eq!(results[0].id, "template1");
+        assert_eq!(results[0].0.id, "template1");
 
         // Search by tag
-        let results = registry.search("chat");
+        let results = registry.search("chat", 10);
         assert_eq!(results.len(), 1);
-        assert_eq!(results[0].id, "template2");
+        assert_eq!(results[0].0.id, "template2");
 
         // Case-insensitive search
-        let results = registry.search("RESEARCH");
+        let results = registry.search("RESEARCH", 10);
         assert_eq!(results.len(), 1);
+
+        // Limit caps the result count
+        assert_eq!(registry.search("test", 1).len(), 1);
+    }
+
+    #[test]
+    fn test_registry_search_ranking_and_typos() {
+        let registry = TemplateRegistry::with_builtin_templates().unwrap();
+
+        let results = registry.search("research", 5);
+        assert_eq!(results[0].0.id, "research-assistant");
+        let exact_score = results[0].1;
+        assert!(results.windows(2).all(|pair| pair[0].1 >= pair[1].1));
+
+        let results = registry.search("reserch", 5);
+        assert_eq!(results[0].0.id, "research-assistant");
+        assert!(results[0].1 > 0.0);
+        assert!(results[0].1 < exact_score);
+
+        assert!(registry.search("zzzzqqqq", 5).is_empty());
     }
 
     #[test]
@@ -479,4 +716,127 @@ mod tests {
         // Just verify we can access it without panic
         let _count = registry.count();
     }
+
+    #[tokio::test]
+    async fn test_registry_execute_resumes_missing_phases() {
+        let registry = TemplateRegistry::new();
+        let template = Arc::new(PhasedTemplate::new());
+        registry.register(template.clone()).unwrap();
+        let dir = tempfile::tempdir().unwrap();
+        let mut context = test_context();
+        context.output_dir = Some(dir.path().to_path_buf());
+
+        let output = registry
+            .execute("phased", TemplateParams::new(), context.clone())
+            .await
+            .unwrap();
+        assert_eq!(template.runs(), (1, 1));
+        assert_eq!(output.artifacts.len(), 2);
+
+        // Keep phase one's output and discard phase two's
+        let saved = ArtifactCollection::from(
+            output
+                .artifacts
+                .into_iter()
+                .filter(|a| a.phase() == Some("outline"))
+                .collect::<Vec<_>>(),
+        );
+        saved.save(&dir.path().join("partial.json")).unwrap();
+
+        // Saved collections are resolved against the output directory
+        let mut params = TemplateParams::new();
+        params.insert(RESUME_PARAM, json!("partial.json"));
+        let resumed = registry
+            .execute("phased", params, context.clone())
+            .await
+            .unwrap();
+
+        // Only phase two re-ran, and the resumed outline carried through
+        assert_eq!(template.runs(), (1, 2));
+        assert!(!resumed.metadata.parameters.contains(RESUME_PARAM));
+        let phases: Vec<_> = resumed.artifacts.iter().filter_map(|a| a.phase()).collect();
+        assert_eq!(phases, vec!["outline", "draft"]);
+        assert!(matches!(&resumed.result, TemplateResult::Text(t) if t == "draft from outline"));
+
+        let mut params = TemplateParams::new();
+        params.insert(RESUME_PARAM, json!(dir.path().join("missing.json")));
+        assert!(registry
+            .execute("phased", params, context.clone())
+            .await
+            .is_err());
+
+        // Paths outside the output directory are rejected, even if they exist
+        let outside = tempfile::tempdir().unwrap();
+        saved.save(&outside.path().join("partial.json")).unwrap();
+        for path in [
+            outside.path().join("partial.json"),
+            Path::new("..")
+                .join(outside.path().file_name().unwrap())
+                .join("partial.json"),
+        ] {
+            let mut params = TemplateParams::new();
+            params.insert(RESUME_PARAM, json!(path));
+            let result = registry.execute("phased", params, context.clone()).await;
+            assert!(matches!(
+                result,
+                Err(TemplateError::ValidationFailed(
+                    ValidationError::InvalidValue { .. }
+                ))
+            ));
+        }
+
+        // Without an output directory there is nothing to resume from
+        let mut params = TemplateParams::new();
+        params.insert(RESUME_PARAM, json!("partial.json"));
+        assert!(registry
+            .execute("phased", params, test_context())
+            .await
+            .is_err());
+        assert_eq!(template.runs(), (1, 2));
+    }
+
+    #[tokio::test]
+    async fn test_registry_execute_applies_artifact_processors() {
+        let registry = TemplateRegistry::new();
+        registry.register(Arc::new(PhasedTemplate::new())).unwrap();
+
+        let context = test_context().with_artifact_processor(Box::new(|mut artifact| {
+            artifact.filename = artifact.filename.to_uppercase();
+            Ok(artifact)
+        }));
+        let output = registry
+            .execute("phased", TemplateParams::new(), context.clone())
+            .await
+            .unwrap();
+        let filenames: Vec<_> = output
+            .artifacts
+            .iter()
+            .map(|a| a.filename.as_str())
+            .collect();
+        assert_eq!(filenames, vec!["OUTLINE.TXT", "DRAFT.TXT"]);
+
+        // A failing processor is reported on its artifact without aborting the run
+        let context = context.with_artifact_processor(Box::new(|artifact| {
+            if artifact.phase() == Some("draft") {
+                Err(TemplateError::ExecutionFailed("upload failed".to_string()))
+            } else {
+                Ok(artifact)
+            }
+        }));
+        let output = registry
+            .execute("phased", TemplateParams::new(), context)
+            .await
+            .unwrap();
+        let filenames: Vec<_> = output
+            .artifacts
+            .iter()
+            .map(|a| a.filename.as_str())
+            .collect();
+        assert_eq!(filenames, vec!["OUTLINE.TXT", "DRAFT.TXT"]);
+        assert!(!output.artifacts[0]
+            .metadata
+            .contains_key(PROCESSING_ERRORS_METADATA_KEY));
+        let errors = &output.artifacts[1].metadata[PROCESSING_ERRORS_METADATA_KEY];
+        assert!(errors[0].as_str().unwrap().contains("upload failed"));
+    }
 }
@@ -0,0 +1,133 @@
+//! Fixture templates for tests of template composition
+//!
+//! Available to this crate's tests and, with the `testing` feature, to other crates.
+
+use crate::{
+    artifacts::Artifact,
+    composition::TemplateStep,
+    context::ExecutionContext,
+    core::{
+        Template, TemplateCategory, TemplateMetadata, TemplateOutput, TemplateParams,
+        TemplateResult,
+    },
+    error::Result,
+    validation::ConfigSchema,
+};
+use async_trait::async_trait;
+
+/// Metadata for a fixture template with the given ID
+pub fn fixture_metadata(id: &str) -> TemplateMetadata {
+    TemplateMetadata {
+        id: id.to_string(),
+        name: id.to_string(),
+        description: format!("Test template: {}", id),
+        category: TemplateCategory::Workflow,
+        version: "0.1.0".to_string(),
+        author: None,
+        requires: vec![],
+        tags: vec![],
+    }
+}
+
+/// Leaf template writing `{id}.md` from its `topic` parameter
+///
+/// Its result is `"notes on {topic}"` and it reports one agent invocation.
+#[derive(Debug)]
+pub struct NotesTemplate {
+    metadata: TemplateMetadata,
+}
+
+impl NotesTemplate {
+    /// Create the template with the given ID
+    pub fn new(id: &str) -> Self {
+        Self {
+            metadata: fixture_metadata(id),
+        }
+    }
+}
+
+#[async_trait]
+impl Template for NotesTemplate {
+    fn metadata(&self) -> &TemplateMetadata {
+        &self.metadata
+    }
+
+    fn config_schema(&self) -> ConfigSchema {
+        ConfigSchema::new(vec![])
+    }
+
+    async fn execute(
+        &self,
+        params: TemplateParams,
+        _context: ExecutionContext,
+    ) -> Result<TemplateOutput> {
+        let topic: String = params.get("topic")?;
+        let mut output = TemplateOutput::new(
+            TemplateResult::text(format!("notes on {}", topic)),
+            self.metadata.id.clone(),
+            self.metadata.version.clone(),
+            params,
+        );
+        output.add_artifact(Artifact::markdown(
+            format!("{}.md", self.metadata.id),
+            format!("# {}", topic),
+        ));
+        output.metrics.agents_invoked = 1;
+        Ok(output)
+    }
+}
+
+/// Template running a sub-template through a [`TemplateStep`]
+///
+/// It writes `{id}.txt`, absorbs the step's artifacts and metrics, and returns both
+/// results as [`TemplateResult::Multiple`].
+#[derive(Debug)]
+pub struct StepTemplate {
+    metadata: TemplateMetadata,
+    step: TemplateStep,
+}
+
+impl StepTemplate {
+    /// Create the template with the given ID, running `step`
+    pub fn new(id: &str, step: TemplateStep) -> Self {
+        Self {
+            metadata: fixture_metadata(id),
+            step,
+        }
+    }
+}
+
+#[async_trait]
+impl Template for StepTemplate {
+    fn metadata(&self) -> &TemplateMetadata {
+        &self.metadata
+    }
+
+    fn config_schema(&self) -> ConfigSchema {
+        ConfigSchema::new(vec![])
+    }
+
+    async fn execute(
+        &self,
+        params: TemplateParams,
+        context: ExecutionContext,
+    ) -> Result<TemplateOutput> {
+        let child = self
+            .step
+            .execute(&self.metadata.id, &params, &context)
+            .await?;
+        let mut output = TemplateOutput::new(
+            TemplateResult::text(self.metadata.id.clone()),
+            self.metadata.id.clone(),
+            self.metadata.version.clone(),
+            params,
+        );
+        output.add_artifact(Artifact::text(
+            format!("{}.txt", self.metadata.id),
+            "summary",
+        ));
+        let child_result = output.absorb(child);
+        output.result = TemplateResult::multiple(vec![output.result.clone(), child_result]);
+        Ok(output)
+    }
+}
@@ -31,25 +31,25 @@ impl ConfigSchema {
     }
 
     /// Validate parameters against schema
+    ///
+    /// All parameters are checked in one pass. On failure the error is
+    /// `ValidationError::Collected`, holding one error per problem, each naming its parameter.
     pub fn validate(&self, params: &HashMap<String, Value>) -> Result<()> {
         let mut errors = Vec::new();
 
         // Check required parameters
         for param in &self.parameters {
             if param.required && !params.contains_key(&param.name) {
-                errors.push(format!("Required parameter missing: {}", param.name));
+                errors.push(ValidationError::missing(&param.name));
                 continue;
             }
 
             if let Some(value) = params.get(&param.name) {
-                // Validate type
+                // Constraints only apply to values of the right type
                 if let Err(e) = param.validate_type(value) {
-                    errors.push(e.to_string());
-                }
-
-                // Validate value constraints
-                if let Err(e) = param.validate_value(value) {
-                    errors.push(e.to_string());
+                    errors.push(e);
+                } else if let Err(e) = param.validate_value(value) {
+                    errors.push(e);
                 }
             }
         }
@@ -66,7 +66,7 @@ impl ConfigSchema {
         if errors.is_empty() {
             Ok(())
         } else {
-            Err(ValidationError::multiple(errors).into())
+            Err(ValidationError::collected(errors).into())
         }
     }
 
@@ -74,6 +74,14 @@ impl ConfigSchema {
     pub fn get_parameter(&self, name: &str) -> Option<&ParameterSchema> {
         self.parameters.iter().find(|p| p.name == name)
     }
+
+    /// Required parameters not present in `params`, in schema order
+    pub fn missing_required(&self, params: &HashMap<String, Value>) -> Vec<&ParameterSchema> {
+        self.parameters
+            .iter()
+            .filter(|p| p.required && !params.contains_key(&p.name))
+            .collect()
+    }
 }
 
 /// Parameter schema definition
@@ -100,6 +108,10 @@ pub struct ParameterSchema {
     /// Validation constraints
     #[serde(skip_serializing_if = "Option::is_none")]
     pub constraints: Option<ParameterConstraints>,
+
+    /// Metadata for collecting the parameter interactively
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub prompt: Option<ParamPrompt>,
 }
 
 impl ParameterSchema {
@@ -116,6 +128,7 @@ impl ParameterSchema {
             required: true,
             default: None,
             constraints: None,
+            prompt: None,
         }
     }
 
@@ -133,6 +146,7 @@ impl ParameterSchema {
             required: false,
             default: Some(default),
             constraints: None,
+            prompt: None,
         }
     }
 
@@ -142,8 +156,46 @@ impl ParameterSchema {
         self
     }
 
+    /// Add interactive prompt metadata to parameter
+    pub fn with_prompt(mut self, prompt: ParamPrompt) -> Self {
+        self.prompt = Some(prompt);
+        self
+    }
+
+    /// Label to show when prompting, falling back to the parameter name
+    pub fn prompt_label(&self) -> &str {
+        self.prompt
+            .as_ref()
+            .map_or(self.name.as_str(), |prompt| prompt.label.as_str())
+    }
+
+    /// Convert interactively collected input into a validated value
+    ///
+    /// Strings are taken verbatim; other types are parsed as JSON. The value
+    /// must then pass type checks, constraints, and the prompt's choices.
+    pub fn parse_input(&self, input: &str) -> Result<Value> {
+        let input = input.trim();
+        let value = match self.param_type {
+            ParameterType::String => Value::String(input.to_string()),
+            _ => serde_json::from_str(input).map_err(|_| {
+                ValidationError::type_mismatch(
+                    &self.name,
+                    self.param_type.to_string(),
+                    format!("{:?}", input),
+                )
+            })?,
+        };
+
+        self.validate_type(&value)?;
+        self.validate_value(&value)?;
+        if let Some(prompt) = &self.prompt {
+            prompt.validate_choice(&self.name, &value)?;
+        }
+        Ok(value)
+    }
+
     /// Validate parameter type
-    fn validate_type(&self, value: &Value) -> Result<()> {
+    fn validate_type(&self, value: &Value) -> std::result::Result<(), ValidationError> {
         let matches = match (&self.param_type, value) {
             (ParameterType::String, Value::String(_)) => true,
             (ParameterType::Number, Value::Number(_)) => true,
@@ -159,15 +211,14 @@ impl ParameterSchema {
         } else {
             Err(ValidationError::type_mismatch(
                 &self.name,
-                format!("{:?}", self.param_type),
-                format!("{:?}", value),
-            )
-            .into())
+                self.param_type.to_string(),
+                json_type_name(value),
+            ))
         }
     }
 
     /// Validate parameter value against constraints
-    fn validate_value(&self, value: &Value) -> Result<()> {
+    fn validate_value(&self, value: &Value) -> std::result::Result<(), ValidationError> {
         if let Some(constraints) = &self.constraints {
             constraints.validate(&self.name, value)?;
         }
@@ -175,6 +226,70 @@ impl ParameterSchema {
     }
 }
 
+/// Metadata for interactively collecting a parameter
+///
+/// Front-ends such as `llmspell template exec` use this to prompt for
+/// missing required parameters with a readable label, masked input for
+/// secrets, and a fixed set of choices.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ParamPrompt {
+    /// Label shown when prompting
+    pub label: String,
+
+    /// Additional help text shown with the prompt
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub help: Option<String>,
+
+    /// Whether input should be masked (API keys, passwords)
+    #[serde(default)]
+    pub secret: bool,
+
+    /// Values the input must be one of
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub choices: Vec<Value>,
+}
+
+impl ParamPrompt {
+    /// Create prompt metadata with a label
+    pub fn new(label: impl Into<String>) -> Self {
+        Self {
+            label: label.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Set help text
+    pub fn with_help(mut self, help: impl Into<String>) -> Self {
+        self.help = Some(help.into());
+        self
+    }
+
+    /// Mask input when prompting
+    pub fn secret(mut self) -> Self {
+        self.secret = true;
+        self
+    }
+
+    /// Restrict input to a set of choices
+    pub fn with_choices(mut self, choices: Vec<Value>) -> Self {
+        self.choices = choices;
+        self
+    }
+
+    /// Check a collected value against the allowed choices
+    fn validate_choice(&self, param_name: &str, value: &Value) -> Result<()> {
+        if self.choices.is_empty() || self.choices.contains(value) {
+            Ok(())
+        } else {
+            Err(ValidationError::invalid_value(
+                param_name,
+                format!("must be one of: {:?}", self.choices),
+            )
+            .into())
+        }
+    }
+}
+
 /// Parameter type
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
@@ -187,6 +302,32 @@ pub enum ParameterType {
     Object,
 }
 
+impl std::fmt::Display for ParameterType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::String => write!(f, "string"),
+            Self::Number => write!(f, "number"),
+            Self::Integer => write!(f, "integer"),
+            Self::Boolean => write!(f, "boolean"),
+            Self::Array => write!(f, "array"),
+            Self::Object => write!(f, "object"),
+        }
+    }
+}
+
+/// JSON type of a value, named like `ParameterType`
+fn json_type_name(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(n) if n.is_i64() || n.is_u64() => "integer",
+        Value::Number(_) => "number",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
 /// Parameter validation constraints
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct ParameterConstraints {
@@ -217,7 +358,11 @@ pub struct ParameterConstraints {
 
 impl ParameterConstraints {
     /// Validate value against constraints
-    fn validate(&self, param_name: &str, value: &Value) -> Result<()> {
+    fn validate(
+        &self,
+        param_name: &str,
+        value: &Value,
+    ) -> std::result::Result<(), ValidationError> {
         // Validate numeric constraints
         if let Some(num) = value.as_f64() {
             if let Some(min) = self.min {
@@ -225,8 +370,7 @@ impl ParameterConstraints {
                     return Err(ValidationError::out_of_range(
                         param_name,
                         format!("must be >= {}", min),
-                    )
-                    .into());
+                    ));
                 }
             }
             if let Some(max) = self.max {
@@ -234,8 +378,7 @@ impl ParameterConstraints {
                     return Err(ValidationError::out_of_range(
                         param_name,
                         format!("must be <= {}", max),
-                    )
-                    .into());
+                    ));
                 }
             }
         }
@@ -247,8 +390,7 @@ impl ParameterConstraints {
                     return Err(ValidationError::out_of_range(
                         param_name,
                         format!("length must be >= {}", min_length),
-                    )
-                    .into());
+                    ));
                 }
             }
             if let Some(max_length) = self.max_length {
@@ -256,8 +398,7 @@ impl ParameterConstraints {
                     return Err(ValidationError::out_of_range(
                         param_name,
                         format!("length must be <= {}", max_length),
-                    )
-                    .into());
+                    ));
                 }
             }
             if let Some(pattern) = &self.pattern {
@@ -266,8 +407,7 @@ impl ParameterConstraints {
                     return Err(ValidationError::invalid_value(
                         param_name,
                         format!("must match pattern: {}", pattern),
-                    )
-                    .into());
+                    ));
                 }
             }
         }
@@ -279,8 +419,7 @@ impl ParameterConstraints {
                     return Err(ValidationError::out_of_range(
                         param_name,
                         format!("array length must be >= {}", min_length),
-                    )
-                    .into());
+                    ));
                 }
             }
             if let Some(max_length) = self.max_length {
@@ -288,8 +427,7 @@ impl ParameterConstraints {
                     return Err(ValidationError::out_of_range(
                         param_name,
                         format!("array length must be <= {}", max_length),
-                    )
-                    .into());
+                    ));
                 }
             }
         }
@@ -300,8 +438,7 @@ impl ParameterConstraints {
                 return Err(ValidationError::invalid_value(
                     param_name,
                     format!("must be one of: {:?}", allowed),
-                )
-                .into());
+                ));
             }
         }
 
@@ -351,6 +488,44 @@ mod tests {
         assert!(schema.validate(&params).is_ok());
     }
 
+    #[test]
+    fn test_validate_collects_all_errors_with_names() {
+        let schema = ConfigSchema::new(vec![
+            ParameterSchema::required("topic", "Research topic", ParameterType::String),
+            ParameterSchema::optional(
+                "max_sources",
+                "Maximum sources",
+                ParameterType::Integer,
+                json!(10),
+            ),
+            ParameterSchema::optional(
+                "include_citations",
+                "Include citations",
+                ParameterType::Boolean,
+                json!(true),
+            ),
+        ]);
+
+        let mut params = HashMap::new();
+        params.insert("topic".to_string(), json!(5));
+        params.insert("max_sources".to_string(), json!("ten"));
+        params.insert("include_citations".to_string(), json!("yes"));
+
+        let err = match schema.validate(&params) {
+            Err(crate::error::TemplateError::ValidationFailed(err)) => err,
+            other => panic!("expected validation failure, got {:?}", other),
+        };
+        assert!(matches!(err, ValidationError::Collected { .. }));
+
+        let names: Vec<_> = err.errors().iter().filter_map(|e| e.parameter()).collect();
+        assert_eq!(names, vec!["topic", "max_sources", "include_citations"]);
+
+        let message = err.to_string();
+        assert!(message.contains("'topic': expected string, got integer"));
+        assert!(message.contains("'max_sources': expected integer, got string"));
+        assert!(message.contains("'include_citations': expected boolean, got string"));
+    }
+
     #[test]
     fn test_numeric_constraints() {
         let schema = ConfigSchema::new(vec![ParameterSchema::required(
@@ -422,4 +597,35 @@ mod tests {
         params.insert("model".to_string(), json!("ollama/llama3.2:3b"));
         assert!(schema.validate(&params).is_ok());
     }
+
+    #[test]
+    fn test_prompt_metadata_and_choice_collection() {
+        let schema = ConfigSchema::new(vec![
+            ParameterSchema::required("format", "Output format", ParameterType::String)
+                .with_prompt(
+                    ParamPrompt::new("Report format")
+                        .with_help("How the report is rendered")
+                        .with_choices(vec![json!("markdown"), json!("json")]),
+                ),
+            ParameterSchema::required("api_key", "Search API key", ParameterType::String)
+                .with_prompt(ParamPrompt::new("API key").secret()),
+        ]);
+
+        // Prompt metadata is part of the serialized schema
+        let serialized = serde_json::to_value(&schema).unwrap();
+        let format = &serialized["parameters"][0];
+        assert_eq!(format["prompt"]["label"], "Report format");
+        assert_eq!(format["prompt"]["choices"], json!(["markdown", "json"]));
+        assert_eq!(serialized["parameters"][1]["prompt"]["secret"], true);
+
+        let mut params = HashMap::new();
+        params.insert("api_key".to_string(), json!("sk-test"));
+        let missing = schema.missing_required(&params);
+        assert_eq!(missing.len(), 1);
+        assert_eq!(missing[0].prompt_label(), "Report format");
+
+        // Collected input is validated against the allowed set
+        assert_eq!(missing[0].parse_input("json").unwrap(), json!("json"));
+        assert!(missing[0].parse_input("pdf").is_err());
+    }
 }
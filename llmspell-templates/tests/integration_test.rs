@@ -106,21 +106,21 @@ fn test_template_search() {
     let registry = TemplateRegistry::with_builtin_templates().expect("Failed to create registry");
 
     // Search for "research"
-    let search_results = registry.search("research");
+    let search_results = registry.search("research", 10);
     assert!(
         !search_results.is_empty(),
         "Should find templates matching 'research'"
     );
 
     // Search for "assistant"
-    let search_results = registry.search("assistant");
+    let search_results = registry.search("assistant", 10);
     assert!(
         !search_results.is_empty(),
         "Should find templates matching 'assistant'"
     );
 
     // Search for nonexistent term
-    let search_results = registry.search("nonexistent_term_xyz");
+    let search_results = registry.search("nonexistent_term_xyz", 10);
     assert!(
         search_results.is_empty(),
         "Should not find templates for nonexistent term"
@@ -350,7 +350,7 @@ fn test_multi_template_discovery_workflow() {
     assert!(all_templates.len() >= 9, "Should have at least 9 templates");
 
     // 2. Search for research-related templates
-    let research_results = registry.search("research");
+    let research_results = registry.search("research", 10);
     assert!(
         !research_results.is_empty(),
         "Should find research templates"
@@ -23,9 +23,9 @@ pub mod usage;
 pub use manager::{MultiTenantVectorManager, TenantInfo};
 pub use registry::{DefaultTenantRegistry, FilteredTenantRegistry};
 pub use traits::{
-    IsolationMode, MultiTenancyConfig, TenantConfig, TenantExtension, TenantLifecycleHook,
-    TenantLimits, TenantOperationResult, TenantRegistry, TenantResourceManager, TenantScoped,
-    UsageTracked,
+    IsolationMode, MultiTenancyConfig, QuotaBreach, QuotaKind, TenantConfig, TenantExtension,
+    TenantLifecycleHook, TenantLimits, TenantOperationResult, TenantRegistry,
+    TenantResourceManager, TenantScoped, UsageTracked,
 };
 pub use usage::{CostEstimate, CostRates, TenantUsageTracker, UsageMetrics, UsageReport};
 
@@ -14,7 +14,7 @@ use std::time::SystemTime;
 use tokio::sync::RwLock;
 use tracing::{debug, info, warn};
 
-use super::traits::{TenantConfig, TenantLimits};
+use super::traits::{QuotaBreach, QuotaKind, TenantConfig, TenantLifecycleHook, TenantLimits};
 use super::usage::{TenantUsageTracker, UsageMetrics};
 use llmspell_events::{EventBus, Language, UniversalEvent};
 use serde_json::json;
@@ -48,6 +48,9 @@ pub struct MultiTenantVectorManager {
 
     /// Event bus for notifications
     event_bus: Option<Arc<EventBus>>,
+
+    /// Lifecycle hooks notified of quota breaches
+    hooks: Vec<Arc<dyn TenantLifecycleHook>>,
 }
 
 impl MultiTenantVectorManager {
@@ -59,6 +62,7 @@ impl MultiTenantVectorManager {
             usage_tracker: Arc::new(TenantUsageTracker::new()),
             default_limits: TenantLimits::default(),
             event_bus: None,
+            hooks: Vec::new(),
         }
     }
 
@@ -70,6 +74,7 @@ impl MultiTenantVectorManager {
             usage_tracker: Arc::new(TenantUsageTracker::new()),
             default_limits: TenantLimits::default(),
             event_bus: Some(event_bus),
+            hooks: Vec::new(),
         }
     }
 
@@ -99,6 +104,12 @@ impl MultiTenantVectorManager {
         self
     }
 
+    /// Add a lifecycle hook
+    pub fn with_lifecycle_hook(mut self, hook: Arc<dyn TenantLifecycleHook>) -> Self {
+        self.hooks.push(hook);
+        self
+    }
+
     /// Create a new tenant
     pub async fn create_tenant(&self, config: TenantConfig) -> Result<()> {
         let tenant_id = config.tenant_id.clone();
@@ -193,14 +204,33 @@ impl MultiTenantVectorManager {
     }
 
     /// Check if operation is allowed for tenant
-    async fn check_limits(&self, tenant_id: &str, operation: &str) -> Result<()> {
+    ///
+    /// Inserts are checked against the vector and storage quotas including
+    /// the requested amounts. A breach is counted in the tenant's usage,
+    /// reported to lifecycle hooks and returned as a `TenantOperationResult`
+    /// error.
+    async fn check_limits(
+        &self,
+        tenant_id: &str,
+        operation: &str,
+        requested_vectors: usize,
+        requested_bytes: usize,
+    ) -> Result<()> {
         let tenant = self
             .tenants
             .get(tenant_id)
             .ok_or_else(|| anyhow!("Tenant {} not found", tenant_id))?;
 
+        let breach = |quota, limit: usize, current: usize, requested: usize| QuotaBreach {
+            tenant_id: tenant_id.to_string(),
+            quota,
+            limit: limit as u64,
+            current: current as u64,
+            requested: requested as u64,
+        };
+
         // Use a block to limit the scope of the lock
-        {
+        let storage_breach = {
             let mut tenant_info = tenant.write().await;
 
             // Update last accessed time
@@ -211,47 +241,130 @@ impl MultiTenantVectorManager {
                 return Err(anyhow!("Tenant {} is inactive", tenant_id));
             }
 
+            let limits = &tenant_info.config.limits;
+            let usage = &tenant_info.usage;
+            let mut storage_breach = None;
+
             // Check storage limits based on operation
-            if operation == "insert" {
-                if let Some(max_vectors) = tenant_info.config.limits.max_vectors {
-                    if tenant_info.usage.vector_count >= max_vectors
-                        && !tenant_info.config.limits.allow_overflow
-                    {
-                        return Err(anyhow!("Vector limit exceeded for tenant {}", tenant_id));
+            if operation == "insert" && !limits.allow_overflow {
+                if let Some(max_vectors) = limits.max_vectors {
+                    if usage.vector_count + requested_vectors > max_vectors {
+                        storage_breach = Some(breach(
+                            QuotaKind::MaxVectors,
+                            max_vectors,
+                            usage.vector_count,
+                            requested_vectors,
+                        ));
                     }
                 }
 
-                if let Some(max_storage) = tenant_info.config.limits.max_storage_bytes {
-                    if tenant_info.usage.storage_bytes >= max_storage
-                        && !tenant_info.config.limits.allow_overflow
+                if let Some(max_storage) = limits.max_storage_bytes {
+                    if storage_breach.is_none()
+                        && usage.storage_bytes + requested_bytes > max_storage
                     {
-                        return Err(anyhow!("Storage limit exceeded for tenant {}", tenant_id));
+                        storage_breach = Some(breach(
+                            QuotaKind::MaxStorageBytes,
+                            max_storage,
+                            usage.storage_bytes,
+                            requested_bytes,
+                        ));
                     }
                 }
             }
-        } // Lock is released here
+
+            if storage_breach.is_some() {
+                tenant_info.usage.quota_breaches += 1;
+            }
+            storage_breach
+        }; // Lock is released here
+
+        if let Some(breach) = storage_breach {
+            return Err(self.quota_exceeded(breach).await);
+        }
 
         // Check rate limits without holding the write lock
-        let tenant_info = tenant.read().await;
-        if let Some(max_qps) = tenant_info.config.limits.max_queries_per_second {
-            let current_qps = self.usage_tracker.get_queries_per_second(tenant_id)?;
+        let rate_breach = {
+            let tenant_info = tenant.read().await;
+            let limits = &tenant_info.config.limits;
+            let mut rate_breach = None;
+
+            if !limits.allow_overflow {
+                if let Some(max_qps) = limits.max_queries_per_second {
+                    let current_qps = self.usage_tracker.get_queries_per_second(tenant_id)?;
+                    if current_qps >= max_qps {
+                        rate_breach = Some(breach(
+                            QuotaKind::MaxQueriesPerSecond,
+                            max_qps as usize,
+                            current_qps as usize,
+                            1,
+                        ));
+                    }
+                }
 
-            if current_qps >= max_qps && !tenant_info.config.limits.allow_overflow {
-                return Err(anyhow!("Rate limit exceeded for tenant {}", tenant_id));
+                if let Some(max_qpm) = limits.max_queries_per_minute {
+                    let current_qpm = self.usage_tracker.get_queries_per_minute(tenant_id)?;
+                    if rate_breach.is_none() && current_qpm >= max_qpm {
+                        rate_breach = Some(breach(
+                            QuotaKind::MaxQueriesPerMinute,
+                            max_qpm as usize,
+                            current_qpm as usize,
+                            1,
+                        ));
+                    }
+                }
             }
+            rate_breach
+        };
+
+        if let Some(breach) = rate_breach {
+            tenant.write().await.usage.quota_breaches += 1;
+            return Err(self.quota_exceeded(breach).await);
         }
 
         Ok(())
     }
 
+    /// Record a quota breach, notify hooks and listeners, and build the error
+    async fn quota_exceeded(&self, breach: QuotaBreach) -> anyhow::Error {
+        warn!(
+            "Quota {:?} exceeded for tenant {} ({} used, {} requested, limit {})",
+            breach.quota, breach.tenant_id, breach.current, breach.requested, breach.limit
+        );
+
+        if let Err(e) = self
+            .usage_tracker
+            .record_quota_breach(&breach.tenant_id)
+            .await
+        {
+            warn!("Failed to record quota breach: {}", e);
+        }
+
+        for hook in &self.hooks {
+            if let Err(e) = hook.on_quota_exceeded(&breach).await {
+                warn!("Lifecycle hook error: {}", e);
+            }
+        }
+
+        self.emit_event(
+            &breach.tenant_id,
+            "quota_exceeded",
+            serde_json::to_value(&breach).unwrap_or_default(),
+        )
+        .await;
+
+        anyhow::Error::new(breach.to_operation_result())
+    }
+
     /// Insert vectors for a specific tenant
     pub async fn insert_for_tenant(
         &self,
         tenant_id: &str,
         mut vectors: Vec<VectorEntry>,
     ) -> Result<Vec<String>> {
-        // Check limits
-        self.check_limits(tenant_id, "insert").await?;
+        // Check limits, including the vectors and bytes this insert adds
+        let requested_bytes = vectors.iter().map(|v| v.embedding.len() * 4).sum();
+        self.check_limits(tenant_id, "insert", vectors.len(), requested_bytes)
+            .await?;
 
         let tenant = self
             .tenants
@@ -328,7 +441,7 @@ impl MultiTenantVectorManager {
         mut query: VectorQuery,
     ) -> Result<Vec<VectorResult>> {
         // Check limits
-        self.check_limits(tenant_id, "search").await?;
+        self.check_limits(tenant_id, "search", 0, 0).await?;
 
         let tenant = self
             .tenants
@@ -347,6 +460,9 @@ impl MultiTenantVectorManager {
             .await
             .unwrap_or_else(|_| Vec::new());
 
+        drop(tenant_info);
+        tenant.write().await.usage.query_count += 1;
+
         // Update usage metrics
         self.usage_tracker.record_search(tenant_id).await?;
 
@@ -496,6 +612,21 @@ impl VectorStorage for MultiTenantVectorManager {
         self.storage.delete_scope(scope).await
     }
 
+    async fn delete_by_filter(
+        &self,
+        scope: &StateScope,
+        filter: HashMap<String, serde_json::Value>,
+    ) -> Result<usize> {
+        let count = self.storage.delete_by_filter(scope, filter).await?;
+        if let StateScope::Custom(scope_str) = scope {
+            if let Some(tenant_id) = scope_str.strip_prefix("tenant:") {
+                // Update usage tracking
+                self.usage_tracker.record_delete(tenant_id, count).await?;
+            }
+        }
+        Ok(count)
+    }
+
     async fn stats(&self) -> Result<StorageStats> {
         self.get_global_stats().await
     }
@@ -608,8 +739,9 @@ mod tests {
             name: "Limited Tenant".to_string(),
             limits: TenantLimits {
                 max_vectors: Some(1),
-                max_storage_bytes: Some(1024),
+                max_storage_bytes: Some(4096),
                 max_queries_per_second: Some(10),
+                max_queries_per_minute: None,
                 max_dimensions: Some(384),
                 allow_overflow: false,
                 custom_limits: HashMap::new(),
@@ -4,6 +4,7 @@ use anyhow::Result;
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::fmt;
 use std::sync::Arc;
 
 // Re-export TenantScoped from llmspell-core (Phase 13b.3.4 - moved to core to avoid cyclic deps)
@@ -88,6 +89,11 @@ pub trait TenantLifecycleHook: Send + Sync {
 
     /// Called when tenant is deactivated
     async fn on_tenant_deactivated(&self, tenant_id: &str) -> Result<()>;
+
+    /// Called when an operation is rejected for exceeding a tenant quota
+    async fn on_quota_exceeded(&self, _breach: &QuotaBreach) -> Result<()> {
+        Ok(())
+    }
 }
 
 /// Extension point for custom tenant operations
@@ -151,6 +157,10 @@ pub struct TenantLimits {
     /// Maximum queries per second
     pub max_queries_per_second: Option<u32>,
 
+    /// Maximum queries per minute
+    #[serde(default)]
+    pub max_queries_per_minute: Option<u32>,
+
     /// Maximum dimensions for vectors
     pub max_dimensions: Option<usize>,
 
@@ -179,6 +189,74 @@ pub struct TenantOperationResult {
     pub usage: Option<HashMap<String, usize>>,
 }
 
+impl fmt::Display for TenantOperationResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for TenantOperationResult {}
+
+/// Tenant quota that an operation can exceed
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum QuotaKind {
+    /// `TenantLimits::max_vectors`
+    MaxVectors,
+
+    /// `TenantLimits::max_storage_bytes`
+    MaxStorageBytes,
+
+    /// `TenantLimits::max_queries_per_second`
+    MaxQueriesPerSecond,
+
+    /// `TenantLimits::max_queries_per_minute`
+    MaxQueriesPerMinute,
+}
+
+/// An operation rejected because it would exceed a tenant quota
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QuotaBreach {
+    /// Tenant whose quota would be exceeded
+    pub tenant_id: String,
+
+    /// Quota that would be exceeded
+    pub quota: QuotaKind,
+
+    /// Configured limit
+    pub limit: u64,
+
+    /// Usage before the operation
+    pub current: u64,
+
+    /// Amount the operation requested
+    pub requested: u64,
+}
+
+impl QuotaBreach {
+    /// Failed operation result describing this breach
+    ///
+    /// Quota enforcement returns this result as the operation's error, so
+    /// callers can `downcast_ref::<TenantOperationResult>()` to inspect it.
+    pub fn to_operation_result(&self) -> TenantOperationResult {
+        let what = match self.quota {
+            QuotaKind::MaxVectors => "Vector limit",
+            QuotaKind::MaxStorageBytes => "Storage limit",
+            QuotaKind::MaxQueriesPerSecond | QuotaKind::MaxQueriesPerMinute => "Rate limit",
+        };
+
+        TenantOperationResult {
+            success: false,
+            message: format!(
+                "{} exceeded for tenant {} ({} used, {} requested, limit {})",
+                what, self.tenant_id, self.current, self.requested, self.limit
+            ),
+            data: serde_json::to_value(self).ok(),
+            usage: None,
+        }
+    }
+}
+
 /// Tenant isolation mode
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
 pub enum IsolationMode {
@@ -1,6 +1,7 @@
 //! Tenant usage tracking and cost calculation
 
 use anyhow::Result;
+use chrono::{DateTime, Utc};
 use dashmap::DashMap;
 use serde::{Deserialize, Serialize};
 use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
@@ -30,6 +31,10 @@ pub struct UsageMetrics {
     /// Total compute time in milliseconds
     pub compute_time_ms: u64,
 
+    /// Total number of operations rejected for exceeding a quota
+    #[serde(default)]
+    pub quota_breaches: u64,
+
     /// Last reset timestamp
     pub last_reset: SystemTime,
 
@@ -47,6 +52,7 @@ impl Default for UsageMetrics {
             insert_count: 0,
             delete_count: 0,
             compute_time_ms: 0,
+            quota_breaches: 0,
             last_reset: now,
             period_start: now,
         }
@@ -66,6 +72,12 @@ struct RealtimeMetrics {
 
     /// Timestamp of current second
     current_second: AtomicU64,
+
+    /// Queries in current minute
+    queries_current_minute: AtomicU64,
+
+    /// Index of current minute since the epoch
+    current_minute: AtomicU64,
 }
 
 impl RealtimeMetrics {
@@ -75,6 +87,8 @@ impl RealtimeMetrics {
             storage_bytes: AtomicUsize::new(0),
             queries_current_second: AtomicU64::new(0),
             current_second: AtomicU64::new(0),
+            queries_current_minute: AtomicU64::new(0),
+            current_minute: AtomicU64::new(0),
         }
     }
 }
@@ -95,6 +109,113 @@ pub struct UsageReport {
     pub timestamp: SystemTime,
 }
 
+/// Column header for CSV usage exports
+const CSV_HEADER: &str = "tenant_id,period_start,period_end,vector_count,storage_bytes,\
+query_count,insert_count,storage_cost,query_cost,compute_cost,total_cost";
+
+impl UsageReport {
+    /// Export this report as CSV; see [`UsageReport::export_csv`]
+    pub fn to_csv(&self) -> String {
+        Self::export_csv(std::slice::from_ref(self))
+    }
+
+    /// Export this report as JSON; see [`UsageReport::export_json`]
+    pub fn to_json(&self) -> serde_json::Value {
+        Self::export_json(std::slice::from_ref(self))
+    }
+
+    /// Export reports as CSV with one row per tenant, sorted by tenant ID
+    ///
+    /// The first line is a `#` comment with the time range covered, followed
+    /// by the column header. Costs are in USD with six decimal places.
+    pub fn export_csv(reports: &[Self]) -> String {
+        let (start, end) = Self::time_range(reports);
+        let mut csv = format!(
+            "# usage report from {} to {}\n{}\n",
+            format_time(start),
+            format_time(end),
+            CSV_HEADER
+        );
+
+        for report in Self::sorted(reports) {
+            let metrics = &report.metrics;
+            let cost = &report.estimated_cost;
+            csv.push_str(&format!(
+                "{},{},{},{},{},{},{},{:.6},{:.6},{:.6},{:.6}\n",
+                csv_field(&report.tenant_id),
+                format_time(metrics.period_start),
+                format_time(report.timestamp),
+                metrics.vector_count,
+                metrics.storage_bytes,
+                metrics.query_count,
+                metrics.insert_count,
+                cost.storage_cost,
+                cost.query_cost,
+                cost.compute_cost,
+                cost.total_cost,
+            ));
+        }
+
+        csv
+    }
+
+    /// Export reports as a JSON document with per-tenant entries and totals
+    pub fn export_json(reports: &[Self]) -> serde_json::Value {
+        let (start, end) = Self::time_range(reports);
+        let tenants: Vec<serde_json::Value> = Self::sorted(reports)
+            .into_iter()
+            .map(|report| {
+                serde_json::json!({
+                    "tenant_id": report.tenant_id,
+                    "period_start": format_time(report.metrics.period_start),
+                    "period_end": format_time(report.timestamp),
+                    "vector_count": report.metrics.vector_count,
+                    "storage_bytes": report.metrics.storage_bytes,
+                    "query_count": report.metrics.query_count,
+                    "insert_count": report.metrics.insert_count,
+                    "cost": report.estimated_cost,
+                })
+            })
+            .collect();
+        let total_cost: f64 = reports.iter().map(|r| r.estimated_cost.total_cost).sum();
+
+        serde_json::json!({
+            "period_start": format_time(start),
+            "period_end": format_time(end),
+            "tenants": tenants,
+            "total_cost": total_cost,
+        })
+    }
+
+    /// Earliest period start and latest report time across reports
+    fn time_range(reports: &[Self]) -> (SystemTime, SystemTime) {
+        let start = reports.iter().map(|r| r.metrics.period_start).min();
+        let end = reports.iter().map(|r| r.timestamp).max();
+        let now = SystemTime::now();
+        (start.unwrap_or(now), end.unwrap_or(now))
+    }
+
+    fn sorted(reports: &[Self]) -> Vec<&Self> {
+        let mut sorted: Vec<&Self> = reports.iter().collect();
+        sorted.sort_by(|a, b| a.tenant_id.cmp(&b.tenant_id));
+        sorted
+    }
+}
+
+/// Format a timestamp as RFC 3339 in UTC
+fn format_time(time: SystemTime) -> String {
+    DateTime::<Utc>::from(time).to_rfc3339()
+}
+
+/// Quote a CSV field if it contains separators, quotes, or newlines
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
 /// Cost estimate for usage
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CostEstimate {
@@ -251,6 +372,27 @@ impl TenantUsageTracker {
                     .queries_current_second
                     .fetch_add(1, Ordering::Relaxed);
             }
+
+            let minute = now / 60;
+            if realtime.current_minute.load(Ordering::Relaxed) != minute {
+                // Reset counter for new minute
+                realtime.current_minute.store(minute, Ordering::Relaxed);
+                realtime.queries_current_minute.store(1, Ordering::Relaxed);
+            } else {
+                realtime
+                    .queries_current_minute
+                    .fetch_add(1, Ordering::Relaxed);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Record an operation rejected for exceeding a quota
+    pub async fn record_quota_breach(&self, tenant_id: &str) -> Result<()> {
+        if let Some(metrics) = self.metrics.get(tenant_id) {
+            let mut metrics = metrics.write().await;
+            metrics.quota_breaches += 1;
         }
 
         Ok(())
@@ -287,6 +429,26 @@ impl TenantUsageTracker {
         }
     }
 
+    /// Get current queries per minute
+    pub fn get_queries_per_minute(&self, tenant_id: &str) -> Result<u32> {
+        if let Some(realtime) = self.realtime.get(tenant_id) {
+            let minute = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap()
+                .as_secs()
+                / 60;
+
+            if realtime.current_minute.load(Ordering::Relaxed) == minute {
+                Ok(realtime.queries_current_minute.load(Ordering::Relaxed) as u32)
+            } else {
+                // Different minute, no queries yet
+                Ok(0)
+            }
+        } else {
+            Ok(0)
+        }
+    }
+
     /// Get usage metrics for a tenant
     pub async fn get_metrics(&self, tenant_id: &str) -> Result<UsageMetrics> {
         if let Some(metrics) = self.metrics.get(tenant_id) {
@@ -348,6 +510,7 @@ impl TenantUsageTracker {
             metrics.insert_count = 0;
             metrics.delete_count = 0;
             metrics.compute_time_ms = 0;
+            metrics.quota_breaches = 0;
             metrics.last_reset = SystemTime::now();
             metrics.period_start = SystemTime::now();
 
@@ -459,4 +622,42 @@ mod tests {
         let qps = tracker.get_queries_per_second("test-tenant").unwrap();
         assert_eq!(qps, 5);
     }
+
+    #[tokio::test]
+    async fn test_usage_report_export() {
+        let tracker = TenantUsageTracker::new();
+
+        for (tenant_id, queries) in [("tenant-a", 1000), ("tenant-b", 2000)] {
+            tracker.initialize_tenant(tenant_id).await.unwrap();
+            tracker.record_insert(tenant_id, 3, 0).await.unwrap();
+            for _ in 0..queries {
+                tracker.record_search(tenant_id).await.unwrap();
+            }
+        }
+
+        let reports = tracker.get_all_reports().await;
+        let csv = UsageReport::export_csv(&reports);
+        let lines: Vec<&str> = csv.lines().collect();
+
+        assert_eq!(lines.len(), 4);
+        assert!(lines[0].starts_with("# usage report from "));
+        assert_eq!(lines[1], CSV_HEADER);
+
+        let row_a: Vec<&str> = lines[2].split(',').collect();
+        assert_eq!(row_a[0], "tenant-a");
+        assert_eq!(row_a[3], "3");
+        assert_eq!(row_a[5], "1000");
+        assert_eq!(row_a[8], "0.010000"); // query_cost
+        assert_eq!(row_a[10], "0.010000"); // total_cost
+
+        let row_b: Vec<&str> = lines[3].split(',').collect();
+        assert_eq!(row_b[0], "tenant-b");
+        assert_eq!(row_b[8], "0.020000");
+        assert_eq!(row_b[10], "0.020000");
+
+        let json = UsageReport::export_json(&reports);
+        assert_eq!(json["tenants"].as_array().unwrap().len(), 2);
+        assert_eq!(json["tenants"][1]["query_count"], 2000);
+        assert!((json["total_cost"].as_f64().unwrap() - 0.03).abs() < 1e-9);
+    }
 }
@@ -7,8 +7,8 @@ use llmspell_events::{EventBus, UniversalEvent};
 use llmspell_storage::backends::sqlite::{SqliteBackend, SqliteConfig, SqliteVectorStorage};
 use llmspell_storage::{VectorEntry, VectorQuery};
 use llmspell_tenancy::{
-    DefaultTenantRegistry, MultiTenantVectorManager, TenantConfig, TenantLifecycleHook,
-    TenantLimits, TenantRegistry,
+    DefaultTenantRegistry, MultiTenantVectorManager, QuotaBreach, QuotaKind, TenantConfig,
+    TenantLifecycleHook, TenantLimits, TenantOperationResult, TenantRegistry,
 };
 use std::collections::HashMap;
 use std::sync::{Arc, Mutex};
@@ -71,6 +71,14 @@ impl TenantLifecycleHook for TestLifecycleHook {
             .push(format!("deactivated:{tenant_id}"));
         Ok(())
     }
+
+    async fn on_quota_exceeded(&self, breach: &QuotaBreach) -> Result<()> {
+        self.calls
+            .lock()
+            .unwrap()
+            .push(format!("quota_exceeded:{}", breach.tenant_id));
+        Ok(())
+    }
 }
 
 #[tokio::test]
@@ -187,6 +195,7 @@ async fn test_multi_tenant_vector_manager_with_events() -> Result<()> {
             max_vectors: Some(100),
             max_storage_bytes: Some(1024 * 1024),
             max_queries_per_second: Some(10),
+            max_queries_per_minute: None,
             max_dimensions: Some(384),
             allow_overflow: false,
             custom_limits: HashMap::new(),
@@ -318,6 +327,59 @@ async fn test_tenant_limits_enforcement() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn test_vector_quota_rejects_insert_past_limit() -> Result<()> {
+    let config = SqliteConfig::in_memory();
+    let backend = Arc::new(SqliteBackend::new(config).await?);
+    let storage = Arc::new(SqliteVectorStorage::new(backend, 384).await?);
+    let (hook, calls) = TestLifecycleHook::new();
+    let manager = MultiTenantVectorManager::new(storage).with_lifecycle_hook(Arc::new(hook));
+
+    let config = TenantConfig {
+        tenant_id: "quota-tenant".to_string(),
+        name: "Quota Tenant".to_string(),
+        limits: TenantLimits {
+            max_vectors: Some(5),
+            ..Default::default()
+        },
+        active: true,
+        metadata: HashMap::new(),
+        created_at: SystemTime::now(),
+        last_accessed: SystemTime::now(),
+        custom_config: None,
+    };
+    manager.create_tenant(config).await?;
+
+    for i in 0..5 {
+        let vectors = vec![VectorEntry::new(format!("vector-{i}"), vec![0.1; 384])];
+        manager.insert_for_tenant("quota-tenant", vectors).await?;
+    }
+
+    let vectors = vec![VectorEntry::new("vector-5".to_string(), vec![0.1; 384])];
+    let error = manager
+        .insert_for_tenant("quota-tenant", vectors)
+        .await
+        .unwrap_err();
+    let result = error
+        .downcast_ref::<TenantOperationResult>()
+        .expect("quota errors are TenantOperationResults");
+    assert!(!result.success);
+    assert!(result.message.contains("Vector limit exceeded"));
+    let breach: QuotaBreach = serde_json::from_value(result.data.clone().unwrap())?;
+    assert_eq!(breach.quota, QuotaKind::MaxVectors);
+    assert_eq!((breach.limit, breach.current, breach.requested), (5, 5, 1));
+
+    let info = manager.get_tenant("quota-tenant").await?;
+    assert_eq!(info.usage.vector_count, 5);
+    assert_eq!(info.usage.quota_breaches, 1);
+    assert!(calls
+        .lock()
+        .unwrap()
+        .contains(&"quota_exceeded:quota-tenant".to_string()));
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_tenant_isolation() -> Result<()> {
     let config = SqliteConfig::in_memory();
@@ -24,6 +24,7 @@ fn bench_agent_baseline(c: &mut Criterion) {
                     context: None,
                     parameters: HashMap::new(),
                     output_modalities: vec![],
+                    attachments: vec![],
                 };
                 let context = ExecutionContext::default();
 
@@ -57,6 +58,7 @@ fn bench_agent_with_simulated_hooks(c: &mut Criterion) {
                     context: None,
                     parameters: HashMap::new(),
                     output_modalities: vec![],
+                    attachments: vec![],
                 };
                 let context = ExecutionContext::default();
 
@@ -128,6 +130,7 @@ fn calculate_hook_overhead(_c: &mut Criterion) {
                 context: None,
                 parameters: HashMap::new(),
                 output_modalities: vec![],
+                attachments: vec![],
             };
             let context = ExecutionContext::default();
 
@@ -153,6 +156,7 @@ fn calculate_hook_overhead(_c: &mut Criterion) {
                 context: None,
                 parameters: HashMap::new(),
                 output_modalities: vec![],
+                attachments: vec![],
             };
             let context = ExecutionContext::default();
 
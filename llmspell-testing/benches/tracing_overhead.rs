@@ -50,6 +50,7 @@ fn bench_tool_execution_tracing(c: &mut Criterion) {
                 ("b".to_string(), serde_json::json!(3)),
             ]),
             output_modalities: vec![],
+            attachments: vec![],
         };
 
         // Baseline: No tracing
@@ -145,6 +146,7 @@ fn bench_agent_execution_tracing(c: &mut Criterion) {
         context: None,
         parameters: HashMap::new(),
         output_modalities: vec![],
+        attachments: vec![],
     };
 
     // Baseline: No tracing
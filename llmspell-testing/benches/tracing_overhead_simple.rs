@@ -25,6 +25,7 @@ fn bench_agent_tracing_overhead(c: &mut Criterion) {
         context: None,
         parameters: HashMap::new(),
         output_modalities: vec![],
+        attachments: vec![],
     };
 
     // Baseline: Tracing disabled (RUST_LOG not set)
@@ -19,13 +19,14 @@ use llmspell_utils::{
     // NEW: Using shared utilities
     rate_limiter::{RateLimiter, RateLimiterBuilder},
     response::ResponseBuilder,
-    retry::{retry, AlwaysRetry, RetryConfig as SharedRetryConfig, RetryError},
+    retry::{retry, ErrorRetryPolicy, RetryConfig as SharedRetryConfig, RetryError},
     timeout::TimeoutBuilder,
 };
 use reqwest::{Client, Method, Response};
 use serde::{Deserialize, Serialize};
 use serde_json::{json, Value};
 use std::collections::HashMap;
+use std::sync::atomic::{AtomicU32, Ordering};
 use std::time::{Duration, Instant};
 use tracing::{debug, error, info, instrument, trace};
 
@@ -330,13 +331,51 @@ impl HttpRequestTool {
         auth: &AuthType,
     ) -> Result<Response> {
         let request = self.build_request(method, url, headers, body, auth);
-        request.send().await.map_err(|e| LLMSpellError::Tool {
-            message: format!("HTTP request failed: {e}"),
-            tool_name: Some("http-requester".to_string()),
-            source: None,
+        request.send().await.map_err(|e| {
+            // Classify so the shared retry policy only retries transient failures
+            if e.is_timeout() {
+                LLMSpellError::Timeout {
+                    message: format!("HTTP request timed out: {e}"),
+                    duration_ms: None,
+                }
+            } else if e.is_builder() {
+                LLMSpellError::Tool {
+                    message: format!("HTTP request failed: {e}"),
+                    tool_name: Some("http-requester".to_string()),
+                    source: None,
+                }
+            } else {
+                LLMSpellError::Network {
+                    message: format!("HTTP request failed: {e}"),
+                    source: None,
+                }
+            }
         })
     }
 
+    /// Error for a response whose status is configured to trigger a retry
+    ///
+    /// A 429 becomes a rate-limit error carrying the `Retry-After` seconds, if given.
+    fn retryable_status_error(response: &Response) -> LLMSpellError {
+        let status = response.status();
+        if status == reqwest::StatusCode::TOO_MANY_REQUESTS {
+            let retry_after = response
+                .headers()
+                .get(reqwest::header::RETRY_AFTER)
+                .and_then(|v| v.to_str().ok())
+                .and_then(|v| v.trim().parse::<u64>().ok());
+            LLMSpellError::RateLimit {
+                message: format!("HTTP {status}"),
+                retry_after,
+            }
+        } else {
+            LLMSpellError::Network {
+                message: format!("HTTP {status}"),
+                source: None,
+            }
+        }
+    }
+
     /// Log the result of an HTTP request
     fn log_result(
         method: &Method,
@@ -392,15 +431,25 @@ impl HttpRequestTool {
         );
 
         let retry_cfg = retry_config.unwrap_or_else(|| self.config.retry_config.clone());
+        let retry_on_status = retry_cfg.retry_on_status.clone();
         let shared_retry_config: SharedRetryConfig = retry_cfg.into();
+        let max_attempts = shared_retry_config.max_attempts;
+        let attempts = AtomicU32::new(0);
 
         // Apply rate limiting
         self.apply_rate_limiting().await?;
 
-        // Execute with retry logic using shared utility
-        let result = retry(shared_retry_config, AlwaysRetry, || async {
-            self.do_request(method.clone(), url, headers.as_ref(), body.as_ref(), &auth)
-                .await
+        // Execute with retry logic using the shared error policy; the final
+        // attempt returns the response as-is so its status reaches the caller
+        let result = retry(shared_retry_config, ErrorRetryPolicy, || async {
+            let attempt = attempts.fetch_add(1, Ordering::Relaxed) + 1;
+            let response = self
+                .do_request(method.clone(), url, headers.as_ref(), body.as_ref(), &auth)
+                .await?;
+            if attempt < max_attempts && retry_on_status.contains(&response.status().as_u16()) {
+                return Err(Self::retryable_status_error(&response));
+            }
+            Ok(response)
         })
         .await;
 
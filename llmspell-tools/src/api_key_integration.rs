@@ -65,6 +65,7 @@ pub fn add_api_key(service: &str, key: &str) -> Result<(), String> {
         expires_at: None,
         is_active: true,
         usage_count: 0,
+        rotated_to: None,
     };
 
     manager.add_key(&metadata.key_id, key, metadata.clone())
@@ -186,6 +187,7 @@ mod tests {
             expires_at: None,
             is_active: true,
             usage_count: 0,
+            rotated_to: None,
         };
 
         // Add key directly to the local manager
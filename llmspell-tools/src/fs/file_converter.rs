@@ -699,6 +699,7 @@ mod tests {
             context: None,
             parameters: HashMap::new(),
             output_modalities: vec![],
+            attachments: vec![],
         };
 
         let result = tool.execute(input, ExecutionContext::default()).await;
@@ -10,7 +10,7 @@ use llmspell_core::{
     types::{AgentInput, AgentOutput},
     ComponentMetadata, ExecutionContext, LLMSpellError, Result as LLMResult,
 };
-use llmspell_security::sandbox::SandboxContext;
+use llmspell_security::sandbox::{EnvSandbox, SandboxContext};
 use llmspell_utils::{
     extract_parameters, extract_required_string,
     response::ResponseBuilder,
@@ -94,6 +94,7 @@ pub struct EnvironmentReaderTool {
     metadata: ComponentMetadata,
     config: EnvironmentReaderConfig,
     sandbox_context: Option<Arc<SandboxContext>>,
+    env_sandbox: Option<Arc<EnvSandbox>>,
 }
 
 impl EnvironmentReaderTool {
@@ -177,6 +178,7 @@ impl EnvironmentReaderTool {
             ),
             config,
             sandbox_context: None,
+            env_sandbox: None,
         }
     }
 
@@ -193,44 +195,45 @@ impl EnvironmentReaderTool {
             ),
             config,
             sandbox_context: Some(sandbox_context),
+            env_sandbox: None,
         }
     }
 
+    /// Restrict readable variables to those permitted by an environment sandbox
+    ///
+    /// The environment sandbox is authoritative: variables it denies are
+    /// refused and variables it allows are readable regardless of the
+    /// tool's own patterns.
+    #[must_use]
+    pub fn with_env_sandbox(mut self, env_sandbox: Arc<EnvSandbox>) -> Self {
+        self.env_sandbox = Some(env_sandbox);
+        self
+    }
+
     /// Check if an environment variable is allowed to be read
     #[allow(clippy::cognitive_complexity)]
     fn is_var_allowed(&self, var_name: &str) -> bool {
-        // Check sandbox permissions first if available
-        if let Some(sandbox) = &self.sandbox_context {
-            if !sandbox.security_requirements.env_permissions.is_empty() {
-                let allowed = sandbox
-                    .security_requirements
-                    .env_permissions
-                    .iter()
-                    .any(|pattern| self.matches_pattern(var_name, pattern));
-                if allowed {
-                    debug!(
-                        "Environment variable '{}' allowed by sandbox permissions",
-                        var_name
-                    );
-                    return true;
-                }
+        // Environment sandbox (from security config) replaces the allowlist;
+        // blocked patterns and sandbox permissions still apply
+        if let Some(env_sandbox) = &self.env_sandbox {
+            if !env_sandbox.is_allowed(var_name) {
                 debug!(
-                    "Environment variable '{}' not allowed by sandbox permissions",
+                    "Environment variable '{}' denied by environment sandbox",
                     var_name
                 );
                 return false;
             }
+            return !self.is_blocked(var_name) && self.sandbox_permits(var_name).unwrap_or(true);
+        }
+
+        // Check sandbox permissions first if available
+        if let Some(allowed) = self.sandbox_permits(var_name) {
+            return allowed;
         }
 
         // Check blocked patterns first (takes precedence)
-        for pattern in &self.config.blocked_patterns {
-            if self.matches_pattern(var_name, pattern) {
-                debug!(
-                    "Environment variable '{}' blocked by pattern '{}'",
-                    var_name, pattern
-                );
-                return false;
-            }
+        if self.is_blocked(var_name) {
+            return false;
         }
 
         // Check if read all is allowed
@@ -265,6 +268,43 @@ impl EnvironmentReaderTool {
         false
     }
 
+    /// Check the variable against the sandbox context's env permissions
+    ///
+    /// Returns `None` when no sandbox permissions are configured.
+    fn sandbox_permits(&self, var_name: &str) -> Option<bool> {
+        let sandbox = self.sandbox_context.as_ref()?;
+        let permissions = &sandbox.security_requirements.env_permissions;
+        if permissions.is_empty() {
+            return None;
+        }
+
+        let allowed = permissions
+            .iter()
+            .any(|pattern| self.matches_pattern(var_name, pattern));
+        debug!(
+            "Environment variable '{}' {} by sandbox permissions",
+            var_name,
+            if allowed { "allowed" } else { "not allowed" }
+        );
+        Some(allowed)
+    }
+
+    /// Check the variable against the blocked patterns
+    fn is_blocked(&self, var_name: &str) -> bool {
+        let blocked = self
+            .config
+            .blocked_patterns
+            .iter()
+            .find(|pattern| self.matches_pattern(var_name, pattern));
+        if let Some(pattern) = blocked {
+            debug!(
+                "Environment variable '{}' blocked by pattern '{}'",
+                var_name, pattern
+            );
+        }
+        blocked.is_some()
+    }
+
     /// Simple glob pattern matching for environment variable names
     #[allow(clippy::unused_self)]
     fn matches_pattern(&self, var_name: &str, pattern: &str) -> bool {
@@ -608,6 +648,77 @@ mod tests {
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("not permitted"));
     }
+    #[tokio::test]
+    async fn test_env_sandbox_allowlist() {
+        std::env::set_var("SECRET", "hunter2");
+        let tool = create_test_environment_reader()
+            .with_env_sandbox(Arc::new(EnvSandbox::new(vec!["PATH".to_string()])));
+
+        let input = create_test_tool_input(vec![("operation", "get"), ("variable_name", "PATH")]);
+        let result = tool
+            .execute(input, ExecutionContext::default())
+            .await
+            .unwrap();
+        assert!(result.text.contains("\"found\": true"));
+
+        let input = create_test_tool_input(vec![("operation", "get"), ("variable_name", "SECRET")]);
+        let result = tool.execute(input, ExecutionContext::default()).await;
+        assert!(result.unwrap_err().to_string().contains("not permitted"));
+
+        let input = create_test_tool_input(vec![("operation", "list")]);
+        let result = tool
+            .execute(input, ExecutionContext::default())
+            .await
+            .unwrap();
+        assert!(!result.text.contains("hunter2"));
+        assert!(!result.text.contains("\"HOME\""));
+    }
+    #[tokio::test]
+    async fn test_env_sandbox_keeps_blocked_patterns() {
+        std::env::set_var("AWS_SECRET_ACCESS_KEY", "wJalrXUtnFEMI");
+        std::env::set_var("AWS_REGION", "us-east-1");
+        // Only the provider prefix is unblocked; secret-looking names stay blocked
+        let mut config = EnvironmentReaderConfig::default();
+        config.blocked_patterns.retain(|pattern| pattern != "AWS_*");
+        let tool = EnvironmentReaderTool::new(config.clone())
+            .with_env_sandbox(Arc::new(EnvSandbox::new(vec!["AWS_*".to_string()])));
+
+        assert!(tool.is_var_allowed("AWS_REGION"));
+        assert!(!tool.is_var_allowed("AWS_SECRET_ACCESS_KEY"));
+
+        let input = create_test_tool_input(vec![
+            ("operation", "get"),
+            ("variable_name", "AWS_SECRET_ACCESS_KEY"),
+        ]);
+        let result = tool.execute(input, ExecutionContext::default()).await;
+        assert!(result.unwrap_err().to_string().contains("not permitted"));
+
+        let input = create_test_tool_input(vec![("operation", "list")]);
+        let result = tool
+            .execute(input, ExecutionContext::default())
+            .await
+            .unwrap();
+        assert!(result.text.contains("AWS_REGION"));
+        assert!(!result.text.contains("wJalrXUtnFEMI"));
+
+        // Sandbox context permissions still apply on top of the environment sandbox
+        let security_requirements = SecurityRequirements {
+            level: SecurityLevel::Restricted,
+            file_permissions: vec![],
+            network_permissions: vec![],
+            env_permissions: vec!["PATH".to_string()],
+            custom_requirements: HashMap::new(),
+        };
+        let sandbox_context = Arc::new(SandboxContext::new(
+            "test_env_reader".to_string(),
+            security_requirements,
+            ResourceLimits::default(),
+        ));
+        let tool = EnvironmentReaderTool::with_sandbox(config, sandbox_context)
+            .with_env_sandbox(Arc::new(EnvSandbox::new(vec!["AWS_*".to_string()])));
+        assert!(!tool.is_var_allowed("AWS_REGION"));
+    }
+
     #[tokio::test]
     async fn test_list_variables() {
         let tool = create_test_environment_reader();
@@ -337,6 +337,7 @@ impl ProcessExecutorTool {
         }
 
         cmd.stdin(Stdio::null()); // Don't allow stdin input for security
+        cmd.kill_on_drop(true); // Don't leave timed-out processes running
 
         // Set environment variables
         if !self.config.permissions.inherit_environment {
@@ -976,6 +977,7 @@ mod tests {
             context: None,
             parameters: HashMap::new(),
             output_modalities: vec![],
+            attachments: vec![],
         };
         let result1 = tool.execute(input1, ExecutionContext::default()).await;
         assert!(result1.is_err());
@@ -32,10 +32,10 @@ use llmspell_utils::{
     resource_limits::{ResourceLimits, ResourceTracker},
     response::ResponseBuilder,
     security::{
-        EnhancedExpressionAnalyzer, EnhancedExpressionConfig, ExpressionAnalyzer,
-        ExpressionComplexityConfig, MemoryTracker,
+        evaluate_with_limits, EnhancedExpressionAnalyzer, EnhancedExpressionConfig,
+        EvaluationBudget, EvaluationLimitError, ExpressionAnalyzer, ExpressionComplexityConfig,
+        MemoryTracker,
     },
-    timeout::with_timeout,
 };
 use serde_json::{json, Value as JsonValue};
 use std::collections::BTreeMap;
@@ -117,23 +117,14 @@ impl CalculatorTool {
         AgentOutput::text(serde_json::to_string_pretty(&error_response).unwrap())
     }
 
-    /// Convert fasteval error to `LLMSpellError`
-    fn convert_error(&self, error: &FastevalError) -> LLMSpellError {
-        tool_error(error.to_string(), Some(self.metadata.name.clone()))
-    }
-
-    fn validate_expression_complexity(&self, expression: &str) -> Result<()> {
-        let complexity = self.analyzer.analyze(expression);
-        if !complexity.is_safe {
-            return Err(validation_error(
-                format!(
-                    "Expression too complex: {}",
-                    complexity.unsafe_reason.as_ref().unwrap_or(&String::new())
-                ),
-                Some("input".to_string()),
-            ));
+    /// Convert an evaluation error to `LLMSpellError`
+    fn convert_error(&self, error: EvaluationLimitError) -> LLMSpellError {
+        match error {
+            EvaluationLimitError::Failed(message) => {
+                tool_error(message, Some(self.metadata.name.clone()))
+            }
+            limit => validation_error(limit.to_string(), Some("input".to_string())),
         }
-        Ok(())
     }
 
     fn validate_expression_security(&self, expression: &str) -> Result<()> {
@@ -178,28 +169,19 @@ impl CalculatorTool {
         })
     }
 
-    #[instrument(skip(self))]
-    async fn evaluate_with_timeout(
-        &self,
+    /// Evaluate with fasteval, counting each variable lookup as a step
+    fn evaluate_counted(
         expression: &str,
-        variables: BTreeMap<String, f64>,
-    ) -> Result<f64> {
-        let max_eval_time = self.analyzer.max_evaluation_time();
-        let expr_clone = expression.to_string();
-        let vars_clone = variables.clone();
-
-        match with_timeout(max_eval_time, async move {
-            fasteval::ez_eval(&expr_clone, &mut vars_clone.clone())
-        })
-        .await
-        {
-            Ok(Ok(result)) => Ok(result),
-            Ok(Err(e)) => Err(self.convert_error(&e)),
-            Err(_) => Err(validation_error(
-                format!("Expression evaluation timed out after {max_eval_time:?}"),
-                Some("input".to_string()),
-            )),
-        }
+        variables: &BTreeMap<String, f64>,
+        budget: &EvaluationBudget,
+    ) -> std::result::Result<f64, EvaluationLimitError> {
+        // Lookups past the budget fail; `evaluate_with_limits` reports the limit
+        let mut namespace = |name: &str, _args: Vec<f64>| -> Option<f64> {
+            budget.step().ok()?;
+            variables.get(name).copied()
+        };
+        fasteval::ez_eval(expression, &mut namespace)
+            .map_err(|e: FastevalError| EvaluationLimitError::Failed(e.to_string()))
     }
 
     /// Evaluate expression with custom functions and variables
@@ -209,17 +191,20 @@ impl CalculatorTool {
         expression: &str,
         variables: &serde_json::Map<String, JsonValue>,
     ) -> Result<f64> {
-        // Validate complexity and security
-        self.validate_expression_complexity(expression)?;
+        // Validate security; complexity is checked by `evaluate_with_limits`
         self.validate_expression_security(expression)?;
 
         // Preprocess and validate
         let processed_expr = self.preprocess_custom_functions(expression);
         Self::validate_memory_requirements(&processed_expr, variables)?;
 
-        // Convert variables and evaluate
+        // Convert variables and evaluate under step and time limits
         let ns = Self::convert_variables(variables);
-        self.evaluate_with_timeout(&processed_expr, ns).await
+        evaluate_with_limits(expression, self.analyzer.config(), move |budget| {
+            Self::evaluate_counted(&processed_expr, &ns, budget)
+        })
+        .await
+        .map_err(|e| self.convert_error(e))
     }
 
     /// Preprocess expression to replace custom functions with their implementations
@@ -770,6 +770,7 @@ mod tests {
             context: None,
             parameters: params,
             output_modalities: vec![],
+            attachments: vec![],
         };
         let context = ExecutionContext::default();
 
@@ -793,6 +794,7 @@ mod tests {
             context: None,
             parameters: params,
             output_modalities: vec![],
+            attachments: vec![],
         };
         let context = ExecutionContext::default();
 
@@ -76,6 +76,7 @@ async fn test_web_search_tool_execution_through_registry() {
             map
         },
         output_modalities: vec![],
+        attachments: vec![],
     };
 
     let context = ExecutionContext::with_conversation("test".to_string());
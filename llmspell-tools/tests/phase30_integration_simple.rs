@@ -38,6 +38,7 @@ fn create_test_input(text: &str, params: Value) -> AgentInput {
             map
         },
         output_modalities: vec![],
+        attachments: vec![],
     }
 }
 
@@ -23,6 +23,7 @@ fn create_test_input(text: &str, params: serde_json::Value) -> AgentInput {
             map
         },
         output_modalities: vec![],
+        attachments: vec![],
     }
 }
 
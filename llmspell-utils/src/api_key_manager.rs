@@ -19,6 +19,7 @@ use parking_lot::RwLock;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::sync::Arc;
+use std::time::Duration;
 use tracing::{debug, info, warn};
 
 /// API key metadata
@@ -38,6 +39,9 @@ pub struct ApiKeyMetadata {
     pub is_active: bool,
     /// Usage count
     pub usage_count: u64,
+    /// Key this key was rotated to, while it stays valid for the overlap window
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rotated_to: Option<String>,
 }
 
 /// API key entry
@@ -195,6 +199,7 @@ impl ApiKeyManager {
                     expires_at: None,
                     is_active: true,
                     usage_count: 0,
+                    rotated_to: None,
                 };
 
                 self.add_key(&key_id, &value, metadata)?;
@@ -222,6 +227,7 @@ impl ApiKeyManager {
                 expires_at: None,
                 is_active: true,
                 usage_count: 0,
+                rotated_to: None,
             };
 
             self.add_key(&key_id, &key, metadata)?;
@@ -265,7 +271,11 @@ impl ApiKeyManager {
                 if let Some(_key) = storage.get(&key_id)? {
                     // Get metadata to check service directly from storage to avoid nested locks
                     if let Some(metadata) = storage.get_metadata(&key_id)? {
-                        if metadata.service == service && metadata.is_active {
+                        // Rotated keys stay valid during their overlap but are no longer primary
+                        if metadata.service == service
+                            && metadata.is_active
+                            && metadata.rotated_to.is_none()
+                        {
                             // Check expiration
                             if let Some(expires_at) = metadata.expires_at {
                                 if expires_at < Utc::now() {
@@ -341,6 +351,7 @@ impl ApiKeyManager {
                         expires_at: metadata.expires_at,
                         is_active: true,
                         usage_count: 0,
+                        rotated_to: None,
                     };
 
                     self.add_key(&new_key_id, new_key, new_metadata)?;
@@ -362,6 +373,99 @@ impl ApiKeyManager {
         Err(format!("No active key found for service '{}'", service))
     }
 
+    /// Rotate a key, keeping the old value valid for an overlap window
+    ///
+    /// The new key becomes primary immediately and is what `get_key` returns,
+    /// while the old key still passes `validate_key` until `overlap` has
+    /// elapsed, so requests already using it are not cut off. Returns the new
+    /// key ID.
+    pub fn rotate(
+        &self,
+        key_id: &str,
+        new_value: &str,
+        overlap: Duration,
+    ) -> Result<String, String> {
+        let now = Utc::now();
+        let overlap = chrono::Duration::from_std(overlap)
+            .map_err(|e| format!("Invalid overlap window: {}", e))?;
+        let overlap_end = now + overlap;
+
+        let mut metadata = self
+            .get_metadata(key_id)?
+            .ok_or_else(|| format!("Key '{}' not found", key_id))?;
+        if !metadata.is_active || metadata.rotated_to.is_some() {
+            return Err(format!("Key '{}' is not the active primary key", key_id));
+        }
+
+        let new_key_id = format!("{}_rotated_{}", key_id, now.timestamp());
+        let new_metadata = ApiKeyMetadata {
+            key_id: new_key_id.clone(),
+            service: metadata.service.clone(),
+            created_at: now,
+            last_used: None,
+            expires_at: metadata.expires_at,
+            is_active: true,
+            usage_count: 0,
+            rotated_to: None,
+        };
+        self.add_key(&new_key_id, new_value, new_metadata)?;
+
+        // The old key never outlives its original expiry
+        metadata.expires_at = Some(
+            metadata
+                .expires_at
+                .map_or(overlap_end, |expires_at| expires_at.min(overlap_end)),
+        );
+        metadata.rotated_to = Some(new_key_id.clone());
+        self.storage.write().update_metadata(key_id, &metadata)?;
+
+        self.log_action(ApiKeyAuditEntry {
+            timestamp: now,
+            key_id: key_id.to_string(),
+            service: metadata.service.clone(),
+            action: ApiKeyAction::Rotate,
+            details: Some(format!(
+                "Rotated to key '{}', old key valid until {}",
+                new_key_id,
+                overlap_end.to_rfc3339()
+            )),
+        });
+
+        info!(
+            "Rotated API key '{}' for service '{}' with {}s overlap",
+            key_id,
+            metadata.service,
+            overlap.num_seconds()
+        );
+        Ok(new_key_id)
+    }
+
+    /// Check whether a key value is currently valid for a service
+    ///
+    /// Accepts the primary key and any rotated key still inside its overlap
+    /// window.
+    pub fn validate_key(&self, service: &str, key: &str) -> Result<bool, String> {
+        let now = Utc::now();
+        let storage = self.storage.read();
+
+        for key_id in storage.list_keys()? {
+            let Some(metadata) = storage.get_metadata(&key_id)? else {
+                continue;
+            };
+            let expired = metadata
+                .expires_at
+                .is_some_and(|expires_at| expires_at < now);
+            if metadata.service != service || !metadata.is_active || expired {
+                continue;
+            }
+            if storage.get(&key_id)?.as_deref() == Some(key) {
+                return Ok(true);
+            }
+        }
+
+        Ok(false)
+    }
+
     /// Deactivate a key
     pub fn deactivate_key(&self, key_id: &str) -> Result<(), String> {
         let mut storage = self.storage.write();
@@ -424,6 +528,7 @@ mod tests {
             expires_at: None,
             is_active: true,
             usage_count: 0,
+            rotated_to: None,
         };
 
         manager
@@ -451,6 +556,7 @@ mod tests {
             expires_at: None,
             is_active: true,
             usage_count: 0,
+            rotated_to: None,
         };
 
         manager.add_key("test_key", "old_secret", metadata).unwrap();
@@ -469,6 +575,48 @@ mod tests {
             .any(|entry| matches!(entry.action, ApiKeyAction::Rotate)));
     }
     #[test]
+    fn test_rotation_overlap_window() {
+        let manager = ApiKeyManager::new();
+        let metadata = ApiKeyMetadata {
+            key_id: "test_key".to_string(),
+            service: "test_service".to_string(),
+            created_at: Utc::now(),
+            last_used: None,
+            expires_at: None,
+            is_active: true,
+            usage_count: 0,
+            rotated_to: None,
+        };
+        manager.add_key("test_key", "old_secret", metadata).unwrap();
+
+        let new_key_id = manager
+            .rotate("test_key", "new_secret", Duration::from_millis(200))
+            .unwrap();
+
+        // During the overlap both keys validate, but the new one is primary
+        assert!(manager.validate_key("test_service", "old_secret").unwrap());
+        assert!(manager.validate_key("test_service", "new_secret").unwrap());
+        assert_eq!(
+            manager.get_key("test_service").unwrap(),
+            Some("new_secret".to_string())
+        );
+        let old = manager.get_metadata("test_key").unwrap().unwrap();
+        assert_eq!(old.rotated_to, Some(new_key_id));
+        assert!(manager.rotate("test_key", "other", Duration::ZERO).is_err());
+
+        let rotation = manager
+            .get_audit_log(None)
+            .into_iter()
+            .find(|entry| entry.action == ApiKeyAction::Rotate)
+            .unwrap();
+        assert_eq!(rotation.key_id, "test_key");
+
+        std::thread::sleep(Duration::from_millis(300));
+        assert!(!manager.validate_key("test_service", "old_secret").unwrap());
+        assert!(manager.validate_key("test_service", "new_secret").unwrap());
+        assert!(!manager.validate_key("other_service", "new_secret").unwrap());
+    }
+    #[test]
     fn test_load_from_env() {
         // Set test environment variable
         std::env::set_var("LLMSPELL_API_KEY_TEST", "env_secret");
@@ -7,6 +7,7 @@
 //! including timeout management, cancellation tokens, and concurrency helpers.
 
 use futures::stream::{FuturesUnordered, StreamExt};
+use rand::Rng;
 use std::fmt::Debug;
 use std::future::Future;
 use std::pin::Pin;
@@ -52,8 +53,8 @@ pub struct RetryConfig {
     pub backoff_factor: f64,
     /// Maximum delay between retries
     pub max_delay: Duration,
-    /// Add jitter to retry delays
-    pub jitter: bool,
+    /// Jitter applied to each computed delay
+    pub jitter: JitterStrategy,
 }
 
 impl Default for RetryConfig {
@@ -63,7 +64,51 @@ impl Default for RetryConfig {
             initial_delay: Duration::from_millis(100),
             backoff_factor: 2.0,
             max_delay: Duration::from_secs(10),
-            jitter: true,
+            jitter: JitterStrategy::None,
+        }
+    }
+}
+
+impl RetryConfig {
+    /// Delay following `delay` under exponential backoff, capped at `max_delay`
+    #[allow(
+        clippy::cast_precision_loss,
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss
+    )]
+    fn next_delay(&self, delay: Duration) -> Duration {
+        Duration::from_millis(
+            ((delay.as_millis() as f64 * self.backoff_factor) as u64)
+                .min(self.max_delay.as_millis() as u64),
+        )
+    }
+}
+
+/// Randomization applied to retry delays to spread out concurrent retries
+///
+/// Jittered delays never exceed the computed backoff delay.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum JitterStrategy {
+    /// Use the computed delay exactly
+    #[default]
+    None,
+    /// Uniform in `[0, delay]`
+    Full,
+    /// Uniform in `[delay / 2, delay]`
+    Equal,
+}
+
+impl JitterStrategy {
+    /// Apply this jitter strategy to a computed delay
+    pub fn apply<R: Rng + ?Sized>(self, delay: Duration, rng: &mut R) -> Duration {
+        let nanos = u64::try_from(delay.as_nanos()).unwrap_or(u64::MAX);
+        match self {
+            Self::None => delay,
+            Self::Full => Duration::from_nanos(rng.gen_range(0..=nanos)),
+            Self::Equal => {
+                let half = nanos / 2;
+                Duration::from_nanos(half + rng.gen_range(0..=nanos - half))
+            }
         }
     }
 }
@@ -156,11 +201,6 @@ where
 /// # Ok(())
 /// # }
 /// ```
-#[allow(
-    clippy::cast_precision_loss,
-    clippy::cast_possible_truncation,
-    clippy::cast_sign_loss
-)]
 pub async fn retry_async<T, E, F, Fut>(config: RetryConfig, mut f: F) -> Result<T, AsyncError>
 where
     F: FnMut() -> Fut,
@@ -190,21 +230,12 @@ where
                 );
 
                 // Add jitter if configured
-                let actual_delay = if config.jitter {
-                    let jitter_range = delay.as_millis() as f64 * 0.1;
-                    let jitter = (rand::random::<f64>() - 0.5) * 2.0 * jitter_range;
-                    Duration::from_millis((delay.as_millis() as f64 + jitter).max(0.0) as u64)
-                } else {
-                    delay
-                };
+                let actual_delay = config.jitter.apply(delay, &mut rand::thread_rng());
 
                 time::sleep(actual_delay).await;
 
                 // Calculate next delay with exponential backoff
-                delay = Duration::from_millis(
-                    ((delay.as_millis() as f64 * config.backoff_factor) as u64)
-                        .min(config.max_delay.as_millis() as u64),
-                );
+                delay = config.next_delay(delay);
             }
         }
     }
@@ -284,6 +315,126 @@ where
     results
 }
 
+/// Outcome of [`concurrent_map_bounded`]
+///
+/// Entries are `(input index, value)` pairs, each list in input order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BatchResults<U, E> {
+    /// Successful results
+    pub successes: Vec<(usize, U)>,
+    /// Per-item errors
+    pub errors: Vec<(usize, E)>,
+}
+
+impl<U, E> BatchResults<U, E> {
+    /// Whether every item succeeded
+    #[must_use]
+    pub fn is_complete(&self) -> bool {
+        self.errors.is_empty()
+    }
+}
+
+/// How [`concurrent_map_bounded_with_mode`] handles per-item errors
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BatchErrorMode {
+    /// Run every item and collect all errors
+    #[default]
+    Collect,
+    /// Stop at the first error, cancelling items still in flight
+    ShortCircuit,
+}
+
+/// Map a fallible async function over items with at most `limit` in flight
+///
+/// Unlike failing on the first error, every item is run and both successes
+/// and errors are collected. A `limit` of 0 is treated as 1.
+///
+/// # Examples
+///
+/// ```
+/// use llmspell_utils::async_utils::concurrent_map_bounded;
+///
+/// # async fn example() {
+/// let results = concurrent_map_bounded(vec![1, 2, 3], 2, |n| async move {
+///     if n == 2 { Err("two") } else { Ok(n * 10) }
+/// })
+/// .await;
+///
+/// assert_eq!(results.successes, vec![(0, 10), (2, 30)]);
+/// assert_eq!(results.errors, vec![(1, "two")]);
+/// # }
+/// ```
+pub async fn concurrent_map_bounded<I, F, Fut, T, U, E>(
+    items: I,
+    limit: usize,
+    f: F,
+) -> BatchResults<U, E>
+where
+    I: IntoIterator<Item = T>,
+    F: Fn(T) -> Fut,
+    Fut: Future<Output = Result<U, E>>,
+{
+    concurrent_map_bounded_with_mode(items, limit, BatchErrorMode::Collect, f).await
+}
+
+/// Map a fallible async function over items with at most `limit` in flight
+///
+/// Like [`concurrent_map_bounded`], but with [`BatchErrorMode::ShortCircuit`]
+/// no further items start after the first error, and items still in flight
+/// are cancelled. Results are always reported in input order.
+pub async fn concurrent_map_bounded_with_mode<I, F, Fut, T, U, E>(
+    items: I,
+    limit: usize,
+    mode: BatchErrorMode,
+    f: F,
+) -> BatchResults<U, E>
+where
+    I: IntoIterator<Item = T>,
+    F: Fn(T) -> Fut,
+    Fut: Future<Output = Result<U, E>>,
+{
+    let semaphore = tokio::sync::Semaphore::new(limit.max(1));
+    let mut tasks: FuturesUnordered<_> = items
+        .into_iter()
+        .enumerate()
+        .map(|(index, item)| {
+            let semaphore = &semaphore;
+            let f = &f;
+            async move {
+                // The semaphore is never closed, so acquire cannot fail
+                let _permit = semaphore.acquire().await.ok();
+                (index, f(item).await)
+            }
+        })
+        .collect();
+
+    let mut results = BatchResults {
+        successes: Vec::new(),
+        errors: Vec::new(),
+    };
+    while let Some((index, result)) = tasks.next().await {
+        match result {
+            Ok(value) => results.successes.push((index, value)),
+            Err(error) => {
+                results.errors.push((index, error));
+                if mode == BatchErrorMode::ShortCircuit {
+                    break;
+                }
+            }
+        }
+    }
+    drop(tasks);
+    results.successes.sort_by_key(|(index, _)| *index);
+    results.errors.sort_by_key(|(index, _)| *index);
+
+    debug!(
+        "Bounded concurrent map finished: {} succeeded, {} failed",
+        results.successes.len(),
+        results.errors.len()
+    );
+    results
+}
+
 /// A future that can be cancelled
 pub struct CancellableFuture<F> {
     future: F,
@@ -448,7 +599,7 @@ mod tests {
             initial_delay: Duration::from_millis(10),
             backoff_factor: 1.0,
             max_delay: Duration::from_millis(10),
-            jitter: false,
+            jitter: JitterStrategy::None,
         };
 
         let result = retry_async(config, || {
@@ -477,7 +628,7 @@ mod tests {
             initial_delay: Duration::from_millis(10),
             backoff_factor: 1.0,
             max_delay: Duration::from_millis(10),
-            jitter: false,
+            jitter: JitterStrategy::None,
         };
 
         let result = retry_async(config, || {
@@ -521,6 +672,87 @@ mod tests {
         assert!(elapsed < Duration::from_millis(200));
     }
     #[tokio::test]
+    async fn test_concurrent_map_bounded_collects_errors() {
+        let in_flight = Arc::new(AtomicUsize::new(0));
+        let max_in_flight = Arc::new(AtomicUsize::new(0));
+
+        let results = concurrent_map_bounded(0..8, 3, |n| {
+            let in_flight = Arc::clone(&in_flight);
+            let max_in_flight = Arc::clone(&max_in_flight);
+            async move {
+                let current = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                max_in_flight.fetch_max(current, Ordering::SeqCst);
+                // Later items finish first to exercise ordering
+                sleep(Duration::from_millis(40 - n * 5)).await;
+                in_flight.fetch_sub(1, Ordering::SeqCst);
+
+                if n % 3 == 0 {
+                    Err(format!("item {n} failed"))
+                } else {
+                    Ok(n * 2)
+                }
+            }
+        })
+        .await;
+
+        assert_eq!(
+            results.successes,
+            vec![(1, 2), (2, 4), (4, 8), (5, 10), (7, 14)]
+        );
+        assert_eq!(
+            results.errors,
+            vec![
+                (0, "item 0 failed".to_string()),
+                (3, "item 3 failed".to_string()),
+                (6, "item 6 failed".to_string()),
+            ]
+        );
+        assert!(!results.is_complete());
+        assert_eq!(max_in_flight.load(Ordering::SeqCst), 3);
+    }
+    #[tokio::test]
+    async fn test_concurrent_map_bounded_short_circuit() {
+        let started = Arc::new(AtomicUsize::new(0));
+        let in_flight = Arc::new(AtomicUsize::new(0));
+        let max_in_flight = Arc::new(AtomicUsize::new(0));
+
+        let results =
+            concurrent_map_bounded_with_mode(0..100u64, 4, BatchErrorMode::ShortCircuit, |n| {
+                let started = Arc::clone(&started);
+                let in_flight = Arc::clone(&in_flight);
+                let max_in_flight = Arc::clone(&max_in_flight);
+                async move {
+                    started.fetch_add(1, Ordering::SeqCst);
+                    let current = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                    max_in_flight.fetch_max(current, Ordering::SeqCst);
+                    sleep(Duration::from_millis(5 + (n % 4) * 3)).await;
+                    in_flight.fetch_sub(1, Ordering::SeqCst);
+
+                    if n == 10 {
+                        Err("boom")
+                    } else {
+                        Ok(n)
+                    }
+                }
+            })
+            .await;
+
+        assert_eq!(results.errors, vec![(10, "boom")]);
+        assert!(max_in_flight.load(Ordering::SeqCst) <= 4);
+        // Nothing new starts after the failure
+        assert!(started.load(Ordering::SeqCst) < 100);
+
+        // Successes come back in input order, each matching its input
+        let indices: Vec<usize> = results.successes.iter().map(|(i, _)| *i).collect();
+        let mut sorted = indices.clone();
+        sorted.sort_unstable();
+        assert_eq!(indices, sorted);
+        assert!(results
+            .successes
+            .iter()
+            .all(|(i, value)| u64::try_from(*i).unwrap() == *value));
+    }
+    #[tokio::test]
     async fn test_cancellable_future() {
         let mut future = CancellableFuture::new(async {
             sleep(Duration::from_millis(100)).await;
@@ -558,6 +790,43 @@ mod tests {
         assert!(jittered.as_millis() >= 90);
         assert!(jittered.as_millis() <= 110);
     }
+    #[test]
+    fn test_jitter_strategies() {
+        use rand::{rngs::StdRng, SeedableRng};
+
+        let config = RetryConfig {
+            max_attempts: 6,
+            initial_delay: Duration::from_millis(10),
+            backoff_factor: 2.0,
+            max_delay: Duration::from_millis(100),
+            jitter: JitterStrategy::None,
+        };
+        let mut delays = vec![config.initial_delay];
+        for _ in 1..5 {
+            delays.push(config.next_delay(delays[delays.len() - 1]));
+        }
+        let expected: Vec<_> = [10, 20, 40, 80, 100]
+            .into_iter()
+            .map(Duration::from_millis)
+            .collect();
+        assert_eq!(delays, expected);
+
+        let mut rng = StdRng::seed_from_u64(42);
+        for &delay in &delays {
+            assert_eq!(JitterStrategy::None.apply(delay, &mut rng), delay);
+            for _ in 0..100 {
+                assert!(JitterStrategy::Full.apply(delay, &mut rng) <= delay);
+                let equal = JitterStrategy::Equal.apply(delay, &mut rng);
+                assert!(equal >= delay / 2 && equal <= delay);
+            }
+        }
+
+        // Full jitter actually spreads delays out
+        let spread: std::collections::HashSet<_> = (0..10)
+            .map(|_| JitterStrategy::Full.apply(delays[4], &mut rng))
+            .collect();
+        assert!(spread.len() > 1);
+    }
     #[tokio::test]
     async fn test_retry_with_backoff() {
         let counter = Arc::new(AtomicUsize::new(0));
@@ -570,7 +839,7 @@ mod tests {
             initial_delay: Duration::from_millis(10),
             backoff_factor: 2.0,
             max_delay: Duration::from_millis(100),
-            jitter: false,
+            jitter: JitterStrategy::None,
         };
 
         let start = std::time::Instant::now();
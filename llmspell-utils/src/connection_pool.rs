@@ -2,10 +2,11 @@
 // ABOUTME: Provides a generic pooling mechanism with health checks and lifecycle management
 
 use std::fmt::Debug;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 use thiserror::Error;
-use tokio::sync::{Mutex, Semaphore};
+use tokio::sync::{Mutex, Notify, Semaphore};
 use tracing::{debug, info, warn};
 
 /// Connection pool error types
@@ -81,6 +82,8 @@ pub struct PoolConfig {
     pub min_size: usize,
     /// Maximum number of connections allowed
     pub max_size: usize,
+    /// Number of idle connections kept ready by the background maintainer
+    pub min_idle: usize,
     /// Maximum time to wait for a connection
     pub acquisition_timeout: Duration,
     /// Maximum idle time before closing a connection
@@ -98,6 +101,7 @@ impl Default for PoolConfig {
         Self {
             min_size: 1,
             max_size: 10,
+            min_idle: 0,
             acquisition_timeout: Duration::from_secs(30),
             idle_timeout: Duration::from_secs(600), // 10 minutes
             max_lifetime: Duration::from_secs(3600), // 1 hour
@@ -112,7 +116,8 @@ impl PoolConfig {
     ///
     /// # Errors
     ///
-    /// Returns `PoolError::InvalidConfiguration` if `min_size` > `max_size` or `max_size` is 0.
+    /// Returns `PoolError::InvalidConfiguration` if `min_size` or `min_idle` > `max_size`,
+    /// or `max_size` is 0.
     pub fn validate(&self) -> Result<(), PoolError> {
         if self.min_size > self.max_size {
             return Err(PoolError::InvalidConfiguration {
@@ -120,6 +125,12 @@ impl PoolConfig {
             });
         }
 
+        if self.min_idle > self.max_size {
+            return Err(PoolError::InvalidConfiguration {
+                message: "min_idle cannot be greater than max_size".to_string(),
+            });
+        }
+
         if self.max_size == 0 {
             return Err(PoolError::InvalidConfiguration {
                 message: "max_size must be greater than 0".to_string(),
@@ -178,6 +189,9 @@ pub struct ConnectionPool<F: ConnectionFactory> {
     connections: Arc<Mutex<Vec<PooledConnection<F::Connection>>>>,
     semaphore: Arc<Semaphore>,
     shutdown: Arc<Mutex<bool>>,
+    idle_notify: Arc<Notify>,
+    /// Connections being created by `warm_up`, only changed while holding `connections`
+    warming: Arc<AtomicUsize>,
 }
 
 impl<F: ConnectionFactory> ConnectionPool<F> {
@@ -196,6 +210,8 @@ impl<F: ConnectionFactory> ConnectionPool<F> {
             connections: Arc::new(Mutex::new(Vec::new())),
             semaphore: Arc::new(Semaphore::new(config.max_size)),
             shutdown: Arc::new(Mutex::new(false)),
+            idle_notify: Arc::new(Notify::new()),
+            warming: Arc::new(AtomicUsize::new(0)),
         };
 
         // Create initial connections
@@ -204,6 +220,11 @@ impl<F: ConnectionFactory> ConnectionPool<F> {
         // Start background health check task
         pool.start_health_check_task();
 
+        // Start background idle maintainer
+        if pool.config.min_idle > 0 {
+            pool.start_idle_maintainer_task();
+        }
+
         Ok(pool)
     }
 
@@ -255,6 +276,7 @@ impl<F: ConnectionFactory> ConnectionPool<F> {
 
                     p.mark_used();
                     let connection = p.connection;
+                    self.idle_notify.notify_one();
 
                     return Ok(PoolGuard {
                         pool: self.clone(),
@@ -270,6 +292,7 @@ impl<F: ConnectionFactory> ConnectionPool<F> {
         match self.factory.create().await {
             Ok(connection) => {
                 debug!("Created new connection");
+                self.idle_notify.notify_one();
                 Ok(PoolGuard {
                     pool: self.clone(),
                     connection: Some(connection),
@@ -331,6 +354,113 @@ impl<F: ConnectionFactory> ConnectionPool<F> {
         Ok(())
     }
 
+    /// Create idle connections until `min_idle` are available
+    ///
+    /// Idle connections that fail validation or have expired are closed and
+    /// replaced first. Slots for new connections are reserved under the pool
+    /// lock, holding a semaphore permit each until the connection is idle, so
+    /// concurrent warm-ups and checkouts never exceed `min_idle` or `max_size`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PoolError::CreationFailed` if a new connection cannot be created.
+    pub async fn warm_up(&self) -> Result<(), PoolError> {
+        // Close idle connections that are no longer usable
+        let mut failed = Vec::new();
+        {
+            let mut connections = self.connections.lock().await;
+            let mut i = 0;
+            while i < connections.len() {
+                let pooled = &connections[i];
+                if pooled.is_expired(&self.config)
+                    || !self.factory.validate(&pooled.connection).await
+                {
+                    failed.push(connections.remove(i));
+                } else {
+                    i += 1;
+                }
+            }
+        }
+        for pooled in failed {
+            debug!("Idle connection failed validation, replacing");
+            let _ = pooled.connection.close().await;
+        }
+
+        // Reserve the connections to create, counting those other warm-ups are creating
+        let mut reserved = {
+            let connections = self.connections.lock().await;
+            let idle = connections.len();
+            let warming = self.warming.load(Ordering::SeqCst);
+            let wanted = self.config.min_idle.saturating_sub(idle + warming);
+            // Idle connections hold no permit, so they take room from the permits left
+            let room = self.semaphore.available_permits().saturating_sub(idle);
+
+            let mut reserved = Vec::with_capacity(wanted.min(room));
+            while reserved.len() < wanted.min(room) {
+                let Ok(permit) = self.semaphore.clone().try_acquire_owned() else {
+                    break;
+                };
+                reserved.push(permit);
+            }
+            self.warming.fetch_add(reserved.len(), Ordering::SeqCst);
+            reserved
+        };
+
+        while let Some(permit) = reserved.pop() {
+            let created = self.factory.create().await;
+
+            let mut connections = self.connections.lock().await;
+            match created {
+                Ok(connection) => {
+                    connections.push(PooledConnection::new(connection));
+                    self.warming.fetch_sub(1, Ordering::SeqCst);
+                    drop(permit);
+                }
+                Err(e) => {
+                    // Release this and every remaining reservation
+                    self.warming.fetch_sub(reserved.len() + 1, Ordering::SeqCst);
+                    return Err(PoolError::CreationFailed {
+                        message: e.to_string(),
+                    });
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Start background task keeping `min_idle` connections ready
+    ///
+    /// Runs after every checkout and on each health check interval.
+    fn start_idle_maintainer_task(&self) {
+        let pool = self.clone();
+        let interval = self.config.health_check_interval;
+
+        tokio::spawn(async move {
+            // The first tick is one interval out; warm-up at startup is `build_and_warm`'s job
+            let mut interval =
+                tokio::time::interval_at(tokio::time::Instant::now() + interval, interval);
+
+            loop {
+                tokio::select! {
+                    () = pool.idle_notify.notified() => {}
+                    _ = interval.tick() => {}
+                }
+
+                // Check if shutting down
+                if *pool.shutdown.lock().await {
+                    break;
+                }
+
+                if let Err(e) = pool.warm_up().await {
+                    warn!("Failed to maintain idle connections: {}", e);
+                }
+            }
+
+            info!("Idle maintainer task stopped");
+        });
+    }
+
     /// Start background health check task
     fn start_health_check_task(&self) {
         let pool = self.clone();
@@ -397,6 +527,7 @@ impl<F: ConnectionFactory> ConnectionPool<F> {
 
         // Mark as shutting down
         *self.shutdown.lock().await = true;
+        self.idle_notify.notify_one();
 
         // Close all connections
         let mut connections = self.connections.lock().await;
@@ -414,6 +545,8 @@ impl<F: ConnectionFactory> Clone for ConnectionPool<F> {
             connections: self.connections.clone(),
             semaphore: self.semaphore.clone(),
             shutdown: self.shutdown.clone(),
+            idle_notify: self.idle_notify.clone(),
+            warming: self.warming.clone(),
         }
     }
 }
@@ -525,6 +658,13 @@ impl<F: ConnectionFactory> PoolBuilder<F> {
         self
     }
 
+    /// Set number of idle connections kept ready
+    #[must_use]
+    pub fn min_idle(mut self, size: usize) -> Self {
+        self.config.min_idle = size;
+        self
+    }
+
     /// Set acquisition timeout
     #[must_use]
     pub fn acquisition_timeout(mut self, timeout: Duration) -> Self {
@@ -562,6 +702,18 @@ impl<F: ConnectionFactory> PoolBuilder<F> {
     pub async fn build(self) -> Result<ConnectionPool<F>, PoolError> {
         ConnectionPool::new(self.factory, self.config).await
     }
+
+    /// Build the pool and wait until `min_idle` connections are established
+    ///
+    /// # Errors
+    ///
+    /// Returns `PoolError::InvalidConfiguration` if the configuration is invalid.
+    /// Returns `PoolError::CreationFailed` if warm-up connections cannot be created.
+    pub async fn build_and_warm(self) -> Result<ConnectionPool<F>, PoolError> {
+        let pool = self.build().await?;
+        pool.warm_up().await?;
+        Ok(pool)
+    }
 }
 
 #[cfg(test)]
@@ -709,4 +861,70 @@ mod tests {
         let conn = pool.acquire().await.unwrap();
         assert_eq!(conn.id, 1); // Should be a new connection
     }
+    #[tokio::test]
+    async fn test_min_idle_maintained() {
+        let counter = Arc::new(AtomicU32::new(0));
+        let factory = MockFactory {
+            counter: counter.clone(),
+            valid: Arc::new(AtomicBool::new(true)),
+        };
+
+        let pool = PoolBuilder::new(factory)
+            .min_size(0)
+            .min_idle(3)
+            .max_size(10)
+            .build_and_warm()
+            .await
+            .unwrap();
+
+        // Warmed up before any checkout
+        assert_eq!(pool.stats().await.available, 3);
+
+        // Checkouts are backfilled to keep the idle floor
+        let conn1 = pool.acquire().await.unwrap();
+        let conn2 = pool.acquire().await.unwrap();
+        tokio::time::sleep(Duration::from_millis(100)).await;
+
+        let stats = pool.stats().await;
+        assert_eq!(stats.in_use, 2);
+        assert!(stats.available >= 3, "idle floor not kept: {stats:?}");
+
+        // Returned connections only add to the idle set
+        drop(conn1);
+        drop(conn2);
+        tokio::time::sleep(Duration::from_millis(100)).await;
+
+        let stats = pool.stats().await;
+        assert_eq!(stats.in_use, 0);
+        assert!(stats.available >= 3, "idle floor not kept: {stats:?}");
+        assert!(stats.total <= stats.max_size);
+    }
+    #[tokio::test]
+    async fn test_concurrent_warm_up_respects_limits() {
+        let factory = MockFactory {
+            counter: Arc::new(AtomicU32::new(0)),
+            valid: Arc::new(AtomicBool::new(true)),
+        };
+
+        let pool = PoolBuilder::new(factory)
+            .min_size(0)
+            .min_idle(3)
+            .max_size(4)
+            .build()
+            .await
+            .unwrap();
+
+        // The checkout also wakes the background maintainer
+        let _conn = pool.acquire().await.unwrap();
+        let (first, second, third) = tokio::join!(pool.warm_up(), pool.warm_up(), pool.warm_up());
+        first.unwrap();
+        second.unwrap();
+        third.unwrap();
+        tokio::time::sleep(Duration::from_millis(100)).await;
+
+        let stats = pool.stats().await;
+        assert_eq!(stats.in_use, 1);
+        assert_eq!(stats.available, 3);
+        assert_eq!(stats.total, stats.max_size);
+    }
 }
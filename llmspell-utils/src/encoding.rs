@@ -1,11 +1,12 @@
 // ABOUTME: Encoding and hashing utilities for llmspell
-// ABOUTME: Provides hash calculation, base64 encoding, and other encoding functions
+// ABOUTME: Provides hash calculation, base64/base32/percent encoding, and other encoding functions
 
 //! Encoding and hashing utilities
 //!
 //! This module provides various encoding and hashing functions including:
-//! - Hash calculation (MD5, SHA-1, SHA-256, SHA-512)
-//! - Base64 encoding/decoding
+//! - Hash calculation (MD5, SHA-1, SHA-256, SHA-512), one-shot or streaming
+//! - Base64 and Base32 encoding/decoding
+//! - Percent (URL) encoding/decoding
 //! - Hex encoding/decoding
 
 use base64::{engine::general_purpose, Engine as _};
@@ -73,6 +74,92 @@ pub fn hash_string(text: &str, algorithm: HashAlgorithm) -> Vec<u8> {
     hash_data(text.as_bytes(), algorithm)
 }
 
+/// Incremental hasher for data that arrives in chunks
+///
+/// Produces the same digest as [`hash_data`] over the concatenated input.
+pub struct Hasher {
+    algorithm: HashAlgorithm,
+    state: HasherState,
+}
+
+enum HasherState {
+    Md5(md5::Context),
+    Sha1(Sha1),
+    Sha256(Sha256),
+    Sha512(Sha512),
+}
+
+impl Hasher {
+    /// Create a hasher for the given algorithm
+    #[must_use]
+    pub fn new(algorithm: HashAlgorithm) -> Self {
+        let state = match algorithm {
+            HashAlgorithm::Md5 => HasherState::Md5(md5::Context::new()),
+            HashAlgorithm::Sha1 => HasherState::Sha1(Sha1::new()),
+            HashAlgorithm::Sha256 => HasherState::Sha256(Sha256::new()),
+            HashAlgorithm::Sha512 => HasherState::Sha512(Sha512::new()),
+        };
+        Self { algorithm, state }
+    }
+
+    /// Algorithm this hasher computes
+    #[must_use]
+    pub const fn algorithm(&self) -> HashAlgorithm {
+        self.algorithm
+    }
+
+    /// Feed the next chunk of data
+    pub fn update(&mut self, data: &[u8]) {
+        match &mut self.state {
+            HasherState::Md5(hasher) => hasher.consume(data),
+            HasherState::Sha1(hasher) => hasher.update(data),
+            HasherState::Sha256(hasher) => hasher.update(data),
+            HasherState::Sha512(hasher) => hasher.update(data),
+        }
+    }
+
+    /// Consume the hasher and return the digest
+    #[must_use]
+    pub fn finalize(self) -> Vec<u8> {
+        match self.state {
+            HasherState::Md5(hasher) => hasher.compute().0.to_vec(),
+            HasherState::Sha1(hasher) => hasher.finalize().to_vec(),
+            HasherState::Sha256(hasher) => hasher.finalize().to_vec(),
+            HasherState::Sha512(hasher) => hasher.finalize().to_vec(),
+        }
+    }
+}
+
+impl std::fmt::Debug for Hasher {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Hasher")
+            .field("algorithm", &self.algorithm)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Calculate hash of everything read from a reader without buffering it
+///
+/// # Errors
+///
+/// Returns an error if reading fails
+pub fn hash_reader<R: Read>(mut reader: R, algorithm: HashAlgorithm) -> std::io::Result<Vec<u8>> {
+    let mut hasher = Hasher::new(algorithm);
+    let mut buffer = [0; 8192]; // 8KB buffer
+
+    loop {
+        let bytes_read = match reader.read(&mut buffer) {
+            Ok(0) => break,
+            Ok(n) => n,
+            Err(e) if e.kind() == std::io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e),
+        };
+        hasher.update(&buffer[..bytes_read]);
+    }
+
+    Ok(hasher.finalize())
+}
+
 /// Calculate hash of a file with streaming (memory efficient)
 ///
 /// # Errors
@@ -80,55 +167,7 @@ pub fn hash_string(text: &str, algorithm: HashAlgorithm) -> Vec<u8> {
 /// Returns an error if the file cannot be opened or read
 pub fn hash_file<P: AsRef<Path>>(path: P, algorithm: HashAlgorithm) -> std::io::Result<Vec<u8>> {
     let file = File::open(path)?;
-    let mut reader = BufReader::new(file);
-    let mut buffer = [0; 8192]; // 8KB buffer
-
-    match algorithm {
-        HashAlgorithm::Md5 => {
-            let mut hasher = md5::Context::new();
-            loop {
-                let bytes_read = reader.read(&mut buffer)?;
-                if bytes_read == 0 {
-                    break;
-                }
-                hasher.consume(&buffer[..bytes_read]);
-            }
-            Ok(hasher.compute().0.to_vec())
-        }
-        HashAlgorithm::Sha1 => {
-            let mut hasher = Sha1::new();
-            loop {
-                let bytes_read = reader.read(&mut buffer)?;
-                if bytes_read == 0 {
-                    break;
-                }
-                hasher.update(&buffer[..bytes_read]);
-            }
-            Ok(hasher.finalize().to_vec())
-        }
-        HashAlgorithm::Sha256 => {
-            let mut hasher = Sha256::new();
-            loop {
-                let bytes_read = reader.read(&mut buffer)?;
-                if bytes_read == 0 {
-                    break;
-                }
-                hasher.update(&buffer[..bytes_read]);
-            }
-            Ok(hasher.finalize().to_vec())
-        }
-        HashAlgorithm::Sha512 => {
-            let mut hasher = Sha512::new();
-            loop {
-                let bytes_read = reader.read(&mut buffer)?;
-                if bytes_read == 0 {
-                    break;
-                }
-                hasher.update(&buffer[..bytes_read]);
-            }
-            Ok(hasher.finalize().to_vec())
-        }
-    }
+    hash_reader(BufReader::new(file), algorithm)
 }
 
 /// Convert bytes to hexadecimal string
@@ -187,6 +226,170 @@ pub fn base64_decode_url_safe(encoded: &str) -> Result<Vec<u8>, base64::DecodeEr
     general_purpose::URL_SAFE.decode(encoded)
 }
 
+/// RFC 4648 base32 alphabet
+const BASE32_ALPHABET: &[u8; 32] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+
+/// Errors from [`base32_decode`]
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum Base32DecodeError {
+    /// Input length is not a multiple of 8
+    #[error("invalid base32 length {0}: must be a multiple of 8")]
+    InvalidLength(usize),
+    /// Character outside the base32 alphabet
+    #[error("invalid base32 character {0:?} at position {1}")]
+    InvalidCharacter(char, usize),
+    /// Padding in the wrong place or of a length no input can produce
+    #[error("invalid base32 padding")]
+    InvalidPadding,
+}
+
+/// Base32 encode data (RFC 4648, padded)
+#[must_use]
+pub fn base32_encode(data: &[u8]) -> String {
+    let mut encoded = String::with_capacity(data.len().div_ceil(5) * 8);
+    for chunk in data.chunks(5) {
+        let mut block = [0u8; 5];
+        block[..chunk.len()].copy_from_slice(chunk);
+        let bits = block
+            .iter()
+            .fold(0u64, |acc, &byte| (acc << 8) | u64::from(byte));
+
+        // Each input byte contributes 8 bits, rounded up to 5-bit symbols
+        let symbols = (chunk.len() * 8).div_ceil(5);
+        for i in 0..8 {
+            if i < symbols {
+                // Masked to 5 bits, so the cast cannot truncate
+                #[allow(clippy::cast_possible_truncation)]
+                let index = ((bits >> (35 - i * 5)) & 0x1F) as usize;
+                encoded.push(char::from(BASE32_ALPHABET[index]));
+            } else {
+                encoded.push('=');
+            }
+        }
+    }
+    encoded
+}
+
+/// Base32 decode data (RFC 4648)
+///
+/// Input must be padded to a multiple of 8 characters. Lowercase letters are
+/// accepted.
+///
+/// # Errors
+///
+/// Returns an error if the length, padding or any character is invalid
+pub fn base32_decode(encoded: &str) -> Result<Vec<u8>, Base32DecodeError> {
+    let input = encoded.as_bytes();
+    if input.len() % 8 != 0 {
+        return Err(Base32DecodeError::InvalidLength(input.len()));
+    }
+
+    let mut decoded = Vec::with_capacity(input.len() / 8 * 5);
+    let blocks = input.len() / 8;
+    for (block_index, block) in input.chunks(8).enumerate() {
+        let symbols = block.iter().position(|&c| c == b'=').unwrap_or(8);
+        if block[symbols..].iter().any(|&c| c != b'=') || (symbols < 8 && block_index + 1 != blocks)
+        {
+            return Err(Base32DecodeError::InvalidPadding);
+        }
+
+        // Symbol counts 1, 3 and 6 do not correspond to a whole number of bytes
+        let bytes = match symbols {
+            8 => 5,
+            7 => 4,
+            5 => 3,
+            4 => 2,
+            2 => 1,
+            _ => return Err(Base32DecodeError::InvalidPadding),
+        };
+
+        let mut bits = 0u64;
+        for (i, &c) in block[..symbols].iter().enumerate() {
+            let value = base32_value(c).ok_or_else(|| {
+                Base32DecodeError::InvalidCharacter(char::from(c), block_index * 8 + i)
+            })?;
+            bits |= u64::from(value) << (35 - i * 5);
+        }
+        decoded.extend_from_slice(&bits.to_be_bytes()[3..3 + bytes]);
+    }
+    Ok(decoded)
+}
+
+/// Map a base32 character to its 5-bit value
+const fn base32_value(c: u8) -> Option<u8> {
+    match c {
+        b'A'..=b'Z' => Some(c - b'A'),
+        b'a'..=b'z' => Some(c - b'a'),
+        b'2'..=b'7' => Some(c - b'2' + 26),
+        _ => None,
+    }
+}
+
+/// Errors from [`percent_decode`]
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum PercentDecodeError {
+    /// `%` not followed by two hex digits
+    #[error("invalid percent escape at position {0}")]
+    InvalidEscape(usize),
+    /// Decoded bytes are not valid UTF-8
+    #[error("percent-decoded data is not valid UTF-8")]
+    InvalidUtf8,
+}
+
+/// Percent-encode a string (RFC 3986)
+///
+/// Unreserved characters (`A-Z a-z 0-9 - . _ ~`) are never encoded. Any ASCII
+/// characters in `safe` are also left as-is; everything else is encoded as
+/// `%XX` per UTF-8 byte. Pass `"/"` to keep path separators, or `""` to
+/// encode every reserved character.
+#[must_use]
+pub fn percent_encode(input: &str, safe: &str) -> String {
+    use std::fmt::Write;
+    let safe = safe.as_bytes();
+    input
+        .bytes()
+        .fold(String::with_capacity(input.len()), |mut output, byte| {
+            if byte.is_ascii_alphanumeric()
+                || matches!(byte, b'-' | b'.' | b'_' | b'~')
+                || (byte.is_ascii() && safe.contains(&byte))
+            {
+                output.push(char::from(byte));
+            } else {
+                let _ = write!(output, "%{byte:02X}");
+            }
+            output
+        })
+}
+
+/// Decode a percent-encoded string
+///
+/// Hex digits are accepted in either case. `+` is not treated as a space.
+///
+/// # Errors
+///
+/// Returns an error if an escape is malformed or the result is not UTF-8
+pub fn percent_decode(encoded: &str) -> Result<String, PercentDecodeError> {
+    let input = encoded.as_bytes();
+    let mut decoded = Vec::with_capacity(input.len());
+    let mut i = 0;
+    while i < input.len() {
+        if input[i] == b'%' {
+            let byte = input
+                .get(i + 1..i + 3)
+                .and_then(|hex| std::str::from_utf8(hex).ok())
+                .filter(|hex| hex.bytes().all(|c| c.is_ascii_hexdigit()))
+                .and_then(|hex| u8::from_str_radix(hex, 16).ok())
+                .ok_or(PercentDecodeError::InvalidEscape(i))?;
+            decoded.push(byte);
+            i += 3;
+        } else {
+            decoded.push(input[i]);
+            i += 1;
+        }
+    }
+    String::from_utf8(decoded).map_err(|_| PercentDecodeError::InvalidUtf8)
+}
+
 /// Text encoding types
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
@@ -464,6 +667,28 @@ mod tests {
         );
     }
     #[test]
+    fn test_streaming_hash_matches_hash_data() {
+        let data: Vec<u8> = (0..10_000u32).flat_map(u32::to_le_bytes).collect();
+
+        for algorithm in [
+            HashAlgorithm::Md5,
+            HashAlgorithm::Sha1,
+            HashAlgorithm::Sha256,
+            HashAlgorithm::Sha512,
+        ] {
+            let expected = hash_data(&data, algorithm);
+
+            let mut hasher = Hasher::new(algorithm);
+            for chunk in [&data[..1], &data[1..12_345], &data[12_345..]] {
+                hasher.update(chunk);
+            }
+            assert_eq!(hasher.finalize(), expected, "{algorithm} chunked digest");
+
+            let streamed = hash_reader(data.as_slice(), algorithm).unwrap();
+            assert_eq!(streamed, expected, "{algorithm} reader digest");
+        }
+    }
+    #[test]
     fn test_verify_hash() {
         let data = b"test data";
         let hash = hash_data(data, HashAlgorithm::Sha256);
@@ -490,6 +715,79 @@ mod tests {
         assert_eq!(url_decoded, data);
     }
     #[test]
+    fn test_base32_encoding() {
+        // RFC 4648 section 10 test vectors
+        let vectors = [
+            ("", ""),
+            ("f", "MY======"),
+            ("fo", "MZXQ===="),
+            ("foo", "MZXW6==="),
+            ("foob", "MZXW6YQ="),
+            ("fooba", "MZXW6YTB"),
+            ("foobar", "MZXW6YTBOI======"),
+        ];
+        for (plain, encoded) in vectors {
+            assert_eq!(base32_encode(plain.as_bytes()), encoded);
+            assert_eq!(base32_decode(encoded).unwrap(), plain.as_bytes());
+        }
+
+        let binary = [0x00, 0xFF, 0x10, 0x80, 0x7F, 0x01];
+        assert_eq!(base32_decode(&base32_encode(&binary)).unwrap(), binary);
+        assert_eq!(base32_decode("mzxw6ytb").unwrap(), b"fooba");
+
+        assert_eq!(
+            base32_decode("MY====="),
+            Err(Base32DecodeError::InvalidLength(7))
+        );
+        assert_eq!(
+            base32_decode("M======="),
+            Err(Base32DecodeError::InvalidPadding)
+        );
+        assert_eq!(
+            base32_decode("MY=A===="),
+            Err(Base32DecodeError::InvalidPadding)
+        );
+        assert_eq!(
+            base32_decode("MY======MZXW6YTB"),
+            Err(Base32DecodeError::InvalidPadding)
+        );
+        assert_eq!(
+            base32_decode("MZXW6YT1"),
+            Err(Base32DecodeError::InvalidCharacter('1', 7))
+        );
+    }
+    #[test]
+    fn test_percent_encoding() {
+        assert_eq!(percent_encode("", ""), "");
+        assert_eq!(percent_decode("").unwrap(), "");
+
+        let reserved = "a b/c?d=e&f#g:h";
+        let encoded = percent_encode(reserved, "");
+        assert_eq!(encoded, "a%20b%2Fc%3Fd%3De%26f%23g%3Ah");
+        assert_eq!(percent_decode(&encoded).unwrap(), reserved);
+
+        // Configurable safe set keeps path separators
+        let path = percent_encode("docs/my file.txt", "/");
+        assert_eq!(path, "docs/my%20file.txt");
+        assert_eq!(percent_decode(&path).unwrap(), "docs/my file.txt");
+
+        // Unreserved characters pass through, multi-byte UTF-8 is encoded per byte
+        assert_eq!(percent_encode("A-z_0.9~", ""), "A-z_0.9~");
+        let unicode = percent_encode("café", "");
+        assert_eq!(unicode, "caf%C3%A9");
+        assert_eq!(percent_decode("caf%c3%a9").unwrap(), "café");
+
+        assert_eq!(
+            percent_decode("100%"),
+            Err(PercentDecodeError::InvalidEscape(3))
+        );
+        assert_eq!(
+            percent_decode("%zz"),
+            Err(PercentDecodeError::InvalidEscape(0))
+        );
+        assert_eq!(percent_decode("%FF"), Err(PercentDecodeError::InvalidUtf8));
+    }
+    #[test]
     fn test_hex_conversion() {
         let data = vec![0xFF, 0x00, 0xAB, 0xCD];
         let hex = to_hex_string(&data);
@@ -1,6 +1,7 @@
 //! ABOUTME: File system monitoring utilities for watching file changes
 //! ABOUTME: Provides functions for tracking file system events and changes
 
+use crate::search::glob_match;
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
 use std::path::{Path, PathBuf};
@@ -189,15 +190,20 @@ impl WatchConfig {
 }
 
 /// Check if a path should be watched based on configuration
+///
+/// Patterns without a `/` are matched against the file name only, so `*.txt`
+/// matches `.txt` files in any directory.
 #[must_use]
 pub fn should_watch_path(path: &Path, config: &WatchConfig) -> bool {
-    if let Some(pattern) = &config.pattern {
-        match glob::Pattern::new(pattern) {
-            Ok(glob_pattern) => glob_pattern.matches_path(path),
-            Err(_) => false,
-        }
+    let Some(pattern) = &config.pattern else {
+        return true;
+    };
+
+    if pattern.contains('/') {
+        glob_match(pattern, &path.to_string_lossy())
     } else {
-        true
+        path.file_name()
+            .is_some_and(|name| glob_match(pattern, &name.to_string_lossy()))
     }
 }
 
@@ -280,6 +286,14 @@ mod tests {
         let config = WatchConfig::new().pattern("*.txt");
         assert!(should_watch_path(&PathBuf::from("file.txt"), &config));
         assert!(!should_watch_path(&PathBuf::from("file.log"), &config));
+        assert!(should_watch_path(
+            &PathBuf::from("/test/dir/file.txt"),
+            &config
+        ));
+
+        let config = WatchConfig::new().pattern("src/**/*.rs");
+        assert!(should_watch_path(&PathBuf::from("src/a/b.rs"), &config));
+        assert!(!should_watch_path(&PathBuf::from("tests/b.rs"), &config));
     }
     #[test]
     fn test_debounce_events() {
@@ -402,6 +402,141 @@ pub fn write_file_atomic(path: &Path, data: &[u8]) -> Result<()> {
     Ok(())
 }
 
+/// Atomically replace a directory with another one
+///
+/// `new_dir` is staged next to `target` and renamed into place. An existing
+/// `target` is kept as a backup beside it, whose path is returned. If the swap
+/// fails, the backup is restored and `new_dir` is put back.
+///
+/// When `new_dir` is on a different filesystem, it is copied into the staging
+/// location first and removed after a successful swap.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use llmspell_utils::file_utils::swap_dir_atomic;
+/// use std::path::Path;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let backup = swap_dir_atomic(Path::new("/tmp/model.new"), Path::new("/srv/model"))?;
+/// if let Some(backup) = backup {
+///     println!("Previous model kept at {}", backup.display());
+/// }
+/// # Ok(())
+/// # }
+/// ```
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - `new_dir` is not a directory
+/// - `target` exists but is not a directory
+/// - Staging, backup or the final rename fails (after rolling back)
+pub fn swap_dir_atomic(new_dir: &Path, target: &Path) -> Result<Option<PathBuf>> {
+    swap_dir_with(new_dir, target, |from, to| fs::rename(from, to))
+}
+
+/// Directory swap with an injectable rename, so rollback can be tested
+fn swap_dir_with<R>(new_dir: &Path, target: &Path, rename: R) -> Result<Option<PathBuf>>
+where
+    R: Fn(&Path, &Path) -> io::Result<()>,
+{
+    if !new_dir.is_dir() {
+        anyhow::bail!("Not a directory: {}", new_dir.display());
+    }
+    if target.exists() && !target.is_dir() {
+        anyhow::bail!("Target is not a directory: {}", target.display());
+    }
+    if let Some(parent) = target.parent() {
+        ensure_dir(parent).with_context(|| {
+            format!(
+                "Failed to create parent directory for: {}",
+                target.display()
+            )
+        })?;
+    }
+
+    // Stage beside the target so the swap itself is a same-filesystem rename
+    let staged = sibling_path(target, "staging")?;
+    let copied = match rename(new_dir, &staged) {
+        Ok(()) => false,
+        Err(e) if e.raw_os_error() == Some(18) => {
+            // Cross-device link error (EXDEV on Unix) - copy instead
+            if let Err(e) = copy_dir_recursive(new_dir, &staged) {
+                let _ = fs::remove_dir_all(&staged);
+                return Err(e)
+                    .with_context(|| format!("Failed to copy {} for staging", new_dir.display()));
+            }
+            true
+        }
+        Err(e) => {
+            return Err(e)
+                .with_context(|| format!("Failed to stage directory: {}", new_dir.display()))
+        }
+    };
+    let unstage = || {
+        if copied {
+            let _ = fs::remove_dir_all(&staged);
+        } else {
+            let _ = rename(&staged, new_dir);
+        }
+    };
+
+    let backup = if target.exists() {
+        let backup = sibling_path(target, "backup")?;
+        if let Err(e) = rename(target, &backup) {
+            unstage();
+            return Err(e)
+                .with_context(|| format!("Failed to back up directory: {}", target.display()));
+        }
+        Some(backup)
+    } else {
+        None
+    };
+
+    if let Err(e) = rename(&staged, target) {
+        if let Some(backup) = &backup {
+            let _ = rename(backup, target);
+        }
+        unstage();
+        return Err(e)
+            .with_context(|| format!("Failed to swap in directory: {}", target.display()));
+    }
+
+    if copied {
+        let _ = fs::remove_dir_all(new_dir);
+    }
+
+    Ok(backup)
+}
+
+/// Hidden, uniquely named path next to `path`
+fn sibling_path(path: &Path, label: &str) -> Result<PathBuf> {
+    let file_name = path
+        .file_name()
+        .ok_or_else(|| anyhow::anyhow!("Path has no file name: {}", path.display()))?;
+    Ok(path.with_file_name(format!(
+        ".{}.{label}.{}",
+        file_name.to_string_lossy(),
+        uuid::Uuid::new_v4().simple()
+    )))
+}
+
+/// Recursively copy a directory tree
+fn copy_dir_recursive(from: &Path, to: &Path) -> io::Result<()> {
+    fs::create_dir_all(to)?;
+    for entry in fs::read_dir(from)? {
+        let entry = entry?;
+        let dest = to.join(entry.file_name());
+        if entry.file_type()?.is_dir() {
+            copy_dir_recursive(&entry.path(), &dest)?;
+        } else {
+            fs::copy(entry.path(), &dest)?;
+        }
+    }
+    Ok(())
+}
+
 /// Check if a path is absolute
 ///
 /// This function correctly handles platform differences:
@@ -1093,6 +1228,83 @@ mod tests {
         let _ = fs::remove_file(&test_file);
     }
     #[test]
+    fn test_swap_dir_atomic() {
+        let root =
+            std::env::temp_dir().join(format!("llmspell_test_swap_{}", uuid::Uuid::new_v4()));
+        let target = root.join("bundle");
+        let new_dir = root.join("bundle.new");
+
+        write_file(&target.join("config.toml"), b"version = 1").unwrap();
+        write_file(&new_dir.join("config.toml"), b"version = 2").unwrap();
+        write_file(&new_dir.join("nested/model.bin"), b"weights").unwrap();
+
+        let backup = swap_dir_atomic(&new_dir, &target).unwrap().unwrap();
+
+        assert_eq!(
+            read_file(&target.join("config.toml")).unwrap(),
+            b"version = 2"
+        );
+        assert_eq!(
+            read_file(&target.join("nested/model.bin")).unwrap(),
+            b"weights"
+        );
+        assert_eq!(
+            read_file(&backup.join("config.toml")).unwrap(),
+            b"version = 1"
+        );
+        assert!(!new_dir.exists());
+
+        // Swapping into a missing target needs no backup
+        let fresh = root.join("fresh");
+        write_file(&new_dir.join("a.txt"), b"a").unwrap();
+        assert!(swap_dir_atomic(&new_dir, &fresh).unwrap().is_none());
+        assert!(fresh.join("a.txt").exists());
+
+        let _ = fs::remove_dir_all(&root);
+    }
+    #[test]
+    fn test_swap_dir_atomic_rolls_back() {
+        use std::cell::Cell;
+
+        let root =
+            std::env::temp_dir().join(format!("llmspell_test_swap_rb_{}", uuid::Uuid::new_v4()));
+        let target = root.join("bundle");
+        let new_dir = root.join("bundle.new");
+
+        write_file(&target.join("config.toml"), b"version = 1").unwrap();
+        write_file(&new_dir.join("config.toml"), b"version = 2").unwrap();
+
+        // Stage and backup succeed, the final swap fails
+        let calls = Cell::new(0);
+        let result = swap_dir_with(&new_dir, &target, |from, to| {
+            calls.set(calls.get() + 1);
+            if calls.get() == 3 {
+                return Err(io::Error::other("injected failure"));
+            }
+            fs::rename(from, to)
+        });
+
+        assert!(result.is_err());
+        assert_eq!(
+            read_file(&target.join("config.toml")).unwrap(),
+            b"version = 1"
+        );
+        assert_eq!(
+            read_file(&new_dir.join("config.toml")).unwrap(),
+            b"version = 2"
+        );
+
+        // No staging or backup directories are left behind
+        let leftovers: Vec<_> = fs::read_dir(&root)
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name())
+            .filter(|name| name.to_string_lossy().starts_with('.'))
+            .collect();
+        assert!(leftovers.is_empty(), "Found leftovers: {leftovers:?}");
+
+        let _ = fs::remove_dir_all(&root);
+    }
+    #[test]
     fn test_copy_file() {
         let temp_dir = std::env::temp_dir();
         let source = temp_dir.join(format!("llmspell_test_src_{}", uuid::Uuid::new_v4()));
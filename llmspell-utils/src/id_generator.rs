@@ -7,6 +7,8 @@
 //! with support for namespaced IDs and deterministic generation.
 
 use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Mutex, PoisonError};
+use std::time::{SystemTime, UNIX_EPOCH};
 use uuid::Uuid;
 
 /// Type alias for component IDs
@@ -15,6 +17,21 @@ pub type ComponentId = String;
 /// Counter for sequential IDs (used in tests)
 static SEQUENTIAL_COUNTER: AtomicU64 = AtomicU64::new(0);
 
+/// Base62 digits in ASCII order, so encoded values sort like the numbers
+const BASE62: &[u8; 62] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+
+/// Characters of millisecond timestamp in a sortable ID (good until year ~8900)
+const SORTABLE_TIME_CHARS: usize = 8;
+
+/// Characters of random entropy in a sortable ID
+const SORTABLE_RANDOM_CHARS: usize = 6;
+
+/// Number of distinct random suffixes (62^6)
+const SORTABLE_RANDOM_SPACE: u64 = 56_800_235_584;
+
+/// Last (timestamp, random) pair handed out, used to keep IDs monotonic
+static SORTABLE_STATE: Mutex<(u64, u64)> = Mutex::new((0, 0));
+
 /// Generate a unique component ID with prefix
 ///
 /// Creates a UUID v4 with the given prefix. The format is: `{prefix}_{uuid}`
@@ -55,6 +72,61 @@ pub fn generate_short_id(prefix: &str) -> ComponentId {
     format!("{prefix}_{short_uuid}")
 }
 
+/// Generate a lexicographically sortable, time-prefixed short ID
+///
+/// The ID is 14 base62 characters: an 8 character millisecond timestamp
+/// followed by 6 characters of random entropy. IDs generated in the same
+/// process always sort in generation order; within the same millisecond the
+/// random part is incremented instead of redrawn.
+///
+/// # Examples
+///
+/// ```rust
+/// use llmspell_utils::id_generator::generate_sortable_short_id;
+///
+/// let id1 = generate_sortable_short_id();
+/// let id2 = generate_sortable_short_id();
+/// assert_eq!(id1.len(), 14);
+/// assert!(id1 < id2);
+/// ```
+#[must_use]
+pub fn generate_sortable_short_id() -> ComponentId {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| u64::try_from(d.as_millis()).unwrap_or(u64::MAX));
+
+    let (millis, random) = {
+        let mut state = SORTABLE_STATE
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        let (last_millis, last_random) = *state;
+        *state = if now > last_millis {
+            (now, rand::random::<u64>() % SORTABLE_RANDOM_SPACE)
+        } else if last_random + 1 < SORTABLE_RANDOM_SPACE {
+            (last_millis, last_random + 1)
+        } else {
+            // Random space exhausted for this millisecond, borrow the next one
+            (last_millis + 1, 0)
+        };
+        *state
+    };
+
+    let mut id = String::with_capacity(SORTABLE_TIME_CHARS + SORTABLE_RANDOM_CHARS);
+    push_base62(&mut id, millis, SORTABLE_TIME_CHARS);
+    push_base62(&mut id, random, SORTABLE_RANDOM_CHARS);
+    id
+}
+
+/// Append `value` as exactly `width` base62 digits, most significant first
+fn push_base62(out: &mut String, mut value: u64, width: usize) {
+    let mut digits = vec![b'0'; width];
+    for digit in digits.iter_mut().rev() {
+        *digit = BASE62[usize::try_from(value % 62).unwrap_or(0)];
+        value /= 62;
+    }
+    out.extend(digits.into_iter().map(char::from));
+}
+
 /// Generate a deterministic component ID
 ///
 /// Creates a UUID v5 (namespace + name based) for deterministic generation.
@@ -298,6 +370,20 @@ mod tests {
         assert!(id.len() <= 14); // "tool_" (5) + 8 chars max
     }
     #[test]
+    fn test_generate_sortable_short_id() {
+        let ids: Vec<String> = (0..10_000).map(|_| generate_sortable_short_id()).collect();
+
+        let mut sorted = ids.clone();
+        sorted.sort();
+        assert_eq!(sorted, ids, "IDs should sort in generation order");
+
+        let unique: std::collections::HashSet<_> = ids.iter().collect();
+        assert_eq!(unique.len(), ids.len(), "IDs should not collide");
+        assert!(ids
+            .iter()
+            .all(|id| id.len() == 14 && id.bytes().all(|b| b.is_ascii_alphanumeric())));
+    }
+    #[test]
     fn test_generate_deterministic_id() {
         let id1 = generate_deterministic_id(NAMESPACE_AGENT, "test-agent");
         let id2 = generate_deterministic_id(NAMESPACE_AGENT, "test-agent");
@@ -121,58 +121,64 @@ pub mod text;
 
 // Re-export commonly used types and functions
 pub use async_utils::{
-    concurrent_map, race_to_success, retry_async, timeout, timeout_with_default, AsyncError,
-    AsyncResult, BoxedResultFuture, Cancellable, RetryConfig,
+    concurrent_map, concurrent_map_bounded, concurrent_map_bounded_with_mode, race_to_success,
+    retry_async, timeout, timeout_with_default, AsyncError, AsyncResult, BatchErrorMode,
+    BatchResults, BoxedResultFuture, Cancellable, JitterStrategy, RetryConfig,
 };
 pub use encoding::{
-    base64_decode, base64_decode_url_safe, base64_encode, base64_encode_url_safe, from_hex_string,
-    hash_data, hash_file, hash_string, to_hex_string, verify_hash, HashAlgorithm,
+    base32_decode, base32_encode, base64_decode, base64_decode_url_safe, base64_encode,
+    base64_encode_url_safe, from_hex_string, hash_data, hash_file, hash_reader, hash_string,
+    percent_decode, percent_encode, to_hex_string, verify_hash, Base32DecodeError, HashAlgorithm,
+    Hasher, PercentDecodeError,
 };
 pub use error_builders::{templates, BuiltError, ErrorBuilder, WithContext};
 pub use file_monitor::{debounce_events, should_watch_path, FileEvent, FileEventType, WatchConfig};
 pub use file_utils::{
     append_file, copy_file, ensure_dir, expand_path, file_exists, get_metadata, is_absolute_path,
     join_paths, list_dir, move_file, normalize_path, parent_dir, read_file,
-    remove_dir_all_if_exists, remove_file_if_exists, write_file, write_file_atomic, DirEntry,
-    FileMetadata,
+    remove_dir_all_if_exists, remove_file_if_exists, swap_dir_atomic, write_file,
+    write_file_atomic, DirEntry, FileMetadata,
 };
 pub use id_generator::{
-    generate_component_id, generate_deterministic_id, generate_short_id, validate_component_id,
-    ComponentId, ComponentIdBuilder, NAMESPACE_AGENT, NAMESPACE_TOOL, NAMESPACE_WORKFLOW,
+    generate_component_id, generate_deterministic_id, generate_short_id,
+    generate_sortable_short_id, validate_component_id, ComponentId, ComponentIdBuilder,
+    NAMESPACE_AGENT, NAMESPACE_TOOL, NAMESPACE_WORKFLOW,
 };
 pub use params::{
     extract_bool_with_default, extract_direct_parameters, extract_optional_array,
     extract_optional_bool, extract_optional_f64, extract_optional_i64, extract_optional_object,
-    extract_optional_string, extract_optional_typed, extract_optional_u64, extract_parameters,
-    extract_required_array, extract_required_bool, extract_required_f64, extract_required_i64,
-    extract_required_object, extract_required_string, extract_required_typed, extract_required_u64,
-    extract_string_with_default, require_all_of, require_one_of,
+    extract_optional_path, extract_optional_string, extract_optional_typed, extract_optional_u64,
+    extract_parameters, extract_required_array, extract_required_bool, extract_required_f64,
+    extract_required_i64, extract_required_object, extract_required_path, extract_required_string,
+    extract_required_typed, extract_required_u64, extract_string_with_default, require_all_of,
+    require_one_of,
 };
 pub use response::{
     error_response, file_operation_response, list_response, success_response, validation_response,
-    ErrorDetails, ResponseBuilder, ValidationError,
+    ErrorDetails, ResponseBuilder, StreamingResponseBuilder, ValidationError,
 };
 pub use search::{
-    search_in_directory, search_in_file, should_search_file, SearchMatch, SearchOptions,
-    SearchResult,
+    glob_match, search_in_directory, search_in_file, should_search_file, GlobSet, SearchMatch,
+    SearchOptions, SearchResult,
 };
 pub use serialization::{
     convert_format, from_json, from_toml, json, merge_json, to_json, to_json_pretty, to_toml,
     Format,
 };
 pub use string_utils::{
-    dedent, indent, is_valid_identifier, join_with, normalize_whitespace, replace_all, reverse,
-    sanitize, split_by, substring, to_camel_case, to_lowercase, to_pascal_case, to_snake_case,
-    to_uppercase, trim, truncate, word_wrap,
+    dedent, fuzzy_similarity, indent, is_valid_identifier, join_with, levenshtein_distance,
+    normalize_whitespace, replace_all, reverse, sanitize, split_by, substring, to_camel_case,
+    to_lowercase, to_pascal_case, to_snake_case, to_uppercase, trim, truncate, word_wrap,
 };
 pub use system_info::{
     find_executable, format_bytes, get_cpu_count, get_home_directory, get_hostname,
     get_system_info, get_username, OperatingSystem, SystemInfo,
 };
 pub use time::{
-    add_duration, convert_timezone, days_in_month, duration_between, end_of_day, format_datetime,
-    format_duration, is_leap_year, now_local, now_utc, parse_datetime, start_of_day,
-    subtract_duration, weekday_name, TimeError, TimeResult, DATE_FORMATS,
+    add_business_days, add_duration, convert_timezone, days_in_month, duration_between, end_of_day,
+    format_datetime, format_duration, is_business_day, is_leap_year, now_local, now_utc,
+    parse_datetime, start_of_day, subtract_duration, weekday_name, BusinessCalendar, TimeError,
+    TimeResult, DATE_FORMATS,
 };
 pub use validators::{
     sanitize_string, validate_date_format, validate_email, validate_enum, validate_file_size,
@@ -180,7 +186,7 @@ pub use validators::{
     validate_no_shell_injection, validate_not_empty, validate_not_empty_collection,
     validate_path_exists, validate_pattern, validate_range, validate_regex_pattern,
     validate_required_field, validate_resource_limit, validate_safe_path, validate_string_length,
-    validate_url,
+    validate_url, ValidationChain,
 };
 
 #[cfg(unix)]
@@ -188,7 +194,8 @@ pub use validators::validate_file_permissions;
 
 // Re-export retry utilities
 pub use retry::{
-    retry, retry_default, AlwaysRetry, HttpStatusRetryPolicy, RetryBuilder, RetryError, RetryPolicy,
+    retry, retry_default, AlwaysRetry, ErrorRetryPolicy, HttpStatusRetryPolicy, RetryBudget,
+    RetryBuilder, RetryError, RetryPolicy,
 };
 
 // Re-export rate limiter utilities
@@ -198,8 +205,8 @@ pub use rate_limiter::{
 
 // Re-export timeout utilities
 pub use timeout::{
-    with_timeout, with_timeout_config, CancellableTimeout, TimeoutBuilder, TimeoutConfig,
-    TimeoutError, TimeoutExt, TimeoutManager,
+    with_deadline, with_timeout, with_timeout_config, CancellableTimeout, TimeoutBuilder,
+    TimeoutConfig, TimeoutError, TimeoutExt, TimeoutManager,
 };
 
 // Re-export connection pool utilities
@@ -210,8 +217,8 @@ pub use connection_pool::{
 
 // Re-export terminal utilities (including progress)
 pub use terminal::progress::{
-    ProgressBuilder, ProgressError, ProgressEvent, ProgressIteratorExt, ProgressReporter,
-    ProgressTracker,
+    MultiProgress, MultiProgressEvent, ProgressBuilder, ProgressError, ProgressEvent,
+    ProgressIteratorExt, ProgressReporter, ProgressTracker,
 };
 pub use terminal::{
     colored_text, confirm, input, select, AsyncSpinner, Color, Colorize, SimpleSpinner,
@@ -220,8 +227,10 @@ pub use terminal::{
 
 // Re-export security utilities
 pub use security::{
+    evaluate_with_limits,
     path::{PathSecurityConfig, PathSecurityValidator},
-    ExpressionAnalyzer, ExpressionComplexity, ExpressionComplexityConfig,
+    EvaluationBudget, EvaluationLimitError, ExpressionAnalyzer, ExpressionComplexity,
+    ExpressionComplexityConfig,
 };
 
 // Re-export API key management utilities
@@ -223,6 +223,107 @@ pub fn extract_optional_typed<T: DeserializeOwned>(params: &Value, key: &str) ->
         .and_then(|v| serde_json::from_value(v.clone()).ok())
 }
 
+/// Split a dotted (`config.retry.max_attempts`) or JSON pointer
+/// (`/config/retry/max_attempts`) path into segments
+fn path_segments(path: &str) -> Vec<String> {
+    path.strip_prefix('/').map_or_else(
+        || path.split('.').map(str::to_string).collect(),
+        |pointer| {
+            pointer
+                .split('/')
+                .map(|segment| segment.replace("~1", "/").replace("~0", "~"))
+                .collect()
+        },
+    )
+}
+
+/// Walk `path` through nested objects and arrays
+///
+/// Returns `Ok(None)` with the failing prefix when a segment is missing, and an
+/// error when an intermediate value is neither an object nor an array.
+fn resolve_path<'a>(
+    params: &'a Value,
+    path: &str,
+) -> LLMResult<std::result::Result<&'a Value, String>> {
+    let separator = if path.starts_with('/') { "/" } else { "." };
+    let mut current = params;
+    let mut traversed: Vec<String> = Vec::new();
+
+    for segment in path_segments(path) {
+        let parent = traversed.join(separator);
+        let next = match current {
+            Value::Object(map) => map.get(&segment),
+            Value::Array(items) => segment.parse::<usize>().ok().and_then(|i| items.get(i)),
+            Value::Null => None,
+            _ => {
+                return Err(LLMSpellError::Validation {
+                    message: format!(
+                        "Parameter '{parent}' is not an object or array (resolving '{path}')"
+                    ),
+                    field: Some(path.to_string()),
+                })
+            }
+        };
+        traversed.push(segment);
+        match next {
+            Some(value) => current = value,
+            None => return Ok(Err(traversed.join(separator))),
+        }
+    }
+
+    Ok(Ok(current))
+}
+
+/// Deserialize the value found at `path`
+fn deserialize_at_path<T: DeserializeOwned>(value: &Value, path: &str) -> LLMResult<T> {
+    serde_json::from_value(value.clone()).map_err(|e| LLMSpellError::Validation {
+        message: format!("Invalid parameter at '{path}': {e}"),
+        field: Some(path.to_string()),
+    })
+}
+
+/// Extract and deserialize a required parameter at a nested path
+///
+/// The path is either dotted (`config.retry.max_attempts`) or a JSON pointer
+/// (`/config/retry/max_attempts`). Numeric segments index into arrays.
+///
+/// # Examples
+/// ```rust,ignore
+/// let attempts: u32 = extract_required_path(params, "config.retry.max_attempts")?;
+/// ```
+///
+/// # Errors
+///
+/// Returns `LLMSpellError::Validation` naming the failing segment if any part of
+/// the path is missing, or naming the path if the value cannot be deserialized
+pub fn extract_required_path<T: DeserializeOwned>(params: &Value, path: &str) -> LLMResult<T> {
+    match resolve_path(params, path)? {
+        Ok(value) => deserialize_at_path(value, path),
+        Err(missing) => Err(LLMSpellError::Validation {
+            message: format!("Missing required parameter '{missing}' (resolving '{path}')"),
+            field: Some(missing),
+        }),
+    }
+}
+
+/// Extract and deserialize an optional parameter at a nested path
+///
+/// Returns `Ok(None)` if any segment of the path is missing.
+///
+/// # Errors
+///
+/// Returns `LLMSpellError::Validation` if an intermediate value is not an
+/// object or array, or if the value cannot be deserialized
+pub fn extract_optional_path<T: DeserializeOwned>(
+    params: &Value,
+    path: &str,
+) -> LLMResult<Option<T>> {
+    match resolve_path(params, path)? {
+        Ok(value) => deserialize_at_path(value, path).map(Some),
+        Err(_) => Ok(None),
+    }
+}
+
 /// Validate that at least one of the specified parameters exists
 ///
 /// # Errors
@@ -284,6 +385,7 @@ mod tests {
             context: None,
             parameters: serde_json::from_value(json!({ "parameters": params })).unwrap(),
             output_modalities: vec![],
+            attachments: vec![],
         }
     }
     #[test]
@@ -350,6 +452,40 @@ mod tests {
         assert_eq!(obj.get("key").and_then(|v| v.as_str()), Some("value"));
     }
     #[test]
+    fn test_extract_path() {
+        let params = json!({
+            "config": {
+                "retry": { "max_attempts": 5, "backoff": "exponential" },
+                "hosts": [{ "name": "a" }, { "name": "b" }]
+            }
+        });
+
+        let attempts: u32 = extract_required_path(&params, "config.retry.max_attempts").unwrap();
+        assert_eq!(attempts, 5);
+        let attempts: u32 = extract_required_path(&params, "/config/retry/max_attempts").unwrap();
+        assert_eq!(attempts, 5);
+        let host: String = extract_required_path(&params, "config.hosts.1.name").unwrap();
+        assert_eq!(host, "b");
+
+        let optional: Option<u32> = extract_optional_path(&params, "config.timeout.secs").unwrap();
+        assert_eq!(optional, None);
+
+        // Missing intermediate key names the exact failing segment
+        let err = extract_required_path::<u32>(&params, "config.limits.max_attempts").unwrap_err();
+        match err {
+            LLMSpellError::Validation { message, field } => {
+                assert_eq!(field.as_deref(), Some("config.limits"));
+                assert!(message.contains("'config.limits'"), "{message}");
+            }
+            other => panic!("Expected validation error, got {other:?}"),
+        }
+
+        // Mistyped values name the full path
+        let err = extract_required_path::<u32>(&params, "config.retry.backoff").unwrap_err();
+        assert!(err.to_string().contains("config.retry.backoff"));
+        assert!(extract_optional_path::<u32>(&params, "config.retry.backoff.x").is_err());
+    }
+    #[test]
     fn test_require_one_of() {
         let params = json!({ "a": 1, "c": 3 });
         assert!(require_one_of(&params, &["a", "b"]).is_ok());
@@ -374,6 +510,7 @@ mod tests {
             context: None,
             parameters,
             output_modalities: vec![],
+            attachments: vec![],
         };
 
         let params = extract_direct_parameters(&input);
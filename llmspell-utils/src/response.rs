@@ -1,5 +1,5 @@
 // ABOUTME: Response builder utilities for consistent output formatting across tools
-// ABOUTME: Provides fluent API for building successful and error responses
+// ABOUTME: Provides fluent API for building successful and error responses, including streamed ones
 
 //! Response building utilities
 //!
@@ -307,6 +307,133 @@ pub fn validation_response(valid: bool, errors: &Option<Vec<ValidationError>>) -
         .build()
 }
 
+/// Accumulates streamed chunks into standard responses
+///
+/// Streaming tools push chunks as they are produced, emit interim snapshots
+/// while streaming, and finish with a consolidated response. Chunks are merged
+/// by type: strings are concatenated, arrays are appended, objects are merged
+/// with later keys winning, and anything else is collected into an array.
+#[derive(Debug, Clone)]
+pub struct StreamingResponseBuilder {
+    operation: String,
+    chunks: Vec<Value>,
+    message: Option<String>,
+    error: Option<ErrorDetails>,
+    validation_errors: Vec<ValidationError>,
+    metadata: HashMap<String, Value>,
+}
+
+impl StreamingResponseBuilder {
+    /// Create a streaming builder for an operation
+    #[must_use]
+    pub fn new(operation: impl Into<String>) -> Self {
+        Self {
+            operation: operation.into(),
+            chunks: Vec::new(),
+            message: None,
+            error: None,
+            validation_errors: Vec::new(),
+            metadata: HashMap::new(),
+        }
+    }
+
+    /// Append a chunk of result data
+    pub fn push_chunk(&mut self, chunk: Value) {
+        self.chunks.push(chunk);
+    }
+
+    /// Append a validation error, for streamed validation
+    pub fn push_validation_error(&mut self, error: ValidationError) {
+        self.validation_errors.push(error);
+    }
+
+    /// Set the human-readable message
+    pub fn set_message(&mut self, message: impl Into<String>) {
+        self.message = Some(message.into());
+    }
+
+    /// Set a metadata field
+    pub fn set_metadata(&mut self, key: impl Into<String>, value: Value) {
+        self.metadata.insert(key.into(), value);
+    }
+
+    /// Mark the stream as failed; chunks received so far are kept as the result
+    pub fn fail(&mut self, error: ErrorDetails) {
+        self.error = Some(error);
+    }
+
+    /// Number of chunks received
+    #[must_use]
+    pub fn chunk_count(&self) -> usize {
+        self.chunks.len()
+    }
+
+    /// Interim response for the chunks received so far
+    ///
+    /// Metadata carries `"partial": true` and the chunk count.
+    #[must_use]
+    pub fn snapshot(&self) -> ResponseBuilder {
+        self.to_builder().with_metadata("partial", json!(true))
+    }
+
+    /// Final response with all chunks merged
+    #[must_use]
+    pub fn finish(self) -> ResponseBuilder {
+        self.to_builder()
+    }
+
+    /// Final validation response listing the streamed validation errors
+    #[must_use]
+    pub fn finish_validation(self) -> Value {
+        let valid = self.validation_errors.is_empty();
+        let errors = (!valid).then_some(self.validation_errors);
+        validation_response(valid, &errors)
+    }
+
+    fn to_builder(&self) -> ResponseBuilder {
+        let mut builder = ResponseBuilder::success(self.operation.clone());
+        if let Some(error) = &self.error {
+            builder = builder.with_error_details(error.clone());
+        }
+        if let Some(message) = &self.message {
+            builder = builder.with_message(message.clone());
+        }
+        if !self.chunks.is_empty() {
+            builder = builder.with_result(merge_chunks(self.chunks.clone()));
+        }
+        builder
+            .with_metadata_map(self.metadata.clone())
+            .with_metadata("chunk_count", json!(self.chunks.len()))
+    }
+}
+
+/// Merge streamed chunks into a single value
+fn merge_chunks(chunks: Vec<Value>) -> Value {
+    if chunks.iter().all(Value::is_string) {
+        Value::String(chunks.iter().filter_map(Value::as_str).collect())
+    } else if chunks.iter().all(Value::is_array) {
+        Value::Array(
+            chunks
+                .into_iter()
+                .flat_map(|chunk| match chunk {
+                    Value::Array(items) => items,
+                    _ => Vec::new(),
+                })
+                .collect(),
+        )
+    } else if chunks.iter().all(Value::is_object) {
+        let mut merged = serde_json::Map::new();
+        for chunk in chunks {
+            if let Value::Object(fields) = chunk {
+                merged.extend(fields);
+            }
+        }
+        Value::Object(merged)
+    } else {
+        Value::Array(chunks)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -431,6 +558,62 @@ mod tests {
         assert_eq!(errors[1]["code"], "FORMAT");
     }
     #[test]
+    fn test_streaming_response_snapshots_and_finish() {
+        let mut stream = StreamingResponseBuilder::new("generate");
+        stream.push_chunk(json!("Hello"));
+        stream.push_chunk(json!(", "));
+
+        let interim = stream.snapshot().build();
+        assert_eq!(interim["operation"], "generate");
+        assert_eq!(interim["success"], true);
+        assert_eq!(interim["result"], "Hello, ");
+        assert_eq!(interim["metadata"]["partial"], true);
+        assert_eq!(interim["metadata"]["chunk_count"], 2);
+
+        stream.push_chunk(json!("world"));
+        stream.set_message("Generated text");
+        stream.set_metadata("model", json!("test"));
+        assert_eq!(stream.snapshot().build()["result"], "Hello, world");
+
+        let (text, response) = stream.finish().build_for_output();
+        assert_eq!(text, "Generated text");
+        assert_eq!(response["result"], "Hello, world");
+        assert_eq!(response["metadata"]["chunk_count"], 3);
+        assert_eq!(response["metadata"]["model"], "test");
+        assert!(response["metadata"].get("partial").is_none());
+
+        // Objects merge and arrays append
+        let mut stream = StreamingResponseBuilder::new("search");
+        stream.push_chunk(json!({"hits": 1, "page": 1}));
+        stream.push_chunk(json!({"page": 2}));
+        assert_eq!(
+            stream.finish().build()["result"],
+            json!({"hits": 1, "page": 2})
+        );
+        let mut stream = StreamingResponseBuilder::new("list");
+        stream.push_chunk(json!([1, 2]));
+        stream.push_chunk(json!([3]));
+        assert_eq!(stream.snapshot().build()["result"], json!([1, 2, 3]));
+
+        // A failed stream keeps the error shape and the partial result
+        let mut stream = StreamingResponseBuilder::new("download");
+        stream.push_chunk(json!("partial data"));
+        stream.fail(ErrorDetails::new("Connection reset").with_code("NETWORK"));
+        let response = stream.finish().build();
+        assert_eq!(response["success"], false);
+        assert_eq!(response["error"]["code"], "NETWORK");
+        assert_eq!(response["result"], "partial data");
+
+        // Streamed validation produces the standard validation shape
+        let mut stream = StreamingResponseBuilder::new("validate");
+        stream.push_validation_error(ValidationError::new("Required").with_field("name"));
+        let response = stream.finish_validation();
+        assert_eq!(response["result"]["valid"], false);
+        assert_eq!(response["result"]["errors"][0]["field"], "name");
+        let response = StreamingResponseBuilder::new("validate").finish_validation();
+        assert_eq!(response["result"]["valid"], true);
+    }
+    #[test]
     fn test_validation_response_success() {
         let response = validation_response(true, &None);
 
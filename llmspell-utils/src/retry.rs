@@ -1,9 +1,12 @@
 // ABOUTME: Retry utility with exponential backoff and configurable retry strategies
 // ABOUTME: Provides a common retry mechanism for operations that may fail temporarily
 
+use llmspell_core::LLMSpellError;
+use parking_lot::Mutex;
 use std::fmt::Display;
 use std::future::Future;
-use std::time::Duration;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 use thiserror::Error;
 use tokio::time::sleep;
 use tracing::{debug, warn};
@@ -21,6 +24,8 @@ pub struct RetryConfig {
     pub backoff_factor: f64,
     /// Optional jitter to add randomness to delays
     pub jitter: bool,
+    /// Optional retry budget shared with other operations
+    pub budget: Option<RetryBudget>,
 }
 
 impl Default for RetryConfig {
@@ -31,6 +36,7 @@ impl Default for RetryConfig {
             max_delay: Duration::from_secs(30),
             backoff_factor: 2.0,
             jitter: true,
+            budget: None,
         }
     }
 }
@@ -73,6 +79,13 @@ impl RetryConfig {
         self
     }
 
+    /// Draw retries from a shared budget
+    #[must_use]
+    pub fn with_budget(mut self, budget: RetryBudget) -> Self {
+        self.budget = Some(budget);
+        self
+    }
+
     /// Calculate delay for a given attempt number
     #[allow(
         clippy::cast_precision_loss,
@@ -102,6 +115,78 @@ impl RetryConfig {
     }
 }
 
+/// Token bucket of retries shared across operations
+///
+/// Each retry (not the first attempt) withdraws one token. When the bucket is
+/// empty, operations fail fast instead of retrying, which keeps a struggling
+/// backend from being hit by a retry storm. One token is added back every
+/// `refill_interval`, up to `capacity`. Clones share the same bucket.
+#[derive(Debug, Clone)]
+pub struct RetryBudget {
+    capacity: u32,
+    refill_interval: Duration,
+    state: Arc<Mutex<BudgetState>>,
+}
+
+#[derive(Debug)]
+struct BudgetState {
+    tokens: u32,
+    last_refill: Instant,
+}
+
+impl RetryBudget {
+    /// Create a full budget of `capacity` retries
+    #[must_use]
+    pub fn new(capacity: u32, refill_interval: Duration) -> Self {
+        Self {
+            capacity,
+            refill_interval,
+            state: Arc::new(Mutex::new(BudgetState {
+                tokens: capacity,
+                last_refill: Instant::now(),
+            })),
+        }
+    }
+
+    /// Take one retry from the budget, returning `false` if none are left
+    pub fn try_withdraw(&self) -> bool {
+        let mut state = self.state.lock();
+        self.refill(&mut state);
+        if state.tokens == 0 {
+            return false;
+        }
+        state.tokens -= 1;
+        true
+    }
+
+    /// Retries currently available
+    pub fn available(&self) -> u32 {
+        let mut state = self.state.lock();
+        self.refill(&mut state);
+        state.tokens
+    }
+
+    fn refill(&self, state: &mut BudgetState) {
+        if self.refill_interval.is_zero() {
+            state.tokens = self.capacity;
+            return;
+        }
+
+        let earned = state.last_refill.elapsed().as_nanos() / self.refill_interval.as_nanos();
+        if earned == 0 {
+            return;
+        }
+        let earned = u32::try_from(earned).unwrap_or(u32::MAX);
+        state.tokens = state.tokens.saturating_add(earned).min(self.capacity);
+        if state.tokens == self.capacity {
+            state.last_refill = Instant::now();
+        } else {
+            // Keep partial progress towards the next token
+            state.last_refill += self.refill_interval * earned;
+        }
+    }
+}
+
 /// Error type for retry operations
 #[derive(Debug, Error)]
 pub enum RetryError<E> {
@@ -114,6 +199,15 @@ pub enum RetryError<E> {
         error: E,
     },
 
+    #[error("Operation failed after {attempts} attempts, retry budget exhausted: {error}")]
+    /// The shared retry budget had no retries left
+    BudgetExhausted {
+        /// Number of attempts made
+        attempts: u32,
+        /// The last error
+        error: E,
+    },
+
     #[error("Operation was cancelled")]
     /// The retry operation was cancelled
     Cancelled,
@@ -123,6 +217,11 @@ pub enum RetryError<E> {
 pub trait RetryPolicy<E> {
     /// Determine if an error should trigger a retry
     fn should_retry(&self, error: &E) -> bool;
+
+    /// Minimum delay the error asks for before the next attempt, if any
+    fn retry_after(&self, _error: &E) -> Option<Duration> {
+        None
+    }
 }
 
 /// Default retry policy that retries on all errors
@@ -134,6 +233,24 @@ impl<E> RetryPolicy<E> for AlwaysRetry {
     }
 }
 
+/// Retry policy driven by [`LLMSpellError::is_retryable`]
+///
+/// Rate-limit hints from [`LLMSpellError::retry_after`] extend the backoff delay.
+pub struct ErrorRetryPolicy;
+
+impl RetryPolicy<LLMSpellError> for ErrorRetryPolicy {
+    fn should_retry(&self, error: &LLMSpellError) -> bool {
+        error.is_retryable()
+    }
+
+    fn retry_after(&self, error: &LLMSpellError) -> Option<Duration> {
+        match error {
+            LLMSpellError::RateLimit { .. } => error.retry_after(),
+            _ => None,
+        }
+    }
+}
+
 /// HTTP status code based retry policy
 pub struct HttpStatusRetryPolicy {
     /// HTTP status codes that should trigger a retry
@@ -161,6 +278,7 @@ impl HttpStatusRetryPolicy {
 /// # Errors
 ///
 /// Returns `RetryError::ExhaustedRetries` if all retry attempts fail or if the error is not retryable.
+/// Returns `RetryError::BudgetExhausted` if the configured retry budget has no retries left.
 /// Returns `RetryError::Cancelled` if the operation is cancelled (not currently implemented).
 pub async fn retry<F, Fut, T, E, P>(
     config: RetryConfig,
@@ -203,7 +321,24 @@ where
                     });
                 }
 
+                if let Some(budget) = &config.budget {
+                    if !budget.try_withdraw() {
+                        warn!(
+                            "Retry budget exhausted after attempt {}: {}",
+                            attempt, error
+                        );
+                        return Err(RetryError::BudgetExhausted {
+                            attempts: attempt,
+                            error,
+                        });
+                    }
+                }
+
+                // A hint from the error can lengthen the delay, up to `max_delay`
                 let delay = config.calculate_delay(attempt);
+                let delay = policy
+                    .retry_after(&error)
+                    .map_or(delay, |hint| delay.max(hint.min(config.max_delay)));
                 warn!(
                     "Attempt {} failed: {}. Retrying in {:?}",
                     attempt, error, delay
@@ -289,6 +424,13 @@ impl<P> RetryBuilder<P> {
         self
     }
 
+    /// Draw retries from a shared budget
+    #[must_use]
+    pub fn budget(mut self, budget: RetryBudget) -> Self {
+        self.config.budget = Some(budget);
+        self
+    }
+
     /// Execute the retry operation
     ///
     /// # Errors
@@ -348,6 +490,51 @@ mod tests {
         }
     }
     #[tokio::test]
+    async fn test_shared_retry_budget() {
+        let budget = RetryBudget::new(2, Duration::from_millis(100));
+        let calls = Arc::new(AtomicU32::new(0));
+        let config = RetryConfig::new(3)
+            .with_initial_delay(Duration::from_millis(1))
+            .with_jitter(false)
+            .with_budget(budget.clone());
+
+        let failing = || {
+            let calls = calls.clone();
+            let config = config.clone();
+            async move {
+                retry(config, AlwaysRetry, || {
+                    calls.fetch_add(1, Ordering::SeqCst);
+                    async { Err::<(), _>("backend down") }
+                })
+                .await
+            }
+        };
+
+        // The first operation spends the whole budget on its two retries
+        assert!(matches!(
+            failing().await,
+            Err(RetryError::ExhaustedRetries { attempts: 3, .. })
+        ));
+        // Later operations fail fast after a single attempt
+        for _ in 0..4 {
+            assert!(matches!(
+                failing().await,
+                Err(RetryError::BudgetExhausted { attempts: 1, .. })
+            ));
+        }
+        assert_eq!(calls.load(Ordering::SeqCst), 3 + 4);
+        assert_eq!(budget.available(), 0);
+
+        // Retries resume once the budget refills
+        tokio::time::sleep(Duration::from_millis(250)).await;
+        assert_eq!(budget.available(), 2);
+        assert!(matches!(
+            failing().await,
+            Err(RetryError::ExhaustedRetries { attempts: 3, .. })
+        ));
+        assert_eq!(calls.load(Ordering::SeqCst), 3 + 4 + 3);
+    }
+    #[tokio::test]
     async fn test_custom_retry_policy() {
         struct CustomPolicy;
 
@@ -369,6 +556,47 @@ mod tests {
             _ => panic!("Expected ExhaustedRetries error"),
         }
     }
+    #[tokio::test]
+    async fn test_error_retry_policy() {
+        let calls = Arc::new(AtomicU32::new(0));
+        let calls_clone = calls.clone();
+        let result = RetryBuilder::with_policy(ErrorRetryPolicy)
+            .max_attempts(3)
+            .initial_delay(Duration::from_millis(1))
+            .jitter(false)
+            .execute(|| {
+                let calls = calls_clone.clone();
+                async move {
+                    calls.fetch_add(1, Ordering::SeqCst);
+                    Err::<(), _>(LLMSpellError::Validation {
+                        message: "bad input".to_string(),
+                        field: None,
+                    })
+                }
+            })
+            .await;
+        assert!(matches!(
+            result,
+            Err(RetryError::ExhaustedRetries { attempts: 1, .. })
+        ));
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+        let rate_limited = LLMSpellError::RateLimit {
+            message: "slow down".to_string(),
+            retry_after: Some(2),
+        };
+        assert!(ErrorRetryPolicy.should_retry(&rate_limited));
+        assert_eq!(
+            ErrorRetryPolicy.retry_after(&rate_limited),
+            Some(Duration::from_secs(2))
+        );
+        let network = LLMSpellError::Network {
+            message: "reset".to_string(),
+            source: None,
+        };
+        assert!(ErrorRetryPolicy.should_retry(&network));
+        assert_eq!(ErrorRetryPolicy.retry_after(&network), None);
+    }
     #[test]
     fn test_delay_calculation() {
         let config = RetryConfig {
@@ -5,6 +5,8 @@
 //!
 //! This module provides utilities for searching within files and directories,
 //! including pattern matching, recursive traversal, and context extraction.
+//! It also provides a shared glob matcher ([`glob_match`], [`GlobSet`]) for
+//! path and name filters.
 //!
 //! # Examples
 //!
@@ -34,8 +36,9 @@
 //! # }
 //! ```
 
+use crate::security::ExpressionComplexityConfig;
 use anyhow::{Context, Result};
-use regex::Regex;
+use regex::{Regex, RegexBuilder};
 use std::collections::HashSet;
 use std::fs;
 use std::path::{Path, PathBuf};
@@ -50,8 +53,10 @@ pub struct SearchOptions {
     pub case_sensitive: bool,
     /// Whether to use regex pattern matching
     pub use_regex: bool,
-    /// Number of context lines to include before and after matches
-    pub context_lines: usize,
+    /// Number of context lines to include before matches
+    pub context_before: usize,
+    /// Number of context lines to include after matches
+    pub context_after: usize,
     /// Maximum file size to search (in bytes)
     pub max_file_size: u64,
     /// File extensions to include (if empty, search all files)
@@ -72,7 +77,8 @@ impl Default for SearchOptions {
             recursive: false,
             case_sensitive: true,
             use_regex: false,
-            context_lines: 0,
+            context_before: 0,
+            context_after: 0,
             max_file_size: 10 * 1024 * 1024, // 10MB
             include_extensions: Vec::new(),
             exclude_extensions: vec![
@@ -136,10 +142,25 @@ impl SearchOptions {
         self
     }
 
-    /// Set the number of context lines
+    /// Set the number of context lines both before and after matches
     #[must_use]
     pub fn with_context_lines(mut self, context_lines: usize) -> Self {
-        self.context_lines = context_lines;
+        self.context_before = context_lines;
+        self.context_after = context_lines;
+        self
+    }
+
+    /// Set the number of context lines before matches
+    #[must_use]
+    pub fn with_context_before(mut self, context_before: usize) -> Self {
+        self.context_before = context_before;
+        self
+    }
+
+    /// Set the number of context lines after matches
+    #[must_use]
+    pub fn with_context_after(mut self, context_after: usize) -> Self {
+        self.context_after = context_after;
         self
     }
 
@@ -205,6 +226,26 @@ pub struct SearchMatch {
     pub matched_text: String,
 }
 
+impl SearchMatch {
+    /// Context lines before the match paired with their 1-based line numbers
+    pub fn numbered_context_before(&self) -> impl Iterator<Item = (usize, &str)> {
+        let first = self.line_number - self.context_before.len();
+        self.context_before
+            .iter()
+            .enumerate()
+            .map(move |(i, line)| (first + i, line.as_str()))
+    }
+
+    /// Context lines after the match paired with their 1-based line numbers
+    pub fn numbered_context_after(&self) -> impl Iterator<Item = (usize, &str)> {
+        let first = self.line_number + 1;
+        self.context_after
+            .iter()
+            .enumerate()
+            .map(move |(i, line)| (first + i, line.as_str()))
+    }
+}
+
 /// Result of a search operation
 #[derive(Debug, Clone)]
 pub struct SearchResult {
@@ -300,7 +341,7 @@ impl Default for SearchResult {
 /// Returns an error if:
 /// - File cannot be read
 /// - File is too large
-/// - Pattern is invalid regex (when using regex mode)
+/// - Pattern is invalid or too complex (when using regex mode)
 pub fn search_in_file(
     file_path: &Path,
     pattern: &str,
@@ -327,16 +368,7 @@ pub fn search_in_file(
 
     // Prepare pattern matcher
     let pattern_matcher = if options.use_regex {
-        let regex_pattern = if options.case_sensitive {
-            pattern.to_string()
-        } else {
-            format!("(?i){pattern}")
-        };
-
-        let regex = Regex::new(&regex_pattern)
-            .with_context(|| format!("Invalid regex pattern: {pattern}"))?;
-
-        PatternMatcher::Regex(regex)
+        PatternMatcher::Regex(build_regex(pattern, options)?)
     } else {
         let search_pattern = if options.case_sensitive {
             pattern.to_string()
@@ -357,17 +389,19 @@ pub fn search_in_file(
             search_match.file_path = file_path.to_path_buf();
 
             // Add context lines if requested
-            if options.context_lines > 0 {
+            if options.context_before > 0 {
                 search_match.context_before = get_context_lines(
                     &lines,
                     line_index,
-                    options.context_lines,
+                    options.context_before,
                     ContextDirection::Before,
                 );
+            }
+            if options.context_after > 0 {
                 search_match.context_after = get_context_lines(
                     &lines,
                     line_index,
-                    options.context_lines,
+                    options.context_after,
                     ContextDirection::After,
                 );
             }
@@ -422,6 +456,11 @@ pub fn search_in_directory(
     pattern: &str,
     options: &SearchOptions,
 ) -> Result<SearchResult> {
+    // Reject a bad regex once instead of skipping every file
+    if options.use_regex {
+        build_regex(pattern, options)?;
+    }
+
     let mut result = SearchResult::new();
 
     // Create directory walker
@@ -519,6 +558,85 @@ pub fn search_in_directory(
     Ok(result)
 }
 
+/// Compiled size limit for search regexes (1 MiB)
+const REGEX_SIZE_LIMIT: usize = 1024 * 1024;
+
+/// Validate and compile a search regex
+///
+/// The regex engine runs in linear time, so there is no catastrophic
+/// backtracking as such, but nested repetition like `(a+)+` can still blow up
+/// the compiled program. Such patterns are rejected by
+/// [`check_regex_complexity`] and compilation is size-limited.
+fn build_regex(pattern: &str, options: &SearchOptions) -> Result<Regex> {
+    check_regex_complexity(pattern)?;
+
+    RegexBuilder::new(pattern)
+        .case_insensitive(!options.case_sensitive)
+        .size_limit(REGEX_SIZE_LIMIT)
+        .build()
+        .with_context(|| format!("Invalid regex pattern: {pattern}"))
+}
+
+/// Reject regex patterns that are too long, too deeply nested, or that repeat
+/// a group which itself contains repetition
+///
+/// Length and nesting limits follow [`ExpressionComplexityConfig::default`].
+///
+/// # Errors
+///
+/// Returns an error describing the first limit the pattern exceeds
+pub fn check_regex_complexity(pattern: &str) -> Result<()> {
+    let limits = ExpressionComplexityConfig::default();
+    if pattern.len() > limits.max_length {
+        anyhow::bail!(
+            "Regex pattern too long: {} > {} characters",
+            pattern.len(),
+            limits.max_length
+        );
+    }
+
+    // Whether each open group contains a repetition
+    let mut groups: Vec<bool> = Vec::new();
+    let mut in_class = false;
+    let mut chars = pattern.chars().peekable();
+    while let Some(ch) = chars.next() {
+        match ch {
+            '\\' => {
+                chars.next();
+            }
+            '[' if !in_class => in_class = true,
+            ']' if in_class => in_class = false,
+            _ if in_class => {}
+            '(' => {
+                groups.push(false);
+                if groups.len() > limits.max_depth {
+                    anyhow::bail!(
+                        "Regex pattern nesting too deep: > {} levels",
+                        limits.max_depth
+                    );
+                }
+            }
+            ')' => {
+                let repeated_inside = groups.pop().unwrap_or(false);
+                if repeated_inside && matches!(chars.peek(), Some('*' | '+' | '{')) {
+                    anyhow::bail!("Regex pattern has nested repetition: {pattern}");
+                }
+                if let Some(parent) = groups.last_mut() {
+                    *parent |= repeated_inside;
+                }
+            }
+            '*' | '+' | '{' => {
+                if let Some(group) = groups.last_mut() {
+                    *group = true;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    Ok(())
+}
+
 /// Internal pattern matcher enum
 enum PatternMatcher {
     Literal(String),
@@ -645,6 +763,83 @@ pub fn should_search_file(file_path: &Path, options: &SearchOptions) -> bool {
     true
 }
 
+/// Match options shared by all glob helpers: `*` and `?` stop at `/`, `**` spans directories
+const GLOB_MATCH_OPTIONS: glob::MatchOptions = glob::MatchOptions {
+    case_sensitive: true,
+    require_literal_separator: true,
+    require_literal_leading_dot: false,
+};
+
+/// Check if a path matches a glob pattern
+///
+/// Supports `*` and `?` within one path segment, `**` across any number of
+/// directories, and character classes such as `[a-z]` or `[!0-9]`. Invalid
+/// patterns match nothing.
+#[must_use]
+pub fn glob_match(pattern: &str, path: &str) -> bool {
+    glob::Pattern::new(pattern).is_ok_and(|pattern| pattern.matches_with(path, GLOB_MATCH_OPTIONS))
+}
+
+/// A set of glob patterns compiled once for matching many paths
+#[derive(Debug, Clone, Default)]
+pub struct GlobSet {
+    patterns: Vec<glob::Pattern>,
+}
+
+impl GlobSet {
+    /// Compile a set of glob patterns
+    ///
+    /// # Errors
+    ///
+    /// Returns an error naming the first pattern that is not a valid glob
+    pub fn new<I, S>(patterns: I) -> Result<Self>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let patterns = patterns
+            .into_iter()
+            .map(|pattern| {
+                let pattern = pattern.as_ref();
+                glob::Pattern::new(pattern)
+                    .with_context(|| format!("Invalid glob pattern: {pattern}"))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Self { patterns })
+    }
+
+    /// Check if any pattern matches the path
+    #[must_use]
+    pub fn is_match(&self, path: &str) -> bool {
+        self.patterns
+            .iter()
+            .any(|pattern| pattern.matches_with(path, GLOB_MATCH_OPTIONS))
+    }
+
+    /// Indices of all patterns matching the path
+    #[must_use]
+    pub fn matches(&self, path: &str) -> Vec<usize> {
+        self.patterns
+            .iter()
+            .enumerate()
+            .filter(|(_, pattern)| pattern.matches_with(path, GLOB_MATCH_OPTIONS))
+            .map(|(i, _)| i)
+            .collect()
+    }
+
+    /// Number of patterns in the set
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.patterns.len()
+    }
+
+    /// Check if the set has no patterns
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.patterns.is_empty()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -673,7 +868,8 @@ mod tests {
         assert!(options.recursive);
         assert!(!options.case_sensitive);
         assert!(options.use_regex);
-        assert_eq!(options.context_lines, 3);
+        assert_eq!(options.context_before, 3);
+        assert_eq!(options.context_after, 3);
         assert_eq!(options.max_file_size, 1024);
         assert_eq!(options.include_extensions, vec!["txt", "rs"]);
         assert_eq!(options.exclude_extensions, vec!["bin"]);
@@ -745,6 +941,52 @@ mod tests {
         assert_eq!(search_match.context_after, vec!["Line 4", "Line 5"]);
     }
     #[test]
+    fn test_search_in_file_regex_with_context() {
+        let temp_dir = TempDir::new().unwrap();
+        let content = "start\nconfig loaded\nERROR 42: disk full\nretrying\ngave up\nend";
+        let file_path = create_test_file(temp_dir.path(), "app.log", content);
+
+        let options = SearchOptions::new()
+            .with_regex(true)
+            .with_context_before(1)
+            .with_context_after(2);
+        let matches = search_in_file(&file_path, r"ERROR \d+", &options).unwrap();
+
+        assert_eq!(matches.len(), 1);
+        let search_match = &matches[0];
+        assert_eq!(search_match.line_number, 3);
+        assert_eq!(search_match.matched_text, "ERROR 42");
+        assert_eq!(
+            search_match.numbered_context_before().collect::<Vec<_>>(),
+            vec![(2, "config loaded")]
+        );
+        assert_eq!(
+            search_match.numbered_context_after().collect::<Vec<_>>(),
+            vec![(4, "retrying"), (5, "gave up")]
+        );
+
+        // Context is clipped at the start of the file
+        let matches = search_in_file(&file_path, "^start$", &options).unwrap();
+        assert!(matches[0].context_before.is_empty());
+        assert_eq!(
+            matches[0].context_after,
+            vec!["config loaded", "ERROR 42: disk full"]
+        );
+    }
+    #[test]
+    fn test_regex_complexity_guard() {
+        assert!(check_regex_complexity(r"(ERROR|WARN)\s+\d+").is_ok());
+        assert!(check_regex_complexity(r"[(a+)+]").is_ok());
+        assert!(check_regex_complexity(r"(a+)+$").is_err());
+        assert!(check_regex_complexity(r"((ab)*c)*").is_err());
+        assert!(check_regex_complexity(&"(".repeat(30)).is_err());
+
+        let temp_dir = TempDir::new().unwrap();
+        create_test_file(temp_dir.path(), "test.txt", "aaaa");
+        let options = SearchOptions::new().with_regex(true);
+        assert!(search_in_directory(temp_dir.path(), r"(a*)*", &options).is_err());
+    }
+    #[test]
     fn test_search_in_file_max_matches() {
         let temp_dir = TempDir::new().unwrap();
         let content = "pattern\npattern\npattern\npattern\npattern";
@@ -930,4 +1172,30 @@ mod tests {
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("File too large"));
     }
+    #[test]
+    fn test_glob_match() {
+        assert!(glob_match("**/*.rs", "main.rs"));
+        assert!(glob_match("**/*.rs", "src/lib.rs"));
+        assert!(glob_match("**/*.rs", "crates/a/src/deep/mod.rs"));
+        assert!(glob_match("src/*.toml", "src/Cargo.toml"));
+        assert!(glob_match("file?.[a-c]x[!0-9]", "file1.bxt"));
+        assert!(glob_match("workflow.*", "workflow.started"));
+
+        assert!(!glob_match("**/*.rs", "src/lib.rs.bak"));
+        assert!(!glob_match("src/*.toml", "src/nested/Cargo.toml"));
+        assert!(!glob_match("src/*.toml", "Cargo.toml"));
+        assert!(!glob_match("file?.txt", "file10.txt"));
+        assert!(!glob_match("[z-a", "z"));
+    }
+    #[test]
+    fn test_glob_set() {
+        let set = GlobSet::new(["**/*.rs", "src/*.toml"]).unwrap();
+        assert_eq!(set.len(), 2);
+        assert!(set.is_match("src/config.toml"));
+        assert_eq!(set.matches("src/main.rs"), vec![0]);
+        assert!(!set.is_match("docs/readme.md"));
+
+        assert!(GlobSet::new(["ok/*", "[bad"]).is_err());
+        assert!(!GlobSet::default().is_match("anything"));
+    }
 }
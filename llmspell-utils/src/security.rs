@@ -5,6 +5,7 @@
 //!
 //! This module provides security utilities to protect tools from various attacks:
 //! - Expression complexity analysis for calculator DoS prevention
+//! - Runtime step and wall-clock limits for expression evaluation
 //! - Timeout enforcement for long-running operations
 //! - Resource limit enforcement
 //! - Input size validation
@@ -38,7 +39,9 @@ pub use input_sanitizer::{
 pub use memory_tracker::{MemoryGuard, MemoryTracker, ScopedMemoryTracker};
 pub use ssrf_protection::{SsrfError, SsrfProtectionConfig, SsrfProtector, ValidatedUrl};
 
-use std::time::Duration;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 /// Configuration for expression complexity analysis
 #[derive(Debug, Clone)]
@@ -53,6 +56,8 @@ pub struct ExpressionComplexityConfig {
     pub max_functions: usize,
     /// Maximum evaluation time allowed
     pub max_evaluation_time: Duration,
+    /// Maximum evaluation steps counted by an [`EvaluationBudget`]
+    pub max_evaluation_steps: usize,
 }
 
 impl Default for ExpressionComplexityConfig {
@@ -63,6 +68,7 @@ impl Default for ExpressionComplexityConfig {
             max_operations: 100,
             max_functions: 50,
             max_evaluation_time: Duration::from_millis(100),
+            max_evaluation_steps: 10_000,
         }
     }
 }
@@ -83,6 +89,7 @@ impl ExpressionComplexityConfig {
             max_operations: 50,
             max_functions: 20,
             max_evaluation_time: Duration::from_millis(50),
+            max_evaluation_steps: 1_000,
         }
     }
 
@@ -95,6 +102,7 @@ impl ExpressionComplexityConfig {
             max_operations: 500,
             max_functions: 200,
             max_evaluation_time: Duration::from_millis(500),
+            max_evaluation_steps: 100_000,
         }
     }
 }
@@ -137,6 +145,12 @@ impl ExpressionAnalyzer {
         Self { config }
     }
 
+    /// Get the analyzer configuration
+    #[must_use]
+    pub fn config(&self) -> &ExpressionComplexityConfig {
+        &self.config
+    }
+
     /// Analyze expression complexity
     #[must_use]
     pub fn analyze(&self, expression: &str) -> ExpressionComplexity {
@@ -328,6 +342,126 @@ impl Default for ExpressionAnalyzer {
     }
 }
 
+/// Error from [`evaluate_with_limits`]
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum EvaluationLimitError {
+    /// The expression failed static complexity analysis
+    #[error("Expression too complex: {0}")]
+    TooComplex(String),
+    /// Evaluation took more steps than allowed
+    #[error("Expression evaluation aborted after exceeding {0} steps")]
+    StepLimitExceeded(usize),
+    /// Evaluation ran past its wall-clock limit
+    #[error("Expression evaluation timed out after {0:?}")]
+    TimedOut(Duration),
+    /// The evaluator itself failed
+    #[error("Expression evaluation failed: {0}")]
+    Failed(String),
+}
+
+/// Step and wall-clock budget for a running evaluation
+///
+/// Evaluators call [`EvaluationBudget::step`] once per operation and stop on
+/// the first error it returns.
+#[derive(Debug)]
+pub struct EvaluationBudget {
+    steps: AtomicUsize,
+    max_steps: usize,
+    started: Instant,
+    max_time: Duration,
+}
+
+impl EvaluationBudget {
+    /// Create a budget starting now
+    #[must_use]
+    pub fn new(max_steps: usize, max_time: Duration) -> Self {
+        Self {
+            steps: AtomicUsize::new(0),
+            max_steps,
+            started: Instant::now(),
+            max_time,
+        }
+    }
+
+    /// Count one evaluation step
+    ///
+    /// # Errors
+    ///
+    /// Returns an error once the step or time limit has been exceeded
+    pub fn step(&self) -> Result<(), EvaluationLimitError> {
+        self.steps.fetch_add(1, Ordering::Relaxed);
+        self.check()
+    }
+
+    /// Check the budget without counting a step
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the step or time limit has been exceeded
+    pub fn check(&self) -> Result<(), EvaluationLimitError> {
+        if self.steps() > self.max_steps {
+            return Err(EvaluationLimitError::StepLimitExceeded(self.max_steps));
+        }
+        if self.started.elapsed() > self.max_time {
+            return Err(EvaluationLimitError::TimedOut(self.max_time));
+        }
+        Ok(())
+    }
+
+    /// Number of steps counted so far
+    #[must_use]
+    pub fn steps(&self) -> usize {
+        self.steps.load(Ordering::Relaxed)
+    }
+}
+
+/// Evaluate an expression under static and runtime limits
+///
+/// The expression must first pass [`ExpressionAnalyzer`] checks for `config`.
+/// `evaluate` then runs on a blocking thread with an [`EvaluationBudget`] of
+/// `max_evaluation_steps` and `max_evaluation_time`. The caller gets control
+/// back once the time limit passes even if the evaluator never checks its
+/// budget; an evaluator that does check it stops at its next step.
+///
+/// # Errors
+///
+/// Returns an error if the expression is too complex, a runtime limit is
+/// exceeded, or the evaluator fails
+pub async fn evaluate_with_limits<T, F>(
+    expression: &str,
+    config: &ExpressionComplexityConfig,
+    evaluate: F,
+) -> Result<T, EvaluationLimitError>
+where
+    T: Send + 'static,
+    F: FnOnce(&EvaluationBudget) -> Result<T, EvaluationLimitError> + Send + 'static,
+{
+    let complexity = ExpressionAnalyzer::with_config(config.clone()).analyze(expression);
+    if !complexity.is_safe {
+        return Err(EvaluationLimitError::TooComplex(
+            complexity.unsafe_reason.unwrap_or_default(),
+        ));
+    }
+
+    let budget = Arc::new(EvaluationBudget::new(
+        config.max_evaluation_steps,
+        config.max_evaluation_time,
+    ));
+    let task = tokio::task::spawn_blocking(move || {
+        let result = evaluate(&budget);
+        // Report an exhausted budget even if the evaluator swallowed the step error
+        budget.check().and(result)
+    });
+
+    match tokio::time::timeout(config.max_evaluation_time, task).await {
+        Ok(Ok(result)) => result,
+        Ok(Err(e)) => Err(EvaluationLimitError::Failed(format!(
+            "Evaluation task failed: {e}"
+        ))),
+        Err(_) => Err(EvaluationLimitError::TimedOut(config.max_evaluation_time)),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -423,6 +557,58 @@ mod tests {
             assert!(result.is_safe, "Expression '{expr}' should be safe");
         }
     }
+    /// Naive recursive Fibonacci counting one step per call
+    fn fib(n: u64, budget: &EvaluationBudget) -> Result<u64, EvaluationLimitError> {
+        budget.step()?;
+        if n < 2 {
+            return Ok(n);
+        }
+        Ok(fib(n - 1, budget)? + fib(n - 2, budget)?)
+    }
+
+    #[tokio::test]
+    async fn test_evaluate_with_limits_aborts_runaway_evaluation() {
+        let config = ExpressionComplexityConfig {
+            max_evaluation_steps: 1_000,
+            max_evaluation_time: Duration::from_millis(200),
+            ..Default::default()
+        };
+        // Statically the expression is trivial
+        assert!(
+            ExpressionAnalyzer::with_config(config.clone())
+                .analyze("fib(x)")
+                .is_safe
+        );
+
+        // Exponential recursion hits the step limit
+        let err = evaluate_with_limits("fib(x)", &config, |budget| fib(90, budget))
+            .await
+            .unwrap_err();
+        assert_eq!(err, EvaluationLimitError::StepLimitExceeded(1_000));
+        assert!(err.to_string().contains("exceeding 1000 steps"));
+
+        // A loop that takes no steps is stopped by the wall clock
+        let err = evaluate_with_limits("fib(x)", &config, |budget| -> Result<(), _> {
+            loop {
+                budget.check()?;
+                std::thread::sleep(Duration::from_millis(10));
+            }
+        })
+        .await
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Expression evaluation timed out after 200ms"
+        );
+
+        // Bounded evaluations and static rejections are unaffected
+        let value = evaluate_with_limits("fib(x)", &config, |budget| fib(10, budget)).await;
+        assert_eq!(value, Ok(55));
+        let err = evaluate_with_limits("1 +++ 2", &config, |_| Ok(0))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, EvaluationLimitError::TooComplex(_)));
+    }
     #[test]
     fn test_config_presets() {
         let strict = ExpressionComplexityConfig::strict();
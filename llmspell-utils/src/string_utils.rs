@@ -692,6 +692,62 @@ pub fn join_with(parts: &[&str], delimiter: &str) -> String {
     parts.join(delimiter)
 }
 
+/// Compute the Levenshtein edit distance between two strings
+///
+/// Counts the single-character insertions, deletions and substitutions
+/// needed to turn `a` into `b`. Operates on characters, not bytes.
+///
+/// # Examples
+///
+/// ```rust
+/// use llmspell_utils::string_utils::levenshtein_distance;
+///
+/// assert_eq!(levenshtein_distance("kitten", "sitting"), 3);
+/// assert_eq!(levenshtein_distance("research", "reserch"), 1);
+/// assert_eq!(levenshtein_distance("", "abc"), 3);
+/// ```
+#[must_use]
+pub fn levenshtein_distance(a: &str, b: &str) -> usize {
+    let b_chars: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b_chars.len()).collect();
+    let mut current = vec![0; b_chars.len() + 1];
+
+    for (i, a_char) in a.chars().enumerate() {
+        current[0] = i + 1;
+        for (j, b_char) in b_chars.iter().enumerate() {
+            let substitution = previous[j] + usize::from(a_char != *b_char);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+
+    previous[b_chars.len()]
+}
+
+/// Fuzzy similarity between two strings in the range `0.0..=1.0`
+///
+/// Normalizes the Levenshtein distance by the longer string's length, so
+/// identical strings score `1.0` and completely different ones `0.0`.
+///
+/// # Examples
+///
+/// ```rust
+/// use llmspell_utils::string_utils::fuzzy_similarity;
+///
+/// assert!((fuzzy_similarity("research", "research") - 1.0).abs() < f64::EPSILON);
+/// assert!(fuzzy_similarity("research", "reserch") > 0.8);
+/// assert!(fuzzy_similarity("research", "chat") < 0.3);
+/// ```
+#[must_use]
+#[allow(clippy::cast_precision_loss)]
+pub fn fuzzy_similarity(a: &str, b: &str) -> f64 {
+    let longest = a.chars().count().max(b.chars().count());
+    if longest == 0 {
+        return 1.0;
+    }
+    1.0 - levenshtein_distance(a, b) as f64 / longest as f64
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -947,6 +1003,17 @@ mod tests {
         assert_eq!(join_with(&["single"], ","), "single");
         assert_eq!(join_with(&["", "", ""], "-"), "--");
     }
+    #[test]
+    fn test_levenshtein_and_fuzzy_similarity() {
+        assert_eq!(levenshtein_distance("kitten", "sitting"), 3);
+        assert_eq!(levenshtein_distance("same", "same"), 0);
+        assert_eq!(levenshtein_distance("abc", ""), 3);
+        assert_eq!(levenshtein_distance("café", "cafe"), 1);
+
+        assert!((fuzzy_similarity("", "") - 1.0).abs() < f64::EPSILON);
+        assert!((fuzzy_similarity("abc", "xyz")).abs() < f64::EPSILON);
+        assert!((fuzzy_similarity("research", "reserch") - 0.875).abs() < f64::EPSILON);
+    }
 }
 
 #[cfg(test)]
@@ -11,6 +11,8 @@ pub mod table;
 
 // Re-export commonly used items
 pub use color::{colored_text, Color, Colorize};
-pub use progress::{ProgressEvent, ProgressReporter, ProgressTracker};
+pub use progress::{
+    MultiProgress, MultiProgressEvent, ProgressEvent, ProgressReporter, ProgressTracker,
+};
 pub use prompt::{confirm, input, input_with_validation, select, AsyncSpinner, SimpleSpinner};
 pub use table::{quick_table, SimpleTable, TableStyle};
@@ -366,6 +366,223 @@ impl ProgressTracker {
     }
 }
 
+/// Event emitted by a [`MultiProgress`]
+#[derive(Debug, Clone)]
+pub enum MultiProgressEvent {
+    /// Event from one child operation
+    Child {
+        /// Name of the child operation
+        name: String,
+        /// The child's own progress event
+        event: ProgressEvent,
+    },
+
+    /// Progress of the whole batch, summed over all children
+    Aggregate(ProgressEvent),
+}
+
+/// Progress state of one child operation
+struct ChildProgress {
+    name: String,
+    current: u64,
+    total: Option<u64>,
+    finished: bool,
+    error: Option<String>,
+}
+
+/// State shared between a multi-progress handle and its forwarding tasks
+struct MultiProgressState {
+    children: Vec<ChildProgress>,
+    completed: bool,
+}
+
+impl MultiProgressState {
+    /// Summed progress; the total is known only if every child's total is
+    fn aggregate(&self) -> (u64, Option<u64>) {
+        let current = self.children.iter().map(|child| child.current).sum();
+        let total = self
+            .children
+            .iter()
+            .map(|child| child.total)
+            .sum::<Option<u64>>();
+        (current, total)
+    }
+}
+
+/// Progress view over several concurrent operations with an overall aggregate
+///
+/// Each child is a regular [`ProgressReporter`] that can be moved into its
+/// own task. Every child event is re-emitted tagged with the child's name,
+/// followed by an aggregate update; once every child added so far has
+/// finished, an aggregate `Completed` (or `Failed`) event is emitted.
+#[derive(Clone)]
+pub struct MultiProgress {
+    operation: String,
+    started_at: Instant,
+    state: Arc<parking_lot::Mutex<MultiProgressState>>,
+    sender: mpsc::UnboundedSender<MultiProgressEvent>,
+}
+
+impl MultiProgress {
+    /// Create a new multi-progress view
+    pub fn new(
+        operation: impl Into<String>,
+    ) -> (Self, mpsc::UnboundedReceiver<MultiProgressEvent>) {
+        let (sender, receiver) = mpsc::unbounded_channel();
+        let operation = operation.into();
+
+        let _ = sender.send(MultiProgressEvent::Aggregate(ProgressEvent::Started {
+            operation: operation.clone(),
+            total: None,
+        }));
+
+        let multi = Self {
+            operation,
+            started_at: Instant::now(),
+            state: Arc::new(parking_lot::Mutex::new(MultiProgressState {
+                children: Vec::new(),
+                completed: false,
+            })),
+            sender,
+        };
+
+        (multi, receiver)
+    }
+
+    /// Add a child operation and return its reporter
+    pub fn add_child(&self, name: impl Into<String>, total: Option<u64>) -> ProgressReporter {
+        let name = name.into();
+        let index = {
+            let mut state = self.state.lock();
+            state.completed = false;
+            state.children.push(ChildProgress {
+                name: name.clone(),
+                current: 0,
+                total,
+                finished: false,
+                error: None,
+            });
+            state.children.len() - 1
+        };
+
+        let (reporter, mut receiver) = ProgressReporter::new(name, total);
+
+        let multi = self.clone();
+        tokio::spawn(async move {
+            while let Some(event) = receiver.recv().await {
+                multi.record(index, event);
+            }
+        });
+
+        reporter
+    }
+
+    /// Apply a child event and emit it along with the resulting aggregate
+    fn record(&self, index: usize, event: ProgressEvent) {
+        // Hold the lock while sending so aggregate events stay in order
+        let mut state = self.state.lock();
+        let child = &mut state.children[index];
+        match &event {
+            ProgressEvent::Update { current, .. } => child.current = *current,
+            ProgressEvent::Completed { .. } => {
+                child.current = child.total.unwrap_or(child.current);
+                child.finished = true;
+            }
+            ProgressEvent::Failed { error, .. } => {
+                child.finished = true;
+                child.error = Some(error.clone());
+            }
+            _ => {}
+        }
+        let name = child.name.clone();
+        let child_finished = child.finished;
+
+        let _ = self.sender.send(MultiProgressEvent::Child { name, event });
+
+        let (current, total) = state.aggregate();
+        let _ = self
+            .sender
+            .send(MultiProgressEvent::Aggregate(ProgressEvent::Update {
+                current,
+                total,
+                message: None,
+            }));
+
+        if child_finished && !state.completed && state.children.iter().all(|c| c.finished) {
+            state.completed = true;
+            let duration = self.started_at.elapsed();
+            let failed: Vec<&str> = state
+                .children
+                .iter()
+                .filter(|child| child.error.is_some())
+                .map(|child| child.name.as_str())
+                .collect();
+
+            let event = if failed.is_empty() {
+                info!("{} completed in {:?}", self.operation, duration);
+                ProgressEvent::Completed {
+                    message: None,
+                    duration,
+                }
+            } else {
+                ProgressEvent::Failed {
+                    error: format!("Failed operations: {}", failed.join(", ")),
+                    duration,
+                }
+            };
+            let _ = self.sender.send(MultiProgressEvent::Aggregate(event));
+        }
+    }
+
+    /// Get summed progress of all children as `(current, total)`
+    #[must_use]
+    pub fn aggregate(&self) -> (u64, Option<u64>) {
+        self.state.lock().aggregate()
+    }
+
+    /// Get aggregate progress percentage (if every child's total is known)
+    #[must_use]
+    pub fn percentage(&self) -> Option<f64> {
+        let (current, total) = self.aggregate();
+        let total = total?;
+        if total == 0 {
+            return Some(100.0);
+        }
+        #[allow(clippy::cast_precision_loss)]
+        {
+            Some((current as f64 / total as f64) * 100.0)
+        }
+    }
+
+    /// Estimate remaining time from the average rate so far
+    #[must_use]
+    pub fn eta(&self) -> Option<Duration> {
+        let (current, total) = self.aggregate();
+        let total = total?;
+        if current == 0 {
+            return None;
+        }
+
+        let remaining = u128::from(total.saturating_sub(current));
+        let nanos = self.started_at.elapsed().as_nanos() * remaining / u128::from(current);
+        Some(Duration::from_nanos(
+            u64::try_from(nanos).unwrap_or(u64::MAX),
+        ))
+    }
+
+    /// Get names of children that have not finished
+    #[must_use]
+    pub fn active_children(&self) -> Vec<String> {
+        self.state
+            .lock()
+            .children
+            .iter()
+            .filter(|child| !child.finished)
+            .map(|child| child.name.clone())
+            .collect()
+    }
+}
+
 /// Progress reporter builder for fluent API
 pub struct ProgressBuilder {
     operation: String,
@@ -572,4 +789,57 @@ mod tests {
         assert_eq!(reporter.total, Some(200));
         assert_eq!(reporter.operation, "Built Operation");
     }
+    #[tokio::test]
+    async fn test_multi_progress_concurrent_children() {
+        let (multi, mut receiver) = MultiProgress::new("Batch");
+        let totals = [("a", 10), ("b", 20), ("c", 30)];
+
+        let handles: Vec<_> = totals
+            .iter()
+            .map(|&(name, total)| {
+                let reporter = multi.add_child(name, Some(total));
+                tokio::spawn(async move {
+                    for _ in 0..total {
+                        reporter.increment(1).await.unwrap();
+                        tokio::task::yield_now().await;
+                    }
+                    reporter.complete(None).unwrap();
+                })
+            })
+            .collect();
+        for handle in handles {
+            handle.await.unwrap();
+        }
+
+        let mut updates = std::collections::HashMap::new();
+        let mut completed = Vec::new();
+        let mut last_aggregate = None;
+        loop {
+            let event = tokio::time::timeout(Duration::from_secs(5), receiver.recv())
+                .await
+                .expect("aggregate should complete")
+                .unwrap();
+            match event {
+                MultiProgressEvent::Child { name, event } => match event {
+                    ProgressEvent::Update { .. } => *updates.entry(name).or_insert(0) += 1,
+                    ProgressEvent::Completed { .. } => completed.push(name),
+                    _ => {}
+                },
+                MultiProgressEvent::Aggregate(ProgressEvent::Update { current, total, .. }) => {
+                    last_aggregate = Some((current, total));
+                }
+                MultiProgressEvent::Aggregate(ProgressEvent::Completed { .. }) => break,
+                MultiProgressEvent::Aggregate(_) => {}
+            }
+        }
+
+        for (name, total) in totals {
+            assert_eq!(updates.get(name), Some(&total), "updates for {name}");
+            assert!(completed.iter().any(|c| c == name), "{name} completed");
+        }
+        assert_eq!(last_aggregate, Some((60, Some(60))));
+        assert!((multi.percentage().unwrap() - 100.0).abs() < f64::EPSILON);
+        assert_eq!(multi.eta(), Some(Duration::ZERO));
+        assert!(multi.active_children().is_empty());
+    }
 }
@@ -96,20 +96,73 @@ pub fn select(prompt: &str, options: &[&str], default: usize) -> io::Result<usiz
     }
 }
 
-/// Simple password input (echoes * characters)
+/// Read a password from stdin without echoing it
+///
+/// Echo is disabled while reading on Unix terminals. Elsewhere, or when stdin
+/// is not a terminal, the input is read as typed.
 ///
 /// # Errors
 ///
 /// Returns an error if reading from stdin fails.
 pub fn password(prompt: &str) -> io::Result<String> {
-    // Note: This is a simple implementation that still shows input.
-    // For true password hiding, we'd need platform-specific code or a dependency.
-    print!("{prompt} (input will be visible): ");
+    print!("{prompt}: ");
     io::stdout().flush()?;
 
     let mut buffer = String::new();
-    io::stdin().read_line(&mut buffer)?;
-    Ok(buffer.trim().to_string())
+    #[cfg(unix)]
+    let echo = EchoGuard::disable();
+    let result = io::stdin().read_line(&mut buffer);
+    #[cfg(unix)]
+    {
+        if echo.is_some() {
+            drop(echo);
+            // The newline typed by the user was not echoed
+            println!();
+        }
+    }
+    result?;
+    Ok(buffer.trim_end_matches(['\r', '\n']).to_string())
+}
+
+/// Disables terminal echo on stdin until dropped
+#[cfg(unix)]
+struct EchoGuard {
+    fd: libc::c_int,
+    original: libc::termios,
+}
+
+#[cfg(unix)]
+#[allow(unsafe_code)]
+impl EchoGuard {
+    /// Turn echo off, `None` if stdin is not a terminal
+    fn disable() -> Option<Self> {
+        use std::os::unix::io::AsRawFd;
+
+        let fd = io::stdin().as_raw_fd();
+        let mut original = std::mem::MaybeUninit::<libc::termios>::uninit();
+        // SAFETY: tcgetattr only writes into the provided termios struct
+        if unsafe { libc::tcgetattr(fd, original.as_mut_ptr()) } != 0 {
+            return None;
+        }
+        // SAFETY: tcgetattr succeeded, so the struct is initialized
+        let original = unsafe { original.assume_init() };
+        let mut hidden = original;
+        hidden.c_lflag &= !libc::ECHO;
+        // SAFETY: fd is stdin and `hidden` is a valid termios read from it
+        if unsafe { libc::tcsetattr(fd, libc::TCSANOW, &hidden) } != 0 {
+            return None;
+        }
+        Some(Self { fd, original })
+    }
+}
+
+#[cfg(unix)]
+#[allow(unsafe_code)]
+impl Drop for EchoGuard {
+    fn drop(&mut self) {
+        // SAFETY: restores the settings read in `disable`
+        unsafe { libc::tcsetattr(self.fd, libc::TCSANOW, &self.original) };
+    }
 }
 
 /// Simple spinner for long operations
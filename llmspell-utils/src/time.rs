@@ -7,10 +7,12 @@
 //! - Parsing dates from multiple formats
 //! - Timezone conversion with DST handling
 //! - Date arithmetic operations
+//! - Business-day arithmetic with weekend and holiday calendars
 //! - Formatting dates in various standards
 
-use chrono::{DateTime, Datelike, Duration, Local, NaiveDateTime, Utc, Weekday};
+use chrono::{DateTime, Datelike, Duration, Local, NaiveDate, NaiveDateTime, Utc, Weekday};
 use chrono_tz::Tz;
+use std::collections::HashSet;
 use std::str::FromStr;
 
 /// Common date formats for parsing
@@ -221,6 +223,97 @@ pub fn days_in_month(year: i32, month: u32) -> Option<u32> {
     }
 }
 
+/// Calendar of non-working days used for business-day arithmetic
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BusinessCalendar {
+    weekend: HashSet<Weekday>,
+    holidays: HashSet<NaiveDate>,
+}
+
+impl Default for BusinessCalendar {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BusinessCalendar {
+    /// Create a calendar with a Saturday/Sunday weekend and no holidays
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            weekend: HashSet::from([Weekday::Sat, Weekday::Sun]),
+            holidays: HashSet::new(),
+        }
+    }
+
+    /// Replace the weekend days
+    #[must_use]
+    pub fn with_weekend(mut self, days: impl IntoIterator<Item = Weekday>) -> Self {
+        self.weekend = days.into_iter().collect();
+        self
+    }
+
+    /// Add a holiday
+    #[must_use]
+    pub fn with_holiday(mut self, date: NaiveDate) -> Self {
+        self.holidays.insert(date);
+        self
+    }
+
+    /// Add several holidays
+    #[must_use]
+    pub fn with_holidays(mut self, dates: impl IntoIterator<Item = NaiveDate>) -> Self {
+        self.holidays.extend(dates);
+        self
+    }
+
+    /// Check if a calendar date is a working day
+    #[must_use]
+    pub fn is_business_date(&self, date: NaiveDate) -> bool {
+        !self.weekend.contains(&date.weekday()) && !self.holidays.contains(&date)
+    }
+}
+
+/// Check if a datetime falls on a business day
+#[must_use]
+pub fn is_business_day(dt: &DateTime<Utc>, calendar: &BusinessCalendar) -> bool {
+    calendar.is_business_date(dt.date_naive())
+}
+
+/// Add business days to a datetime, skipping weekends and holidays
+///
+/// Negative amounts move backwards. The time of day is preserved, and adding
+/// zero returns the input even if it is not a business day.
+///
+/// # Errors
+///
+/// Returns `TimeError::InvalidOperation` if the calendar has no working weekdays
+/// or arithmetic overflows
+pub fn add_business_days(
+    dt: &DateTime<Utc>,
+    amount: i64,
+    calendar: &BusinessCalendar,
+) -> TimeResult<DateTime<Utc>> {
+    if amount != 0 && calendar.weekend.len() >= 7 {
+        return Err(TimeError::InvalidOperation(
+            "Calendar has no business days".to_string(),
+        ));
+    }
+
+    let step = Duration::days(amount.signum());
+    let mut result = *dt;
+    let mut remaining = amount.unsigned_abs();
+    while remaining > 0 {
+        result = result
+            .checked_add_signed(step)
+            .ok_or_else(|| TimeError::InvalidOperation("Date arithmetic overflow".to_string()))?;
+        if is_business_day(&result, calendar) {
+            remaining -= 1;
+        }
+    }
+    Ok(result)
+}
+
 /// Format duration in human readable format
 #[must_use]
 pub fn format_duration(duration: &Duration) -> String {
@@ -262,6 +355,49 @@ mod tests {
     use super::*;
     use chrono::Timelike;
     #[test]
+    fn test_add_business_days() {
+        let calendar = BusinessCalendar::new();
+        // Thursday 2024-01-11
+        let thursday = parse_datetime("2024-01-11T09:00:00Z").unwrap();
+
+        let result = add_business_days(&thursday, 3, &calendar).unwrap();
+        assert_eq!(result, parse_datetime("2024-01-16T09:00:00Z").unwrap());
+        assert!(!is_business_day(
+            &parse_datetime("2024-01-13").unwrap(),
+            &calendar
+        ));
+
+        // Spanning two weekends
+        let result = add_business_days(&thursday, 7, &calendar).unwrap();
+        assert_eq!(
+            result.date_naive(),
+            NaiveDate::from_ymd_opt(2024, 1, 22).unwrap()
+        );
+
+        // Monday 2024-01-15 is a holiday
+        let holiday = NaiveDate::from_ymd_opt(2024, 1, 15).unwrap();
+        let calendar = calendar.with_holiday(holiday);
+        let result = add_business_days(&thursday, 3, &calendar).unwrap();
+        assert_eq!(
+            result.date_naive(),
+            NaiveDate::from_ymd_opt(2024, 1, 17).unwrap()
+        );
+
+        let back = add_business_days(&result, -3, &calendar).unwrap();
+        assert_eq!(back, thursday);
+
+        let never = BusinessCalendar::new().with_weekend([
+            Weekday::Mon,
+            Weekday::Tue,
+            Weekday::Wed,
+            Weekday::Thu,
+            Weekday::Fri,
+            Weekday::Sat,
+            Weekday::Sun,
+        ]);
+        assert!(add_business_days(&thursday, 1, &never).is_err());
+    }
+    #[test]
     fn test_parse_datetime_formats() {
         let test_dates = vec![
             "2024-01-15T10:30:00Z",
@@ -1,5 +1,6 @@
 // ABOUTME: Timeout management utilities for async operations
 // ABOUTME: Provides consistent timeout handling with cancellation support
+// ABOUTME: Supports absolute deadlines shared across nested operations
 
 use std::fmt::Display;
 use std::future::Future;
@@ -8,7 +9,7 @@ use std::sync::Arc;
 use std::task::{Context, Poll};
 use std::time::Duration;
 use thiserror::Error;
-use tokio::time::{error::Elapsed, timeout, Instant};
+use tokio::time::{error::Elapsed, timeout, timeout_at, Instant};
 use tracing::{debug, warn};
 
 /// Timeout error types
@@ -52,6 +53,8 @@ pub struct TimeoutConfig {
     pub warn_threshold: Option<Duration>,
     /// Grace period before hard timeout
     pub grace_period: Option<Duration>,
+    /// Absolute deadline bounding every timeout
+    pub deadline: Option<Instant>,
 }
 
 impl Default for TimeoutConfig {
@@ -61,6 +64,7 @@ impl Default for TimeoutConfig {
             max_timeout: Some(Duration::from_secs(300)), // 5 minutes
             warn_threshold: Some(Duration::from_secs(10)),
             grace_period: Some(Duration::from_secs(5)),
+            deadline: None,
         }
     }
 }
@@ -75,6 +79,22 @@ impl TimeoutConfig {
         }
     }
 
+    /// Create a timeout config bounded by an absolute deadline
+    ///
+    /// Nested operations built from the same deadline share one time budget
+    /// instead of each starting a fresh relative timer.
+    #[must_use]
+    pub fn deadline(deadline: impl Into<Instant>) -> Self {
+        Self::default().with_deadline(deadline)
+    }
+
+    /// Set the absolute deadline
+    #[must_use]
+    pub fn with_deadline(mut self, deadline: impl Into<Instant>) -> Self {
+        self.deadline = Some(deadline.into());
+        self
+    }
+
     /// Set the maximum timeout
     #[must_use]
     pub fn with_max_timeout(mut self, max: Duration) -> Self {
@@ -124,6 +144,18 @@ impl TimeoutConfig {
 
         Ok(effective)
     }
+
+    /// Validate a requested timeout and cap it at the time left before the deadline
+    ///
+    /// # Errors
+    ///
+    /// Returns `TimeoutError::InvalidConfiguration` if the duration is zero.
+    pub fn effective_timeout(&self, requested: Duration) -> Result<Duration, TimeoutError> {
+        let validated = self.validate_timeout(requested)?;
+        Ok(self.deadline.map_or(validated, |deadline| {
+            validated.min(deadline.saturating_duration_since(Instant::now()))
+        }))
+    }
 }
 
 /// Execute an operation with timeout
@@ -141,6 +173,30 @@ where
     }
 }
 
+/// Execute an operation that must finish by an absolute deadline
+///
+/// Unlike [`with_timeout`], passing the same deadline to nested calls keeps
+/// them on one time budget regardless of how long the outer work has taken.
+///
+/// # Errors
+///
+/// Returns `TimeoutError::Timeout` if the deadline passes first; its duration is
+/// the time that was left when the call started.
+pub async fn with_deadline<F, T>(
+    deadline: impl Into<Instant>,
+    operation: F,
+) -> Result<T, TimeoutError>
+where
+    F: Future<Output = T>,
+{
+    let deadline = deadline.into();
+    let budget = deadline.saturating_duration_since(Instant::now());
+
+    timeout_at(deadline, operation)
+        .await
+        .map_err(|_| TimeoutError::Timeout { duration: budget })
+}
+
 /// Execute an operation with timeout and configuration
 ///
 /// # Errors
@@ -155,7 +211,7 @@ pub async fn with_timeout_config<F, T>(
 where
     F: Future<Output = T>,
 {
-    let duration = config.validate_timeout(requested_timeout.unwrap_or(config.default_timeout))?;
+    let duration = config.effective_timeout(requested_timeout.unwrap_or(config.default_timeout))?;
 
     let start = Instant::now();
 
@@ -282,7 +338,7 @@ impl TimeoutManager {
         let name_str = name.to_string();
         let timeout_duration = self
             .config
-            .validate_timeout(duration.unwrap_or(self.config.default_timeout))?;
+            .effective_timeout(duration.unwrap_or(self.config.default_timeout))?;
 
         // Register the operation
         {
@@ -393,6 +449,14 @@ pub trait TimeoutExt: Future + Sized {
         with_timeout(duration, self)
     }
 
+    /// Require this future to finish by an absolute deadline
+    fn with_deadline(
+        self,
+        deadline: impl Into<Instant>,
+    ) -> impl Future<Output = Result<Self::Output, TimeoutError>> {
+        with_deadline(deadline, self)
+    }
+
     /// Add a cancellable timeout to this future
     fn with_cancellable_timeout(self, duration: Duration) -> CancellableTimeout<Self> {
         CancellableTimeout::new(self, duration)
@@ -490,4 +554,31 @@ mod tests {
         let result = future.with_timeout(Duration::from_secs(1)).await;
         assert_eq!(result.unwrap(), 42);
     }
+    #[tokio::test(start_paused = true)]
+    async fn test_nested_deadline_uses_absolute_time() {
+        let start = Instant::now();
+        let deadline = start + Duration::from_millis(500);
+        let config = TimeoutConfig::deadline(deadline);
+
+        let outer = with_deadline(deadline, async {
+            // Intermediate work uses up part of the shared budget
+            sleep(Duration::from_millis(300)).await;
+            assert_eq!(
+                config.effective_timeout(Duration::from_secs(10)).unwrap(),
+                Duration::from_millis(200)
+            );
+
+            let inner = with_deadline(deadline, sleep(Duration::from_secs(10))).await;
+            (inner, Instant::now())
+        })
+        .await;
+
+        // The inner call fires at the shared deadline, not 500ms after it started
+        let (inner, fired_at) = outer.unwrap();
+        assert!(matches!(
+            inner,
+            Err(TimeoutError::Timeout { duration }) if duration == Duration::from_millis(200)
+        ));
+        assert_eq!(fired_at, deadline);
+    }
 }
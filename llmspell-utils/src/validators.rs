@@ -6,7 +6,9 @@
 //! This module provides reusable validation functions to ensure consistent
 //! validation logic and error messages across all LLMSpell tools.
 
+use crate::response::ValidationError;
 use llmspell_core::{LLMSpellError, Result as LLMResult};
+use regex::Regex;
 use std::path::Path;
 
 /// Validate that a file size is within allowed limits
@@ -467,6 +469,135 @@ pub fn validate_file_permissions(path: &Path, required_mode: u32) -> LLMResult<(
     Ok(())
 }
 
+/// A single rule in a [`ValidationChain`], called with the value and field name
+type Rule = Box<dyn Fn(&str, &str) -> LLMResult<()> + Send + Sync>;
+
+/// Composes several string validators for one field
+///
+/// Unlike calling the validators directly, every rule runs and all failures
+/// are reported together.
+///
+/// # Examples
+/// ```rust,ignore
+/// ValidationChain::new("username")
+///     .not_empty()
+///     .max_length(32)
+///     .pattern(Regex::new("^[a-z]+$")?)
+///     .validate(username)?;
+/// ```
+pub struct ValidationChain {
+    field: String,
+    rules: Vec<Rule>,
+}
+
+impl ValidationChain {
+    /// Create an empty chain for `field`
+    #[must_use]
+    pub fn new(field: impl Into<String>) -> Self {
+        Self {
+            field: field.into(),
+            rules: Vec::new(),
+        }
+    }
+
+    /// Add a custom rule
+    #[must_use]
+    pub fn rule<F>(mut self, rule: F) -> Self
+    where
+        F: Fn(&str, &str) -> LLMResult<()> + Send + Sync + 'static,
+    {
+        self.rules.push(Box::new(rule));
+        self
+    }
+
+    /// Require a non-empty value
+    #[must_use]
+    pub fn not_empty(self) -> Self {
+        self.rule(validate_not_empty)
+    }
+
+    /// Require at most `max_length` bytes
+    #[must_use]
+    pub fn max_length(self, max_length: usize) -> Self {
+        self.rule(move |value, _| validate_string_length(value, max_length))
+    }
+
+    /// Require a match for `pattern`
+    #[must_use]
+    pub fn pattern(self, pattern: Regex) -> Self {
+        self.rule(move |value, field| {
+            if pattern.is_match(value) {
+                Ok(())
+            } else {
+                Err(LLMSpellError::Validation {
+                    message: format!("{field} does not match required pattern: {pattern}"),
+                    field: Some(field.to_string()),
+                })
+            }
+        })
+    }
+
+    /// Require a valid identifier
+    #[must_use]
+    pub fn identifier(self) -> Self {
+        self.rule(validate_identifier)
+    }
+
+    /// Require a valid URL
+    #[must_use]
+    pub fn url(self) -> Self {
+        self.rule(validate_url)
+    }
+
+    /// Require a valid email address
+    #[must_use]
+    pub fn email(self) -> Self {
+        self.rule(validate_email)
+    }
+
+    /// Reject shell metacharacters
+    #[must_use]
+    pub fn no_shell_injection(self) -> Self {
+        self.rule(validate_no_shell_injection)
+    }
+
+    /// Run every rule against `value`
+    ///
+    /// # Errors
+    ///
+    /// Returns one `ValidationError` per failed rule, each tagged with the
+    /// chain's field name
+    pub fn validate(&self, value: &str) -> Result<(), Vec<ValidationError>> {
+        let errors: Vec<ValidationError> = self
+            .rules
+            .iter()
+            .filter_map(|rule| rule(value, &self.field).err())
+            .map(|e| {
+                let message = match e {
+                    LLMSpellError::Validation { message, .. } => message,
+                    other => other.to_string(),
+                };
+                ValidationError::new(message).with_field(self.field.clone())
+            })
+            .collect();
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+impl std::fmt::Debug for ValidationChain {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ValidationChain")
+            .field("field", &self.field)
+            .field("rules", &self.rules.len())
+            .finish()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -650,4 +781,22 @@ mod tests {
         // Validate incorrect permissions
         assert!(validate_file_permissions(&file_path, 0o755).is_err());
     }
+    #[test]
+    fn test_validation_chain_collects_all_failures() {
+        let chain = ValidationChain::new("username")
+            .not_empty()
+            .max_length(8)
+            .pattern(Regex::new("^[a-z_]+$").unwrap())
+            .no_shell_injection();
+
+        assert!(chain.validate("alice").is_ok());
+
+        let errors = chain.validate("Alice-the-great").unwrap_err();
+        assert_eq!(errors.len(), 2);
+        assert!(errors
+            .iter()
+            .all(|e| e.field.as_deref() == Some("username")));
+        assert!(errors[0].message.contains("exceeds maximum allowed length"));
+        assert!(errors[1].message.contains("username does not match"));
+    }
 }
@@ -157,7 +157,7 @@ async fn test_async_utils_exports() {
         initial_delay: Duration::from_millis(1),
         backoff_factor: 1.0,
         max_delay: Duration::from_millis(10),
-        jitter: false,
+        jitter: JitterStrategy::None,
     };
 
     let result = retry_async(config, || {
@@ -107,6 +107,7 @@ impl WorkflowInputAdapter {
             context,
             parameters,
             output_modalities: Vec::new(),
+            attachments: Vec::new(),
         }
     }
 }
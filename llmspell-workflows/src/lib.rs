@@ -68,7 +68,7 @@ pub use state::{
 pub use step_executor::StepExecutor;
 
 pub use r#loop::{
-    BreakCondition, LoopConfig, LoopIterator, LoopWorkflow, LoopWorkflowBuilder,
+    AggregateFunction, BreakCondition, LoopConfig, LoopIterator, LoopWorkflow, LoopWorkflowBuilder,
     LoopWorkflowResult, ResultAggregation,
 };
 
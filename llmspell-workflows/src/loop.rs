@@ -22,7 +22,7 @@ use llmspell_core::{
     types::{AgentInput, AgentOutput},
     ComponentId, ComponentLookup, ComponentMetadata, LLMSpellError, Result,
 };
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use serde_json::Value;
 use std::{
     collections::HashMap,
@@ -60,12 +60,124 @@ pub enum LoopIterator {
 }
 
 /// Break condition for early loop termination
+///
+/// Any satisfied condition breaks the loop. Untagged `{expression, message}`
+/// objects from before aggregate conditions still deserialize as `Expression`.
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct BreakCondition {
-    /// Condition expression that when true, breaks the loop
-    pub expression: String,
-    /// Optional message to include when breaking
-    pub message: Option<String>,
+#[serde(tag = "type", rename_all = "snake_case", remote = "Self")]
+pub enum BreakCondition {
+    /// Expression over loop variables, evaluated before each iteration
+    Expression {
+        /// Condition expression that when true, breaks the loop
+        expression: String,
+        /// Optional message to include when breaking
+        message: Option<String>,
+    },
+    /// Threshold on the running result aggregation, evaluated after each iteration
+    Aggregate {
+        /// Function applied to the numeric outputs retained by the aggregation strategy
+        function: AggregateFunction,
+        /// Loop breaks once the aggregate exceeds this value
+        threshold: f64,
+        /// Optional message to include when breaking
+        message: Option<String>,
+    },
+}
+
+impl Serialize for BreakCondition {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        Self::serialize(self, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for BreakCondition {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Repr {
+            Tagged(#[serde(with = "BreakCondition")] BreakCondition),
+            Legacy {
+                expression: String,
+                #[serde(default)]
+                message: Option<String>,
+            },
+        }
+
+        Ok(match Repr::deserialize(deserializer)? {
+            Repr::Tagged(condition) => condition,
+            Repr::Legacy {
+                expression,
+                message,
+            } => Self::Expression {
+                expression,
+                message,
+            },
+        })
+    }
+}
+
+impl BreakCondition {
+    /// Create an expression-based break condition
+    pub fn expression(expression: impl Into<String>, message: Option<String>) -> Self {
+        Self::Expression {
+            expression: expression.into(),
+            message,
+        }
+    }
+
+    /// Create a break condition on the running result aggregation
+    pub fn aggregate(function: AggregateFunction, threshold: f64, message: Option<String>) -> Self {
+        Self::Aggregate {
+            function,
+            threshold,
+            message,
+        }
+    }
+}
+
+/// Function applied to aggregated iteration outputs for aggregate break conditions
+///
+/// Outputs that don't parse as numbers are ignored (except by `Count`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AggregateFunction {
+    /// Sum of numeric outputs
+    Sum,
+    /// Mean of numeric outputs
+    Mean,
+    /// Minimum numeric output
+    Min,
+    /// Maximum numeric output
+    Max,
+    /// Number of aggregated outputs
+    Count,
+}
+
+impl AggregateFunction {
+    /// Apply the function to aggregated outputs, `None` if there is nothing to aggregate
+    pub fn apply<'a>(self, outputs: impl IntoIterator<Item = &'a Value>) -> Option<f64> {
+        let outputs: Vec<&Value> = outputs.into_iter().collect();
+        let numbers: Vec<f64> = outputs.iter().copied().filter_map(numeric_output).collect();
+
+        #[allow(clippy::cast_precision_loss)]
+        match self {
+            Self::Count => Some(outputs.len() as f64),
+            _ if numbers.is_empty() => None,
+            Self::Sum => Some(numbers.iter().sum()),
+            Self::Mean => Some(numbers.iter().sum::<f64>() / numbers.len() as f64),
+            Self::Min => numbers.into_iter().reduce(f64::min),
+            Self::Max => numbers.into_iter().reduce(f64::max),
+        }
+    }
+}
+
+/// Extract a number from an iteration output (JSON number or numeric string)
+fn numeric_output(value: &Value) -> Option<f64> {
+    match value {
+        Value::Number(n) => n.as_f64(),
+        Value::String(s) => s.trim().parse().ok(),
+        _ => None,
+    }
 }
 
 /// Result aggregation strategy
@@ -476,20 +588,51 @@ impl LoopWorkflow {
         iteration: usize,
     ) -> Result<Option<String>> {
         for condition in &self.config.break_conditions {
+            let BreakCondition::Expression {
+                expression,
+                message,
+            } = condition
+            else {
+                continue;
+            };
             if self
-                .evaluate_condition(&condition.expression, state, iteration)
+                .evaluate_condition(expression, state, iteration)
                 .await?
             {
-                let message = condition
-                    .message
+                let message = message
                     .clone()
-                    .unwrap_or_else(|| format!("Break condition met: {}", condition.expression));
+                    .unwrap_or_else(|| format!("Break condition met: {}", expression));
                 return Ok(Some(message));
             }
         }
         Ok(None)
     }
 
+    /// Evaluate aggregate break conditions against the running aggregation
+    fn should_break_on_aggregate(&self, aggregated: &HashMap<String, Value>) -> Option<String> {
+        for condition in &self.config.break_conditions {
+            let BreakCondition::Aggregate {
+                function,
+                threshold,
+                message,
+            } = condition
+            else {
+                continue;
+            };
+            if let Some(value) = function.apply(aggregated.values()) {
+                if value > *threshold {
+                    return Some(message.clone().unwrap_or_else(|| {
+                        format!(
+                            "Aggregate break condition met: {:?} {} > {}",
+                            function, value, threshold
+                        )
+                    }));
+                }
+            }
+        }
+        None
+    }
+
     /// Evaluate a condition expression
     async fn evaluate_condition(
         &self,
@@ -789,17 +932,9 @@ impl LoopWorkflow {
         iteration: usize,
         total_iterations: usize,
     ) -> Result<Option<(String, usize)>> {
-        for break_condition in &self.config.break_conditions {
-            if self
-                .evaluate_condition(&break_condition.expression, workflow_state, iteration)
-                .await?
-            {
-                let reason = break_condition.message.clone().unwrap_or_else(|| {
-                    format!("Break condition met: {}", break_condition.expression)
-                });
-                let skipped = total_iterations.saturating_sub(iteration + 1);
-                return Ok(Some((reason, skipped)));
-            }
+        if let Some(reason) = self.should_break(workflow_state, iteration).await? {
+            let skipped = total_iterations.saturating_sub(iteration + 1);
+            return Ok(Some((reason, skipped)));
         }
         Ok(None)
     }
@@ -1287,6 +1422,16 @@ impl LoopWorkflow {
                     completed_iterations,
                     last_step_output,
                 );
+
+                if let Some(reason) = self.should_break_on_aggregate(&aggregated_results) {
+                    debug!(
+                        "Loop workflow '{}' breaking after iteration {}: {}",
+                        self.name, iteration, reason
+                    );
+                    break_reason = Some(reason);
+                    skipped_iterations = total_iterations.saturating_sub(iteration + 1);
+                    break;
+                }
             } else {
                 failed_iterations += 1;
                 if !self.config.continue_on_error {
@@ -1369,6 +1514,7 @@ impl LoopWorkflow {
         let total_iterations = iterator_values.len();
 
         let mut all_results = Vec::new();
+        let mut running_aggregate = HashMap::new();
         let mut completed_iterations = 0;
         let mut break_reason = None;
 
@@ -1425,6 +1571,20 @@ impl LoopWorkflow {
             }
             completed_iterations += 1;
 
+            if let Some(last) = all_results.last().and_then(|results| results.last()) {
+                self.aggregate_iteration_result(
+                    &mut running_aggregate,
+                    iteration,
+                    completed_iterations,
+                    Value::String(last.output.clone()),
+                );
+            }
+            if let Some(reason) = self.should_break_on_aggregate(&running_aggregate) {
+                info!("Breaking loop: {}", reason);
+                break_reason = Some(reason);
+                break;
+            }
+
             // Apply iteration delay if configured
             if let Some(delay) = self.config.iteration_delay {
                 tokio::time::sleep(delay).await;
@@ -1907,10 +2067,20 @@ impl LoopWorkflowBuilder {
         expression: impl Into<String>,
         message: Option<String>,
     ) -> Self {
-        self.break_conditions.push(BreakCondition {
-            expression: expression.into(),
-            message,
-        });
+        self.break_conditions
+            .push(BreakCondition::expression(expression, message));
+        self
+    }
+
+    /// Add a condition that breaks the loop once the running aggregation exceeds a threshold
+    pub fn add_aggregate_break_condition(
+        mut self,
+        function: AggregateFunction,
+        threshold: f64,
+        message: Option<String>,
+    ) -> Self {
+        self.break_conditions
+            .push(BreakCondition::aggregate(function, threshold, message));
         self
     }
 
@@ -2074,4 +2244,96 @@ mod tests {
 
         assert!(result.is_err());
     }
+    #[tokio::test]
+    async fn test_aggregate_break_condition() {
+        let workflow = LoopWorkflowBuilder::new("sum_loop")
+            .with_range(0, 10, 1)
+            .add_step(TraitWorkflowStep::new(
+                "score".to_string(),
+                StepType::Tool {
+                    tool_name: "constant".to_string(),
+                    parameters: serde_json::json!({"value": 0.25}),
+                },
+            ))
+            .add_break_condition("$iteration > 8", None)
+            .add_aggregate_break_condition(
+                AggregateFunction::Sum,
+                0.9,
+                Some("score threshold reached".to_string()),
+            )
+            .build()
+            .unwrap();
+
+        let result = workflow.execute_workflow().await.unwrap();
+
+        assert!(result.success);
+        // 0.25 * 4 = 1.0 is the first sum exceeding 0.9
+        assert_eq!(result.completed_iterations, 4);
+        assert_eq!(result.total_iterations, 10);
+        assert_eq!(
+            result.break_reason.as_deref(),
+            Some("score threshold reached")
+        );
+    }
+    #[test]
+    fn test_aggregate_function_apply() {
+        let outputs = [
+            Value::String("1.5".to_string()),
+            serde_json::json!(2),
+            Value::String("not a number".to_string()),
+        ];
+        assert_eq!(AggregateFunction::Sum.apply(&outputs), Some(3.5));
+        assert_eq!(AggregateFunction::Mean.apply(&outputs), Some(1.75));
+        assert_eq!(AggregateFunction::Min.apply(&outputs), Some(1.5));
+        assert_eq!(AggregateFunction::Max.apply(&outputs), Some(2.0));
+        assert_eq!(AggregateFunction::Count.apply(&outputs), Some(3.0));
+        assert_eq!(AggregateFunction::Sum.apply(std::iter::empty()), None);
+    }
+    #[test]
+    fn test_legacy_break_condition_json() {
+        let legacy = serde_json::json!({"expression": "$iteration > 3", "message": "done"});
+        let condition: BreakCondition = serde_json::from_value(legacy).unwrap();
+        let BreakCondition::Expression {
+            expression,
+            message,
+        } = &condition
+        else {
+            panic!("legacy JSON should deserialize as an expression condition");
+        };
+        assert_eq!(expression, "$iteration > 3");
+        assert_eq!(message.as_deref(), Some("done"));
+
+        // Legacy conditions without a message and inside a full config
+        let config: LoopConfig = serde_json::from_value(serde_json::json!({
+            "iterator": {"type": "range", "start": 0, "end": 5, "step": 1},
+            "body": [],
+            "break_conditions": [{"expression": "$iteration > 1"}],
+            "aggregation": "collect_all",
+            "continue_on_error": false,
+            "timeout": null,
+            "iteration_delay": null,
+        }))
+        .unwrap();
+        assert!(matches!(
+            &config.break_conditions[..],
+            [BreakCondition::Expression { message: None, .. }]
+        ));
+
+        // Re-serialized conditions use the tagged form and round-trip
+        let json = serde_json::to_value(&condition).unwrap();
+        assert_eq!(json["type"], "expression");
+        let round_trip: BreakCondition = serde_json::from_value(json).unwrap();
+        assert!(matches!(round_trip, BreakCondition::Expression { .. }));
+
+        let aggregate = BreakCondition::aggregate(AggregateFunction::Max, 2.0, None);
+        let json = serde_json::to_value(&aggregate).unwrap();
+        assert_eq!(json["type"], "aggregate");
+        assert!(matches!(
+            serde_json::from_value(json).unwrap(),
+            BreakCondition::Aggregate {
+                function: AggregateFunction::Max,
+                ..
+            }
+        ));
+    }
 }
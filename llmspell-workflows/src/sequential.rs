@@ -4,7 +4,7 @@
 use super::error_handling::{ErrorAction, ErrorHandler};
 use super::hooks::{WorkflowExecutionPhase, WorkflowExecutor, WorkflowHookContext};
 use super::result::{WorkflowError, WorkflowResult, WorkflowType};
-use super::state::StateManager;
+use super::state::{StateManager, WorkflowCheckpoint};
 use super::step_executor::StepExecutor;
 use super::traits::{ErrorStrategy, StepType, WorkflowStatus, WorkflowStep};
 use super::types::{StepExecutionContext, WorkflowConfig};
@@ -45,6 +45,8 @@ pub struct SequentialWorkflow {
     core_steps: Arc<RwLock<Vec<CoreWorkflowStep>>>,
     /// Core workflow results for Workflow trait
     core_results: Arc<RwLock<Vec<CoreStepResult>>>,
+    /// Index of the first step to execute (non-zero when resumed from a checkpoint)
+    resume_from: usize,
 }
 
 impl SequentialWorkflow {
@@ -89,6 +91,7 @@ impl SequentialWorkflow {
             core_config,
             core_steps: Arc::new(RwLock::new(Vec::new())),
             core_results: Arc::new(RwLock::new(Vec::new())),
+            resume_from: 0,
         }
     }
 
@@ -143,6 +146,7 @@ impl SequentialWorkflow {
             core_config,
             core_steps: Arc::new(RwLock::new(Vec::new())),
             core_results: Arc::new(RwLock::new(Vec::new())),
+            resume_from: 0,
         }
     }
 
@@ -271,7 +275,16 @@ impl BaseAgent for SequentialWorkflow {
         let mut steps_failed = 0usize;
         let mut steps_skipped = 0usize;
 
-        for (index, step) in self.steps.iter().enumerate() {
+        if self.resume_from > 0 {
+            info!(
+                "Resuming workflow '{}' at step {} of {}",
+                self.name,
+                self.resume_from + 1,
+                self.steps.len()
+            );
+        }
+
+        for (index, step) in self.steps.iter().enumerate().skip(self.resume_from) {
             // Check for execution timeout
             if self.state_manager.check_execution_timeout().await? {
                 error!("Workflow '{}' exceeded maximum execution time", self.name);
@@ -704,6 +717,8 @@ pub struct SequentialWorkflowBuilder {
     workflow_executor: Option<Arc<WorkflowExecutor>>,
     template_executor: Option<Arc<dyn llmspell_core::traits::template_executor::TemplateExecutor>>,
     registry: Option<Arc<dyn ComponentLookup>>,
+    state_manager: Option<StateManager>,
+    resume_from: usize,
 }
 
 impl SequentialWorkflowBuilder {
@@ -717,6 +732,8 @@ impl SequentialWorkflowBuilder {
             workflow_executor: None,
             template_executor: None,
             registry: None,
+            state_manager: None,
+            resume_from: 0,
         }
     }
 
@@ -812,6 +829,18 @@ impl SequentialWorkflowBuilder {
         self
     }
 
+    /// Use an existing state manager, e.g. one owned by a `PersistentWorkflowStateManager`
+    pub fn with_state_manager(mut self, state_manager: StateManager) -> Self {
+        self.state_manager = Some(state_manager);
+        self
+    }
+
+    /// Resume from a checkpoint, skipping steps that already completed
+    pub fn resume_from(mut self, checkpoint: &WorkflowCheckpoint) -> Self {
+        self.resume_from = checkpoint.step_index;
+        self
+    }
+
     /// Build the sequential workflow
     pub fn build(mut self) -> SequentialWorkflow {
         // Apply error strategy if provided
@@ -819,6 +848,7 @@ impl SequentialWorkflowBuilder {
             self.config.default_error_strategy = strategy;
         }
 
+        let hook_executor = self.workflow_executor.clone();
         let mut workflow = match (self.workflow_executor, self.registry) {
             (Some(executor), Some(registry)) => SequentialWorkflow::new_with_hooks_and_registry(
                 self.name,
@@ -836,6 +866,13 @@ impl SequentialWorkflowBuilder {
         };
         workflow.add_steps(self.steps);
         workflow.template_executor = self.template_executor;
+        if let Some(mut state_manager) = self.state_manager {
+            if let Some(executor) = hook_executor {
+                state_manager.with_hooks(executor);
+            }
+            workflow.state_manager = state_manager;
+        }
+        workflow.resume_from = self.resume_from;
         workflow
     }
 }
@@ -1051,4 +1088,148 @@ mod tests {
         let status = workflow.state_manager.get_status().await.unwrap();
         assert_eq!(status, WorkflowStatus::Pending);
     }
+    #[tokio::test]
+    async fn test_step_timeout_continue_strategy() {
+        let fast_step = WorkflowStep::new(
+            "fast".to_string(),
+            StepType::Tool {
+                tool_name: "calculator".to_string(),
+                parameters: serde_json::json!({"expression": "1 + 1"}),
+            },
+        );
+        let slow_step = WorkflowStep::new(
+            "slow".to_string(),
+            StepType::Tool {
+                tool_name: "slow_tool".to_string(),
+                parameters: serde_json::json!({"delay_ms": 2000}),
+            },
+        )
+        .with_timeout(std::time::Duration::from_millis(50));
+        let next_step = WorkflowStep::new(
+            "next".to_string(),
+            StepType::Tool {
+                tool_name: "json_processor".to_string(),
+                parameters: serde_json::json!({"input": {"data": "after timeout"}}),
+            },
+        );
+
+        let workflow = SequentialWorkflow::builder("timeout_workflow".to_string())
+            .add_step(fast_step)
+            .add_step(slow_step)
+            .add_step(next_step)
+            .with_error_strategy(ErrorStrategy::Continue)
+            .build();
+
+        let input = AgentInput::text("run");
+        let context = crate::test_utils::create_test_execution_context();
+        // Partial completion is reported as an error, but all steps must have run
+        let _ = workflow.execute(input, context).await;
+
+        let history = workflow
+            .state_manager
+            .get_execution_history()
+            .await
+            .unwrap();
+        assert_eq!(history.len(), 3);
+        assert!(history[0].success);
+        assert!(!history[1].success);
+        assert!(history[1].timed_out);
+        assert!(history[1].duration < std::time::Duration::from_millis(1000));
+        assert!(history[2].success);
+        assert!(!history[2].timed_out);
+    }
+
+    #[tokio::test]
+    async fn test_checkpoint_resume_skips_completed_steps() {
+        use crate::state::PersistentWorkflowStateManager;
+
+        let step = |name: &str| {
+            WorkflowStep::new(
+                name.to_string(),
+                StepType::Tool {
+                    tool_name: "calculator".to_string(),
+                    parameters: serde_json::json!({"expression": "1 + 1"}),
+                },
+            )
+        };
+        let backend: Arc<dyn llmspell_core::state::StateManager> = Arc::new(
+            llmspell_kernel::state::StateManager::new(None)
+                .await
+                .unwrap(),
+        );
+
+        // First run: steps one and two complete, then the process "crashes"
+        let mut manager = PersistentWorkflowStateManager::new(
+            WorkflowConfig::default(),
+            backend.clone(),
+            "resumable".to_string(),
+        );
+        let first_run = SequentialWorkflow::builder("resumable".to_string())
+            .add_step(step("step1"))
+            .add_step(step("step2"))
+            .with_state_manager(manager.memory_state_manager().clone())
+            .build();
+        first_run
+            .execute(
+                AgentInput::text("run"),
+                crate::test_utils::create_test_execution_context(),
+            )
+            .await
+            .unwrap();
+        manager
+            .memory_state_manager()
+            .set_shared_data("progress".to_string(), serde_json::json!("halfway"))
+            .await
+            .unwrap();
+        let checkpoint = manager.checkpoint().await.unwrap();
+        assert_eq!(checkpoint.step_index, 2);
+        drop(manager);
+
+        // Restart: a fresh manager over the same backend
+        let mut restarted = PersistentWorkflowStateManager::new(
+            WorkflowConfig::default(),
+            backend,
+            "resumable".to_string(),
+        );
+        let checkpoint = restarted.resume().await.unwrap().expect("checkpoint");
+        assert_eq!(
+            restarted
+                .memory_state_manager()
+                .get_shared_data("progress")
+                .await
+                .unwrap(),
+            Some(serde_json::json!("halfway"))
+        );
+
+        let resumed = SequentialWorkflow::builder("resumable".to_string())
+            .add_step(step("step1"))
+            .add_step(step("step2"))
+            .add_step(step("step3"))
+            .with_state_manager(restarted.memory_state_manager().clone())
+            .resume_from(&checkpoint)
+            .build();
+        resumed
+            .execute(
+                AgentInput::text("run"),
+                crate::test_utils::create_test_execution_context(),
+            )
+            .await
+            .unwrap();
+
+        let history = restarted
+            .memory_state_manager()
+            .get_execution_history()
+            .await
+            .unwrap();
+        let names: Vec<&str> = history.iter().map(|r| r.step_name.as_str()).collect();
+        assert_eq!(names, vec!["step1", "step2", "step3"]);
+        assert_eq!(
+            restarted
+                .memory_state_manager()
+                .get_current_step()
+                .await
+                .unwrap(),
+            3
+        );
+    }
 }
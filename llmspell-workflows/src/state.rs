@@ -441,6 +441,36 @@ impl StateManager {
         Ok(state.clone())
     }
 
+    /// Restore state and execution history, e.g. from a checkpoint
+    pub async fn restore_snapshot(
+        &self,
+        snapshot: WorkflowState,
+        history: Vec<StepResult>,
+    ) -> Result<()> {
+        {
+            let mut state = self.state.write().map_err(|e| LLMSpellError::Workflow {
+                message: format!("Failed to acquire state lock: {}", e),
+                step: None,
+                source: None,
+            })?;
+            *state = snapshot;
+        }
+
+        {
+            let mut execution_history =
+                self.execution_history
+                    .write()
+                    .map_err(|e| LLMSpellError::Workflow {
+                        message: format!("Failed to acquire history lock: {}", e),
+                        step: None,
+                        source: None,
+                    })?;
+            *execution_history = history;
+        }
+
+        Ok(())
+    }
+
     /// Track shared data access for hooks (call after get operations)
     pub async fn track_shared_data_access(
         &self,
@@ -901,6 +931,92 @@ impl PersistentWorkflowStateManager {
         Ok(())
     }
 
+    /// Checkpoint the current step index, shared state and history to persistent storage
+    pub async fn checkpoint(&mut self) -> Result<WorkflowCheckpoint> {
+        let state_snapshot = self.memory_state_manager.get_state_snapshot().await?;
+        let execution_history = self.memory_state_manager.get_execution_history().await?;
+        let status = self.memory_state_manager.get_status().await?;
+
+        let checkpoint = WorkflowCheckpoint {
+            step_index: state_snapshot.current_step,
+            state_snapshot: state_snapshot.clone(),
+            execution_history: execution_history.clone(),
+            created_at: SystemTime::now(),
+            description: format!("Checkpoint after step {}", state_snapshot.current_step),
+            is_automatic: false,
+        };
+
+        if self.persistent_state.is_none() {
+            self.load_state().await?;
+        }
+        let mut persistent_state = self.persistent_state.take().unwrap_or_else(|| {
+            PersistentWorkflowState::new(
+                self.workflow_id.clone(),
+                self.memory_state_manager.config.clone(),
+                ComponentMetadata::new(self.workflow_id.clone(), "Persistent workflow".to_string()),
+            )
+        });
+        persistent_state.workflow_state = state_snapshot;
+        persistent_state.execution_history = execution_history;
+        persistent_state.status = status;
+        persistent_state
+            .checkpoints
+            .insert(checkpoint.step_index, checkpoint.clone());
+        persistent_state.last_updated = checkpoint.created_at;
+
+        let state_scope = StateScope::Custom(format!("workflow_{}", self.workflow_id));
+        self.persistent_state_manager
+            .set(
+                state_scope,
+                "state",
+                serde_json::to_value(&persistent_state)?,
+            )
+            .await
+            .map_err(|e| LLMSpellError::Internal {
+                message: format!("Failed to save workflow checkpoint: {}", e),
+                source: None,
+            })?;
+
+        self.persistent_state = Some(persistent_state);
+        info!(
+            "Checkpointed workflow {} at step {}",
+            self.workflow_id, checkpoint.step_index
+        );
+        Ok(checkpoint)
+    }
+
+    /// Restore the latest checkpoint into the in-memory state manager
+    ///
+    /// Returns `None` if no checkpoint has been saved for this workflow.
+    pub async fn resume(&mut self) -> Result<Option<WorkflowCheckpoint>> {
+        if !self.load_state().await? {
+            return Ok(None);
+        }
+
+        let Some(checkpoint) = self
+            .persistent_state
+            .as_ref()
+            .and_then(|state| state.checkpoints.values().max_by_key(|cp| cp.step_index))
+            .cloned()
+        else {
+            debug!("No checkpoint found for workflow {}", self.workflow_id);
+            return Ok(None);
+        };
+
+        self.memory_state_manager
+            .restore_snapshot(
+                checkpoint.state_snapshot.clone(),
+                checkpoint.execution_history.clone(),
+            )
+            .await?;
+
+        info!(
+            "Resumed workflow {} from step {}",
+            self.workflow_id, checkpoint.step_index
+        );
+        Ok(Some(checkpoint))
+    }
+
     /// Get in-memory state manager
     pub fn memory_state_manager(&self) -> &StateManager {
         &self.memory_state_manager
@@ -7,7 +7,6 @@ use super::types::{StepExecutionContext, WorkflowConfig};
 use llmspell_core::{ComponentId, ComponentLookup, ComponentMetadata, LLMSpellError, Result};
 use std::sync::Arc;
 use std::time::{Duration, Instant};
-use tokio::time::timeout;
 use tracing::field::Empty;
 use tracing::{debug, error, info, instrument, warn};
 
@@ -123,7 +122,7 @@ impl StepExecutor {
         }
 
         // Execute with timeout
-        let result = timeout(
+        let result = llmspell_utils::with_timeout(
             step_timeout,
             self.execute_step_internal(
                 step,
@@ -180,10 +179,10 @@ impl StepExecutor {
             }
             Err(_) => {
                 error!("Step '{}' timed out after {:?}", step.name, step_timeout);
-                StepResult::failure(
+                StepResult::timeout(
                     step.id,
                     step.name.clone(),
-                    format!("Step timed out after {:?}", step_timeout),
+                    step_timeout,
                     duration,
                     context.retry_attempt,
                 )
@@ -681,6 +680,24 @@ impl StepExecutor {
             "text_parser" => {
                 format!("Text parsed with parameters: {}", parameters)
             }
+            "constant" => {
+                // Returns the configured value verbatim
+                let default_value = serde_json::json!(0);
+                let value = parameters.get("value").unwrap_or(&default_value);
+                match value {
+                    serde_json::Value::String(s) => s.clone(),
+                    other => other.to_string(),
+                }
+            }
+            "slow_tool" => {
+                // Deliberately slow tool for timeout testing
+                let delay_ms = parameters
+                    .get("delay_ms")
+                    .and_then(serde_json::Value::as_u64)
+                    .unwrap_or(1000);
+                tokio::time::sleep(Duration::from_millis(delay_ms)).await;
+                format!("Slow tool completed after {}ms", delay_ms)
+            }
             "item_processor" => {
                 let default_item = serde_json::json!("item");
                 let item = parameters.get("item").unwrap_or(&default_item);
@@ -1077,6 +1094,7 @@ mod tests {
         let result = executor.execute_step(&step, context).await.unwrap();
 
         assert!(!result.success);
+        assert!(result.timed_out);
         assert!(result.error.is_some());
         assert!(result.error.unwrap().contains("timed out"));
     }
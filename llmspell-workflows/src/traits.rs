@@ -99,6 +99,9 @@ pub struct StepResult {
     pub duration: Duration,
     /// Number of times this step was retried
     pub retry_count: u32,
+    /// Whether the step was abandoned because it exceeded its timeout
+    #[serde(default)]
+    pub timed_out: bool,
 }
 
 impl StepResult {
@@ -117,6 +120,7 @@ impl StepResult {
             error: None,
             duration,
             retry_count: 0,
+            timed_out: false,
         }
     }
 
@@ -136,6 +140,27 @@ impl StepResult {
             error: Some(error),
             duration,
             retry_count,
+            timed_out: false,
+        }
+    }
+
+    /// Create a failed step result for a step that exceeded its timeout
+    pub fn timeout(
+        step_id: ComponentId,
+        step_name: String,
+        timeout: Duration,
+        duration: Duration,
+        retry_count: u32,
+    ) -> Self {
+        Self {
+            timed_out: true,
+            ..Self::failure(
+                step_id,
+                step_name,
+                format!("Step timed out after {:?}", timeout),
+                duration,
+                retry_count,
+            )
         }
     }
 }